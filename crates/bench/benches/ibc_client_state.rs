@@ -0,0 +1,69 @@
+use std::str::FromStr;
+
+use base64::prelude::*;
+use cnidarium::StateDelta;
+use ibc_types::core::client::{msgs::MsgCreateClient, ClientId};
+use ibc_types::lightclients::tendermint::client_state::ClientState as TendermintClientState;
+use penumbra_ibc::component::{ClientStateReadExt as _, ClientStateWriteExt as _};
+use penumbra_proto::DomainType;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// Returns the client state of the Stargaze light client on the Cosmos Hub.
+fn stargaze_client_state() -> TendermintClientState {
+    let msg_create_client_raw = BASE64_STANDARD
+        .decode(
+            include_str!("../../core/component/ibc/src/component/test/create_client.msg")
+                .replace('\n', ""),
+        )
+        .expect("valid base64");
+    let msg_create_client =
+        <MsgCreateClient as ibc_types::DomainType>::decode(msg_create_client_raw.as_slice())
+            .expect("valid message");
+
+    TendermintClientState::try_from(msg_create_client.client_state)
+        .expect("valid tendermint client state")
+}
+
+// Compares reading a client's proof specs out of its full client state with
+// reading the proof specs cached for the client.
+fn client_proof_specs(c: &mut Criterion) {
+    let runtime = tokio::runtime::Runtime::new().expect("can build a runtime");
+    let client_id = ClientId::from_str("07-tendermint-0").expect("valid client id");
+    let client_state = stargaze_client_state();
+    let encoded = client_state.encode_to_vec();
+
+    let mut state = StateDelta::new(());
+    state.put_client(&client_id, client_state);
+
+    c.bench_function("ibc: decode client state", |b| {
+        b.iter(|| {
+            let client_state =
+                <TendermintClientState as DomainType>::decode(black_box(encoded.as_slice()))
+                    .expect("valid client state");
+            black_box(client_state.proof_specs)
+        })
+    });
+
+    c.bench_function("ibc: get client state proof specs", |b| {
+        b.iter(|| {
+            let client_state = runtime
+                .block_on(state.get_client_state(black_box(&client_id)))
+                .expect("client exists");
+            black_box(client_state.proof_specs)
+        })
+    });
+
+    c.bench_function("ibc: get cached client proof specs", |b| {
+        b.iter(|| {
+            black_box(
+                runtime
+                    .block_on(state.get_client_proof_specs(black_box(&client_id)))
+                    .expect("client exists"),
+            )
+        })
+    });
+}
+
+criterion_group!(benches, client_proof_specs);
+criterion_main!(benches);
@@ -1,12 +1,9 @@
-use std::{
-    io::{stdin, IsTerminal as _, Read, Write},
-    str::FromStr,
-};
+use std::io::{stdin, IsTerminal as _, Read, Write};
 
 use anyhow::Result;
 use camino::Utf8PathBuf;
 use penumbra_custody::threshold;
-use penumbra_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
+use penumbra_keys::keys::{Bip44Path, FullViewingKeyExport, SeedPhrase, SpendKey, Wordlist};
 use rand_core::OsRng;
 use termion::screen::IntoAlternateScreen;
 use url::Url;
@@ -45,7 +42,8 @@ pub enum InitTopSubCmd {
     /// Initialize `pcli` in view-only mode, without spending keys.
     #[clap(display_order = 200)]
     ViewOnly {
-        /// The full viewing key for the wallet to view.
+        /// The full viewing key for the wallet to view, either bare or as
+        /// exported with `pcli view export-fvk`, with the wallet's birthday.
         full_viewing_key: String,
     },
     /// Initialize a separate validator governance key for an existing `pcli` configuration (this
@@ -82,6 +80,15 @@ pub enum SoftKmsInitCmd {
         /// If set, will write the seed phrase to stdout.
         #[clap(long, action)]
         stdout: bool,
+        /// If set, will prompt for a BIP39 passphrase to protect the seed phrase.
+        ///
+        /// The same passphrase is needed to import the seed phrase again.
+        #[clap(long, action)]
+        passphrase: bool,
+        /// A BIP39 wordlist to generate the seed phrase from, one word per line,
+        /// instead of the English one.
+        #[clap(long)]
+        wordlist: Option<Utf8PathBuf>,
     },
     /// Import a spend key from an existing seed phrase.
     #[clap(display_order = 200)]
@@ -93,14 +100,48 @@ pub enum SoftKmsInitCmd {
         /// - you need to replicate legacy derivation for some reason.
         #[clap(long, action)]
         legacy_raw_bip39_derivation: bool,
+        /// If set, will prompt for the BIP39 passphrase protecting the seed phrase.
+        #[clap(long, action, conflicts_with = "legacy_raw_bip39_derivation")]
+        passphrase: bool,
+        /// The BIP39 wordlist of the seed phrase, one word per line, if not the
+        /// English one.
+        #[clap(long)]
+        wordlist: Option<Utf8PathBuf>,
     },
 }
 
+/// Reads the BIP39 wordlist at `path`, or the English one if unset.
+fn read_wordlist(path: &Option<Utf8PathBuf>) -> Result<Wordlist> {
+    match path {
+        Some(path) => Wordlist::parse(&std::fs::read_to_string(path)?),
+        None => Ok(Wordlist::english()),
+    }
+}
+
+/// Prompts for a BIP39 passphrase if `prompt` is set, or returns the empty passphrase.
+fn read_passphrase(prompt: bool, confirm: bool) -> Result<String> {
+    if !prompt {
+        return Ok(String::new());
+    }
+    if !std::io::stdin().is_terminal() {
+        anyhow::bail!("entering a passphrase requires an interactive terminal");
+    }
+    let passphrase = rpassword::prompt_password("Enter passphrase: ")?;
+    if confirm && passphrase != rpassword::prompt_password("Confirm passphrase: ")? {
+        anyhow::bail!("passphrases do not match");
+    }
+    Ok(passphrase)
+}
+
 impl SoftKmsInitCmd {
     fn spend_key(&self, init_type: InitType) -> Result<SpendKey> {
         Ok(match self {
-            SoftKmsInitCmd::Generate { stdout } => {
-                let seed_phrase = SeedPhrase::generate(OsRng);
+            SoftKmsInitCmd::Generate {
+                stdout,
+                passphrase,
+                wordlist,
+            } => {
+                let seed_phrase = SeedPhrase::generate_with(OsRng, &read_wordlist(wordlist)?);
                 let seed_msg = format!(
                     "YOUR PRIVATE SEED PHRASE ({init_type:?}):\n\n\
                    {seed_phrase}\n\n\
@@ -121,11 +162,14 @@ impl SoftKmsInitCmd {
                     let _ = stdin().bytes().next();
                 }
 
+                let passphrase = read_passphrase(*passphrase, true)?;
                 let path = Bip44Path::new(0);
-                SpendKey::from_seed_phrase_bip44(seed_phrase, &path)
+                SpendKey::from_seed_phrase_bip44_with_passphrase(seed_phrase, &passphrase, &path)
             }
             SoftKmsInitCmd::ImportPhrase {
                 legacy_raw_bip39_derivation,
+                passphrase,
+                wordlist,
             } => {
                 let mut seed_phrase = String::new();
                 // The `rpassword` crate doesn't support reading from stdin, so we check
@@ -143,13 +187,18 @@ impl SoftKmsInitCmd {
                     }
                 }
 
-                let seed_phrase = SeedPhrase::from_str(&seed_phrase)?;
+                let seed_phrase = SeedPhrase::parse_with(&seed_phrase, &read_wordlist(wordlist)?)?;
 
                 if *legacy_raw_bip39_derivation {
                     SpendKey::from_seed_phrase_bip39(seed_phrase, 0)
                 } else {
+                    let passphrase = read_passphrase(*passphrase, false)?;
                     let path = Bip44Path::new(0);
-                    SpendKey::from_seed_phrase_bip44(seed_phrase, &path)
+                    SpendKey::from_seed_phrase_bip44_with_passphrase(
+                        seed_phrase,
+                        &passphrase,
+                        &path,
+                    )
                 }
             }
         })
@@ -232,6 +281,8 @@ fn exec_deal(
                 view_url: None,
                 disable_warning: false,
                 governance_custody: None,
+                auto_sweep_threshold: None,
+                birthday: None,
             }
         } else {
             let mut pcli_config = PcliConfig::load(config_path.join(crate::CONFIG_FILE_NAME))?;
@@ -303,6 +354,8 @@ impl InitCmd {
                 .is_some_and(|x| x.governance_custody.is_some()),
         };
 
+        // Only a view-only wallet is imported with a known birthday.
+        let mut birthday = None;
         let (full_viewing_key, custody) = match (&init_type, &subcmd, relevant_config_exists) {
             (_, InitSubCmd::SoftKms(cmd), false) => {
                 let spend_key = cmd.spend_key(init_type)?;
@@ -346,8 +399,9 @@ impl InitCmd {
                 unreachable!("this should already have been handled above")
             }
             (InitType::SpendKey, InitSubCmd::ViewOnly { full_viewing_key }, false) => {
-                let full_viewing_key = full_viewing_key.parse()?;
-                (full_viewing_key, CustodyConfig::ViewOnly)
+                let export = full_viewing_key.parse::<FullViewingKeyExport>()?;
+                birthday = Some(export.birthday).filter(|&height| height > 0);
+                (export.fvk, CustodyConfig::ViewOnly)
             }
             (InitType::GovernanceKey, InitSubCmd::ViewOnly { .. }, false) => {
                 unreachable!("governance keys can't be initialized in view-only mode")
@@ -413,6 +467,8 @@ impl InitCmd {
                 view_url: None,
                 disable_warning: false,
                 governance_custody: None,
+                auto_sweep_threshold: None,
+                birthday,
             }
         } else {
             let config_path = home_dir.join(crate::CONFIG_FILE_NAME);
@@ -2,9 +2,12 @@ use anyhow::{anyhow, Context, Result};
 use comfy_table::{presets, Table};
 use futures::TryStreamExt;
 use penumbra_app::params::AppParameters;
+use penumbra_asset::{asset, Value};
+use penumbra_num::Amount;
 use penumbra_proto::{
     core::app::v1::{
         query_service_client::QueryServiceClient as AppQueryServiceClient, AppParametersRequest,
+        ValueBalanceAuditRequest,
     },
     core::component::sct::v1::{
         query_service_client::QueryServiceClient as SctQueryServiceClient, EpochByHeightRequest,
@@ -12,11 +15,13 @@ use penumbra_proto::{
     core::component::stake::v1::{
         query_service_client::QueryServiceClient as StakeQueryServiceClient, ValidatorInfoRequest,
     },
+    core::num::v1 as pb_num,
     util::tendermint_proxy::v1::{
         tendermint_proxy_service_client::TendermintProxyServiceClient, GetStatusRequest,
     },
 };
 use penumbra_stake::validator;
+use penumbra_view::ViewClient;
 
 use crate::App;
 
@@ -30,6 +35,11 @@ pub enum ChainCmd {
         #[clap(short, long)]
         verbose: bool,
     },
+    /// Audit the supply of assets against the IBC and Community Pool balances.
+    Supply {
+        /// Audit only the specified assets, given as asset IDs or unit names.
+        assets: Vec<String>,
+    },
 }
 
 pub struct Stats {
@@ -61,6 +71,64 @@ impl ChainCmd {
         Ok(())
     }
 
+    pub async fn print_supply_audit(&self, app: &mut App, assets: &[String]) -> Result<()> {
+        let asset_ids = assets
+            .iter()
+            .map(|asset| {
+                // Try to parse as an asset ID, then if it's not an asset ID, assume it's a unit name
+                asset
+                    .parse::<asset::Id>()
+                    .unwrap_or_else(|_| asset::REGISTRY.parse_unit(asset).id())
+            })
+            .map(Into::into)
+            .collect();
+
+        let mut client = AppQueryServiceClient::new(app.pd_channel().await?);
+        let audits = client
+            .value_balance_audit(ValueBalanceAuditRequest { asset_ids })
+            .await?
+            .into_inner()
+            .try_collect::<Vec<_>>()
+            .await
+            .context("cannot process supply audit data")?;
+
+        let asset_cache = app.view().assets().await?;
+        let mut table = Table::new();
+        table.load_preset(presets::NOTHING);
+        table.set_header(vec![
+            "Issued",
+            "IBC Balance",
+            "Community Pool",
+            "Community Pool Reconciled",
+        ]);
+        for audit in audits {
+            let asset_id: asset::Id = audit
+                .asset_id
+                .context("missing asset ID in supply audit")?
+                .try_into()?;
+            let format = |amount: Option<pb_num::Amount>| -> Result<String> {
+                Ok(match amount {
+                    Some(amount) => Value {
+                        amount: Amount::try_from(amount)?,
+                        asset_id,
+                    }
+                    .format(&asset_cache),
+                    None => "n/a".to_string(),
+                })
+            };
+            table.add_row(vec![
+                format(audit.issued)?,
+                format(audit.ibc_balance)?,
+                format(audit.community_pool_balance)?,
+                audit.community_pool_reconciled.to_string(),
+            ]);
+        }
+
+        println!("{table}");
+
+        Ok(())
+    }
+
     pub async fn get_stats(&self, app: &mut App) -> Result<Stats> {
         let channel = app.pd_channel().await?;
 
@@ -137,6 +205,9 @@ impl ChainCmd {
             ChainCmd::Params => {
                 self.print_app_params(app).await?;
             }
+            ChainCmd::Supply { assets } => {
+                self.print_supply_audit(app, assets).await?;
+            }
             // TODO: we could implement this as an RPC call using the metrics
             // subsystems once #829 is complete
             // OR (hdevalence): fold it into pcli q
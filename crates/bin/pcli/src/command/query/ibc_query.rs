@@ -1,4 +1,4 @@
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 
 use anyhow::Result;
 use colored_json::ToColoredJson;
@@ -59,6 +59,12 @@ struct ChannelInfo {
     consensus_state: TendermintConsensusState,
 }
 
+/// Formats the delay period of a connection, which packets sent over it must
+/// wait out before they can be relayed.
+fn format_delay_period(connection: &ConnectionEnd) -> String {
+    humantime::format_duration(Duration::from_nanos(connection.delay_period)).to_string()
+}
+
 impl IbcCmd {
     pub async fn exec(&self, app: &mut App) -> Result<()> {
         match self {
@@ -178,6 +184,7 @@ impl IbcCmd {
                     "State",
                     "Client ID",
                     "Client Height",
+                    "Connection Delay",
                 ]);
                 let mut state_str = State::from_i32(channel.state)
                     .expect("invalid state value")
@@ -203,6 +210,7 @@ impl IbcCmd {
                     state_str,
                     connection.client_id.to_string(),
                     client_state.latest_height.to_string(),
+                    format_delay_period(&connection),
                 ]);
 
                 println!("{table}")
@@ -269,6 +277,7 @@ impl IbcCmd {
                     "State",
                     "Client ID",
                     "Client Height",
+                    "Connection Delay",
                 ]);
 
                 for info in channel_infos {
@@ -295,6 +304,7 @@ impl IbcCmd {
                         state_str,
                         info.connection.client_id.to_string(),
                         info.client.latest_height.to_string(),
+                        format_delay_period(&info.connection),
                     ]);
                 }
 
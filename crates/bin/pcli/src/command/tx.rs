@@ -36,7 +36,7 @@ use penumbra_proto::{
     core::component::{
         dex::v1::{
             query_service_client::QueryServiceClient as DexQueryServiceClient,
-            LiquidityPositionByIdRequest, PositionId,
+            LiquidityPositionByIdRequest, PositionId, PositionRewardsRequest,
         },
         governance::v1::{
             query_service_client::QueryServiceClient as GovernanceQueryServiceClient,
@@ -65,9 +65,9 @@ use penumbra_proto::{
 use penumbra_shielded_pool::Ics20Withdrawal;
 use penumbra_stake::rate::RateData;
 use penumbra_stake::{DelegationToken, IdentityKey, Penalty, UnbondingToken, UndelegateClaimPlan};
-use penumbra_transaction::{gas::swap_claim_gas_cost, Transaction};
+use penumbra_transaction::{gas::swap_claim_gas_cost, PrivacyLevel, Transaction};
 use penumbra_view::{SpendableNoteRecord, ViewClient};
-use penumbra_wallet::plan::{self, Planner};
+use penumbra_wallet::plan::{self, SweepOptions};
 use proposal::ProposalCmd;
 use tonic::transport::{Channel, ClientTlsConfig};
 use url::Url;
@@ -91,6 +91,11 @@ pub struct TxCmdWithOptions {
     /// If present, a file to save the transaction to instead of broadcasting it
     #[clap(long)]
     pub offline: Option<PathBuf>,
+    /// How much to hide about the shape of the transaction: "standard" orders actions by type,
+    /// "shuffled" also shuffles actions of the same type, and "padded" also pads outputs with
+    /// dummy outputs to a power of two, at the cost of a higher fee.
+    #[clap(long, default_value_t)]
+    pub privacy_level: PrivacyLevel,
     #[clap(subcommand)]
     pub cmd: TxCmd,
 }
@@ -103,10 +108,58 @@ impl TxCmdWithOptions {
 
     pub async fn exec(&self, app: &mut App) -> Result<()> {
         app.save_transaction_here_instead = self.offline.clone();
+        app.privacy_level = self.privacy_level;
+
+        // Sweep a fragmented wallet before using it, if configured to, unless
+        // the transaction is only being saved.
+        if let (Some(threshold), None) = (app.config.auto_sweep_threshold, &self.offline) {
+            if !matches!(self.cmd, TxCmd::Sweep { .. })
+                && plan::needs_sweep(
+                    app.view
+                        .as_mut()
+                        .context("view service must be initialized")?,
+                    plan::SWEEP_COUNT,
+                    threshold,
+                )
+                .await?
+            {
+                println!("sweeping fragmented notes first");
+                sweep(app, SweepOptions::default()).await?;
+            }
+        }
+
         self.cmd.exec(app).await
     }
 }
 
+/// Sweeps the wallet until there's nothing left to sweep, or the fees of the
+/// sweep transactions reach `options.max_fee`.
+async fn sweep(app: &mut App, mut options: SweepOptions) -> Result<()> {
+    loop {
+        let plans = plan::sweep_with(
+            app.view
+                .as_mut()
+                .context("view service must be initialized")?,
+            OsRng,
+            options,
+        )
+        .await?;
+        let num_plans = plans.len();
+
+        for (i, plan) in plans.into_iter().enumerate() {
+            println!("building sweep {i} of {num_plans}");
+            if let Some(max_fee) = options.max_fee.as_mut() {
+                *max_fee = max_fee.saturating_sub(&plan.transaction_parameters.fee.amount());
+            }
+            app.build_and_submit_transaction(plan).await?;
+        }
+        if num_plans == 0 {
+            println!("finished sweeping");
+            return Ok(());
+        }
+    }
+}
+
 #[derive(Debug, clap::Subcommand)]
 pub enum TxCmd {
     /// Auction related commands.
@@ -126,6 +179,11 @@ pub enum TxCmd {
         /// Optional. Set the transaction's memo field to the provided text.
         #[clap(long)]
         memo: Option<String>,
+        /// Prove to the recipient that the memo's return address is yours, so that they can reply to it.
+        ///
+        /// This limits the memo text to 368 bytes.
+        #[clap(long)]
+        authenticate_return_address: bool,
         /// The selected fee tier to multiply the fee amount by.
         #[clap(short, long, default_value_t)]
         fee_tier: FeeTier,
@@ -224,9 +282,21 @@ pub enum TxCmd {
     /// slightly preferable to sweep small notes into larger ones in an isolated
     /// "sweep" transaction, rather than at the point that they should be spent.
     ///
-    /// Currently, only zero-fee sweep transactions are implemented.
+    /// Notes are swept in groups of the same account and asset, skipping
+    /// groups of the fee asset worth less than the fee to consolidate them.
+    ///
+    /// Setting `auto_sweep_threshold` in the config sweeps the wallet before
+    /// any other transaction, once that many notes are fragmented.
     #[clap(display_order = 990)]
-    Sweep,
+    Sweep {
+        /// The number of notes to consolidate in each sweep transaction.
+        #[clap(long, default_value_t = plan::SWEEP_COUNT)]
+        count: usize,
+        /// The most to pay in fees for the sweep, altogether, in base units of
+        /// the fee asset.
+        #[clap(long)]
+        max_fee: Option<u128>,
+    },
 
     /// Perform an ICS-20 withdrawal, moving funds from the Penumbra chain
     /// to a counterparty chain.
@@ -373,6 +443,7 @@ impl TxCmd {
                 to,
                 source: from,
                 memo,
+                authenticate_return_address,
                 fee_tier,
             } => {
                 // Parse all of the values provided.
@@ -384,7 +455,7 @@ impl TxCmd {
                     .parse::<Address>()
                     .map_err(|_| anyhow::anyhow!("address is invalid"))?;
 
-                let mut planner = Planner::new(OsRng);
+                let mut planner = app.planner();
 
                 planner
                     .set_gas_prices(gas_prices)
@@ -392,6 +463,11 @@ impl TxCmd {
                 for value in values.iter().cloned() {
                     planner.output(value, to.clone());
                 }
+                if *authenticate_return_address {
+                    planner.authenticate_memo_return_address(
+                        app.config.full_viewing_key.incoming().clone(),
+                    );
+                }
                 let plan = planner
                     .memo(memo.clone().unwrap_or_default())
                     .plan(
@@ -414,7 +490,7 @@ impl TxCmd {
                     .map(|v| v.parse())
                     .collect::<Result<Vec<Value>, _>>()?;
 
-                let mut planner = Planner::new(OsRng);
+                let mut planner = app.planner();
                 planner
                     .set_gas_prices(gas_prices)
                     .set_fee_tier((*fee_tier).into());
@@ -431,25 +507,16 @@ impl TxCmd {
                     .await?;
                 app.build_and_submit_transaction(plan).await?;
             }
-            TxCmd::Sweep => loop {
-                let plans = plan::sweep(
-                    app.view
-                        .as_mut()
-                        .context("view service must be initialized")?,
-                    OsRng,
+            TxCmd::Sweep { count, max_fee } => {
+                sweep(
+                    app,
+                    SweepOptions {
+                        count: *count,
+                        max_fee: max_fee.map(Amount::from),
+                    },
                 )
                 .await?;
-                let num_plans = plans.len();
-
-                for (i, plan) in plans.into_iter().enumerate() {
-                    println!("building sweep {i} of {num_plans}");
-                    app.build_and_submit_transaction(plan).await?;
-                }
-                if num_plans == 0 {
-                    println!("finished sweeping");
-                    break;
-                }
-            },
+            }
             TxCmd::Swap {
                 input,
                 into,
@@ -467,7 +534,7 @@ impl TxCmd {
                 let (claim_address, _dtk_d) =
                     fvk.incoming().payment_address(AddressIndex::new(*source));
 
-                let mut planner = Planner::new(OsRng);
+                let mut planner = app.planner();
                 planner
                     .set_gas_prices(gas_prices.clone())
                     .set_fee_tier(fee_tier.into());
@@ -530,7 +597,7 @@ impl TxCmd {
                     .app_params()
                     .await?;
 
-                let mut planner = Planner::new(OsRng);
+                let mut planner = app.planner();
                 planner
                     .set_gas_prices(gas_prices)
                     .set_fee_tier(fee_tier.into());
@@ -587,7 +654,7 @@ impl TxCmd {
                     .expect("epoch must be available")
                     .into();
 
-                let mut planner = Planner::new(OsRng);
+                let mut planner = app.planner();
                 planner
                     .set_gas_prices(gas_prices)
                     .set_fee_tier((*fee_tier).into());
@@ -641,7 +708,7 @@ impl TxCmd {
                     .expect("epoch must be available")
                     .into();
 
-                let mut planner = Planner::new(OsRng);
+                let mut planner = app.planner();
                 planner
                     .set_gas_prices(gas_prices)
                     .set_fee_tier((*fee_tier).into());
@@ -747,7 +814,7 @@ impl TxCmd {
                             })?
                             .try_into()?;
 
-                        let mut planner = Planner::new(OsRng);
+                        let mut planner = app.planner();
                         planner
                             .set_gas_prices(gas_prices.clone())
                             .set_fee_tier((*fee_tier).into());
@@ -797,7 +864,7 @@ impl TxCmd {
                     "deposit amount must be in staking token"
                 );
 
-                let mut planner = Planner::new(OsRng);
+                let mut planner = app.planner();
                 planner
                     .set_gas_prices(gas_prices)
                     .set_fee_tier((*fee_tier).into());
@@ -818,7 +885,7 @@ impl TxCmd {
                 source,
                 fee_tier,
             }) => {
-                let mut planner = Planner::new(OsRng);
+                let mut planner = app.planner();
                 planner
                     .set_gas_prices(gas_prices)
                     .set_fee_tier((*fee_tier).into());
@@ -899,7 +966,8 @@ impl TxCmd {
                     }
                 };
 
-                let plan = Planner::new(OsRng)
+                let plan = app
+                    .planner()
                     .set_gas_prices(gas_prices)
                     .set_fee_tier((*fee_tier).into())
                     .proposal_deposit_claim(*proposal_id, deposit_amount, outcome)
@@ -956,7 +1024,8 @@ impl TxCmd {
                     start_rate_data.insert(rate_data.identity_key.clone(), rate_data);
                 }
 
-                let plan = Planner::new(OsRng)
+                let plan = app
+                    .planner()
                     .set_gas_prices(gas_prices)
                     .set_fee_tier((*fee_tier).into())
                     .delegator_vote(
@@ -990,7 +1059,7 @@ impl TxCmd {
                     println!("Position id: {}", position.id());
                 }
 
-                let mut planner = Planner::new(OsRng);
+                let mut planner = app.planner();
                 planner
                     .set_gas_prices(gas_prices)
                     .set_fee_tier(order.fee_tier().into());
@@ -1133,9 +1202,11 @@ impl TxCmd {
                     // TODO: impl From<u64> for ChannelId
                     source_channel: ChannelId::from_str(format!("channel-{}", channel).as_ref())?,
                     use_compat_address: *use_compat_address,
+                    relay_fee: None,
                 };
 
-                let plan = Planner::new(OsRng)
+                let plan = app
+                    .planner()
                     .set_gas_prices(gas_prices)
                     .set_fee_tier((*fee_tier).into())
                     .ics20_withdrawal(withdrawal)
@@ -1153,7 +1224,7 @@ impl TxCmd {
                 source,
                 fee_tier,
             }) => {
-                let mut planner = Planner::new(OsRng);
+                let mut planner = app.planner();
                 planner
                     .set_gas_prices(gas_prices)
                     .set_fee_tier((*fee_tier).into());
@@ -1199,7 +1270,7 @@ impl TxCmd {
                     POSITION_CHUNK_SIZE
                 );
 
-                let mut planner = Planner::new(OsRng);
+                let mut planner = app.planner();
 
                 // Close 5 positions in a single transaction to avoid planner failures.
                 for positions_to_close_now in owned_position_ids.chunks(POSITION_CHUNK_SIZE) {
@@ -1251,7 +1322,7 @@ impl TxCmd {
 
                 let mut client = DexQueryServiceClient::new(app.pd_channel().await?);
 
-                let mut planner = Planner::new(OsRng);
+                let mut planner = app.planner();
 
                 // Withdraw 5 positions in a single transaction to avoid planner failures.
                 for positions_to_withdraw_now in owned_position_ids.chunks(POSITION_CHUNK_SIZE) {
@@ -1283,10 +1354,24 @@ impl TxCmd {
                             .expect("missing position trading function")
                             .pair
                             .expect("missing trading function pair");
+
+                        // Claim the rewards the position accrued along with its reserves.
+                        let rewards = client
+                            .position_rewards(PositionRewardsRequest {
+                                position_id: Some((*position_id).into()),
+                            })
+                            .await?
+                            .into_inner()
+                            .rewards
+                            .into_iter()
+                            .map(Value::try_from)
+                            .collect::<Result<Vec<_>>>()?;
+
                         planner.position_withdraw(
                             *position_id,
                             reserves.try_into().expect("invalid reserves"),
                             pair.try_into().expect("invalid pair"),
+                            rewards,
                         );
                     }
 
@@ -1308,7 +1393,7 @@ impl TxCmd {
             }) => {
                 let mut client = DexQueryServiceClient::new(app.pd_channel().await?);
 
-                let mut planner = Planner::new(OsRng);
+                let mut planner = app.planner();
                 planner
                     .set_gas_prices(gas_prices)
                     .set_fee_tier((*fee_tier).into());
@@ -1336,7 +1421,24 @@ impl TxCmd {
                         .pair
                         .expect("missing trading function pair");
 
-                    planner.position_withdraw(*position_id, reserves.try_into()?, pair.try_into()?);
+                    // Claim the rewards the position accrued along with its reserves.
+                    let rewards = client
+                        .position_rewards(PositionRewardsRequest {
+                            position_id: Some(PositionId::from(*position_id)),
+                        })
+                        .await?
+                        .into_inner()
+                        .rewards
+                        .into_iter()
+                        .map(Value::try_from)
+                        .collect::<Result<Vec<_>>>()?;
+
+                    planner.position_withdraw(
+                        *position_id,
+                        reserves.try_into()?,
+                        pair.try_into()?,
+                        rewards,
+                    );
                 }
 
                 let plan = planner
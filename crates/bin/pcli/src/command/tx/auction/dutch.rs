@@ -13,7 +13,6 @@ use penumbra_keys::keys::AddressIndex;
 use penumbra_num::Amount;
 use penumbra_proto::{view::v1::GasPricesRequest, DomainType};
 use penumbra_view::ViewClient;
-use penumbra_wallet::plan::Planner;
 use rand::RngCore;
 use rand_core::OsRng;
 use serde_json;
@@ -162,7 +161,8 @@ impl DutchCmd {
                 let min_output = min_output.parse::<Value>()?;
                 let output_id = max_output.asset_id;
 
-                let plan = Planner::new(OsRng)
+                let plan = app
+                    .planner()
                     .set_gas_prices(gas_prices)
                     .set_fee_tier((*fee_tier).into())
                     .dutch_auction_schedule(DutchAuctionDescription {
@@ -203,7 +203,7 @@ impl DutchCmd {
                     }
                 };
 
-                let mut planner = Planner::new(OsRng);
+                let mut planner = app.planner();
 
                 planner
                     .set_gas_prices(gas_prices)
@@ -249,7 +249,7 @@ impl DutchCmd {
                     }
                 };
 
-                let mut planner = Planner::new(OsRng);
+                let mut planner = app.planner();
 
                 planner
                     .set_gas_prices(gas_prices)
@@ -309,7 +309,7 @@ impl DutchCmd {
                 println!("end price: {min_output_fmt}");
                 display_auction_description(&asset_cache, auction_descriptions.clone());
 
-                let mut planner = Planner::new(OsRng);
+                let mut planner = app.planner();
                 planner
                     .set_gas_prices(gas_prices)
                     .set_fee_tier((*fee_tier).into());
@@ -101,6 +101,10 @@ pub enum OrderCmd {
         /// When set, tags the position as an auto-closing buy.
         #[clap(long)]
         auto_close: bool,
+        /// When set, the position is closed at the end of the block at this height,
+        /// so that its reserves can be withdrawn.
+        #[clap(long)]
+        expiry_height: Option<u64>,
         /// The selected fee tier to multiply the fee amount by.
         #[clap(short, long, default_value_t)]
         fee_tier: FeeTier,
@@ -121,6 +125,10 @@ pub enum OrderCmd {
         /// When set, tags the position as an auto-closing sell.
         #[clap(long)]
         auto_close: bool,
+        /// When set, the position is closed at the end of the block at this height,
+        /// so that its reserves can be withdrawn.
+        #[clap(long)]
+        expiry_height: Option<u64>,
         /// The selected fee tier to multiply the fee amount by.
         #[clap(short, long, default_value_t)]
         fee_tier: FeeTier,
@@ -152,6 +160,13 @@ impl OrderCmd {
         }
     }
 
+    pub fn expiry_height(&self) -> Option<u64> {
+        match self {
+            OrderCmd::Buy { expiry_height, .. } => *expiry_height,
+            OrderCmd::Sell { expiry_height, .. } => *expiry_height,
+        }
+    }
+
     pub fn num_copies(&self) -> u32 {
         match self {
             OrderCmd::Buy { num_copies, .. } => *num_copies,
@@ -175,6 +190,7 @@ impl OrderCmd {
                     if self.is_auto_closing() {
                         position.close_on_fill = true;
                     }
+                    position.expiry_height = self.expiry_height();
                     positions.push(position);
                 }
                 positions
@@ -189,6 +205,7 @@ impl OrderCmd {
                     if self.is_auto_closing() {
                         position.close_on_fill = true;
                     }
+                    position.expiry_height = self.expiry_height();
                     positions.push(position);
                 }
                 positions
@@ -1,6 +1,7 @@
 use anyhow::{Context, Result};
 
 use penumbra_app::params::AppParameters;
+use penumbra_asset::asset::Metadata;
 use penumbra_governance::{change::ParameterChange, Proposal, ProposalPayload};
 use penumbra_proto::DomainType;
 use penumbra_transaction::TransactionPlan;
@@ -84,6 +85,12 @@ pub enum ProposalKindCmd {
     },
     /// Generate a template for an upgrade proposal,
     UpgradePlan,
+    /// Generate a template for a proposal registering the metadata of an asset.
+    RegisterAssetMetadata {
+        /// The base denom of the asset, e.g. `transfer/channel-0/uatom`.
+        #[clap(long)]
+        denom: String,
+    },
 }
 
 impl ProposalKindCmd {
@@ -118,6 +125,11 @@ impl ProposalKindCmd {
                 }
             }
             ProposalKindCmd::UpgradePlan { .. } => ProposalPayload::UpgradePlan { height: 0 },
+            ProposalKindCmd::RegisterAssetMetadata { denom } => {
+                ProposalPayload::RegisterAssetMetadata {
+                    metadata: vec![Metadata::try_from(denom.as_str())?],
+                }
+            }
         };
 
         Ok(Proposal {
@@ -10,7 +10,7 @@ use penumbra_dex::{
 use penumbra_keys::keys::AddressIndex;
 use penumbra_num::Amount;
 use penumbra_proto::view::v1::GasPricesRequest;
-use penumbra_view::{Planner, ViewClient};
+use penumbra_view::ViewClient;
 
 use crate::App;
 
@@ -151,7 +151,7 @@ impl Linear {
             .expect("gas prices must be available")
             .try_into()?;
 
-        let mut planner = Planner::new(OsRng);
+        let mut planner = app.planner();
         planner.set_gas_prices(gas_prices);
         positions.iter().for_each(|position| {
             planner.position_open(position.clone());
@@ -3,14 +3,13 @@ use std::path::PathBuf;
 
 use anyhow::{anyhow, Context};
 use dialoguer::Confirm;
-use rand_core::OsRng;
 
 use penumbra_asset::Value;
 use penumbra_dex::{lp::position::Position, DirectedUnitPair};
 use penumbra_keys::keys::AddressIndex;
 use penumbra_num::{fixpoint::U128x128, Amount};
 use penumbra_proto::view::v1::GasPricesRequest;
-use penumbra_view::{Planner, ViewClient};
+use penumbra_view::ViewClient;
 
 use crate::dex_utils;
 use crate::dex_utils::replicate::debug;
@@ -131,7 +130,7 @@ impl ConstantProduct {
             .expect("gas prices must be available")
             .try_into()?;
 
-        let mut planner = Planner::new(OsRng);
+        let mut planner = app.planner();
         planner.set_gas_prices(gas_prices);
         positions.iter().for_each(|position| {
             planner.position_open(position.clone());
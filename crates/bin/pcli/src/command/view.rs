@@ -2,6 +2,8 @@ use anyhow::Result;
 
 use address::AddressCmd;
 use balance::BalanceCmd;
+use checkpoint::CheckpointCmd;
+use export_fvk::ExportFvkCmd;
 use lps::LiquidityPositionsCmd;
 use noble_address::NobleAddressCmd;
 use staked::StakedCmd;
@@ -16,6 +18,8 @@ use self::auction::AuctionCmd;
 mod address;
 mod auction;
 mod balance;
+mod checkpoint;
+mod export_fvk;
 mod lps;
 mod noble_address;
 mod staked;
@@ -30,6 +34,9 @@ pub enum ViewCmd {
     Auction(AuctionCmd),
     /// View your wallet id
     WalletId(WalletIdCmd),
+    /// Export your full viewing key, with your wallet's birthday, to import
+    /// into a watch-only wallet.
+    ExportFvk(ExportFvkCmd),
     /// View one of your addresses, either by numerical index, or a random ephemeral one.
     Address(AddressCmd),
     /// View the Noble forwarding address associated with one of your addresses, either by numerical index, or a random ephemeral one.
@@ -40,6 +47,10 @@ pub enum ViewCmd {
     Staked(StakedCmd),
     /// Deletes all scanned data and local state, while leaving keys untouched.
     Reset(Reset),
+    /// Exports or imports a checkpoint of the scanned data, to move a wallet
+    /// to another machine without resyncing it.
+    #[clap(subcommand)]
+    Checkpoint(CheckpointCmd),
     /// Synchronizes the client, privately scanning the chain state.
     ///
     /// `pcli` syncs automatically prior to any action requiring chain state,
@@ -60,11 +71,13 @@ impl ViewCmd {
         match self {
             ViewCmd::Auction(auction_cmd) => auction_cmd.offline(),
             ViewCmd::WalletId(wallet_id_cmd) => wallet_id_cmd.offline(),
+            ViewCmd::ExportFvk(export_fvk_cmd) => export_fvk_cmd.offline(),
             ViewCmd::Address(address_cmd) => address_cmd.offline(),
             ViewCmd::NobleAddress(address_cmd) => address_cmd.offline(),
             ViewCmd::Balance(balance_cmd) => balance_cmd.offline(),
             ViewCmd::Staked(staked_cmd) => staked_cmd.offline(),
             ViewCmd::Reset(_) => true,
+            ViewCmd::Checkpoint(checkpoint_cmd) => checkpoint_cmd.offline(),
             ViewCmd::Sync => false,
             ViewCmd::ListTransactionHashes(transactions_cmd) => transactions_cmd.offline(),
             ViewCmd::Tx(tx_cmd) => tx_cmd.offline(),
@@ -83,6 +96,9 @@ impl ViewCmd {
             ViewCmd::WalletId(wallet_id_cmd) => {
                 wallet_id_cmd.exec(&full_viewing_key)?;
             }
+            ViewCmd::ExportFvk(export_fvk_cmd) => {
+                export_fvk_cmd.exec(&full_viewing_key, app.config.birthday)?;
+            }
             ViewCmd::Tx(tx_cmd) => {
                 tx_cmd.exec(app).await?;
             }
@@ -99,6 +115,9 @@ impl ViewCmd {
             ViewCmd::Reset(_reset) => {
                 // The wallet has already been reset by a short-circuiting path.
             }
+            ViewCmd::Checkpoint(_checkpoint_cmd) => {
+                // The checkpoint has already been handled by a short-circuiting path.
+            }
             ViewCmd::Address(address_cmd) => {
                 address_cmd.exec(&full_viewing_key)?;
             }
@@ -40,25 +40,26 @@ impl BalanceCmd {
                                 asset.value(record.note.amount()),
                                 record.source.clone(),
                                 record.return_address.clone(),
+                                record.return_address_authenticated,
                             )
                         })
                     })
                 })
                 /* Don't exclude withdrawn LPNFTs in by_note, which is a more precise view.
                 // Exclude withdrawn LPNFTs.
-                .filter(|(_, value, _, _)| match asset_cache.get(&value.asset_id) {
+                .filter(|(_, value, _, _, _)| match asset_cache.get(&value.asset_id) {
                     None => true,
                     Some(denom) => !denom.is_withdrawn_position_nft(),
                 });
                  */
                 ;
 
-            for (index, value, source, return_address) in rows {
+            for (index, value, source, return_address, authenticated) in rows {
                 table.add_row(vec![
                     format!("# {}", index),
                     value.format(&asset_cache),
                     format_source(&source),
-                    format_return_address(&return_address),
+                    format_return_address(&return_address, authenticated),
                 ]);
             }
 
@@ -112,6 +113,7 @@ fn format_source(source: &CommitmentSource) -> String {
             packet_seq,
             channel_id,
             sender,
+            ..
         } => format!(
             "ICS20 packet {} via {} from {}",
             packet_seq, channel_id, sender
@@ -119,8 +121,11 @@ fn format_source(source: &CommitmentSource) -> String {
     }
 }
 
-fn format_return_address(return_address: &Option<penumbra_keys::AddressView>) -> String {
-    match return_address {
+fn format_return_address(
+    return_address: &Option<penumbra_keys::AddressView>,
+    authenticated: bool,
+) -> String {
+    let sender = match return_address {
         None => "Unknown".to_owned(),
         Some(AddressView::Opaque { address }) => address.display_short_form(),
         Some(AddressView::Decoded { index, .. }) => {
@@ -130,5 +135,10 @@ fn format_return_address(return_address: &Option<penumbra_keys::AddressView>) ->
                 format!("[account {}]", index.account)
             }
         }
+    };
+    if authenticated {
+        format!("{sender} (authenticated)")
+    } else {
+        sender
     }
 }
@@ -0,0 +1,51 @@
+use anyhow::Result;
+use camino::{Utf8Path, Utf8PathBuf};
+
+use penumbra_view::Storage;
+
+use crate::config::PcliConfig;
+
+/// Export or import a checkpoint of the wallet's sync state.
+#[derive(Debug, clap::Subcommand)]
+pub enum CheckpointCmd {
+    /// Write a checkpoint of the local view data to a file.
+    Export {
+        /// The file to write the checkpoint to.
+        path: Utf8PathBuf,
+    },
+    /// Import a checkpoint of the same wallet as the local view data,
+    /// e.g. one exported on another machine, instead of syncing from scratch.
+    Import {
+        /// The checkpoint file to import.
+        path: Utf8PathBuf,
+    },
+}
+
+impl CheckpointCmd {
+    /// Determine if this command requires a network sync before it executes.
+    pub fn offline(&self) -> bool {
+        true
+    }
+
+    pub async fn exec(&self, data_path: impl AsRef<Utf8Path>) -> Result<()> {
+        let view_path = data_path.as_ref().join(crate::VIEW_FILE_NAME);
+
+        match self {
+            CheckpointCmd::Export { path } => {
+                if !view_path.is_file() {
+                    anyhow::bail!("No view data exists at {view_path}, so it cannot be exported");
+                }
+                let storage = Storage::load(&view_path).await?;
+                storage.export_checkpoint(path).await?;
+                println!("Wrote checkpoint of {view_path} to {path}");
+            }
+            CheckpointCmd::Import { path } => {
+                let config = PcliConfig::load(data_path.as_ref().join(crate::CONFIG_FILE_NAME))?;
+                Storage::import_checkpoint(path, &view_path, &config.full_viewing_key).await?;
+                println!("Imported checkpoint {path} to {view_path}");
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+use anyhow::Result;
+
+use penumbra_keys::{keys::FullViewingKeyExport, FullViewingKey};
+
+#[derive(Debug, clap::Parser)]
+pub struct ExportFvkCmd {
+    /// The birthday of the wallet, i.e. the height of the first block that
+    /// may contain its notes.
+    ///
+    /// Defaults to the birthday the wallet was imported with, if any, or else
+    /// to genesis.
+    #[clap(long)]
+    birthday: Option<u64>,
+}
+
+impl ExportFvkCmd {
+    /// Determine if this command requires a network sync before it executes.
+    pub fn offline(&self) -> bool {
+        true
+    }
+
+    pub fn exec(&self, fvk: &FullViewingKey, birthday: Option<u64>) -> Result<()> {
+        let birthday = self.birthday.or(birthday).unwrap_or_default();
+        let export = FullViewingKeyExport::new(fvk.clone(), birthday);
+        println!("{export}");
+
+        Ok(())
+    }
+}
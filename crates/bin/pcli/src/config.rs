@@ -30,6 +30,14 @@ pub struct PcliConfig {
     pub custody: CustodyConfig,
     /// The governance custody backend to use.
     pub governance_custody: Option<GovernanceCustodyConfig>,
+    /// If set, sweep the wallet before any transaction once this many of its
+    /// notes are fragmented.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_sweep_threshold: Option<usize>,
+    /// The height of the first block that may contain notes of the wallet, if
+    /// known, e.g. from an imported full viewing key export.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub birthday: Option<u64>,
 }
 
 impl PcliConfig {
@@ -123,6 +131,8 @@ mod tests {
                 penumbra_keys::test_keys::SPEND_KEY.clone(),
             )),
             governance_custody: None,
+            auto_sweep_threshold: None,
+            birthday: None,
         };
 
         let mut config2 = config.clone();
@@ -11,7 +11,9 @@ use {
         box_grpc_svc::BoxGrpcService, custody::v1::custody_service_client::CustodyServiceClient,
         view::v1::view_service_client::ViewServiceClient,
     },
-    penumbra_view::ViewClient,
+    penumbra_transaction::PrivacyLevel,
+    penumbra_view::{Planner, ViewClient},
+    rand_core::OsRng,
     std::path::PathBuf,
 };
 
@@ -39,9 +41,18 @@ pub struct App {
     pub config: PcliConfig,
     /// If present, save the transaction here instead of broadcasting it.
     pub save_transaction_here_instead: Option<PathBuf>,
+    /// The privacy level to plan transactions with.
+    pub privacy_level: PrivacyLevel,
 }
 
 impl App {
+    /// Creates a planner for a transaction, with the configured privacy level.
+    pub fn planner(&self) -> Planner<OsRng> {
+        let mut planner = Planner::new(OsRng);
+        planner.set_privacy_level(self.privacy_level);
+        planner
+    }
+
     pub fn view(&mut self) -> &mut impl ViewClient {
         self.view.as_mut().expect("view service initialized")
     }
@@ -39,6 +39,11 @@ async fn main() -> Result<()> {
         reset.exec(opt.home.as_path())?;
         return Ok(());
     }
+    // Likewise for checkpoints, which read or replace the view data directly.
+    if let Command::View(ViewCmd::Checkpoint(checkpoint_cmd)) = &opt.cmd {
+        checkpoint_cmd.exec(opt.home.as_path()).await?;
+        return Ok(());
+    }
     // The debug command takes the home dir directly
     if let Command::Debug(debug_cmd) = &opt.cmd {
         let dd = opt.home.into_std_path_buf();
@@ -183,6 +183,7 @@ impl Opt {
             governance_custody,
             config,
             save_transaction_here_instead: None,
+            privacy_level: Default::default(),
         };
         Ok((app, self.cmd))
     }
@@ -145,10 +145,12 @@ fn pretty_print_transaction_plan(
             ActionPlan::PositionOpen(_) => None,
             ActionPlan::PositionClose(_) => None,
             ActionPlan::PositionWithdraw(_) => None,
+            ActionPlan::GaugeFund(_) => None,
             ActionPlan::Delegate(_) => None,
             ActionPlan::Undelegate(_) => None,
             ActionPlan::UndelegateClaim(_) => None,
             ActionPlan::Ics20Withdrawal(_) => None,
+            ActionPlan::NftWithdrawal(_) => None,
             ActionPlan::CommunityPoolSpend(_) => None,
             ActionPlan::CommunityPoolOutput(_) => None,
             ActionPlan::CommunityPoolDeposit(_) => None,
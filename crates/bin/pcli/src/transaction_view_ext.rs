@@ -351,6 +351,16 @@ impl TransactionViewExt for TransactionView {
                     );
                     ["Ics20 Withdrawal", &action]
                 }
+                penumbra_transaction::ActionView::NftWithdrawal(withdrawal) => {
+                    action = format!(
+                        "{} tokens of {} via {} to {}",
+                        withdrawal.token_ids.len(),
+                        withdrawal.class_id,
+                        withdrawal.source_channel,
+                        withdrawal.destination_chain_address,
+                    );
+                    ["NFT Withdrawal", &action]
+                }
                 penumbra_transaction::ActionView::PositionOpen(position_open) => {
                     let position = &position_open.position;
                     /* TODO: leaving this around since we may want it to render prices
@@ -377,6 +387,17 @@ impl TransactionViewExt for TransactionView {
                 penumbra_transaction::ActionView::PositionWithdraw(_) => {
                     ["Withdraw Liquitity Position", ""]
                 }
+                penumbra_transaction::ActionView::GaugeFund(x) => {
+                    action = format!(
+                        "{} {} to {}/{} over {} blocks",
+                        x.rewards.amount,
+                        format_asset_id(&x.rewards.asset_id),
+                        format_asset_id(&x.trading_pair.asset_1()),
+                        format_asset_id(&x.trading_pair.asset_2()),
+                        x.duration,
+                    );
+                    ["Fund Reward Gauge", &action]
+                }
                 penumbra_transaction::ActionView::ProposalDepositClaim(proposal_deposit_claim) => {
                     action = format!(
                         "Claim Deposit for Governance Proposal #{}",
@@ -10,7 +10,7 @@ use clap::Parser;
 use directories::ProjectDirs;
 use penumbra_custody::policy::{AuthPolicy, PreAuthorizationPolicy};
 use penumbra_custody::soft_kms::{self, SoftKms};
-use penumbra_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
+use penumbra_keys::keys::{Bip44Path, FullViewingKeyExport, SeedPhrase, SpendKey};
 use penumbra_keys::FullViewingKey;
 use penumbra_proto::{
     core::app::v1::{
@@ -51,6 +51,10 @@ pub struct PclientdConfig {
     pub bind_addr: SocketAddr,
     /// Optional KMS config for custody mode
     pub kms_config: Option<soft_kms::Config>,
+    /// The height of the first block that may contain notes of the wallet, if
+    /// known, e.g. from an imported full viewing key export.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub birthday: Option<u64>,
 }
 
 impl PclientdConfig {
@@ -89,7 +93,8 @@ pub struct Opt {
 pub enum Command {
     /// Generate configs for `pclientd` in view or custody mode.
     Init {
-        /// If provided, initialize in view mode with the given full viewing key.
+        /// If provided, initialize in view mode with the given full viewing key,
+        /// either bare or as exported with the wallet's birthday.
         #[clap(long, display_order = 100, value_name = "FULL_VIEWING_KEY")]
         view: Option<String>,
         /// If provided, initialize in custody mode with the given seed phrase.
@@ -108,6 +113,28 @@ pub enum Command {
         #[clap(long, display_order = 900, default_value = "127.0.0.1:8081")]
         bind_addr: SocketAddr,
     },
+    /// Export the configured full viewing key, with the wallet's birthday.
+    ExportFvk {
+        /// The birthday of the wallet, overriding the configured one.
+        #[clap(long)]
+        birthday: Option<u64>,
+    },
+    /// Split the detection key of one of the wallet's addresses into shares
+    /// for a set of threshold detection servers.
+    ///
+    /// Any `threshold` of the servers can jointly flag the clues sent to the
+    /// address, without any of them holding its detection key.
+    SplitDetectionKey {
+        /// The index of the address whose detection key is split.
+        #[clap(long, default_value = "0")]
+        address_index: u32,
+        /// The number of servers needed to examine a clue.
+        #[clap(long)]
+        threshold: u16,
+        /// The number of shares, one for each server.
+        #[clap(long)]
+        shares: u16,
+    },
     /// Start running `pclientd`.
     Start {},
     /// Delete `pclientd` storage to reset local state.
@@ -213,16 +240,20 @@ impl Opt {
                     }
                 };
 
-                let (spend_key, full_viewing_key) = match (seed_phrase, view) {
+                let (spend_key, full_viewing_key, birthday) = match (seed_phrase, view) {
                     (Some(seed_phrase), None) => {
                         let spend_key = SpendKey::from_seed_phrase_bip44(
                             SeedPhrase::from_str(seed_phrase.as_str())?,
                             &Bip44Path::new(0),
                         );
                         let full_viewing_key = spend_key.full_viewing_key().clone();
-                        (Some(spend_key), full_viewing_key)
+                        (Some(spend_key), full_viewing_key, None)
+                    }
+                    (None, Some(view)) => {
+                        let export = view.parse::<FullViewingKeyExport>()?;
+                        let birthday = Some(export.birthday).filter(|&height| height > 0);
+                        (None, export.fvk, birthday)
                     }
-                    (None, Some(view)) => (None, view.parse()?),
                     (None, None) => {
                         return Err(anyhow::anyhow!(
                             "Must provide either a seed phrase or a full viewing key."
@@ -273,6 +304,7 @@ impl Opt {
                     full_viewing_key,
                     grpc_url: grpc_url.clone(),
                     bind_addr: *bind_addr,
+                    birthday,
                 };
 
                 let encoded = toml::to_string_pretty(&client_config)
@@ -288,6 +320,37 @@ impl Opt {
 
                 Ok(())
             }
+            Command::ExportFvk { birthday } => {
+                let config = PclientdConfig::load(opt.config_path()).context(
+                    "Failed to load pclientd config file. Have you run `pclientd init` with a FVK?",
+                )?;
+                let birthday = birthday.or(config.birthday).unwrap_or_default();
+                let export = FullViewingKeyExport::new(config.full_viewing_key, birthday);
+                println!("{export}");
+
+                Ok(())
+            }
+            Command::SplitDetectionKey {
+                address_index,
+                threshold,
+                shares,
+            } => {
+                let config = PclientdConfig::load(opt.config_path()).context(
+                    "Failed to load pclientd config file. Have you run `pclientd init` with a FVK?",
+                )?;
+                let (_address, dtk) = config
+                    .full_viewing_key
+                    .incoming()
+                    .payment_address(address_index.into());
+                let shares = dtk.split(threshold, shares, rand_core::OsRng)?;
+
+                println!("clue key: {}", hex::encode(dtk.clue_key().0));
+                for share in shares {
+                    println!("share {}: {}", share.index(), hex::encode(share.to_bytes()));
+                }
+
+                Ok(())
+            }
             Command::Start {} => {
                 let config = PclientdConfig::load(opt.config_path()).context(
                     "Failed to load pclientd config file. Have you run `pclientd init` with a FVK?",
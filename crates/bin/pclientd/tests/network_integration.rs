@@ -42,6 +42,7 @@ fn generate_config() -> anyhow::Result<PclientdConfig> {
             spend_key: test_keys::SPEND_KEY.clone(),
             auth_policy: Vec::new(),
         }),
+        birthday: None,
     })
 }
 
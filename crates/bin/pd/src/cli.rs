@@ -89,6 +89,76 @@ pub enum RootCommand {
         /// Enable expensive RPCs, currently a no-op.
         #[clap(short, long, display_order = 500)]
         enable_expensive_rpc: bool,
+        /// Prune the state that is only needed to read versions older than
+        /// this many blocks, to save disk space.
+        ///
+        /// If unset, every version of the state is kept.
+        #[clap(long, env = "PENUMBRA_PD_RETAIN_BLOCKS", display_order = 600)]
+        retain_blocks: Option<u64>,
+        /// Take a snapshot of the state every this many blocks, and serve the
+        /// latest snapshots to nodes joining the network with state sync.
+        ///
+        /// If unset, no snapshots are taken or served.
+        #[clap(long, env = "PENUMBRA_PD_SNAPSHOT_INTERVAL", display_order = 700)]
+        snapshot_interval: Option<u64>,
+        /// Only write the state to disk every this many blocks, rather than as
+        /// each block is committed, to commit blocks faster.
+        ///
+        /// After a crash, CometBFT replays the blocks committed since the last
+        /// write. If unset, each block is written to disk as it's committed.
+        #[clap(long, env = "PENUMBRA_PD_STORAGE_FLUSH_INTERVAL", display_order = 800)]
+        storage_flush_interval: Option<u64>,
+        /// The size of RocksDB's block cache, in megabytes.
+        ///
+        /// If unset, RocksDB's default is used.
+        #[clap(long, env = "PENUMBRA_PD_ROCKSDB_BLOCK_CACHE_MB", display_order = 900)]
+        rocksdb_block_cache_mb: Option<usize>,
+        /// Compact RocksDB's column families with universal compaction, which
+        /// favors writes, rather than leveled compaction, which favors reads and
+        /// disk space.
+        #[clap(
+            long,
+            env = "PENUMBRA_PD_ROCKSDB_UNIVERSAL_COMPACTION",
+            display_order = 1000
+        )]
+        rocksdb_universal_compaction: bool,
+        /// Keep a bloom filter with this many bits per key for each RocksDB
+        /// table, to speed up reads of missing keys. 10 bits per key gives a
+        /// false positive rate of about 1%.
+        ///
+        /// If unset, no bloom filters are kept.
+        #[clap(
+            long,
+            env = "PENUMBRA_PD_ROCKSDB_BLOOM_FILTER_BITS",
+            display_order = 1100
+        )]
+        rocksdb_bloom_filter_bits: Option<f64>,
+        /// Cache up to this many reads of the state, to serve keys read in
+        /// every block, like parameters, without reading RocksDB.
+        ///
+        /// If unset, reads are not cached.
+        #[clap(
+            long,
+            env = "PENUMBRA_PD_STORAGE_READ_CACHE_SIZE",
+            display_order = 1200
+        )]
+        storage_read_cache_size: Option<usize>,
+        /// Log reads from storage taking longer than this many milliseconds,
+        /// along with the prefix of the key read, to find slow components.
+        ///
+        /// If unset, slow reads are not logged.
+        #[clap(
+            long,
+            env = "PENUMBRA_PD_STORAGE_SLOW_READ_THRESHOLD_MS",
+            display_order = 1300
+        )]
+        storage_slow_read_threshold_ms: Option<u64>,
+        /// Update the trees of the state, and hash the keys written, on this
+        /// many threads when committing a block.
+        ///
+        /// If unset, commits are done on a single thread.
+        #[clap(long, env = "PENUMBRA_PD_STORAGE_COMMIT_THREADS", display_order = 1400)]
+        storage_commit_threads: Option<usize>,
     },
 
     /// Generate, join, or reset a network.
@@ -116,6 +186,15 @@ pub enum RootCommand {
         /// Whether to prune the JMT tree.
         #[clap(long, display_order = 300)]
         prune: bool,
+        /// If set, export the state at this height as the `genesis.json` of a new
+        /// chain, instead of copying the node state.
+        #[clap(long, display_order = 400)]
+        height: Option<u64>,
+        /// Path to CSV file containing the allocations of the new chain exported
+        /// with `--height`, in the format of `pd network generate`. Notes in the
+        /// shielded pool can't be exported, so a new chain has no other funds.
+        #[clap(long, display_order = 500, requires = "height")]
+        allocations_input_file: Option<PathBuf>,
     },
 
     /// Run a migration before resuming post-upgrade.
@@ -143,6 +222,43 @@ pub enum RootCommand {
         #[clap(long, display_order = 1000)]
         ready_to_start: bool,
     },
+
+    /// Roll the state back by a number of blocks, e.g. to recover from an app
+    /// hash mismatch without syncing from genesis again.
+    ///
+    /// The rolled back blocks are executed again once the node restarts.
+    Rollback {
+        /// The home directory of the full node.
+        ///
+        /// The rollback is performed in-place on the home directory.
+        #[clap(long, env = "PENUMBRA_PD_HOME", display_order = 100)]
+        home: Option<PathBuf>,
+        /// If set, also roll back the CometBFT state located in this home directory,
+        /// using the `cometbft` binary. If both `--home` and `--comet-home` are unset,
+        /// will attempt to roll back CometBFT state alongside the auto-located `pd` state.
+        // Note: this does _NOT_ use an env var because we are trying to
+        // get explicit consent to muck around in another daemon's state.
+        #[clap(long, display_order = 200)]
+        comet_home: Option<PathBuf>,
+        /// The number of blocks to roll back.
+        #[clap(long, display_order = 300)]
+        blocks: u64,
+    },
+
+    /// Verify the integrity of the state, e.g. to validate a backup.
+    ///
+    /// The merkle tree of the state is walked again at the latest height, and
+    /// its root hash recomputed, reporting the corrupt or missing nodes found.
+    VerifyStorage {
+        /// The home directory of the full node.
+        #[clap(long, env = "PENUMBRA_PD_HOME", display_order = 100)]
+        home: Option<PathBuf>,
+        /// The app hash CometBFT committed for the latest height, as hex, e.g.
+        /// the `last_block_app_hash` reported by its `/abci_info` endpoint. If
+        /// set, the root hash of the state is checked against it.
+        #[clap(long, display_order = 200)]
+        app_hash: Option<String>,
+    },
 }
 
 #[derive(Debug, Subcommand)]
@@ -0,0 +1,44 @@
+//! Exporting the state of a full node as the genesis of a new chain.
+//!
+//! Use [`export_genesis()`] to reset a testnet, or to fork the chain, from the
+//! state at a given height. Each component exports the state its genesis sets
+//! up, like its parameters, so the new chain starts out configured like the
+//! old one. Notes in the shielded pool are private to their holders, so they
+//! can't be carried over; allocations to fund the new chain, including the
+//! delegations that give its validators voting power, are supplied separately.
+
+use std::path::PathBuf;
+
+use anyhow::Context;
+use cnidarium::Storage;
+use penumbra_app::{app::App, genesis::AppState, SUBSTORE_PREFIXES};
+use penumbra_shielded_pool::genesis::Allocation;
+use tendermint::Genesis;
+use tracing::instrument;
+
+use crate::network::generate::NetworkConfig;
+
+/// Exports the `pd` state in `pd_home` at `height` as the genesis of a new
+/// chain, funded with `allocations`.
+#[instrument(skip_all)]
+pub async fn export_genesis(
+    pd_home: PathBuf,
+    height: u64,
+    allocations: Vec<Allocation>,
+) -> anyhow::Result<Genesis<AppState>> {
+    let rocksdb_dir = pd_home.join("rocksdb");
+    let storage = Storage::load(rocksdb_dir, SUBSTORE_PREFIXES.to_vec()).await?;
+    let snapshot = storage
+        .snapshot(height)
+        .with_context(|| format!("the state at height {height} is not available"))?;
+
+    tracing::info!(?height, "exporting pd state as genesis");
+    let mut content = App::export_genesis(&snapshot)
+        .await
+        .context("failed to export pd state")?;
+    content.shielded_pool_content.allocations = allocations;
+    std::mem::drop(snapshot);
+    storage.release().await;
+
+    NetworkConfig::make_genesis(content)
+}
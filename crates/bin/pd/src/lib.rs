@@ -8,8 +8,12 @@
 pub mod metrics;
 
 pub mod cli;
+pub mod genesis_export;
 pub mod migrate;
 pub mod network;
+pub mod pruning;
+pub mod rollback;
+pub mod verify_storage;
 pub mod zipserve;
 
 pub use crate::metrics::register_metrics;
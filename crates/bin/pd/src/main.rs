@@ -2,24 +2,25 @@
 #![deny(clippy::unwrap_used)]
 #![recursion_limit = "512"]
 use std::io::IsTerminal as _;
-use std::{error::Error, process::exit};
+use std::{error::Error, process::exit, time::Duration};
 
 use metrics_tracing_context::{MetricsLayer, TracingContextLayer};
 use metrics_util::layers::Stack;
 
 use anyhow::{anyhow, Context};
-use cnidarium::Storage;
+use cnidarium::{CompactionStyle, Durability, Storage, StorageOptions};
 use metrics_exporter_prometheus::PrometheusBuilder;
 use pd::{
     cli::{NetworkCommand, Opt, RootCommand},
     migrate::Migration::{Mainnet1, ReadyToStart},
     network::{
         config::{get_network_dir, parse_tm_address, url_has_necessary_parts},
-        generate::NetworkConfig,
+        generate::{NetworkAllocation, NetworkConfig},
         join::network_join,
     },
 };
 use penumbra_app::app_version::check_and_update_app_version;
+use penumbra_app::server::snapshot::SnapshotStore;
 use penumbra_app::{APP_VERSION, SUBSTORE_PREFIXES};
 use rand::Rng;
 use rand_core::OsRng;
@@ -62,6 +63,15 @@ async fn main() -> anyhow::Result<()> {
             metrics_bind,
             cometbft_addr,
             enable_expensive_rpc,
+            retain_blocks,
+            snapshot_interval,
+            storage_flush_interval,
+            rocksdb_block_cache_mb,
+            rocksdb_universal_compaction,
+            rocksdb_bloom_filter_bits,
+            storage_read_cache_size,
+            storage_slow_read_threshold_ms,
+            storage_commit_threads,
         } => {
             // Use the given `grpc_bind` address if one was specified. If not, we will choose a
             // default depending on whether or not `grpc_auto_https` was set. See the
@@ -98,11 +108,31 @@ async fn main() -> anyhow::Result<()> {
             };
             let rocksdb_home = pd_home.join("rocksdb");
 
-            let storage = Storage::load(rocksdb_home, SUBSTORE_PREFIXES.to_vec())
-                .await
-                .context(
-                    "Unable to initialize RocksDB storage - is there another `pd` process running?",
-                )?;
+            let storage_options = StorageOptions {
+                durability: match storage_flush_interval {
+                    Some(interval) => Durability::WriteBehind { interval },
+                    None => Durability::WriteAheadLog,
+                },
+                block_cache_size: rocksdb_block_cache_mb.map(|mb| mb * 1024 * 1024),
+                compaction_style: if rocksdb_universal_compaction {
+                    CompactionStyle::Universal
+                } else {
+                    CompactionStyle::Level
+                },
+                bloom_filter_bits_per_key: rocksdb_bloom_filter_bits,
+                read_cache_size: storage_read_cache_size,
+                slow_read_threshold: storage_slow_read_threshold_ms.map(Duration::from_millis),
+                commit_threads: storage_commit_threads,
+            };
+            let storage = Storage::load_with_options(
+                rocksdb_home,
+                SUBSTORE_PREFIXES.to_vec(),
+                storage_options.clone(),
+            )
+            .await
+            .context(
+                "Unable to initialize RocksDB storage - is there another `pd` process running?",
+            )?;
             check_and_update_app_version(storage.clone()).await?;
 
             tracing::info!(
@@ -114,6 +144,9 @@ async fn main() -> anyhow::Result<()> {
                 ?metrics_bind,
                 %cometbft_addr,
                 ?enable_expensive_rpc,
+                ?retain_blocks,
+                ?snapshot_interval,
+                ?storage_options,
                 "starting pd"
             );
 
@@ -124,8 +157,22 @@ async fn main() -> anyhow::Result<()> {
                 exit(0)
             }
 
+            if let Some(retain_blocks) = retain_blocks {
+                tokio::spawn(pd::pruning::run(storage.clone(), retain_blocks));
+            }
+
+            let snapshot_store = snapshot_interval.map(|interval| {
+                let store = SnapshotStore::new(pd_home.join("snapshots"));
+                tokio::spawn(penumbra_app::server::snapshot::run(
+                    storage.clone(),
+                    store.clone(),
+                    interval,
+                ));
+                store
+            });
+
             let abci_server = tokio::task::spawn(
-                penumbra_app::server::new(storage.clone()).listen_tcp(abci_bind),
+                penumbra_app::server::new(storage.clone(), snapshot_store).listen_tcp(abci_bind),
             );
 
             let tm_proxy = penumbra_tendermint_proxy::TendermintProxy::new(cometbft_addr);
@@ -168,12 +215,14 @@ async fn main() -> anyhow::Result<()> {
             };
 
             // Configure a Prometheus recorder and exporter.
-            use penumbra_dex::component::metrics::PrometheusBuilderExt;
+            use penumbra_dex::component::metrics::PrometheusBuilderExt as _;
+            use penumbra_ibc::component::metrics::PrometheusBuilderExt as _;
             let (recorder, exporter) = PrometheusBuilder::new()
                 .with_http_listener(metrics_bind)
                 // Set explicit buckets so that Prometheus endpoint emits true histograms, rather
                 // than the default distribution type summaries, for time-series data.
                 .set_buckets_for_dex_metrics()?
+                .set_buckets_for_ibc_metrics()?
                 .build()
                 .map_err(|e| {
                     let msg = format!(
@@ -402,9 +451,25 @@ async fn main() -> anyhow::Result<()> {
             export_directory,
             export_archive,
             prune,
+            height,
+            allocations_input_file,
         } => {
             use fs_extra;
 
+            // Export state as the genesis of a new chain, if requested.
+            if let Some(height) = height {
+                let allocations = match allocations_input_file {
+                    Some(file) => NetworkAllocation::from_csv(file)?,
+                    None => Vec::new(),
+                };
+                let genesis = pd::genesis_export::export_genesis(home, height, allocations).await?;
+                std::fs::create_dir_all(&export_directory)?;
+                let genesis_path = export_directory.join("genesis.json");
+                std::fs::write(&genesis_path, serde_json::to_string_pretty(&genesis)?)?;
+                tracing::info!("export complete: {}", genesis_path.display());
+                return Ok(());
+            }
+
             // Export state as directory.
             let src_rocksdb_dir = home.join("rocksdb");
             tracing::info!(
@@ -479,6 +544,38 @@ async fn main() -> anyhow::Result<()> {
                 .await
                 .context("failed to upgrade state")?;
         }
+
+        RootCommand::Rollback {
+            home,
+            comet_home,
+            blocks,
+        } => {
+            let (pd_home, comet_home) = match home {
+                Some(h) => (h, comet_home),
+                None => {
+                    let base = get_network_dir(None).join("node0");
+                    (base.join("pd"), Some(base.join("cometbft")))
+                }
+            };
+            pd::rollback::rollback(pd_home, comet_home, blocks)
+                .await
+                .context("failed to roll back state")?;
+        }
+        RootCommand::VerifyStorage { home, app_hash } => {
+            let pd_home = home.unwrap_or_else(|| get_network_dir(None).join("node0").join("pd"));
+            let app_hash = app_hash
+                .map(|app_hash| -> anyhow::Result<_> {
+                    let bytes = hex::decode(app_hash).context("app hash must be hex")?;
+                    let bytes = bytes
+                        .try_into()
+                        .map_err(|_| anyhow!("app hash must be 32 bytes"))?;
+                    Ok(cnidarium::RootHash(bytes))
+                })
+                .transpose()?;
+            pd::verify_storage::verify_storage(pd_home, app_hash)
+                .await
+                .context("failed to verify state")?;
+        }
     }
     Ok(())
 }
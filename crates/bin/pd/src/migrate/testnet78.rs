@@ -343,6 +343,11 @@ async fn truncate_proposal_fields(delta: &mut StateDelta<Snapshot>) -> anyhow::R
                     },
                 ));
             }
+            // client recovery, connection metadata and asset metadata proposals postdate this
+            // migration.
+            pb_governance::proposal::Payload::RecoverIbcClient(_recover_ibc_client) => {}
+            pb_governance::proposal::Payload::SetIbcConnectionMetadata(_set_metadata) => {}
+            pb_governance::proposal::Payload::RegisterAssetMetadata(_register_metadata) => {}
         };
 
         // Store the truncated proposal data
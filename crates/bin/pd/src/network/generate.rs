@@ -259,6 +259,7 @@ impl NetworkConfig {
             shielded_pool_content: ShieldedPoolContent {
                 shielded_pool_params: ShieldedPoolParameters::default(),
                 allocations: allocations.clone(),
+                denom_metadata: Vec::new(),
             },
             sct_content: SctContent {
                 sct_params: SctParameters {
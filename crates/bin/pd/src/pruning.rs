@@ -0,0 +1,35 @@
+//! Pruning of old versions of the state.
+//!
+//! Use [`run()`] to spawn a worker that keeps a window of recent versions of
+//! the state, pruning the data that only older versions need.
+
+use cnidarium::Storage;
+
+/// The number of blocks between prunings. Each pruning compacts the database,
+/// so pruning after every block would be wasteful.
+const PRUNING_INTERVAL: u64 = 1000;
+
+/// Run the pruning worker, keeping the versions of the state from the last
+/// `retain_blocks` blocks readable.
+///
+/// This function returns once the storage stops committing new versions.
+pub async fn run(storage: Storage, retain_blocks: u64) {
+    let mut snapshots = storage.subscribe();
+    let mut pruned_to: Option<u64> = None;
+
+    while snapshots.changed().await.is_ok() {
+        let latest_version = snapshots.borrow_and_update().version();
+        let Some(version) = latest_version.checked_sub(retain_blocks) else {
+            continue;
+        };
+        if pruned_to.is_some_and(|pruned_to| version < pruned_to.saturating_add(PRUNING_INTERVAL)) {
+            continue;
+        }
+
+        // A failed pruning leaves the stale data in place, to be pruned next time.
+        match storage.prune_to(version).await {
+            Ok(()) => pruned_to = Some(version),
+            Err(error) => tracing::error!(?error, ?version, "failed to prune state"),
+        }
+    }
+}
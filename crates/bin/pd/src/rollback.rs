@@ -0,0 +1,64 @@
+//! Rolling back the state of a full node by a number of blocks.
+//!
+//! Use [`rollback()`] to recover from an app hash mismatch, e.g. after an
+//! upgrade that executed some blocks differently than the rest of the network,
+//! without syncing from genesis again. Once rolled back, `pd` executes the
+//! blocks it undid again, as CometBFT replays them from its block store.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{ensure, Context};
+use cnidarium::Storage;
+use penumbra_app::SUBSTORE_PREFIXES;
+use tracing::instrument;
+
+/// Rolls the `pd` state in `pd_home` back by `blocks` blocks, and if
+/// `comet_home` is set, the CometBFT state in it back by a block.
+#[instrument(skip_all)]
+pub async fn rollback(
+    pd_home: PathBuf,
+    comet_home: Option<PathBuf>,
+    blocks: u64,
+) -> anyhow::Result<()> {
+    ensure!(blocks > 0, "must roll back at least one block");
+    let rocksdb_dir = pd_home.join("rocksdb");
+    let storage = Storage::load(rocksdb_dir, SUBSTORE_PREFIXES.to_vec()).await?;
+    let latest_version = storage.latest_version();
+    ensure!(latest_version != u64::MAX, "there is no state to roll back");
+    let version = latest_version
+        .checked_sub(blocks)
+        .with_context(|| format!("can't roll back {blocks} blocks from height {latest_version}"))?;
+
+    tracing::info!(?latest_version, ?version, "rolling back pd state");
+    storage
+        .rollback_to(version)
+        .await
+        .context("failed to roll back pd state")?;
+    storage.release().await;
+
+    if let Some(comet_home) = comet_home {
+        rollback_comet_state(&comet_home)?;
+    }
+
+    Ok(())
+}
+
+/// Rolls the CometBFT state back by a block, with `cometbft rollback`.
+///
+/// CometBFT replays the blocks that `pd` is missing on startup, but it checks
+/// the app hash `pd` ends up with against the one it recorded for its latest
+/// block, which `pd` computed before rolling back. Rolling the CometBFT state
+/// back by a block makes it check against the app hash agreed on by the
+/// network instead. The blocks themselves are kept.
+#[instrument(skip_all)]
+fn rollback_comet_state(comet_home: &Path) -> anyhow::Result<()> {
+    tracing::info!(?comet_home, "rolling back cometbft state");
+    let status = std::process::Command::new("cometbft")
+        .arg("rollback")
+        .arg("--home")
+        .arg(comet_home)
+        .status()
+        .context("failed to run `cometbft rollback`, is `cometbft` installed?")?;
+    ensure!(status.success(), "`cometbft rollback` failed with {status}");
+    Ok(())
+}
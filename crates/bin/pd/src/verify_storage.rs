@@ -0,0 +1,63 @@
+//! Verifying the integrity of the state of a full node.
+//!
+//! Use [`verify_storage()`] to validate a backup of the state of `pd` before
+//! restoring it, or the state of a node after a crash. The tree of each store
+//! is walked again at the latest version, and its root hash recomputed, to
+//! find corrupt or missing nodes and values.
+
+use std::path::PathBuf;
+
+use anyhow::ensure;
+use cnidarium::{RootHash, Storage};
+use penumbra_app::SUBSTORE_PREFIXES;
+use tracing::instrument;
+
+/// Verifies the latest version of the `pd` state in `pd_home`, and if
+/// `app_hash` is set, checks that it's the app hash of that version, e.g. the
+/// one CometBFT committed for the block.
+///
+/// Each problem found is logged, and an error is returned if there are any.
+#[instrument(skip_all)]
+pub async fn verify_storage(pd_home: PathBuf, app_hash: Option<RootHash>) -> anyhow::Result<()> {
+    let rocksdb_dir = pd_home.join("rocksdb");
+    let storage = Storage::load(rocksdb_dir, SUBSTORE_PREFIXES.to_vec()).await?;
+    let snapshot = storage.latest_snapshot();
+    ensure!(
+        snapshot.version() != u64::MAX,
+        "there is no state to verify"
+    );
+
+    tracing::info!(version = snapshot.version(), "verifying pd state");
+    let verification = snapshot.verify().await?;
+    for corruption in &verification.corruptions {
+        tracing::error!(%corruption, "found corrupt state");
+    }
+    let root_hash = hex::encode(verification.root_hash.0);
+    tracing::info!(
+        version = verification.version,
+        %root_hash,
+        keys = verification.keys,
+        corruptions = verification.corruptions.len(),
+        "verified pd state"
+    );
+
+    if let Some(app_hash) = app_hash {
+        ensure!(
+            app_hash == verification.root_hash,
+            "the root hash of the state at height {} is {}, not the app hash {}",
+            verification.version,
+            root_hash,
+            hex::encode(app_hash.0)
+        );
+    }
+    ensure!(
+        verification.is_ok(),
+        "found {} problems with the state at height {}",
+        verification.corruptions.len(),
+        verification.version
+    );
+
+    std::mem::drop(snapshot);
+    storage.release().await;
+    Ok(())
+}
@@ -259,6 +259,8 @@ impl Opt {
             grpc_url: grpc_url.clone(),
             view_url: None,
             governance_custody: None,
+            auto_sweep_threshold: None,
+            birthday: None,
             full_viewing_key: fvk.clone(),
             disable_warning: true,
             custody: pcli::config::CustodyConfig::ViewOnly,
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use anyhow::Result;
 use async_trait::async_trait;
 
-use cnidarium::StateWrite;
+use cnidarium::{StateRead, StateWrite};
 use tendermint::abci;
 
 /// A component of a [`cnidarium`]-based application.
@@ -31,6 +31,14 @@ pub trait Component {
     /// indicating that the chain needs to fully initialize.
     async fn init_chain<S: StateWrite>(state: S, app_state: Option<&Self::AppState>);
 
+    /// Exports the genesis state of a new chain starting from `state`, in the
+    /// form passed in to [`Component::init_chain`].
+    ///
+    /// Only the state that genesis can set up is exported, e.g. parameters;
+    /// anything else the component built up over the life of the chain is
+    /// left behind.
+    async fn export_genesis<S: StateRead>(state: &S) -> Result<Self::AppState>;
+
     /// Begins a new block, optionally inspecting the ABCI
     /// [`BeginBlock`](abci::request::BeginBlock) request.
     ///
@@ -7,7 +7,7 @@ use tendermint::abci;
 use crate::{
     future::{
         CacheFuture, StateDeltaNonconsensusPrefixRawStream, StateDeltaNonconsensusRangeRawStream,
-        StateDeltaPrefixKeysStream, StateDeltaPrefixRawStream,
+        StateDeltaPrefixKeysStream, StateDeltaPrefixRangeRawStream, StateDeltaPrefixRawStream,
     },
     utils, Cache, EscapedByteSlice, StateRead, StateWrite,
 };
@@ -101,6 +101,48 @@ impl<S: StateRead> StateDelta<S> {
         }
     }
 
+    /// Apply the changes made in `fork` since it was forked from this state on
+    /// top of the changes made here, keeping the rest of the tree intact.
+    ///
+    /// This allows executing something speculatively in a fork, and keeping
+    /// its changes only if it succeeds; to discard them, drop the fork. Other
+    /// forks of `fork` are invalidated, as they would be by [`apply`](Self::apply).
+    pub fn apply_fork(&mut self, fork: Self) {
+        assert!(
+            Arc::ptr_eq(&self.state, &fork.state),
+            "can only apply a fork of the same underlying state"
+        );
+        // The layers written before the fork are shared with this state, and
+        // their changes are already visible here.
+        let shared = self
+            .layers
+            .iter()
+            .zip(fork.layers.iter())
+            .take_while(|(ours, theirs)| Arc::ptr_eq(ours, theirs))
+            .count();
+
+        let mut changes = Cache::default();
+        for layer in &fork.layers[shared..] {
+            let cache = layer
+                .write()
+                .take()
+                .expect("cache must not have already been applied");
+            changes.merge(cache);
+        }
+        changes.merge(
+            fork.leaf_cache
+                .write()
+                .take()
+                .expect("unable to take leaf cache, was it already applied?"),
+        );
+
+        self.leaf_cache
+            .write()
+            .as_mut()
+            .expect("delta must not have been applied")
+            .merge(changes);
+    }
+
     /// Flatten all changes in this branch of the tree into a single [`Cache`],
     /// invalidating all other branches of the tree and releasing the underlying
     /// state back to the caller.
@@ -176,6 +218,7 @@ impl<S: StateRead> StateRead for StateDelta<S> {
     type GetRawFut = CacheFuture<S::GetRawFut>;
     type PrefixRawStream = StateDeltaPrefixRawStream<S::PrefixRawStream>;
     type PrefixKeysStream = StateDeltaPrefixKeysStream<S::PrefixKeysStream>;
+    type PrefixRangeRawStream = StateDeltaPrefixRangeRawStream<S::PrefixRangeRawStream>;
     type NonconsensusPrefixRawStream =
         StateDeltaNonconsensusPrefixRawStream<S::NonconsensusPrefixRawStream>;
     type NonconsensusRangeRawStream =
@@ -370,6 +413,31 @@ impl<S: StateRead> StateRead for StateDelta<S> {
         }
     }
 
+    fn prefix_range_raw(
+        &self,
+        prefix: &str,
+        range: impl std::ops::RangeBounds<str>,
+        reverse: bool,
+    ) -> anyhow::Result<Self::PrefixRangeRawStream> {
+        let full_range = utils::prefix_range_bounds(prefix, &range)?;
+        let underlying = self
+            .state
+            .read()
+            .as_ref()
+            .expect("delta must not have been applied")
+            .prefix_range_raw(prefix, range, reverse)?
+            .peekable();
+        Ok(StateDeltaPrefixRangeRawStream {
+            underlying,
+            layers: self.layers.clone(),
+            leaf_cache: self.leaf_cache.clone(),
+            last_key: None,
+            prefix: prefix.to_owned(),
+            range: full_range,
+            reverse,
+        })
+    }
+
     fn nonverifiable_prefix_raw(&self, prefix: &[u8]) -> Self::NonconsensusPrefixRawStream {
         let underlying = self
             .state
@@ -0,0 +1,211 @@
+//! Exports of versions of the state, for bootstrapping new storage.
+//!
+//! An export holds every verifiable and nonverifiable entry of a version of
+//! the state, split into chunks of bounded size so that it can be transferred
+//! piecewise, e.g. by CometBFT's state sync. Restoring an export with
+//! [`Storage::restore`](crate::Storage::restore) rebuilds the same state, with
+//! the same root hash, without replaying the history that led to it.
+//!
+//! Each chunk is a borsh-encoded list of [`ExportEntry`]s. Entries are keyed
+//! by their full key, including the prefix of their substore, so chunks can be
+//! applied to any [`StateWrite`] in any order.
+
+use std::sync::Arc;
+
+use anyhow::{Context as _, Result};
+use borsh::{BorshDeserialize, BorshSerialize};
+use jmt::KeyHash;
+use rocksdb::IteratorMode;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tracing::Span;
+
+use crate::{
+    store::substore::{SubstoreConfig, SubstoreSnapshot},
+    Snapshot, StateWrite,
+};
+
+/// The version of the export format, bumped whenever the encoding of chunks
+/// changes.
+pub const EXPORT_FORMAT: u32 = 1;
+
+/// An entry of an exported state.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum ExportEntry {
+    Verifiable { key: String, value: Vec<u8> },
+    Nonverifiable { key: Vec<u8>, value: Vec<u8> },
+}
+
+impl ExportEntry {
+    fn encoded_len(&self) -> usize {
+        match self {
+            ExportEntry::Verifiable { key, value } => key.len() + value.len(),
+            ExportEntry::Nonverifiable { key, value } => key.len() + value.len(),
+        }
+    }
+}
+
+/// Writes the entries of an exported chunk to `state`.
+pub fn apply_chunk<S: StateWrite>(state: &mut S, chunk: &[u8]) -> Result<()> {
+    let entries: Vec<ExportEntry> =
+        BorshDeserialize::try_from_slice(chunk).context("couldn't decode export chunk")?;
+    for entry in entries {
+        match entry {
+            ExportEntry::Verifiable { key, value } => state.put_raw(key, value),
+            ExportEntry::Nonverifiable { key, value } => state.nonverifiable_put_raw(key, value),
+        }
+    }
+    Ok(())
+}
+
+impl Snapshot {
+    /// Exports this version of the state, as chunks of about `chunk_size`
+    /// bytes. There is always at least one chunk, even if the state is empty.
+    ///
+    /// See the [`export`](crate::export) module for the format of the chunks.
+    pub fn export(&self, chunk_size: usize) -> ReceiverStream<Result<Vec<u8>>> {
+        let config = &self.0.multistore_cache.config;
+        let stores = std::iter::once(&config.main_store)
+            .chain(config.iter())
+            .cloned()
+            .collect();
+        self.export_stores(stores, chunk_size)
+    }
+
+    /// Exports the entries of the substore with the given prefix in this
+    /// version of the state, as chunks of about `chunk_size` bytes, like
+    /// [`Snapshot::export`] does for the whole state. If the prefix is empty,
+    /// the entries of the main store are exported.
+    ///
+    /// An export of a single substore can't be restored as a whole state, but
+    /// it can be checked against the substore's root hash, as reported by
+    /// [`Snapshot::prefix_root_hash`].
+    ///
+    /// # Errors
+    /// Returns an error if the supplied prefix does not correspond to a known substore.
+    pub fn export_substore(
+        &self,
+        prefix: &str,
+        chunk_size: usize,
+    ) -> Result<ReceiverStream<Result<Vec<u8>>>> {
+        let Some(config) = self
+            .0
+            .multistore_cache
+            .config
+            .find_substore(prefix.as_bytes())
+        else {
+            anyhow::bail!("requested an export of a substore that does not exist (prefix={prefix})")
+        };
+        Ok(self.export_stores(vec![config], chunk_size))
+    }
+
+    fn export_stores(
+        &self,
+        stores: Vec<Arc<SubstoreConfig>>,
+        chunk_size: usize,
+    ) -> ReceiverStream<Result<Vec<u8>>> {
+        let span = Span::current();
+        let snapshot = self.clone();
+        let (tx, rx) = mpsc::channel(2);
+
+        tokio::task::spawn_blocking(move || {
+            span.in_scope(|| {
+                let mut chunk = Vec::new();
+                let mut chunk_len = 0;
+                let mut chunks = 0;
+                let mut push = |entry: ExportEntry| -> Result<bool> {
+                    chunk_len += entry.encoded_len();
+                    chunk.push(entry);
+                    if chunk_len < chunk_size {
+                        return Ok(true);
+                    }
+                    chunk_len = 0;
+                    chunks += 1;
+                    let encoded = borsh::to_vec(&std::mem::take(&mut chunk))?;
+                    Ok(tx.blocking_send(Ok(encoded)).is_ok())
+                };
+
+                let result = snapshot.export_entries(&stores, &mut push);
+                let result = result.and_then(|()| {
+                    if !chunk.is_empty() || chunks == 0 {
+                        let encoded = borsh::to_vec(&chunk)?;
+                        let _ = tx.blocking_send(Ok(encoded));
+                    }
+                    Ok(())
+                });
+                if let Err(e) = result {
+                    let _ = tx.blocking_send(Err(e));
+                }
+            })
+        });
+
+        ReceiverStream::new(rx)
+    }
+
+    /// Passes each entry of the given stores in this version of the state to
+    /// `push`, until it returns `false`.
+    fn export_entries(
+        &self,
+        stores: &[Arc<SubstoreConfig>],
+        push: &mut impl FnMut(ExportEntry) -> Result<bool>,
+    ) -> Result<()> {
+        for store in stores {
+            let substore = SubstoreSnapshot {
+                config: store.clone(),
+                rocksdb_snapshot: self.0.snapshot.clone(),
+                version: self.substore_version(store).unwrap_or(u64::MAX),
+                db: self.0.db.clone(),
+            };
+            // Keys of the main store are exported as they are, including the
+            // keys holding the root hashes of substores: those are recomputed
+            // when a restored substore is committed.
+            let full_key = |key: &[u8]| -> Vec<u8> {
+                if store.prefix.is_empty() {
+                    key.to_vec()
+                } else {
+                    [store.prefix_with_delimiter.as_bytes(), key].concat()
+                }
+            };
+
+            let cf_jmt_keys = store.cf_jmt_keys(&self.0.db);
+            for entry in self
+                .0
+                .snapshot
+                .iterator_cf(cf_jmt_keys, IteratorMode::Start)
+            {
+                let (key, key_hash) = entry?;
+                let key_hash = KeyHash(
+                    key_hash
+                        .as_ref()
+                        .try_into()
+                        .context("key hashes are 32 bytes")?,
+                );
+                let value = substore
+                    .get_jmt(key_hash)?
+                    .context("indexed keys have a value")?;
+                let key = String::from_utf8(full_key(&key)).context("keys are utf8")?;
+                if !push(ExportEntry::Verifiable { key, value })? {
+                    return Ok(());
+                }
+            }
+
+            let cf_nonverifiable = store.cf_nonverifiable(&self.0.db);
+            for entry in self
+                .0
+                .snapshot
+                .iterator_cf(cf_nonverifiable, IteratorMode::Start)
+            {
+                let (key, value) = entry?;
+                let entry = ExportEntry::Nonverifiable {
+                    key: full_key(&key),
+                    value: value.into(),
+                };
+                if !push(entry)? {
+                    return Ok(());
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -761,3 +761,167 @@ where
         }
     }
 }
+
+/// A stream of key-value pairs in a range of keys with a common prefix, that
+/// interleaves a verifiable storage and caching layers, in either direction.
+// This implementation follows [`StateDeltaPrefixRawStream`], but bounds the
+// search space for the cache by the range, and walks it backwards when the
+// stream is reversed.
+#[pin_project]
+pub struct StateDeltaPrefixRangeRawStream<St>
+where
+    St: Stream<Item = Result<(String, Vec<u8>)>>,
+{
+    #[pin]
+    pub(crate) underlying: Peekable<St>,
+    pub(crate) layers: Vec<Arc<RwLock<Option<Cache>>>>,
+    pub(crate) leaf_cache: Arc<RwLock<Option<Cache>>>,
+    pub(crate) last_key: Option<String>,
+    pub(crate) prefix: String,
+    /// The bounds of the full keys in the range. An unbounded upper bound
+    /// stands for the end of the prefix.
+    pub(crate) range: (Bound<String>, Bound<String>),
+    pub(crate) reverse: bool,
+}
+
+impl<St> Stream for StateDeltaPrefixRangeRawStream<St>
+where
+    St: Stream<Item = Result<(String, Vec<u8>)>>,
+{
+    type Item = Result<(String, Vec<u8>)>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // This implementation interleaves items from the underlying stream with
+        // items in cache layers, like [`StateDeltaPrefixRawStream`] does. When
+        // the stream is reversed, the search range lies between the peeked key
+        // (inclusive) and the last-returned key (exclusive) instead, and the
+        // rightmost cached key in it is picked.
+
+        // Optimization: ensure we have a peekable item in the underlying stream before continuing.
+        let mut this = self.project();
+        ready!(this.underlying.as_mut().poll_peek(cx));
+
+        // Now that we're ready to interleave the next underlying item with any
+        // cache layers, lock them all for the duration of the method, using a
+        // SmallVec to (hopefully) store all the guards on the stack.
+        let mut layer_guards = SmallVec::<[_; 8]>::new();
+        for layer in this.layers.iter() {
+            layer_guards.push(layer.read());
+        }
+        // Tacking the leaf cache onto the list is important to not miss any values.
+        layer_guards.push(this.leaf_cache.read());
+
+        loop {
+            // Obtain a reference to the next key-value pair from the underlying stream.
+            let peeked = match ready!(this.underlying.as_mut().poll_peek(cx)) {
+                // If we get an underlying error, bubble it up immediately.
+                Some(Err(_e)) => return this.underlying.poll_next(cx),
+                // Otherwise, pass through the peeked value.
+                Some(Ok(pair)) => Some(pair),
+                None => None,
+            };
+
+            // Search the cache layers between the last key we returned
+            // (exclusive, so we make forward progress on the stream) and the
+            // peeked key (inclusive, because we need to find out whether or
+            // not there was a covering deletion), or the end of the range.
+            let last_key = this.last_key.as_ref().map(Bound::Excluded);
+            let peeked_key = peeked.map(|(k, _)| Bound::Included(k));
+            let search_range = if *this.reverse {
+                (
+                    peeked_key.unwrap_or(this.range.0.as_ref()),
+                    last_key.unwrap_or(this.range.1.as_ref()),
+                )
+            } else {
+                (
+                    last_key.unwrap_or(this.range.0.as_ref()),
+                    peeked_key.unwrap_or(this.range.1.as_ref()),
+                )
+            };
+
+            // Initialize with `None` to only search the cache layers, and
+            // compare with the peeked pair at the end.
+            let mut next_pair = None;
+            for layer in layer_guards.iter() {
+                // Find this layer's next key-value pair in the search range.
+                let mut in_range = layer
+                    .as_ref()
+                    .expect("layer must not have been applied")
+                    .unwritten_changes
+                    .range::<String, _>(search_range);
+                let found_pair = if *this.reverse {
+                    // Keys past the end of the prefix come first when walking
+                    // backwards from an unbounded end.
+                    in_range
+                        .rev()
+                        .find(|(k, _v)| k.starts_with(this.prefix.as_str()))
+                } else {
+                    in_range
+                        .next()
+                        .filter(|(k, _v)| k.starts_with(this.prefix.as_str()))
+                };
+
+                // Check whether the new pair, if any, is the new next pair.
+                // We want to replace the pair even when the key is equal, so
+                // that we always prefer a newer value over an older value.
+                match (next_pair, found_pair) {
+                    (Some((next_k, _)), Some((k, v)))
+                        if (*this.reverse && k >= next_k) || (!*this.reverse && k <= next_k) =>
+                    {
+                        next_pair = Some((k, v));
+                    }
+                    (None, Some((k, v))) => {
+                        next_pair = Some((k, v));
+                    }
+                    _ => {}
+                }
+            }
+
+            // Overwrite a String, attempting to reuse its existing allocation.
+            let overwrite_in_place = |dst: &mut Option<String>, src: &str| {
+                if let Some(ref mut dst) = dst {
+                    dst.clear();
+                    dst.push_str(src);
+                } else {
+                    *dst = Some(src.to_owned());
+                }
+            };
+
+            match (next_pair, peeked) {
+                (Some((k, v)), peeked) => {
+                    // Since we searched for cached keys up to the peeked key,
+                    // we know that the cached pair takes priority over the
+                    // peeked pair.
+                    //
+                    // If the keys are exactly equal, we advance the underlying stream.
+                    if peeked.map(|(kp, _)| kp) == Some(k) {
+                        let _ = this.underlying.as_mut().poll_next(cx);
+                    }
+                    overwrite_in_place(this.last_key, k);
+                    if let Some(v) = v {
+                        // If the value is Some, we have a key-value pair to yield.
+                        return Poll::Ready(Some(Ok((k.clone(), v.clone()))));
+                    } else {
+                        // If the value is None, this pair represents a deletion,
+                        // so continue looping until we find a non-deleted pair.
+                        continue;
+                    }
+                }
+                (None, Some(_)) => {
+                    // There's no cache hit before the peeked pair, so we want
+                    // to extract and return it from the underlying stream.
+                    let Poll::Ready(Some(Ok((k, v)))) = this.underlying.as_mut().poll_next(cx)
+                    else {
+                        unreachable!("peeked stream must yield peeked item");
+                    };
+                    overwrite_in_place(this.last_key, &k);
+                    return Poll::Ready(Some(Ok((k, v))));
+                }
+                (None, None) => {
+                    // Terminate the stream, no more items are available.
+                    return Poll::Ready(None);
+                }
+            }
+        }
+    }
+}
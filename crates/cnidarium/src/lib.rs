@@ -5,7 +5,8 @@
 //! The [`Storage`] type is a handle for an instance of a backing store,
 //! implemented using RocksDB.  The storage records a sequence of versioned
 //! [`Snapshot`]s.  The [`Snapshot`] type is a lightweight snapshot of a particular
-//! version of the chain state.
+//! version of the chain state.  For tests and simulations, storage can also be
+//! held entirely in memory, with [`Storage::new_in_memory`].
 //!
 //! Each [`Snapshot`] instance can also be used as the basis for a copy-on-write
 //! fork to build up changes before committing them to persistent storage.  The
@@ -28,7 +29,11 @@
 //!
 //! * A secondary, non-verifiable key-value store with byte keys and byte
 //! values, backed directly by RocksDB.  This is intended for use building
-//! application-specific indexes of the verifiable consensus state.
+//! application-specific indexes of the verifiable consensus state, like
+//! price indexes, which can be iterated over by prefix or range in key
+//! order.  Its contents are versioned and transactional like the rest of the
+//! state, but they are not committed to by the root hash, so they don't bloat
+//! the JMT.
 //!
 //! * A tertiary, in-memory object store. This is intended for use implementing
 //! accumulators, like lists of data to be batch-processed at the end of the
@@ -42,7 +47,8 @@
 //! root hash is written into the base store under the prefix.  This allows use
 //! cases like storing IBC data in a subtree.  The substore's non-verifiable
 //! store is also stored in a separate RocksDB column family, allowing storage
-//! optimizations.
+//! optimizations. Since each substore commits to its own entries, it can be
+//! proven against and [exported](Snapshot::export_substore) on its own.
 //!
 //! Remember that the chain state is a public API.  Mapping from raw byte values
 //! to typed data should be accomplished by means of extension traits.  For
@@ -62,7 +68,9 @@ mod cache;
 mod delta;
 mod escaped_byte_slice;
 mod metrics;
+mod object;
 mod read;
+mod read_cache;
 mod snapshot;
 mod snapshot_cache;
 mod storage;
@@ -79,13 +87,16 @@ pub use cache::Cache;
 pub use delta::{ArcStateDeltaExt, StateDelta};
 pub use escaped_byte_slice::EscapedByteSlice;
 pub use jmt::{ics23_spec, RootHash};
+pub use object::ObjectKey;
 pub use read::StateRead;
 pub use snapshot::Snapshot;
-pub use storage::{Storage, TempStorage};
+pub use storage::{CompactionStyle, Durability, KeyChange, Storage, StorageOptions, TempStorage};
 pub use write::StateWrite;
 pub use write_batch::StagedWriteBatch;
 
+pub mod export;
 pub mod future;
+pub mod verify;
 
 #[cfg(feature = "rpc")]
 pub mod rpc;
@@ -26,8 +26,50 @@ pub fn register_metrics() {
         Unit::Seconds,
         "The duration of a nonverifiable_get_raw request"
     );
+    describe_counter!(
+        STORAGE_READ_CACHE_HITS,
+        Unit::Count,
+        "The number of get_raw requests served by the read cache"
+    );
+    describe_counter!(
+        STORAGE_READ_CACHE_MISSES,
+        Unit::Count,
+        "The number of get_raw requests that missed the read cache"
+    );
+    describe_counter!(
+        STORAGE_READ_BYTES,
+        Unit::Bytes,
+        "The number of bytes read from storage, by kind of store"
+    );
+    describe_counter!(
+        STORAGE_WRITES,
+        Unit::Count,
+        "The number of keys written or deleted by commits, by kind of store"
+    );
+    describe_counter!(
+        STORAGE_WRITTEN_BYTES,
+        Unit::Bytes,
+        "The number of bytes written by commits, by kind of store"
+    );
+    describe_histogram!(
+        STORAGE_PREPARE_COMMIT_DURATION,
+        Unit::Seconds,
+        "The duration of preparing a commit, including updating the JMTs"
+    );
+    describe_histogram!(
+        STORAGE_COMMIT_BATCH_DURATION,
+        Unit::Seconds,
+        "The duration of writing a prepared commit to the database"
+    );
 }
 
 pub const STORAGE_GET_RAW_DURATION: &str = "cnidarium_get_raw_duration_seconds";
 pub const STORAGE_NONCONSENSUS_GET_RAW_DURATION: &str =
     "cnidarium_nonverifiable_get_raw_duration_seconds";
+pub const STORAGE_READ_CACHE_HITS: &str = "cnidarium_read_cache_hits_total";
+pub const STORAGE_READ_CACHE_MISSES: &str = "cnidarium_read_cache_misses_total";
+pub const STORAGE_READ_BYTES: &str = "cnidarium_read_bytes_total";
+pub const STORAGE_WRITES: &str = "cnidarium_writes_total";
+pub const STORAGE_WRITTEN_BYTES: &str = "cnidarium_written_bytes_total";
+pub const STORAGE_PREPARE_COMMIT_DURATION: &str = "cnidarium_prepare_commit_duration_seconds";
+pub const STORAGE_COMMIT_BATCH_DURATION: &str = "cnidarium_commit_batch_duration_seconds";
@@ -0,0 +1,52 @@
+use std::{fmt, marker::PhantomData};
+
+/// A key of the ephemeral key-object store, bound to the type of the object
+/// stored at it.
+///
+/// Reading an object with [`StateRead::object`](crate::StateRead::object)
+/// through an `ObjectKey` can't ask for another type than the one written with
+/// [`StateWrite::object_set`](crate::StateWrite::object_set), so components
+/// can pass values between the actions of a block and its `EndBlock` without
+/// repeating their types at each access. Like other objects, they are not
+/// persisted to the `Storage` during `commit`, so each block starts without
+/// them.
+///
+/// Keys should be declared once, alongside the other state keys of a
+/// component, since two `ObjectKey`s with the same key but different types
+/// still conflict.
+pub struct ObjectKey<T> {
+    key: &'static str,
+    object: PhantomData<fn() -> T>,
+}
+
+impl<T> ObjectKey<T> {
+    pub const fn new(key: &'static str) -> Self {
+        Self {
+            key,
+            object: PhantomData,
+        }
+    }
+
+    /// The key the object is stored at.
+    pub const fn key(&self) -> &'static str {
+        self.key
+    }
+}
+
+// Implemented by hand, since derives would require `T` to implement the traits.
+impl<T> Clone for ObjectKey<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ObjectKey<T> {}
+
+impl<T> fmt::Debug for ObjectKey<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ObjectKey")
+            .field(&self.key)
+            .field(&std::any::type_name::<T>())
+            .finish()
+    }
+}
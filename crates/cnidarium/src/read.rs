@@ -3,11 +3,14 @@ use std::{any::Any, future::Future, ops::RangeBounds, sync::Arc};
 use anyhow::Result;
 use futures::Stream;
 
+use crate::ObjectKey;
+
 /// Read access to chain state.
 pub trait StateRead: Send + Sync {
     type GetRawFut: Future<Output = Result<Option<Vec<u8>>>> + Send + 'static;
     type PrefixRawStream: Stream<Item = Result<(String, Vec<u8>)>> + Send + 'static;
     type PrefixKeysStream: Stream<Item = Result<String>> + Send + 'static;
+    type PrefixRangeRawStream: Stream<Item = Result<(String, Vec<u8>)>> + Send + 'static;
     type NonconsensusPrefixRawStream: Stream<Item = Result<(Vec<u8>, Vec<u8>)>> + Send + 'static;
     type NonconsensusRangeRawStream: Stream<Item = Result<(Vec<u8>, Vec<u8>)>> + Send + 'static;
 
@@ -20,6 +23,14 @@ pub trait StateRead: Send + Sync {
     ///
     /// This is intended for application-specific indexes of the verifiable
     /// consensus state, rather than for use as a primary data storage method.
+    /// Values in this store are versioned and transactional like those in the
+    /// verifiable store, but they are not committed to by the root hash, so
+    /// they can't be proven, and writing them doesn't grow the JMT.
+    ///
+    /// Keys are routed to substores by their prefix, like verifiable keys.
+    ///
+    /// Users should generally prefer to use `nonverifiable_get` or
+    /// `nonverifiable_get_proto` from an extension trait.
     fn nonverifiable_get_raw(&self, key: &[u8]) -> Self::GetRawFut;
 
     /// Gets an object from the ephemeral key-object store.
@@ -44,6 +55,14 @@ pub trait StateRead: Send + Sync {
     /// is present.
     fn object_type(&self, key: &'static str) -> Option<std::any::TypeId>;
 
+    /// Gets an object from the ephemeral key-object store, at a key bound to
+    /// its type.
+    ///
+    /// See [`ObjectKey`] for when to prefer this over `object_get`.
+    fn object<T: Any + Send + Sync + Clone>(&self, key: ObjectKey<T>) -> Option<T> {
+        self.object_get(key.key())
+    }
+
     /// Retrieve all values for keys matching a prefix from the verifiable key-value store, as raw bytes.
     ///
     /// Users should generally prefer to use `prefix` or `prefix_proto` from an extension trait.
@@ -52,15 +71,39 @@ pub trait StateRead: Send + Sync {
     /// Retrieve all keys (but not values) matching a prefix from the verifiable key-value store.
     fn prefix_keys(&self, prefix: &str) -> Self::PrefixKeysStream;
 
+    /// Retrieve all values for keys matching a prefix from the verifiable key-value store, as raw
+    /// bytes, restricted to the keys whose remainder after the prefix lies in `range`.
+    ///
+    /// Keys are returned in ascending order, or in descending order if `reverse` is set. This
+    /// method returns an error if the range is empty because its bounds are out of order.
+    ///
+    /// Users should generally prefer to use `prefix_range` or `prefix_range_proto` from an extension trait.
+    fn prefix_range_raw(
+        &self,
+        prefix: &str,
+        range: impl RangeBounds<str>,
+        reverse: bool,
+    ) -> Result<Self::PrefixRangeRawStream>;
+
     /// Retrieve all values for keys matching a prefix from the non-verifiable key-value store, as raw bytes.
     ///
-    /// Users should generally prefer to use wrapper methods in an extension trait.
+    /// Keys are returned in ascending byte order. Since keys are raw bytes, a
+    /// component can lay out an index so that iterating over a prefix returns
+    /// its entries in the order it needs, e.g. by encoding integers big-endian.
+    ///
+    /// Users should generally prefer to use `nonverifiable_prefix` or
+    /// `nonverifiable_prefix_proto` from an extension trait.
     fn nonverifiable_prefix_raw(&self, prefix: &[u8]) -> Self::NonconsensusPrefixRawStream;
 
     /// Retrieve all values for keys in a range from the non-verifiable key-value store, as raw bytes.
     /// This method does not support inclusive ranges, and will return an error if passed one.
     ///
-    /// Users should generally prefer to use wrapper methods in an extension trait.
+    /// If a `prefix` is given, only the keys starting with it are returned, and the bounds of the
+    /// range apply to the remainder of the keys after the prefix. Keys are returned in ascending
+    /// byte order.
+    ///
+    /// Users should generally prefer to use `nonverifiable_range` or
+    /// `nonverifiable_range_proto` from an extension trait.
     fn nonverifiable_range_raw(
         &self,
         prefix: Option<&[u8]>,
@@ -72,6 +115,7 @@ impl<'a, S: StateRead + Send + Sync> StateRead for &'a S {
     type GetRawFut = S::GetRawFut;
     type PrefixRawStream = S::PrefixRawStream;
     type PrefixKeysStream = S::PrefixKeysStream;
+    type PrefixRangeRawStream = S::PrefixRangeRawStream;
     type NonconsensusPrefixRawStream = S::NonconsensusPrefixRawStream;
     type NonconsensusRangeRawStream = S::NonconsensusRangeRawStream;
 
@@ -87,6 +131,15 @@ impl<'a, S: StateRead + Send + Sync> StateRead for &'a S {
         (**self).prefix_keys(prefix)
     }
 
+    fn prefix_range_raw(
+        &self,
+        prefix: &str,
+        range: impl RangeBounds<str>,
+        reverse: bool,
+    ) -> Result<S::PrefixRangeRawStream> {
+        (**self).prefix_range_raw(prefix, range, reverse)
+    }
+
     fn nonverifiable_prefix_raw(&self, prefix: &[u8]) -> S::NonconsensusPrefixRawStream {
         (**self).nonverifiable_prefix_raw(prefix)
     }
@@ -116,6 +169,7 @@ impl<'a, S: StateRead + Send + Sync> StateRead for &'a mut S {
     type GetRawFut = S::GetRawFut;
     type PrefixRawStream = S::PrefixRawStream;
     type PrefixKeysStream = S::PrefixKeysStream;
+    type PrefixRangeRawStream = S::PrefixRangeRawStream;
     type NonconsensusPrefixRawStream = S::NonconsensusPrefixRawStream;
     type NonconsensusRangeRawStream = S::NonconsensusRangeRawStream;
 
@@ -131,6 +185,15 @@ impl<'a, S: StateRead + Send + Sync> StateRead for &'a mut S {
         (**self).prefix_keys(prefix)
     }
 
+    fn prefix_range_raw(
+        &self,
+        prefix: &str,
+        range: impl RangeBounds<str>,
+        reverse: bool,
+    ) -> Result<S::PrefixRangeRawStream> {
+        (**self).prefix_range_raw(prefix, range, reverse)
+    }
+
     fn nonverifiable_prefix_raw(&self, prefix: &[u8]) -> S::NonconsensusPrefixRawStream {
         (**self).nonverifiable_prefix_raw(prefix)
     }
@@ -160,6 +223,7 @@ impl<S: StateRead + Send + Sync> StateRead for Arc<S> {
     type GetRawFut = S::GetRawFut;
     type PrefixRawStream = S::PrefixRawStream;
     type PrefixKeysStream = S::PrefixKeysStream;
+    type PrefixRangeRawStream = S::PrefixRangeRawStream;
     type NonconsensusPrefixRawStream = S::NonconsensusPrefixRawStream;
     type NonconsensusRangeRawStream = S::NonconsensusRangeRawStream;
 
@@ -175,6 +239,15 @@ impl<S: StateRead + Send + Sync> StateRead for Arc<S> {
         (**self).prefix_keys(prefix)
     }
 
+    fn prefix_range_raw(
+        &self,
+        prefix: &str,
+        range: impl RangeBounds<str>,
+        reverse: bool,
+    ) -> Result<S::PrefixRangeRawStream> {
+        (**self).prefix_range_raw(prefix, range, reverse)
+    }
+
     fn nonverifiable_prefix_raw(&self, prefix: &[u8]) -> S::NonconsensusPrefixRawStream {
         (**self).nonverifiable_prefix_raw(prefix)
     }
@@ -204,6 +277,7 @@ impl StateRead for () {
     type GetRawFut = futures::future::Ready<Result<Option<Vec<u8>>>>;
     type PrefixRawStream = futures::stream::Iter<std::iter::Empty<Result<(String, Vec<u8>)>>>;
     type PrefixKeysStream = futures::stream::Iter<std::iter::Empty<Result<String>>>;
+    type PrefixRangeRawStream = futures::stream::Iter<std::iter::Empty<Result<(String, Vec<u8>)>>>;
     type NonconsensusPrefixRawStream =
         futures::stream::Iter<std::iter::Empty<Result<(Vec<u8>, Vec<u8>)>>>;
     type NonconsensusRangeRawStream =
@@ -233,6 +307,15 @@ impl StateRead for () {
         futures::stream::iter(std::iter::empty())
     }
 
+    fn prefix_range_raw(
+        &self,
+        _prefix: &str,
+        _range: impl RangeBounds<str>,
+        _reverse: bool,
+    ) -> Result<Self::PrefixRangeRawStream> {
+        Ok(futures::stream::iter(std::iter::empty()))
+    }
+
     fn nonverifiable_prefix_raw(&self, _prefix: &[u8]) -> Self::NonconsensusPrefixRawStream {
         futures::stream::iter(std::iter::empty())
     }
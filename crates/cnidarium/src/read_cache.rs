@@ -0,0 +1,144 @@
+//! A cache of reads from the verifiable store.
+//!
+//! The value of a key at a given version of a substore never changes, so reads
+//! can be cached by key and substore version, and shared by every snapshot of
+//! a [`Storage`](crate::Storage), without ever being stale. The only writes
+//! that rewrite a version are migrations committed in place and restores, and
+//! both clear the cache.
+
+use std::collections::BTreeMap;
+
+use parking_lot::Mutex;
+
+#[cfg(feature = "metrics")]
+use crate::metrics;
+
+/// A key read from a substore at a version: the prefix of the substore, the
+/// hash of the key within it, and the version of the substore.
+type CacheKey = (String, [u8; 32], jmt::Version);
+
+/// A least-recently-used cache of the values read from the verifiable store.
+pub(crate) struct ReadCache {
+    capacity: usize,
+    inner: Mutex<Entries>,
+}
+
+#[derive(Default)]
+struct Entries {
+    /// The number of lookups so far, used to order entries by their last use.
+    clock: u64,
+    values: BTreeMap<CacheKey, (Option<Vec<u8>>, u64)>,
+    /// The cached keys, keyed by when they were last used.
+    by_last_use: BTreeMap<u64, CacheKey>,
+}
+
+impl std::fmt::Debug for ReadCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReadCache")
+            .field("capacity", &self.capacity)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Entries {
+    fn touch(&mut self, key: CacheKey) -> u64 {
+        self.clock += 1;
+        self.by_last_use.insert(self.clock, key);
+        self.clock
+    }
+}
+
+impl ReadCache {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            inner: Mutex::new(Entries::default()),
+        }
+    }
+
+    /// Gets the value of the key with the given hash in the substore with the
+    /// given prefix, at the given version of the substore, if it's cached.
+    pub(crate) fn get(
+        &self,
+        prefix: &str,
+        key_hash: jmt::KeyHash,
+        version: jmt::Version,
+    ) -> Option<Option<Vec<u8>>> {
+        let key = (prefix.to_string(), key_hash.0, version);
+        let mut entries = self.inner.lock();
+        let Some((_, last_use)) = entries.values.get(&key) else {
+            #[cfg(feature = "metrics")]
+            metrics::counter!(metrics::STORAGE_READ_CACHE_MISSES).increment(1);
+            return None;
+        };
+        let last_use = *last_use;
+        entries.by_last_use.remove(&last_use);
+
+        let now = entries.touch(key.clone());
+        let (value, last_use) = entries.values.get_mut(&key)?;
+        *last_use = now;
+        #[cfg(feature = "metrics")]
+        metrics::counter!(metrics::STORAGE_READ_CACHE_HITS).increment(1);
+        Some(value.clone())
+    }
+
+    /// Caches the value read, evicting the least recently used value if the
+    /// cache is full.
+    pub(crate) fn insert(
+        &self,
+        prefix: &str,
+        key_hash: jmt::KeyHash,
+        version: jmt::Version,
+        value: Option<Vec<u8>>,
+    ) {
+        if self.capacity == 0 {
+            return;
+        }
+        let key = (prefix.to_string(), key_hash.0, version);
+        let mut entries = self.inner.lock();
+        if let Some((_, last_use)) = entries.values.remove(&key) {
+            entries.by_last_use.remove(&last_use);
+        }
+        while entries.values.len() >= self.capacity {
+            let Some((_, evicted)) = entries.by_last_use.pop_first() else {
+                break;
+            };
+            entries.values.remove(&evicted);
+        }
+
+        let now = entries.touch(key.clone());
+        entries.values.insert(key, (value, now));
+    }
+
+    /// Removes every cached value.
+    pub(crate) fn clear(&self) {
+        *self.inner.lock() = Entries::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key_hash(key: &str) -> jmt::KeyHash {
+        jmt::KeyHash::with::<sha2::Sha256>(key)
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let cache = ReadCache::new(2);
+        cache.insert("", key_hash("a"), 1, Some(vec![1]));
+        cache.insert("ibc", key_hash("a"), 1, None);
+        assert_eq!(cache.get("", key_hash("a"), 1), Some(Some(vec![1])));
+        assert_eq!(cache.get("", key_hash("a"), 2), None);
+
+        // The read of `ibc/a` is the least recently used.
+        cache.insert("", key_hash("b"), 1, Some(vec![2]));
+        assert_eq!(cache.get("ibc", key_hash("a"), 1), None);
+        assert_eq!(cache.get("", key_hash("a"), 1), Some(Some(vec![1])));
+        assert_eq!(cache.get("", key_hash("b"), 1), Some(Some(vec![2])));
+
+        cache.clear();
+        assert_eq!(cache.get("", key_hash("a"), 1), None);
+    }
+}
@@ -1,5 +1,5 @@
 use std::iter;
-use std::{any::Any, sync::Arc};
+use std::{any::Any, sync::Arc, time::Duration};
 
 use anyhow::Result;
 use async_trait::async_trait;
@@ -9,6 +9,7 @@ use tracing::Span;
 
 #[cfg(feature = "metrics")]
 use crate::metrics;
+use crate::read_cache::ReadCache;
 use crate::store::multistore::{self, MultistoreCache};
 use crate::{store, StateRead};
 
@@ -36,6 +37,36 @@ pub(crate) struct Inner {
     pub(crate) version: jmt::Version,
     // Used to retrieve column family handles.
     pub(crate) db: Arc<rocksdb::DB>,
+    /// How reads are served and traced, shared by the snapshots of the storage.
+    pub(crate) read_options: ReadOptions,
+}
+
+/// How the snapshots of a storage serve and trace reads.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ReadOptions {
+    /// The cache of reads from the verifiable store, if any.
+    pub(crate) cache: Option<Arc<ReadCache>>,
+    /// If set, reads taking longer than this are logged, with the prefix of the key read.
+    pub(crate) slow_threshold: Option<Duration>,
+}
+
+impl ReadOptions {
+    /// Returns the threshold over which a read of `key` is slow, and the prefix of
+    /// the key to log it with, if slow reads are logged.
+    fn slow_read(&self, key: &[u8]) -> Option<(Duration, String)> {
+        let threshold = self.slow_threshold?;
+        let end = key.iter().position(|b| *b == b'/').unwrap_or(key.len());
+        Some((threshold, String::from_utf8_lossy(&key[..end]).into_owned()))
+    }
+}
+
+/// Logs a read that took `elapsed`, if it's over the threshold of `slow_read`.
+fn trace_slow_read(slow_read: Option<(Duration, String)>, elapsed: Duration, nonverifiable: bool) {
+    if let Some((threshold, prefix)) = slow_read {
+        if elapsed > threshold {
+            tracing::warn!(%prefix, ?elapsed, nonverifiable, "slow read from storage");
+        }
+    }
 }
 
 impl Snapshot {
@@ -44,12 +75,14 @@ impl Snapshot {
         db: Arc<rocksdb::DB>,
         version: jmt::Version,
         multistore_cache: multistore::MultistoreCache,
+        read_options: ReadOptions,
     ) -> Self {
         Self(Arc::new(Inner {
             snapshot: Arc::new(RocksDbSnapshot::new(db.clone())),
             version,
             db,
             multistore_cache,
+            read_options,
         }))
     }
 
@@ -190,6 +223,8 @@ impl StateRead for Snapshot {
     type PrefixRawStream =
         tokio_stream::wrappers::ReceiverStream<anyhow::Result<(String, Vec<u8>)>>;
     type PrefixKeysStream = tokio_stream::wrappers::ReceiverStream<anyhow::Result<String>>;
+    type PrefixRangeRawStream =
+        tokio_stream::wrappers::ReceiverStream<anyhow::Result<(String, Vec<u8>)>>;
     type NonconsensusPrefixRawStream =
         tokio_stream::wrappers::ReceiverStream<anyhow::Result<(Vec<u8>, Vec<u8>)>>;
     type NonconsensusRangeRawStream =
@@ -198,6 +233,7 @@ impl StateRead for Snapshot {
     /// Fetch a key from the JMT.
     fn get_raw(&self, key: &str) -> Self::GetRawFut {
         let span = Span::current();
+        let slow_read = self.0.read_options.slow_read(key.as_bytes());
         let (key, config) = self.0.multistore_cache.config.route_key_str(key);
 
         let rocksdb_snapshot = self.0.snapshot.clone();
@@ -214,13 +250,32 @@ impl StateRead for Snapshot {
             db,
         };
         let key_hash = jmt::KeyHash::with::<sha2::Sha256>(key);
+        let read_cache = self.0.read_options.cache.clone();
 
         crate::future::SnapshotFuture(tokio::task::spawn_blocking(move || {
             span.in_scope(|| {
-                let _start = std::time::Instant::now();
+                let start = std::time::Instant::now();
+                let prefix = &substore.config.prefix;
+                if let Some(value) = read_cache
+                    .as_ref()
+                    .and_then(|cache| cache.get(prefix, key_hash, version))
+                {
+                    return Ok(value);
+                }
                 let rsp = substore.get_jmt(key_hash);
+                let elapsed = start.elapsed();
                 #[cfg(feature = "metrics")]
-                metrics::histogram!(metrics::STORAGE_GET_RAW_DURATION).record(_start.elapsed());
+                {
+                    metrics::histogram!(metrics::STORAGE_GET_RAW_DURATION).record(elapsed);
+                    if let Ok(Some(value)) = &rsp {
+                        metrics::counter!(metrics::STORAGE_READ_BYTES, "kind" => "verifiable")
+                            .increment(value.len() as u64);
+                    }
+                }
+                trace_slow_read(slow_read, elapsed, false);
+                if let (Some(cache), Ok(value)) = (&read_cache, &rsp) {
+                    cache.insert(prefix, key_hash, version, value.clone());
+                }
                 rsp
             })
         }))
@@ -229,6 +284,7 @@ impl StateRead for Snapshot {
     /// Fetch a key from nonverifiable storage.
     fn nonverifiable_get_raw(&self, key: &[u8]) -> Self::GetRawFut {
         let span = Span::current();
+        let slow_read = self.0.read_options.slow_read(key);
         let (key, config) = self.0.multistore_cache.config.route_key_bytes(key);
 
         let rocksdb_snapshot = self.0.snapshot.clone();
@@ -248,16 +304,24 @@ impl StateRead for Snapshot {
 
         crate::future::SnapshotFuture(tokio::task::spawn_blocking(move || {
             span.in_scope(|| {
-                let _start = std::time::Instant::now();
+                let start = std::time::Instant::now();
 
                 let cf_nonverifiable = substore.config.cf_nonverifiable(&substore.db);
                 let rsp = substore
                     .rocksdb_snapshot
                     .get_cf(cf_nonverifiable, key)
                     .map_err(Into::into);
+                let elapsed = start.elapsed();
                 #[cfg(feature = "metrics")]
-                metrics::histogram!(metrics::STORAGE_NONCONSENSUS_GET_RAW_DURATION)
-                    .record(_start.elapsed());
+                {
+                    metrics::histogram!(metrics::STORAGE_NONCONSENSUS_GET_RAW_DURATION)
+                        .record(elapsed);
+                    if let Ok(Some(value)) = &rsp {
+                        metrics::counter!(metrics::STORAGE_READ_BYTES, "kind" => "nonverifiable")
+                            .increment(value.len() as u64);
+                    }
+                }
+                trace_slow_read(slow_read, elapsed, true);
                 rsp
             })
         }))
@@ -383,6 +447,108 @@ impl StateRead for Snapshot {
         tokio_stream::wrappers::ReceiverStream::new(rx_prefix_keys)
     }
 
+    /// Returns a stream of the key-value pairs with the given prefix, whose
+    /// remainder after the prefix lies in the given range.
+    fn prefix_range_raw(
+        &self,
+        prefix: &str,
+        range: impl std::ops::RangeBounds<str>,
+        reverse: bool,
+    ) -> anyhow::Result<Self::PrefixRangeRawStream> {
+        use std::ops::Bound;
+
+        let span = Span::current();
+
+        let rocksdb_snapshot = self.0.snapshot.clone();
+        let db = self.0.db.clone();
+
+        let (prefix_truncated, config) = self.0.multistore_cache.config.match_prefix_str(prefix);
+        // The range applies to the keys after the prefix, so it can't span
+        // the delimiter between a substore's prefix and its keys.
+        if !config.prefix.is_empty() && !prefix.starts_with(&config.prefix_with_delimiter) {
+            anyhow::bail!(
+                "prefix {prefix} of a range query must include the delimiter of substore {}",
+                config.prefix
+            );
+        }
+        let substore_prefix = config.prefix.clone();
+
+        let version = self
+            .substore_version(&config)
+            .expect("the substore exists and has been initialized");
+
+        let substore = store::substore::SubstoreSnapshot {
+            config,
+            rocksdb_snapshot,
+            version,
+            db,
+        };
+
+        // RocksDB bounds are inclusive below and exclusive above, and the
+        // smallest key greater than `k` is `k` followed by a zero byte.
+        let (start, end) = crate::utils::prefix_range_bounds(prefix_truncated, &range)?;
+        let next_key = |k: String| {
+            let mut k = k.into_bytes();
+            k.push(0);
+            k
+        };
+        let mut options = rocksdb::ReadOptions::default();
+        match start {
+            Bound::Included(k) => options.set_iterate_lower_bound(k.into_bytes()),
+            Bound::Excluded(k) => options.set_iterate_lower_bound(next_key(k)),
+            Bound::Unbounded => unreachable!("the lower bound is at least the prefix"),
+        }
+        match end {
+            Bound::Included(k) => options.set_iterate_upper_bound(next_key(k)),
+            Bound::Excluded(k) => options.set_iterate_upper_bound(k.into_bytes()),
+            Bound::Unbounded => {
+                if let Some(prefix_end) = prefix_successor(prefix_truncated.as_bytes()) {
+                    options.set_iterate_upper_bound(prefix_end);
+                }
+            }
+        }
+        let mode = if reverse {
+            rocksdb::IteratorMode::End
+        } else {
+            rocksdb::IteratorMode::Start
+        };
+        let (tx_prefix_item, rx_prefix_query) = mpsc::channel(10);
+
+        // As in `prefix_raw`, the range is scanned over the key preimages, and
+        // the values are fetched from the JMT.
+        tokio::task::spawn_blocking(move || {
+            span.in_scope(|| {
+                let cf_jmt_keys = substore.config.cf_jmt_keys(&substore.db);
+                let jmt_keys_iterator =
+                    substore
+                        .rocksdb_snapshot
+                        .iterator_cf_opt(cf_jmt_keys, options, mode);
+
+                for tuple in jmt_keys_iterator {
+                    let (key_preimage, _) = tuple?;
+                    let substore_key = std::str::from_utf8(key_preimage.as_ref())
+                        .expect("saved jmt keys are utf-8 strings");
+                    let key_hash = jmt::KeyHash::with::<sha2::Sha256>(substore_key.as_bytes());
+
+                    let full_key = if substore_prefix.is_empty() {
+                        substore_key.to_string()
+                    } else {
+                        format!("{substore_prefix}/{substore_key}")
+                    };
+
+                    let v = substore
+                        .get_jmt(key_hash)?
+                        .expect("keys in jmt_keys should have a corresponding value in jmt");
+
+                    tx_prefix_item.blocking_send(Ok((full_key, v)))?;
+                }
+                anyhow::Ok(())
+            })
+        });
+
+        Ok(tokio_stream::wrappers::ReceiverStream::new(rx_prefix_query))
+    }
+
     /// Returns a stream of all key-value pairs with the given prefix, from nonverifiable storage.
     fn nonverifiable_prefix_raw(&self, prefix: &[u8]) -> Self::NonconsensusPrefixRawStream {
         let span = Span::current();
@@ -549,6 +715,19 @@ impl StateRead for Snapshot {
     }
 }
 
+/// Returns the smallest key greater than every key with the given prefix, or
+/// `None` if there is no such key.
+fn prefix_successor(prefix: &[u8]) -> Option<Vec<u8>> {
+    let mut successor = prefix.to_vec();
+    while let Some(last) = successor.pop() {
+        if last < u8::MAX {
+            successor.push(last + 1);
+            return Some(successor);
+        }
+    }
+    None
+}
+
 impl std::fmt::Debug for Snapshot {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Snapshot")
@@ -83,6 +83,13 @@ impl SnapshotCache {
     pub fn clear(&mut self) {
         self.cache.clear();
     }
+
+    /// Empties the cache, and inserts `snapshot` in it, e.g. to start from a
+    /// version that doesn't follow the latest one.
+    pub fn restart(&mut self, snapshot: Snapshot) {
+        self.cache.clear();
+        self.cache.push_front(snapshot);
+    }
 }
 
 #[cfg(test)]
@@ -114,7 +121,7 @@ mod test {
 
         // Check that the cache has a capacity at least 1
         assert!(cache.get(u64::MAX).is_some());
-        let new_snapshot = Snapshot::new(db, 0, MultistoreCache::default());
+        let new_snapshot = Snapshot::new(db, 0, MultistoreCache::default(), Default::default());
         cache
             .try_push(new_snapshot)
             .expect("should not fail to insert a new entry");
@@ -131,7 +138,8 @@ mod test {
         let db_handle = storage.db();
         let snapshot = storage.latest_snapshot();
         let mut cache = SnapshotCache::new(snapshot, 1);
-        let stale_snapshot = Snapshot::new(db_handle, 1, MultistoreCache::default());
+        let stale_snapshot =
+            Snapshot::new(db_handle, 1, MultistoreCache::default(), Default::default());
         cache
             .try_push(stale_snapshot)
             .expect_err("should fail to insert a stale entry in the snapshot cache");
@@ -142,9 +150,14 @@ mod test {
     async fn fail_insert_gapped_snapshot() {
         let storage = create_storage_instance().await;
         let db_handle = storage.db();
-        let snapshot = Snapshot::new(db_handle.clone(), 0, MultistoreCache::default());
+        let snapshot = Snapshot::new(
+            db_handle.clone(),
+            0,
+            MultistoreCache::default(),
+            Default::default(),
+        );
         let mut cache = SnapshotCache::new(snapshot, 2);
-        let snapshot = Snapshot::new(db_handle, 2, MultistoreCache::default());
+        let snapshot = Snapshot::new(db_handle, 2, MultistoreCache::default(), Default::default());
         cache
             .try_push(snapshot)
             .expect_err("should fail to insert snapshot with skipped version number");
@@ -162,7 +175,12 @@ mod test {
 
         // Fill the entire cache by inserting 9 more entries.
         for i in 0..9 {
-            let snapshot = Snapshot::new(db_handle.clone(), i, MultistoreCache::default());
+            let snapshot = Snapshot::new(
+                db_handle.clone(),
+                i,
+                MultistoreCache::default(),
+                Default::default(),
+            );
             cache
                 .try_push(snapshot)
                 .expect("should not fail to insert a new entry");
@@ -173,7 +191,8 @@ mod test {
 
         // Push another snapshot in the cache, this should cause eviction of the oldest entry
         // alone.
-        let new_snapshot = Snapshot::new(db_handle, 9, MultistoreCache::default());
+        let new_snapshot =
+            Snapshot::new(db_handle, 9, MultistoreCache::default(), Default::default());
         cache
             .try_push(new_snapshot)
             .expect("should not fail to insert a new entry");
@@ -192,14 +211,24 @@ mod test {
     async fn drop_oldest_snapshot() {
         let storage = create_storage_instance().await;
         let db_handle = storage.db();
-        let snapshot = Snapshot::new(db_handle.clone(), 0, MultistoreCache::default());
+        let snapshot = Snapshot::new(
+            db_handle.clone(),
+            0,
+            MultistoreCache::default(),
+            Default::default(),
+        );
 
         // Create a cache of size 10, populated with a snapshot at version 0.
         let mut cache = SnapshotCache::new(snapshot, 10);
 
         // Saturate the cache by inserting 9 more entries.
         for i in 1..10 {
-            let snapshot = Snapshot::new(db_handle.clone(), i, MultistoreCache::default());
+            let snapshot = Snapshot::new(
+                db_handle.clone(),
+                i,
+                MultistoreCache::default(),
+                Default::default(),
+            );
             cache
                 .try_push(snapshot)
                 .expect("should be able to insert new entries")
@@ -209,7 +238,12 @@ mod test {
         assert!(cache.get(0).is_some());
 
         // Insert a new value that should overflow the cache.
-        let snapshot = Snapshot::new(db_handle, 10, MultistoreCache::default());
+        let snapshot = Snapshot::new(
+            db_handle,
+            10,
+            MultistoreCache::default(),
+            Default::default(),
+        );
         cache
             .try_push(snapshot)
             .expect("should be able to insert a new entry");
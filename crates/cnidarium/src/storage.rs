@@ -1,23 +1,42 @@
-use std::{path::PathBuf, sync::Arc};
+use std::{
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
 
-use anyhow::{bail, ensure, Result};
+use anyhow::{bail, ensure, Context as _, Result};
+use futures::Stream;
 use parking_lot::RwLock;
-use rocksdb::{Options, DB};
+use rocksdb::{ColumnFamilyDescriptor, Options, DB};
 use std::collections::HashMap;
-use tokio::sync::watch;
+use tokio::sync::{broadcast, watch};
 use tracing::Span;
 
 use crate::{
     cache::Cache,
-    snapshot::Snapshot,
+    snapshot::{ReadOptions, Snapshot},
     store::{
         multistore::{self, MultistoreConfig},
-        substore::{SubstoreConfig, SubstoreSnapshot, SubstoreStorage},
+        substore::{
+            commit_substores, DbNodeKey, HistoryKey, SubstoreConfig, SubstoreSnapshot,
+            SubstoreStorage,
+        },
     },
 };
-use crate::{snapshot_cache::SnapshotCache, StagedWriteBatch, StateDelta};
+use crate::{read_cache::ReadCache, snapshot_cache::SnapshotCache, StagedWriteBatch, StateDelta};
+
+#[cfg(feature = "metrics")]
+use crate::metrics;
 
+mod changes;
+mod durability;
+mod options;
 mod temp;
+pub use changes::KeyChange;
+pub use durability::Durability;
+pub use options::{CompactionStyle, StorageOptions};
 pub use temp::TempStorage;
 
 /// A handle for a storage instance, backed by RocksDB.
@@ -38,17 +57,38 @@ struct Inner {
     dispatcher_tx: watch::Sender<(Snapshot, (jmt::Version, Arc<Cache>))>,
     snapshot_rx: watch::Receiver<Snapshot>,
     changes_rx: watch::Receiver<(jmt::Version, Arc<Cache>)>,
+    /// Sends the changes of every committed version to watchers, see [`Storage::watch`].
+    watch_tx: broadcast::Sender<(jmt::Version, Arc<Cache>)>,
     snapshots: RwLock<SnapshotCache>,
     multistore_config: MultistoreConfig,
     /// A handle to the dispatcher task.
     /// This is used by `Storage::release` to wait for the task to terminate.
     jh_dispatcher: Option<tokio::task::JoinHandle<()>>,
     db: Arc<DB>,
+    durability: Durability,
+    /// The latest version known to be written to disk, with [`Durability::WriteBehind`].
+    durable_version: Arc<AtomicU64>,
+    /// Held while versions are written to disk, so only one write happens at a time.
+    flush_lock: Arc<tokio::sync::Mutex<()>>,
+    /// How the snapshots of this storage serve and trace reads.
+    read_options: ReadOptions,
+    /// The pool commits update trees on, if any, see [`StorageOptions::commit_threads`].
+    commit_pool: Option<Arc<rayon::ThreadPool>>,
 }
 
 impl Storage {
     /// Loads a storage instance from the given path, initializing it if necessary.
     pub async fn load(path: PathBuf, default_prefixes: Vec<String>) -> Result<Self> {
+        Storage::load_with_options(path, default_prefixes, StorageOptions::default()).await
+    }
+
+    /// Loads a storage instance from the given path, like [`Storage::load`],
+    /// with the supplied options.
+    pub async fn load_with_options(
+        path: PathBuf,
+        default_prefixes: Vec<String>,
+        options: StorageOptions,
+    ) -> Result<Self> {
         let span = Span::current();
         let db_path = path.clone();
         // initializing main storage instance.
@@ -96,7 +136,7 @@ impl Storage {
         })
         .await?;
 
-        Storage::init(db_path, prefixes).await
+        Storage::open(db_path, prefixes, None, options).await
     }
 
     /// Initializes a new storage instance at the given path. Takes a list of default prefixes
@@ -108,6 +148,43 @@ impl Storage {
     /// 4. Initialize the substore cache with the latest version of each substore.
     /// 5. Spawn a dispatcher task that forwards new snapshots to subscribers.
     pub async fn init(path: PathBuf, prefixes: Vec<String>) -> Result<Self> {
+        Storage::open(path, prefixes, None, StorageOptions::default()).await
+    }
+
+    /// Creates a new, empty storage instance held entirely in memory, with the
+    /// given substore prefixes.
+    ///
+    /// The instance behaves like one loaded from disk, but nothing is written
+    /// to the filesystem, and its data is lost once it's dropped. This makes it
+    /// suited to tests and simulations running through many blocks.
+    pub async fn new_in_memory(prefixes: Vec<String>) -> Result<Self> {
+        let mut unique_prefixes = Vec::new();
+        for prefix in prefixes {
+            if !unique_prefixes.contains(&prefix) {
+                unique_prefixes.push(prefix);
+            }
+        }
+        // RocksDB's in-memory environment holds the files of the database,
+        // so the path only names the database within it.
+        let env = rocksdb::Env::mem_env()?;
+        Storage::open(
+            PathBuf::from("/cnidarium"),
+            unique_prefixes,
+            Some(env),
+            StorageOptions::default(),
+        )
+        .await
+    }
+
+    /// Opens the RocksDB instance at the given path, in the supplied
+    /// environment if any, and initializes storage on top of it. See
+    /// [`Storage::init`].
+    async fn open(
+        path: PathBuf,
+        prefixes: Vec<String>,
+        env: Option<rocksdb::Env>,
+        options: StorageOptions,
+    ) -> Result<Self> {
         let span = Span::current();
 
         tokio::task
@@ -144,9 +221,21 @@ impl Storage {
                     let mut opts = Options::default();
                     opts.create_if_missing(true);
                     opts.create_missing_column_families(true);
+                    if let Some(env) = &env {
+                        opts.set_env(env);
+                    }
+                    // Without the write-ahead log, column families must be flushed together,
+                    // so that a crash leaves the substores at the same version.
+                    if options.durability != Durability::WriteAheadLog {
+                        opts.set_atomic_flush(true);
+                    }
                     columns.push(&cf_config_string);
 
-                    let db = DB::open_cf(&opts, path, columns)?;
+                    let cf_opts = options.column_family_options();
+                    let columns = columns
+                        .into_iter()
+                        .map(|column| ColumnFamilyDescriptor::new(column, cf_opts.clone()));
+                    let db = DB::open_cf_descriptors(&opts, path, columns)?;
                     let shared_db = Arc::new(db);
 
                     // Initialize the substore cache with the latest version of each substore.
@@ -175,8 +264,29 @@ impl Storage {
                     multistore_cache.set_version(main_store, jmt_version);
                     tracing::debug!(?jmt_version, "initializing main store");
 
-                    let latest_snapshot =
-                        Snapshot::new(shared_db.clone(), jmt_version, multistore_cache);
+                    let read_options = ReadOptions {
+                        cache: options
+                            .read_cache_size
+                            .map(|size| Arc::new(ReadCache::new(size))),
+                        slow_threshold: options.slow_read_threshold,
+                    };
+                    let latest_snapshot = Snapshot::new(
+                        shared_db.clone(),
+                        jmt_version,
+                        multistore_cache,
+                        read_options.clone(),
+                    );
+
+                    let commit_pool = options
+                        .commit_threads
+                        .map(|threads| {
+                            rayon::ThreadPoolBuilder::new()
+                                .num_threads(threads)
+                                .thread_name(|i| format!("cnidarium-commit-{i}"))
+                                .build()
+                        })
+                        .transpose()?
+                        .map(Arc::new);
 
                     // A concurrent-safe ring buffer of the latest 10 snapshots.
                     let snapshots = RwLock::new(SnapshotCache::new(latest_snapshot.clone(), 10));
@@ -200,6 +310,11 @@ impl Storage {
                     let (changes_tx, changes_rx) = watch::channel(dummy_cache.clone());
                     let (tx_dispatcher, mut rx_dispatcher) = watch::channel((latest_snapshot, dummy_cache));
 
+                    // Unlike the watch channels, which only hold the latest value, watchers
+                    // receive the changes of every version, so they are sent on a broadcast
+                    // channel straight from the commit; sending never waits on receivers.
+                    let (watch_tx, _) = broadcast::channel(changes::WATCH_CAPACITY);
+
                     let jh_dispatcher = tokio::spawn(async move {
                         tracing::info!("snapshot dispatcher task has started");
                         // If the sender is dropped, the task will terminate.
@@ -222,9 +337,15 @@ impl Storage {
                         dispatcher_tx: tx_dispatcher,
                         snapshot_rx,
                         changes_rx,
+                        watch_tx,
                         multistore_config,
                         snapshots,
                         db: shared_db,
+                        durability: options.durability,
+                        read_options,
+                        commit_pool,
+                        durable_version: Arc::new(AtomicU64::new(jmt_version)),
+                        flush_lock: Default::default(),
                     })))
                 })
             })
@@ -257,15 +378,104 @@ impl Storage {
         rx
     }
 
+    /// Streams the changes to verifiable keys starting with `prefix`, from each
+    /// version committed after the call.
+    ///
+    /// Changes are yielded in commit order, and in key order within a version. A
+    /// watcher that falls too far behind the commits gets an error in place of
+    /// the changes it missed; the commits themselves are never held up by it.
+    pub fn watch(
+        &self,
+        prefix: &str,
+    ) -> impl Stream<Item = Result<KeyChange<String>>> + Send + 'static {
+        let prefix = prefix.to_string();
+        changes::watch(self.0.watch_tx.subscribe(), move |cache| {
+            cache
+                .unwritten_changes
+                .range(prefix.clone()..)
+                .take_while(|(key, _)| key.starts_with(&prefix))
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect()
+        })
+    }
+
+    /// Streams the changes to nonverifiable keys starting with `prefix`, from
+    /// each version committed after the call, like [`Storage::watch`].
+    pub fn nonverifiable_watch(
+        &self,
+        prefix: &[u8],
+    ) -> impl Stream<Item = Result<KeyChange<Vec<u8>>>> + Send + 'static {
+        let prefix = prefix.to_vec();
+        changes::watch(self.0.watch_tx.subscribe(), move |cache| {
+            cache
+                .nonverifiable_changes
+                .range(prefix.clone()..)
+                .take_while(|(key, _)| key.starts_with(&prefix))
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect()
+        })
+    }
+
     /// Returns a new [`Snapshot`] on top of the latest version of the tree.
     pub fn latest_snapshot(&self) -> Snapshot {
         self.0.snapshots.read().latest()
     }
 
-    /// Fetches the [`Snapshot`] corresponding to the supplied `jmt::Version` from
-    /// the [`SnapshotCache`]. Returns `None` if no match was found.
+    /// Fetches the [`Snapshot`] corresponding to the supplied `jmt::Version`.
+    ///
+    /// Recent versions are served from the [`SnapshotCache`]. Older versions
+    /// are read from the database, as long as they haven't been pruned, and
+    /// were committed by a version of this crate that records the versions of
+    /// substores. Returns `None` if the version can't be read.
     pub fn snapshot(&self, version: jmt::Version) -> Option<Snapshot> {
-        self.0.snapshots.read().get(version)
+        if let Some(snapshot) = self.0.snapshots.read().get(version) {
+            return Some(snapshot);
+        }
+        if version == u64::MAX || version > self.latest_version() {
+            return None;
+        }
+
+        match self.historical_snapshot(version) {
+            Ok(snapshot) => snapshot,
+            Err(error) => {
+                tracing::warn!(
+                    ?error,
+                    version,
+                    "failed to open a past version of the state"
+                );
+                None
+            }
+        }
+    }
+
+    /// Opens a [`Snapshot`] of a past version, with the versions of the
+    /// substores recorded when it was committed.
+    fn historical_snapshot(&self, version: jmt::Version) -> Result<Option<Snapshot>> {
+        let db = self.0.db.clone();
+        let mut multistore_cache =
+            multistore::MultistoreCache::from_config(self.0.multistore_config.clone());
+        let snapshot = Snapshot::new(
+            db.clone(),
+            version,
+            Default::default(),
+            self.0.read_options.clone(),
+        );
+
+        // The versions are read through the RocksDB snapshot, so that pruning
+        // them (and the data they point to) after the fact doesn't affect it.
+        let stores = std::iter::once(&self.0.multistore_config.main_store)
+            .chain(self.0.multistore_config.iter());
+        for config in stores {
+            let Some(store_version) = config.version_at(&db, &snapshot.0.snapshot, version)? else {
+                return Ok(None);
+            };
+            multistore_cache.set_version(config.clone(), store_version);
+        }
+
+        Ok(Some(Snapshot(Arc::new(crate::snapshot::Inner {
+            multistore_cache,
+            ..Arc::into_inner(snapshot.0).expect("the snapshot was not shared")
+        }))))
     }
 
     /// Prepares a commit for the provided [`StateDelta`], returning a [`StagedWriteBatch`].
@@ -300,6 +510,9 @@ impl Storage {
         perform_migration: bool,
     ) -> Result<StagedWriteBatch> {
         tracing::debug!(new_jmt_version = ?version, "preparing to commit state delta");
+        let _start = std::time::Instant::now();
+        #[cfg(feature = "metrics")]
+        record_writes(&cache);
         // Save a copy of the changes to send to subscribers later.
         let changes = Arc::new(cache.clone_changes());
 
@@ -314,31 +527,16 @@ impl Storage {
 
         let mut new_versions = vec![];
 
-        // We use a single write batch to commit all the substores at once. Each task will append
-        // its own changes to the batch, and we will commit it at the end.
-        let mut write_batch = rocksdb::WriteBatch::default();
+        // We use a single write batch to commit all the substores at once. Each substore
+        // appends its own changes to the batch, and we will commit it at the end.
+        let write_batch = rocksdb::WriteBatch::default();
 
-        //  Note(erwan): Here, we spawn a commit task for each substore.
-        //  The substore keyspaces are disjoint, so conceptually it is
-        //  fine to rewrite it using a [`tokio::task::JoinSet`].
-        //  The reason this isn't done is because `rocksdb::WriteBatch`
-        //  is _not_ thread-safe.
-        //
-        //  This means that to spin-up N tasks, we would need to use a
-        //  single batch wrapped in a mutex, or use N batches, and find
-        //  a way to commit to them atomically. This isn't supported by
-        //  RocksDB which leaves one option: to iterate over each entry
-        //  in each batch, and merge them together. At this point, this
-        //  is probably not worth it.
-        //
-        //  Another option is to trade atomicity for parallelism by producing
-        //  N batches, and committing them in distinct atomic writes. This is
-        //  potentially faster, but it is also more dangerous, because if one
-        //  of the writes fails, we are left with a partially committed state.
-        //
-        //  The current implementation leans on the fact that the number of
-        //  substores is small, and that the synchronization overhead of a joinset
-        //  would exceed its benefits. This works well for now.
+        // The trees of the substores are updated together, in parallel if there
+        // is a commit pool, and then added to the write batch one at a time, since
+        // a `rocksdb::WriteBatch` can't be shared. The main store is updated last,
+        // as it commits to the root hash of each substore.
+        let mut substore_commits = Vec::new();
+        let mut committed_substores = Vec::new();
         for config in self.0.multistore_config.iter() {
             tracing::debug!(substore_prefix = ?config.prefix, "processing substore");
             // If the substore is empty, we need to fetch its initialized version from the cache.
@@ -371,13 +569,21 @@ impl Storage {
             };
 
             let substore_storage = SubstoreStorage { substore_snapshot };
+            substore_commits.push((substore_storage, changeset, new_version));
+            committed_substores.push((config.clone(), new_version));
+        }
 
-            // Commit the substore and collect its root hash
-            let (root_hash, substore_batch) = substore_storage
-                .commit(changeset, write_batch, new_version, perform_migration)
-                .await?;
-            write_batch = substore_batch;
+        // Commit the substores and collect their root hashes
+        let (root_hashes, mut write_batch) = commit_substores(
+            substore_commits,
+            write_batch,
+            version,
+            perform_migration,
+            self.0.commit_pool.clone(),
+        )
+        .await?;
 
+        for ((config, new_version), root_hash) in committed_substores.into_iter().zip(root_hashes) {
             tracing::debug!(
                 ?root_hash,
                 prefix = config.prefix,
@@ -392,7 +598,7 @@ impl Storage {
                 ?new_version,
                 "updating substore version"
             );
-            multistore_versions.set_version(config.clone(), new_version);
+            multistore_versions.set_version(config, new_version);
         }
 
         // Add substore roots to the main store changeset
@@ -422,9 +628,15 @@ impl Storage {
             substore_snapshot: main_store_snapshot,
         };
 
-        let (global_root_hash, write_batch) = main_store_storage
-            .commit(main_store_changes, write_batch, version, perform_migration)
-            .await?;
+        let (root_hashes, mut write_batch) = commit_substores(
+            vec![(main_store_storage, main_store_changes, version)],
+            write_batch,
+            version,
+            perform_migration,
+            self.0.commit_pool.clone(),
+        )
+        .await?;
+        let global_root_hash = root_hashes[0];
         tracing::debug!(
             ?global_root_hash,
             ?version,
@@ -435,6 +647,24 @@ impl Storage {
         let main_store_config = self.0.multistore_config.main_store.clone();
         multistore_versions.set_version(main_store_config, version);
 
+        // Record the version of every store at this version, so that it can
+        // be read later on, even once it's no longer in the snapshot cache.
+        for (config, substore_version) in &multistore_versions.substores {
+            write_batch.put_cf(
+                config.cf_versions(&self.0.db),
+                version.to_be_bytes(),
+                substore_version.to_be_bytes(),
+            );
+        }
+        // Record that the writes of this version can be rolled back.
+        write_batch.put_cf(
+            self.0.multistore_config.main_store.cf_history(&self.0.db),
+            HistoryKey::Version(version).encode(),
+            b"",
+        );
+
+        #[cfg(feature = "metrics")]
+        metrics::histogram!(metrics::STORAGE_PREPARE_COMMIT_DURATION).record(_start.elapsed());
         Ok(StagedWriteBatch {
             write_batch,
             version,
@@ -535,7 +765,24 @@ impl Storage {
 
         tracing::debug!(new_jmt_version = ?batch.version, "committing batch to db");
 
-        db.write(write_batch).expect("can write to db");
+        let _start = std::time::Instant::now();
+        if perform_migration || self.0.durability == Durability::WriteAheadLog {
+            db.write(write_batch).expect("can write to db");
+        } else {
+            // The version is written to disk later on, see [`Durability::WriteBehind`].
+            let mut write_opts = rocksdb::WriteOptions::default();
+            write_opts.disable_wal(true);
+            db.write_opt(write_batch, &write_opts)
+                .expect("can write to db");
+        }
+        #[cfg(feature = "metrics")]
+        metrics::histogram!(metrics::STORAGE_COMMIT_BATCH_DURATION).record(_start.elapsed());
+        if perform_migration {
+            // A migration rewrites the current versions, so their cached reads are stale.
+            if let Some(read_cache) = &self.0.read_options.cache {
+                read_cache.clear();
+            }
+        }
         tracing::debug!(
             ?global_root_hash,
             ?version,
@@ -546,7 +793,12 @@ impl Storage {
         if !perform_migration {
             tracing::debug!("updating snapshot cache");
 
-            let latest_snapshot = Snapshot::new(db.clone(), version, multistore_versions);
+            let latest_snapshot = Snapshot::new(
+                db.clone(),
+                version,
+                multistore_versions,
+                self.0.read_options.clone(),
+            );
             // Obtain a write lock to the snapshot cache, and push the latest snapshot
             // available. The lock guard is implicitly dropped immediately.
             self.0
@@ -559,10 +811,31 @@ impl Storage {
 
             // Send fails if the channel is closed (i.e., if there are no receivers);
             // in this case, we should ignore the error, we have no one to notify.
+            let _ = self.0.watch_tx.send((version, changes.clone()));
             let _ = self
                 .0
                 .dispatcher_tx
                 .send((latest_snapshot, (version, changes)));
+
+            let durable_version = self.0.durable_version.load(Ordering::Acquire);
+            if self.0.durability.flush_due(version, durable_version) {
+                // If versions are already being written, the next commit tries again.
+                if let Ok(guard) = self.0.flush_lock.clone().try_lock_owned() {
+                    tracing::debug!(?version, "writing committed versions to disk");
+                    let flush = durability::flush(
+                        db.clone(),
+                        self.0.multistore_config.clone(),
+                        self.0.durable_version.clone(),
+                        version,
+                        guard,
+                    );
+                    tokio::spawn(async move {
+                        if let Err(error) = flush.await {
+                            tracing::error!(?error, "failed to write committed versions to disk");
+                        }
+                    });
+                }
+            }
         } else {
             tracing::debug!("skipping snapshot cache update");
         }
@@ -582,6 +855,266 @@ impl Storage {
         self.commit_batch(batch)
     }
 
+    /// Returns the latest version written to disk, which survives a crash.
+    ///
+    /// With [`Durability::WriteBehind`], the versions committed after it are
+    /// only held in memory until the next write to disk.
+    pub fn durable_version(&self) -> jmt::Version {
+        match self.0.durability {
+            Durability::WriteAheadLog => self.latest_version(),
+            Durability::WriteBehind { .. } => self.0.durable_version.load(Ordering::Acquire),
+        }
+    }
+
+    /// Writes every version committed so far to disk, returning once they're
+    /// durable. This is a no-op unless storage was loaded with
+    /// [`Durability::WriteBehind`].
+    pub async fn flush(&self) -> Result<()> {
+        if self.0.durability == Durability::WriteAheadLog {
+            return Ok(());
+        }
+        let guard = self.0.flush_lock.clone().lock_owned().await;
+        durability::flush(
+            self.0.db.clone(),
+            self.0.multistore_config.clone(),
+            self.0.durable_version.clone(),
+            self.latest_version(),
+            guard,
+        )
+        .await
+    }
+
+    /// Prunes the data that is only needed to read versions of the tree older
+    /// than `version`, and compacts the database to reclaim the space it took.
+    ///
+    /// Versions from `version` onwards stay readable, as do the [`Snapshot`]s
+    /// that are still held when pruning, whatever their version: RocksDB
+    /// snapshots keep seeing deleted data until they are released, so the
+    /// space it takes is only reclaimed by a later compaction.
+    ///
+    /// Only data that became stale in versions committed with pruning support
+    /// can be pruned; data from earlier versions is kept.
+    pub async fn prune_to(&self, version: jmt::Version) -> Result<()> {
+        let latest_version = self.latest_version();
+        if latest_version == u64::MAX {
+            // There is nothing to prune before genesis.
+            return Ok(());
+        }
+        ensure!(
+            version <= latest_version,
+            "can't prune to version {}, past the latest version {}",
+            version,
+            latest_version
+        );
+        // Pruning is written to the write-ahead log, so it must not outlive the
+        // versions it builds on in a crash.
+        self.flush().await?;
+
+        let span = Span::current();
+        let db = self.0.db.clone();
+        let multistore_config = self.0.multistore_config.clone();
+        tokio::task::spawn_blocking(move || {
+            span.in_scope(|| {
+                let configs = multistore_config
+                    .iter()
+                    .chain(std::iter::once(&multistore_config.main_store));
+
+                let mut write_batch = rocksdb::WriteBatch::default();
+                let mut pruned = 0;
+                for config in configs.clone() {
+                    pruned += config.prune(&db, version, &mut write_batch)?;
+                }
+                db.write(write_batch)?;
+                tracing::debug!(?version, pruned, "pruned stale data");
+
+                for config in configs {
+                    config.compact_pruned(&db);
+                }
+                tracing::info!(?version, "pruned and compacted storage");
+
+                Ok(())
+            })
+        })
+        .await?
+    }
+
+    /// Rolls the storage back to `version`, undoing the versions committed
+    /// after it, so that the next version committed is `version + 1`.
+    ///
+    /// Only versions committed with rollback support can be undone, and
+    /// `version` must not have been pruned. [`Snapshot`]s of the undone
+    /// versions that are still held keep reading them.
+    pub async fn rollback_to(&self, version: jmt::Version) -> Result<()> {
+        let latest_version = self.latest_version();
+        ensure!(
+            latest_version != u64::MAX && version < latest_version,
+            "can't roll back to version {}, which is not before the latest version {}",
+            version,
+            latest_version
+        );
+        // The rollback is written to the write-ahead log, so the versions it
+        // undoes must be on disk first.
+        self.flush().await?;
+        let snapshot = self
+            .historical_snapshot(version)?
+            .with_context(|| format!("can't roll back to version {version}, which was pruned"))?;
+        let multistore_versions = snapshot.0.multistore_cache.clone();
+
+        let span = Span::current();
+        let db = self.0.db.clone();
+        let multistore_config = self.0.multistore_config.clone();
+        let store_versions = multistore_versions.substores.clone();
+        tokio::task::spawn_blocking(move || {
+            span.in_scope(|| {
+                let cf_history = multistore_config.main_store.cf_history(&db);
+                for undone in (version + 1)..=latest_version {
+                    ensure!(
+                        db.get_cf(cf_history, HistoryKey::Version(undone).encode())?
+                            .is_some(),
+                        "can't roll back version {undone}, which was committed without rollback support"
+                    );
+                }
+
+                let mut write_batch = rocksdb::WriteBatch::default();
+                for (config, store_version) in &store_versions {
+                    config.roll_back(&db, version, *store_version, &mut write_batch)?;
+                }
+                db.write(write_batch)?;
+                Ok(())
+            })
+        })
+        .await??;
+        tracing::info!(
+            ?version,
+            undone = latest_version - version,
+            "rolled back storage"
+        );
+
+        // The undone versions of the substores will be committed again, with other values.
+        if let Some(read_cache) = &self.0.read_options.cache {
+            read_cache.clear();
+        }
+        self.0.durable_version.store(version, Ordering::Release);
+        let latest_snapshot = Snapshot::new(
+            self.0.db.clone(),
+            version,
+            multistore_versions,
+            self.0.read_options.clone(),
+        );
+        self.0.snapshots.write().restart(latest_snapshot.clone());
+        let _ = self
+            .0
+            .dispatcher_tx
+            .send((latest_snapshot, (version, Default::default())));
+
+        Ok(())
+    }
+
+    /// Commits `delta`, holding a version of the state restored from an
+    /// [`export`](crate::export), as `version` of this storage, which must be
+    /// empty.
+    ///
+    /// Nothing is committed unless the restored state has `root_hash`.
+    pub async fn restore(
+        &self,
+        delta: StateDelta<Snapshot>,
+        version: jmt::Version,
+        root_hash: crate::RootHash,
+    ) -> Result<()> {
+        ensure!(
+            self.latest_version() == u64::MAX,
+            "can only restore into empty storage"
+        );
+        ensure!(version != u64::MAX, "can't restore the pre-genesis version");
+        let (_, changes) = delta.flatten();
+        let db = self.0.db.clone();
+
+        // The JMT writes each version on top of the previous one, so the main store is restored
+        // on top of an empty tree at the version before. This is how the JMT writes version 0.
+        let main_store = self.0.multistore_config.main_store.clone();
+        let empty_root_key = match version.checked_sub(1) {
+            Some(previous_version) => {
+                let key = DbNodeKey::encode_from_node_key(&jmt::storage::NodeKey::new_empty_path(
+                    previous_version,
+                ))?;
+                db.put_cf(
+                    main_store.cf_jmt(&db),
+                    &key,
+                    borsh::to_vec(&jmt::storage::Node::Null)?,
+                )?;
+                Some(key)
+            }
+            None => None,
+        };
+
+        // The snapshot of the empty storage must include the empty tree.
+        let mut multistore_cache =
+            multistore::MultistoreCache::from_config(self.0.multistore_config.clone());
+        for config in self
+            .0
+            .multistore_config
+            .iter()
+            .chain(std::iter::once(&main_store))
+        {
+            multistore_cache.set_version(config.clone(), u64::MAX);
+        }
+        let snapshot = Snapshot::new(
+            db.clone(),
+            u64::MAX,
+            multistore_cache,
+            ReadOptions::default(),
+        );
+
+        let batch = match self
+            .prepare_commit_inner(snapshot, changes, version, false)
+            .await
+        {
+            Ok(batch) if batch.root_hash == root_hash => batch,
+            prepared => {
+                // Leave the storage empty, as it was.
+                if let Some(key) = empty_root_key {
+                    db.delete_cf(main_store.cf_jmt(&db), key)?;
+                }
+                let batch = prepared?;
+                bail!(
+                    "restored state has root hash {}, but {} was expected",
+                    hex::encode(batch.root_hash.0),
+                    hex::encode(root_hash.0)
+                );
+            }
+        };
+
+        let StagedWriteBatch {
+            write_batch,
+            multistore_versions,
+            changes,
+            ..
+        } = batch;
+        db.write(write_batch)?;
+        tracing::info!(?version, root_hash = %hex::encode(root_hash.0), "restored state");
+
+        // Restoring starts a new history of versions, which snapshots of the empty storage can't
+        // be part of.
+        // The restored versions may have been read before, as empty substores.
+        if let Some(read_cache) = &self.0.read_options.cache {
+            read_cache.clear();
+        }
+        let latest_snapshot = Snapshot::new(
+            db,
+            version,
+            multistore_versions,
+            self.0.read_options.clone(),
+        );
+        self.0.snapshots.write().restart(latest_snapshot.clone());
+        let _ = self.0.watch_tx.send((version, changes.clone()));
+        let _ = self
+            .0
+            .dispatcher_tx
+            .send((latest_snapshot, (version, changes)));
+
+        Ok(())
+    }
+
     /// Returns the internal handle to RocksDB, this is useful to test adjacent storage crates.
     #[cfg(test)]
     pub(crate) fn db(&self) -> Arc<DB> {
@@ -609,3 +1142,17 @@ impl Inner {
         }
     }
 }
+
+/// Records the number of keys and bytes written to each store by a commit.
+#[cfg(feature = "metrics")]
+fn record_writes(cache: &Cache) {
+    let bytes = |value: &Option<Vec<u8>>| value.as_ref().map_or(0, |value| value.len() as u64);
+    metrics::counter!(metrics::STORAGE_WRITES, "kind" => "verifiable")
+        .increment(cache.unwritten_changes.len() as u64);
+    metrics::counter!(metrics::STORAGE_WRITTEN_BYTES, "kind" => "verifiable")
+        .increment(cache.unwritten_changes.values().map(bytes).sum());
+    metrics::counter!(metrics::STORAGE_WRITES, "kind" => "nonverifiable")
+        .increment(cache.nonverifiable_changes.len() as u64);
+    metrics::counter!(metrics::STORAGE_WRITTEN_BYTES, "kind" => "nonverifiable")
+        .increment(cache.nonverifiable_changes.values().map(bytes).sum());
+}
@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use futures::{Stream, StreamExt};
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use crate::Cache;
+
+/// The number of committed versions a watcher can fall behind by before it
+/// misses changes.
+pub(super) const WATCH_CAPACITY: usize = 64;
+
+/// A change to a key of the state, committed as part of `version`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyChange<K> {
+    /// The version the change was committed in.
+    pub version: jmt::Version,
+    /// The key that changed.
+    pub key: K,
+    /// The new value of the key, or `None` if it was deleted.
+    pub value: Option<Vec<u8>>,
+}
+
+/// Streams the changes selected by `select` from each version committed after
+/// the call, in order.
+///
+/// If the stream falls behind the commits by more than [`WATCH_CAPACITY`]
+/// versions, it yields an error, then carries on from the oldest version that
+/// it still holds.
+pub(super) fn watch<K, F>(
+    rx: broadcast::Receiver<(jmt::Version, Arc<Cache>)>,
+    select: F,
+) -> impl Stream<Item = Result<KeyChange<K>>> + Send + 'static
+where
+    K: Send + 'static,
+    F: Fn(&Cache) -> Vec<(K, Option<Vec<u8>>)> + Send + 'static,
+{
+    futures::stream::unfold(rx, |mut rx| async move {
+        let item = match rx.recv().await {
+            Ok(changes) => Ok(changes),
+            Err(RecvError::Lagged(missed)) => Err(missed),
+            Err(RecvError::Closed) => return None,
+        };
+        Some((item, rx))
+    })
+    .flat_map(move |item| {
+        let changes = match item {
+            Ok((version, changes)) => select(&changes)
+                .into_iter()
+                .map(|(key, value)| {
+                    Ok(KeyChange {
+                        version,
+                        key,
+                        value,
+                    })
+                })
+                .collect(),
+            Err(missed) => vec![Err(anyhow::anyhow!(
+                "fell behind the committed versions, missing the changes of {missed} of them"
+            ))],
+        };
+        futures::stream::iter(changes)
+    })
+}
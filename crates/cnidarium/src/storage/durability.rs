@@ -0,0 +1,83 @@
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
+
+use anyhow::Result;
+use rocksdb::DB;
+use tokio::sync::OwnedMutexGuard;
+use tracing::Span;
+
+use crate::store::multistore::MultistoreConfig;
+
+/// How the versions committed to a [`Storage`](crate::Storage) are made
+/// durable.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Durability {
+    /// Each version is written to RocksDB's write-ahead log before
+    /// [`Storage::commit`](crate::Storage::commit) returns, so it survives a
+    /// crash of the process.
+    #[default]
+    WriteAheadLog,
+    /// Versions skip the write-ahead log: when
+    /// [`Storage::commit`](crate::Storage::commit) returns, a version is only
+    /// held in memory, where it can be read from. A background task writes
+    /// the versions committed so far to disk every `interval` versions, or
+    /// whenever [`Storage::flush`](crate::Storage::flush) is called.
+    ///
+    /// A crash loses the versions committed since the last write to disk, so
+    /// they must be committed again, e.g. by replaying the blocks that led to
+    /// them.
+    WriteBehind { interval: u64 },
+}
+
+impl Durability {
+    /// Returns whether a write to disk is due, given the latest version
+    /// committed and the latest version written to disk.
+    pub(super) fn flush_due(&self, version: jmt::Version, durable_version: jmt::Version) -> bool {
+        match self {
+            Durability::WriteAheadLog => false,
+            // Before genesis, the durable version is `u64::MAX`, so this counts
+            // every version committed so far.
+            Durability::WriteBehind { interval } => {
+                version.wrapping_sub(durable_version) >= (*interval).max(1)
+            }
+        }
+    }
+}
+
+/// Writes every version committed up to `version` to disk, then records it as
+/// the durable version.
+///
+/// The guard ensures that only one write to disk happens at a time.
+pub(super) async fn flush(
+    db: Arc<DB>,
+    multistore_config: MultistoreConfig,
+    durable_version: Arc<AtomicU64>,
+    version: jmt::Version,
+    _guard: OwnedMutexGuard<()>,
+) -> Result<()> {
+    if durable_version.load(Ordering::Acquire) == version {
+        return Ok(());
+    }
+
+    let span = Span::current();
+    tokio::task::spawn_blocking(move || {
+        span.in_scope(|| {
+            let columns: Vec<_> = std::iter::once(&multistore_config.main_store)
+                .chain(multistore_config.iter())
+                .flat_map(|config| config.columns())
+                .filter_map(|column| db.cf_handle(column))
+                .collect();
+            // The database is opened with atomic flushes, so the column
+            // families are written together, and a crash can't leave a
+            // version partially written.
+            db.flush_cfs_opt(&columns, &rocksdb::FlushOptions::default())
+        })
+    })
+    .await??;
+
+    durable_version.store(version, Ordering::Release);
+    tracing::debug!(?version, "wrote committed versions to disk");
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use std::time::Duration;
+
+use rocksdb::{BlockBasedOptions, Cache, DBCompactionStyle, Options};
+
+use super::Durability;
+
+/// Options for loading a [`Storage`](crate::Storage) instance.
+///
+/// The RocksDB options apply to every column family. The unset options keep
+/// RocksDB's defaults.
+#[derive(Clone, Debug, Default)]
+pub struct StorageOptions {
+    /// How the versions committed to storage are made durable.
+    pub durability: Durability,
+    /// The size of the block cache shared by every column family, in bytes.
+    pub block_cache_size: Option<usize>,
+    /// How column families are compacted.
+    pub compaction_style: CompactionStyle,
+    /// If set, a bloom filter with this many bits per key is kept for each
+    /// table, to speed up reads of keys that aren't present.
+    pub bloom_filter_bits_per_key: Option<f64>,
+    /// If set, up to this many reads from the verifiable store are cached, to
+    /// serve repeated reads of the same keys without reading RocksDB.
+    pub read_cache_size: Option<usize>,
+    /// If set, reads from storage taking longer than this are logged, along
+    /// with the first segment of the key read, to find which components are slow.
+    pub slow_read_threshold: Option<Duration>,
+    /// If set, commits update the trees of the substores, and hash the keys
+    /// written to each, in parallel on a pool of this many threads.
+    pub commit_threads: Option<usize>,
+}
+
+/// How RocksDB compacts a column family.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CompactionStyle {
+    /// Leveled compaction, RocksDB's default, which favors reads and space.
+    #[default]
+    Level,
+    /// Universal compaction, which favors writes, at the cost of space.
+    Universal,
+}
+
+impl StorageOptions {
+    /// Returns the RocksDB options of each column family.
+    pub(super) fn column_family_options(&self) -> Options {
+        let mut opts = Options::default();
+        if self.block_cache_size.is_some() || self.bloom_filter_bits_per_key.is_some() {
+            let mut table_opts = BlockBasedOptions::default();
+            if let Some(block_cache_size) = self.block_cache_size {
+                // The options are cloned for each column family, which share the cache.
+                table_opts.set_block_cache(&Cache::new_lru_cache(block_cache_size));
+            }
+            if let Some(bits_per_key) = self.bloom_filter_bits_per_key {
+                table_opts.set_bloom_filter(bits_per_key, false);
+            }
+            opts.set_block_based_table_factory(&table_opts);
+        }
+        opts.set_compaction_style(match self.compaction_style {
+            CompactionStyle::Level => DBCompactionStyle::Level,
+            CompactionStyle::Universal => DBCompactionStyle::Universal,
+        });
+        opts
+    }
+}
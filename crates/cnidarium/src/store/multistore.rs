@@ -199,7 +199,7 @@ impl Default for MultistoreConfig {
 }
 
 /// Tracks the latest version of each substore, and wraps a `MultistoreConfig`.
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct MultistoreCache {
     pub config: MultistoreConfig,
     pub substores: std::collections::BTreeMap<Arc<SubstoreConfig>, jmt::Version>,
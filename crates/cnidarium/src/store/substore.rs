@@ -1,4 +1,5 @@
 use std::{
+    collections::BTreeMap,
     fmt::{Display, Formatter},
     sync::Arc,
 };
@@ -6,9 +7,10 @@ use std::{
 use anyhow::Result;
 use borsh::BorshDeserialize;
 use jmt::{
-    storage::{HasPreimage, LeafNode, Node, NodeKey, TreeReader},
+    storage::{HasPreimage, LeafNode, Node, NodeKey, TreeReader, TreeUpdateBatch},
     KeyHash, RootHash,
 };
+use rayon::prelude::*;
 use rocksdb::{ColumnFamily, IteratorMode, ReadOptions};
 use tracing::Span;
 
@@ -42,11 +44,26 @@ pub struct SubstoreConfig {
     /// role: index JMT keys by their keyhash.
     /// maps: keyhashes to their preimage.
     cf_jmt_keys_by_keyhash: String,
+    /// name: "substore-{prefix}-jmt-stale"
+    /// role: index of the JMT nodes and values that are no longer needed to read
+    /// the tree from some version onwards, used for pruning.
+    /// maps: `StaleIndexKey`s to nothing.
+    cf_jmt_stale: String,
+    /// name: "substore-{prefix}-versions"
+    /// role: index of the version of the substore at each version of the main
+    /// store, used to read past versions of the state.
+    /// maps: BE(main store version) to BE(substore version)
+    cf_versions: String,
     /// name: "substore-{prefix}-nonverifiable"
     /// role: auxiliary data that is not part of our merkle tree, and thus not strictly
     /// part of consensus.
     /// maps: arbitrary keys to arbitrary values.
     cf_nonverifiable: String,
+    /// name: "substore-{prefix}-history"
+    /// role: index of the key index and nonverifiable entries replaced by each
+    /// version, used to roll the substore back to an earlier version.
+    /// maps: `HistoryKey`s to the replaced `Option<Vec<u8>>`
+    cf_history: String,
 }
 
 impl SubstoreConfig {
@@ -57,7 +74,10 @@ impl SubstoreConfig {
             cf_jmt_keys: format!("substore-{}-jmt-keys", prefix),
             cf_jmt_values: format!("substore-{}-jmt-values", prefix),
             cf_jmt_keys_by_keyhash: format!("substore-{}-jmt-keys-by-keyhash", prefix),
+            cf_jmt_stale: format!("substore-{}-jmt-stale", prefix),
+            cf_versions: format!("substore-{}-versions", prefix),
             cf_nonverifiable: format!("substore-{}-nonverifiable", prefix),
+            cf_history: format!("substore-{}-history", prefix),
             prefix_with_delimiter: format!("{}/", prefix),
             prefix,
         }
@@ -70,7 +90,10 @@ impl SubstoreConfig {
             .chain(std::iter::once(&self.cf_jmt_keys))
             .chain(std::iter::once(&self.cf_jmt_values))
             .chain(std::iter::once(&self.cf_jmt_keys_by_keyhash))
+            .chain(std::iter::once(&self.cf_jmt_stale))
+            .chain(std::iter::once(&self.cf_versions))
             .chain(std::iter::once(&self.cf_nonverifiable))
+            .chain(std::iter::once(&self.cf_history))
     }
 
     pub fn cf_jmt<'s>(&self, db_handle: &'s Arc<rocksdb::DB>) -> &'s ColumnFamily {
@@ -113,6 +136,26 @@ impl SubstoreConfig {
         })
     }
 
+    pub fn cf_jmt_stale<'s>(&self, db_handle: &'s Arc<rocksdb::DB>) -> &'s ColumnFamily {
+        let column = self.cf_jmt_stale.as_str();
+        db_handle.cf_handle(column).unwrap_or_else(|| {
+            panic!(
+                "jmt-stale column family not found for prefix: {}, substore: {}",
+                column, self.prefix
+            )
+        })
+    }
+
+    pub fn cf_versions<'s>(&self, db_handle: &'s Arc<rocksdb::DB>) -> &'s ColumnFamily {
+        let column = self.cf_versions.as_str();
+        db_handle.cf_handle(column).unwrap_or_else(|| {
+            panic!(
+                "versions column family not found for prefix: {}, substore: {}",
+                column, self.prefix
+            )
+        })
+    }
+
     pub fn cf_nonverifiable<'s>(&self, db_handle: &'s Arc<rocksdb::DB>) -> &'s ColumnFamily {
         let column = self.cf_nonverifiable.as_str();
         db_handle.cf_handle(column).unwrap_or_else(|| {
@@ -123,6 +166,16 @@ impl SubstoreConfig {
         })
     }
 
+    pub fn cf_history<'s>(&self, db_handle: &'s Arc<rocksdb::DB>) -> &'s ColumnFamily {
+        let column = self.cf_history.as_str();
+        db_handle.cf_handle(column).unwrap_or_else(|| {
+            panic!(
+                "jmt history column family not found for prefix: {}, substore: {}",
+                column, self.prefix
+            )
+        })
+    }
+
     pub fn latest_version_from_db(
         &self,
         db_handle: &Arc<rocksdb::DB>,
@@ -142,6 +195,25 @@ impl SubstoreConfig {
             .map(|(node_key, _)| node_key.version()))
     }
 
+    /// Returns the version of this substore at the given version of the main
+    /// store, or `None` if it wasn't recorded, or was pruned since.
+    pub fn version_at(
+        &self,
+        db_handle: &Arc<rocksdb::DB>,
+        snapshot: &RocksDbSnapshot,
+        main_version: jmt::Version,
+    ) -> Result<Option<jmt::Version>> {
+        let cf_versions = self.cf_versions(db_handle);
+        let Some(raw_version) = snapshot.get_cf(cf_versions, main_version.to_be_bytes())? else {
+            return Ok(None);
+        };
+        let raw_version: [u8; 8] = raw_version
+            .as_slice()
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("substore versions are 8 bytes"))?;
+        Ok(Some(u64::from_be_bytes(raw_version)))
+    }
+
     // TODO(erwan): having two different implementations of this is a bit weird and should
     // be refactored, or remodeled. The DB version is only used during initialization, before
     // a `Snapshot` is available.
@@ -187,6 +259,164 @@ impl SubstoreConfig {
         };
         Ok(Some((node_key, leaf)))
     }
+
+    /// Adds the deletion of the nodes and values of this substore that aren't
+    /// needed to read the tree at `version` or later to `write_batch`, returning
+    /// the number of entries of the stale index that were processed.
+    ///
+    /// `version` is a version of the main store, like the versions in the stale
+    /// index.
+    pub(crate) fn prune(
+        &self,
+        db_handle: &Arc<rocksdb::DB>,
+        version: jmt::Version,
+        write_batch: &mut rocksdb::WriteBatch,
+    ) -> Result<usize> {
+        let cf_jmt = self.cf_jmt(db_handle);
+        let cf_jmt_values = self.cf_jmt_values(db_handle);
+        let cf_jmt_stale = self.cf_jmt_stale(db_handle);
+
+        // Data that became stale at `version` isn't needed to read `version` either.
+        let mut readopts = ReadOptions::default();
+        readopts.set_iterate_upper_bound(version.saturating_add(1).to_be_bytes().to_vec());
+        let mut pruned = 0;
+        for entry in db_handle.iterator_cf_opt(cf_jmt_stale, readopts, IteratorMode::Start) {
+            let (raw_key, _) = entry?;
+            match StaleIndexKey::decode(&raw_key)? {
+                StaleIndexKey::Node { node_key, .. } => {
+                    write_batch.delete_cf(cf_jmt, DbNodeKey::encode_from_node_key(&node_key)?);
+                }
+                StaleIndexKey::Value {
+                    key_hash,
+                    written_at,
+                    ..
+                } => {
+                    // Every value of the key older than the one written then is superseded.
+                    write_batch.delete_range_cf(
+                        cf_jmt_values,
+                        VersionedKeyHash::encode_from_keyhash(&key_hash, &0),
+                        VersionedKeyHash::encode_from_keyhash(&key_hash, &written_at),
+                    );
+                }
+            }
+            write_batch.delete_cf(cf_jmt_stale, raw_key);
+            pruned += 1;
+        }
+
+        // Versions older than `version` can't be read anymore.
+        write_batch.delete_range_cf(
+            self.cf_versions(db_handle),
+            0u64.to_be_bytes(),
+            version.to_be_bytes(),
+        );
+        // Nor rolled back to, so the history of the versions up to `version` isn't needed.
+        write_batch.delete_range_cf(
+            self.cf_history(db_handle),
+            0u64.to_be_bytes(),
+            version.saturating_add(1).to_be_bytes(),
+        );
+
+        Ok(pruned)
+    }
+
+    /// Adds the deletion of everything this substore wrote after `version` of
+    /// the main store, when the substore was at `substore_version`, to
+    /// `write_batch`, restoring its key index and nonverifiable data to what
+    /// they were at `version`.
+    pub(crate) fn roll_back(
+        &self,
+        db_handle: &Arc<rocksdb::DB>,
+        version: jmt::Version,
+        substore_version: jmt::Version,
+        write_batch: &mut rocksdb::WriteBatch,
+    ) -> Result<()> {
+        let cf_jmt_keys = self.cf_jmt_keys(db_handle);
+        let cf_jmt_keys_by_keyhash = self.cf_jmt_keys_by_keyhash(db_handle);
+        let cf_nonverifiable = self.cf_nonverifiable(db_handle);
+        let cf_history = self.cf_history(db_handle);
+
+        // The history is visited from the latest version back, so that the
+        // entry replaced by the earliest write to a key is written last.
+        let mut key_hashes = std::collections::BTreeSet::new();
+        let mut readopts = ReadOptions::default();
+        readopts.set_iterate_lower_bound(version.wrapping_add(1).to_be_bytes().to_vec());
+        for entry in db_handle.iterator_cf_opt(cf_history, readopts, IteratorMode::End) {
+            let (raw_key, raw_value) = entry?;
+            write_batch.delete_cf(cf_history, &raw_key);
+            let history_key = HistoryKey::decode(&raw_key)?;
+            let replaced = || -> Result<Option<Vec<u8>>> {
+                Ok(BorshDeserialize::try_from_slice(raw_value.as_ref())?)
+            };
+            match history_key {
+                HistoryKey::Version(_) => {}
+                HistoryKey::Key { key, .. } => {
+                    let key_hash = KeyHash::with::<sha2::Sha256>(&key);
+                    match replaced()? {
+                        Some(indexed_hash) => {
+                            write_batch.put_cf(cf_jmt_keys, &key, &indexed_hash);
+                            write_batch.put_cf(cf_jmt_keys_by_keyhash, &indexed_hash, &key);
+                        }
+                        None => {
+                            write_batch.delete_cf(cf_jmt_keys, &key);
+                            write_batch.delete_cf(cf_jmt_keys_by_keyhash, key_hash.0);
+                        }
+                    }
+                    key_hashes.insert(key_hash.0);
+                }
+                HistoryKey::Nonverifiable { key, .. } => match replaced()? {
+                    Some(value) => write_batch.put_cf(cf_nonverifiable, &key, value),
+                    None => write_batch.delete_cf(cf_nonverifiable, &key),
+                },
+            }
+        }
+
+        // Before its first version, the version of a substore is `u64::MAX`, so
+        // everything it wrote is rolled back.
+        let first_rolled_back = substore_version.wrapping_add(1);
+        let cf_jmt_values = self.cf_jmt_values(db_handle);
+        for key_hash in key_hashes {
+            let mut readopts = ReadOptions::default();
+            readopts.set_iterate_lower_bound(VersionedKeyHash::encode_from_keyhash(
+                &KeyHash(key_hash),
+                &first_rolled_back,
+            ));
+            for entry in db_handle.iterator_cf_opt(cf_jmt_values, readopts, IteratorMode::Start) {
+                let (raw_key, _) = entry?;
+                if !raw_key.starts_with(&key_hash) {
+                    break;
+                }
+                write_batch.delete_cf(cf_jmt_values, raw_key);
+            }
+        }
+        for (cf, lower_bound) in [
+            (self.cf_jmt(db_handle), first_rolled_back),
+            // The stale index and the versions are keyed by versions of the main store.
+            (self.cf_jmt_stale(db_handle), version.wrapping_add(1)),
+            (self.cf_versions(db_handle), version.wrapping_add(1)),
+        ] {
+            let mut readopts = ReadOptions::default();
+            readopts.set_iterate_lower_bound(lower_bound.to_be_bytes().to_vec());
+            for entry in db_handle.iterator_cf_opt(cf, readopts, IteratorMode::Start) {
+                let (raw_key, _) = entry?;
+                write_batch.delete_cf(cf, raw_key);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Compacts the column families that pruning deletes from, so that the space
+    /// taken by the pruned data is reclaimed.
+    pub(crate) fn compact_pruned(&self, db_handle: &Arc<rocksdb::DB>) {
+        for cf in [
+            self.cf_jmt(db_handle),
+            self.cf_jmt_values(db_handle),
+            self.cf_jmt_stale(db_handle),
+            self.cf_history(db_handle),
+        ] {
+            db_handle.compact_range_cf::<&[u8], &[u8]>(cf, None, None);
+        }
+    }
 }
 
 impl Display for SubstoreConfig {
@@ -365,92 +595,251 @@ pub struct SubstoreStorage {
     pub(crate) substore_snapshot: SubstoreSnapshot,
 }
 
+/// The changes to the tree of a substore computed for a commit, yet to be
+/// added to a write batch by [`SubstoreStorage::write`].
+pub(crate) struct TreeUpdate {
+    root_hash: RootHash,
+    batch: TreeUpdateBatch,
+    /// The key hash and preimage of each key written, and whether it was deleted.
+    keys: Vec<(KeyHash, String, bool)>,
+    nonverifiable_changes: BTreeMap<Vec<u8>, Option<Vec<u8>>>,
+}
+
+/// Adds the changes of each substore in `commits`, made as the given version
+/// of the substore, to `write_batch`, as part of `main_version` of the main
+/// store. Returns the root hash of each substore, in order.
+///
+/// Updating the tree of a substore doesn't depend on the others, so if a
+/// `pool` is set, the trees are updated on it in parallel, and the keys of
+/// each substore are hashed in parallel too. The changes are then added to
+/// the write batch one substore at a time, since it can't be shared.
+pub(crate) async fn commit_substores(
+    commits: Vec<(SubstoreStorage, Cache, jmt::Version)>,
+    mut write_batch: rocksdb::WriteBatch,
+    main_version: jmt::Version,
+    perform_migration: bool,
+    pool: Option<Arc<rayon::ThreadPool>>,
+) -> Result<(Vec<RootHash>, rocksdb::WriteBatch)> {
+    let span = Span::current();
+
+    tokio::task::spawn_blocking(move || {
+        span.in_scope(|| {
+            let pool = pool.as_deref();
+            let update = |commit: (SubstoreStorage, Cache, jmt::Version)| -> Result<_> {
+                let (storage, cache, write_version) = commit;
+                let tree_update =
+                    storage.update_tree(cache, write_version, perform_migration, pool)?;
+                Ok((storage, tree_update))
+            };
+            let updates: Vec<(SubstoreStorage, TreeUpdate)> = match pool {
+                Some(pool) => {
+                    pool.install(|| commits.into_par_iter().map(update).collect::<Result<_>>())?
+                }
+                None => commits.into_iter().map(update).collect::<Result<_>>()?,
+            };
+
+            let mut root_hashes = Vec::with_capacity(updates.len());
+            for (storage, update) in updates {
+                root_hashes.push(storage.write(
+                    update,
+                    &mut write_batch,
+                    main_version,
+                    perform_migration,
+                )?);
+            }
+            Ok((root_hashes, write_batch))
+        })
+    })
+    .await?
+}
+
 impl SubstoreStorage {
-    pub async fn commit(
-        self,
+    /// Computes the changes to the tree of the substore for the changes in
+    /// `cache`, as `write_version` of the substore, hashing keys on `pool` if
+    /// it is set.
+    fn update_tree(
+        &self,
         cache: Cache,
-        mut write_batch: rocksdb::WriteBatch,
         write_version: jmt::Version,
         perform_migration: bool,
-    ) -> Result<(RootHash, rocksdb::WriteBatch)> {
-        let span = Span::current();
-
-        tokio::task
-                ::spawn_blocking(move || {
-                    span.in_scope(|| {
-                        let jmt = jmt::Sha256Jmt::new(&self.substore_snapshot);
-                        let unwritten_changes: Vec<_> = cache
-                            .unwritten_changes
-                            .into_iter()
-                            .map(|(key, some_value)| (KeyHash::with::<sha2::Sha256>(&key), key, some_value))
-                            .collect();
-
-                        let cf_jmt_keys = self.substore_snapshot.config.cf_jmt_keys(&self.substore_snapshot.db);
-                        let cf_jmt_keys_by_keyhash = self.substore_snapshot.config.cf_jmt_keys_by_keyhash(&self.substore_snapshot.db);
-                        let cf_jmt = self.substore_snapshot.config.cf_jmt(&self.substore_snapshot.db);
-                        let cf_jmt_values = self.substore_snapshot.config.cf_jmt_values(&self.substore_snapshot.db);
-
-                        /* Keyhash and pre-image indices */
-                        for (keyhash, key_preimage, value) in unwritten_changes.iter() {
-                            match value {
-                                Some(_) => { /* Key inserted, or updated, so we add it to the keyhash index */
-                                    write_batch.put_cf(cf_jmt_keys, key_preimage, keyhash.0);
-                                        write_batch
-                                        .put_cf(cf_jmt_keys_by_keyhash, keyhash.0, key_preimage)
-                                }
-                                None => { /* Key deleted, so we delete it from the preimage and keyhash index entries */
-                                    write_batch.delete_cf(cf_jmt_keys, key_preimage);
-                                    write_batch.delete_cf(cf_jmt_keys_by_keyhash, keyhash.0);
-                                }
-                            };
-                        }
+        pool: Option<&rayon::ThreadPool>,
+    ) -> Result<TreeUpdate> {
+        let jmt = jmt::Sha256Jmt::new(&self.substore_snapshot);
+        let hash = |(key, some_value): (String, Option<Vec<u8>>)| {
+            (KeyHash::with::<sha2::Sha256>(&key), key, some_value)
+        };
+        let unwritten_changes: Vec<_> = match pool {
+            Some(pool) => {
+                pool.install(|| cache.unwritten_changes.into_par_iter().map(hash).collect())
+            }
+            None => cache.unwritten_changes.into_iter().map(hash).collect(),
+        };
 
-                        // We only track the keyhash and possible values; at the time of writing,
-                        // `rustfmt` panics on inlining the closure, so we use a helper function to skip the key.
-                        let skip_key = |(keyhash, _key, some_value)| (keyhash, some_value);
-
-                        let (root_hash, batch) = if perform_migration {
-                            jmt.append_value_set(unwritten_changes.into_iter().map(skip_key), write_version)?
-                        } else {
-                            jmt.put_value_set(unwritten_changes.into_iter().map(skip_key), write_version)?
-                        };
-
-                        /* JMT nodes and values */
-                        for (node_key, node) in batch.node_batch.nodes() {
-                            let db_node_key_bytes= DbNodeKey::encode_from_node_key(node_key)?;
-                            let value_bytes = borsh::to_vec(node)?;
-                            tracing::trace!(?db_node_key_bytes, value_bytes = ?hex::encode(&value_bytes));
-                            write_batch.put_cf(cf_jmt, db_node_key_bytes, value_bytes);
-                        }
+        let (keys, value_set): (Vec<_>, Vec<_>) = unwritten_changes
+            .into_iter()
+            .map(|(keyhash, key, some_value)| {
+                ((keyhash, key, some_value.is_none()), (keyhash, some_value))
+            })
+            .unzip();
 
+        let (root_hash, batch) = if perform_migration {
+            jmt.append_value_set(value_set, write_version)?
+        } else {
+            jmt.put_value_set(value_set, write_version)?
+        };
 
-                        for ((version, key_hash), some_value) in batch.node_batch.values() {
-                            let key_bytes = VersionedKeyHash::encode_from_keyhash(key_hash, version);
-                            let value_bytes = borsh::to_vec(some_value)?;
-                            tracing::trace!(?key_bytes, value_bytes = ?hex::encode(&value_bytes));
-                            write_batch.put_cf(cf_jmt_values, key_bytes, value_bytes);
-                        }
+        Ok(TreeUpdate {
+            root_hash,
+            batch,
+            keys,
+            nonverifiable_changes: cache.nonverifiable_changes,
+        })
+    }
 
-                        tracing::trace!(?root_hash, "accumulated node changes in the write batch");
+    /// Adds `update` to `write_batch`, as part of `main_version` of the main
+    /// store, and returns the new root hash of the substore.
+    fn write(
+        self,
+        update: TreeUpdate,
+        write_batch: &mut rocksdb::WriteBatch,
+        main_version: jmt::Version,
+        perform_migration: bool,
+    ) -> Result<RootHash> {
+        let TreeUpdate {
+            root_hash,
+            batch,
+            keys,
+            nonverifiable_changes,
+        } = update;
+        let snapshot = &self.substore_snapshot;
+        let cf_jmt_keys = snapshot.config.cf_jmt_keys(&snapshot.db);
+        let cf_jmt_keys_by_keyhash = snapshot.config.cf_jmt_keys_by_keyhash(&snapshot.db);
+        let cf_jmt = snapshot.config.cf_jmt(&snapshot.db);
+        let cf_jmt_values = snapshot.config.cf_jmt_values(&snapshot.db);
+        let cf_nonverifiable = snapshot.config.cf_nonverifiable(&snapshot.db);
+
+        /* History of the replaced index entries, to roll back to */
+        for (_, key_preimage, _) in keys.iter() {
+            let history_key = HistoryKey::Key {
+                version: main_version,
+                key: key_preimage.as_bytes().to_vec(),
+            };
+            self.record_history(
+                write_batch,
+                history_key,
+                cf_jmt_keys,
+                key_preimage.as_bytes(),
+                perform_migration,
+            )?;
+        }
+        for key in nonverifiable_changes.keys() {
+            let history_key = HistoryKey::Nonverifiable {
+                version: main_version,
+                key: key.clone(),
+            };
+            self.record_history(
+                write_batch,
+                history_key,
+                cf_nonverifiable,
+                key,
+                perform_migration,
+            )?;
+        }
 
+        /* Keyhash and pre-image indices */
+        for (keyhash, key_preimage, deleted) in keys.iter() {
+            if *deleted {
+                /* Key deleted, so we delete it from the preimage and keyhash index entries */
+                write_batch.delete_cf(cf_jmt_keys, key_preimage);
+                write_batch.delete_cf(cf_jmt_keys_by_keyhash, keyhash.0);
+            } else {
+                /* Key inserted, or updated, so we add it to the keyhash index */
+                write_batch.put_cf(cf_jmt_keys, key_preimage, keyhash.0);
+                write_batch.put_cf(cf_jmt_keys_by_keyhash, keyhash.0, key_preimage);
+            }
+        }
 
-                        for (k, v) in cache.nonverifiable_changes.into_iter() {
-                            let cf_nonverifiable = self.substore_snapshot.config.cf_nonverifiable(&self.substore_snapshot.db);
-                            match v {
-                                Some(v) => {
-                                    tracing::trace!(key = ?crate::EscapedByteSlice(&k), value = ?crate::EscapedByteSlice(&v), "put nonverifiable key");
-                                    write_batch.put_cf(cf_nonverifiable, k, &v);
-                                }
-                                None => {
-                                    write_batch.delete_cf(cf_nonverifiable, k);
-                                }
-                            };
-                        }
+        /* JMT nodes and values */
+        for (node_key, node) in batch.node_batch.nodes() {
+            let db_node_key_bytes = DbNodeKey::encode_from_node_key(node_key)?;
+            let value_bytes = borsh::to_vec(node)?;
+            tracing::trace!(?db_node_key_bytes, value_bytes = ?hex::encode(&value_bytes));
+            write_batch.put_cf(cf_jmt, db_node_key_bytes, value_bytes);
+        }
 
-                        Ok((root_hash, write_batch))
-                    })
-                })
-                .await?
+        for ((version, key_hash), some_value) in batch.node_batch.values() {
+            let key_bytes = VersionedKeyHash::encode_from_keyhash(key_hash, version);
+            let value_bytes = borsh::to_vec(some_value)?;
+            tracing::trace!(?key_bytes, value_bytes = ?hex::encode(&value_bytes));
+            write_batch.put_cf(cf_jmt_values, key_bytes, value_bytes);
+        }
+
+        tracing::trace!(?root_hash, "accumulated node changes in the write batch");
+
+        // Migrations rewrite the tree in place, so nothing they replace becomes stale.
+        if !perform_migration {
+            let cf_jmt_stale = snapshot.config.cf_jmt_stale(&snapshot.db);
+            for stale_node in batch.stale_node_index_batch.iter() {
+                let key_bytes = StaleIndexKey::Node {
+                    stale_since: main_version,
+                    node_key: stale_node.node_key.clone(),
+                }
+                .encode()?;
+                write_batch.put_cf(cf_jmt_stale, key_bytes, b"");
+            }
+            for (version, key_hash) in batch.node_batch.values().keys() {
+                let key_bytes = StaleIndexKey::Value {
+                    stale_since: main_version,
+                    key_hash: *key_hash,
+                    written_at: *version,
+                }
+                .encode()?;
+                write_batch.put_cf(cf_jmt_stale, key_bytes, b"");
+            }
+        }
+
+        for (k, v) in nonverifiable_changes.into_iter() {
+            match v {
+                Some(v) => {
+                    tracing::trace!(key = ?crate::EscapedByteSlice(&k), value = ?crate::EscapedByteSlice(&v), "put nonverifiable key");
+                    write_batch.put_cf(cf_nonverifiable, k, &v);
+                }
+                None => {
+                    write_batch.delete_cf(cf_nonverifiable, k);
+                }
+            };
+        }
+
+        Ok(root_hash)
+    }
+
+    /// Records the entry of `cf` at `raw_key` that the write recorded by
+    /// `history_key` replaces, so that it can be restored on rollback.
+    ///
+    /// A migration rewrites a version in place, so the entries it replaces are
+    /// only recorded if the version didn't already replace them.
+    fn record_history(
+        &self,
+        write_batch: &mut rocksdb::WriteBatch,
+        history_key: HistoryKey,
+        cf: &ColumnFamily,
+        raw_key: &[u8],
+        perform_migration: bool,
+    ) -> Result<()> {
+        let snapshot = &self.substore_snapshot;
+        let cf_history = snapshot.config.cf_history(&snapshot.db);
+        let history_key = history_key.encode();
+        if perform_migration
+            && snapshot
+                .rocksdb_snapshot
+                .get_cf(cf_history, &history_key)?
+                .is_some()
+        {
+            return Ok(());
+        }
+        let replaced = snapshot.rocksdb_snapshot.get_cf(cf, raw_key)?;
+        write_batch.put_cf(cf_history, history_key, borsh::to_vec(&replaced)?);
+        Ok(())
     }
 }
 
@@ -523,7 +912,6 @@ impl VersionedKeyHash {
         buf
     }
 
-    #[allow(dead_code)]
     pub fn decode(buf: Vec<u8>) -> Result<Self> {
         if buf.len() != 40 {
             Err(anyhow::anyhow!(
@@ -544,3 +932,131 @@ impl VersionedKeyHash {
         }
     }
 }
+
+/// An entry of the stale index, recording data that isn't needed to read the
+/// tree at versions of the main store from `stale_since` onwards.
+///
+/// Entries are ordered by `stale_since`, so that pruning up to a version only
+/// has to visit the entries it prunes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StaleIndexKey {
+    /// A node that was replaced.
+    Node {
+        stale_since: jmt::Version,
+        node_key: NodeKey,
+    },
+    /// A key that was written to at `written_at`, superseding every older
+    /// value of the key.
+    Value {
+        stale_since: jmt::Version,
+        key_hash: KeyHash,
+        written_at: jmt::Version,
+    },
+}
+
+impl StaleIndexKey {
+    const NODE_TAG: u8 = 0;
+    const VALUE_TAG: u8 = 1;
+
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        let mut buf = Vec::new();
+        match self {
+            StaleIndexKey::Node {
+                stale_since,
+                node_key,
+            } => {
+                buf.extend_from_slice(&stale_since.to_be_bytes());
+                buf.push(Self::NODE_TAG);
+                buf.extend_from_slice(&DbNodeKey::encode_from_node_key(node_key)?);
+            }
+            StaleIndexKey::Value {
+                stale_since,
+                key_hash,
+                written_at,
+            } => {
+                buf.extend_from_slice(&stale_since.to_be_bytes());
+                buf.push(Self::VALUE_TAG);
+                buf.extend_from_slice(&VersionedKeyHash::encode_from_keyhash(key_hash, written_at));
+            }
+        }
+        Ok(buf)
+    }
+
+    pub fn decode(bytes: impl AsRef<[u8]>) -> Result<Self> {
+        let bytes = bytes.as_ref();
+        if bytes.len() < 9 {
+            anyhow::bail!("byte slice is too short")
+        }
+        let stale_since = u64::from_be_bytes(bytes[0..8].try_into().expect("slice is 8 bytes"));
+        match bytes[8] {
+            Self::NODE_TAG => Ok(StaleIndexKey::Node {
+                stale_since,
+                node_key: DbNodeKey::decode(&bytes[9..])?.into_inner(),
+            }),
+            Self::VALUE_TAG => {
+                let VersionedKeyHash { key_hash, version } =
+                    VersionedKeyHash::decode(bytes[9..].to_vec())?;
+                Ok(StaleIndexKey::Value {
+                    stale_since,
+                    key_hash,
+                    written_at: version,
+                })
+            }
+            tag => anyhow::bail!("unknown stale index entry tag {tag}"),
+        }
+    }
+}
+
+/// An entry of the history index, recording what a version of the main store
+/// replaced in the key index and the nonverifiable data of a substore.
+///
+/// Entries are ordered by version, so that rolling back to a version only has
+/// to visit the entries it rolls back.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HistoryKey {
+    /// Marks a version whose writes are recorded in the history of every
+    /// substore. Only written to the history of the main store.
+    Version(jmt::Version),
+    /// The key index entry of a key of the verifiable store, mapping to its keyhash.
+    Key { version: jmt::Version, key: Vec<u8> },
+    /// A key of the nonverifiable store.
+    Nonverifiable { version: jmt::Version, key: Vec<u8> },
+}
+
+impl HistoryKey {
+    const KEY_TAG: u8 = 0;
+    const NONVERIFIABLE_TAG: u8 = 1;
+
+    pub fn encode(&self) -> Vec<u8> {
+        let (version, tagged_key) = match self {
+            HistoryKey::Version(version) => (version, None),
+            HistoryKey::Key { version, key } => (version, Some((Self::KEY_TAG, key))),
+            HistoryKey::Nonverifiable { version, key } => {
+                (version, Some((Self::NONVERIFIABLE_TAG, key)))
+            }
+        };
+        let mut buf = version.to_be_bytes().to_vec();
+        if let Some((tag, key)) = tagged_key {
+            buf.push(tag);
+            buf.extend_from_slice(key);
+        }
+        buf
+    }
+
+    pub fn decode(bytes: impl AsRef<[u8]>) -> Result<Self> {
+        let bytes = bytes.as_ref();
+        if bytes.len() < 8 {
+            anyhow::bail!("byte slice is too short")
+        }
+        let version = u64::from_be_bytes(bytes[0..8].try_into().expect("slice is 8 bytes"));
+        let Some((tag, key)) = bytes[8..].split_first() else {
+            return Ok(HistoryKey::Version(version));
+        };
+        let key = key.to_vec();
+        match *tag {
+            Self::KEY_TAG => Ok(HistoryKey::Key { version, key }),
+            Self::NONVERIFIABLE_TAG => Ok(HistoryKey::Nonverifiable { version, key }),
+            tag => anyhow::bail!("unknown history entry tag {tag}"),
+        }
+    }
+}
@@ -1466,3 +1466,334 @@ async fn range_query_storage() -> anyhow::Result<()> {
     std::mem::drop(range);
     Ok(())
 }
+
+#[tokio::test]
+/// Pruning drops the nodes and values that only older versions need, while
+/// newer versions, and versions held in snapshots, stay readable.
+async fn prune_to_keeps_newer_versions() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let tmpdir = tempfile::tempdir()?;
+    let storage = Storage::load(tmpdir.path().to_owned(), vec!["ibc".to_string()]).await?;
+
+    // Versions 0 to 3 each overwrite a key in the main store and in a substore.
+    for i in 0..4u8 {
+        let mut delta = StateDelta::new(storage.latest_snapshot());
+        delta.put_raw("key".to_string(), vec![i]);
+        delta.put_raw("ibc/key".to_string(), vec![i]);
+        storage.commit(delta).await?;
+    }
+    let held = storage.snapshot(1).expect("version 1 is cached");
+    let root_hash = storage.latest_snapshot().root_hash().await?;
+
+    let db = storage.db();
+    let count = |column: &str| {
+        let cf = db.cf_handle(column).expect("column family exists");
+        db.iterator_cf(cf, rocksdb::IteratorMode::Start).count()
+    };
+    let nodes_before = count("substore--jmt");
+    // The values of `key` and of the root of the `ibc` substore, at each version.
+    assert_eq!(count("substore--jmt-values"), 8);
+
+    storage
+        .prune_to(5)
+        .await
+        .expect_err("can't prune past the latest version");
+    storage.prune_to(2).await?;
+
+    // Only the values read by versions 2 and 3 are left.
+    assert_eq!(count("substore--jmt-values"), 4);
+    assert!(count("substore--jmt") < nodes_before);
+
+    let latest = storage.latest_snapshot();
+    assert_eq!(latest.root_hash().await?, root_hash);
+    assert_eq!(latest.get_raw("key").await?, Some(vec![3]));
+    assert_eq!(latest.get_raw("ibc/key").await?, Some(vec![3]));
+    let (value, _) = latest.get_with_proof(b"ibc/key".to_vec()).await?;
+    assert_eq!(value, Some(vec![3]));
+
+    // The snapshot was taken before pruning, so it still sees what it needs.
+    assert_eq!(held.get_raw("key").await?, Some(vec![1]));
+    assert_eq!(held.get_raw("ibc/key").await?, Some(vec![1]));
+
+    // Later commits still build on the pruned tree.
+    let mut delta = StateDelta::new(storage.latest_snapshot());
+    delta.put_raw("ibc/key".to_string(), vec![4]);
+    storage.commit(delta).await?;
+    assert_eq!(
+        storage.latest_snapshot().get_raw("ibc/key").await?,
+        Some(vec![4])
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Test that range queries over the verifiable store interleave committed and
+/// cached writes, in both directions, within a prefix and a substore.
+async fn prefix_range_queries() -> anyhow::Result<()> {
+    use futures::TryStreamExt;
+    let _ = tracing_subscriber::fmt::try_init();
+    let tmpdir = tempfile::tempdir()?;
+    let storage = Storage::load(tmpdir.path().to_owned(), vec!["ibc".to_string()]).await?;
+
+    let mut state_init = StateDelta::new(storage.latest_snapshot());
+    for key in ["a/1", "a/3", "a/5", "b/1", "ibc/a/1", "ibc/a/3"] {
+        state_init.put_raw(key.to_string(), key.as_bytes().to_vec());
+    }
+    storage.commit(state_init).await?;
+
+    let mut state = StateDelta::new(storage.latest_snapshot());
+    state.put_raw("a/2".to_string(), b"a/2".to_vec());
+    state.put_raw("a/5".to_string(), b"a/5 again".to_vec());
+    state.delete("a/3".to_string());
+    state.put_raw("ibc/a/2".to_string(), b"ibc/a/2".to_vec());
+    let mut tx = StateDelta::new(&mut state);
+    tx.put_raw("a/4".to_string(), b"a/4".to_vec());
+    tx.put_raw("a/6".to_string(), b"a/6".to_vec());
+
+    async fn keys<S: StateRead>(
+        state: &S,
+        prefix: &str,
+        range: impl std::ops::RangeBounds<str>,
+        reverse: bool,
+    ) -> anyhow::Result<Vec<String>> {
+        state
+            .prefix_range_raw(prefix, range, reverse)?
+            .map_ok(|(k, _)| k)
+            .try_collect()
+            .await
+    }
+
+    assert_eq!(
+        keys(&tx, "a/", .., false).await?,
+        vec!["a/1", "a/2", "a/4", "a/5", "a/6"]
+    );
+    assert_eq!(
+        keys(&tx, "a/", .., true).await?,
+        vec!["a/6", "a/5", "a/4", "a/2", "a/1"]
+    );
+    assert_eq!(keys(&tx, "a/", "2".."5", false).await?, vec!["a/2", "a/4"]);
+    assert_eq!(keys(&tx, "a/", "2".."5", true).await?, vec!["a/4", "a/2"]);
+    assert_eq!(
+        keys(&tx, "a/", "2"..="5", true).await?,
+        vec!["a/5", "a/4", "a/2"]
+    );
+    assert_eq!(
+        keys(
+            &tx,
+            "a/",
+            (std::ops::Bound::Excluded("1"), std::ops::Bound::Unbounded),
+            false
+        )
+        .await?,
+        vec!["a/2", "a/4", "a/5", "a/6"]
+    );
+    assert_eq!(keys(&tx, "a/", .."3", true).await?, vec!["a/2", "a/1"]);
+    assert_eq!(keys(&tx, "a/", "7".., false).await?, Vec::<String>::new());
+    // The committed state only sees committed writes.
+    assert_eq!(
+        keys(&storage.latest_snapshot(), "a/", "2".., true).await?,
+        vec!["a/5", "a/3"]
+    );
+    assert_eq!(
+        keys(&tx, "ibc/a/", "1"..="3", true).await?,
+        vec!["ibc/a/3", "ibc/a/2", "ibc/a/1"]
+    );
+
+    let (_, value) = tx
+        .prefix_range_raw("a/", "5".., false)?
+        .try_next()
+        .await?
+        .expect("a key is in range");
+    assert_eq!(value, b"a/5 again".to_vec());
+
+    assert!(tx.prefix_range_raw("a/", "5".."2", false).is_err());
+    assert!(storage
+        .latest_snapshot()
+        .prefix_range_raw("ibc", "/a".., false)
+        .is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Versions that fell out of the snapshot cache can still be read, until
+/// they're pruned.
+async fn snapshot_of_past_versions() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let tmpdir = tempfile::tempdir()?;
+    let storage = Storage::load(tmpdir.path().to_owned(), vec!["ibc".to_string()]).await?;
+
+    // Only the first versions write to the substore, so its version lags
+    // behind the version of the main store.
+    for i in 0..16u8 {
+        let mut delta = StateDelta::new(storage.latest_snapshot());
+        delta.put_raw("key".to_string(), vec![i]);
+        if i < 3 {
+            delta.put_raw("ibc/key".to_string(), vec![i]);
+        }
+        storage.commit(delta).await?;
+    }
+
+    let old = storage.snapshot(1).expect("version 1 was not pruned");
+    assert_eq!(old.version(), 1);
+    assert_eq!(old.get_raw("key").await?, Some(vec![1]));
+    assert_eq!(old.get_raw("ibc/key").await?, Some(vec![1]));
+    let (value, _) = old.get_with_proof(b"ibc/key".to_vec()).await?;
+    assert_eq!(value, Some(vec![1]));
+
+    let later = storage.snapshot(4).expect("version 4 was not pruned");
+    assert_eq!(later.get_raw("key").await?, Some(vec![4]));
+    assert_eq!(later.get_raw("ibc/key").await?, Some(vec![2]));
+
+    assert!(storage.snapshot(16).is_none(), "version 16 isn't committed");
+
+    storage.prune_to(4).await?;
+    assert!(storage.snapshot(1).is_none(), "version 1 was pruned");
+    let later = storage.snapshot(4).expect("version 4 was not pruned");
+    assert_eq!(later.get_raw("ibc/key").await?, Some(vec![2]));
+    // The snapshot was opened before pruning, so it still sees what it needs.
+    assert_eq!(old.get_raw("key").await?, Some(vec![1]));
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Rolling back undoes the versions committed after the one rolled back to,
+/// in the verifiable store, its key index and the nonverifiable store.
+async fn rollback_to_undoes_later_versions() -> anyhow::Result<()> {
+    use futures::TryStreamExt;
+
+    let _ = tracing_subscriber::fmt::try_init();
+    let tmpdir = tempfile::tempdir()?;
+    let storage = Storage::load(tmpdir.path().to_owned(), vec!["ibc".to_string()]).await?;
+
+    for i in 0..4u8 {
+        let mut delta = StateDelta::new(storage.latest_snapshot());
+        delta.put_raw("key".to_string(), vec![i]);
+        delta.put_raw(format!("ibc/key_{i}"), vec![i]);
+        delta.nonverifiable_put_raw(b"nv".to_vec(), vec![i]);
+        if i == 2 {
+            delta.delete("ibc/key_0".to_string());
+            delta.nonverifiable_delete(b"ibc/nv".to_vec());
+        } else {
+            delta.nonverifiable_put_raw(b"ibc/nv".to_vec(), vec![i]);
+        }
+        storage.commit(delta).await?;
+    }
+    let root_hash = storage
+        .snapshot(1)
+        .expect("version 1 is cached")
+        .root_hash()
+        .await?;
+
+    storage
+        .rollback_to(4)
+        .await
+        .expect_err("can't roll back past the latest version");
+    storage.rollback_to(1).await?;
+
+    let snapshot = storage.latest_snapshot();
+    assert_eq!(storage.latest_version(), 1);
+    assert_eq!(snapshot.root_hash().await?, root_hash);
+    assert_eq!(snapshot.get_raw("key").await?, Some(vec![1]));
+    assert_eq!(snapshot.get_raw("ibc/key_0").await?, Some(vec![0]));
+    assert_eq!(snapshot.get_raw("ibc/key_2").await?, None);
+    let keys: Vec<_> = snapshot.prefix_keys("ibc/").try_collect().await?;
+    assert_eq!(keys, vec!["ibc/key_0", "ibc/key_1"]);
+    assert_eq!(snapshot.nonverifiable_get_raw(b"nv").await?, Some(vec![1]));
+    assert_eq!(
+        snapshot.nonverifiable_get_raw(b"ibc/nv").await?,
+        Some(vec![1])
+    );
+
+    // The undone versions are committed again, with other values.
+    let mut delta = StateDelta::new(storage.latest_snapshot());
+    delta.put_raw("ibc/key_2".to_string(), vec![5]);
+    storage.commit(delta).await?;
+    let snapshot = storage.latest_snapshot();
+    assert_eq!(snapshot.version(), 2);
+    assert_eq!(snapshot.get_raw("key").await?, Some(vec![1]));
+    assert_eq!(snapshot.get_raw("ibc/key_2").await?, Some(vec![5]));
+    assert_eq!(snapshot.get_raw("ibc/key_3").await?, None);
+
+    storage.prune_to(2).await?;
+    storage
+        .rollback_to(1)
+        .await
+        .expect_err("can't roll back to a pruned version");
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Checks that the changes of a fork can be applied to the state it was forked
+/// from, or discarded, without affecting the rest of the tree.
+async fn apply_fork() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = Storage::new_in_memory(vec![]).await?;
+    let mut delta = StateDelta::new(storage.latest_snapshot());
+    delta.put_raw("base".to_string(), vec![0]);
+
+    // A discarded fork leaves no trace.
+    let mut discarded = delta.fork();
+    discarded.put_raw("discarded".to_string(), vec![1]);
+    std::mem::drop(discarded);
+
+    let mut fork = delta.fork();
+    fork.put_raw("base".to_string(), vec![1]);
+    fork.nonverifiable_put_raw(b"fork".to_vec(), vec![1]);
+    // Changes to the fork's own forks are applied with it.
+    let mut nested = fork.fork();
+    nested.put_raw("nested".to_string(), vec![1]);
+    fork.apply_fork(nested);
+    fork.put_raw("nested".to_string(), vec![2]);
+    // Changes made after the fork are kept, unless the fork overwrites them.
+    delta.put_raw("after".to_string(), vec![1]);
+    delta.apply_fork(fork);
+
+    assert_eq!(delta.get_raw("base").await?, Some(vec![1]));
+    assert_eq!(delta.get_raw("after").await?, Some(vec![1]));
+    assert_eq!(delta.get_raw("nested").await?, Some(vec![2]));
+    assert_eq!(delta.get_raw("discarded").await?, None);
+    assert_eq!(delta.nonverifiable_get_raw(b"fork").await?, Some(vec![1]));
+
+    // The tree can still be committed as a whole.
+    storage.commit(delta).await?;
+    let snapshot = storage.latest_snapshot();
+    assert_eq!(snapshot.get_raw("base").await?, Some(vec![1]));
+    assert_eq!(snapshot.get_raw("nested").await?, Some(vec![2]));
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Checks that objects at typed keys accumulate over the transactions of a
+/// block, and that the next block starts without them.
+async fn typed_objects() -> anyhow::Result<()> {
+    const GAS_USED: ObjectKey<u64> = ObjectKey::new("test/gas_used");
+    let storage = Storage::new_in_memory(vec![]).await?;
+    let mut block = StateDelta::new(storage.latest_snapshot());
+    assert_eq!(block.object(GAS_USED), None);
+
+    let mut tx = StateDelta::new(&mut block);
+    tx.object_update(GAS_USED, |gas| *gas += 3);
+    tx.object_update(GAS_USED, |gas| *gas += 4);
+    tx.apply();
+    // A transaction that fails contributes nothing.
+    let mut failed = StateDelta::new(&mut block);
+    failed.object_update(GAS_USED, |gas| *gas += 100);
+    std::mem::drop(failed);
+    assert_eq!(block.object(GAS_USED), Some(7));
+    assert_eq!(block.object_get::<u64>(GAS_USED.key()), Some(7));
+
+    block.object_set(GAS_USED, 1);
+    assert_eq!(block.object(GAS_USED), Some(1));
+    block.put_raw("key".to_string(), vec![0]);
+    storage.commit(block).await?;
+
+    let block = StateDelta::new(storage.latest_snapshot());
+    assert_eq!(block.object(GAS_USED), None);
+
+    Ok(())
+}
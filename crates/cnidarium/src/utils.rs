@@ -1,4 +1,7 @@
+use std::ops::{Bound, RangeBounds};
+
 use anyhow::bail;
+
 /// Splits a range into a tuple of start and end bounds, ignoring the inclusive/exclusive
 /// nature of the range bounds. And returns a tuple consisting of the range implementation,
 /// and the start and end bounds.
@@ -32,3 +35,44 @@ pub(crate) fn convert_bounds(
 
     Ok((range, (start, end)))
 }
+
+/// Returns the bounds of the keys with the given `prefix` whose remainder
+/// after the prefix lies in `range`. The upper bound is [`Bound::Unbounded`]
+/// when the range is, in which case only keys with the prefix are included.
+/// # Errors
+/// This method returns an error when the lower bound is greater than the upper bound,
+/// and when both bounds are equal and excluded.
+pub(crate) fn prefix_range_bounds(
+    prefix: &str,
+    range: &impl RangeBounds<str>,
+) -> anyhow::Result<(Bound<String>, Bound<String>)> {
+    let with_prefix = |key: &str| format!("{prefix}{key}");
+    let start = match range.start_bound() {
+        Bound::Included(k) => Bound::Included(with_prefix(k)),
+        Bound::Excluded(k) => Bound::Excluded(with_prefix(k)),
+        Bound::Unbounded => Bound::Included(prefix.to_string()),
+    };
+    let end = match range.end_bound() {
+        Bound::Included(k) => Bound::Included(with_prefix(k)),
+        Bound::Excluded(k) => Bound::Excluded(with_prefix(k)),
+        Bound::Unbounded => Bound::Unbounded,
+    };
+
+    if let (
+        Bound::Included(k_start) | Bound::Excluded(k_start),
+        Bound::Included(k_end) | Bound::Excluded(k_end),
+    ) = (&start, &end)
+    {
+        if k_start > k_end {
+            bail!("lower bound is greater than upper bound")
+        }
+        if k_start == k_end
+            && matches!(start, Bound::Excluded(_))
+            && matches!(end, Bound::Excluded(_))
+        {
+            bail!("both bounds are equal and excluded")
+        }
+    }
+
+    Ok((start, end))
+}
@@ -0,0 +1,210 @@
+//! Verification of the integrity of versions of the state.
+//!
+//! [`Snapshot::verify`] walks the tree of each store of a version of the
+//! state: every key in the index of a store is read along with a proof of its
+//! value, which is checked against the root hash of the store, so that a
+//! corrupt or missing node on the path from the root to the key, or a corrupt
+//! value, is found. The root hash of each substore is then checked against the
+//! one the main store commits to, whose own root hash is the app hash.
+
+use std::{
+    fmt::{self, Display, Formatter},
+    sync::Arc,
+};
+
+use anyhow::Result;
+use jmt::{KeyHash, RootHash};
+use rocksdb::IteratorMode;
+use tracing::Span;
+
+use crate::{
+    store::substore::{SubstoreConfig, SubstoreSnapshot},
+    Snapshot,
+};
+
+/// A problem found when verifying a version of the state.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Corruption {
+    /// The key index of the store maps `key` to a hash that isn't its hash.
+    KeyHash { prefix: String, key: String },
+    /// The key is in the index of the store, but the tree has no value for it.
+    MissingValue { prefix: String, key: String },
+    /// The value of `key` can't be proven against the root hash of its store,
+    /// so a node on its path, or the value itself, is corrupt or missing.
+    Proof {
+        prefix: String,
+        key: String,
+        error: String,
+    },
+    /// The main store commits to another root hash for the substore than the
+    /// root hash of its tree.
+    SubstoreRoot {
+        prefix: String,
+        committed: Option<RootHash>,
+        root_hash: RootHash,
+    },
+}
+
+impl Display for Corruption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Corruption::KeyHash { prefix, key } => {
+                write!(
+                    f,
+                    "key {key:?} of store {prefix:?} is indexed with the wrong hash"
+                )
+            }
+            Corruption::MissingValue { prefix, key } => {
+                write!(
+                    f,
+                    "key {key:?} of store {prefix:?} is indexed, but has no value"
+                )
+            }
+            Corruption::Proof { prefix, key, error } => {
+                write!(f, "key {key:?} of store {prefix:?} doesn't verify: {error}")
+            }
+            Corruption::SubstoreRoot {
+                prefix,
+                committed,
+                root_hash,
+            } => write!(
+                f,
+                "substore {prefix:?} has root hash {}, but the main store commits to {}",
+                hex::encode(root_hash.0),
+                committed.map_or("nothing".to_string(), |hash| hex::encode(hash.0)),
+            ),
+        }
+    }
+}
+
+/// The outcome of verifying a version of the state, see [`Snapshot::verify`].
+#[derive(Clone, Debug)]
+pub struct Verification {
+    /// The version of the state verified.
+    pub version: jmt::Version,
+    /// The root hash of the main store, the app hash of the version.
+    pub root_hash: RootHash,
+    /// The number of keys verified, across all stores.
+    pub keys: u64,
+    /// The problems found, if any.
+    pub corruptions: Vec<Corruption>,
+}
+
+impl Verification {
+    /// Returns whether no problem was found.
+    pub fn is_ok(&self) -> bool {
+        self.corruptions.is_empty()
+    }
+}
+
+impl Snapshot {
+    /// Verifies the integrity of this version of the state, re-walking the tree
+    /// of each store from its root hash.
+    ///
+    /// Corrupt data is reported in the returned [`Verification`], rather than
+    /// as an error; errors are only returned if the storage can't be read.
+    ///
+    /// See the [`verify`](crate::verify) module for what is checked.
+    pub async fn verify(&self) -> Result<Verification> {
+        let span = Span::current();
+        let snapshot = self.clone();
+        tokio::task::spawn_blocking(move || span.in_scope(|| snapshot.verify_stores())).await?
+    }
+
+    fn verify_stores(&self) -> Result<Verification> {
+        let config = &self.0.multistore_cache.config;
+        let mut corruptions = Vec::new();
+        let mut keys = 0;
+
+        let main_store = self.substore_snapshot(&config.main_store);
+        keys += verify_store(&main_store, &mut corruptions)?;
+        let root_hash = main_store.root_hash()?;
+
+        for substore_config in config.iter() {
+            let substore = self.substore_snapshot(substore_config);
+            let substore_keys = verify_store(&substore, &mut corruptions)?;
+            keys += substore_keys;
+
+            let root_hash = substore.root_hash()?;
+            let committed = main_store
+                .get_jmt(KeyHash::with::<sha2::Sha256>(&substore_config.prefix))?
+                .and_then(|hash| hash.try_into().ok())
+                .map(RootHash);
+            // A substore that was never written to has no root hash committed.
+            let never_written = committed.is_none() && substore_keys == 0;
+            if committed != Some(root_hash) && !never_written {
+                corruptions.push(Corruption::SubstoreRoot {
+                    prefix: substore_config.prefix.clone(),
+                    committed,
+                    root_hash,
+                });
+            }
+        }
+
+        Ok(Verification {
+            version: self.version(),
+            root_hash,
+            keys,
+            corruptions,
+        })
+    }
+
+    fn substore_snapshot(&self, config: &Arc<SubstoreConfig>) -> SubstoreSnapshot {
+        SubstoreSnapshot {
+            config: config.clone(),
+            rocksdb_snapshot: self.0.snapshot.clone(),
+            version: self.substore_version(config).unwrap_or(u64::MAX),
+            db: self.0.db.clone(),
+        }
+    }
+}
+
+/// Checks each key in the index of `store` against its root hash, adding the
+/// problems found to `corruptions`, and returns the number of keys checked.
+fn verify_store(store: &SubstoreSnapshot, corruptions: &mut Vec<Corruption>) -> Result<u64> {
+    let prefix = &store.config.prefix;
+    let root_hash = store.root_hash()?;
+    let tree = jmt::Sha256Jmt::new(store);
+    let mut keys = 0;
+
+    let cf_jmt_keys = store.config.cf_jmt_keys(&store.db);
+    for entry in store
+        .rocksdb_snapshot
+        .iterator_cf(cf_jmt_keys, IteratorMode::Start)
+    {
+        let (key, indexed_hash) = entry?;
+        keys += 1;
+        let key_hash = KeyHash::with::<sha2::Sha256>(&key);
+        let key = String::from_utf8_lossy(&key).into_owned();
+        if indexed_hash.as_ref() != key_hash.0 {
+            corruptions.push(Corruption::KeyHash {
+                prefix: prefix.clone(),
+                key: key.clone(),
+            });
+        }
+
+        // Reading the proof walks the nodes from the root to the key, so a
+        // missing node fails the read rather than the proof.
+        let verified = match tree.get_with_proof(key_hash, store.version) {
+            Ok((Some(value), proof)) => proof.verify(root_hash, key_hash, Some(value)),
+            Ok((None, _)) => {
+                corruptions.push(Corruption::MissingValue {
+                    prefix: prefix.clone(),
+                    key,
+                });
+                continue;
+            }
+            Err(e) => Err(e),
+        };
+        if let Err(e) = verified {
+            tracing::debug!(?prefix, ?key, error = ?e, "key doesn't verify");
+            corruptions.push(Corruption::Proof {
+                prefix: prefix.clone(),
+                key,
+                error: e.to_string(),
+            });
+        }
+    }
+
+    Ok(keys)
+}
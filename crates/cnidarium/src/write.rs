@@ -1,4 +1,4 @@
-use crate::StateRead;
+use crate::{ObjectKey, StateRead};
 use std::{any::Any, collections::BTreeMap};
 use tendermint::abci;
 
@@ -11,6 +11,12 @@ pub trait StateWrite: StateRead + Send + Sync {
     fn delete(&mut self, key: String);
 
     /// Puts raw bytes into the non-verifiable key-value store with the given key.
+    ///
+    /// The write is not committed to by the root hash, so this is suited to
+    /// indexes of the verifiable state that would otherwise bloat the JMT.
+    ///
+    /// Users should generally prefer to use `nonverifiable_put` or
+    /// `nonverifiable_put_proto` from an extension trait.
     fn nonverifiable_put_raw(&mut self, key: Vec<u8>, value: Vec<u8>);
 
     /// Delete a key from non-verifiable key-value storage.
@@ -27,6 +33,24 @@ pub trait StateWrite: StateRead + Send + Sync {
     /// Deletes a key from the ephemeral object store.
     fn object_delete(&mut self, key: &'static str);
 
+    /// Puts an object into the ephemeral object store, at a key bound to its type.
+    fn object_set<T: Clone + Any + Send + Sync>(&mut self, key: ObjectKey<T>, value: T) {
+        self.object_put(key.key(), value)
+    }
+
+    /// Updates the object at a key bound to its type in the ephemeral object
+    /// store with `f`, starting from the default value if it isn't present,
+    /// e.g. to accumulate a value over the actions of a block.
+    fn object_update<T: Clone + Default + Any + Send + Sync>(
+        &mut self,
+        key: ObjectKey<T>,
+        f: impl FnOnce(&mut T),
+    ) {
+        let mut value = self.object(key).unwrap_or_default();
+        f(&mut value);
+        self.object_set(key, value)
+    }
+
     /// Merge a set of object changes into this `StateWrite`.
     ///
     /// Unlike `object_put`, this avoids re-boxing values and messing up the downcasting.
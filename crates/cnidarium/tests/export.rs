@@ -0,0 +1,136 @@
+use anyhow::Result;
+use cnidarium::{export, StateDelta, StateRead, StateWrite, Storage};
+use futures::TryStreamExt;
+use tempfile;
+use tokio;
+
+#[tokio::test]
+/// Exports a version of the state, and checks that restoring it into empty
+/// storage rebuilds the same state, which later versions can build on.
+pub async fn test_export_and_restore() -> Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let substore_prefixes = vec!["ibc".to_string(), "dex".to_string()];
+    let tmpdir = tempfile::tempdir()?;
+    let storage = Storage::load(tmpdir.path().join("source"), substore_prefixes.clone()).await?;
+
+    for i in 0..3u8 {
+        let mut delta = StateDelta::new(storage.latest_snapshot());
+        delta.put_raw(format!("key_{i}"), vec![i]);
+        delta.put_raw(format!("ibc/key_{i}"), vec![i]);
+        delta.nonverifiable_put_raw(format!("nv_{i}").into_bytes(), vec![i]);
+        delta.nonverifiable_put_raw(format!("ibc/nv_{i}").into_bytes(), vec![i]);
+        if i == 2 {
+            delta.delete("ibc/key_0".to_string());
+        }
+        storage.commit(delta).await?;
+    }
+    let snapshot = storage.latest_snapshot();
+    let version = snapshot.version();
+    let root_hash = snapshot.root_hash().await?;
+
+    let chunks: Vec<Vec<u8>> = snapshot.export(16).try_collect().await?;
+    assert!(chunks.len() > 1, "the export is split into chunks");
+
+    let restored = Storage::load(tmpdir.path().join("restored"), substore_prefixes).await?;
+    let mut delta = StateDelta::new(restored.latest_snapshot());
+    for chunk in &chunks {
+        export::apply_chunk(&mut delta, chunk)?;
+    }
+    restored
+        .restore(delta, version, jmt::RootHash([1; 32]))
+        .await
+        .expect_err("the restored state doesn't have this root hash");
+    assert_eq!(restored.latest_version(), u64::MAX, "nothing was restored");
+
+    let mut delta = StateDelta::new(restored.latest_snapshot());
+    for chunk in chunks.iter().rev() {
+        export::apply_chunk(&mut delta, chunk)?;
+    }
+    restored.restore(delta, version, root_hash).await?;
+
+    let restored_snapshot = restored.latest_snapshot();
+    assert_eq!(restored_snapshot.version(), version);
+    assert_eq!(restored_snapshot.root_hash().await?, root_hash);
+    assert_eq!(restored_snapshot.get_raw("key_0").await?, Some(vec![0]));
+    assert_eq!(restored_snapshot.get_raw("ibc/key_0").await?, None);
+    assert_eq!(restored_snapshot.get_raw("ibc/key_2").await?, Some(vec![2]));
+    assert_eq!(
+        restored_snapshot.nonverifiable_get_raw(b"ibc/nv_1").await?,
+        Some(vec![1])
+    );
+    let (value, _) = restored_snapshot
+        .get_with_proof(b"ibc/key_1".to_vec())
+        .await?;
+    assert_eq!(value, Some(vec![1]));
+
+    // Both histories carry on the same way.
+    for storage in [&storage, &restored] {
+        let mut delta = StateDelta::new(storage.latest_snapshot());
+        delta.put_raw("dex/key".to_string(), vec![3]);
+        delta.put_raw("key_1".to_string(), vec![3]);
+        storage.commit(delta).await?;
+    }
+    assert_eq!(restored.latest_version(), version + 1);
+    assert_eq!(
+        restored.latest_snapshot().root_hash().await?,
+        storage.latest_snapshot().root_hash().await?
+    );
+
+    restored
+        .restore(StateDelta::new(restored.latest_snapshot()), 0, root_hash)
+        .await
+        .expect_err("can only restore into empty storage");
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Exports a single substore, and checks that the export only holds its
+/// entries, which commit to the substore's own root.
+pub async fn test_export_substore() -> Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let tmpdir = tempfile::tempdir()?;
+    let storage = Storage::load(
+        tmpdir.path().to_owned(),
+        vec!["ibc".to_string(), "dex".to_string()],
+    )
+    .await?;
+
+    let mut delta = StateDelta::new(storage.latest_snapshot());
+    delta.put_raw("key".to_string(), vec![0]);
+    delta.put_raw("ibc/key".to_string(), vec![1]);
+    delta.put_raw("dex/key".to_string(), vec![2]);
+    delta.nonverifiable_put_raw(b"dex/nv".to_vec(), vec![3]);
+    storage.commit(delta).await?;
+    let snapshot = storage.latest_snapshot();
+    let dex_root = snapshot.prefix_root_hash("dex").await?;
+
+    let chunks: Vec<Vec<u8>> = snapshot.export_substore("dex", 1024)?.try_collect().await?;
+    let mut delta = StateDelta::new(());
+    for chunk in &chunks {
+        export::apply_chunk(&mut delta, chunk)?;
+    }
+    let (_, cache) = delta.flatten();
+    assert_eq!(
+        cache.unwritten_changes().keys().collect::<Vec<_>>(),
+        vec!["dex/key"]
+    );
+    assert_eq!(
+        cache.nonverifiable_changes().keys().collect::<Vec<_>>(),
+        vec![b"dex/nv"]
+    );
+
+    // The substore's root only commits to its own entries.
+    let mut delta = StateDelta::new(storage.latest_snapshot());
+    delta.put_raw("key".to_string(), vec![4]);
+    delta.put_raw("ibc/key".to_string(), vec![5]);
+    storage.commit(delta).await?;
+    assert_eq!(
+        storage.latest_snapshot().prefix_root_hash("dex").await?,
+        dex_root
+    );
+
+    assert!(snapshot.export_substore("stake", 1024).is_err());
+
+    Ok(())
+}
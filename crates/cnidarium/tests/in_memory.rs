@@ -0,0 +1,46 @@
+use anyhow::Result;
+use cnidarium::{StateDelta, StateRead, StateWrite, Storage};
+use tokio;
+
+#[tokio::test]
+/// Checks that in-memory storage commits versions like storage on disk does,
+/// with the same root hashes.
+pub async fn test_in_memory_storage() -> Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let tmpdir = tempfile::tempdir()?;
+    let prefixes = vec!["ibc".to_string(), "dex".to_string()];
+    let on_disk = Storage::load(tmpdir.path().to_owned(), prefixes.clone()).await?;
+    let in_memory = Storage::new_in_memory(prefixes.clone()).await?;
+    // Each instance has its own data.
+    let other = Storage::new_in_memory(prefixes).await?;
+
+    assert_eq!(in_memory.latest_version(), u64::MAX);
+    for i in 0..3u8 {
+        for storage in [&on_disk, &in_memory] {
+            let mut delta = StateDelta::new(storage.latest_snapshot());
+            delta.put_raw(format!("key_{i}"), vec![i]);
+            delta.put_raw(format!("ibc/key_{i}"), vec![i]);
+            delta.nonverifiable_put_raw(format!("dex/nv_{i}").into_bytes(), vec![i]);
+            storage.commit(delta).await?;
+        }
+    }
+
+    let snapshot = in_memory.latest_snapshot();
+    assert_eq!(snapshot.version(), 2);
+    assert_eq!(
+        snapshot.root_hash().await?,
+        on_disk.latest_snapshot().root_hash().await?
+    );
+    assert_eq!(snapshot.get_raw("ibc/key_1").await?, Some(vec![1]));
+    assert_eq!(
+        snapshot.nonverifiable_get_raw(b"dex/nv_2").await?,
+        Some(vec![2])
+    );
+    let (value, _) = snapshot.get_with_proof(b"ibc/key_0".to_vec()).await?;
+    assert_eq!(value, Some(vec![0]));
+
+    assert_eq!(other.latest_version(), u64::MAX);
+    assert_eq!(other.latest_snapshot().get_raw("key_0").await?, None);
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anyhow::Result;
+use cnidarium::{StateDelta, StateRead, StateWrite, Storage};
+use futures::TryStreamExt;
+use tempfile;
+use tokio;
+
+#[tokio::test]
+/// Checks that the nonverifiable store is versioned and iterable like the
+/// verifiable store, without being committed to by the root hash.
+pub async fn test_nonverifiable_store() -> Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let tmpdir = tempfile::tempdir()?;
+    let storage = Storage::load(tmpdir.path().to_owned(), vec!["dex".to_string()]).await?;
+
+    let mut delta = StateDelta::new(storage.latest_snapshot());
+    delta.put_raw("dex/key".to_string(), vec![0]);
+    storage.commit(delta).await?;
+    let root_hash = storage.latest_snapshot().root_hash().await?;
+    let dex_root = storage.latest_snapshot().prefix_root_hash("dex").await?;
+
+    let mut delta = StateDelta::new(storage.latest_snapshot());
+    for i in 0..4u8 {
+        delta.nonverifiable_put_raw(vec![b'd', b'e', b'x', b'/', i], vec![i]);
+        delta.nonverifiable_put_raw(vec![b'i', i], vec![i]);
+    }
+    storage.commit(delta).await?;
+    let snapshot = storage.latest_snapshot();
+    assert_eq!(snapshot.root_hash().await?, root_hash);
+    assert_eq!(snapshot.prefix_root_hash("dex").await?, dex_root);
+
+    // Reads through a delta see its own writes, in key order.
+    let mut delta = StateDelta::new(snapshot.clone());
+    delta.nonverifiable_delete(vec![b'd', b'e', b'x', b'/', 1]);
+    delta.nonverifiable_put_raw(vec![b'd', b'e', b'x', b'/', 5], vec![5]);
+    let entries: Vec<_> = delta
+        .nonverifiable_prefix_raw(b"dex/")
+        .try_collect()
+        .await?;
+    assert_eq!(
+        entries.into_iter().map(|(_, v)| v).collect::<Vec<_>>(),
+        vec![vec![0], vec![2], vec![3], vec![5]]
+    );
+    let entries: Vec<_> = delta
+        .nonverifiable_range_raw(Some(b"dex/".as_slice()), vec![2]..vec![5])?
+        .try_collect()
+        .await?;
+    assert_eq!(
+        entries.into_iter().map(|(_, v)| v).collect::<Vec<_>>(),
+        vec![vec![2], vec![3]]
+    );
+
+    // The snapshot is unaffected by the delta.
+    assert_eq!(
+        snapshot
+            .nonverifiable_get_raw(&[b'd', b'e', b'x', b'/', 1])
+            .await?,
+        Some(vec![1])
+    );
+    assert_eq!(
+        snapshot.nonverifiable_get_raw(&[b'i', 3]).await?,
+        Some(vec![3])
+    );
+
+    Ok(())
+}
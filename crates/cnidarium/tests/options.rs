@@ -0,0 +1,182 @@
+use anyhow::Result;
+use cnidarium::{
+    CompactionStyle, Durability, StateDelta, StateRead, StateWrite, Storage, StorageOptions,
+};
+use std::time::Duration;
+use tokio;
+
+#[tokio::test]
+/// Checks that versions committed with write-behind durability can be read
+/// right away, and are on disk once flushed.
+pub async fn test_write_behind() -> Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let tmpdir = tempfile::tempdir()?;
+    let prefixes = vec!["ibc".to_string()];
+    let options = StorageOptions {
+        durability: Durability::WriteBehind { interval: 100 },
+        ..Default::default()
+    };
+    let storage =
+        Storage::load_with_options(tmpdir.path().to_owned(), prefixes.clone(), options).await?;
+
+    for i in 0..3u8 {
+        let mut delta = StateDelta::new(storage.latest_snapshot());
+        delta.put_raw("key".to_string(), vec![i]);
+        delta.put_raw(format!("ibc/key_{i}"), vec![i]);
+        delta.nonverifiable_put_raw(b"nv".to_vec(), vec![i]);
+        storage.commit(delta).await?;
+    }
+    let snapshot = storage.latest_snapshot();
+    let root_hash = snapshot.root_hash().await?;
+    assert_eq!(snapshot.get_raw("key").await?, Some(vec![2]));
+    assert_eq!(snapshot.get_raw("ibc/key_1").await?, Some(vec![1]));
+    assert_eq!(snapshot.nonverifiable_get_raw(b"nv").await?, Some(vec![2]));
+    assert_eq!(
+        storage.durable_version(),
+        u64::MAX,
+        "nothing was written to disk yet"
+    );
+
+    storage.flush().await?;
+    assert_eq!(storage.durable_version(), 2);
+    std::mem::drop(snapshot);
+    storage.release().await;
+
+    let storage = Storage::load(tmpdir.path().to_owned(), prefixes).await?;
+    let snapshot = storage.latest_snapshot();
+    assert_eq!(snapshot.version(), 2);
+    assert_eq!(snapshot.root_hash().await?, root_hash);
+    assert_eq!(snapshot.get_raw("ibc/key_0").await?, Some(vec![0]));
+    assert_eq!(snapshot.nonverifiable_get_raw(b"nv").await?, Some(vec![2]));
+    assert_eq!(storage.durable_version(), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Checks that storage tuned with RocksDB options reads back what it commits,
+/// including once reloaded.
+pub async fn test_tuned_storage() -> Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let tmpdir = tempfile::tempdir()?;
+    let prefixes = vec!["ibc".to_string()];
+    let options = StorageOptions {
+        block_cache_size: Some(1024 * 1024),
+        compaction_style: CompactionStyle::Universal,
+        bloom_filter_bits_per_key: Some(10.0),
+        // Every read is logged as slow.
+        slow_read_threshold: Some(Duration::ZERO),
+        ..Default::default()
+    };
+    let storage =
+        Storage::load_with_options(tmpdir.path().to_owned(), prefixes.clone(), options.clone())
+            .await?;
+
+    let mut delta = StateDelta::new(storage.latest_snapshot());
+    delta.put_raw("key".to_string(), vec![0]);
+    delta.put_raw("ibc/key".to_string(), vec![1]);
+    delta.nonverifiable_put_raw(b"nv".to_vec(), vec![2]);
+    let root_hash = storage.commit(delta).await?;
+    let snapshot = storage.latest_snapshot();
+    assert_eq!(snapshot.get_raw("ibc/key").await?, Some(vec![1]));
+    assert_eq!(snapshot.get_raw("ibc/missing").await?, None);
+    std::mem::drop(snapshot);
+    storage.release().await;
+
+    let storage = Storage::load_with_options(tmpdir.path().to_owned(), prefixes, options).await?;
+    let snapshot = storage.latest_snapshot();
+    assert_eq!(snapshot.root_hash().await?, root_hash);
+    assert_eq!(snapshot.get_raw("key").await?, Some(vec![0]));
+    assert_eq!(snapshot.nonverifiable_get_raw(b"nv").await?, Some(vec![2]));
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Checks that reads served by the read cache follow the versions of the state.
+pub async fn test_read_cache() -> Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let tmpdir = tempfile::tempdir()?;
+    let options = StorageOptions {
+        read_cache_size: Some(2),
+        ..Default::default()
+    };
+    let storage =
+        Storage::load_with_options(tmpdir.path().to_owned(), vec!["ibc".to_string()], options)
+            .await?;
+
+    for i in 0..3u8 {
+        let mut delta = StateDelta::new(storage.latest_snapshot());
+        delta.put_raw("key".to_string(), vec![i]);
+        if i == 0 {
+            delta.put_raw("ibc/key".to_string(), vec![i]);
+        }
+        let snapshot = storage.latest_snapshot();
+        for _ in 0..2 {
+            let expected = i.checked_sub(1).map(|value| vec![value]);
+            assert_eq!(snapshot.get_raw("key").await?, expected);
+        }
+        storage.commit(delta).await?;
+    }
+
+    let snapshot = storage.latest_snapshot();
+    assert_eq!(snapshot.get_raw("key").await?, Some(vec![2]));
+    assert_eq!(snapshot.get_raw("ibc/key").await?, Some(vec![0]));
+    assert_eq!(snapshot.get_raw("ibc/missing").await?, None);
+    let old = storage.snapshot(1).expect("version 1 is cached");
+    assert_eq!(old.get_raw("key").await?, Some(vec![1]));
+    assert_eq!(old.get_raw("ibc/key").await?, Some(vec![0]));
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Checks that committing on a pool of threads produces the same root hashes
+/// as committing on one.
+pub async fn test_parallel_commit() -> Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let prefixes = vec!["ibc".to_string(), "dex".to_string(), "misc".to_string()];
+    let sequential_dir = tempfile::tempdir()?;
+    let sequential = Storage::load(sequential_dir.path().to_owned(), prefixes.clone()).await?;
+    let parallel_dir = tempfile::tempdir()?;
+    let options = StorageOptions {
+        commit_threads: Some(4),
+        ..Default::default()
+    };
+    let parallel =
+        Storage::load_with_options(parallel_dir.path().to_owned(), prefixes.clone(), options)
+            .await?;
+
+    for i in 0..3u64 {
+        let mut root_hashes = vec![];
+        for storage in [&sequential, &parallel] {
+            let mut delta = StateDelta::new(storage.latest_snapshot());
+            for j in 0..100u64 {
+                let value = (i * j).to_be_bytes().to_vec();
+                delta.put_raw(format!("key_{j}"), value.clone());
+                for prefix in &prefixes {
+                    delta.put_raw(format!("{prefix}/key_{j}"), value.clone());
+                }
+            }
+            if i > 0 {
+                delta.delete("ibc/key_0".to_string());
+            }
+            delta.nonverifiable_put_raw(i.to_be_bytes().to_vec(), vec![1]);
+            root_hashes.push(storage.commit(delta).await?);
+        }
+        assert_eq!(root_hashes[0], root_hashes[1]);
+    }
+
+    let snapshot = parallel.latest_snapshot();
+    assert_eq!(
+        snapshot.get_raw("dex/key_7").await?,
+        Some(14u64.to_be_bytes().to_vec())
+    );
+    assert_eq!(snapshot.get_raw("ibc/key_0").await?, None);
+    assert_eq!(
+        snapshot.nonverifiable_get_raw(&2u64.to_be_bytes()).await?,
+        Some(vec![1])
+    );
+
+    Ok(())
+}
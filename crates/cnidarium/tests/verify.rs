@@ -0,0 +1,69 @@
+use anyhow::Result;
+use cnidarium::{verify::Corruption, StateDelta, StateWrite, Storage};
+use tempfile;
+use tokio;
+
+#[tokio::test]
+/// Verifies a state, then corrupts its database and checks that verifying it
+/// again reports the corrupt keys.
+pub async fn test_verify_storage() -> Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let tmpdir = tempfile::tempdir()?;
+    let prefixes = vec!["ibc".to_string(), "dex".to_string()];
+    let storage = Storage::load(tmpdir.path().to_owned(), prefixes.clone()).await?;
+
+    for i in 0..3u8 {
+        let mut delta = StateDelta::new(storage.latest_snapshot());
+        delta.put_raw(format!("key_{i}"), vec![i]);
+        delta.put_raw(format!("ibc/key_{i}"), vec![i]);
+        if i == 2 {
+            delta.delete("ibc/key_0".to_string());
+        }
+        storage.commit(delta).await?;
+    }
+    let snapshot = storage.latest_snapshot();
+    let root_hash = snapshot.root_hash().await?;
+    let verification = snapshot.verify().await?;
+    assert!(verification.is_ok(), "{:?}", verification.corruptions);
+    assert_eq!(verification.version, 2);
+    assert_eq!(verification.root_hash, root_hash);
+    assert_eq!(verification.keys, 3 + 2 + 1, "keys and the root of `ibc`");
+    std::mem::drop(snapshot);
+    storage.release().await;
+
+    // Index a key that isn't in the tree, and overwrite the latest value of
+    // `ibc/key_2` behind the back of the storage.
+    {
+        let options = rocksdb::Options::default();
+        let columns = rocksdb::DB::list_cf(&options, tmpdir.path())?;
+        let db = rocksdb::DB::open_cf(&options, tmpdir.path(), columns)?;
+        let cf_keys = db.cf_handle("substore--jmt-keys").expect("main store keys");
+        db.put_cf(cf_keys, "missing", [0; 32])?;
+        let cf_values = db.cf_handle("substore-ibc-jmt-values").expect("ibc values");
+        let key_hash = jmt::KeyHash::with::<sha2::Sha256>("key_2");
+        let key = [key_hash.0.as_slice(), &2u64.to_be_bytes()].concat();
+        assert!(db.get_cf(cf_values, &key)?.is_some());
+        db.put_cf(cf_values, key, borsh::to_vec(&Some(vec![42u8]))?)?;
+    }
+
+    let storage = Storage::load(tmpdir.path().to_owned(), prefixes).await?;
+    let verification = storage.latest_snapshot().verify().await?;
+    assert!(!verification.is_ok());
+    assert_eq!(verification.root_hash, root_hash);
+    assert!(verification
+        .corruptions
+        .iter()
+        .any(|c| matches!(c, Corruption::KeyHash { key, .. } if key == "missing")));
+    assert!(verification
+        .corruptions
+        .contains(&Corruption::MissingValue {
+            prefix: "".to_string(),
+            key: "missing".to_string(),
+        }));
+    assert!(verification
+        .corruptions
+        .iter()
+        .any(|c| matches!(c, Corruption::Proof { prefix, .. } if prefix == "ibc")));
+
+    Ok(())
+}
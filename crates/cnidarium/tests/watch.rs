@@ -0,0 +1,57 @@
+use anyhow::Result;
+use cnidarium::{KeyChange, StateDelta, StateWrite, Storage};
+use futures::StreamExt;
+use tokio;
+
+#[tokio::test]
+/// Checks that watchers receive the committed changes under their prefix, in
+/// commit order, and only those committed after they started watching.
+pub async fn test_watch_prefix() -> Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = Storage::new_in_memory(vec!["ibc".to_string()]).await?;
+
+    let mut delta = StateDelta::new(storage.latest_snapshot());
+    delta.put_raw("dex/before".to_string(), vec![0]);
+    storage.commit(delta).await?;
+
+    let mut watch = Box::pin(storage.watch("dex/"));
+    let mut nonverifiable_watch = Box::pin(storage.nonverifiable_watch(b"dex/"));
+
+    let mut delta = StateDelta::new(storage.latest_snapshot());
+    delta.put_raw("dex/a".to_string(), vec![1]);
+    delta.put_raw("ibc/a".to_string(), vec![1]);
+    delta.nonverifiable_put_raw(b"dex/nv".to_vec(), vec![1]);
+    storage.commit(delta).await?;
+
+    let mut delta = StateDelta::new(storage.latest_snapshot());
+    delta.delete("dex/before".to_string());
+    delta.put_raw("dexter".to_string(), vec![2]);
+    storage.commit(delta).await?;
+
+    assert_eq!(
+        watch.next().await.transpose()?,
+        Some(KeyChange {
+            version: 1,
+            key: "dex/a".to_string(),
+            value: Some(vec![1]),
+        })
+    );
+    assert_eq!(
+        watch.next().await.transpose()?,
+        Some(KeyChange {
+            version: 2,
+            key: "dex/before".to_string(),
+            value: None,
+        })
+    );
+    assert_eq!(
+        nonverifiable_watch.next().await.transpose()?,
+        Some(KeyChange {
+            version: 1,
+            key: b"dex/nv".to_vec(),
+            value: Some(vec![1]),
+        })
+    );
+
+    Ok(())
+}
@@ -3,17 +3,28 @@ use std::sync::Arc;
 use anyhow::Result;
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
-use penumbra_shielded_pool::component::Ics20Transfer;
+use penumbra_ibc::component::{fees::WithFees, ica::WithIcaHost};
+use penumbra_shielded_pool::component::{
+    nft_transfer::WithNftTransfer, Ics20FeePayout, Ics20Transfer,
+};
 use penumbra_transaction::Action;
 use penumbra_txhash::TransactionContext;
 
+mod ica;
 mod submit;
 
 use crate::PenumbraHost;
 
+use ica::PenumbraIcaMessages;
+
 use super::AppActionHandler;
 use cnidarium_component::ActionHandler as _;
 
+/// The IBC applications bound on Penumbra: ICS-20 and ICS-721 transfers, and
+/// an ICS-27 interchain accounts host, all with ICS-29 relayer fees.
+type IbcApps =
+    WithFees<WithIcaHost<WithNftTransfer<Ics20Transfer>, PenumbraIcaMessages>, Ics20FeePayout>;
+
 #[async_trait]
 impl AppActionHandler for Action {
     type CheckStatelessContext = TransactionContext;
@@ -31,15 +42,16 @@ impl AppActionHandler for Action {
             Action::PositionClose(action) => action.check_stateless(()).await,
             Action::PositionOpen(action) => action.check_stateless(()).await,
             Action::PositionWithdraw(action) => action.check_stateless(()).await,
+            Action::GaugeFund(action) => action.check_stateless(()).await,
             Action::ProposalSubmit(action) => action.check_stateless(()).await,
             Action::ProposalWithdraw(action) => action.check_stateless(()).await,
             Action::ProposalDepositClaim(action) => action.check_stateless(()).await,
-            Action::Swap(action) => action.check_stateless(()).await,
-            Action::Output(action) => action.check_stateless(()).await,
+            Action::Swap(action) => action.check_stateless(context).await,
+            Action::Output(action) => action.check_stateless(context).await,
             Action::IbcRelay(action) => {
                 action
                     .clone()
-                    .with_handler::<Ics20Transfer, PenumbraHost>()
+                    .with_handler::<IbcApps, PenumbraHost>()
                     .check_stateless(())
                     .await
             }
@@ -50,6 +62,13 @@ impl AppActionHandler for Action {
                     .check_stateless(())
                     .await
             }
+            Action::NftWithdrawal(action) => {
+                action
+                    .clone()
+                    .with_handler::<PenumbraHost>()
+                    .check_stateless(())
+                    .await
+            }
             Action::CommunityPoolSpend(action) => action.check_stateless(()).await,
             Action::CommunityPoolOutput(action) => action.check_stateless(()).await,
             Action::CommunityPoolDeposit(action) => action.check_stateless(()).await,
@@ -70,6 +89,7 @@ impl AppActionHandler for Action {
             Action::PositionClose(action) => action.check_historical(state).await,
             Action::PositionOpen(action) => action.check_historical(state).await,
             Action::PositionWithdraw(action) => action.check_historical(state).await,
+            Action::GaugeFund(action) => action.check_historical(state).await,
             Action::ProposalSubmit(action) => action.check_historical(state).await,
             Action::ProposalWithdraw(action) => action.check_historical(state).await,
             Action::ProposalDepositClaim(action) => action.check_historical(state).await,
@@ -80,7 +100,7 @@ impl AppActionHandler for Action {
             Action::IbcRelay(action) => {
                 action
                     .clone()
-                    .with_handler::<Ics20Transfer, PenumbraHost>()
+                    .with_handler::<IbcApps, PenumbraHost>()
                     .check_historical(state)
                     .await
             }
@@ -91,6 +111,13 @@ impl AppActionHandler for Action {
                     .check_historical(state)
                     .await
             }
+            Action::NftWithdrawal(action) => {
+                action
+                    .clone()
+                    .with_handler::<PenumbraHost>()
+                    .check_historical(state)
+                    .await
+            }
             Action::CommunityPoolSpend(action) => action.check_historical(state).await,
             Action::CommunityPoolOutput(action) => action.check_historical(state).await,
             Action::CommunityPoolDeposit(action) => action.check_historical(state).await,
@@ -111,6 +138,7 @@ impl AppActionHandler for Action {
             Action::PositionClose(action) => action.check_and_execute(state).await,
             Action::PositionOpen(action) => action.check_and_execute(state).await,
             Action::PositionWithdraw(action) => action.check_and_execute(state).await,
+            Action::GaugeFund(action) => action.check_and_execute(state).await,
             Action::ProposalSubmit(action) => action.check_and_execute(state).await,
             Action::ProposalWithdraw(action) => action.check_and_execute(state).await,
             Action::ProposalDepositClaim(action) => action.check_and_execute(state).await,
@@ -121,7 +149,7 @@ impl AppActionHandler for Action {
             Action::IbcRelay(action) => {
                 action
                     .clone()
-                    .with_handler::<Ics20Transfer, PenumbraHost>()
+                    .with_handler::<IbcApps, PenumbraHost>()
                     .check_and_execute(state)
                     .await
             }
@@ -132,6 +160,13 @@ impl AppActionHandler for Action {
                     .check_and_execute(state)
                     .await
             }
+            Action::NftWithdrawal(action) => {
+                action
+                    .clone()
+                    .with_handler::<PenumbraHost>()
+                    .check_and_execute(state)
+                    .await
+            }
             Action::CommunityPoolSpend(action) => action.check_and_execute(state).await,
             Action::CommunityPoolOutput(action) => action.check_and_execute(state).await,
             Action::CommunityPoolDeposit(action) => action.check_and_execute(state).await,
@@ -0,0 +1,52 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use cnidarium::StateWrite;
+use penumbra_ibc::{
+    component::{fees::WithFees, ica::IcaMessageExecutor},
+    IbcRelay,
+};
+use penumbra_proto::core::component::ibc::v1 as pb;
+use penumbra_shielded_pool::component::{
+    nft_transfer::WithNftTransfer, Ics20FeePayout, Ics20Transfer,
+};
+
+use crate::PenumbraHost;
+
+/// The IBC applications that interchain accounts can relay packets to: every
+/// application bound on Penumbra but the interchain accounts host itself, so
+/// that interchain account transactions can't nest.
+type IcaRelayApps = WithFees<WithNftTransfer<Ics20Transfer>, Ics20FeePayout>;
+
+/// Executes the messages in interchain account transactions by dispatching
+/// them into the component that handles them.
+///
+/// Interchain accounts hold no notes, so they can only execute messages that
+/// don't move value out of the account: for now, IBC messages, e.g. to update
+/// clients or relay packets on behalf of the controller chain.
+pub struct PenumbraIcaMessages;
+
+#[async_trait]
+impl IcaMessageExecutor for PenumbraIcaMessages {
+    async fn execute<S: StateWrite>(
+        mut state: S,
+        _address: &str,
+        msg: &pbjson_types::Any,
+    ) -> Result<pbjson_types::Any> {
+        let relay = IbcRelay::try_from(pb::IbcRelay {
+            raw_action: Some(msg.clone()),
+        })?;
+        if let IbcRelay::Unknown(_) = relay {
+            anyhow::bail!("message type {} is not supported", msg.type_url);
+        }
+
+        let relay = relay.with_handler::<IcaRelayApps, PenumbraHost>();
+        relay.check_stateless(()).await?;
+        relay.check_and_execute(&mut state).await?;
+
+        // IBC message responses carry no data that controllers rely on.
+        Ok(pbjson_types::Any {
+            type_url: format!("{}Response", msg.type_url),
+            value: Default::default(),
+        })
+    }
+}
@@ -1,14 +1,17 @@
+use std::collections::BTreeSet;
 use std::str::FromStr;
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use decaf377::Fq;
 use decaf377_rdsa::{VerificationKey, VerificationKeyBytes};
+use ibc_types::core::channel::PortId;
 use ibc_types::core::client::ClientId;
+use ibc_types::core::connection::ConnectionId;
 use once_cell::sync::Lazy;
 
 use cnidarium::StateWrite;
-use penumbra_asset::STAKING_TOKEN_DENOM;
+use penumbra_asset::{STAKING_TOKEN_ASSET_ID, STAKING_TOKEN_DENOM};
 use penumbra_community_pool::component::StateReadExt as _;
 use penumbra_governance::{
     component::{StateReadExt as _, StateWriteExt as _},
@@ -17,7 +20,9 @@ use penumbra_governance::{
     proposal_state::State as ProposalState,
     ProposalNft, ProposalSubmit, VotingReceiptToken,
 };
-use penumbra_ibc::component::ClientStateReadExt;
+use penumbra_ibc::component::{
+    ChannelStateReadExt as _, ClientStateReadExt, ConnectionStateReadExt as _,
+};
 use penumbra_keys::keys::{FullViewingKey, NullifierKey};
 use penumbra_proto::{DomainType, StateWriteProto as _};
 use penumbra_sct::component::clock::EpochRead;
@@ -98,9 +103,11 @@ impl AppActionHandler for ProposalSubmit {
                         | PositionOpen(_)
                         | PositionClose(_)
                         | PositionWithdraw(_)
+                        | GaugeFund(_)
                         | CommunityPoolSpend(_)
                         | CommunityPoolOutput(_)
                         | Ics20Withdrawal(_)
+                        | NftWithdrawal(_)
                         | CommunityPoolDeposit(_)
                         | ActionDutchAuctionSchedule(_)
                         | ActionDutchAuctionEnd(_)
@@ -117,6 +124,38 @@ impl AppActionHandler for ProposalSubmit {
                 let _ = &ClientId::from_str(client_id)
                     .context("can't decode client id from IBC proposal")?;
             }
+            RecoverIbcClient {
+                subject_client_id,
+                substitute_client_id,
+            } => {
+                let _ = &ClientId::from_str(subject_client_id)
+                    .context("can't decode subject client id from IBC proposal")?;
+                let _ = &ClientId::from_str(substitute_client_id)
+                    .context("can't decode substitute client id from IBC proposal")?;
+            }
+            SetIbcConnectionMetadata { connection_id, .. } => {
+                let _ = &ConnectionId::from_str(connection_id)
+                    .context("can't decode connection id from IBC proposal")?;
+            }
+            RegisterAssetMetadata { metadata } => {
+                // Each asset's metadata is registered at most once, and the staking token's
+                // metadata is fixed by the protocol.
+                let mut asset_ids = BTreeSet::new();
+                for metadata in metadata {
+                    let asset_id = metadata.id();
+                    if asset_id == *STAKING_TOKEN_ASSET_ID {
+                        anyhow::bail!(
+                            "the staking token's metadata can't be changed by governance"
+                        );
+                    }
+                    if !asset_ids.insert(asset_id) {
+                        anyhow::bail!(
+                            "metadata for {} is registered more than once",
+                            metadata.base_denom().denom
+                        );
+                    }
+                }
+            }
         }
 
         Ok(())
@@ -186,7 +225,7 @@ impl AppActionHandler for ProposalSubmit {
                 let tx = build_community_pool_transaction(parsed_transaction_plan.clone())
                     .await
                     .context("failed to build submitted Community Pool spend transaction plan")?;
-                tx.check_stateless(()).await.context(
+                tx.check_stateless(Default::default()).await.context(
                     "submitted Community Pool spend transaction failed stateless checks",
                 )?;
                 /*
@@ -222,6 +261,52 @@ impl AppActionHandler for ProposalSubmit {
                     .map_err(|e| tonic::Status::aborted(format!("invalid client id: {e}")))?;
                 let _ = state.get_client_state(client_id).await?;
             }
+            ProposalPayload::RecoverIbcClient {
+                subject_client_id,
+                substitute_client_id,
+            } => {
+                // Check that both client IDs are valid and have corresponding client
+                // states. Whether the subject can be recovered depends on the state of
+                // both clients when the proposal is enacted, so it is checked then.
+                for client_id in [subject_client_id, substitute_client_id] {
+                    let client_id = &ClientId::from_str(client_id)
+                        .map_err(|e| tonic::Status::aborted(format!("invalid client id: {e}")))?;
+                    let _ = state.get_client_state(client_id).await?;
+                }
+            }
+            ProposalPayload::SetIbcConnectionMetadata {
+                connection_id,
+                metadata,
+            } => {
+                // Check that the connection exists, and that the preferred channels are
+                // transfer channels over it, so that wallets aren't pointed elsewhere.
+                let connection_id = &ConnectionId::from_str(connection_id)
+                    .map_err(|e| tonic::Status::aborted(format!("invalid connection id: {e}")))?;
+                state
+                    .get_connection(connection_id)
+                    .await?
+                    .ok_or_else(|| anyhow::anyhow!("connection {connection_id} does not exist"))?;
+                for channel_id in metadata
+                    .iter()
+                    .flat_map(|metadata| &metadata.preferred_channels)
+                {
+                    let channel = state
+                        .get_channel(channel_id, &PortId::transfer())
+                        .await?
+                        .ok_or_else(|| {
+                            anyhow::anyhow!("transfer channel {channel_id} does not exist")
+                        })?;
+                    if channel.connection_hops.first() != Some(connection_id) {
+                        anyhow::bail!(
+                            "channel {channel_id} is not over connection {connection_id}"
+                        );
+                    }
+                }
+            }
+            ProposalPayload::RegisterAssetMetadata { .. } => {
+                // Metadata can be registered for assets that don't exist yet, e.g. those that
+                // have yet to be transferred in over IBC, so there's nothing to check.
+            }
         }
 
         // (end of former check_stateful checks)
@@ -7,6 +7,7 @@ use penumbra_fee::component::FeePay as _;
 use penumbra_sct::{component::source::SourceContext, CommitmentSource};
 use penumbra_shielded_pool::component::ClueManager;
 use penumbra_transaction::{gas::GasCost as _, Transaction};
+use penumbra_txhash::{TransactionContext, VerificationCache};
 use tokio::task::JoinSet;
 use tracing::{instrument, Instrument};
 
@@ -25,11 +26,11 @@ use stateless::{
 
 #[async_trait]
 impl AppActionHandler for Transaction {
-    type CheckStatelessContext = ();
+    type CheckStatelessContext = Arc<VerificationCache>;
 
     // We only instrument the top-level `check_stateless`, so we get one span for each transaction.
-    #[instrument(skip(self, _context))]
-    async fn check_stateless(&self, _context: ()) -> Result<()> {
+    #[instrument(skip(self, verification_cache))]
+    async fn check_stateless(&self, verification_cache: Arc<VerificationCache>) -> Result<()> {
         // This check should be done first, and complete before all other
         // stateless checks, like proof verification.  In addition to proving
         // that value balances, the binding signature binds the proofs to the
@@ -42,14 +43,17 @@ impl AppActionHandler for Transaction {
         // transactions upfront -- past this point, we can be sure that the user
         // who submitted the transaction actually formed the proofs, rather than
         // replaying them from another transaction.
-        valid_binding_signature(self)?;
+        valid_binding_signature(self, &verification_cache)?;
         // Other checks probably too cheap to be worth splitting into tasks.
         num_clues_equal_to_num_outputs(self)?;
         check_memo_exists_if_outputs_absent_if_not(self)?;
         // This check ensures that transactions contain at least one action.
         check_non_empty_transaction(self)?;
 
-        let context = self.context();
+        let context = TransactionContext {
+            verification_cache,
+            ..self.context()
+        };
 
         // Currently, we need to clone the component actions so that the spawned
         // futures can have 'static lifetimes. In the future, we could try to
@@ -302,7 +306,7 @@ mod tests {
         tx.anchor = wrong_root;
 
         // On the verifier side, perform stateless verification.
-        let result = tx.check_stateless(()).await;
+        let result = tx.check_stateless(Default::default()).await;
         assert!(result.is_err());
 
         Ok(())
@@ -1,17 +1,25 @@
 use anyhow::{Context, Result};
+use penumbra_keys::signature_batch;
 use penumbra_transaction::Transaction;
-use penumbra_txhash::AuthorizingData;
+use penumbra_txhash::{AuthorizingData, VerificationCache};
 
-#[tracing::instrument(skip(tx))]
-pub(super) fn valid_binding_signature(tx: &Transaction) -> Result<()> {
+#[tracing::instrument(skip(tx, verification_cache))]
+pub(super) fn valid_binding_signature(
+    tx: &Transaction,
+    verification_cache: &VerificationCache,
+) -> Result<()> {
     let auth_hash = tx.auth_hash();
 
     tracing::debug!(bvk = ?tx.binding_verification_key(), ?auth_hash);
 
     // Check binding signature.
-    tx.binding_verification_key()
-        .verify(auth_hash.as_bytes(), tx.binding_sig())
-        .context("binding signature failed to verify")
+    signature_batch::verify(
+        &tx.binding_verification_key(),
+        auth_hash.as_bytes(),
+        tx.binding_sig(),
+        &verification_cache.signatures,
+    )
+    .context("binding signature failed to verify")
 }
 
 pub fn num_clues_equal_to_num_outputs(tx: &Transaction) -> anyhow::Result<()> {
@@ -0,0 +1,206 @@
+use std::collections::BTreeSet;
+
+use penumbra_dex::swap::proof::SwapProofPublic;
+use penumbra_keys::signature_batch::SignatureBatch;
+use penumbra_proof_params::{
+    batch::BatchVerifier, OUTPUT_PROOF_VERIFICATION_KEY, SPEND_PROOF_VERIFICATION_KEY,
+    SWAP_PROOF_VERIFICATION_KEY,
+};
+use penumbra_proto::DomainType;
+use penumbra_shielded_pool::{output::OutputProofPublic, SpendProofPublic};
+use penumbra_transaction::Transaction;
+use penumbra_txhash::{AuthorizingData, VerificationCache};
+
+/// Verifies the signatures and proofs of the transactions of a proposal in
+/// batches, ahead of delivering them, and returns the indices of the
+/// transactions with an invalid signature, along with what verified.
+///
+/// The spend authorization and binding signatures of all the transactions are
+/// verified in a single batch; if it fails, they are verified one by one to
+/// find the offending transactions. The spend, output and swap proofs are
+/// verified with one batch per circuit.
+///
+/// Whatever verifies is recorded in the returned cache, so that the action
+/// handlers skip verifying it again when the proposal's transactions are
+/// delivered with it. Anything else, e.g. the proofs of a batch that fails or
+/// of a transaction that can't be decoded, is verified on delivery as usual,
+/// which rejects what's invalid.
+pub(crate) async fn verify_batches<T>(txs: Vec<T>) -> (BTreeSet<usize>, VerificationCache)
+where
+    T: AsRef<[u8]> + Send + 'static,
+{
+    let span = tracing::Span::current();
+    let verification = tokio::task::spawn_blocking(move || {
+        let _guard = span.enter();
+        let txs = txs
+            .iter()
+            .enumerate()
+            .filter_map(|(i, tx)| Some((i, Transaction::decode(tx.as_ref()).ok()?)))
+            .collect::<Vec<_>>();
+        let mut cache = VerificationCache::default();
+        let invalid = verify_signatures(&txs, &mut cache);
+        for &i in &invalid {
+            tracing::debug!(tx_index = i, "transaction has an invalid signature");
+        }
+        verify_proofs(&txs, &mut cache);
+        (invalid, cache)
+    });
+
+    verification.await.unwrap_or_else(|error| {
+        tracing::warn!(?error, "batch verification task failed");
+        Default::default()
+    })
+}
+
+fn verify_signatures(
+    txs: &[(usize, Transaction)],
+    cache: &mut VerificationCache,
+) -> BTreeSet<usize> {
+    let mut signatures = SignatureBatch::new();
+    for (i, tx) in txs {
+        let effect_hash = tx.context().effect_hash;
+        for spend in tx.spends() {
+            signatures.queue_spend_auth(*i, spend.body.rk, spend.auth_sig, effect_hash.as_ref());
+        }
+        for vote in tx.delegator_votes() {
+            signatures.queue_spend_auth(*i, vote.body.rk, vote.auth_sig, effect_hash.as_ref());
+        }
+        signatures.queue_binding(
+            *i,
+            tx.binding_verification_key(),
+            *tx.binding_sig(),
+            tx.auth_hash().as_bytes(),
+        );
+    }
+    signatures.verify(&mut cache.signatures)
+}
+
+fn verify_proofs(txs: &[(usize, Transaction)], cache: &mut VerificationCache) {
+    let mut spends = BatchVerifier::new();
+    let mut outputs = BatchVerifier::new();
+    let mut swaps = BatchVerifier::new();
+
+    for (_, tx) in txs {
+        for spend in tx.spends() {
+            let public = SpendProofPublic {
+                anchor: tx.anchor,
+                balance_commitment: spend.body.balance_commitment,
+                nullifier: spend.body.nullifier,
+                rk: spend.body.rk,
+            };
+            let _ = spend.proof.queue(&mut spends, public);
+        }
+        for output in tx.outputs() {
+            let public = OutputProofPublic {
+                balance_commitment: output.body.balance_commitment,
+                note_commitment: output.body.note_payload.note_commitment,
+            };
+            let _ = output.proof.queue(&mut outputs, public);
+        }
+        for swap in tx.swaps() {
+            let public = SwapProofPublic {
+                balance_commitment: swap.balance_commitment_inner(),
+                swap_commitment: swap.body.payload.commitment,
+                fee_commitment: swap.body.fee_commitment,
+            };
+            let _ = swap.proof.queue(&mut swaps, public);
+        }
+    }
+
+    for (circuit, batch, vk) in [
+        ("spend", spends, &*SPEND_PROOF_VERIFICATION_KEY),
+        ("output", outputs, &*OUTPUT_PROOF_VERIFICATION_KEY),
+        ("swap", swaps, &*SWAP_PROOF_VERIFICATION_KEY),
+    ] {
+        if batch.is_empty() {
+            continue;
+        }
+        let proofs = batch.len();
+        match batch.verify(vk, &mut cache.proofs) {
+            Ok(true) => {}
+            Ok(false) => {
+                tracing::debug!(circuit, proofs, "batch of proofs did not verify")
+            }
+            Err(error) => {
+                tracing::debug!(circuit, proofs, ?error, "failed to verify batch of proofs")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Deref;
+
+    use anyhow::Result;
+    use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+    use penumbra_fee::Fee;
+    use penumbra_keys::test_keys;
+    use penumbra_shielded_pool::{Note, OutputPlan, SpendPlan};
+    use penumbra_tct as tct;
+    use penumbra_transaction::{plan::TransactionPlan, TransactionParameters, WitnessData};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    /// Builds a transaction spending a note to the test address.
+    async fn spend_transaction() -> Result<Transaction> {
+        let value = Value {
+            amount: 100u64.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        };
+        let note = Note::generate(&mut OsRng, &test_keys::ADDRESS_0, value);
+        let mut sct = tct::Tree::new();
+        sct.insert(tct::Witness::Keep, note.commit()).unwrap();
+        let auth_path = sct.witness(note.commit()).expect("note is witnessed");
+
+        let plan = TransactionPlan {
+            transaction_parameters: TransactionParameters {
+                expiry_height: 0,
+                fee: Fee::default(),
+                chain_id: "".into(),
+            },
+            actions: vec![
+                SpendPlan::new(&mut OsRng, note.clone(), auth_path.position()).into(),
+                OutputPlan::new(&mut OsRng, value, test_keys::ADDRESS_1.deref().clone()).into(),
+            ],
+            detection_data: None,
+            memo: None,
+        };
+        let auth_data = plan.authorize(OsRng, &test_keys::SPEND_KEY)?;
+        let witness_data = WitnessData {
+            anchor: sct.root(),
+            state_commitment_proofs: [(note.commit(), auth_path)].into_iter().collect(),
+        };
+        plan.build_concurrent(&test_keys::FULL_VIEWING_KEY, &witness_data, &auth_data)
+            .await
+    }
+
+    #[tokio::test]
+    async fn rejected_proposals_do_not_seed_later_verification() -> Result<()> {
+        let tx = spend_transaction().await?;
+        // The same transaction, with a binding signature that doesn't verify.
+        let mut bad_tx = tx.clone();
+        bad_tx.binding_sig = [0u8; 64].into();
+
+        // A proposal with a transaction whose signature is invalid is rejected,
+        // even though the proofs of its other transaction verify in batches.
+        let (invalid, rejected) =
+            verify_batches(vec![tx.encode_to_vec(), bad_tx.encode_to_vec()]).await;
+        assert_eq!(invalid, BTreeSet::from([1]));
+        assert!(!rejected.signatures.is_empty());
+        assert!(!rejected.proofs.is_empty());
+
+        // What verified for the rejected proposal was only recorded in its own
+        // cache: a later proposal starts from nothing, and a transaction checked
+        // on its own is verified in full.
+        let (invalid, later) = verify_batches(Vec::<Vec<u8>>::new()).await;
+        assert!(invalid.is_empty());
+        assert!(later.signatures.is_empty() && later.proofs.is_empty());
+        let context = tx.context();
+        assert!(context.verification_cache.signatures.is_empty());
+        assert!(context.verification_cache.proofs.is_empty());
+
+        Ok(())
+    }
+}
@@ -27,11 +27,14 @@ use penumbra_sct::component::clock::EpochRead;
 use penumbra_sct::component::sct::Sct;
 use penumbra_sct::component::{StateReadExt as _, StateWriteExt as _};
 use penumbra_sct::epoch::Epoch;
-use penumbra_shielded_pool::component::{ShieldedPool, StateReadExt as _, StateWriteExt as _};
+use penumbra_shielded_pool::component::{
+    ShieldedPool, StateReadExt as _, StateWriteExt as _, SupplyManager as _,
+};
 use penumbra_stake::component::{
     stake::ConsensusUpdateRead, Staking, StateReadExt as _, StateWriteExt as _,
 };
 use penumbra_transaction::Transaction;
+use penumbra_txhash::VerificationCache;
 use prost::Message as _;
 use tendermint::abci::{self, Event};
 
@@ -41,11 +44,13 @@ use tokio::time::sleep;
 use tracing::{instrument, Instrument};
 
 use crate::action_handler::AppActionHandler;
-use crate::genesis::AppState;
+use crate::app_version::MIGRATIONS;
+use crate::genesis::{AppState, Content};
 use crate::params::change::ParameterChangeExt as _;
 use crate::params::AppParameters;
 use crate::{CommunityPoolStateReadExt, PenumbraHost};
 
+mod batch;
 pub mod state_key;
 
 /// The inter-block state being written to by the application.
@@ -142,6 +147,10 @@ impl App {
                 FeeComponent::init_chain(&mut state_tx, Some(&genesis.fee_content)).await;
                 Funding::init_chain(&mut state_tx, Some(&genesis.funding_content)).await;
 
+                state_tx
+                    .flush_supply_changes()
+                    .await
+                    .expect("must be able to record genesis supply");
                 state_tx
                     .finish_block()
                     .await
@@ -172,6 +181,28 @@ impl App {
         state_tx.apply();
     }
 
+    /// Exports the genesis state of a new chain starting from `state`, e.g. to
+    /// reset a testnet, or to fork the chain from a past height.
+    ///
+    /// See [`Component::export_genesis`] for what each component leaves behind.
+    pub async fn export_genesis<S: StateRead>(state: &S) -> Result<Content> {
+        let (stake_content, shielded_pool_content) = Staking::export_genesis(state).await?;
+        Ok(Content {
+            chain_id: state.get_chain_id().await?,
+            community_pool_content: CommunityPool::export_genesis(state).await?,
+            distributions_content: Distributions::export_genesis(state).await?,
+            fee_content: FeeComponent::export_genesis(state).await?,
+            funding_content: Funding::export_genesis(state).await?,
+            governance_content: Governance::export_genesis(state).await?,
+            ibc_content: Ibc::export_genesis(state).await?,
+            sct_content: Sct::export_genesis(state).await?,
+            shielded_pool_content,
+            stake_content,
+            dex_content: Dex::export_genesis(state).await?,
+            auction_content: Auction::export_genesis(state).await?,
+        })
+    }
+
     pub async fn prepare_proposal(
         &mut self,
         proposal: request::PrepareProposal,
@@ -198,7 +229,16 @@ impl App {
         // Tracking the size of the proposal
         let mut proposal_size_bytes = 0u64;
 
-        for tx in proposal.txs {
+        // Verify the signatures and proofs of the candidate transactions in
+        // batches first, so that delivering them doesn't verify each on its own,
+        // and leave out those with an invalid signature.
+        let (invalid_txs, verification_cache) = batch::verify_batches(proposal.txs.clone()).await;
+        let verification_cache = Arc::new(verification_cache);
+
+        for (i, tx) in proposal.txs.into_iter().enumerate() {
+            if invalid_txs.contains(&i) {
+                continue;
+            }
             let transaction_size = tx.len() as u64;
 
             // We compute the total proposal size if we were to include this transaction.
@@ -216,7 +256,10 @@ impl App {
             }
 
             // Then, we make sure to only include successful transactions.
-            match self.deliver_tx_bytes(&tx).await {
+            match self
+                .deliver_proposal_tx_bytes(&tx, verification_cache.clone())
+                .await
+            {
                 Ok(_) => {
                     proposal_size_bytes = total_with_tx;
                     included_txs.push(tx)
@@ -277,6 +320,11 @@ impl App {
         // payload: they MUST be below the tx size limit, and apply cleanly on
         // state fork.
         let mut total_txs_payload_size = 0usize;
+        let (invalid_txs, verification_cache) = batch::verify_batches(proposal.txs.clone()).await;
+        if !invalid_txs.is_empty() {
+            return response::ProcessProposal::Reject;
+        }
+        let verification_cache = Arc::new(verification_cache);
         for tx in proposal.txs {
             let tx_size = tx.len();
             if tx_size > MAX_TRANSACTION_SIZE_BYTES {
@@ -288,7 +336,10 @@ impl App {
                 return response::ProcessProposal::Reject;
             }
 
-            match self.deliver_tx_bytes(&tx).await {
+            match self
+                .deliver_proposal_tx_bytes(&tx, verification_cache.clone())
+                .await
+            {
                 Ok(_) => continue,
                 Err(_) => return response::ProcessProposal::Reject,
             }
@@ -300,6 +351,13 @@ impl App {
     pub async fn begin_block(&mut self, begin_block: &request::BeginBlock) -> Vec<abci::Event> {
         let mut state_tx = StateDelta::new(self.state.clone());
 
+        // If the state was written by an earlier app version, migrate it before
+        // anything else executes, so that the block runs against the current layout.
+        MIGRATIONS
+            .run(&mut state_tx)
+            .await
+            .expect("must be able to migrate the state to the current app version");
+
         // If a app parameter change is scheduled for this block, apply it here,
         // before any other component has executed. This ensures that app
         // parameter changes are consistently applied precisely at the boundary
@@ -370,7 +428,7 @@ impl App {
             // delivering transactions that have been scheduled by the chain itself for delivery.
             tracing::info!(?transaction, "delivering Community Pool transaction");
             match self
-                .deliver_tx_allowing_community_pool_spends(Arc::new(transaction))
+                .deliver_tx_allowing_community_pool_spends(Arc::new(transaction), Arc::default())
                 .await
             {
                 Err(error) => {
@@ -391,7 +449,29 @@ impl App {
             .context("failed to deliver transaction")
     }
 
+    /// Delivers a transaction of a proposal, skipping the signatures and
+    /// proofs that were verified in batches into the proposal's
+    /// `verification_cache`.
+    async fn deliver_proposal_tx_bytes(
+        &mut self,
+        tx_bytes: &[u8],
+        verification_cache: Arc<VerificationCache>,
+    ) -> Result<Vec<abci::Event>> {
+        let tx = Arc::new(Transaction::decode(tx_bytes).context("decoding transaction")?);
+        self.deliver_user_tx(tx, verification_cache)
+            .await
+            .context("failed to deliver transaction")
+    }
+
     pub async fn deliver_tx(&mut self, tx: Arc<Transaction>) -> Result<Vec<abci::Event>> {
+        self.deliver_user_tx(tx, Arc::default()).await
+    }
+
+    async fn deliver_user_tx(
+        &mut self,
+        tx: Arc<Transaction>,
+        verification_cache: Arc<VerificationCache>,
+    ) -> Result<Vec<abci::Event>> {
         // Ensure that any normally-delivered transaction (originating from a user) does not contain
         // any Community Pool spends or outputs; the only place those are permitted is transactions originating
         // from the chain itself:
@@ -403,14 +483,21 @@ impl App {
             tx.community_pool_outputs().peekable().peek().is_none(),
             "Community Pool outputs are not permitted in user-submitted transactions"
         );
+        // Reward gauges are funded by governance, through Community Pool spend proposals.
+        anyhow::ensure!(
+            tx.gauge_fundings().peekable().peek().is_none(),
+            "gauge fundings are not permitted in user-submitted transactions"
+        );
 
-        // Now that we've ensured that there are not any Community Pool spends or outputs, we can deliver the transaction:
-        self.deliver_tx_allowing_community_pool_spends(tx).await
+        // Now that we've ensured that there are not any Community Pool spends, outputs or gauge fundings, we can deliver the transaction:
+        self.deliver_tx_allowing_community_pool_spends(tx, verification_cache)
+            .await
     }
 
     async fn deliver_tx_allowing_community_pool_spends(
         &mut self,
         tx: Arc<Transaction>,
+        verification_cache: Arc<VerificationCache>,
     ) -> Result<Vec<abci::Event>> {
         // Both stateful and stateless checks take the transaction as
         // verification context.  The separate clone of the Arc<Transaction>
@@ -420,7 +507,8 @@ impl App {
         // and I/O-bound stateful checks at the same time.
         let tx2 = tx.clone();
         let stateless = tokio::spawn(
-            async move { tx2.check_stateless(()).await }.instrument(tracing::Span::current()),
+            async move { tx2.check_stateless(verification_cache).await }
+                .instrument(tracing::Span::current()),
         );
         let tx2 = tx.clone();
         let state2 = self.state.clone();
@@ -471,7 +559,7 @@ impl App {
         Sct::end_block(&mut arc_state_tx, end_block).await;
         ShieldedPool::end_block(&mut arc_state_tx, end_block).await;
         Distributions::end_block(&mut arc_state_tx, end_block).await;
-        Ibc::end_block(&mut arc_state_tx, end_block).await;
+        Ibc::end_block::<PenumbraHost, _>(&mut arc_state_tx, end_block).await;
         Auction::end_block(&mut arc_state_tx, end_block).await;
         Dex::end_block(&mut arc_state_tx, end_block).await;
         CommunityPool::end_block(&mut arc_state_tx, end_block).await;
@@ -549,6 +637,10 @@ impl App {
             let mut state_tx = Arc::try_unwrap(arc_state_tx)
                 .expect("components did not retain copies of shared state");
 
+            state_tx
+                .flush_supply_changes()
+                .await
+                .expect("must be able to record supply changes");
             state_tx
                 .finish_epoch()
                 .await
@@ -573,6 +665,10 @@ impl App {
                 current_epoch,
             );
 
+            state_tx
+                .flush_supply_changes()
+                .await
+                .expect("must be able to record supply changes");
             state_tx
                 .finish_block()
                 .await
@@ -620,6 +716,12 @@ impl App {
         // assuming a `5s` timeout.
         // See #4443 for more context.
         if should_halt || is_pre_upgrade_height {
+            // The state we halt at must be on disk, since the blocks leading to it
+            // can't be replayed by the next version of the node.
+            storage
+                .flush()
+                .await
+                .expect("must be able to write the state to disk before halting");
             tokio::spawn(async move {
                 sleep(Duration::from_secs(2)).await;
                 tracing::info!("halt signal recorded, exiting process");
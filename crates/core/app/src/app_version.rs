@@ -5,6 +5,8 @@ pub const APP_VERSION: u64 = 8;
 cfg_if::cfg_if! {
     if #[cfg(feature="component")] {
         mod component;
+        mod migration;
         pub use component::{check_and_update_app_version, migrate_app_version};
+        pub use migration::{Migration, MigrationRegistry, MigrationState, MIGRATIONS};
     }
 }
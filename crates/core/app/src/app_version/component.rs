@@ -4,7 +4,7 @@ use anyhow::{anyhow, Context};
 use cnidarium::{StateDelta, Storage};
 use penumbra_proto::{StateReadProto, StateWriteProto};
 
-use super::APP_VERSION;
+use super::{APP_VERSION, MIGRATIONS};
 
 fn version_to_software_version(version: u64) -> &'static str {
     match version {
@@ -83,7 +83,9 @@ fn check_version(ctx: CheckContext, expected: u64, found: Option<u64>) -> anyhow
 }
 
 /// Read the app version safeguard from nonverifiable storage.
-async fn read_app_version_safeguard<S: StateReadProto>(s: &S) -> anyhow::Result<Option<u64>> {
+pub(super) async fn read_app_version_safeguard<S: StateReadProto>(
+    s: &S,
+) -> anyhow::Result<Option<u64>> {
     let out = s
         .nonverifiable_get_proto(crate::app::state_key::app_version::safeguard().as_bytes())
         .await
@@ -92,7 +94,7 @@ async fn read_app_version_safeguard<S: StateReadProto>(s: &S) -> anyhow::Result<
 }
 
 /// Write the app version safeguard to nonverifiable storage.
-fn write_app_version_safeguard<S: StateWriteProto>(s: &mut S, x: u64) {
+pub(super) fn write_app_version_safeguard<S: StateWriteProto>(s: &mut S, x: u64) {
     s.nonverifiable_put_proto(
         crate::app::state_key::app_version::safeguard()
             .as_bytes()
@@ -103,8 +105,12 @@ fn write_app_version_safeguard<S: StateWriteProto>(s: &mut S, x: u64) {
 
 /// Ensure that the app version safeguard is `APP_VERSION`, or update it if it is missing.
 ///
+/// If the safeguard is an earlier version that the registered [`MIGRATIONS`] can migrate
+/// from, the state is left as is, and migrated at the start of the next block.
+///
 /// # Errors
-/// This method errors if the app version safeguard is different than `APP_VERSION`.
+/// This method errors if the app version safeguard is different than `APP_VERSION`,
+/// and can't be migrated from.
 ///
 /// # Usage
 /// This should be called on startup. This method short-circuits if the database
@@ -128,6 +134,13 @@ pub async fn check_and_update_app_version(s: Storage) -> anyhow::Result<()> {
             write_app_version_safeguard(&mut delta, APP_VERSION);
             s.commit_in_place(delta).await?;
         }
+        Some(found) if MIGRATIONS.can_migrate_from(found) => {
+            tracing::info!(
+                found,
+                ?APP_VERSION,
+                "state will be migrated to the current app version at the next block"
+            );
+        }
         Some(found) => check_version(CheckContext::Running, APP_VERSION, Some(found))?,
     }
     Ok(())
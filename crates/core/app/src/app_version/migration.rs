@@ -0,0 +1,164 @@
+//! Migrations of the state between app versions.
+//!
+//! When a new app version changes how the state is laid out, it registers a
+//! [`Migration`] that rewrites the state of the previous app version. Once the
+//! chain halts for the upgrade and restarts with the new version, the pending
+//! migrations run in order at the start of the next block, before any
+//! component executes, so every node migrates its state as part of the same
+//! state transition.
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use anyhow::{Context as _, Result};
+use async_trait::async_trait;
+use cnidarium::{Snapshot, StateDelta};
+use once_cell::sync::Lazy;
+
+use super::{component::read_app_version_safeguard, migrate_app_version, APP_VERSION};
+
+/// The state migrations run at the start of a block.
+pub type MigrationState = StateDelta<Arc<StateDelta<Snapshot>>>;
+
+/// The migrations of the state to the current app version.
+///
+/// Migrations are registered here, as the app version is bumped.
+pub static MIGRATIONS: Lazy<MigrationRegistry> = Lazy::new(MigrationRegistry::default);
+
+/// A migration of the state from the app version before
+/// [`Migration::app_version`] to that app version.
+#[async_trait]
+pub trait Migration: Send + Sync {
+    /// The app version the state is migrated to.
+    fn app_version(&self) -> u64;
+
+    /// A short description of the migration, for logging.
+    fn name(&self) -> &'static str;
+
+    /// Rewrites the state of the previous app version.
+    async fn migrate(&self, state: &mut MigrationState) -> Result<()>;
+}
+
+/// A set of migrations, keyed by the app version they migrate the state to.
+#[derive(Default)]
+pub struct MigrationRegistry {
+    migrations: BTreeMap<u64, Box<dyn Migration>>,
+}
+
+impl MigrationRegistry {
+    /// Registers a migration.
+    ///
+    /// # Errors
+    /// Returns an error if a migration to the same app version is already
+    /// registered, or if the app version is not one this binary knows of.
+    pub fn register(&mut self, migration: impl Migration + 'static) -> Result<()> {
+        let app_version = migration.app_version();
+        anyhow::ensure!(
+            app_version > 1 && app_version <= APP_VERSION,
+            "can't register a migration to app version {app_version}"
+        );
+        anyhow::ensure!(
+            !self.migrations.contains_key(&app_version),
+            "a migration to app version {app_version} is already registered"
+        );
+        self.migrations.insert(app_version, Box::new(migration));
+        Ok(())
+    }
+
+    /// Returns the migrations bringing the state from app version `from` to
+    /// [`APP_VERSION`], in order.
+    ///
+    /// # Errors
+    /// Returns an error if the migration to one of the app versions in between
+    /// is missing.
+    fn pending(&self, from: u64) -> Result<Vec<&dyn Migration>> {
+        ((from + 1)..=APP_VERSION)
+            .map(|app_version| {
+                self.migrations
+                    .get(&app_version)
+                    .map(|migration| migration.as_ref())
+                    .with_context(|| format!("no migration to app version {app_version}"))
+            })
+            .collect()
+    }
+
+    /// Returns whether the state of app version `from` can be migrated to
+    /// [`APP_VERSION`].
+    pub fn can_migrate_from(&self, from: u64) -> bool {
+        from < APP_VERSION && self.pending(from).is_ok()
+    }
+
+    /// Migrates `state` to [`APP_VERSION`], if it was written by an earlier
+    /// app version, and records the app version reached after each migration.
+    pub async fn run(&self, state: &mut MigrationState) -> Result<()> {
+        let Some(found) = read_app_version_safeguard(state).await? else {
+            return Ok(());
+        };
+        if found >= APP_VERSION {
+            return Ok(());
+        }
+
+        for migration in self.pending(found)? {
+            let app_version = migration.app_version();
+            tracing::info!(app_version, name = migration.name(), "migrating state");
+            migration
+                .migrate(state)
+                .await
+                .with_context(|| format!("failed to migrate state to app version {app_version}"))?;
+            migrate_app_version(state, app_version).await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::{StateRead as _, StateWrite as _, TempStorage};
+
+    use super::super::component::write_app_version_safeguard;
+    use super::*;
+
+    struct PutKey(u64);
+
+    #[async_trait]
+    impl Migration for PutKey {
+        fn app_version(&self) -> u64 {
+            self.0
+        }
+
+        fn name(&self) -> &'static str {
+            "put key"
+        }
+
+        async fn migrate(&self, state: &mut MigrationState) -> Result<()> {
+            state.put_raw(format!("migrated_to_{}", self.0), vec![]);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn migrations_run_in_order() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(Arc::new(StateDelta::new(storage.latest_snapshot())));
+        write_app_version_safeguard(&mut state, APP_VERSION - 2);
+
+        let mut registry = MigrationRegistry::default();
+        registry.register(PutKey(APP_VERSION))?;
+        assert!(!registry.can_migrate_from(APP_VERSION - 2));
+        assert!(registry.run(&mut state).await.is_err());
+
+        registry.register(PutKey(APP_VERSION - 1))?;
+        assert!(registry.register(PutKey(APP_VERSION)).is_err());
+        assert!(registry.register(PutKey(APP_VERSION + 1)).is_err());
+        assert!(registry.can_migrate_from(APP_VERSION - 2));
+        assert!(!registry.can_migrate_from(APP_VERSION));
+
+        registry.run(&mut state).await?;
+        assert_eq!(read_app_version_safeguard(&state).await?, Some(APP_VERSION));
+        for app_version in [APP_VERSION - 1, APP_VERSION] {
+            let key = format!("migrated_to_{app_version}");
+            assert!(state.get_raw(&key).await?.is_some());
+        }
+
+        Ok(())
+    }
+}
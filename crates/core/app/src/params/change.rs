@@ -1,3 +1,4 @@
+use std::collections::BTreeSet;
 use std::fmt::Display;
 
 use anyhow::Context;
@@ -88,6 +89,13 @@ impl AppParameters {
                     ibc_enabled: _,
                     inbound_ics20_transfers_enabled: _,
                     outbound_ics20_transfers_enabled: _,
+                    max_expected_time_per_block: _,
+                    ics20_rate_limits: _,
+                    inbound_ics20_allowlist: _,
+                    inbound_ics20_denylist: _,
+                    ack_retention_blocks: _,
+                    accepted_proof_spec_templates: _,
+                    ica_allowed_messages: _,
                 },
             sct_params: SctParameters { epoch_duration },
             shielded_pool_params: ShieldedPoolParameters { fmd_meta_params: _ },
@@ -108,6 +116,12 @@ impl AppParameters {
                     max_hops: _,
                     max_positions_per_pair: _,
                     max_execution_budget: _,
+                    price_oracle_windows: _,
+                    max_dynamic_candidates: _,
+                    spill_price_margin_bps: _,
+                    max_price_deviation_bps: _,
+                    price_deviation_window: _,
+                    max_reward_gauges_per_pair: _,
                 },
             // IMPORTANT: Don't use `..` here! We want to ensure every single field is verified!
         } = self;
@@ -183,6 +197,13 @@ impl AppParameters {
                     ibc_enabled,
                     inbound_ics20_transfers_enabled,
                     outbound_ics20_transfers_enabled,
+                    max_expected_time_per_block,
+                    ics20_rate_limits,
+                    inbound_ics20_allowlist,
+                    inbound_ics20_denylist,
+                    ack_retention_blocks: _,
+                    accepted_proof_spec_templates,
+                    ica_allowed_messages,
                 },
             sct_params: SctParameters { epoch_duration },
             shielded_pool_params: ShieldedPoolParameters { fmd_meta_params: _ },
@@ -203,6 +224,12 @@ impl AppParameters {
                     max_hops: _,
                     max_positions_per_pair: _,
                     max_execution_budget: _,
+                    price_oracle_windows,
+                    max_dynamic_candidates: _,
+                    spill_price_margin_bps,
+                    max_price_deviation_bps,
+                    price_deviation_window,
+                    max_reward_gauges_per_pair,
                 },
             // IMPORTANT: Don't use `..` here! We want to ensure every single field is verified!
         } = self;
@@ -250,6 +277,57 @@ impl AppParameters {
                     || *ibc_enabled,
                 "IBC must be enabled if either inbound or outbound ICS20 transfers are enabled",
             ),
+            (
+                !max_expected_time_per_block.is_zero(),
+                "IBC max expected time per block must be positive",
+            ),
+            (
+                ics20_rate_limits
+                    .iter()
+                    .all(|limit| limit.window_blocks >= 1),
+                "ICS20 rate limit windows must be at least one block",
+            ),
+            (
+                ics20_rate_limits
+                    .iter()
+                    .map(|limit| (limit.channel_id.as_str(), limit.asset_id))
+                    .collect::<BTreeSet<_>>()
+                    .len()
+                    == ics20_rate_limits.len(),
+                "ICS20 rate limits must each apply to a different channel and asset",
+            ),
+            (
+                inbound_ics20_allowlist
+                    .iter()
+                    .chain(inbound_ics20_denylist)
+                    .all(|filter| filter.channel_id.is_some() || filter.denom.is_some()),
+                "ICS20 asset filters must match a channel, a denom, or both",
+            ),
+            (
+                !accepted_proof_spec_templates.is_empty(),
+                "at least one proof spec template must be accepted",
+            ),
+            (
+                accepted_proof_spec_templates.iter().all(|template| {
+                    penumbra_ibc::params::check_proof_specs_are_safe(&template.proof_specs).is_ok()
+                }),
+                "accepted proof spec templates must be safe to verify proofs with",
+            ),
+            (
+                accepted_proof_spec_templates
+                    .iter()
+                    .map(|template| template.name.as_str())
+                    .collect::<BTreeSet<_>>()
+                    .len()
+                    == accepted_proof_spec_templates.len(),
+                "accepted proof spec templates must have distinct names",
+            ),
+            (
+                ica_allowed_messages
+                    .iter()
+                    .all(|type_url| type_url.starts_with('/')),
+                "interchain account message type URLs must begin with '/'",
+            ),
             (
                 *proposal_voting_blocks >= 1,
                 "proposal voting blocks must be at least 1",
@@ -274,6 +352,26 @@ impl AppParameters {
                 *min_validator_stake >= 1_000_000u128.into(),
                 "the minimum validator stake must be at least 1penumbra",
             ),
+            (
+                price_oracle_windows.iter().all(|window| *window >= 1),
+                "price oracle windows must be at least one block",
+            ),
+            (
+                *spill_price_margin_bps <= 10_000,
+                "spill price margin must be at most 10,000 basis points",
+            ),
+            (
+                *max_price_deviation_bps == 0
+                    || (*price_deviation_window >= 1
+                        && price_oracle_windows
+                            .iter()
+                            .any(|window| window >= price_deviation_window)),
+                "price deviation window must be at least one block, and at most the longest price oracle window",
+            ),
+            (
+                (1..=16).contains(max_reward_gauges_per_pair),
+                "the maximum number of reward gauges per pair must be between 1 and 16",
+            ),
         ])
     }
 }
@@ -38,6 +38,7 @@ use {
                 },
                 fee::v1::query_service_server::QueryServiceServer as FeeQueryServiceServer,
                 governance::v1::query_service_server::QueryServiceServer as GovernanceQueryServiceServer,
+                ibc::v1::query_service_server::QueryServiceServer as IbcQueryServiceServer,
                 sct::v1::query_service_server::QueryServiceServer as SctQueryServiceServer,
                 shielded_pool::v1::query_service_server::QueryServiceServer as ShieldedPoolQueryServiceServer,
                 stake::v1::query_service_server::QueryServiceServer as StakeQueryServiceServer,
@@ -119,6 +120,7 @@ pub fn router(
         .add_service(we(ClientQueryServer::new(ibc.clone())))
         .add_service(we(ChannelQueryServer::new(ibc.clone())))
         .add_service(we(ConnectionQueryServer::new(ibc.clone())))
+        .add_service(we(IbcQueryServiceServer::new(ibc.clone())))
         .add_service(we(TendermintProxyServiceServer::new(tm_proxy)))
         .add_service(we(SimulationServiceServer::new(DexServer::new(
             storage.clone(),
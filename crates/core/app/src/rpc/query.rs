@@ -1,10 +1,21 @@
 use {
     crate::app::StateReadExt as _,
     cnidarium::Storage,
-    penumbra_proto::core::app::v1::{
-        query_service_server::QueryService, AppParametersRequest, AppParametersResponse,
-        TransactionsByHeightRequest, TransactionsByHeightResponse,
+    futures::{StreamExt, TryStreamExt},
+    penumbra_asset::asset,
+    penumbra_community_pool::StateReadExt as _,
+    penumbra_ibc::component::state_key::ics20_value_balance,
+    penumbra_num::Amount,
+    penumbra_proto::{
+        core::app::v1::{
+            query_service_server::QueryService, AppParametersRequest, AppParametersResponse,
+            TransactionsByHeightRequest, TransactionsByHeightResponse, ValueBalanceAuditRequest,
+            ValueBalanceAuditResponse,
+        },
+        StateReadProto as _,
     },
+    penumbra_shielded_pool::component::SupplyRead as _,
+    std::{collections::BTreeMap, pin::Pin},
     tonic::Status,
     tracing::instrument,
 };
@@ -55,4 +66,91 @@ impl QueryService for AppQueryServer {
             app_parameters: Some(app_parameters.into()),
         }))
     }
+
+    type ValueBalanceAuditStream = Pin<
+        Box<dyn futures::Stream<Item = Result<ValueBalanceAuditResponse, tonic::Status>> + Send>,
+    >;
+
+    /// Reports, for each asset, the changes the chain has made to its supply,
+    /// next to the IBC and Community Pool balances they reconcile with.
+    ///
+    /// Supply changes are only tracked from the upgrade that introduced them,
+    /// so on older chains the Community Pool doesn't reconcile with them.
+    #[instrument(skip(self, request))]
+    async fn value_balance_audit(
+        &self,
+        request: tonic::Request<ValueBalanceAuditRequest>,
+    ) -> Result<tonic::Response<Self::ValueBalanceAuditStream>, Status> {
+        let state = self.storage.latest_snapshot();
+        let asset_ids = request
+            .into_inner()
+            .asset_ids
+            .into_iter()
+            .map(asset::Id::try_from)
+            .collect::<anyhow::Result<Vec<_>>>()
+            .map_err(|e| Status::invalid_argument(format!("invalid asset ID: {e}")))?;
+
+        let supply_error =
+            |e: anyhow::Error| Status::internal(format!("error reading supply: {e}"));
+        let supplies = if asset_ids.is_empty() {
+            state.all_asset_supplies().await.map_err(supply_error)?
+        } else {
+            let mut supplies = BTreeMap::new();
+            for asset_id in asset_ids {
+                let supply = state.asset_supply(&asset_id).await.map_err(supply_error)?;
+                supplies.insert(asset_id, supply);
+            }
+            supplies
+        };
+
+        // The ICS20 value balances are kept per channel, so add them up for each asset.
+        let ibc_balances = state
+            .prefix::<Amount>(ics20_value_balance::prefix())
+            .map(|result| {
+                let (key, amount) = result?;
+                let asset_id: asset::Id = key
+                    .rsplit('/')
+                    .next()
+                    .expect("key is well-formed")
+                    .parse()?;
+                anyhow::Ok((asset_id, amount))
+            })
+            .try_fold(
+                BTreeMap::new(),
+                |mut balances, (asset_id, amount)| async move {
+                    *balances.entry(asset_id).or_insert_with(Amount::zero) += amount;
+                    Ok(balances)
+                },
+            )
+            .await
+            .map_err(|e| Status::internal(format!("error reading IBC balances: {e}")))?;
+
+        let mut responses = Vec::with_capacity(supplies.len());
+        for (asset_id, supply) in supplies {
+            let community_pool_balance = state
+                .community_pool_asset_balance(asset_id)
+                .await
+                .map_err(|e| {
+                    Status::internal(format!("error reading Community Pool balance: {e}"))
+                })?;
+            let ibc_balance = ibc_balances
+                .get(&asset_id)
+                .copied()
+                .unwrap_or_else(Amount::zero);
+
+            responses.push(Ok(ValueBalanceAuditResponse {
+                asset_id: Some(asset_id.into()),
+                issued: supply.issued().map(Into::into),
+                ibc_balance: Some(ibc_balance.into()),
+                community_pool_balance: Some(community_pool_balance.into()),
+                community_pool_reconciled: supply.community_pool_balance()
+                    == Some(community_pool_balance),
+                supply: Some(supply.into()),
+            }));
+        }
+
+        Ok(tonic::Response::new(
+            futures::stream::iter(responses).boxed(),
+        ))
+    }
 }
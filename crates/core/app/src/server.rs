@@ -2,8 +2,11 @@
 
 use {
     self::{
-        consensus::Consensus, events::EventIndexLayer, info::Info, mempool::Mempool,
-        snapshot::Snapshot,
+        consensus::Consensus,
+        events::EventIndexLayer,
+        info::Info,
+        mempool::Mempool,
+        snapshot::{Snapshot, SnapshotStore},
     },
     cnidarium::Storage,
     penumbra_tower_trace::trace::request_span,
@@ -19,10 +22,15 @@ pub mod mempool;
 pub mod snapshot;
 
 mod events;
+mod pending_updates;
 
 /// Returns a newly instantiated ABCI [`Server`], backed by the provided [`Storage`].
+///
+/// If a [`SnapshotStore`] is provided, the snapshots in it are served to nodes
+/// that state sync.
 pub fn new(
     storage: Storage,
+    snapshots: Option<SnapshotStore>,
 ) -> Server<
     // These bounds ensure that the server can be bound to a TCP port, or a Unix socket.
     impl tower_service::Service<
@@ -60,7 +68,7 @@ pub fn new(
             Mempool::new(storage.clone(), queue).run()
         }));
     let info = Info::new(storage.clone());
-    let snapshot = Snapshot {};
+    let snapshot = Snapshot::new(storage.clone(), snapshots);
 
     tower_abci::v037::Server::builder()
         .consensus(consensus)
@@ -80,7 +88,7 @@ mod bounds_test {
     async fn servers_can_listen() {
         let storage: cnidarium::Storage = todo!();
         let addr: std::net::SocketAddr = todo!();
-        let server = super::new(storage).listen_tcp(addr);
+        let server = super::new(storage, None).listen_tcp(addr);
         drop(server);
     }
 }
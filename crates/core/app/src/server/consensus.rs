@@ -16,6 +16,9 @@ pub struct Consensus {
     queue: mpsc::Receiver<Message<Request, Response, tower::BoxError>>,
     storage: Storage,
     app: App,
+    /// Whether the storage was empty when the service started, in which case
+    /// it may have been restored from a state sync snapshot since.
+    started_empty: bool,
 }
 
 pub type ConsensusService = tower_actor::Actor<Request, Response, BoxError>;
@@ -45,11 +48,13 @@ impl Consensus {
         queue: mpsc::Receiver<Message<Request, Response, tower::BoxError>>,
     ) -> Self {
         let app = App::new(storage.latest_snapshot());
+        let started_empty = storage.latest_version() == u64::MAX;
 
         Self {
             queue,
             storage,
             app,
+            started_empty,
         }
     }
 
@@ -188,6 +193,12 @@ impl Consensus {
         // included in the span modeling the abci request handling.
         tracing::info!(time = ?begin_block.header.time, "beginning block");
 
+        // State sync restores the storage behind the app's back, so the app
+        // has to pick up the restored state before its first block.
+        if std::mem::take(&mut self.started_empty) {
+            self.app = App::new(self.storage.latest_snapshot());
+        }
+
         let events = self.app.begin_block(&begin_block).await;
 
         Ok(response::BeginBlock { events })
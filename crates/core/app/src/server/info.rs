@@ -8,7 +8,7 @@ use std::{
 
 use crate::PenumbraHost;
 use anyhow::Context as _;
-use cnidarium::Storage;
+use cnidarium::{StateRead as _, Storage};
 use futures::FutureExt;
 use ibc_proto::ibc::core::{
     channel::v1::{
@@ -26,6 +26,7 @@ use ibc_proto::ibc::core::{
 use ibc_types::core::channel::IdentifiedChannelEnd;
 use ibc_types::core::channel::{ChannelId, PortId};
 use ibc_types::core::client::ClientId;
+use ibc_types::core::commitment::MerkleProof;
 use ibc_types::core::connection::ConnectionId;
 use ibc_types::core::connection::IdentifiedConnectionEnd;
 use penumbra_ibc::component::ChannelStateReadExt as _;
@@ -139,46 +140,25 @@ impl Info {
 
                 let key = hex::decode(&query.data).unwrap_or_else(|_| query.data.to_vec());
 
-                let (value, proof) =
-                    snapshot
-                        .get_with_proof(key.clone())
+                // Proving a key takes a walk down the JMT of its substore and
+                // of the main store, so it's only done when asked for.
+                let (value, proof) = if query.prove {
+                    let (value, proof) =
+                        snapshot
+                            .get_with_proof(key.clone())
+                            .await
+                            .with_context(|| {
+                                format!("failed to get key {}", String::from_utf8_lossy(&key))
+                            })?;
+                    (value, Some(proof_ops(proof)?))
+                } else {
+                    let key_str = std::str::from_utf8(&key).context("state keys are utf-8")?;
+                    let value = snapshot
+                        .get_raw(key_str)
                         .await
-                        .with_context(|| {
-                            format!("failed to get key {}", String::from_utf8_lossy(&key))
-                        })?;
-
-                let mut ops = vec![];
-                for commitment_proof in proof.proofs {
-                    match commitment_proof
-                        .clone()
-                        .proof
-                        .expect("should have non empty commitment proofs")
-                    {
-                        ics23::commitment_proof::Proof::Exist(x_proof) => {
-                            let proof_op = tendermint::merkle::proof::ProofOp {
-                                field_type: "jmt:v".to_string(),
-                                key: x_proof.key,
-                                data: commitment_proof.encode_to_vec(),
-                            };
-                            ops.push(proof_op);
-                        }
-                        ics23::commitment_proof::Proof::Nonexist(nx_proof) => {
-                            let proof_op = tendermint::merkle::proof::ProofOp {
-                                field_type: "jmt:v".to_string(),
-                                key: nx_proof.key,
-                                data: commitment_proof.encode_to_vec(),
-                            };
-                            ops.push(proof_op);
-                        }
-                        ics23::commitment_proof::Proof::Batch(_) => {
-                            anyhow::bail!("batch proofs not supported in abci query")
-                        }
-                        ics23::commitment_proof::Proof::Compressed(_) => {
-                            anyhow::bail!("compressed proofs not supported in abci query")
-                        }
-                    }
-                }
-                let proof_ops = tendermint::merkle::proof::ProofOps { ops };
+                        .with_context(|| format!("failed to get key {key_str}"))?;
+                    (value, None)
+                };
                 let value = value.unwrap_or_else(Vec::new);
 
                 Ok(response::Query {
@@ -186,7 +166,7 @@ impl Info {
                     key: query.data,
                     log: "".to_string(),
                     value: value.into(),
-                    proof: Some(proof_ops),
+                    proof,
                     height: height.try_into().context("failed to convert height")?,
                     codespace: "".to_string(),
                     info: "".to_string(),
@@ -602,6 +582,43 @@ impl Info {
     }
 }
 
+/// Converts a proof of a key into the proof operations of an ABCI query
+/// response, one for each proof from the key up to the root.
+fn proof_ops(proof: MerkleProof) -> anyhow::Result<tendermint::merkle::proof::ProofOps> {
+    let mut ops = vec![];
+    for commitment_proof in proof.proofs {
+        match commitment_proof
+            .clone()
+            .proof
+            .expect("should have non empty commitment proofs")
+        {
+            ics23::commitment_proof::Proof::Exist(x_proof) => {
+                let proof_op = tendermint::merkle::proof::ProofOp {
+                    field_type: "jmt:v".to_string(),
+                    key: x_proof.key,
+                    data: commitment_proof.encode_to_vec(),
+                };
+                ops.push(proof_op);
+            }
+            ics23::commitment_proof::Proof::Nonexist(nx_proof) => {
+                let proof_op = tendermint::merkle::proof::ProofOp {
+                    field_type: "jmt:v".to_string(),
+                    key: nx_proof.key,
+                    data: commitment_proof.encode_to_vec(),
+                };
+                ops.push(proof_op);
+            }
+            ics23::commitment_proof::Proof::Batch(_) => {
+                anyhow::bail!("batch proofs not supported in abci query")
+            }
+            ics23::commitment_proof::Proof::Compressed(_) => {
+                anyhow::bail!("compressed proofs not supported in abci query")
+            }
+        }
+    }
+    Ok(tendermint::merkle::proof::ProofOps { ops })
+}
+
 impl tower_service::Service<InfoRequest> for Info {
     type Response = InfoResponse;
     type Error = BoxError;
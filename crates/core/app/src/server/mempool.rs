@@ -1,7 +1,12 @@
-use anyhow::Result;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
 
 use cnidarium::Storage;
+use penumbra_proto::DomainType;
+use penumbra_transaction::Transaction;
 
+use tendermint::abci::Event;
 use tendermint::v0_37::abci::{
     request::CheckTx as CheckTxReq, request::CheckTxKind, response::CheckTx as CheckTxRsp,
     MempoolRequest as Request, MempoolResponse as Response,
@@ -10,12 +15,14 @@ use tokio::sync::mpsc;
 use tower_actor::Message;
 use tracing::Instrument;
 
+use super::pending_updates::PendingClientUpdates;
 use crate::{app::App, metrics};
 
 /// A mempool service that applies transaction checks against an isolated application fork.
 pub struct Mempool {
     queue: mpsc::Receiver<Message<Request, Response, tower::BoxError>>,
     storage: Storage,
+    pending_updates: PendingClientUpdates,
 }
 
 impl Mempool {
@@ -23,7 +30,37 @@ impl Mempool {
         storage: Storage,
         queue: mpsc::Receiver<Message<Request, Response, tower::BoxError>>,
     ) -> Self {
-        Self { queue, storage }
+        Self {
+            queue,
+            storage,
+            pending_updates: PendingClientUpdates::default(),
+        }
+    }
+
+    /// Checks a transaction against a fork of the latest state, rejecting it if
+    /// it only submits client updates that are already pending.
+    async fn check_tx_bytes(&mut self, tx_bytes: &[u8]) -> Result<Vec<Event>> {
+        let snapshot = self.storage.latest_snapshot();
+        let version = snapshot.version();
+        let tx = Arc::new(Transaction::decode(tx_bytes).context("decoding transaction")?);
+        let id = tx.id();
+
+        self.pending_updates.prune(version);
+        if self.pending_updates.is_redundant(&id, &tx) {
+            anyhow::bail!("transaction only submits client updates that are already pending");
+        }
+
+        let mut app = App::new(snapshot);
+        match app.deliver_tx(tx.clone()).await {
+            Ok(events) => {
+                self.pending_updates.claim(id, &tx, version);
+                Ok(events)
+            }
+            Err(e) => {
+                self.pending_updates.release(&id);
+                Err(e).context("failed to deliver transaction")
+            }
+        }
     }
 
     pub async fn check_tx(&mut self, req: Request) -> Result<Response, tower::BoxError> {
@@ -37,9 +74,7 @@ impl Mempool {
             CheckTxKind::Recheck => "recheck",
         };
 
-        let mut app = App::new(self.storage.latest_snapshot());
-
-        match app.deliver_tx_bytes(tx_bytes.as_ref()).await {
+        match self.check_tx_bytes(tx_bytes.as_ref()).await {
             Ok(events) => {
                 let elapsed = start.elapsed();
                 tracing::info!(?elapsed, "tx accepted");
@@ -0,0 +1,171 @@
+//! Deduplication of IBC client updates pending in the mempool.
+//!
+//! Relayers racing to relay the same packets often submit identical
+//! `MsgUpdateClient`s. Only the first of them to be included does anything: the
+//! rest are no-ops, but still take up block space. So the mempool tracks the
+//! client updates of the transactions it has accepted, and rejects transactions
+//! that only submit updates that another pending transaction already submits.
+//!
+//! Transactions are checked against forks of the latest state that are never
+//! committed, so this is tracked in memory rather than in the state, and is
+//! local to each node. It never affects consensus: a duplicate update that
+//! makes it into a block is still a no-op.
+
+use std::collections::BTreeMap;
+
+use penumbra_ibc::IbcRelay;
+use penumbra_transaction::{Action, Transaction};
+use penumbra_txhash::TransactionId;
+use sha2::{Digest, Sha256};
+
+/// A client update, identified by the client it updates and a digest of the
+/// header it submits.
+type ClientUpdate = (String, [u8; 32]);
+
+/// The client updates submitted by the transactions in the mempool.
+#[derive(Debug, Default)]
+pub struct PendingClientUpdates {
+    /// The transaction that submitted each update, and the version of the
+    /// state it was last checked against.
+    claims: BTreeMap<ClientUpdate, (TransactionId, u64)>,
+}
+
+impl PendingClientUpdates {
+    /// Gets the client updates in `tx`.
+    pub fn client_updates(tx: &Transaction) -> Vec<ClientUpdate> {
+        tx.ibc_actions()
+            .filter_map(|action| match action {
+                IbcRelay::UpdateClient(msg) => Some((
+                    msg.client_id.to_string(),
+                    Sha256::digest(&msg.client_message.value).into(),
+                )),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Forgets the updates of transactions that haven't been checked since the
+    /// block before `version`.
+    ///
+    /// Every transaction left in the mempool is rechecked after a block is
+    /// committed, so this only forgets the updates of transactions that were
+    /// included in a block or dropped from the mempool.
+    pub fn prune(&mut self, version: u64) {
+        self.claims
+            .retain(|_, (_, checked_at)| checked_at.saturating_add(1) >= version);
+    }
+
+    /// Returns whether `tx` does nothing but submit client updates that other
+    /// pending transactions already submit.
+    ///
+    /// Besides its updates, such a transaction may only spend and output notes
+    /// to pay its fee: any other action might do something on its own.
+    pub fn is_redundant(&self, id: &TransactionId, tx: &Transaction) -> bool {
+        let only_updates = tx.actions().all(|action| {
+            matches!(
+                action,
+                Action::IbcRelay(IbcRelay::UpdateClient(_)) | Action::Spend(_) | Action::Output(_)
+            )
+        });
+        let updates = Self::client_updates(tx);
+
+        only_updates
+            && !updates.is_empty()
+            && updates.iter().all(|update| {
+                self.claims
+                    .get(update)
+                    .is_some_and(|(claimant, _)| claimant != id)
+            })
+    }
+
+    /// Records the updates of `tx`, which was accepted against `version`.
+    pub fn claim(&mut self, id: TransactionId, tx: &Transaction, version: u64) {
+        for update in Self::client_updates(tx) {
+            let (claimant, checked_at) = self.claims.entry(update).or_insert((id, version));
+            if *claimant == id {
+                *checked_at = version;
+            }
+        }
+    }
+
+    /// Forgets the updates of `id`, which was rejected.
+    pub fn release(&mut self, id: &TransactionId) {
+        self.claims.retain(|_, (claimant, _)| claimant != id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_proto::google::protobuf::Any;
+    use ibc_types::core::{
+        channel::{msgs::MsgRecvPacket, ChannelId, Packet, PortId, TimeoutHeight},
+        client::{msgs::MsgUpdateClient, Height},
+        commitment::MerkleProof,
+    };
+    use ibc_types::timestamp::Timestamp;
+
+    use super::*;
+
+    fn update_client() -> Action {
+        Action::IbcRelay(IbcRelay::UpdateClient(MsgUpdateClient {
+            client_id: "07-tendermint-0".parse().expect("valid client id"),
+            client_message: Any {
+                type_url: "/ibc.lightclients.tendermint.v1.Header".to_string(),
+                value: vec![1, 2, 3],
+            },
+            signer: "relayer".to_string(),
+        }))
+    }
+
+    fn recv_packet() -> Action {
+        Action::IbcRelay(IbcRelay::RecvPacket(MsgRecvPacket {
+            packet: Packet {
+                sequence: 1.into(),
+                port_on_a: PortId::transfer(),
+                chan_on_a: ChannelId::new(0),
+                port_on_b: PortId::transfer(),
+                chan_on_b: ChannelId::new(0),
+                data: vec![],
+                timeout_height_on_b: TimeoutHeight::Never,
+                timeout_timestamp_on_b: Timestamp::none(),
+            },
+            proof_commitment_on_a: MerkleProof { proofs: vec![] },
+            proof_height_on_a: Height::new(0, 1).expect("valid height"),
+            signer: "relayer".to_string(),
+        }))
+    }
+
+    fn transaction(actions: Vec<Action>) -> Transaction {
+        let mut tx = Transaction::default();
+        tx.transaction_body.actions = actions;
+        tx
+    }
+
+    #[test]
+    fn duplicate_updates_are_redundant() {
+        let mut pending = PendingClientUpdates::default();
+        pending.claim(
+            TransactionId([0; 32]),
+            &transaction(vec![update_client()]),
+            1,
+        );
+
+        assert!(pending.is_redundant(&TransactionId([1; 32]), &transaction(vec![update_client()])));
+        assert!(!pending.is_redundant(&TransactionId([0; 32]), &transaction(vec![update_client()])));
+    }
+
+    #[test]
+    fn updates_relayed_with_packets_are_not_redundant() {
+        let mut pending = PendingClientUpdates::default();
+        pending.claim(
+            TransactionId([0; 32]),
+            &transaction(vec![update_client()]),
+            1,
+        );
+
+        assert!(!pending.is_redundant(
+            &TransactionId([1; 32]),
+            &transaction(vec![update_client(), recv_packet()])
+        ));
+    }
+}
@@ -1,15 +1,188 @@
+//! State sync: serving snapshots of the state to other nodes, and restoring
+//! the state of a new node from a snapshot offered by its peers.
+
 use std::{
     future::Future,
     pin::Pin,
+    sync::Arc,
     task::{Context, Poll},
 };
 
+use anyhow::Context as _;
+use cnidarium::{export, RootHash, StateDelta, Storage};
 use futures::FutureExt;
-use tendermint::v0_37::abci::{SnapshotRequest, SnapshotResponse};
+use sha2::{Digest as _, Sha256};
+use tendermint::v0_37::abci::{
+    request, response, response::ApplySnapshotChunkResult, SnapshotRequest, SnapshotResponse,
+};
+use tokio::sync::Mutex;
 use tower_abci::BoxError;
 
-#[derive(Clone, Debug)]
-pub struct Snapshot {}
+mod store;
+
+pub use store::{run, SnapshotMetadata, SnapshotStore};
+
+/// A snapshot being restored.
+struct Restore {
+    metadata: SnapshotMetadata,
+    root_hash: RootHash,
+    height: u64,
+    /// The state restored from the chunks applied so far.
+    delta: StateDelta<cnidarium::Snapshot>,
+    applied: Vec<bool>,
+}
+
+#[derive(Clone)]
+pub struct Snapshot {
+    storage: Storage,
+    /// The snapshots served to other nodes, if any.
+    store: Option<SnapshotStore>,
+    restore: Arc<Mutex<Option<Restore>>>,
+}
+
+impl Snapshot {
+    pub fn new(storage: Storage, store: Option<SnapshotStore>) -> Self {
+        Self {
+            storage,
+            store,
+            restore: Default::default(),
+        }
+    }
+
+    async fn list_snapshots(&self) -> anyhow::Result<response::ListSnapshots> {
+        let snapshots = match &self.store {
+            Some(store) => store.list().await?,
+            None => Vec::new(),
+        };
+        Ok(response::ListSnapshots { snapshots })
+    }
+
+    async fn load_snapshot_chunk(
+        &self,
+        req: request::LoadSnapshotChunk,
+    ) -> anyhow::Result<response::LoadSnapshotChunk> {
+        let store = self.store.as_ref().context("snapshots are not served")?;
+        let chunk = store
+            .load_chunk(req.height.value(), req.format, req.chunk)
+            .await?;
+        Ok(response::LoadSnapshotChunk {
+            chunk: chunk.into(),
+        })
+    }
+
+    async fn offer_snapshot(&self, req: request::OfferSnapshot) -> response::OfferSnapshot {
+        let request::OfferSnapshot { snapshot, app_hash } = req;
+        let height = snapshot.height.value();
+
+        if self.storage.latest_version() != u64::MAX {
+            tracing::warn!(height, "storage is not empty, aborting state sync");
+            return response::OfferSnapshot::Abort;
+        }
+        if snapshot.format != export::EXPORT_FORMAT {
+            return response::OfferSnapshot::RejectFormat;
+        }
+        let metadata = match SnapshotMetadata::decode(&snapshot.metadata) {
+            Ok(metadata) => metadata,
+            Err(error) => {
+                tracing::info!(?error, height, "rejecting snapshot with invalid metadata");
+                return response::OfferSnapshot::Reject;
+            }
+        };
+        if metadata.hash().as_slice() != snapshot.hash.as_ref()
+            || metadata.chunk_hashes.len() != snapshot.chunks as usize
+        {
+            tracing::info!(height, "rejecting snapshot not matching its metadata");
+            return response::OfferSnapshot::Reject;
+        }
+        let Ok(root_hash) = app_hash.as_bytes().try_into().map(RootHash) else {
+            tracing::info!(height, "rejecting snapshot with invalid app hash");
+            return response::OfferSnapshot::Reject;
+        };
+
+        tracing::info!(
+            height,
+            version = metadata.version,
+            chunks = snapshot.chunks,
+            "accepting snapshot"
+        );
+        *self.restore.lock().await = Some(Restore {
+            applied: vec![false; metadata.chunk_hashes.len()],
+            metadata,
+            root_hash,
+            height,
+            delta: StateDelta::new(self.storage.latest_snapshot()),
+        });
+        response::OfferSnapshot::Accept
+    }
+
+    async fn apply_snapshot_chunk(
+        &self,
+        req: request::ApplySnapshotChunk,
+    ) -> response::ApplySnapshotChunk {
+        let request::ApplySnapshotChunk {
+            index,
+            chunk,
+            sender,
+        } = req;
+        let response = |result| response::ApplySnapshotChunk {
+            result,
+            refetch_chunks: Vec::new(),
+            reject_senders: Vec::new(),
+        };
+
+        let mut restore = self.restore.lock().await;
+        let Some(current) = restore.as_mut() else {
+            tracing::warn!(index, "no snapshot is being restored");
+            return response(ApplySnapshotChunkResult::Abort);
+        };
+
+        let Some(expected_hash) = current.metadata.chunk_hashes.get(index as usize) else {
+            tracing::warn!(index, "chunk is not part of the snapshot");
+            return response(ApplySnapshotChunkResult::RejectSnapshot);
+        };
+        if Sha256::digest(&chunk).as_slice() != expected_hash {
+            tracing::info!(index, %sender, "refetching chunk not matching its hash");
+            return response::ApplySnapshotChunk {
+                result: ApplySnapshotChunkResult::Retry,
+                refetch_chunks: vec![index],
+                reject_senders: vec![sender],
+            };
+        }
+        if !current.applied[index as usize] {
+            if let Err(error) = export::apply_chunk(&mut current.delta, &chunk) {
+                tracing::warn!(?error, index, "snapshot chunk is invalid");
+                *restore = None;
+                return response(ApplySnapshotChunkResult::RejectSnapshot);
+            }
+            current.applied[index as usize] = true;
+        }
+        if current.applied.iter().any(|applied| !applied) {
+            return response(ApplySnapshotChunkResult::Accept);
+        }
+
+        let Restore {
+            metadata,
+            root_hash,
+            height,
+            delta,
+            ..
+        } = restore.take().expect("a snapshot is being restored");
+        match self
+            .storage
+            .restore(delta, metadata.version, root_hash)
+            .await
+        {
+            Ok(()) => {
+                tracing::info!(height, version = metadata.version, "restored snapshot");
+                response(ApplySnapshotChunkResult::Accept)
+            }
+            Err(error) => {
+                tracing::warn!(?error, height, "failed to restore snapshot");
+                response(ApplySnapshotChunkResult::RejectSnapshot)
+            }
+        }
+    }
+}
 
 impl tower_service::Service<SnapshotRequest> for Snapshot {
     type Response = SnapshotResponse;
@@ -22,15 +195,21 @@ impl tower_service::Service<SnapshotRequest> for Snapshot {
     }
 
     fn call(&mut self, req: SnapshotRequest) -> Self::Future {
-        // No-op, we don't implement snapshot support
         use SnapshotRequest as Request;
         use SnapshotResponse as Response;
+        let this = self.clone();
         async move {
             Ok(match req {
-                Request::ListSnapshots => Response::ListSnapshots(Default::default()),
-                Request::OfferSnapshot(_) => Response::OfferSnapshot(Default::default()),
-                Request::LoadSnapshotChunk(_) => Response::LoadSnapshotChunk(Default::default()),
-                Request::ApplySnapshotChunk(_) => Response::ApplySnapshotChunk(Default::default()),
+                Request::ListSnapshots => Response::ListSnapshots(this.list_snapshots().await?),
+                Request::OfferSnapshot(req) => {
+                    Response::OfferSnapshot(this.offer_snapshot(req).await)
+                }
+                Request::LoadSnapshotChunk(req) => {
+                    Response::LoadSnapshotChunk(this.load_snapshot_chunk(req).await?)
+                }
+                Request::ApplySnapshotChunk(req) => {
+                    Response::ApplySnapshotChunk(this.apply_snapshot_chunk(req).await)
+                }
             })
         }
         .boxed()
@@ -0,0 +1,187 @@
+//! Exported snapshots of the state, kept on disk for other nodes to state sync
+//! from.
+//!
+//! Each snapshot is kept in a directory named after the height of the block it
+//! was taken after, holding the [chunks](cnidarium::export) of the exported
+//! state and a metadata file listing their hashes. A snapshot is written to a
+//! temporary directory first, so a snapshot directory is always complete.
+
+use std::path::PathBuf;
+
+use anyhow::{Context as _, Result};
+use cnidarium::{export::EXPORT_FORMAT, Storage};
+use futures::StreamExt as _;
+use penumbra_ibc::component::HostInterface as _;
+use sha2::{Digest as _, Sha256};
+use tendermint::abci::types::Snapshot as AbciSnapshot;
+
+use crate::PenumbraHost;
+
+/// The size of the chunks snapshots are split into. CometBFT doesn't accept
+/// chunks larger than 16MB.
+const CHUNK_SIZE: usize = 8 * 1024 * 1024;
+
+/// The number of snapshots kept on disk.
+const SNAPSHOTS_KEPT: usize = 2;
+
+const METADATA_FILE: &str = "metadata";
+
+/// The metadata of a snapshot, which is also sent to the nodes it's offered
+/// to, so that they can check the chunks they're sent.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SnapshotMetadata {
+    /// The version of the storage the snapshot was exported from.
+    pub version: jmt::Version,
+    /// The SHA-256 hash of each chunk.
+    pub chunk_hashes: Vec<[u8; 32]>,
+}
+
+impl SnapshotMetadata {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = self.version.to_be_bytes().to_vec();
+        for hash in &self.chunk_hashes {
+            bytes.extend_from_slice(hash);
+        }
+        bytes
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        let (version, hashes) = bytes
+            .split_first_chunk::<8>()
+            .context("snapshot metadata is too short")?;
+        if hashes.is_empty() || hashes.len() % 32 != 0 {
+            anyhow::bail!("snapshot metadata has a partial chunk hash");
+        }
+        Ok(SnapshotMetadata {
+            version: u64::from_be_bytes(*version),
+            chunk_hashes: hashes
+                .chunks_exact(32)
+                .map(|hash| hash.try_into().expect("chunks are 32 bytes"))
+                .collect(),
+        })
+    }
+
+    /// The hash identifying the snapshot, which commits to its chunks.
+    pub fn hash(&self) -> [u8; 32] {
+        Sha256::digest(self.encode()).into()
+    }
+}
+
+/// The snapshots kept in a directory.
+#[derive(Clone, Debug)]
+pub struct SnapshotStore {
+    dir: PathBuf,
+}
+
+impl SnapshotStore {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    fn snapshot_dir(&self, height: u64) -> PathBuf {
+        self.dir.join(height.to_string())
+    }
+
+    /// Exports `snapshot`, taken after the block at `height`, and removes the
+    /// oldest snapshots.
+    pub async fn create(&self, snapshot: cnidarium::Snapshot, height: u64) -> Result<()> {
+        let snapshot_dir = self.snapshot_dir(height);
+        if tokio::fs::try_exists(&snapshot_dir).await? {
+            return Ok(());
+        }
+        let tmp_dir = self.dir.join(format!("{height}.tmp"));
+        if tokio::fs::try_exists(&tmp_dir).await? {
+            tokio::fs::remove_dir_all(&tmp_dir).await?;
+        }
+        tokio::fs::create_dir_all(&tmp_dir).await?;
+
+        let mut chunks = snapshot.export(CHUNK_SIZE);
+        let mut chunk_hashes = Vec::new();
+        while let Some(chunk) = chunks.next().await {
+            let chunk = chunk?;
+            tokio::fs::write(tmp_dir.join(chunk_hashes.len().to_string()), &chunk).await?;
+            chunk_hashes.push(Sha256::digest(&chunk).into());
+        }
+        let metadata = SnapshotMetadata {
+            version: snapshot.version(),
+            chunk_hashes,
+        };
+        tokio::fs::write(tmp_dir.join(METADATA_FILE), metadata.encode()).await?;
+        tokio::fs::rename(&tmp_dir, &snapshot_dir).await?;
+        tracing::info!(
+            height,
+            version = metadata.version,
+            chunks = metadata.chunk_hashes.len(),
+            "created state snapshot"
+        );
+
+        for height in self.heights().await?.into_iter().rev().skip(SNAPSHOTS_KEPT) {
+            tokio::fs::remove_dir_all(self.snapshot_dir(height)).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the heights of the snapshots in the store, in ascending order.
+    async fn heights(&self) -> Result<Vec<u64>> {
+        let mut heights = Vec::new();
+        if !tokio::fs::try_exists(&self.dir).await? {
+            return Ok(heights);
+        }
+        let mut entries = tokio::fs::read_dir(&self.dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            // Skips snapshots that are still being written.
+            if let Some(height) = entry.file_name().to_str().and_then(|n| n.parse().ok()) {
+                heights.push(height);
+            }
+        }
+        heights.sort();
+        Ok(heights)
+    }
+
+    /// Lists the snapshots in the store.
+    pub async fn list(&self) -> Result<Vec<AbciSnapshot>> {
+        let mut snapshots = Vec::new();
+        for height in self.heights().await? {
+            let metadata = SnapshotMetadata::decode(
+                &tokio::fs::read(self.snapshot_dir(height).join(METADATA_FILE)).await?,
+            )?;
+            snapshots.push(AbciSnapshot {
+                height: height.try_into()?,
+                format: EXPORT_FORMAT,
+                chunks: metadata.chunk_hashes.len().try_into()?,
+                hash: metadata.hash().to_vec().into(),
+                metadata: metadata.encode().into(),
+            });
+        }
+        Ok(snapshots)
+    }
+
+    /// Loads a chunk of the snapshot at `height`.
+    pub async fn load_chunk(&self, height: u64, format: u32, chunk: u32) -> Result<Vec<u8>> {
+        if format != EXPORT_FORMAT {
+            anyhow::bail!("unknown snapshot format {format}");
+        }
+        tokio::fs::read(self.snapshot_dir(height).join(chunk.to_string()))
+            .await
+            .with_context(|| format!("couldn't read chunk {chunk} of snapshot {height}"))
+    }
+}
+
+/// Exports a snapshot of the state after every block at a multiple of
+/// `interval`, for as long as `storage` commits new versions.
+pub async fn run(storage: Storage, store: SnapshotStore, interval: u64) {
+    let mut snapshots = storage.subscribe();
+    while snapshots.changed().await.is_ok() {
+        let snapshot = snapshots.borrow_and_update().clone();
+        let Ok(height) = PenumbraHost::get_block_height(snapshot.clone()).await else {
+            continue;
+        };
+        if interval == 0 || height == 0 || height % interval != 0 {
+            continue;
+        }
+        if let Err(error) = store.create(snapshot, height).await {
+            tracing::error!(?error, height, "failed to create state snapshot");
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use {
+    self::common::{BuilderExt, ValidatorDataReadExt},
+    cnidarium::TempStorage,
+    common::TempStorageExt as _,
+    penumbra_app::{
+        app::App,
+        genesis::{self, AppState},
+        server::consensus::Consensus,
+    },
+    penumbra_mock_consensus::TestNode,
+    penumbra_stake::validator::Validator,
+    tap::TapFallible,
+};
+
+mod common;
+
+/// The length of the [`penumbra_sct`] epoch, distinct from the default one.
+const EPOCH_DURATION: u64 = 8;
+
+/// Exercises that the state of a chain can be exported as the genesis of a new chain.
+#[tokio::test]
+async fn app_can_export_genesis() -> anyhow::Result<()> {
+    // Install a test logger, acquire some temporary storage, and start the test node.
+    let guard = common::set_tracing_subscriber();
+    let storage = TempStorage::new_with_penumbra_prefixes().await?;
+    let mut test_node = {
+        let app_state = AppState::Content(
+            genesis::Content::default()
+                .with_epoch_duration(EPOCH_DURATION)
+                .with_chain_id(TestNode::<()>::CHAIN_ID.to_string()),
+        );
+        let consensus = Consensus::new(storage.as_ref().clone());
+        TestNode::builder()
+            .single_validator()
+            .with_penumbra_auto_app_state(app_state)?
+            .init_chain(consensus)
+            .await
+            .tap_ok(|e| tracing::info!(hash = %e.last_app_hash_hex(), "finished init chain"))?
+    };
+    test_node.block().execute().await?;
+
+    let snapshot = storage.latest_snapshot();
+    let content = App::export_genesis(&snapshot).await?;
+
+    assert_eq!(content.chain_id, TestNode::<()>::CHAIN_ID);
+    assert_eq!(
+        content.sct_content.sct_params.epoch_duration,
+        EPOCH_DURATION
+    );
+    // The notes minted at genesis are not exported.
+    assert!(content.shielded_pool_content.allocations.is_empty());
+    let exported_validators = content
+        .stake_content
+        .validators
+        .into_iter()
+        .map(Validator::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+    let validators = snapshot.validator_definitions().await?;
+    assert_eq!(exported_validators, validators);
+
+    // Free our temporary storage.
+    drop(test_node);
+    drop(storage);
+    drop(guard);
+
+    Ok(())
+}
@@ -1485,6 +1485,7 @@ impl MockRelayer {
             source_channel: ChannelId::from_str("channel-0")?,
             // Penumbra <-> Penumbra so false
             use_compat_address: false,
+            relay_fee: None,
         };
         // There will need to be `Spend` and `Output` actions
         // within the transaction in order for it to balance
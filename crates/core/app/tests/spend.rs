@@ -65,6 +65,7 @@ async fn spend_happy_path() -> anyhow::Result<()> {
     let transaction_context = TransactionContext {
         anchor: root,
         effect_hash: EffectHash(dummy_effect_hash),
+        verification_cache: Default::default(),
     };
 
     // 3. Simulate execution of the Spend action
@@ -189,6 +190,7 @@ async fn invalid_dummy_spend() {
     let transaction_context = TransactionContext {
         anchor: root,
         effect_hash: EffectHash(dummy_effect_hash),
+        verification_cache: Default::default(),
     };
 
     // 3. Simulate execution of the Spend action
@@ -250,6 +252,7 @@ async fn spend_duplicate_nullifier_previous_transaction() {
     let transaction_context = TransactionContext {
         anchor: root,
         effect_hash: EffectHash(dummy_effect_hash),
+        verification_cache: Default::default(),
     };
 
     // 3. Simulate execution of the Spend action
@@ -278,6 +281,7 @@ async fn spend_duplicate_nullifier_previous_transaction() {
     let transaction_context = TransactionContext {
         anchor: root,
         effect_hash: EffectHash(dummy_effect_hash),
+        verification_cache: Default::default(),
     };
 
     // 5. Simulate execution of the double spend - the test should panic here
@@ -381,6 +385,6 @@ async fn spend_duplicate_nullifier_same_transaction() {
     };
 
     // 6. Simulate execution of the transaction - the test should panic here
-    transaction.check_stateless(()).await.unwrap();
+    transaction.check_stateless(Default::default()).await.unwrap();
 }
  */
@@ -70,7 +70,7 @@ async fn swap_and_swap_claim() -> anyhow::Result<()> {
 
     // 3. Simulate execution of the Swap action
 
-    swap.check_stateless(()).await?;
+    swap.check_stateless(Transaction::default().context()).await?;
     swap.check_historical(state.clone()).await?;
     let mut state_tx = state.try_begin_transaction().unwrap();
     state_tx.put_mock_source(1u8);
@@ -186,7 +186,7 @@ async fn swap_claim_duplicate_nullifier_previous_transaction() {
 
     // 3. Simulate execution of the Swap action
 
-    swap.check_stateless(()).await.unwrap();
+    swap.check_stateless(Transaction::default().context()).await.unwrap();
     swap.check_historical(state.clone()).await.unwrap();
     let mut state_tx = state.try_begin_transaction().unwrap();
     state_tx.put_mock_source(1u8);
@@ -310,7 +310,7 @@ async fn swap_with_nonzero_fee() -> anyhow::Result<()> {
 
     // 3. Simulate execution of the Swap action
 
-    swap.check_stateless(()).await?;
+    swap.check_stateless(Transaction::default().context()).await?;
     swap.check_historical(state.clone()).await?;
     let mut state_tx = state.try_begin_transaction().unwrap();
     state_tx.put_mock_source(1u8);
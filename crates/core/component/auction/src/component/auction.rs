@@ -32,6 +32,13 @@ impl Component for Auction {
         }
     }
 
+    #[instrument(name = "auction", skip(state))]
+    async fn export_genesis<S: StateRead>(state: &S) -> Result<Self::AppState> {
+        Ok(crate::genesis::Content {
+            auction_params: state.get_auction_params().await?,
+        })
+    }
+
     #[instrument(name = "auction", skip(_state, _begin_block))]
     async fn begin_block<S: StateWrite + 'static>(
         _state: &mut Arc<S>,
@@ -9,8 +9,10 @@ mod view;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use cnidarium::StateWrite;
+use cnidarium::{StateRead, StateWrite};
 use cnidarium_component::Component;
+use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_shielded_pool::{component::SupplyManager as _, supply::SupplyChange};
 use tendermint::v0_37::abci;
 use tracing::instrument;
 pub use view::{StateReadExt, StateWriteExt};
@@ -29,11 +31,26 @@ impl Component for CommunityPool {
             Some(genesis) => {
                 state.put_community_pool_params(genesis.community_pool_params.clone());
                 state.community_pool_deposit(genesis.initial_balance).await;
+                state.record_supply_change(genesis.initial_balance, SupplyChange::Genesis);
             }
             None => {}
         }
     }
 
+    #[instrument(name = "community_pool", skip(state))]
+    async fn export_genesis<S: StateRead>(state: &S) -> anyhow::Result<Self::AppState> {
+        // Genesis only funds the Community Pool with the staking token.
+        Ok(genesis::Content {
+            community_pool_params: state.get_community_pool_params().await?,
+            initial_balance: Value {
+                amount: state
+                    .community_pool_asset_balance(*STAKING_TOKEN_ASSET_ID)
+                    .await?,
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            },
+        })
+    }
+
     #[instrument(name = "community_pool", skip(_state, _begin_block))]
     async fn begin_block<S: StateWrite + 'static>(
         _state: &mut Arc<S>,
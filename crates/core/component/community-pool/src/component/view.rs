@@ -8,6 +8,7 @@ use futures::{StreamExt, TryStreamExt};
 use penumbra_asset::{asset, Value};
 use penumbra_num::Amount;
 use penumbra_proto::{StateReadProto, StateWriteProto};
+use penumbra_shielded_pool::{component::SupplyManager as _, supply::SupplyChange};
 
 use crate::params::CommunityPoolParameters;
 
@@ -60,6 +61,7 @@ pub trait StateWriteExt: StateWrite {
             .expect("no deserialization errors")
             .unwrap_or_else(|| Amount::from(0u64));
         self.put(key, current + value.amount);
+        self.record_supply_change(value, SupplyChange::CommunityPoolDeposit);
     }
 
     async fn community_pool_withdraw(&mut self, value: Value) -> Result<()> {
@@ -78,6 +80,7 @@ pub trait StateWriteExt: StateWrite {
                 value.asset_id
             );
         }
+        self.record_supply_change(value, SupplyChange::CommunityPoolWithdrawal);
         Ok(())
     }
 }
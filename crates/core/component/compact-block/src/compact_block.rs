@@ -9,7 +9,10 @@ use penumbra_proto::{
 };
 use penumbra_sct::Nullifier;
 use penumbra_shielded_pool::fmd;
-use penumbra_tct::builder::{block, epoch};
+use penumbra_tct::{
+    builder::{block, epoch},
+    storage::Updates,
+};
 use serde::{Deserialize, Serialize};
 
 use super::StatePayload;
@@ -42,6 +45,12 @@ pub struct CompactBlock {
     pub alt_gas_prices: Vec<GasPrices>,
     // The epoch index
     pub epoch_index: u64,
+    /// The changes made to the state commitment tree by this block, as they would be written to
+    /// a store of the tree, unless they only moved its position forward.
+    ///
+    /// A client keeping a store of the tree can apply these rather than re-inserting every
+    /// commitment of the block.
+    pub sct_updates: Option<Updates>,
     // **IMPORTANT NOTE FOR FUTURE HUMANS**: if you want to add new fields to the `CompactBlock`,
     // you must update `CompactBlock::requires_scanning` to check for the emptiness of those fields,
     // because the client will skip processing any compact block that is marked as not requiring
@@ -63,6 +72,7 @@ impl Default for CompactBlock {
             gas_prices: None,
             alt_gas_prices: Vec::new(),
             epoch_index: 0,
+            sct_updates: None,
         }
     }
 }
@@ -104,6 +114,7 @@ impl From<CompactBlock> for pb::CompactBlock {
             gas_prices: cb.gas_prices.map(Into::into),
             alt_gas_prices: cb.alt_gas_prices.into_iter().map(Into::into).collect(),
             epoch_index: cb.epoch_index,
+            sct_updates: cb.sct_updates.map(Into::into),
         }
     }
 }
@@ -147,6 +158,7 @@ impl TryFrom<pb::CompactBlock> for CompactBlock {
                 .map(GasPrices::try_from)
                 .collect::<Result<Vec<GasPrices>>>()?,
             epoch_index: value.epoch_index,
+            sct_updates: value.sct_updates.map(TryInto::try_into).transpose()?,
         })
     }
 }
@@ -9,6 +9,7 @@ use penumbra_proto::DomainType;
 use penumbra_sct::component::clock::EpochRead;
 use penumbra_sct::component::tree::{SctManager as _, SctRead};
 use penumbra_shielded_pool::component::NoteManager as _;
+use penumbra_tct::{storage::Updates, Position};
 use tracing::instrument;
 
 use crate::{state_key, CompactBlock};
@@ -80,6 +81,15 @@ trait Inner: StateWrite {
         // Check to see if a governance proposal has started, and mark this fact if so.
         let proposal_started = self.proposal_started();
 
+        // Remember where the SCT stood when the block started, to record the changes made to it.
+        let (last_position, last_forgotten) = {
+            let sct = self.get_sct().await;
+            let block_start = sct
+                .position()
+                .map(|position| Position::from((position.epoch(), position.block(), 0)));
+            (block_start, sct.forgotten())
+        };
+
         // End the block in the SCT and record the block root, epoch root if applicable, and the SCT
         // itself, storing the resultant block and epoch root if applicable in the compact block.
         let (block_root, epoch_root) = self
@@ -87,6 +97,17 @@ trait Inner: StateWrite {
             .await
             .context("could not end SCT block")?;
 
+        // Clients need not be told about the SCT merely moving on to the next block or epoch.
+        let sct_updates: Updates = self
+            .get_sct()
+            .await
+            .updates(last_position, last_forgotten)
+            .collect();
+        let sct_updates = (!sct_updates.store_commitments.is_empty()
+            || !sct_updates.store_hashes.is_empty()
+            || !sct_updates.delete_ranges.is_empty())
+        .then_some(sct_updates);
+
         // Pull out all the pending state payloads (note and swap)
         let note_payloads = self
             .pending_note_payloads()
@@ -140,6 +161,7 @@ trait Inner: StateWrite {
             gas_prices,
             alt_gas_prices,
             epoch_index,
+            sct_updates,
         };
 
         self.nonverifiable_put_raw(
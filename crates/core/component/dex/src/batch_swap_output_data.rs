@@ -81,6 +81,28 @@ impl BatchSwapOutputData {
                 .expect("rounded amount is integral"),
         )
     }
+
+    /// The price at which the batch cleared, in units of asset 2 per unit of
+    /// asset 1, weighted by the volume traded in each direction.
+    ///
+    /// Returns `None` if nothing was traded.
+    pub fn clearing_price(&self) -> Option<U128x128> {
+        // Each asset was both sold by the swaps into the other one, less what
+        // was returned unfilled, and bought by the swaps out of the other one.
+        let traded_1 = self
+            .delta_1
+            .checked_sub(&self.unfilled_1)?
+            .checked_add(&self.lambda_1)?;
+        let traded_2 = self
+            .delta_2
+            .checked_sub(&self.unfilled_2)?
+            .checked_add(&self.lambda_2)?;
+        if traded_1 == Amount::zero() || traded_2 == Amount::zero() {
+            return None;
+        }
+
+        U128x128::ratio(traded_2, traded_1).ok()
+    }
 }
 
 impl ToConstraintField<Fq> for BatchSwapOutputData {
@@ -1,3 +1,4 @@
+mod gauge_fund;
 mod position;
 mod swap;
 mod swap_claim;
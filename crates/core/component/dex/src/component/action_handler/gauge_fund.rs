@@ -0,0 +1,36 @@
+use anyhow::{ensure, Result};
+use async_trait::async_trait;
+use cnidarium::StateWrite;
+use cnidarium_component::ActionHandler;
+use penumbra_num::Amount;
+
+use crate::{component::GaugeManager as _, GaugeFund};
+
+#[async_trait]
+/// Debits the rewards and adds them to the reward gauge of the trading pair.
+impl ActionHandler for GaugeFund {
+    type CheckStatelessContext = ();
+    async fn check_stateless(&self, _context: ()) -> Result<()> {
+        ensure!(
+            self.rewards.amount > Amount::zero(),
+            "gauge rewards must be nonzero"
+        );
+        ensure!(
+            self.duration >= 1,
+            "gauge rewards must be distributed over at least one block"
+        );
+        // Every block of the gauge's duration distributes something, so that its
+        // rewards don't sit idle while it costs work to process.
+        ensure!(
+            self.rewards.amount >= Amount::from(self.duration),
+            "gauge rewards must amount to at least one unit per block"
+        );
+        Ok(())
+    }
+
+    async fn check_and_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        state
+            .fund_gauge(self.trading_pair, self.rewards, self.duration)
+            .await
+    }
+}
@@ -5,10 +5,14 @@ use cnidarium::StateWrite;
 use cnidarium_component::ActionHandler;
 use decaf377::Fr;
 
-use crate::{component::PositionManager, lp::action::PositionWithdraw};
+use crate::{
+    component::{GaugeManager as _, PositionManager},
+    lp::action::PositionWithdraw,
+};
 
 #[async_trait]
-/// Debits a closed position NFT and credits a withdrawn position NFT and the final reserves.
+/// Debits a closed position NFT and credits a withdrawn position NFT, the final reserves
+/// and the rewards accrued by the position.
 impl ActionHandler for PositionWithdraw {
     type CheckStatelessContext = ();
     async fn check_stateless(&self, _context: ()) -> Result<()> {
@@ -27,6 +31,13 @@ impl ActionHandler for PositionWithdraw {
             .withdraw_position(self.position_id, self.sequence)
             .await?;
 
+        // Claim the rewards the position accrued from reward gauges while it was open,
+        // so that later withdrawals from the same position claim nothing.
+        let mut withdrawn = actual_reserves;
+        for reward in state.take_position_rewards(&self.position_id).await? {
+            withdrawn += reward;
+        }
+
         // Next, and CRITICALLY, check that the commitment to the amount the user is
         // withdrawing is correct.
         //
@@ -34,10 +45,9 @@ impl ActionHandler for PositionWithdraw {
         // shielding a value, this commitment is used for compression, giving a
         // single commitment rather than a list of token amounts.
         //
-        // Note: since this is forming a commitment only to the reserves,
-        // we are implicitly setting the reward amount to 0. However, we can
-        // add support for rewards in the future without client changes.
-        let expected_reserves_commitment = actual_reserves.commit(Fr::zero());
+        // The commitment covers both the reserves and the rewards, which is why
+        // clients list the accrued rewards in their withdrawal plans.
+        let expected_reserves_commitment = withdrawn.commit(Fr::zero());
 
         if self.reserves_commitment != expected_reserves_commitment {
             anyhow::bail!(
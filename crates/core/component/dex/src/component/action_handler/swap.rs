@@ -7,6 +7,7 @@ use cnidarium_component::ActionHandler;
 use penumbra_proof_params::SWAP_PROOF_VERIFICATION_KEY;
 use penumbra_proto::{DomainType as _, StateWriteProto};
 use penumbra_sct::component::source::SourceContext;
+use penumbra_txhash::TransactionContext;
 
 use crate::{
     component::{InternalDexWrite, StateReadExt, SwapDataWrite, SwapManager},
@@ -16,20 +17,21 @@ use crate::{
 
 #[async_trait]
 impl ActionHandler for Swap {
-    type CheckStatelessContext = ();
-    async fn check_stateless(&self, _context: ()) -> Result<()> {
+    type CheckStatelessContext = TransactionContext;
+    async fn check_stateless(&self, context: TransactionContext) -> Result<()> {
         // Check that the trading pair is distinct.
         if self.body.trading_pair.asset_1() == self.body.trading_pair.asset_2() {
             anyhow::bail!("Trading pair must be distinct");
         }
 
-        self.proof.verify(
+        self.proof.verify_unless_batched(
             &SWAP_PROOF_VERIFICATION_KEY,
             SwapProofPublic {
                 balance_commitment: self.balance_commitment_inner(),
                 swap_commitment: self.body.payload.commitment,
                 fee_commitment: self.body.fee_commitment,
             },
+            &context.verification_cache.proofs,
         )?;
 
         Ok(())
@@ -4,7 +4,7 @@ use anyhow::{Context as _, Result};
 use cnidarium::{StateRead, StateWrite};
 use futures::{StreamExt, TryStreamExt as _};
 use penumbra_num::fixpoint::U128x128;
-use penumbra_proto::{DomainType, StateReadProto, StateWriteProto};
+use penumbra_proto::{StateReadProto, StateWriteProto};
 use penumbra_sct::component::clock::EpochRead as _;
 use tonic::async_trait;
 
@@ -13,6 +13,10 @@ use crate::{lp::position::Position, state_key::candlesticks, DirectedTradingPair
 
 use crate::CandlestickData;
 
+/// The resolutions, in blocks, at which candlesticks are aggregated besides the
+/// candlesticks of each block: about a minute, an hour, and a day of blocks.
+pub const CANDLESTICK_RESOLUTIONS: [u64; 3] = [12, 720, 17_280];
+
 #[async_trait]
 pub trait CandlestickRead: StateRead {
     #[tracing::instrument(level = "debug", skip(self))]
@@ -42,14 +46,44 @@ pub trait CandlestickRead: StateRead {
         );
 
         let range = self
-            .nonverifiable_range_raw(Some(prefix.as_bytes()), start_height_key..)
+            .nonverifiable_range::<CandlestickData>(Some(prefix.as_bytes()), start_height_key..)
             .context("error forming range query")?;
 
         range
             .take(limit)
-            .and_then(|(_k, v)| async move {
-                CandlestickData::decode(v.as_ref()).context("error deserializing candlestick")
-            })
+            .map_ok(|(_k, candlestick)| candlestick)
+            .try_collect()
+            .await
+    }
+
+    /// The candlesticks covering `resolution` blocks each, starting from the one
+    /// covering `start_height`.
+    ///
+    /// Returns no candlesticks if they aren't aggregated at `resolution`, see
+    /// [`CANDLESTICK_RESOLUTIONS`].
+    async fn candlesticks_at_resolution(
+        &self,
+        trading_pair: &DirectedTradingPair,
+        resolution: u64,
+        start_height: u64,
+        limit: usize,
+    ) -> Result<Vec<CandlestickData>> {
+        if resolution == 1 {
+            return self.candlesticks(trading_pair, start_height, limit).await;
+        }
+
+        let prefix = candlesticks::aggregate::by_pair_and_resolution(trading_pair, resolution);
+        let start_height_key = format!("{:020}", start_height - start_height % resolution)
+            .as_bytes()
+            .to_vec();
+
+        let range = self
+            .nonverifiable_range::<CandlestickData>(Some(prefix.as_bytes()), start_height_key..)
+            .context("error forming range query")?;
+
+        range
+            .take(limit)
+            .map_ok(|(_k, candlestick)| candlestick)
             .try_collect()
             .await
     }
@@ -211,6 +245,28 @@ pub trait Chandelier: StateWrite {
                 candlesticks::data::by_pair_and_height(&trading_pair, height).into(),
                 candlestick,
             );
+
+            // Fold the block's candlestick into the candlesticks covering it at each resolution.
+            for resolution in CANDLESTICK_RESOLUTIONS {
+                let start_height = height - height % resolution;
+                let key = candlesticks::aggregate::by_pair_resolution_and_height(
+                    trading_pair,
+                    resolution,
+                    start_height,
+                );
+                let aggregate = match self
+                    .nonverifiable_get::<CandlestickData>(key.as_bytes())
+                    .await?
+                {
+                    Some(aggregate) => extend_candlestick(aggregate, &candlestick),
+                    None => CandlestickData {
+                        height: start_height,
+                        ..candlestick
+                    },
+                };
+                self.nonverifiable_put(key.into(), aggregate);
+            }
+
             self.record_proto(
                 EventCandlestickData {
                     pair: *trading_pair,
@@ -226,6 +282,19 @@ pub trait Chandelier: StateWrite {
 
 impl<T: StateWrite + ?Sized> Chandelier for T {}
 
+/// Extends a candlestick covering several blocks with the candlestick of a later block.
+fn extend_candlestick(candlestick: CandlestickData, next: &CandlestickData) -> CandlestickData {
+    CandlestickData {
+        height: candlestick.height,
+        open: candlestick.open,
+        close: next.close,
+        high: candlestick.high.max(next.high),
+        low: candlestick.low.min(next.low),
+        direct_volume: candlestick.direct_volume + next.direct_volume,
+        swap_volume: candlestick.swap_volume + next.swap_volume,
+    }
+}
+
 #[async_trait]
 trait Inner: StateWrite {
     #[tracing::instrument(level = "debug", skip(self))]
@@ -483,4 +552,65 @@ mod tests {
         );
         Ok(())
     }
+
+    #[tokio::test]
+    /// Check that block candlesticks are aggregated into candlesticks covering several blocks.
+    async fn chandelier_aggregates_resolutions() -> anyhow::Result<()> {
+        let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+
+        let penumbra = asset::Cache::with_known_assets()
+            .get_unit("penumbra")
+            .unwrap();
+        let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+        let pair = DirectedTradingPair::new(gn.id(), penumbra.id());
+
+        // Swap 10 gn at a price of 2, 3 and then 1 penumbra, in blocks 11, 12 and 13.
+        for (height, output) in [(11u64, 20u64), (12, 30), (13, 10)] {
+            let mut state = StateDelta::new(storage.latest_snapshot());
+            state.put_block_height(height);
+            state
+                .record_swap_execution(&SwapExecution {
+                    traces: vec![],
+                    input: gn.base().value(10u64.into()),
+                    output: penumbra.base().value(output.into()),
+                })
+                .await;
+            state.finalize_block_candlesticks().await?;
+            storage.commit(state).await?;
+        }
+
+        let state = storage.latest_snapshot();
+        let candlesticks = state.candlesticks_at_resolution(&pair, 12, 0, 10).await?;
+        assert_eq!(
+            candlesticks.len(),
+            2,
+            "blocks 11 and 12 are in different candlesticks"
+        );
+
+        let first = candlesticks[0];
+        assert_eq!(first.height, 0);
+        assert_eq!(
+            (first.open, first.close, first.high, first.low),
+            (2.0, 2.0, 2.0, 2.0)
+        );
+        assert_eq!(first.swap_volume, 10.0);
+
+        let second = candlesticks[1];
+        assert_eq!(second.height, 12);
+        assert_eq!(
+            (second.open, second.close, second.high, second.low),
+            (3.0, 1.0, 3.0, 1.0)
+        );
+        assert_eq!(second.swap_volume, 20.0);
+
+        // Queries start from the candlestick covering the start height.
+        let candlesticks = state.candlesticks_at_resolution(&pair, 12, 13, 10).await?;
+        assert_eq!(candlesticks, vec![second]);
+
+        // Block candlesticks are still available.
+        let candlesticks = state.candlesticks_at_resolution(&pair, 1, 12, 10).await?;
+        assert_eq!(candlesticks.len(), 2);
+
+        Ok(())
+    }
 }
@@ -24,7 +24,8 @@ use super::eviction_manager::EvictionManager;
 use super::{
     chandelier::Chandelier,
     router::{HandleBatchSwaps, RoutingParams},
-    Arbitrage, PositionManager, PositionRead as _, ValueCircuitBreaker,
+    Arbitrage, ExecutionLog as _, GaugeManager as _, PositionManager, PositionRead as _,
+    PriceOracle as _, ValueCircuitBreaker,
 };
 
 pub struct Dex {}
@@ -43,6 +44,13 @@ impl Component for Dex {
         }
     }
 
+    #[instrument(name = "dex", skip(state))]
+    async fn export_genesis<S: StateRead>(state: &S) -> Result<Self::AppState> {
+        Ok(genesis::Content {
+            dex_params: state.get_dex_params().await?,
+        })
+    }
+
     #[instrument(name = "dex", skip(_state, _begin_block))]
     async fn begin_block<S: StateWrite + 'static>(
         _state: &mut Arc<S>,
@@ -137,6 +145,18 @@ impl Component for Dex {
             bsods.insert(trading_pair, bsod);
         }
 
+        // Record the clearing prices of the batch swaps with the price oracle.
+        {
+            let state_ref =
+                Arc::get_mut(state).expect("should have unique ref after finishing batch swaps");
+            for bsod in bsods.values() {
+                state_ref
+                    .record_clearing_price(bsod)
+                    .await
+                    .expect("recording clearing prices is infallible");
+            }
+        }
+
         // F.1. Having performed all batch swaps, "claim" the base fees and tips.
         // The VCB has already been debited through the BSOD.
         {
@@ -210,6 +230,7 @@ impl Component for Dex {
             max_hops: routing_params.max_hops + 2,
             fixed_candidates,
             price_limit: Some(1u64.into()),
+            ..routing_params
         };
 
         match state
@@ -242,12 +263,45 @@ impl Component for Dex {
             .await
             .expect("closing queued positions should not fail");
 
+        // Close the positions that expire at this height, likewise after execution, so that
+        // they can be traded against until the end of the block.
+        Arc::get_mut(state)
+            .expect("state should be uniquely referenced after batch swaps complete")
+            .close_expired_positions(
+                end_block
+                    .height
+                    .try_into()
+                    .expect("height is part of the end block data"),
+            )
+            .await
+            .expect("closing expired positions should not fail");
+
+        // Distribute the rewards of the reward gauges to the positions still open
+        // at the end of the block, after any closures.
+        Arc::get_mut(state)
+            .expect("state should be uniquely referenced after batch swaps complete")
+            .distribute_gauge_rewards(
+                end_block
+                    .height
+                    .try_into()
+                    .expect("height is part of the end block data"),
+            )
+            .await
+            .expect("distributing gauge rewards should not fail");
+
         // 5. Finalize the candlestick data for the block.
         Arc::get_mut(state)
             .expect("state should be uniquely referenced after batch swaps complete")
             .finalize_block_candlesticks()
             .await
             .expect("finalizing block candlesticks should not fail");
+
+        // 6. Log the position executions of the block, for clients following trading pairs.
+        Arc::get_mut(state)
+            .expect("state should be uniquely referenced after batch swaps complete")
+            .finalize_block_execution_log()
+            .await
+            .expect("finalizing the block execution log should not fail");
     }
 
     #[instrument(name = "dex", skip(_state))]
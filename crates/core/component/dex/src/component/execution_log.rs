@@ -0,0 +1,81 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use futures::TryStreamExt as _;
+use penumbra_proto::{StateReadProto, StateWriteProto};
+
+use crate::{event::EventPositionExecution, state_key::block_position_executions, TradingPair};
+
+/// Reads the log of the position executions of the latest block.
+///
+/// Only the latest block's executions are kept, so that clients can follow
+/// the fills of a trading pair by reading them from each new snapshot.
+#[async_trait]
+pub trait ExecutionLogRead: StateRead {
+    /// The executions of positions on `pair` during the latest block, in the
+    /// order they happened.
+    async fn block_position_executions(
+        &self,
+        pair: &TradingPair,
+    ) -> Result<Vec<EventPositionExecution>> {
+        self.nonverifiable_prefix(block_position_executions::by_pair(pair).as_bytes())
+            .map_ok(|(_, execution)| execution)
+            .try_collect()
+            .await
+    }
+}
+
+impl<T: StateRead + ?Sized> ExecutionLogRead for T {}
+
+#[async_trait]
+pub(crate) trait ExecutionLog: StateWrite {
+    /// Records a position execution, to be added to the log at the end of the block.
+    fn log_position_execution(&mut self, execution: EventPositionExecution) {
+        let mut pending = self.pending_position_executions();
+        pending
+            .entry(execution.trading_pair)
+            .or_default()
+            .push_back(execution);
+        self.object_put(block_position_executions::object::pending(), pending);
+    }
+
+    /// Replaces the logged executions of the previous block with those of this block.
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn finalize_block_execution_log(&mut self) -> Result<()> {
+        let stale: Vec<Vec<u8>> = self
+            .nonverifiable_prefix_raw(block_position_executions::prefix().as_bytes())
+            .map_ok(|(key, _)| key)
+            .try_collect()
+            .await?;
+        for key in stale {
+            self.nonverifiable_delete(key);
+        }
+
+        let pending = self.pending_position_executions();
+        self.object_delete(block_position_executions::object::pending());
+        for (pair, executions) in pending {
+            tracing::debug!(
+                ?pair,
+                count = executions.len(),
+                "logging position executions"
+            );
+            for (index, execution) in executions.into_iter().enumerate() {
+                self.nonverifiable_put(
+                    block_position_executions::by_pair_and_index(&pair, index).into_bytes(),
+                    execution,
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    fn pending_position_executions(
+        &self,
+    ) -> im::HashMap<TradingPair, im::Vector<EventPositionExecution>> {
+        self.object_get(block_position_executions::object::pending())
+            .unwrap_or_default()
+    }
+}
+
+impl<T: StateWrite + ?Sized> ExecutionLog for T {}
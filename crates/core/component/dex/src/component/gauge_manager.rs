@@ -0,0 +1,249 @@
+use std::collections::BTreeMap;
+
+use anyhow::{ensure, Result};
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use futures::TryStreamExt as _;
+use penumbra_asset::Value;
+use penumbra_community_pool::component::StateWriteExt as _;
+use penumbra_num::{fixpoint::U128x128, Amount};
+use penumbra_proto::{DomainType as _, StateReadProto, StateWriteProto};
+use penumbra_sct::component::clock::EpochRead as _;
+use tracing::instrument;
+
+use crate::{
+    component::{PositionRead as _, StateReadExt as _, ValueCircuitBreaker as _},
+    event,
+    lp::position::{self, Position},
+    state_key::{eviction_queue, position_rewards, reward_gauges},
+    DirectedTradingPair, RewardGauge, TradingPair,
+};
+
+/// Reads the reward gauges of trading pairs, and the rewards they accrued to positions.
+#[async_trait]
+pub trait GaugeRead: StateRead {
+    /// The reward gauges of `pair`, one per reward asset.
+    async fn reward_gauges(&self, pair: &TradingPair) -> Result<Vec<RewardGauge>> {
+        self.prefix(&reward_gauges::by_pair(pair))
+            .map_ok(|(_, gauge)| gauge)
+            .try_collect()
+            .await
+    }
+
+    /// The rewards accrued by the position `id`, which its next withdrawal must claim.
+    async fn position_rewards(&self, id: &position::Id) -> Result<Vec<Value>> {
+        self.prefix(&position_rewards::by_position(id))
+            .map_ok(|(_, rewards)| rewards)
+            .try_collect()
+            .await
+    }
+}
+
+impl<T: StateRead + ?Sized> GaugeRead for T {}
+
+#[async_trait]
+pub(crate) trait GaugeManager: StateWrite {
+    /// Adds `rewards` to the gauge of `pair` in their asset, so that they are
+    /// distributed over the next `duration` blocks, starting with this one.
+    ///
+    /// If the pair already has a gauge in that asset, the rewards are added to
+    /// it, and its end is pushed back if needed.
+    #[instrument(skip(self))]
+    async fn fund_gauge(&mut self, pair: TradingPair, rewards: Value, duration: u64) -> Result<()> {
+        let height = self.get_block_height().await?;
+        let end_height = height.saturating_add(duration.saturating_sub(1));
+
+        let key = reward_gauges::by_pair_and_asset(&pair, &rewards.asset_id);
+        let gauge = match self.get::<RewardGauge>(&key).await? {
+            Some(gauge) => RewardGauge {
+                remaining: Value {
+                    amount: gauge
+                        .remaining
+                        .amount
+                        .checked_add(&rewards.amount)
+                        .ok_or_else(|| anyhow::anyhow!("reward gauge overflowed"))?,
+                    asset_id: rewards.asset_id,
+                },
+                end_height: gauge.end_height.max(end_height),
+                ..gauge
+            },
+            None => {
+                let max_gauges = self.get_dex_params().await?.max_reward_gauges_per_pair;
+                let num_gauges = self.reward_gauges(&pair).await?.len();
+                ensure!(
+                    num_gauges < max_gauges as usize,
+                    "trading pair {pair} already has the maximum of {max_gauges} reward gauges"
+                );
+                RewardGauge {
+                    trading_pair: pair,
+                    remaining: rewards,
+                    end_height,
+                }
+            }
+        };
+
+        // The DEX holds the rewards until they are withdrawn along with positions.
+        self.dex_vcb_credit(rewards).await?;
+
+        self.record_proto(
+            event::EventGaugeFund {
+                trading_pair: pair,
+                rewards,
+                end_height: gauge.end_height,
+            }
+            .to_proto(),
+        );
+        self.put(key, gauge);
+        Ok(())
+    }
+
+    /// Distributes the rewards of every gauge for the block at `height` to the
+    /// open positions of its trading pair.
+    ///
+    /// Half of each emission goes to the reserves of either asset of the pair, so
+    /// that positions earn in proportion to the liquidity they provide on each
+    /// side, without relying on a price to compare the two. While a pair has no
+    /// open positions, its rewards are carried over to the next block. Once a
+    /// gauge has ended, whatever it couldn't distribute, whether because the
+    /// pair had no liquidity or as dust left over by rounding, is returned to
+    /// the Community Pool.
+    #[instrument(skip(self))]
+    async fn distribute_gauge_rewards(&mut self, height: u64) -> Result<()> {
+        let mut gauges_by_pair: BTreeMap<TradingPair, Vec<RewardGauge>> = BTreeMap::new();
+        let gauges: Vec<RewardGauge> = self
+            .prefix(reward_gauges::prefix())
+            .map_ok(|(_, gauge)| gauge)
+            .try_collect()
+            .await?;
+        for gauge in gauges {
+            gauges_by_pair
+                .entry(gauge.trading_pair)
+                .or_default()
+                .push(gauge);
+        }
+
+        for (pair, gauges) in gauges_by_pair {
+            let positions = self.open_positions(&pair).await?;
+            let total_1 = positions.iter().fold(Amount::zero(), |total, p| {
+                total.saturating_add(&p.reserves.r1)
+            });
+            let total_2 = positions.iter().fold(Amount::zero(), |total, p| {
+                total.saturating_add(&p.reserves.r2)
+            });
+
+            for gauge in gauges {
+                let emission = gauge.emission_at(height);
+                let (emission_1, emission_2) = match (total_1.value(), total_2.value()) {
+                    (0, 0) => (Amount::zero(), Amount::zero()),
+                    (0, _) => (Amount::zero(), emission),
+                    (_, 0) => (emission, Amount::zero()),
+                    _ => {
+                        let half = emission / Amount::from(2u64);
+                        (half, emission - half)
+                    }
+                };
+
+                let mut distributed = Amount::zero();
+                for position in &positions {
+                    let reward = pro_rata(emission_1, position.reserves.r1, total_1)?
+                        + pro_rata(emission_2, position.reserves.r2, total_2)?;
+                    if reward == Amount::zero() {
+                        continue;
+                    }
+                    self.accrue_position_rewards(
+                        &position.id(),
+                        Value {
+                            amount: reward,
+                            asset_id: gauge.remaining.asset_id,
+                        },
+                    )
+                    .await?;
+                    distributed += reward;
+                }
+
+                let key = reward_gauges::by_pair_and_asset(&pair, &gauge.remaining.asset_id);
+                let remaining = Value {
+                    amount: gauge.remaining.amount - distributed,
+                    asset_id: gauge.remaining.asset_id,
+                };
+                tracing::debug!(?pair, ?distributed, ?remaining, "distributed gauge rewards");
+                if remaining.amount == Amount::zero() {
+                    self.delete(key);
+                } else if height >= gauge.end_height {
+                    self.dex_vcb_debit(remaining).await?;
+                    self.community_pool_deposit(remaining).await;
+                    self.delete(key);
+                } else {
+                    self.put(key, RewardGauge { remaining, ..gauge });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes the rewards accrued by the position `id`, returning them so that
+    /// they can be paid out by its withdrawal.
+    async fn take_position_rewards(&mut self, id: &position::Id) -> Result<Vec<Value>> {
+        let rewards = self.position_rewards(id).await?;
+        for reward in &rewards {
+            self.delete(position_rewards::by_position_and_asset(
+                id,
+                &reward.asset_id,
+            ));
+            self.dex_vcb_debit(*reward).await?;
+        }
+        Ok(rewards)
+    }
+
+    async fn accrue_position_rewards(&mut self, id: &position::Id, reward: Value) -> Result<()> {
+        let key = position_rewards::by_position_and_asset(id, &reward.asset_id);
+        let accrued = self
+            .get::<Value>(&key)
+            .await?
+            .map(|accrued| accrued.amount)
+            .unwrap_or_default();
+        self.put(
+            key,
+            Value {
+                amount: accrued
+                    .checked_add(&reward.amount)
+                    .ok_or_else(|| anyhow::anyhow!("position rewards overflowed"))?,
+                asset_id: reward.asset_id,
+            },
+        );
+        Ok(())
+    }
+
+    /// The open positions of `pair`, read off the inventory index, which holds
+    /// each of them once per direction of the pair.
+    async fn open_positions(&self, pair: &TradingPair) -> Result<Vec<Position>> {
+        let pair_ab = DirectedTradingPair::new(pair.asset_1(), pair.asset_2());
+        let ids: Vec<position::Id> = self
+            .nonverifiable_prefix_raw(&eviction_queue::inventory_index::by_trading_pair(&pair_ab))
+            .and_then(|(key, _)| async move {
+                let raw_id = eviction_queue::inventory_index::parse_id_from_key(key)?;
+                Ok(position::Id(raw_id))
+            })
+            .try_collect()
+            .await?;
+
+        let mut positions = Vec::with_capacity(ids.len());
+        for id in ids {
+            if let Some(position) = self.position_by_id(&id).await? {
+                positions.push(position);
+            }
+        }
+        Ok(positions)
+    }
+}
+
+impl<T: StateWrite + ?Sized> GaugeManager for T {}
+
+/// The share of `amount` that `part` of `total` is entitled to, rounded down.
+fn pro_rata(amount: Amount, part: Amount, total: Amount) -> Result<Amount> {
+    if total == Amount::zero() {
+        return Ok(Amount::zero());
+    }
+    Ok(U128x128::ratio(part, total)?.apply_to_amount(&amount)?)
+}
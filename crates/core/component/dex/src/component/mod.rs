@@ -12,15 +12,21 @@ mod chandelier;
 pub(crate) mod circuit_breaker;
 mod dex;
 mod eviction_manager;
+mod execution_log;
 mod flow;
+mod gauge_manager;
 mod position_manager;
+mod price_oracle;
 mod swap_manager;
 
 pub use dex::{Dex, StateReadExt, StateWriteExt};
 pub use position_manager::PositionManager;
 
 // Read data from the Dex component;
+pub use execution_log::ExecutionLogRead;
+pub use gauge_manager::GaugeRead;
 pub use position_manager::PositionRead;
+pub use price_oracle::PriceOracleRead;
 pub use swap_manager::SwapDataRead;
 
 pub(crate) use arb::Arbitrage;
@@ -28,6 +34,9 @@ pub(crate) use circuit_breaker::ExecutionCircuitBreaker;
 pub(crate) use circuit_breaker::ValueCircuitBreaker;
 pub use circuit_breaker::ValueCircuitBreakerRead;
 pub(crate) use dex::InternalDexWrite;
+pub(crate) use execution_log::ExecutionLog;
+pub(crate) use gauge_manager::GaugeManager;
+pub(crate) use price_oracle::PriceOracle;
 pub(crate) use swap_manager::SwapDataWrite;
 pub(crate) use swap_manager::SwapManager;
 
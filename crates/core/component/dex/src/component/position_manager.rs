@@ -1,15 +1,14 @@
-use std::future;
-use std::{pin::Pin, sync::Arc};
+use std::pin::Pin;
 
 use anyhow::{bail, ensure, Result};
-use async_stream::try_stream;
 use async_trait::async_trait;
 use cnidarium::{EscapedByteSlice, StateRead, StateWrite};
 use futures::Stream;
-use futures::StreamExt;
+use futures::{StreamExt, TryStreamExt};
 use penumbra_asset::{asset, Balance};
 use penumbra_proto::DomainType;
 use penumbra_proto::{StateReadProto, StateWriteProto};
+use penumbra_sct::component::clock::EpochRead as _;
 use tap::Tap;
 use tracing::instrument;
 
@@ -32,8 +31,7 @@ use crate::{
 use crate::{event, state_key};
 
 use super::chandelier::Chandelier;
-
-const DYNAMIC_ASSET_LIMIT: usize = 10;
+use super::ExecutionLog as _;
 
 mod base_liquidity_index;
 pub(crate) mod counter;
@@ -102,38 +100,6 @@ pub trait PositionRead: StateRead {
             .unwrap_or_default()
     }
 
-    /// Returns the list of candidate assets to route through for a trade from `from`.
-    /// Combines a list of fixed candidates with a list of liquidity-based candidates.
-    /// This ensures that the fixed candidates are always considered, minimizing
-    /// the risk of attacks on routing.
-    fn candidate_set(
-        &self,
-        from: asset::Id,
-        fixed_candidates: Arc<Vec<asset::Id>>,
-    ) -> Pin<Box<dyn Stream<Item = Result<asset::Id>> + Send>> {
-        // Clone the fixed candidates Arc so it can be moved into the stream filter's future.
-        let fc = fixed_candidates.clone();
-        let mut dynamic_candidates = self
-            .ordered_routable_assets(&from)
-            .filter(move |c| {
-                future::ready(!fc.contains(c.as_ref().expect("failed to fetch candidate")))
-            })
-            .take(DYNAMIC_ASSET_LIMIT);
-        try_stream! {
-            // First stream the fixed candidates, so those can be processed while the dynamic candidates are fetched.
-            for candidate in fixed_candidates.iter() {
-                yield candidate.clone();
-            }
-
-            // Yield the liquidity-based candidates. Note that this _may_ include some assets already included in the fixed set.
-            while let Some(candidate) = dynamic_candidates
-                .next().await {
-                    yield candidate.expect("failed to fetch candidate");
-            }
-        }
-        .boxed()
-    }
-
     /// Returns a stream of [`asset::Id`] routable from a given asset, ordered by liquidity.
     fn ordered_routable_assets(
         &self,
@@ -229,13 +195,46 @@ pub trait PositionManager: StateWrite + PositionRead {
         Ok(())
     }
 
+    /// Close all positions expiring at the end of the block at `height`.
+    ///
+    /// Positions that were already closed, or withdrawn, before expiring are left as they are.
+    #[instrument(skip(self))]
+    async fn close_expired_positions(&mut self, height: u64) -> Result<()> {
+        let prefix = state_key::expiring_positions::by_height(height);
+        let expiring: Vec<(Vec<u8>, Vec<u8>)> = self
+            .nonverifiable_prefix_raw(prefix.as_bytes())
+            .try_collect()
+            .await?;
+
+        for (key, raw_id) in expiring {
+            let id = position::Id(
+                raw_id
+                    .as_slice()
+                    .try_into()
+                    .map_err(|_| anyhow::anyhow!("malformed expiring position id"))?,
+            );
+            let is_opened = self
+                .position_by_id(&id)
+                .await?
+                .is_some_and(|position| position.state == position::State::Opened);
+            if is_opened {
+                tracing::debug!(?id, "closing expired position");
+                self.close_position_by_id(&id).await?;
+            }
+            self.nonverifiable_delete(key);
+        }
+
+        Ok(())
+    }
+
     /// Opens a new position, updating all necessary indexes and checking for
     /// its nonexistence prior to being opened.
     ///
     /// # Errors
     /// This method returns an error if the position is malformed
     /// e.g. it is set to a state other than `Opened`
-    ///  or, it specifies a position identifier already used by another position.
+    ///  or, it specifies a position identifier already used by another position
+    ///  or, it expires at a height that has already passed.
     ///
     /// An error can also occur if a DEX engine invariant is breached
     /// e.g. overflowing the position counter (`u16::MAX`)
@@ -259,6 +258,20 @@ pub trait PositionManager: StateWrite + PositionRead {
             );
         }
 
+        // A position expiring at the current height is only usable during this block,
+        // and is closed with the other expiring positions at the end of it.
+        if let Some(expiry_height) = position.expiry_height {
+            let current_height = self.get_block_height().await?;
+            ensure!(
+                expiry_height >= current_height,
+                "attempted to open a position expiring at height {expiry_height}, before the current height {current_height}",
+            );
+            self.nonverifiable_put_raw(
+                state_key::expiring_positions::by_height_and_id(expiry_height, &id).into_bytes(),
+                id.0.to_vec(),
+            );
+        }
+
         // Credit the DEX for the inflows from this position.
         self.dex_vcb_credit(position.reserves_1()).await?;
         self.dex_vcb_credit(position.reserves_2()).await?;
@@ -349,9 +362,9 @@ pub trait PositionManager: StateWrite + PositionRead {
 
         // We have already short-circuited no-op execution updates, so we can emit an execution
         // event and not worry about duplicates.
-        self.record_proto(
-            event::EventPositionExecution::in_context(&prev_state, &new_state, context).to_proto(),
-        );
+        let execution = event::EventPositionExecution::in_context(&prev_state, &new_state, context);
+        self.record_proto(execution.to_proto());
+        self.log_position_execution(execution);
 
         // Handle "close-on-fill": automatically flip the position state to "closed" if
         // either of the reserves are zero.
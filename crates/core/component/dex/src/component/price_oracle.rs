@@ -0,0 +1,232 @@
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use futures::{StreamExt as _, TryStreamExt as _};
+use penumbra_num::fixpoint::U128x128;
+use penumbra_sct::component::clock::EpochRead as _;
+
+use crate::{
+    component::dex::StateReadExt as _, state_key::price_oracle, BatchSwapOutputData,
+    DirectedTradingPair, TradingPair,
+};
+
+/// The length of the zero-padded height at the end of a price observation key.
+const HEIGHT_KEY_LEN: usize = 20;
+
+/// Reads time-weighted average prices from the price oracle.
+///
+/// The oracle observes the price at which each batch swap clears, and treats
+/// it as the price of the trading pair from that block until the next batch
+/// swap on the pair.
+#[async_trait]
+pub trait PriceOracleRead: StateRead {
+    /// The clearing prices the oracle holds for `pair`, in units of asset 2 per
+    /// unit of asset 1, from oldest to newest, along with the heights at which
+    /// they were observed.
+    async fn price_observations(&self, pair: &TradingPair) -> Result<Vec<(u64, U128x128)>> {
+        self.nonverifiable_prefix_raw(price_oracle::by_pair(pair).as_bytes())
+            .map(|entry| entry.and_then(parse_observation))
+            .try_collect()
+            .await
+    }
+
+    /// The time-weighted average price of the start asset of `pair`, in terms
+    /// of its end asset, over the `window` blocks ending with the current one.
+    ///
+    /// Returns `None` if the oracle's history of the pair doesn't cover the
+    /// whole window.
+    async fn time_weighted_average_price(
+        &self,
+        pair: &DirectedTradingPair,
+        window: u64,
+    ) -> Result<Option<U128x128>> {
+        let height = self.get_block_height().await?;
+        let canonical = pair.to_canonical();
+        let observations = self.price_observations(&canonical).await?;
+
+        let Some(price) = time_weighted_average(&observations, height, window)? else {
+            return Ok(None);
+        };
+
+        if pair.start == canonical.asset_1() {
+            Ok(Some(price))
+        } else {
+            Ok(Some(U128x128::from(1u64).checked_div(&price)?))
+        }
+    }
+}
+
+impl<T: StateRead + ?Sized> PriceOracleRead for T {}
+
+#[async_trait]
+pub(crate) trait PriceOracle: StateWrite {
+    /// Records the price at which a batch swap cleared, if anything was traded,
+    /// and forgets the prices of its pair that are too old to be part of any
+    /// window tracked by the oracle.
+    async fn record_clearing_price(&mut self, bsod: &BatchSwapOutputData) -> Result<()> {
+        let windows = self.get_dex_params().await?.price_oracle_windows;
+        let Some(max_window) = windows.iter().copied().max() else {
+            // The oracle is disabled.
+            return Ok(());
+        };
+        let Some(price) = bsod.clearing_price() else {
+            return Ok(());
+        };
+
+        let pair = bsod.trading_pair;
+        tracing::debug!(
+            ?pair,
+            height = bsod.height,
+            ?price,
+            "recording clearing price"
+        );
+        self.nonverifiable_put_raw(
+            price_oracle::by_pair_and_height(&pair, bsod.height).into_bytes(),
+            price.to_bytes().to_vec(),
+        );
+
+        // The longest window ending at this block or any later one starts at
+        // `oldest_start` or after it, so only the last price observed at or
+        // before it is still needed, as the price at the start of the window.
+        let Some(oldest_start) = (bsod.height + 1).checked_sub(max_window) else {
+            return Ok(());
+        };
+        let expired: Vec<(Vec<u8>, Vec<u8>)> = self
+            .nonverifiable_range_raw(
+                Some(price_oracle::by_pair(&pair).as_bytes()),
+                ..format!("{:020}", oldest_start + 1).into_bytes(),
+            )?
+            .try_collect()
+            .await?;
+        if let Some((_, older)) = expired.split_last() {
+            for (key, _) in older {
+                self.nonverifiable_delete(key.clone());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether the price at which a batch swap cleared deviates from the
+    /// time-weighted average price of its pair by more than the DEX parameters
+    /// allow, in which case the batch swap should not be executed.
+    ///
+    /// Batch swaps on pairs without enough price history to compare to never
+    /// deviate.
+    async fn clearing_price_deviates(&self, bsod: &BatchSwapOutputData) -> Result<bool> {
+        let params = self.get_dex_params().await?;
+        if params.max_price_deviation_bps == 0 {
+            // The circuit breaker is disabled.
+            return Ok(false);
+        }
+        let Some(price) = bsod.clearing_price() else {
+            return Ok(false);
+        };
+        let pair =
+            DirectedTradingPair::new(bsod.trading_pair.asset_1(), bsod.trading_pair.asset_2());
+        let Some(average) = self
+            .time_weighted_average_price(&pair, params.price_deviation_window)
+            .await?
+        else {
+            return Ok(false);
+        };
+
+        let deviation = if price > average {
+            price.checked_sub(&average)?
+        } else {
+            average.checked_sub(&price)?
+        };
+        let max_deviation = average
+            .checked_mul(&U128x128::from(params.max_price_deviation_bps))?
+            .checked_div(&U128x128::from(10_000u64))?;
+
+        if deviation > max_deviation {
+            tracing::warn!(
+                ?pair,
+                ?price,
+                ?average,
+                max_deviation_bps = params.max_price_deviation_bps,
+                "clearing price deviates from the time-weighted average price"
+            );
+            return Ok(true);
+        }
+        Ok(false)
+    }
+}
+
+impl<T: StateWrite + ?Sized> PriceOracle for T {}
+
+fn parse_observation((key, value): (Vec<u8>, Vec<u8>)) -> Result<(u64, U128x128)> {
+    let height = key
+        .len()
+        .checked_sub(HEIGHT_KEY_LEN)
+        .and_then(|start| std::str::from_utf8(&key[start..]).ok())
+        .and_then(|height| height.parse().ok())
+        .ok_or_else(|| anyhow!("malformed price observation key"))?;
+    let price = U128x128::try_from(value.as_slice())?;
+    Ok((height, price))
+}
+
+/// The average of the prices observed at the given heights, sorted in
+/// ascending order, over the `window` blocks ending with `height`, where each
+/// price holds from the block it was observed at until the next observation.
+///
+/// Returns `None` if no price was observed at or before the start of the window.
+fn time_weighted_average(
+    observations: &[(u64, U128x128)],
+    height: u64,
+    window: u64,
+) -> Result<Option<U128x128>> {
+    // The window covers the blocks `start..=height`.
+    let Some(start) = (height + 1).checked_sub(window).filter(|_| window > 0) else {
+        return Ok(None);
+    };
+    let Some(first) = observations.iter().rposition(|(h, _)| *h <= start) else {
+        return Ok(None);
+    };
+
+    let mut sum = U128x128::default();
+    let (mut since, mut price) = (start, observations[first].1);
+    for &(observed_at, next_price) in observations[first + 1..]
+        .iter()
+        .take_while(|(h, _)| *h <= height)
+    {
+        sum = sum.checked_add(&price.checked_mul(&U128x128::from(observed_at - since))?)?;
+        (since, price) = (observed_at, next_price);
+    }
+    sum = sum.checked_add(&price.checked_mul(&U128x128::from(height + 1 - since))?)?;
+
+    Ok(Some(sum.checked_div(&U128x128::from(window))?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prices_are_weighted_by_the_blocks_they_hold_for() {
+        let observations = [
+            (3, U128x128::from(10u64)),
+            (6, U128x128::from(4u64)),
+            (8, U128x128::from(1u64)),
+        ];
+
+        // Nothing was observed before block 3.
+        assert!(time_weighted_average(&observations, 5, 4)
+            .unwrap()
+            .is_none());
+        // Blocks 3..=5 at 10.
+        assert_eq!(
+            time_weighted_average(&observations, 5, 3).unwrap(),
+            Some(U128x128::from(10u64))
+        );
+        // Blocks 4..=5 at 10, 6..=7 at 4, and 8..=9 at 1.
+        assert_eq!(
+            time_weighted_average(&observations, 9, 6).unwrap(),
+            Some(U128x128::from(5u64))
+        );
+        assert!(time_weighted_average(&observations, 9, 0)
+            .unwrap()
+            .is_none());
+    }
+}
@@ -0,0 +1,77 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use anyhow::Result;
+use cnidarium::StateRead;
+use futures::{StreamExt as _, TryStreamExt as _};
+use parking_lot::Mutex;
+use penumbra_asset::asset;
+
+use crate::component::PositionRead as _;
+
+/// The assets routable from some asset, ordered by the depth of their liquidity.
+#[derive(Debug)]
+struct Neighbors {
+    assets: Vec<asset::Id>,
+    /// Whether `assets` are all the routable assets, rather than the first of them.
+    complete: bool,
+}
+
+/// A cache of the liquidity graph explored by routing.
+///
+/// Each asset's neighbors are read from the liquidity index the first time a
+/// path is extended from it, and shared by every path search with the same
+/// graph. Routing parameters built from the DEX parameters start with an empty
+/// graph, which is then shared by all of a block's batch swaps, so that large
+/// batches over many pairs don't repeatedly scan the same indexes.
+///
+/// The graph only determines which assets are considered as the next hop of a
+/// path; the positions along it are always read from the current state.
+#[derive(Debug, Clone, Default)]
+pub struct LiquidityGraph(Arc<Mutex<BTreeMap<asset::Id, Arc<Neighbors>>>>);
+
+impl LiquidityGraph {
+    /// The candidates for the next hop from `from`: the `fixed_candidates`,
+    /// then up to `max_dynamic` of the other assets with the deepest liquidity.
+    pub(super) async fn candidates<S: StateRead>(
+        &self,
+        state: &S,
+        from: asset::Id,
+        fixed_candidates: &[asset::Id],
+        max_dynamic: usize,
+    ) -> Result<Vec<asset::Id>> {
+        // At most `fixed_candidates.len()` of the neighbors are skipped, so
+        // this many of them are enough to find `max_dynamic` others.
+        let needed = max_dynamic + fixed_candidates.len();
+
+        let cached = self.0.lock().get(&from).cloned();
+        let neighbors = match cached {
+            Some(neighbors) if neighbors.complete || neighbors.assets.len() >= needed => neighbors,
+            _ => {
+                let assets: Vec<asset::Id> = state
+                    .ordered_routable_assets(&from)
+                    .take(needed)
+                    .try_collect()
+                    .await?;
+                let neighbors = Arc::new(Neighbors {
+                    complete: assets.len() < needed,
+                    assets,
+                });
+                self.0.lock().insert(from, neighbors.clone());
+                neighbors
+            }
+        };
+
+        Ok(fixed_candidates
+            .iter()
+            .copied()
+            .chain(
+                neighbors
+                    .assets
+                    .iter()
+                    .filter(|asset| !fixed_candidates.contains(asset))
+                    .take(max_dynamic)
+                    .copied(),
+            )
+            .collect())
+    }
+}
@@ -1,4 +1,5 @@
 mod fill_route;
+mod liquidity_graph;
 mod params;
 mod path;
 mod path_cache;
@@ -9,6 +10,7 @@ use path::Path;
 use path_cache::{PathCache, PathEntry, SharedPathCache};
 
 pub use fill_route::FillRoute;
+pub use liquidity_graph::LiquidityGraph;
 pub use params::RoutingParams;
 pub use path_search::PathSearch;
 pub use route_and_fill::{HandleBatchSwaps, RouteAndFill};
@@ -5,11 +5,20 @@ use penumbra_num::fixpoint::U128x128;
 
 use crate::DexParameters;
 
+use super::LiquidityGraph;
+
 #[derive(Debug, Clone)]
 pub struct RoutingParams {
     pub price_limit: Option<U128x128>,
     pub fixed_candidates: Arc<Vec<asset::Id>>,
     pub max_hops: usize,
+    /// The maximum number of liquidity-based candidates for each hop, in
+    /// addition to the fixed candidates.
+    pub max_dynamic_candidates: usize,
+    /// How far past the spill price, in basis points, to fill a route.
+    pub spill_price_margin_bps: u32,
+    /// The liquidity graph explored by the path searches using these parameters.
+    pub liquidity_graph: LiquidityGraph,
 }
 
 impl RoutingParams {
@@ -23,6 +32,28 @@ impl RoutingParams {
         }
     }
 
+    /// Raises the spill price by the spill price margin, so that routes are
+    /// filled somewhat past the price of the next best route.
+    ///
+    /// The margin never raises the spill price past the price limit, and if the
+    /// raised price overflows, the spill price is returned unchanged.
+    pub fn apply_spill_margin(&self, spill_price: Option<U128x128>) -> Option<U128x128> {
+        let spill_price = spill_price?;
+        if self.spill_price_margin_bps == 0 {
+            return Some(spill_price);
+        }
+
+        let margin = U128x128::ratio(10_000 + self.spill_price_margin_bps as u64, 10_000)
+            .expect("denominator is nonzero");
+        let raised = (spill_price * margin).unwrap_or(spill_price);
+        match self.price_limit {
+            Some(price_limit) if raised > price_limit && spill_price <= price_limit => {
+                Some(price_limit)
+            }
+            _ => Some(raised),
+        }
+    }
+
     /// Clamps the spill price to the price limit and returns whether or not it was clamped.
     pub fn clamp_to_limit(&self, spill_price: Option<U128x128>) -> (Option<U128x128>, bool) {
         match (spill_price, self.price_limit) {
@@ -45,12 +76,17 @@ impl From<DexParameters> for RoutingParams {
         DexParameters {
             fixed_candidates,
             max_hops,
+            max_dynamic_candidates,
+            spill_price_margin_bps,
             ..
         }: DexParameters,
     ) -> Self {
         Self {
             fixed_candidates: Arc::new(fixed_candidates),
             max_hops: max_hops as usize,
+            max_dynamic_candidates: max_dynamic_candidates as usize,
+            spill_price_margin_bps,
+            liquidity_graph: LiquidityGraph::default(),
             price_limit: None,
         }
     }
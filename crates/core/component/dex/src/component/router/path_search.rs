@@ -3,16 +3,13 @@ use std::sync::Arc;
 use anyhow::Result;
 use async_trait::async_trait;
 use cnidarium::{StateDelta, StateRead};
-use futures::StreamExt;
 use penumbra_asset::asset;
 use penumbra_num::fixpoint::U128x128;
 use tap::Tap;
 use tokio::task::JoinSet;
 use tracing::{instrument, Instrument};
 
-use crate::component::PositionRead as _;
-
-use super::{Path, PathCache, PathEntry, RoutingParams, SharedPathCache};
+use super::{LiquidityGraph, Path, PathCache, PathEntry, RoutingParams, SharedPathCache};
 
 #[async_trait]
 pub trait PathSearch: StateRead + Clone + 'static {
@@ -30,6 +27,9 @@ pub trait PathSearch: StateRead + Clone + 'static {
             max_hops,
             fixed_candidates,
             price_limit,
+            max_dynamic_candidates,
+            liquidity_graph,
+            ..
         } = params;
 
         // Initialize some metrics for calculating time spent on path searching
@@ -48,7 +48,13 @@ pub trait PathSearch: StateRead + Clone + 'static {
 
         let cache = PathCache::begin(src, state);
         for i in 0..max_hops {
-            relax_active_paths(cache.clone(), fixed_candidates.clone()).await?;
+            relax_active_paths(
+                cache.clone(),
+                fixed_candidates.clone(),
+                liquidity_graph.clone(),
+                max_dynamic_candidates,
+            )
+            .await?;
             tracing::trace!(i, "finished relaxing all active paths");
         }
 
@@ -86,6 +92,8 @@ impl<S> PathSearch for S where S: StateRead + Clone + 'static {}
 async fn relax_active_paths<S: StateRead + 'static>(
     cache: SharedPathCache<S>,
     fixed_candidates: Arc<Vec<asset::Id>>,
+    liquidity_graph: LiquidityGraph,
+    max_dynamic_candidates: usize,
 ) -> Result<()> {
     let active_paths = cache.lock().extract_active();
     let mut js = JoinSet::new();
@@ -96,13 +104,20 @@ async fn relax_active_paths<S: StateRead + 'static>(
     for path in active_paths {
         let candidates = Arc::clone(&fixed_candidates);
         let cache = Arc::clone(&cache);
+        let liquidity_graph = liquidity_graph.clone();
         js.spawn(async move {
             use crate::component::metrics::DEX_PATH_SEARCH_RELAX_PATH_DURATION;
             let metric = metrics::histogram!(DEX_PATH_SEARCH_RELAX_PATH_DURATION);
             let start = std::time::Instant::now();
-            relax_path(cache, path, candidates)
-                .await
-                .tap(|_| metric.record(start.elapsed()))
+            relax_path(
+                cache,
+                path,
+                candidates,
+                liquidity_graph,
+                max_dynamic_candidates,
+            )
+            .await
+            .tap(|_| metric.record(start.elapsed()))
         });
     }
     // Wait for all relaxations to complete.
@@ -116,11 +131,18 @@ async fn relax_path<S: StateRead + 'static>(
     cache: SharedPathCache<S>,
     mut path: Path<S>,
     fixed_candidates: Arc<Vec<asset::Id>>,
+    liquidity_graph: LiquidityGraph,
+    max_dynamic_candidates: usize,
 ) -> Result<()> {
-    let mut candidates = path
-        .state
-        .candidate_set(*path.end(), fixed_candidates)
-        .instrument(path.span.clone());
+    let candidates = liquidity_graph
+        .candidates(
+            &path.state,
+            *path.end(),
+            &fixed_candidates,
+            max_dynamic_candidates,
+        )
+        .instrument(path.span.clone())
+        .await?;
 
     path.span.in_scope(|| {
         tracing::trace!("relaxing path");
@@ -128,11 +150,11 @@ async fn relax_path<S: StateRead + 'static>(
 
     let mut js = JoinSet::new();
 
-    while let Some(new_end) = candidates.inner_mut().next().await {
+    for new_end in candidates {
         let new_path = path.fork();
         let cache2 = cache.clone();
         js.spawn(async move {
-            if let Some(new_path) = new_path.extend_to(new_end?).await? {
+            if let Some(new_path) = new_path.extend_to(new_end).await? {
                 cache2.lock().consider(new_path)
             }
             anyhow::Ok(())
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
-use cnidarium::StateWrite;
+use cnidarium::{StateDelta, StateWrite};
 use penumbra_asset::{asset, Value};
 use penumbra_num::Amount;
 use penumbra_sct::component::clock::EpochRead;
@@ -13,7 +13,7 @@ use crate::{
         chandelier::Chandelier,
         flow::SwapFlow,
         router::{FillRoute, PathSearch, RoutingParams},
-        ExecutionCircuitBreaker, InternalDexWrite, PositionManager,
+        ExecutionCircuitBreaker, InternalDexWrite, PositionManager, PriceOracle,
     },
     lp::position::MAX_RESERVE_AMOUNT,
     BatchSwapOutputData, SwapExecution, TradingPair,
@@ -44,7 +44,11 @@ pub trait HandleBatchSwaps: StateWrite + Sized {
         // executions up to the specified `execution_budget` parameter.
         let execution_circuit_breaker = ExecutionCircuitBreaker::new(execution_budget);
 
-        let swap_execution_1_for_2 = self
+        // Fill the batch in a fork of the state, so that the fills can be discarded
+        // if the batch's clearing price trips the price deviation circuit breaker.
+        let mut this = Arc::new(StateDelta::new(self.clone()));
+
+        let swap_execution_1_for_2 = this
             .route_and_fill(
                 trading_pair.asset_1(),
                 trading_pair.asset_2(),
@@ -54,7 +58,7 @@ pub trait HandleBatchSwaps: StateWrite + Sized {
             )
             .await?;
 
-        let swap_execution_2_for_1 = self
+        let swap_execution_2_for_1 = this
             .route_and_fill(
                 trading_pair.asset_2(),
                 trading_pair.asset_1(),
@@ -99,6 +103,29 @@ pub trait HandleBatchSwaps: StateWrite + Sized {
                 .into(),
         };
 
+        let (output_data, swap_execution_1_for_2, swap_execution_2_for_1) =
+            if self.clearing_price_deviates(&output_data).await? {
+                // Discard the fills, and return the whole batch to the swappers.
+                tracing::warn!(?trading_pair, "price deviation circuit breaker tripped");
+                std::mem::drop(this);
+                let output_data = BatchSwapOutputData {
+                    lambda_1: 0u64.into(),
+                    lambda_2: 0u64.into(),
+                    unfilled_1: delta_1,
+                    unfilled_2: delta_2,
+                    ..output_data
+                };
+                (output_data, None, None)
+            } else {
+                let (this, cache) = Arc::try_unwrap(this)
+                    .map_err(|_| ())
+                    .expect("no more outstanding refs to state after routing")
+                    .flatten();
+                std::mem::drop(this);
+                cache.apply_to(Arc::get_mut(self).expect("expected state to have no other refs"));
+                (output_data, swap_execution_1_for_2, swap_execution_2_for_1)
+            };
+
         tracing::debug!(
             ?output_data,
             ?swap_execution_1_for_2,
@@ -203,6 +230,10 @@ pub trait RouteAndFill: StateWrite + Sized {
                 asset_id: asset_1,
             };
 
+            // Filling past the spill price, by the configured margin, saves path
+            // searches at the cost of a slightly worse execution price.
+            let spill_price = params.apply_spill_margin(spill_price);
+
             tracing::debug!(?path, delta_1 = ?delta_1.amount, "found path, filling up to spill price");
 
             let execution = Arc::get_mut(self)
@@ -25,7 +25,7 @@ use crate::{
     DirectedTradingPair, DirectedUnitPair,
 };
 
-use super::PathSearch;
+use super::{LiquidityGraph, PathSearch, RoutingParams};
 
 #[tokio::test(flavor = "multi_thread")]
 async fn path_search_basic() {
@@ -2175,3 +2175,74 @@ async fn path_compare_node_ids() -> anyhow::Result<()> {
     assert!(path2 < path1);
     Ok(())
 }
+
+#[tokio::test]
+/// Test that routing considers at most the configured number of liquidity-based
+/// candidates, and that path searches with the same routing parameters share the
+/// liquidity graph they explore.
+async fn dynamic_candidates_and_liquidity_graph() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+
+    // Create a 1:1 gn:gm position, so that gn can be routed to gm.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    let buy_gn = create_buy(
+        DirectedUnitPair::new(gn.clone(), gm.clone()),
+        1u64.into(),
+        1u64.into(),
+    );
+    state_tx.open_position(buy_gn).await?;
+    state_tx.apply();
+
+    // Only route through liquidity-based candidates.
+    let mut routing_params = state.routing_params().await?;
+    routing_params.fixed_candidates = Arc::new(vec![]);
+    routing_params.max_hops = 2;
+
+    let (path, _spill) = state
+        .path_search(gn.id(), penumbra.id(), routing_params.clone())
+        .await?;
+    assert!(path.is_none(), "gm can't be routed to penumbra yet");
+
+    // Create a 1:1 gm:penumbra position, so that gn can be routed to penumbra through gm.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    let buy_gm = create_buy(
+        DirectedUnitPair::new(gm.clone(), penumbra.clone()),
+        1u64.into(),
+        1u64.into(),
+    );
+    state_tx.open_position(buy_gm).await?;
+    state_tx.apply();
+
+    // The graph explored by the first search doesn't have the new position's edge.
+    let (path, _spill) = state
+        .path_search(gn.id(), penumbra.id(), routing_params.clone())
+        .await?;
+    assert!(path.is_none(), "the liquidity graph is cached");
+
+    // A fresh graph does, but gm is only a candidate if dynamic candidates are considered.
+    let no_dynamic_candidates = RoutingParams {
+        max_dynamic_candidates: 0,
+        liquidity_graph: LiquidityGraph::default(),
+        ..routing_params.clone()
+    };
+    let (path, _spill) = state
+        .path_search(gn.id(), penumbra.id(), no_dynamic_candidates)
+        .await?;
+    assert!(path.is_none(), "gm is not a fixed candidate");
+
+    routing_params.liquidity_graph = LiquidityGraph::default();
+    let (path, _spill) = state
+        .path_search(gn.id(), penumbra.id(), routing_params)
+        .await?;
+    assert_eq!(path, Some(vec![gm.id(), penumbra.id()]));
+
+    Ok(())
+}
@@ -18,16 +18,21 @@ use penumbra_proto::{
         },
         simulation_service_server::SimulationService,
         ArbExecutionRequest, ArbExecutionResponse, ArbExecutionsRequest, ArbExecutionsResponse,
-        BatchSwapOutputDataRequest, BatchSwapOutputDataResponse, CandlestickDataRequest,
-        CandlestickDataResponse, CandlestickDataStreamRequest, CandlestickDataStreamResponse,
-        LiquidityPositionByIdRequest, LiquidityPositionByIdResponse, LiquidityPositionsByIdRequest,
+        BatchSwapOutputDataRequest, BatchSwapOutputDataResponse, BatchSwapOutputDataStreamRequest,
+        BatchSwapOutputDataStreamResponse, CandlestickDataRequest, CandlestickDataResponse,
+        CandlestickDataStreamRequest, CandlestickDataStreamResponse, LiquidityPositionByIdRequest,
+        LiquidityPositionByIdResponse, LiquidityPositionsByIdRequest,
         LiquidityPositionsByIdResponse, LiquidityPositionsByPriceRequest,
         LiquidityPositionsByPriceResponse, LiquidityPositionsRequest, LiquidityPositionsResponse,
+        PositionRewardsRequest, PositionRewardsResponse, RewardGaugesRequest, RewardGaugesResponse,
         SimulateTradeRequest, SimulateTradeResponse, SpreadRequest, SpreadResponse,
         SwapExecutionRequest, SwapExecutionResponse, SwapExecutionsRequest, SwapExecutionsResponse,
+        TimeWeightedAveragePrice, TimeWeightedAveragePricesRequest,
+        TimeWeightedAveragePricesResponse,
     },
     DomainType, StateReadProto,
 };
+use penumbra_sct::component::clock::EpochRead as _;
 
 use super::ExecutionCircuitBreaker;
 use crate::{
@@ -36,7 +41,11 @@ use crate::{
     state_key, CandlestickData, DirectedTradingPair, SwapExecution, TradingPair,
 };
 
-use super::{chandelier::CandlestickRead, router::RouteAndFill, PositionRead, StateReadExt};
+use super::{
+    chandelier::{CandlestickRead, CANDLESTICK_RESOLUTIONS},
+    router::RouteAndFill,
+    ExecutionLogRead, GaugeRead, PositionRead, PriceOracleRead, StateReadExt,
+};
 
 pub mod stub;
 
@@ -77,6 +86,14 @@ impl QueryService for Server {
             dyn futures::Stream<Item = Result<CandlestickDataStreamResponse, tonic::Status>> + Send,
         >,
     >;
+    type BatchSwapOutputDataStreamStream = Pin<
+        Box<
+            dyn futures::Stream<Item = Result<BatchSwapOutputDataStreamResponse, tonic::Status>>
+                + Send,
+        >,
+    >;
+    type RewardGaugesStream =
+        Pin<Box<dyn futures::Stream<Item = Result<RewardGaugesResponse, tonic::Status>> + Send>>;
 
     #[instrument(skip(self, request))]
     async fn arb_execution(
@@ -216,11 +233,20 @@ impl QueryService for Server {
         // Limit the number of candlesticks returned to 20,000 (approximately 1 day)
         // to prevent the server from being overwhelmed by a single request.
         let limit = std::cmp::min(request.get_ref().limit, 20_000u64);
+        let resolution = match request.get_ref().resolution {
+            0 | 1 => 1,
+            resolution if CANDLESTICK_RESOLUTIONS.contains(&resolution) => resolution,
+            resolution => {
+                return Err(Status::invalid_argument(format!(
+                    "unsupported resolution {resolution}, expected 1 or one of {CANDLESTICK_RESOLUTIONS:?}"
+                )))
+            }
+        };
         let start_height = match request.get_ref().start_height {
             0 => {
-                // If no start height is provided, go `limit` blocks back from now.
+                // If no start height is provided, go `limit` candlesticks back from now.
                 let current_height = state.version();
-                current_height.saturating_sub(limit)
+                current_height.saturating_sub(limit.saturating_mul(resolution))
             }
             start_height => start_height,
         };
@@ -234,7 +260,7 @@ impl QueryService for Server {
             .map_err(|_| Status::invalid_argument("invalid trading_pair"))?;
 
         let candlesticks = state
-            .candlesticks(&pair, start_height, limit as usize)
+            .candlesticks_at_resolution(&pair, resolution, start_height, limit as usize)
             .await
             .map_err(|e| tonic::Status::internal(e.to_string()))?;
 
@@ -293,6 +319,51 @@ impl QueryService for Server {
         ))
     }
 
+    #[instrument(skip(self, request))]
+    async fn time_weighted_average_prices(
+        &self,
+        request: tonic::Request<TimeWeightedAveragePricesRequest>,
+    ) -> Result<tonic::Response<TimeWeightedAveragePricesResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+
+        let pair: DirectedTradingPair = request
+            .get_ref()
+            .pair
+            .clone()
+            .ok_or_else(|| Status::invalid_argument("missing trading_pair"))?
+            .try_into()
+            .map_err(|_| Status::invalid_argument("invalid trading_pair"))?;
+
+        let height = state
+            .get_block_height()
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?;
+        let windows = state
+            .get_dex_params()
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?
+            .price_oracle_windows;
+
+        let mut prices = Vec::with_capacity(windows.len());
+        for window in windows {
+            let price = state
+                .time_weighted_average_price(&pair, window)
+                .await
+                .map_err(|e| tonic::Status::internal(e.to_string()))?;
+            if let Some(price) = price {
+                prices.push(TimeWeightedAveragePrice {
+                    window,
+                    price: price.into(),
+                });
+            }
+        }
+
+        Ok(tonic::Response::new(TimeWeightedAveragePricesResponse {
+            height,
+            prices,
+        }))
+    }
+
     #[instrument(skip(self, request))]
     async fn swap_executions(
         &self,
@@ -584,6 +655,125 @@ impl QueryService for Server {
             .boxed(),
         ))
     }
+
+    #[instrument(skip(self, request))]
+    async fn batch_swap_output_data_stream(
+        &self,
+        request: tonic::Request<BatchSwapOutputDataStreamRequest>,
+    ) -> Result<tonic::Response<Self::BatchSwapOutputDataStreamStream>, Status> {
+        let trading_pairs = request
+            .into_inner()
+            .trading_pairs
+            .into_iter()
+            .map(TradingPair::try_from)
+            .collect::<Result<Vec<_>>>()
+            .map_err(|_| Status::invalid_argument("invalid trading_pair"))?;
+        if trading_pairs.is_empty() {
+            return Err(Status::invalid_argument("missing trading_pairs"));
+        }
+
+        let (tx_execution, rx_execution) =
+            mpsc::channel::<BatchSwapOutputDataStreamResponse>(trading_pairs.len());
+        let storage = self.storage.clone();
+        tokio::spawn(async move {
+            let mut rx_state_snapshot = storage.subscribe();
+            loop {
+                rx_state_snapshot
+                    .changed()
+                    .await
+                    .expect("channel should be open");
+                let snapshot = rx_state_snapshot.borrow().clone();
+                let height = snapshot.get_block_height().await?;
+                for pair in &trading_pairs {
+                    let output_data = snapshot.output_data(height, *pair).await?;
+                    let position_executions = snapshot.block_position_executions(pair).await?;
+                    // Skip the pairs that weren't executed in this block.
+                    if output_data.is_none() && position_executions.is_empty() {
+                        continue;
+                    }
+                    tx_execution
+                        .send(BatchSwapOutputDataStreamResponse {
+                            height,
+                            trading_pair: Some((*pair).into()),
+                            output_data: output_data.map(Into::into),
+                            position_executions: position_executions
+                                .into_iter()
+                                .map(Into::into)
+                                .collect(),
+                        })
+                        .await?;
+                }
+                // Check that the client is still connected, even if none of its
+                // pairs were executed in this block.
+                if tx_execution.is_closed() {
+                    break;
+                }
+            }
+            Ok::<_, anyhow::Error>(())
+        });
+
+        Ok(tonic::Response::new(
+            tokio_stream::wrappers::ReceiverStream::new(rx_execution)
+                .map(Ok)
+                .boxed(),
+        ))
+    }
+
+    #[instrument(skip(self, request))]
+    async fn reward_gauges(
+        &self,
+        request: tonic::Request<RewardGaugesRequest>,
+    ) -> Result<tonic::Response<Self::RewardGaugesStream>, Status> {
+        let state = self.storage.latest_snapshot();
+
+        let pair: TradingPair = request
+            .into_inner()
+            .trading_pair
+            .ok_or_else(|| Status::invalid_argument("missing trading_pair"))?
+            .try_into()
+            .map_err(|_| Status::invalid_argument("invalid trading_pair"))?;
+
+        let gauges = state
+            .reward_gauges(&pair)
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?;
+
+        Ok(tonic::Response::new(
+            futures::stream::iter(gauges)
+                .map(|gauge| {
+                    Ok(RewardGaugesResponse {
+                        gauge: Some(gauge.into()),
+                    })
+                })
+                .boxed(),
+        ))
+    }
+
+    #[instrument(skip(self, request))]
+    async fn position_rewards(
+        &self,
+        request: tonic::Request<PositionRewardsRequest>,
+    ) -> Result<tonic::Response<PositionRewardsResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+
+        let position_id: position::Id = request
+            .into_inner()
+            .position_id
+            .ok_or_else(|| Status::invalid_argument("empty message"))?
+            .try_into()
+            .map_err(|e: anyhow::Error| {
+                tonic::Status::invalid_argument(format!("error converting position_id: {e}"))
+            })?;
+
+        let rewards = state
+            .position_rewards(&position_id)
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?;
+
+        Ok(tonic::Response::new(PositionRewardsResponse {
+            rewards: rewards.into_iter().map(Into::into).collect(),
+        }))
+    }
 }
 
 #[tonic::async_trait]
@@ -684,9 +874,15 @@ impl SimulationService for Server {
             asset_id: input.asset_id,
         };
 
+        let price_impact = swap_execution
+            .price_impact()
+            .map(f64::from)
+            .unwrap_or_default();
+
         let rsp = tonic::Response::new(SimulateTradeResponse {
             unfilled: Some(unfilled.into()),
             output: Some(swap_execution.into()),
+            price_impact,
         });
 
         let duration = start_time.elapsed();
@@ -5,7 +5,7 @@ use async_trait::async_trait;
 use cnidarium::{ArcStateDeltaExt, StateDelta, TempStorage};
 use futures::StreamExt;
 use penumbra_asset::{asset, Value};
-use penumbra_num::Amount;
+use penumbra_num::{fixpoint::U128x128, Amount};
 use rand_core::OsRng;
 
 use crate::component::{SwapDataRead, SwapDataWrite};
@@ -16,10 +16,11 @@ use crate::{
     component::{
         router::FillRoute,
         router::{create_buy, create_sell, HandleBatchSwaps, RoutingParams},
-        Arbitrage, PositionManager, PositionRead, StateReadExt, StateWriteExt,
+        Arbitrage, ExecutionLog, ExecutionLogRead, PositionManager, PositionRead, PriceOracle,
+        PriceOracleRead, StateReadExt, StateWriteExt,
     },
     lp::{position::Position, Reserves},
-    BatchSwapOutputData, DirectedTradingPair, DirectedUnitPair,
+    BatchSwapOutputData, DirectedTradingPair, DirectedUnitPair, TradingPair,
 };
 
 #[async_trait]
@@ -312,6 +313,355 @@ async fn check_close_on_fill() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+/// Fills two positions, and checks that their executions are logged in order,
+/// until the end of the next block replaces them.
+async fn position_executions_are_logged_per_block() -> anyhow::Result<()> {
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let pair = TradingPair::new(gm.id(), gn.id());
+
+    let position_1 = SellOrder::parse_str("100gm@1gn")?.into_position(OsRng);
+    let position_2 = SellOrder::parse_str("100gm@1.1gn")?.into_position(OsRng);
+    let position_1_id = position_1.id();
+    let position_2_id = position_2.id();
+    state_tx.open_position(position_1).await.unwrap();
+    state_tx.open_position(position_2).await.unwrap();
+
+    let input = "150gn".parse::<Value>().unwrap();
+    FillRoute::fill_route(&mut state_tx, input, &[gm.id()], None).await?;
+
+    // Nothing is logged until the end of the block.
+    assert!(state_tx.block_position_executions(&pair).await?.is_empty());
+
+    state_tx.finalize_block_execution_log().await?;
+    let executions = state_tx.block_position_executions(&pair).await?;
+    let executed: Vec<_> = executions.iter().map(|e| e.position_id).collect();
+    assert_eq!(executed, vec![position_1_id, position_2_id]);
+    assert!(executions
+        .iter()
+        .all(|e| e.context == DirectedTradingPair::new(gn.id(), gm.id())));
+
+    // A block without executions clears the log.
+    state_tx.finalize_block_execution_log().await?;
+    assert!(state_tx.block_position_executions(&pair).await?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Opens positions with and without an expiry height, and checks that only the
+/// expiring positions that are still open are closed at their expiry height.
+async fn expired_positions_are_closed() -> anyhow::Result<()> {
+    use penumbra_sct::component::clock::EpochManager as _;
+
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_block_height(5);
+
+    let mut position_1 = SellOrder::parse_str("100gm@1gn")?.into_position(OsRng);
+    position_1.expiry_height = Some(10);
+    let position_2 = SellOrder::parse_str("100gm@1.1gn")?.into_position(OsRng);
+    let mut position_3 = SellOrder::parse_str("100gm@1.2gn")?.into_position(OsRng);
+    position_3.expiry_height = Some(10);
+    let mut position_4 = SellOrder::parse_str("100gm@1.3gn")?.into_position(OsRng);
+    position_4.expiry_height = Some(4);
+
+    let position_1_id = position_1.id();
+    let position_2_id = position_2.id();
+    let position_3_id = position_3.id();
+
+    state_tx.open_position(position_1).await.unwrap();
+    state_tx.open_position(position_2).await.unwrap();
+    state_tx.open_position(position_3).await.unwrap();
+    // Positions can't be opened after their expiry height.
+    assert!(state_tx.open_position(position_4).await.is_err());
+
+    // Position 3 is closed and withdrawn before it expires.
+    state_tx.close_position_by_id(&position_3_id).await?;
+    state_tx.withdraw_position(position_3_id, 0).await?;
+
+    state_tx.close_expired_positions(9).await?;
+    let position_1_state = state_tx.position_by_id(&position_1_id).await?.unwrap();
+    assert_eq!(position_1_state.state, position::State::Opened);
+
+    state_tx.close_expired_positions(10).await?;
+    let position_1_state = state_tx.position_by_id(&position_1_id).await?.unwrap();
+    let position_2_state = state_tx.position_by_id(&position_2_id).await?.unwrap();
+    let position_3_state = state_tx.position_by_id(&position_3_id).await?.unwrap();
+    assert_eq!(position_1_state.state, position::State::Closed);
+    assert_eq!(position_2_state.state, position::State::Opened);
+    assert_eq!(
+        position_3_state.state,
+        position::State::Withdrawn { sequence: 0 }
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Funds a reward gauge, and checks that its rewards are spread over its duration
+/// and accrued to the open positions of its pair in proportion to their reserves.
+async fn reward_gauges_accrue_to_positions() -> anyhow::Result<()> {
+    use crate::component::{GaugeManager, GaugeRead};
+    use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+    use penumbra_sct::component::clock::EpochManager as _;
+
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_block_height(5);
+
+    let position_1 = SellOrder::parse_str("100gm@1gn")?.into_position(OsRng);
+    let position_2 = SellOrder::parse_str("300gm@1gn")?.into_position(OsRng);
+    let pair = position_1.phi.pair;
+    let position_1_id = position_1.id();
+    let position_2_id = position_2.id();
+    state_tx.open_position(position_1).await?;
+    state_tx.open_position(position_2).await?;
+
+    let rewards = |amount: u64| Value {
+        amount: amount.into(),
+        asset_id: *STAKING_TOKEN_ASSET_ID,
+    };
+
+    // The gauge distributes its rewards at heights 5 and 6.
+    state_tx.fund_gauge(pair, rewards(400), 2).await?;
+    assert_eq!(state_tx.reward_gauges(&pair).await?.len(), 1);
+
+    state_tx.distribute_gauge_rewards(5).await?;
+    assert_eq!(
+        state_tx.position_rewards(&position_1_id).await?,
+        vec![rewards(50)]
+    );
+    assert_eq!(
+        state_tx.position_rewards(&position_2_id).await?,
+        vec![rewards(150)]
+    );
+
+    state_tx.distribute_gauge_rewards(6).await?;
+    assert_eq!(
+        state_tx.position_rewards(&position_1_id).await?,
+        vec![rewards(100)]
+    );
+    assert_eq!(
+        state_tx.position_rewards(&position_2_id).await?,
+        vec![rewards(300)]
+    );
+    // The gauge has ended.
+    assert!(state_tx.reward_gauges(&pair).await?.is_empty());
+
+    // Taking the rewards of a position clears them.
+    assert_eq!(
+        state_tx.take_position_rewards(&position_1_id).await?,
+        vec![rewards(100)]
+    );
+    assert!(state_tx.position_rewards(&position_1_id).await?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+/// A gauge whose pair has no liquidity carries its rewards over, and returns them
+/// to the Community Pool once it ends, rather than paying them all to whichever
+/// position is opened on the pair next.
+async fn ended_reward_gauges_are_refunded() -> anyhow::Result<()> {
+    use crate::component::{GaugeManager, GaugeRead};
+    use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+    use penumbra_community_pool::component::StateReadExt as _;
+    use penumbra_sct::component::clock::EpochManager as _;
+
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_block_height(5);
+
+    let position = SellOrder::parse_str("100gm@1gn")?.into_position(OsRng);
+    let pair = position.phi.pair;
+    let rewards = Value {
+        amount: 400u64.into(),
+        asset_id: *STAKING_TOKEN_ASSET_ID,
+    };
+
+    state_tx.fund_gauge(pair, rewards, 2).await?;
+
+    // Without positions, nothing is distributed.
+    state_tx.distribute_gauge_rewards(5).await?;
+    assert_eq!(state_tx.reward_gauges(&pair).await?[0].remaining, rewards);
+
+    // The gauge ends, and its rewards go back to the Community Pool.
+    state_tx.distribute_gauge_rewards(6).await?;
+    assert!(state_tx.reward_gauges(&pair).await?.is_empty());
+    assert_eq!(
+        state_tx
+            .community_pool_asset_balance(*STAKING_TOKEN_ASSET_ID)
+            .await?,
+        rewards.amount
+    );
+
+    // A position opened afterwards collects nothing.
+    let position_id = position.id();
+    state_tx.put_block_height(7);
+    state_tx.open_position(position).await?;
+    state_tx.distribute_gauge_rewards(7).await?;
+    assert!(state_tx.position_rewards(&position_id).await?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+/// The price oracle averages the clearing prices of batch swaps over its windows,
+/// and forgets the prices too old to be part of any of them.
+async fn price_oracle_tracks_clearing_prices() -> anyhow::Result<()> {
+    use penumbra_sct::component::clock::EpochManager as _;
+
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_dex_params(DexParameters {
+        price_oracle_windows: vec![2, 4],
+        ..DexParameters::default()
+    });
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let trading_pair = TradingPair::new(gm.id(), gn.id());
+    let bsod = |height: u64, traded_1: u64, traded_2: u64| BatchSwapOutputData {
+        delta_1: traded_1.into(),
+        delta_2: 0u64.into(),
+        lambda_1: 0u64.into(),
+        lambda_2: traded_2.into(),
+        unfilled_1: 0u64.into(),
+        unfilled_2: 0u64.into(),
+        height,
+        trading_pair,
+        sct_position_prefix: Default::default(),
+    };
+
+    state_tx.record_clearing_price(&bsod(1, 10, 20)).await?;
+    state_tx.record_clearing_price(&bsod(3, 10, 40)).await?;
+    state_tx.record_clearing_price(&bsod(5, 10, 10)).await?;
+    state_tx.record_clearing_price(&bsod(6, 10, 30)).await?;
+    // Batches where nothing was traded don't have a price.
+    state_tx.record_clearing_price(&bsod(6, 0, 0)).await?;
+
+    // The price observed at height 1 is no longer needed, since the longest
+    // window now starts after the one observed at height 3.
+    let observations = state_tx.price_observations(&trading_pair).await?;
+    assert_eq!(
+        observations.iter().map(|(h, _)| *h).collect::<Vec<_>>(),
+        vec![3, 5, 6]
+    );
+
+    state_tx.put_block_height(6);
+    let pair = DirectedTradingPair::new(trading_pair.asset_1(), trading_pair.asset_2());
+    // Blocks 3..=6 at 4, 4, 1, and 3.
+    assert_eq!(
+        state_tx.time_weighted_average_price(&pair, 4).await?,
+        Some(3u64.into())
+    );
+    // Blocks 5..=6 at 1 and 3, in both directions.
+    assert_eq!(
+        state_tx.time_weighted_average_price(&pair, 2).await?,
+        Some(2u64.into())
+    );
+    assert_eq!(
+        state_tx
+            .time_weighted_average_price(&pair.flip(), 2)
+            .await?,
+        Some(U128x128::ratio(1u64, 2u64)?)
+    );
+    // There's no history of the pair for a longer window.
+    assert_eq!(state_tx.time_weighted_average_price(&pair, 5).await?, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Batch swaps clearing too far from the time-weighted average price of their pair
+/// are not executed, and their inputs are returned unfilled.
+async fn price_deviation_circuit_breaker() -> anyhow::Result<()> {
+    use penumbra_sct::component::clock::EpochManager as _;
+
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_dex_params(DexParameters {
+        price_oracle_windows: vec![4],
+        max_price_deviation_bps: 1_000,
+        price_deviation_window: 4,
+        ..DexParameters::default()
+    });
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let trading_pair = TradingPair::new(gm.id(), gn.id());
+    let bsod = |height: u64, traded_1: u64, traded_2: u64| BatchSwapOutputData {
+        delta_1: traded_1.into(),
+        delta_2: 0u64.into(),
+        lambda_1: 0u64.into(),
+        lambda_2: traded_2.into(),
+        unfilled_1: 0u64.into(),
+        unfilled_2: 0u64.into(),
+        height,
+        trading_pair,
+        sct_position_prefix: Default::default(),
+    };
+
+    // The pair cleared at a price of 2 at height 1.
+    state_tx.record_clearing_price(&bsod(1, 10, 20)).await?;
+    state_tx.put_block_height(2);
+    state_tx.put_epoch_by_height(
+        2,
+        penumbra_sct::epoch::Epoch {
+            index: 0,
+            start_height: 0,
+        },
+    );
+
+    // Prices within 10% of the average don't trip the circuit breaker.
+    assert!(!state_tx.clearing_price_deviates(&bsod(2, 10, 21)).await?);
+    assert!(!state_tx.clearing_price_deviates(&bsod(2, 10, 19)).await?);
+    assert!(state_tx.clearing_price_deviates(&bsod(2, 10, 25)).await?);
+    assert!(state_tx.clearing_price_deviates(&bsod(2, 10, 15)).await?);
+
+    // Sell asset 2 for asset 1 at a price of 1, half the average price.
+    let (unit_1, unit_2) = if trading_pair.asset_1() == gm.id() {
+        ("gm", "gn")
+    } else {
+        ("gn", "gm")
+    };
+    let position = SellOrder::parse_str(&format!("100{unit_2}@1{unit_1}"))?.into_position(OsRng);
+    let position_id = position.id();
+    state_tx.open_position(position.clone()).await?;
+    state_tx.apply();
+
+    let mut swap_flow = state.swap_flow(&trading_pair);
+    swap_flow.0 += 10u64.into();
+    Arc::get_mut(&mut state)
+        .unwrap()
+        .accumulate_swap_flow(&trading_pair, swap_flow.clone())
+        .await?;
+
+    let routing_params = state.routing_params().await?;
+    let output_data = state
+        .handle_batch_swaps(trading_pair, swap_flow, 2, routing_params, 64)
+        .await?;
+
+    // The batch wasn't executed against the position.
+    assert_eq!(output_data.lambda_2, Amount::zero());
+    assert_eq!(output_data.unfilled_1, 10u64.into());
+    let position_after = state.position_by_id(&position_id).await?.unwrap();
+    assert_eq!(position_after.reserves, position.reserves);
+
+    Ok(())
+}
+
 #[tokio::test]
 /// Try to execute against multiple positions, mainly testing that the order-book traversal
 /// is done correctly.
@@ -845,6 +1195,7 @@ async fn basic_cycle_arb() -> anyhow::Result<()> {
         max_hops: 4 + 2,
         price_limit: Some(1u64.into()),
         fixed_candidates: Arc::new(vec![penumbra.id(), gm.id(), gn.id()]),
+        ..RoutingParams::from(DexParameters::default())
     };
     state.arbitrage(penumbra.id(), routing_params).await?;
 
@@ -944,6 +1295,7 @@ async fn reproduce_arbitrage_loop_testnet_53() -> anyhow::Result<()> {
         max_hops: 4 + 2,
         price_limit: Some(1u64.into()),
         fixed_candidates: Arc::new(vec![penumbra.id(), test_usd.id()]),
+        ..RoutingParams::from(DexParameters::default())
     };
 
     let arb_profit = tokio::time::timeout(
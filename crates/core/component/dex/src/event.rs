@@ -5,7 +5,7 @@ use crate::{
     BatchSwapOutputData, CandlestickData, DirectedTradingPair, SwapExecution, TradingPair,
 };
 use anyhow::{anyhow, Context};
-use penumbra_asset::asset;
+use penumbra_asset::{asset, Value};
 use penumbra_num::Amount;
 use penumbra_proto::{penumbra::core::component::dex::v1 as pb, DomainType};
 use penumbra_sct::Nullifier;
@@ -360,6 +360,48 @@ impl DomainType for EventPositionWithdraw {
     type Proto = pb::EventPositionWithdraw;
 }
 
+#[derive(Clone, Debug)]
+pub struct EventGaugeFund {
+    pub trading_pair: TradingPair,
+    pub rewards: Value,
+    pub end_height: u64,
+}
+
+impl TryFrom<pb::EventGaugeFund> for EventGaugeFund {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::EventGaugeFund) -> Result<Self, Self::Error> {
+        fn inner(value: pb::EventGaugeFund) -> anyhow::Result<EventGaugeFund> {
+            Ok(EventGaugeFund {
+                trading_pair: value
+                    .trading_pair
+                    .ok_or(anyhow!("missing `trading_pair`"))?
+                    .try_into()?,
+                rewards: value
+                    .rewards
+                    .ok_or(anyhow!("missing `rewards`"))?
+                    .try_into()?,
+                end_height: value.end_height,
+            })
+        }
+        inner(value).context(format!("parsing {}", pb::EventGaugeFund::NAME))
+    }
+}
+
+impl From<EventGaugeFund> for pb::EventGaugeFund {
+    fn from(value: EventGaugeFund) -> Self {
+        Self {
+            trading_pair: Some(value.trading_pair.into()),
+            rewards: Some(value.rewards.into()),
+            end_height: value.end_height,
+        }
+    }
+}
+
+impl DomainType for EventGaugeFund {
+    type Proto = pb::EventGaugeFund;
+}
+
 #[derive(Clone, Debug)]
 pub struct EventPositionExecution {
     pub position_id: position::Id,
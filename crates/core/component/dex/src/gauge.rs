@@ -0,0 +1,129 @@
+use anyhow::Context;
+use penumbra_asset::{Balance, Value};
+use penumbra_num::Amount;
+use penumbra_proto::{penumbra::core::component::dex::v1 as pb, DomainType};
+use penumbra_txhash::{EffectHash, EffectingData};
+use serde::{Deserialize, Serialize};
+
+use crate::TradingPair;
+
+/// A transaction action that funds a reward gauge of a trading pair.
+///
+/// The rewards are distributed over the next `duration` blocks to the open
+/// positions of the trading pair, in proportion to their reserves, and are
+/// withdrawn together with the reserves of each position.
+///
+/// Gauges are funded by governance: this action is only valid in the
+/// transactions of Community Pool spend proposals.
+///
+/// This action's contribution to the transaction's value balance is to consume
+/// the rewards.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "pb::GaugeFund", into = "pb::GaugeFund")]
+pub struct GaugeFund {
+    pub trading_pair: TradingPair,
+    pub rewards: Value,
+    pub duration: u64,
+}
+
+impl EffectingData for GaugeFund {
+    fn effect_hash(&self) -> EffectHash {
+        EffectHash::from_proto_effecting_data(&self.to_proto())
+    }
+}
+
+impl GaugeFund {
+    /// Compute the value this action contributes to its transaction.
+    pub fn balance(&self) -> Balance {
+        // Funding a gauge requires the rewards.
+        -Balance::from(self.rewards)
+    }
+}
+
+/// The rewards of a trading pair still to be distributed to its liquidity
+/// providers, in a single asset.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::RewardGauge", into = "pb::RewardGauge")]
+pub struct RewardGauge {
+    pub trading_pair: TradingPair,
+    pub remaining: Value,
+    /// The last height at which rewards are distributed.
+    pub end_height: u64,
+}
+
+impl RewardGauge {
+    /// The rewards the gauge distributes at `height`.
+    ///
+    /// The remaining rewards are spread evenly over the blocks left until the
+    /// end of the gauge, so that the last block distributes whatever remains.
+    pub fn emission_at(&self, height: u64) -> Amount {
+        let blocks_left = self.end_height.saturating_sub(height).saturating_add(1);
+        self.remaining.amount / Amount::from(blocks_left)
+    }
+}
+
+impl DomainType for GaugeFund {
+    type Proto = pb::GaugeFund;
+}
+
+impl From<GaugeFund> for pb::GaugeFund {
+    fn from(value: GaugeFund) -> Self {
+        Self {
+            trading_pair: Some(value.trading_pair.into()),
+            rewards: Some(value.rewards.into()),
+            duration: value.duration,
+        }
+    }
+}
+
+impl TryFrom<pb::GaugeFund> for GaugeFund {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::GaugeFund) -> Result<Self, Self::Error> {
+        Ok(Self {
+            trading_pair: value
+                .trading_pair
+                .ok_or_else(|| anyhow::anyhow!("missing trading_pair"))?
+                .try_into()?,
+            rewards: value
+                .rewards
+                .ok_or_else(|| anyhow::anyhow!("missing rewards"))?
+                .try_into()
+                .context("malformed rewards")?,
+            duration: value.duration,
+        })
+    }
+}
+
+impl DomainType for RewardGauge {
+    type Proto = pb::RewardGauge;
+}
+
+impl From<RewardGauge> for pb::RewardGauge {
+    fn from(value: RewardGauge) -> Self {
+        Self {
+            trading_pair: Some(value.trading_pair.into()),
+            remaining: Some(value.remaining.into()),
+            end_height: value.end_height,
+        }
+    }
+}
+
+impl TryFrom<pb::RewardGauge> for RewardGauge {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::RewardGauge) -> Result<Self, Self::Error> {
+        Ok(Self {
+            trading_pair: value
+                .trading_pair
+                .ok_or_else(|| anyhow::anyhow!("missing trading_pair"))?
+                .try_into()?,
+            remaining: value
+                .remaining
+                .ok_or_else(|| anyhow::anyhow!("missing remaining"))?
+                .try_into()
+                .context("malformed remaining rewards")?,
+            end_height: value.end_height,
+        })
+    }
+}
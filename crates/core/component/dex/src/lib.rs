@@ -19,10 +19,12 @@ pub use params::DexParameters;
 pub use swap_execution::SwapExecution;
 pub use trading_pair::{DirectedTradingPair, DirectedUnitPair, TradingPair, TradingPairVar};
 
+pub mod gauge;
 pub mod lp;
 pub mod swap;
 pub mod swap_claim;
 
+pub use gauge::{GaugeFund, RewardGauge};
 pub use lp::action::{PositionClose, PositionOpen, PositionWithdraw};
 pub use swap::Swap;
 pub use swap_claim::SwapClaim;
@@ -44,10 +44,13 @@ impl PositionWithdrawPlan {
     }
 
     pub fn balance(&self) -> Balance {
-        // PositionWithdraw outputs will correspond to the final reserves
-        // and a PositionWithdraw token.
+        // PositionWithdraw outputs will correspond to the final reserves,
+        // the accrued rewards and a PositionWithdraw token.
         // Spends will be the PositionClose token.
         let mut balance = self.reserves.balance(&self.pair);
+        for reward in &self.rewards {
+            balance += *reward;
+        }
 
         // We consume a token of self.sequence-1 and produce one of self.sequence.
         // We treat -1 as "closed", the previous state.
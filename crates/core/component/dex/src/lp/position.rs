@@ -35,6 +35,9 @@ pub struct Position {
     /// Set to `true` if a position is a limit-order, meaning that it will be closed after being
     /// filled against.
     pub close_on_fill: bool,
+    /// If set, the position is closed at the end of the block at this height,
+    /// after batch execution, making its reserves withdrawable.
+    pub expiry_height: Option<u64>,
 }
 
 impl std::fmt::Debug for Position {
@@ -96,6 +99,7 @@ impl Position {
             state: State::Opened,
             reserves,
             close_on_fill: false,
+            expiry_height: None,
         }
     }
 
@@ -138,6 +142,7 @@ impl Position {
             state: State::Opened,
             reserves,
             close_on_fill: false,
+            expiry_height: None,
         }
     }
 
@@ -398,6 +403,7 @@ impl From<Position> for pb::Position {
             phi: Some(p.phi.into()),
             nonce: p.nonce.to_vec(),
             close_on_fill: p.close_on_fill,
+            expiry_height: p.expiry_height.unwrap_or(0),
         }
     }
 }
@@ -424,6 +430,7 @@ impl TryFrom<pb::Position> for Position {
                 .try_into()
                 .context("expected 32-byte nonce")?,
             close_on_fill: p.close_on_fill,
+            expiry_height: (p.expiry_height != 0).then_some(p.expiry_height),
         })
     }
 }
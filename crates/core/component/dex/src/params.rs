@@ -12,6 +12,29 @@ pub struct DexParameters {
     pub max_hops: u32,
     pub max_positions_per_pair: u32,
     pub max_execution_budget: u32,
+    /// The windows, in blocks, over which the price oracle tracks time-weighted
+    /// average prices. If empty, the price oracle is disabled.
+    pub price_oracle_windows: Vec<u64>,
+    /// The maximum number of liquidity-based candidates routing considers as
+    /// the next hop from each asset, in addition to the fixed candidates.
+    pub max_dynamic_candidates: u32,
+    /// How far past the spill price, in basis points, a route is filled.
+    pub spill_price_margin_bps: u32,
+    /// The maximum deviation, in basis points, of a batch swap's clearing price
+    /// from its pair's time-weighted average price. If zero, the price deviation
+    /// circuit breaker is disabled.
+    pub max_price_deviation_bps: u32,
+    /// The window, in blocks, of the time-weighted average price that clearing
+    /// prices are compared to.
+    pub price_deviation_window: u64,
+    /// The maximum number of reward gauges a trading pair can have at once.
+    pub max_reward_gauges_per_pair: u32,
+}
+
+impl DexParameters {
+    /// The maximum number of reward gauges per pair for chains whose parameters
+    /// predate it being configurable.
+    pub const DEFAULT_MAX_REWARD_GAUGES_PER_PAIR: u32 = 4;
 }
 
 impl DomainType for DexParameters {
@@ -32,6 +55,16 @@ impl TryFrom<pb::DexParameters> for DexParameters {
             max_hops: msg.max_hops,
             max_positions_per_pair: msg.max_positions_per_pair,
             max_execution_budget: msg.max_execution_budget,
+            price_oracle_windows: msg.price_oracle_windows,
+            max_dynamic_candidates: msg.max_dynamic_candidates,
+            spill_price_margin_bps: msg.spill_price_margin_bps,
+            max_price_deviation_bps: msg.max_price_deviation_bps,
+            price_deviation_window: msg.price_deviation_window,
+            // Parameters written before this field existed decode it as zero.
+            max_reward_gauges_per_pair: match msg.max_reward_gauges_per_pair {
+                0 => Self::DEFAULT_MAX_REWARD_GAUGES_PER_PAIR,
+                max => max,
+            },
         })
     }
 }
@@ -48,6 +81,12 @@ impl From<DexParameters> for pb::DexParameters {
             max_hops: params.max_hops,
             max_positions_per_pair: params.max_positions_per_pair,
             max_execution_budget: params.max_execution_budget,
+            price_oracle_windows: params.price_oracle_windows,
+            max_dynamic_candidates: params.max_dynamic_candidates,
+            spill_price_margin_bps: params.spill_price_margin_bps,
+            max_price_deviation_bps: params.max_price_deviation_bps,
+            price_deviation_window: params.price_deviation_window,
+            max_reward_gauges_per_pair: params.max_reward_gauges_per_pair,
         }
     }
 }
@@ -72,6 +111,12 @@ impl Default for DexParameters {
             max_hops: 4,
             max_positions_per_pair: 1_000,
             max_execution_budget: 64,
+            price_oracle_windows: vec![10, 100, 1_000],
+            max_dynamic_candidates: 10,
+            spill_price_margin_bps: 0,
+            max_price_deviation_bps: 0,
+            price_deviation_window: 10,
+            max_reward_gauges_per_pair: Self::DEFAULT_MAX_REWARD_GAUGES_PER_PAIR,
         }
     }
 }
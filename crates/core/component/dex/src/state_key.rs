@@ -44,6 +44,26 @@ pub mod candlesticks {
         }
     }
 
+    pub mod aggregate {
+        use crate::DirectedTradingPair;
+
+        /// The prefix of the candlesticks of `pair` covering `resolution` blocks each.
+        pub fn by_pair_and_resolution(pair: &DirectedTradingPair, resolution: u64) -> String {
+            format!(
+                "dex/candlesticks/aggregate/{resolution}/{}/{}/",
+                &pair.start, &pair.end
+            )
+        }
+
+        pub fn by_pair_resolution_and_height(
+            pair: &DirectedTradingPair,
+            resolution: u64,
+            height: u64,
+        ) -> String {
+            format!("{}{height:020}", by_pair_and_resolution(pair, resolution))
+        }
+    }
+
     pub mod data {
         use crate::DirectedTradingPair;
 
@@ -101,6 +121,83 @@ pub fn swap_flows() -> &'static str {
     "dex/swap_flows"
 }
 
+pub mod expiring_positions {
+    /// The prefix of the positions expiring at the end of the block at `height`.
+    pub fn by_height(height: u64) -> String {
+        format!("dex/expiring_positions/{height:020}/")
+    }
+
+    pub fn by_height_and_id(height: u64, id: &crate::lp::position::Id) -> String {
+        format!("{}{id}", by_height(height))
+    }
+}
+
+pub mod block_position_executions {
+    use crate::TradingPair;
+
+    pub fn prefix() -> &'static str {
+        "dex/block_position_executions/"
+    }
+
+    /// The prefix of the executions of positions on `pair` during the latest block.
+    pub fn by_pair(pair: &TradingPair) -> String {
+        format!("{}{pair}/", prefix())
+    }
+
+    pub fn by_pair_and_index(pair: &TradingPair, index: usize) -> String {
+        format!("{}{index:010}", by_pair(pair))
+    }
+
+    pub mod object {
+        pub fn pending() -> &'static str {
+            "dex/block_position_executions/object/pending"
+        }
+    }
+}
+
+pub mod price_oracle {
+    use crate::TradingPair;
+
+    /// The prefix of the clearing prices observed for `pair`, by height.
+    pub fn by_pair(pair: &TradingPair) -> String {
+        format!("dex/price_oracle/{pair}/")
+    }
+
+    pub fn by_pair_and_height(pair: &TradingPair, height: u64) -> String {
+        format!("{}{height:020}", by_pair(pair))
+    }
+}
+
+pub mod reward_gauges {
+    use super::*;
+
+    pub fn prefix() -> &'static str {
+        "dex/reward_gauges/"
+    }
+
+    /// The prefix of the reward gauges of `pair`, one per reward asset.
+    pub fn by_pair(pair: &TradingPair) -> String {
+        format!("{}{pair}/", prefix())
+    }
+
+    pub fn by_pair_and_asset(pair: &TradingPair, asset_id: &asset::Id) -> String {
+        format!("{}{asset_id}", by_pair(pair))
+    }
+}
+
+pub mod position_rewards {
+    use super::*;
+
+    /// The prefix of the rewards accrued by the position `id`, one per reward asset.
+    pub fn by_position(id: &position::Id) -> String {
+        format!("dex/position_rewards/{id}/")
+    }
+
+    pub fn by_position_and_asset(id: &position::Id, asset_id: &asset::Id) -> String {
+        format!("{}{asset_id}", by_position(id))
+    }
+}
+
 pub fn pending_position_closures() -> &'static str {
     "dex/pending_position_closures"
 }
@@ -1,3 +1,5 @@
+use std::collections::BTreeSet;
+
 use anyhow::{Context, Result};
 use ark_ff::ToConstraintField;
 use ark_groth16::{
@@ -29,7 +31,10 @@ use crate::{
     TradingPair,
 };
 
-use penumbra_proof_params::{DummyWitness, GROTH16_PROOF_LENGTH_BYTES};
+use penumbra_proof_params::{
+    batch::{self, BatchVerifier},
+    DummyWitness, GROTH16_PROOF_LENGTH_BYTES,
+};
 
 /// The public inputs to a [`SwapProof`].
 #[derive(Clone, Debug)]
@@ -229,9 +234,47 @@ impl SwapProof {
         vk: &PreparedVerifyingKey<Bls12_377>,
         public: SwapProofPublic,
     ) -> anyhow::Result<()> {
+        let public_inputs = Self::public_inputs(public)?;
         let proof =
             Proof::deserialize_compressed_unchecked(&self.0[..]).map_err(|e| anyhow::anyhow!(e))?;
 
+        let start = std::time::Instant::now();
+        let proof_result = Groth16::<Bls12_377, LibsnarkReduction>::verify_with_processed_vk(
+            vk,
+            public_inputs.as_slice(),
+            &proof,
+        )
+        .map_err(|err| anyhow::anyhow!(err))?;
+        tracing::debug!(?proof_result, elapsed = ?start.elapsed());
+        proof_result
+            .then_some(())
+            .ok_or_else(|| anyhow::anyhow!("a swap proof did not verify"))
+    }
+
+    /// Verifies the proof like [`Self::verify`], unless it was recorded in
+    /// `verified` by a batch of swap proofs.
+    pub fn verify_unless_batched(
+        &self,
+        vk: &PreparedVerifyingKey<Bls12_377>,
+        public: SwapProofPublic,
+        verified: &BTreeSet<[u8; 32]>,
+    ) -> anyhow::Result<()> {
+        let public_inputs = Self::public_inputs(public.clone())?;
+        if batch::is_verified(verified, vk, &self.0, &public_inputs) {
+            tracing::debug!("proof was verified in a batch");
+            return Ok(());
+        }
+        self.verify(vk, public)
+    }
+
+    /// Adds this proof to a batch of swap proofs to verify at once.
+    pub fn queue(&self, batch: &mut BatchVerifier, public: SwapProofPublic) -> anyhow::Result<()> {
+        batch
+            .queue(self.0, Self::public_inputs(public)?)
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+
+    fn public_inputs(public: SwapProofPublic) -> anyhow::Result<Vec<Fq>> {
         let mut public_inputs = Vec::new();
         public_inputs.extend(
             public
@@ -256,17 +299,7 @@ impl SwapProof {
         );
 
         tracing::trace!(?public_inputs);
-        let start = std::time::Instant::now();
-        let proof_result = Groth16::<Bls12_377, LibsnarkReduction>::verify_with_processed_vk(
-            vk,
-            public_inputs.as_slice(),
-            &proof,
-        )
-        .map_err(|err| anyhow::anyhow!(err))?;
-        tracing::debug!(?proof_result, elapsed = ?start.elapsed());
-        proof_result
-            .then_some(())
-            .ok_or_else(|| anyhow::anyhow!("a swap proof did not verify"))
+        Ok(public_inputs)
     }
 }
 
@@ -27,6 +27,30 @@ impl SwapExecution {
         let price = U128x128::ratio(input.amount, output.amount).ok()?;
         Some(price)
     }
+
+    /// Returns the price of the earliest execution trace.
+    pub fn min_price(&self) -> Option<U128x128> {
+        let Some((input, output)) = self.traces.first().and_then(|trace| {
+            let input = trace.first()?;
+            let output = trace.last()?;
+            Some((input, output))
+        }) else {
+            return None;
+        };
+
+        let price = U128x128::ratio(input.amount, output.amount).ok()?;
+        Some(price)
+    }
+
+    /// Returns the fraction of the output lost to price impact, relative to
+    /// filling the entire input at the price of the earliest execution trace.
+    pub fn price_impact(&self) -> Option<U128x128> {
+        let best_price = self.min_price()?;
+        let effective_price = U128x128::ratio(self.input.amount, self.output.amount).ok()?;
+        let ratio = best_price.checked_div(&effective_price).ok()?;
+        // Rounding can make the effective price slightly better than the best one.
+        Some(U128x128::from(1u64).checked_sub(&ratio).unwrap_or_default())
+    }
 }
 
 impl DomainType for SwapExecution {
@@ -7,7 +7,7 @@ use std::sync::Arc;
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
-use cnidarium::StateWrite;
+use cnidarium::{StateRead, StateWrite};
 use cnidarium_component::Component;
 use penumbra_num::Amount;
 use tendermint::v0_37::abci;
@@ -31,6 +31,13 @@ impl Component for Distributions {
         };
     }
 
+    #[instrument(name = "distributions", skip(state))]
+    async fn export_genesis<S: StateRead>(state: &S) -> Result<Self::AppState> {
+        Ok(genesis::Content {
+            distributions_params: state.get_distributions_params().await?,
+        })
+    }
+
     #[instrument(name = "distributions", skip(_state, _begin_block))]
     async fn begin_block<S: StateWrite + 'static>(
         _state: &mut Arc<S>,
@@ -6,7 +6,7 @@ use std::sync::Arc;
 
 use crate::{event::EventBlockFees, genesis, Fee};
 use async_trait::async_trait;
-use cnidarium::StateWrite;
+use cnidarium::{StateRead, StateWrite};
 use cnidarium_component::Component;
 use penumbra_proto::state::StateWriteProto as _;
 use penumbra_proto::DomainType as _;
@@ -33,6 +33,13 @@ impl Component for FeeComponent {
         }
     }
 
+    #[instrument(name = "fee", skip(state))]
+    async fn export_genesis<S: StateRead>(state: &S) -> anyhow::Result<Self::AppState> {
+        Ok(genesis::Content {
+            fee_params: state.get_fee_params().await?,
+        })
+    }
+
     #[instrument(name = "fee", skip(_state, _begin_block))]
     async fn begin_block<S: StateWrite + 'static>(
         _state: &mut Arc<S>,
@@ -55,6 +62,7 @@ impl Component for FeeComponent {
             .unwrap_or_default();
 
         let swapped_total = swapped_base + swapped_tip;
+        tracing::debug!(gas_used = ?state_ref.block_gas_used(), "gas used in block");
 
         state_ref.record_proto(
             EventBlockFees {
@@ -69,6 +69,7 @@ pub trait FeePay: StateWrite {
         // Finally, queue the paid fee for processing at the end of the block.
         self.raw_accumulate_base_fee(base_fee);
         self.raw_accumulate_tip(tip);
+        self.raw_accumulate_gas_used(gas_used);
 
         Ok(())
     }
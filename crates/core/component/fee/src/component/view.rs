@@ -5,7 +5,7 @@ use penumbra_asset::asset;
 use penumbra_num::Amount;
 use penumbra_proto::{StateReadProto, StateWriteProto};
 
-use crate::{params::FeeParameters, state_key, Fee, GasPrices};
+use crate::{params::FeeParameters, state_key, Fee, Gas, GasPrices};
 
 /// This trait provides read access to fee-related parts of the Penumbra
 /// state store.
@@ -49,6 +49,11 @@ pub trait StateReadExt: StateRead {
         self.object_get(state_key::fee_accumulator())
             .unwrap_or_default()
     }
+
+    /// The gas used by the transactions of this block so far.
+    fn block_gas_used(&self) -> Gas {
+        self.object(state_key::block_gas_used()).unwrap_or_default()
+    }
 }
 
 impl<T: StateRead + ?Sized> StateReadExt for T {}
@@ -94,6 +99,10 @@ pub trait StateWriteExt: StateWrite {
         self.object_put(state_key::fee_accumulator(), new);
     }
 
+    fn raw_accumulate_gas_used(&mut self, gas_used: Gas) {
+        self.object_update(state_key::block_gas_used(), |total| *total += gas_used);
+    }
+
     fn raw_accumulate_tip(&mut self, tip_fee: Fee) {
         let old = self.accumulated_base_fees_and_tips();
         let new = old.alter(
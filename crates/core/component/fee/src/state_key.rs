@@ -13,3 +13,8 @@ pub fn gas_prices_changed() -> &'static str {
 pub fn fee_accumulator() -> &'static str {
     "fee/accumulator"
 }
+
+#[cfg(feature = "component")]
+pub fn block_gas_used() -> cnidarium::ObjectKey<crate::Gas> {
+    cnidarium::ObjectKey::new("fee/block_gas_used")
+}
@@ -14,7 +14,7 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use async_trait::async_trait;
-use cnidarium::StateWrite;
+use cnidarium::{StateRead, StateWrite};
 use cnidarium_component::Component;
 use tendermint::v0_37::abci;
 use tracing::instrument;
@@ -37,6 +37,13 @@ impl Component for Funding {
         };
     }
 
+    #[instrument(name = "funding", skip(state))]
+    async fn export_genesis<S: StateRead>(state: &S) -> Result<Self::AppState> {
+        Ok(genesis::Content {
+            funding_params: state.get_funding_params().await?,
+        })
+    }
+
     #[instrument(name = "funding", skip(_state, _begin_block))]
     async fn begin_block<S: StateWrite + 'static>(
         _state: &mut Arc<S>,
@@ -59,7 +66,8 @@ impl Component for Funding {
         use penumbra_community_pool::StateWriteExt as _;
         use penumbra_distributions::component::StateReadExt as _;
         use penumbra_sct::CommitmentSource;
-        use penumbra_shielded_pool::component::NoteManager;
+        use penumbra_shielded_pool::component::{NoteManager, SupplyManager as _};
+        use penumbra_shielded_pool::supply::SupplyChange;
         use penumbra_stake::funding_stream::Recipient;
         use penumbra_stake::StateReadExt as _;
 
@@ -133,6 +141,13 @@ impl Component for Funding {
                                 },
                             )
                             .await?;
+                        state.record_supply_change(
+                            Value {
+                                amount: reward_amount_for_stream.into(),
+                                asset_id: *STAKING_TOKEN_ASSET_ID,
+                            },
+                            SupplyChange::StakingReward,
+                        );
                     }
                     // If the recipient is the Community Pool, deposit the funds into the Community Pool
                     Recipient::CommunityPool => {
@@ -146,12 +161,12 @@ impl Component for Funding {
                             .to_proto(),
                         );
 
-                        state
-                            .community_pool_deposit(Value {
-                                amount: reward_amount_for_stream.into(),
-                                asset_id: *STAKING_TOKEN_ASSET_ID,
-                            })
-                            .await;
+                        let reward = Value {
+                            amount: reward_amount_for_stream.into(),
+                            asset_id: *STAKING_TOKEN_ASSET_ID,
+                        };
+                        state.community_pool_deposit(reward).await;
+                        state.record_supply_change(reward, SupplyChange::StakingReward);
                     }
                 }
             }
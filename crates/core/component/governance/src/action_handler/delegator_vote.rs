@@ -3,6 +3,7 @@ use ark_ff::Zero;
 use async_trait::async_trait;
 use cnidarium::StateWrite;
 use decaf377::Fr;
+use penumbra_keys::signature_batch;
 use penumbra_proof_params::DELEGATOR_VOTE_PROOF_VERIFICATION_KEY;
 use penumbra_proto::StateWriteProto as _;
 use penumbra_txhash::TransactionContext;
@@ -35,8 +36,13 @@ impl ActionHandler for DelegatorVote {
         } = self;
 
         // 1. Check spend auth signature using provided spend auth key.
-        rk.verify(context.effect_hash.as_ref(), auth_sig)
-            .context("delegator vote auth signature failed to verify")?;
+        signature_batch::verify(
+            rk,
+            context.effect_hash.as_ref(),
+            auth_sig,
+            &context.verification_cache.signatures,
+        )
+        .context("delegator vote auth signature failed to verify")?;
 
         // 2. Verify the proof against the provided anchor and start position:
         let public = DelegatorVoteProofPublic {
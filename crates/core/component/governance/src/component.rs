@@ -3,7 +3,7 @@ use std::sync::Arc;
 use crate::{event, genesis};
 use anyhow::{Context, Result};
 use async_trait::async_trait;
-use cnidarium::StateWrite;
+use cnidarium::{StateRead, StateWrite};
 use penumbra_proto::StateWriteProto as _;
 use tendermint::v0_37::abci;
 use tracing::instrument;
@@ -45,6 +45,13 @@ impl Component for Governance {
         }
     }
 
+    #[instrument(name = "governance", skip(state))]
+    async fn export_genesis<S: StateRead>(state: &S) -> Result<Self::AppState> {
+        Ok(genesis::Content {
+            governance_params: state.get_governance_params().await?,
+        })
+    }
+
     #[instrument(name = "governance", skip(_state, _begin_block))]
     async fn begin_block<S: StateWrite + 'static>(
         _state: &mut Arc<S>,
@@ -8,6 +8,7 @@ use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
 use futures::StreamExt;
 use ibc_types::core::client::ClientId;
+use ibc_types::core::connection::ConnectionId;
 use penumbra_asset::{asset, Value, STAKING_TOKEN_DENOM};
 use penumbra_ibc::component::ClientStateReadExt as _;
 use penumbra_ibc::component::ClientStateWriteExt as _;
@@ -17,7 +18,7 @@ use penumbra_sct::{
     component::{clock::EpochRead, tree::SctRead},
     Nullifier,
 };
-use penumbra_shielded_pool::component::AssetRegistryRead;
+use penumbra_shielded_pool::component::{AssetRegistry as _, AssetRegistryRead};
 use penumbra_stake::{
     component::{validator_handler::ValidatorDataRead, ConsensusIndexRead},
     DelegationToken, GovernanceKey, IdentityKey,
@@ -933,6 +934,38 @@ pub trait StateWriteExt: StateWrite + penumbra_ibc::component::ConnectionStateWr
                 let unfrozen_client = client_state.unfrozen();
                 self.put_client(client_id, unfrozen_client);
             }
+            ProposalPayload::RecoverIbcClient {
+                subject_client_id,
+                substitute_client_id,
+            } => {
+                let subject_client_id = &ClientId::from_str(subject_client_id)
+                    .map_err(|e| tonic::Status::aborted(format!("invalid client id: {e}")))?;
+                let substitute_client_id = &ClientId::from_str(substitute_client_id)
+                    .map_err(|e| tonic::Status::aborted(format!("invalid client id: {e}")))?;
+                let current_block_time = self.get_current_block_timestamp().await?;
+
+                // the clients may have changed since the proposal was submitted, in which case
+                // the recovery fails without affecting either client.
+                if let Err(error) = self
+                    .recover_client(subject_client_id, substitute_client_id, current_block_time)
+                    .await
+                {
+                    return Ok(Err(error));
+                }
+            }
+            ProposalPayload::SetIbcConnectionMetadata {
+                connection_id,
+                metadata,
+            } => {
+                let connection_id = &ConnectionId::from_str(connection_id)
+                    .map_err(|e| tonic::Status::aborted(format!("invalid connection id: {e}")))?;
+                self.put_connection_metadata(connection_id, metadata.clone());
+            }
+            ProposalPayload::RegisterAssetMetadata { metadata } => {
+                for metadata in metadata {
+                    self.update_denom_metadata(metadata).await;
+                }
+            }
         }
         Ok(Ok(()))
     }
@@ -1,6 +1,9 @@
 use anyhow::Context;
 use bytes::Bytes;
 use ibc_types::core::client::ClientId;
+use ibc_types::core::connection::ConnectionId;
+use penumbra_asset::asset::Metadata;
+use penumbra_ibc::connection_metadata::ConnectionMetadata;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
@@ -73,6 +76,27 @@ impl From<Proposal> for pb::Proposal {
                     client_id: client_id.into(),
                 },
             )),
+            ProposalPayload::RecoverIbcClient {
+                subject_client_id,
+                substitute_client_id,
+            } => Some(Payload::RecoverIbcClient(pb::proposal::RecoverIbcClient {
+                subject_client_id,
+                substitute_client_id,
+            })),
+            ProposalPayload::SetIbcConnectionMetadata {
+                connection_id,
+                metadata,
+            } => Some(Payload::SetIbcConnectionMetadata(
+                pb::proposal::SetIbcConnectionMetadata {
+                    connection_id,
+                    metadata: metadata.map(Into::into),
+                },
+            )),
+            ProposalPayload::RegisterAssetMetadata { metadata } => Some(
+                Payload::RegisterAssetMetadata(pb::proposal::RegisterAssetMetadata {
+                    metadata: metadata.into_iter().map(Into::into).collect(),
+                }),
+            ),
         };
         proposal.payload = payload;
         proposal
@@ -164,6 +188,53 @@ impl TryFrom<pb::Proposal> for Proposal {
                         client_id: unfreeze_ibc_client.client_id,
                     }
                 }
+                Payload::RecoverIbcClient(recover_ibc_client) => {
+                    for client_id in [
+                        &recover_ibc_client.subject_client_id,
+                        &recover_ibc_client.substitute_client_id,
+                    ] {
+                        // Validation: client ID has a max length of 128 bytes
+                        if client_id.len() > 128 {
+                            anyhow::bail!("client ID must be less than 128 bytes");
+                        }
+                        // Validation: Check the client ID is valid using the validation inside `ClientId::from_str`.
+                        ClientId::from_str(client_id)
+                            .map_err(|e| anyhow::anyhow!("invalid client id: {e}"))?;
+                    }
+                    ProposalPayload::RecoverIbcClient {
+                        subject_client_id: recover_ibc_client.subject_client_id,
+                        substitute_client_id: recover_ibc_client.substitute_client_id,
+                    }
+                }
+                Payload::SetIbcConnectionMetadata(set_ibc_connection_metadata) => {
+                    // Validation: connection ID has a max length of 128 bytes
+                    if set_ibc_connection_metadata.connection_id.len() > 128 {
+                        anyhow::bail!("connection ID must be less than 128 bytes");
+                    }
+                    // Validation: Check the connection ID is valid using the validation inside `ConnectionId::from_str`.
+                    ConnectionId::from_str(&set_ibc_connection_metadata.connection_id)
+                        .map_err(|e| anyhow::anyhow!("invalid connection id: {e}"))?;
+                    ProposalPayload::SetIbcConnectionMetadata {
+                        connection_id: set_ibc_connection_metadata.connection_id,
+                        metadata: set_ibc_connection_metadata
+                            .metadata
+                            .map(TryInto::try_into)
+                            .transpose()?,
+                    }
+                }
+                Payload::RegisterAssetMetadata(register_asset_metadata) => {
+                    // Validation: there's some metadata to register.
+                    if register_asset_metadata.metadata.is_empty() {
+                        anyhow::bail!("asset metadata proposals must register some metadata");
+                    }
+                    ProposalPayload::RegisterAssetMetadata {
+                        metadata: register_asset_metadata
+                            .metadata
+                            .into_iter()
+                            .map(TryInto::try_into)
+                            .collect::<Result<_, _>>()?,
+                    }
+                }
             },
         })
     }
@@ -225,6 +296,12 @@ pub enum ProposalKind {
     FreezeIbcClient,
     /// A proposal to unfreeze an IBC client.
     UnfreezeIbcClient,
+    /// A proposal to recover an IBC client from a substitute client.
+    RecoverIbcClient,
+    /// A proposal to register information about the counterparty of an IBC connection.
+    SetIbcConnectionMetadata,
+    /// A proposal to register the metadata of assets.
+    RegisterAssetMetadata,
 }
 
 impl From<ProposalKind> for pb::ProposalKind {
@@ -237,6 +314,9 @@ impl From<ProposalKind> for pb::ProposalKind {
             ProposalKind::UpgradePlan => pb::ProposalKind::UpgradePlan,
             ProposalKind::FreezeIbcClient => pb::ProposalKind::FreezeIbcClient,
             ProposalKind::UnfreezeIbcClient => pb::ProposalKind::UnfreezeIbcClient,
+            ProposalKind::RecoverIbcClient => pb::ProposalKind::RecoverIbcClient,
+            ProposalKind::SetIbcConnectionMetadata => pb::ProposalKind::SetIbcConnectionMetadata,
+            ProposalKind::RegisterAssetMetadata => pb::ProposalKind::RegisterAssetMetadata,
         }
     }
 }
@@ -254,6 +334,9 @@ impl TryFrom<pb::ProposalKind> for ProposalKind {
             pb::ProposalKind::UpgradePlan => ProposalKind::UpgradePlan,
             pb::ProposalKind::FreezeIbcClient => ProposalKind::FreezeIbcClient,
             pb::ProposalKind::UnfreezeIbcClient => ProposalKind::UnfreezeIbcClient,
+            pb::ProposalKind::RecoverIbcClient => ProposalKind::RecoverIbcClient,
+            pb::ProposalKind::SetIbcConnectionMetadata => ProposalKind::SetIbcConnectionMetadata,
+            pb::ProposalKind::RegisterAssetMetadata => ProposalKind::RegisterAssetMetadata,
         };
         Ok(kind)
     }
@@ -285,6 +368,11 @@ impl Proposal {
             ProposalPayload::UpgradePlan { .. } => ProposalKind::UpgradePlan,
             ProposalPayload::FreezeIbcClient { .. } => ProposalKind::FreezeIbcClient,
             ProposalPayload::UnfreezeIbcClient { .. } => ProposalKind::UnfreezeIbcClient,
+            ProposalPayload::RecoverIbcClient { .. } => ProposalKind::RecoverIbcClient,
+            ProposalPayload::SetIbcConnectionMetadata { .. } => {
+                ProposalKind::SetIbcConnectionMetadata
+            }
+            ProposalPayload::RegisterAssetMetadata { .. } => ProposalKind::RegisterAssetMetadata,
         }
     }
 }
@@ -331,19 +419,64 @@ pub enum ProposalPayload {
         /// The identifier of the client to unfreeze.
         client_id: String,
     },
+    /// A proposal to recover a frozen or expired IBC client, by substituting its state with that
+    /// of an active client tracking the same chain.
+    RecoverIbcClient {
+        /// The identifier of the client to recover.
+        subject_client_id: String,
+        /// The identifier of the client whose state is copied to the subject.
+        substitute_client_id: String,
+    },
+    /// A proposal to register human-readable information about the chain at the other end of an
+    /// IBC connection, so that wallets can label its channels.
+    SetIbcConnectionMetadata {
+        /// The identifier of the connection.
+        connection_id: String,
+        /// The information to register, or `None` to remove the registered information.
+        metadata: Option<ConnectionMetadata>,
+    },
+    /// A proposal to register the metadata of assets, such as their display units, so that
+    /// wallets can render amounts of them in a human-readable way.
+    RegisterAssetMetadata {
+        /// The metadata to register, replacing any already registered for the same assets.
+        metadata: Vec<Metadata>,
+    },
 }
 
 /// A TOML-serializable version of `ProposalPayload`, meant for human consumption.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "kind", rename_all = "snake_case")]
 pub enum ProposalPayloadToml {
-    Signaling { commit: Option<String> },
-    Emergency { halt_chain: bool },
+    Signaling {
+        commit: Option<String>,
+    },
+    Emergency {
+        halt_chain: bool,
+    },
     ParameterChange(ParameterChange),
-    CommunityPoolSpend { transaction: String },
-    UpgradePlan { height: u64 },
-    FreezeIbcClient { client_id: String },
-    UnfreezeIbcClient { client_id: String },
+    CommunityPoolSpend {
+        transaction: String,
+    },
+    UpgradePlan {
+        height: u64,
+    },
+    FreezeIbcClient {
+        client_id: String,
+    },
+    UnfreezeIbcClient {
+        client_id: String,
+    },
+    RecoverIbcClient {
+        subject_client_id: String,
+        substitute_client_id: String,
+    },
+    SetIbcConnectionMetadata {
+        connection_id: String,
+        metadata: Option<ConnectionMetadata>,
+    },
+    RegisterAssetMetadata {
+        metadata: Vec<Metadata>,
+    },
 }
 
 impl TryFrom<ProposalPayloadToml> for ProposalPayload {
@@ -377,6 +510,23 @@ impl TryFrom<ProposalPayloadToml> for ProposalPayload {
             ProposalPayloadToml::UnfreezeIbcClient { client_id } => {
                 ProposalPayload::UnfreezeIbcClient { client_id }
             }
+            ProposalPayloadToml::RecoverIbcClient {
+                subject_client_id,
+                substitute_client_id,
+            } => ProposalPayload::RecoverIbcClient {
+                subject_client_id,
+                substitute_client_id,
+            },
+            ProposalPayloadToml::SetIbcConnectionMetadata {
+                connection_id,
+                metadata,
+            } => ProposalPayload::SetIbcConnectionMetadata {
+                connection_id,
+                metadata,
+            },
+            ProposalPayloadToml::RegisterAssetMetadata { metadata } => {
+                ProposalPayload::RegisterAssetMetadata { metadata }
+            }
         })
     }
 }
@@ -406,6 +556,23 @@ impl From<ProposalPayload> for ProposalPayloadToml {
             ProposalPayload::UnfreezeIbcClient { client_id } => {
                 ProposalPayloadToml::UnfreezeIbcClient { client_id }
             }
+            ProposalPayload::RecoverIbcClient {
+                subject_client_id,
+                substitute_client_id,
+            } => ProposalPayloadToml::RecoverIbcClient {
+                subject_client_id,
+                substitute_client_id,
+            },
+            ProposalPayload::SetIbcConnectionMetadata {
+                connection_id,
+                metadata,
+            } => ProposalPayloadToml::SetIbcConnectionMetadata {
+                connection_id,
+                metadata,
+            },
+            ProposalPayload::RegisterAssetMetadata { metadata } => {
+                ProposalPayloadToml::RegisterAssetMetadata { metadata }
+            }
         }
     }
 }
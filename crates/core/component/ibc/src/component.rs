@@ -1,23 +1,34 @@
+mod ack_pruning;
 mod action_handler;
+mod base64_bytes;
 mod channel;
 mod client;
 mod client_counter;
 mod connection;
 mod connection_counter;
+mod consensus_state_provider;
+mod decoded_cache;
+mod events;
 mod ics02_validation;
+mod light_client;
 
 #[cfg(feature = "rpc")]
 pub mod rpc;
 
 mod host_interface;
 mod ibc_component;
-mod metrics;
 mod msg_handler;
+mod proof_batch;
 mod proof_verification;
+mod relay_simulation;
+mod verification_stats;
 mod view;
 
 pub mod app_handler;
+pub mod fees;
 pub mod ibc_action_with_handler;
+pub mod ica;
+pub mod metrics;
 pub mod packet;
 pub mod state_key;
 
@@ -30,7 +41,13 @@ pub use client::StateReadExt as ClientStateReadExt;
 pub use client::StateWriteExt as ClientStateWriteExt;
 pub use connection::StateReadExt as ConnectionStateReadExt;
 pub use connection::StateWriteExt as ConnectionStateWriteExt;
+pub use consensus_state_provider::{ConsensusStateProvider, NoConsensusStateProvider};
 pub use host_interface::HostInterface;
+pub use light_client::{
+    LightClient, LightClientRegistry, LightClientVerifier, TendermintLightClient,
+};
+pub use proof_verification::{packet_commitment_matches, QueryProofVerifier, VerificationError};
+pub use relay_simulation::{RelaySimulationExt, SimulationResult};
 pub use view::{StateReadExt, StateWriteExt};
 
 pub use ibc_component::Ibc;
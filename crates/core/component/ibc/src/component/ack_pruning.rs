@@ -0,0 +1,140 @@
+//! Pruning of the acknowledgements of received packets.
+//!
+//! An acknowledgement is written for every packet received, and is only needed
+//! until a relayer has proven it to the packet's sender. To bound the growth of
+//! the IBC state, acknowledgements are queued as they are written, and pruned in
+//! [`Ibc::end_block`](super::Ibc::end_block) once
+//! [`IBCParameters::ack_retention_blocks`](crate::params::IBCParameters::ack_retention_blocks)
+//! blocks have passed. An acknowledgement that hasn't been relayed by then
+//! can't be relayed at all, so the retention period should be generous.
+//!
+//! Commitments to sent packets don't need pruning, since they are deleted as
+//! soon as their packet is acknowledged or times out. Packet receipts are kept
+//! forever: without its receipt, the sender of a packet that was received could
+//! prove that it wasn't, and time it out after all.
+
+use anyhow::Result;
+use cnidarium::StateWrite;
+use futures::{future, StreamExt as _, TryStreamExt as _};
+
+use super::{state_key, view::StateReadExt as _};
+
+/// The maximum number of acknowledgements pruned in a single block, so that a
+/// backlog (e.g. after the retention period is shortened) is worked through
+/// over several blocks.
+const MAX_PRUNED_PER_BLOCK: usize = 1_000;
+
+/// Queues an acknowledgement written in the current block for pruning.
+pub(crate) fn queue<S: StateWrite + ?Sized>(state: &mut S, ack_key: String) {
+    let mut pending: Vec<String> = state
+        .object_get(state_key::ack_pruning::pending())
+        .unwrap_or_default();
+    pending.push(ack_key);
+    state.object_put(state_key::ack_pruning::pending(), pending);
+}
+
+/// Gets the height at which a queued acknowledgement was written from its key
+/// in the queue.
+fn queued_height(key: &str) -> Option<u64> {
+    key.strip_prefix(state_key::ack_pruning::queue_prefix())?
+        .split('/')
+        .next()?
+        .parse()
+        .ok()
+}
+
+/// Queues the acknowledgements written in the block at `height`, and prunes
+/// those whose retention period has passed.
+pub(crate) async fn prune<S: StateWrite + ?Sized>(state: &mut S, height: u64) -> Result<()> {
+    let pending: Vec<String> = state
+        .object_get(state_key::ack_pruning::pending())
+        .unwrap_or_default();
+    state.object_delete(state_key::ack_pruning::pending());
+
+    let retention = state.get_ibc_params().await?.ack_retention_blocks;
+    if retention == 0 {
+        return Ok(());
+    }
+
+    for (index, ack_key) in pending.into_iter().enumerate() {
+        state.put_raw(
+            state_key::ack_pruning::queued(height, index as u64),
+            ack_key.into_bytes(),
+        );
+    }
+
+    let Some(cutoff) = height.checked_sub(retention) else {
+        return Ok(());
+    };
+    let expired: Vec<(String, Vec<u8>)> = state
+        .prefix_raw(state_key::ack_pruning::queue_prefix())
+        .try_take_while(|(key, _)| {
+            future::ready(Ok(queued_height(key).is_some_and(|h| h <= cutoff)))
+        })
+        .take(MAX_PRUNED_PER_BLOCK)
+        .try_collect()
+        .await?;
+
+    for (queued_key, ack_key) in expired {
+        state.delete(String::from_utf8(ack_key)?);
+        state.delete(queued_key);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::{StateDelta, StateRead as _};
+    use ibc_types::core::channel::{ChannelId, PortId};
+
+    use super::*;
+    use crate::component::channel::{StateReadExt as _, StateWriteExt as _};
+    use crate::params::IBCParameters;
+    use crate::StateWriteExt as _;
+
+    #[tokio::test]
+    async fn acks_are_pruned_after_retention() -> Result<()> {
+        let mut state = StateDelta::new(());
+        state.put_ibc_params(IBCParameters {
+            ack_retention_blocks: 10,
+            ..Default::default()
+        });
+        let port_id = PortId::transfer();
+        let channel_id = ChannelId::new(0);
+
+        state.put_packet_acknowledgement(&port_id, &channel_id, 1, b"ack");
+        prune(&mut state, 5).await?;
+        state.put_packet_acknowledgement(&port_id, &channel_id, 2, b"ack");
+        prune(&mut state, 6).await?;
+
+        // the acks are kept for the retention period
+        prune(&mut state, 14).await?;
+        assert!(state
+            .get_packet_acknowledgement(&port_id, &channel_id, 1)
+            .await?
+            .is_some());
+
+        prune(&mut state, 15).await?;
+        assert!(state
+            .get_packet_acknowledgement(&port_id, &channel_id, 1)
+            .await?
+            .is_none());
+        assert!(state
+            .get_packet_acknowledgement(&port_id, &channel_id, 2)
+            .await?
+            .is_some());
+
+        prune(&mut state, 16).await?;
+        assert!(state
+            .get_packet_acknowledgement(&port_id, &channel_id, 2)
+            .await?
+            .is_none());
+        assert!(state
+            .get_raw(&state_key::ack_pruning::queued(6, 0))
+            .await?
+            .is_none());
+
+        Ok(())
+    }
+}
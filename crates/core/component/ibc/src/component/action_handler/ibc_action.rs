@@ -29,6 +29,7 @@ impl<AH: AppHandler, HI: HostInterface> IbcRelayWithHandlers<AH, HI> {
             IbcRelay::RecvPacket(msg) => msg.check_stateless::<AH>().await?,
             IbcRelay::Acknowledgement(msg) => msg.check_stateless::<AH>().await?,
             IbcRelay::Timeout(msg) => msg.check_stateless::<AH>().await?,
+            IbcRelay::TimeoutOnClose(msg) => msg.check_stateless::<AH>().await?,
             IbcRelay::Unknown(msg) => {
                 anyhow::bail!("unknown IBC message type: {}", msg.type_url)
             }
@@ -118,6 +119,10 @@ impl<AH: AppHandler, HI: HostInterface> IbcRelayWithHandlers<AH, HI> {
                 .try_execute::<S, AH, HI>(state)
                 .await
                 .context("failed to execute MsgTimeout")?,
+            IbcRelay::TimeoutOnClose(msg) => msg
+                .try_execute::<S, AH, HI>(state)
+                .await
+                .context("failed to execute MsgTimeoutOnClose")?,
             IbcRelay::Unknown(msg) => {
                 anyhow::bail!("unknown IBC message type: {}", msg.type_url)
             }
@@ -6,19 +6,82 @@
 ///
 /// The primary IBC application is the Ics20 transfer application, which allows for interchain
 /// token transfers.
-use anyhow::Result;
+use anyhow::{Context, Result};
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
-use ibc_types::core::channel::msgs::{
-    MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
-    MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket, MsgTimeout,
+use ibc_types::core::channel::{
+    msgs::{
+        MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
+        MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket, MsgTimeout,
+    },
+    PortId, Version,
 };
+use serde::{Deserialize, Serialize};
+
+use super::base64_bytes;
+
+/// The result of processing a packet on the counterparty chain, as reported by its
+/// acknowledgement.
+///
+/// This is the acknowledgement format that ICS-04 recommends, and that ibc-go's applications
+/// use: a JSON object with either a `result` or an `error` field.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AcknowledgementResult {
+    /// The packet was processed, with an application-specific result.
+    #[serde(rename = "result", with = "base64_bytes")]
+    Success(Vec<u8>),
+    /// The packet couldn't be processed, so its effects on the sending chain should be
+    /// reverted.
+    #[serde(rename = "error")]
+    Error(String),
+}
+
+impl AcknowledgementResult {
+    pub fn decode(acknowledgement: &[u8]) -> Result<Self> {
+        serde_json::from_slice(acknowledgement).context("failed to decode acknowledgement")
+    }
+
+    pub fn is_success(&self) -> bool {
+        matches!(self, AcknowledgementResult::Success(_))
+    }
+}
+
+impl From<AcknowledgementResult> for Vec<u8> {
+    fn from(ack: AcknowledgementResult) -> Self {
+        serde_json::to_vec(&ack).expect("acknowledgements serialize to JSON")
+    }
+}
 
 /// AppHandlerCheck defines the interface for an IBC application to consume IBC channel and packet
 /// events, and apply their validation logic. This validation logic is used for stateful validation
 /// only.
 #[async_trait]
 pub trait AppHandlerCheck: Send + Sync {
+    /// Returns whether this handler is bound to `port_id`. Channel and packet
+    /// messages for ports that no handler is bound to are rejected.
+    fn is_bound_port(port_id: &PortId) -> bool {
+        *port_id == PortId::transfer()
+    }
+
+    /// Returns the version of a channel opened in response to `msg`, which
+    /// defaults to the version proposed by the counterparty.
+    async fn chan_open_try_version<S: StateRead>(
+        _state: S,
+        msg: &MsgChannelOpenTry,
+    ) -> Result<Version> {
+        Ok(msg.version_supported_on_a.clone())
+    }
+
+    /// Returns the acknowledgement that the counterparty application writes as
+    /// a placeholder while it processes a packet asynchronously, on a channel
+    /// of `port_id` opened with `version`.
+    ///
+    /// Acknowledgements matching the sentinel are reported as pending rather
+    /// than final. Defaults to none, so that every acknowledgement is final.
+    fn pending_ack_sentinel(_port_id: &PortId, _version: &Version) -> Option<Vec<u8>> {
+        None
+    }
+
     async fn chan_open_init_check<S: StateRead>(state: S, msg: &MsgChannelOpenInit) -> Result<()>;
     async fn chan_open_try_check<S: StateRead>(state: S, msg: &MsgChannelOpenTry) -> Result<()>;
     async fn chan_open_ack_check<S: StateRead>(state: S, msg: &MsgChannelOpenAck) -> Result<()>;
@@ -46,19 +109,73 @@ pub trait AppHandlerCheck: Send + Sync {
 // once the transaction has been validated using the AppHandlerCheck interface.
 #[async_trait]
 pub trait AppHandlerExecute: Send + Sync {
-    async fn chan_open_init_execute<S: StateWrite>(state: S, msg: &MsgChannelOpenInit);
-    async fn chan_open_try_execute<S: StateWrite>(state: S, msg: &MsgChannelOpenTry);
-    async fn chan_open_ack_execute<S: StateWrite>(state: S, msg: &MsgChannelOpenAck);
-    async fn chan_open_confirm_execute<S: StateWrite>(state: S, msg: &MsgChannelOpenConfirm);
-    async fn chan_close_confirm_execute<S: StateWrite>(state: S, msg: &MsgChannelCloseConfirm);
-    async fn chan_close_init_execute<S: StateWrite>(state: S, msg: &MsgChannelCloseInit);
+    async fn chan_open_init_execute<S: StateWrite>(
+        state: S,
+        msg: &MsgChannelOpenInit,
+    ) -> Result<()>;
+    async fn chan_open_try_execute<S: StateWrite>(state: S, msg: &MsgChannelOpenTry) -> Result<()>;
+    async fn chan_open_ack_execute<S: StateWrite>(state: S, msg: &MsgChannelOpenAck) -> Result<()>;
+    async fn chan_open_confirm_execute<S: StateWrite>(
+        state: S,
+        msg: &MsgChannelOpenConfirm,
+    ) -> Result<()>;
+    async fn chan_close_confirm_execute<S: StateWrite>(
+        state: S,
+        msg: &MsgChannelCloseConfirm,
+    ) -> Result<()>;
+    async fn chan_close_init_execute<S: StateWrite>(
+        state: S,
+        msg: &MsgChannelCloseInit,
+    ) -> Result<()>;
 
     async fn recv_packet_execute<S: StateWrite>(state: S, msg: &MsgRecvPacket) -> Result<()>;
     async fn timeout_packet_execute<S: StateWrite>(state: S, msg: &MsgTimeout) -> Result<()>;
+
+    /// Handles the acknowledgement of a packet sent by this application, by decoding it and
+    /// passing the result to [`AppHandlerExecute::on_acknowledgement`].
+    ///
+    /// Middleware that wraps the acknowledgements of the applications beneath it overrides this
+    /// to unwrap them first.
     async fn acknowledge_packet_execute<S: StateWrite>(
         state: S,
         msg: &MsgAcknowledgement,
+    ) -> Result<()> {
+        let ack = AcknowledgementResult::decode(&msg.acknowledgement)?;
+        Self::on_acknowledgement(state, msg, &ack).await
+    }
+
+    /// Called when a packet sent by this application is acknowledged, with the result the
+    /// counterparty application reported for it. An application that escrowed or burned
+    /// anything to send the packet should refund it if the result is an error.
+    async fn on_acknowledgement<S: StateWrite>(
+        state: S,
+        msg: &MsgAcknowledgement,
+        ack: &AcknowledgementResult,
     ) -> Result<()>;
 }
 
 pub trait AppHandler: AppHandlerCheck + AppHandlerExecute {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn acknowledgements_match_ibc_go_encoding() {
+        let success = AcknowledgementResult::decode(br#"{"result":"AQ=="}"#).unwrap();
+        assert_eq!(success, AcknowledgementResult::Success(vec![1]));
+        assert!(success.is_success());
+
+        let error = AcknowledgementResult::decode(br#"{"error":"insufficient funds"}"#).unwrap();
+        assert_eq!(
+            error,
+            AcknowledgementResult::Error("insufficient funds".to_string())
+        );
+        assert_eq!(
+            Vec::<u8>::from(error),
+            br#"{"error":"insufficient funds"}"#.to_vec()
+        );
+
+        assert!(AcknowledgementResult::decode(b"\x01").is_err());
+    }
+}
@@ -0,0 +1,16 @@
+//! Serializes bytes as base64 strings, as in the JSON encoding of protobuf
+//! `bytes` fields used by ibc-go.
+
+use base64::prelude::*;
+use serde::{Deserialize, Deserializer, Serializer};
+
+pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&BASE64_STANDARD.encode(bytes))
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    let string = String::deserialize(deserializer)?;
+    BASE64_STANDARD
+        .decode(string)
+        .map_err(serde::de::Error::custom)
+}
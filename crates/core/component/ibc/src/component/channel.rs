@@ -1,4 +1,5 @@
 use crate::component::proof_verification::{commit_acknowledgement, commit_packet};
+use crate::component::{ack_pruning, state_key};
 use crate::prefix::MerklePrefixExt;
 use crate::IBC_COMMITMENT_PREFIX;
 
@@ -94,11 +95,29 @@ pub trait StateWriteExt: StateWrite + StateReadExt {
         channel_id: &ChannelId,
         sequence: u64,
         acknowledgement: &[u8],
+    ) {
+        let ack_key = IBC_COMMITMENT_PREFIX
+            .apply_string(AckPath::new(port_id, channel_id, sequence.into()).to_string());
+        self.put_raw(ack_key.clone(), commit_acknowledgement(acknowledgement));
+        ack_pruning::queue(self, ack_key);
+    }
+
+    /// Records the acknowledgement bytes that the counterparty application
+    /// writes as a placeholder while it processes a packet asynchronously.
+    ///
+    /// The sentinel is supplied by the application bound to the channel when
+    /// the channel opens, see [`AppHandlerCheck::pending_ack_sentinel`].
+    ///
+    /// [`AppHandlerCheck::pending_ack_sentinel`]: crate::component::app_handler::AppHandlerCheck::pending_ack_sentinel
+    fn put_pending_ack_sentinel(
+        &mut self,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        sentinel: Vec<u8>,
     ) {
         self.put_raw(
-            IBC_COMMITMENT_PREFIX
-                .apply_string(AckPath::new(port_id, channel_id, sequence.into()).to_string()),
-            commit_acknowledgement(acknowledgement),
+            state_key::pending_ack_sentinel(channel_id, port_id),
+            sentinel,
         );
     }
 }
@@ -263,6 +282,30 @@ pub trait StateReadExt: StateRead {
         )
         .await
     }
+
+    async fn get_pending_ack_sentinel(
+        &self,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+    ) -> Result<Option<Vec<u8>>> {
+        self.get_raw(&state_key::pending_ack_sentinel(channel_id, port_id))
+            .await
+    }
+
+    /// Returns whether `acknowledgement`, as written by the counterparty
+    /// application, is the channel's pending-acknowledgement sentinel rather
+    /// than a final acknowledgement.
+    async fn is_pending_ack(
+        &self,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        acknowledgement: &[u8],
+    ) -> Result<bool> {
+        Ok(self
+            .get_pending_ack_sentinel(channel_id, port_id)
+            .await?
+            .is_some_and(|sentinel| sentinel == acknowledgement))
+    }
 }
 
 impl<T: StateRead + ?Sized> StateReadExt for T {}
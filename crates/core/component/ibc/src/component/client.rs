@@ -1,6 +1,7 @@
 use core::fmt;
 use std::fmt::Display;
 use std::fmt::Formatter;
+use std::sync::Mutex;
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
@@ -17,18 +18,26 @@ use ibc_types::lightclients::tendermint::{
     consensus_state::ConsensusState as TendermintConsensusState,
     header::Header as TendermintHeader,
 };
-use penumbra_proto::{StateReadProto, StateWriteProto};
+use once_cell::sync::Lazy;
+use penumbra_proto::{DomainType, StateReadProto, StateWriteProto};
+use prost::Message as _;
 
 use crate::component::client_counter::{ClientCounter, VerifiedHeights};
 use crate::prefix::MerklePrefixExt;
 use crate::IBC_COMMITMENT_PREFIX;
 
+use super::consensus_state_provider::{
+    validate_provided_consensus_state, ConsensusStateProvider, NoConsensusStateProvider,
+};
+use super::decoded_cache::{decode_with, DecodedCache};
+use super::events;
 use super::state_key;
 use super::HostInterface;
 
 /// ClientStatus represents the current status of an IBC client.
 ///
 /// https://github.com/cosmos/ibc-go/blob/main/modules/core/exported/client.go#L30
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ClientStatus {
     /// Active is a status type of a client. An active client is allowed to be used.
     Active,
@@ -205,6 +214,11 @@ pub trait StateWriteExt: StateWrite + StateReadExt {
             ibc_types::lightclients::tendermint::client_type().to_string(),
         );
 
+        self.nonverifiable_put_raw(
+            state_key::client_proof_specs(client_id).into_bytes(),
+            encode_proof_specs(&client_state.proof_specs),
+        );
+
         self.put(
             IBC_COMMITMENT_PREFIX.apply_string(ClientStatePath(client_id.clone()).to_string()),
             client_state,
@@ -222,6 +236,114 @@ pub trait StateWriteExt: StateWrite + StateReadExt {
         );
     }
 
+    /// Substitutes the state of a frozen or expired client with that of an
+    /// active substitute client tracking the same chain, following ibc-go's
+    /// client recovery.
+    ///
+    /// The substitute's latest consensus state is copied to the subject, and the
+    /// subject takes on the substitute's latest height, chain id and trusting
+    /// period. All other client parameters must match.
+    ///
+    /// https://github.com/cosmos/ibc-go/blob/main/modules/light-clients/07-tendermint/proposal_handle.go
+    async fn recover_client(
+        &mut self,
+        subject_client_id: &ClientId,
+        substitute_client_id: &ClientId,
+        current_block_time: tendermint::Time,
+    ) -> Result<()> {
+        if subject_client_id == substitute_client_id {
+            anyhow::bail!("client {subject_client_id} cannot be its own substitute");
+        }
+
+        let subject_status = self
+            .get_client_status(subject_client_id, current_block_time)
+            .await;
+        if !matches!(subject_status, ClientStatus::Frozen | ClientStatus::Expired) {
+            anyhow::bail!(
+                "client {subject_client_id} is {subject_status}, only frozen or expired clients can be recovered"
+            );
+        }
+        let substitute_status = self
+            .get_client_status(substitute_client_id, current_block_time)
+            .await;
+        if !matches!(substitute_status, ClientStatus::Active) {
+            anyhow::bail!(
+                "substitute client {substitute_client_id} is {substitute_status}, but must be active"
+            );
+        }
+
+        let subject_client_state = self.get_client_state(subject_client_id).await?;
+        let substitute_client_state = self.get_client_state(substitute_client_id).await?;
+        if substitute_client_state.latest_height() <= subject_client_state.latest_height() {
+            anyhow::bail!(
+                "substitute client height {} is not greater than subject client height {}",
+                substitute_client_state.latest_height(),
+                subject_client_state.latest_height()
+            );
+        }
+        if !is_matching_client_state(&subject_client_state, &substitute_client_state) {
+            anyhow::bail!(
+                "substitute client {substitute_client_id} does not match subject client {subject_client_id}"
+            );
+        }
+
+        // copy the substitute's latest consensus state, along with when it was processed, so
+        // that connection delays are measured from the substitute's update.
+        let height = substitute_client_state.latest_height();
+        let consensus_state = self
+            .get_verified_consensus_state(&height, substitute_client_id)
+            .await?;
+        let processed_time: u64 = self
+            .get_proto(&state_key::client_processed_times(
+                substitute_client_id,
+                &height,
+            ))
+            .await?
+            .context(format!(
+                "client update time not found for client {substitute_client_id} at height {height}"
+            ))?;
+        let processed_height = self
+            .get_client_update_height(substitute_client_id, &height)
+            .await?;
+
+        self.put(
+            IBC_COMMITMENT_PREFIX.apply_string(
+                ClientConsensusStatePath::new(subject_client_id, &height).to_string(),
+            ),
+            consensus_state,
+        );
+        self.put_proto::<u64>(
+            state_key::client_processed_times(subject_client_id, &height),
+            processed_time,
+        );
+        self.put(
+            state_key::client_processed_heights(subject_client_id, &height),
+            processed_height,
+        );
+
+        let mut verified_heights = self
+            .get_verified_heights(subject_client_id)
+            .await?
+            .unwrap_or(VerifiedHeights {
+                heights: Vec::new(),
+            });
+        verified_heights.heights.push(height);
+        self.put_verified_heights(subject_client_id, verified_heights);
+
+        let mut recovered_client_state = subject_client_state;
+        recovered_client_state.latest_height = substitute_client_state.latest_height;
+        recovered_client_state.chain_id = substitute_client_state.chain_id;
+        recovered_client_state.trusting_period = substitute_client_state.trusting_period;
+        recovered_client_state.frozen_height = None;
+        self.put_client(subject_client_id, recovered_client_state);
+        self.record(events::recover_client(
+            subject_client_id,
+            &ibc_types::lightclients::tendermint::client_type(),
+        ));
+
+        Ok(())
+    }
+
     // returns the ConsensusState for the penumbra chain (this chain) at the given height
     fn put_penumbra_consensus_state(
         &mut self,
@@ -239,6 +361,64 @@ pub trait StateWriteExt: StateWrite + StateReadExt {
 
 impl<T: StateWrite + ?Sized> StateWriteExt for T {}
 
+// returns whether a substitute client state may replace the subject's. they may only differ in
+// the parameters that are expected to change while a client is frozen or expired.
+fn is_matching_client_state(
+    subject: &TendermintClientState,
+    substitute: &TendermintClientState,
+) -> bool {
+    let mut normalized_substitute = substitute.clone();
+    normalized_substitute.latest_height = subject.latest_height;
+    normalized_substitute.chain_id = subject.chain_id.clone();
+    normalized_substitute.trusting_period = subject.trusting_period;
+    normalized_substitute.frozen_height = subject.frozen_height;
+
+    normalized_substitute == *subject
+}
+
+/// Recently decoded Tendermint client states.
+static CLIENT_STATE_CACHE: Lazy<Mutex<DecodedCache<TendermintClientState>>> =
+    Lazy::new(|| Mutex::new(DecodedCache::new(256)));
+
+/// Recently decoded Tendermint consensus states.
+static CONSENSUS_STATE_CACHE: Lazy<Mutex<DecodedCache<TendermintConsensusState>>> =
+    Lazy::new(|| Mutex::new(DecodedCache::new(1024)));
+
+/// Decodes a Tendermint client state, reusing a recent decoding of the same
+/// encoding if there is one.
+pub(crate) fn decode_client_state(encoded: &[u8]) -> Result<TendermintClientState> {
+    decode_with(&CLIENT_STATE_CACHE, encoded, |encoded| {
+        <TendermintClientState as DomainType>::decode(encoded)
+    })
+}
+
+/// Decodes a Tendermint consensus state, reusing a recent decoding of the same
+/// encoding if there is one.
+pub(crate) fn decode_consensus_state(encoded: &[u8]) -> Result<TendermintConsensusState> {
+    decode_with(&CONSENSUS_STATE_CACHE, encoded, |encoded| {
+        <TendermintConsensusState as DomainType>::decode(encoded)
+    })
+}
+
+/// Encodes proof specs as they're cached by [`StateWriteExt::put_client`].
+fn encode_proof_specs(proof_specs: &[ics23::ProofSpec]) -> Vec<u8> {
+    let mut encoded = Vec::new();
+    for proof_spec in proof_specs {
+        proof_spec
+            .encode_length_delimited(&mut encoded)
+            .expect("encoding to a vec can't run out of space");
+    }
+    encoded
+}
+
+fn decode_proof_specs(mut encoded: &[u8]) -> Result<Vec<ics23::ProofSpec>> {
+    let mut proof_specs = Vec::new();
+    while !encoded.is_empty() {
+        proof_specs.push(ics23::ProofSpec::decode_length_delimited(&mut encoded)?);
+    }
+    Ok(proof_specs)
+}
+
 #[async_trait]
 pub trait StateReadExt: StateRead {
     async fn client_counter(&self) -> Result<ClientCounter> {
@@ -258,14 +438,55 @@ pub trait StateReadExt: StateRead {
 
     async fn get_client_state(&self, client_id: &ClientId) -> Result<TendermintClientState> {
         let client_state = self
-            .get(
+            .get_raw(
                 &IBC_COMMITMENT_PREFIX.apply_string(ClientStatePath(client_id.clone()).to_string()),
             )
-            .await?;
+            .await?
+            .context(format!("could not find client state for {client_id}"))?;
 
-        client_state.context(format!("could not find client state for {client_id}"))
+        decode_client_state(&client_state)
     }
 
+    /// Gets the ICS-23 proof specs of a client.
+    ///
+    /// Client states are stored as a single encoded value, so their proof specs
+    /// can't be read on their own. Instead, [`StateWriteExt::put_client`] caches
+    /// each client's proof specs in the nonverifiable store, keyed by client id
+    /// and replaced whenever the client is updated, so that verification-only
+    /// flows need not read and decode the full client state. Clients last
+    /// written before the cache existed fall back to their client state.
+    async fn get_client_proof_specs(&self, client_id: &ClientId) -> Result<Vec<ics23::ProofSpec>> {
+        match self
+            .nonverifiable_get_raw(state_key::client_proof_specs(client_id).as_bytes())
+            .await?
+        {
+            Some(encoded) => decode_proof_specs(&encoded),
+            None => Ok(self.get_client_state(client_id).await?.proof_specs),
+        }
+    }
+
+    /// Gets the status of a client as of the current block: whether it can be
+    /// used, or is frozen, expired or of a client type the host doesn't allow.
+    async fn client_status<HI: HostInterface>(&self, client_id: &ClientId) -> ClientStatus
+    where
+        Self: Sized,
+    {
+        let Ok(client_type) = self.get_client_type(client_id).await else {
+            return ClientStatus::Unknown;
+        };
+        // https://github.com/cosmos/ibc-go/blob/main/modules/core/02-client/types/params.go#L34
+        if HI::light_clients().get(&client_type).is_none() {
+            return ClientStatus::Unauthorized;
+        }
+
+        match HI::get_block_timestamp(self).await {
+            Ok(current_block_time) => self.get_client_status(client_id, current_block_time).await,
+            Err(_) => ClientStatus::Unknown,
+        }
+    }
+
+    /// Gets the status of a client as of `current_block_time`, without checking
+    /// that its client type is allowed.
     async fn get_client_status(
         &self,
         client_id: &ClientId,
@@ -277,11 +498,6 @@ pub trait StateReadExt: StateRead {
             return ClientStatus::Unknown;
         }
 
-        // let _client_type = client_type.expect("client type is Ok");
-        // IBC-Go has a check here to see if the client type is allowed.
-        // We don't have a similar allowlist in Penumbra, so we skip that check.
-        // https://github.com/cosmos/ibc-go/blob/main/modules/core/02-client/types/params.go#L34
-
         let client_state = self.get_client_state(client_id).await;
 
         if client_state.is_err() {
@@ -307,11 +523,11 @@ pub trait StateReadExt: StateRead {
 
         let latest_consensus_state = latest_consensus_state.expect("latest consensus state is Ok");
 
-        let time_elapsed = current_block_time.duration_since(latest_consensus_state.timestamp);
-        if time_elapsed.is_err() {
-            return ClientStatus::Unknown;
-        }
-        let time_elapsed = time_elapsed.expect("time elapsed is Ok");
+        // a consensus state from the future (e.g. due to clock drift between the chains) hasn't
+        // expired, matching ibc-go.
+        let time_elapsed = current_block_time
+            .duration_since(latest_consensus_state.timestamp)
+            .unwrap_or_default();
 
         if client_state.expired(time_elapsed) {
             return ClientStatus::Expired;
@@ -348,16 +564,45 @@ pub trait StateReadExt: StateRead {
         height: &Height,
         client_id: &ClientId,
     ) -> Result<TendermintConsensusState> {
-        self.get(
-            &IBC_COMMITMENT_PREFIX
-                .apply_string(ClientConsensusStatePath::new(client_id, height).to_string()),
+        self.get_verified_consensus_state_with_provider(
+            height,
+            client_id,
+            &NoConsensusStateProvider,
         )
-        .await?
-        .ok_or_else(|| {
-            anyhow::anyhow!(
-                "counterparty consensus state not found for client {client_id} at height {height}"
+        .await
+    }
+
+    /// Gets the consensus state of a client at `height`, falling back to
+    /// `provider` if it is not in the local state (e.g. because it was pruned).
+    ///
+    /// See [`ConsensusStateProvider`] for the trust assumptions this implies.
+    async fn get_verified_consensus_state_with_provider<P: ConsensusStateProvider>(
+        &self,
+        height: &Height,
+        client_id: &ClientId,
+        provider: &P,
+    ) -> Result<TendermintConsensusState> {
+        if let Some(consensus_state) = self
+            .get_raw(
+                &IBC_COMMITMENT_PREFIX
+                    .apply_string(ClientConsensusStatePath::new(client_id, height).to_string()),
             )
-        })
+            .await?
+        {
+            return decode_consensus_state(&consensus_state);
+        }
+
+        let consensus_state = provider
+            .get_consensus_state(client_id, height)
+            .await?
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "counterparty consensus state not found for client {client_id} at height {height}"
+                )
+            })?;
+        validate_provided_consensus_state(&consensus_state)?;
+
+        Ok(consensus_state)
     }
 
     async fn get_client_update_height(
@@ -474,7 +719,9 @@ mod tests {
     use crate::component::ClientStateReadExt;
     use crate::{IbcRelay, StateWriteExt};
 
-    use crate::component::app_handler::{AppHandler, AppHandlerCheck, AppHandlerExecute};
+    use crate::component::app_handler::{
+        AcknowledgementResult, AppHandler, AppHandlerCheck, AppHandlerExecute,
+    };
     use ibc_types::core::channel::msgs::{
         MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
         MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket, MsgTimeout,
@@ -557,26 +804,52 @@ mod tests {
 
     #[async_trait]
     impl AppHandlerExecute for MockAppHandler {
-        async fn chan_open_init_execute<S: StateWrite>(_state: S, _msg: &MsgChannelOpenInit) {}
-        async fn chan_open_try_execute<S: StateWrite>(_state: S, _msg: &MsgChannelOpenTry) {}
-        async fn chan_open_ack_execute<S: StateWrite>(_state: S, _msg: &MsgChannelOpenAck) {}
-        async fn chan_open_confirm_execute<S: StateWrite>(_state: S, _msg: &MsgChannelOpenConfirm) {
+        async fn chan_open_init_execute<S: StateWrite>(
+            _state: S,
+            _msg: &MsgChannelOpenInit,
+        ) -> Result<()> {
+            Ok(())
+        }
+        async fn chan_open_try_execute<S: StateWrite>(
+            _state: S,
+            _msg: &MsgChannelOpenTry,
+        ) -> Result<()> {
+            Ok(())
+        }
+        async fn chan_open_ack_execute<S: StateWrite>(
+            _state: S,
+            _msg: &MsgChannelOpenAck,
+        ) -> Result<()> {
+            Ok(())
+        }
+        async fn chan_open_confirm_execute<S: StateWrite>(
+            _state: S,
+            _msg: &MsgChannelOpenConfirm,
+        ) -> Result<()> {
+            Ok(())
         }
         async fn chan_close_confirm_execute<S: StateWrite>(
             _state: S,
             _msg: &MsgChannelCloseConfirm,
-        ) {
+        ) -> Result<()> {
+            Ok(())
+        }
+        async fn chan_close_init_execute<S: StateWrite>(
+            _state: S,
+            _msg: &MsgChannelCloseInit,
+        ) -> Result<()> {
+            Ok(())
         }
-        async fn chan_close_init_execute<S: StateWrite>(_state: S, _msg: &MsgChannelCloseInit) {}
         async fn recv_packet_execute<S: StateWrite>(_state: S, _msg: &MsgRecvPacket) -> Result<()> {
             Ok(())
         }
         async fn timeout_packet_execute<S: StateWrite>(_state: S, _msg: &MsgTimeout) -> Result<()> {
             Ok(())
         }
-        async fn acknowledge_packet_execute<S: StateWrite>(
+        async fn on_acknowledgement<S: StateWrite>(
             _state: S,
             _msg: &MsgAcknowledgement,
+            _ack: &AcknowledgementResult,
         ) -> Result<()> {
             Ok(())
         }
@@ -627,6 +900,7 @@ mod tests {
             ibc_enabled: true,
             inbound_ics20_transfers_enabled: true,
             outbound_ics20_transfers_enabled: true,
+            ..Default::default()
         });
         state_tx.put_epoch_by_height(
             1,
@@ -717,6 +991,7 @@ mod tests {
             ibc_enabled: false,
             inbound_ics20_transfers_enabled: true,
             outbound_ics20_transfers_enabled: true,
+            ..Default::default()
         });
 
         let msg_create_client_stargaze_raw = BASE64_STANDARD
@@ -738,4 +1013,264 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn cached_client_states_follow_updates() -> anyhow::Result<()> {
+        let mut state = Arc::new(StateDelta::new(()));
+        let client_id = ClientId::from_str("07-tendermint-0")?;
+
+        let msg_create_client_stargaze_raw = BASE64_STANDARD
+            .decode(include_str!("./test/create_client.msg").replace('\n', ""))
+            .unwrap();
+        let msg_create_stargaze_client =
+            MsgCreateClient::decode(msg_create_client_stargaze_raw.as_slice()).unwrap();
+        let mut client_state = crate::component::ics02_validation::get_tendermint_client_state(
+            msg_create_stargaze_client.client_state,
+        )?;
+
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.put_client(&client_id, client_state.clone());
+        state_tx.apply();
+
+        assert_eq!(
+            state.get_client_state(&client_id).await?.proof_specs,
+            client_state.proof_specs
+        );
+
+        // Replacing the client state must not serve the stale cached decoding.
+        client_state.proof_specs.truncate(1);
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.put_client(&client_id, client_state.clone());
+        state_tx.apply();
+
+        assert_eq!(
+            state.get_client_state(&client_id).await?.proof_specs,
+            client_state.proof_specs
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn proof_specs_are_cached_per_client() -> anyhow::Result<()> {
+        let mut state = StateDelta::new(());
+        let client_id = ClientId::from_str("07-tendermint-0")?;
+
+        let msg_create_client_stargaze_raw = BASE64_STANDARD
+            .decode(include_str!("./test/create_client.msg").replace('\n', ""))
+            .unwrap();
+        let msg_create_stargaze_client =
+            MsgCreateClient::decode(msg_create_client_stargaze_raw.as_slice()).unwrap();
+        let mut client_state = crate::component::ics02_validation::get_tendermint_client_state(
+            msg_create_stargaze_client.client_state,
+        )?;
+
+        state.put_client(&client_id, client_state.clone());
+        assert_eq!(
+            state.get_client_proof_specs(&client_id).await?,
+            client_state.proof_specs
+        );
+
+        // Updating the client replaces its cached proof specs.
+        client_state.proof_specs.truncate(1);
+        state.put_client(&client_id, client_state.clone());
+        assert_eq!(
+            state.get_client_proof_specs(&client_id).await?,
+            client_state.proof_specs
+        );
+
+        // Without cached proof specs, they're read from the client state.
+        state.nonverifiable_delete(state_key::client_proof_specs(&client_id).into_bytes());
+        assert_eq!(
+            state.get_client_proof_specs(&client_id).await?,
+            client_state.proof_specs
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn frozen_clients_can_be_recovered() -> anyhow::Result<()> {
+        let mut state = Arc::new(StateDelta::new(()));
+        let timestamp = Time::parse_from_rfc3339("2022-02-11T17:30:50.425417198Z")?;
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.put_block_timestamp(1u64, timestamp);
+        state_tx.put_block_height(1);
+        state_tx.apply();
+
+        let msg_create_client_stargaze_raw = BASE64_STANDARD
+            .decode(include_str!("./test/create_client.msg").replace('\n', ""))
+            .unwrap();
+        let msg_create_stargaze_client =
+            MsgCreateClient::decode(msg_create_client_stargaze_raw.as_slice()).unwrap();
+        let client_state = crate::component::ics02_validation::get_tendermint_client_state(
+            msg_create_stargaze_client.client_state,
+        )?;
+        let consensus_state = TendermintConsensusState::new(
+            ibc_types::core::commitment::MerkleRoot { hash: vec![1; 32] },
+            timestamp,
+            tendermint::Hash::None,
+        );
+
+        let subject_client_id = ClientId::from_str("07-tendermint-0")?;
+        let subject_client_state = client_state.clone().with_frozen_height(Height {
+            revision_number: 0,
+            revision_height: 1,
+        });
+        let substitute_client_id = ClientId::from_str("07-tendermint-1")?;
+        let mut substitute_client_state = client_state.clone();
+        substitute_client_state.latest_height = Height {
+            revision_number: client_state.latest_height.revision_number,
+            revision_height: client_state.latest_height.revision_height + 100,
+        };
+
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.put_client(&subject_client_id, subject_client_state.clone());
+        state_tx
+            .put_verified_consensus_state::<MockHost>(
+                client_state.latest_height(),
+                subject_client_id.clone(),
+                consensus_state.clone(),
+            )
+            .await?;
+        state_tx.put_client(&substitute_client_id, substitute_client_state.clone());
+        state_tx
+            .put_verified_consensus_state::<MockHost>(
+                substitute_client_state.latest_height(),
+                substitute_client_id.clone(),
+                consensus_state.clone(),
+            )
+            .await?;
+        state_tx.apply();
+
+        // an active client can't be recovered.
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx
+            .recover_client(&substitute_client_id, &subject_client_id, timestamp)
+            .await
+            .expect_err("active clients can't be recovered");
+
+        state_tx
+            .recover_client(&subject_client_id, &substitute_client_id, timestamp)
+            .await?;
+        state_tx.apply();
+
+        let recovered_client_state = state.get_client_state(&subject_client_id).await?;
+        assert!(!recovered_client_state.is_frozen());
+        assert_eq!(
+            recovered_client_state.latest_height(),
+            substitute_client_state.latest_height()
+        );
+        assert_eq!(
+            state
+                .get_verified_consensus_state(
+                    &substitute_client_state.latest_height(),
+                    &subject_client_id
+                )
+                .await?,
+            consensus_state
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn client_status_is_reported() -> anyhow::Result<()> {
+        let mut state = StateDelta::new(());
+        let timestamp = Time::parse_from_rfc3339("2022-02-11T17:30:50.425417198Z")?;
+        state.put_block_timestamp(1u64, timestamp);
+        state.put_block_height(1);
+
+        let msg_create_client_stargaze_raw = BASE64_STANDARD
+            .decode(include_str!("./test/create_client.msg").replace('\n', ""))
+            .unwrap();
+        let msg_create_stargaze_client =
+            MsgCreateClient::decode(msg_create_client_stargaze_raw.as_slice()).unwrap();
+        let client_state = crate::component::ics02_validation::get_tendermint_client_state(
+            msg_create_stargaze_client.client_state,
+        )?;
+        let consensus_state = TendermintConsensusState::new(
+            ibc_types::core::commitment::MerkleRoot { hash: vec![1; 32] },
+            timestamp,
+            tendermint::Hash::None,
+        );
+
+        let client_id = ClientId::from_str("07-tendermint-0")?;
+        assert_eq!(
+            state.client_status::<MockHost>(&client_id).await,
+            ClientStatus::Unknown
+        );
+
+        state.put_client(&client_id, client_state.clone());
+        state
+            .put_verified_consensus_state::<MockHost>(
+                client_state.latest_height(),
+                client_id.clone(),
+                consensus_state,
+            )
+            .await?;
+        assert_eq!(
+            state.client_status::<MockHost>(&client_id).await,
+            ClientStatus::Active
+        );
+
+        // once the trusting period has elapsed, the client expires.
+        let after_trusting_period =
+            ((timestamp + client_state.trusting_period)? + std::time::Duration::from_secs(1))?;
+        state.put_block_timestamp(2u64, after_trusting_period);
+        state.put_block_height(2);
+        assert_eq!(
+            state.client_status::<MockHost>(&client_id).await,
+            ClientStatus::Expired
+        );
+
+        state.put_client(
+            &client_id,
+            client_state.with_frozen_height(Height {
+                revision_number: 0,
+                revision_height: 1,
+            }),
+        );
+        assert_eq!(
+            state.client_status::<MockHost>(&client_id).await,
+            ClientStatus::Frozen
+        );
+
+        // clients of a type the host doesn't allow are unauthorized.
+        let solo_machine_client_id = ClientId::from_str("06-solomachine-0")?;
+        state.put_proto(
+            IBC_COMMITMENT_PREFIX
+                .apply_string(ClientTypePath(solo_machine_client_id.clone()).to_string()),
+            "06-solomachine".to_string(),
+        );
+        assert_eq!(
+            state
+                .client_status::<MockHost>(&solo_machine_client_id)
+                .await,
+            ClientStatus::Unauthorized
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn substitutes_must_match_the_subject() -> anyhow::Result<()> {
+        let msg_create_client_stargaze_raw = BASE64_STANDARD
+            .decode(include_str!("./test/create_client.msg").replace('\n', ""))
+            .unwrap();
+        let msg_create_stargaze_client =
+            MsgCreateClient::decode(msg_create_client_stargaze_raw.as_slice()).unwrap();
+        let subject = crate::component::ics02_validation::get_tendermint_client_state(
+            msg_create_stargaze_client.client_state,
+        )?;
+
+        let mut substitute = subject.clone();
+        substitute.trusting_period += std::time::Duration::from_secs(60);
+        assert!(is_matching_client_state(&subject, &substitute));
+
+        substitute.unbonding_period += std::time::Duration::from_secs(60);
+        assert!(!is_matching_client_state(&subject, &substitute));
+
+        Ok(())
+    }
 }
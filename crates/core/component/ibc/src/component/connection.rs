@@ -1,6 +1,9 @@
+use std::time::Duration;
+
 use anyhow::Result;
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
+use futures::{StreamExt as _, TryStreamExt as _};
 use ibc_types::{
     core::{
         client::ClientId,
@@ -11,7 +14,8 @@ use ibc_types::{
 };
 use penumbra_proto::{StateReadProto, StateWriteProto};
 
-use crate::{prefix::MerklePrefixExt, IBC_COMMITMENT_PREFIX};
+use crate::connection_metadata::{ConnectionMetadata, IdentifiedConnectionMetadata};
+use crate::{prefix::MerklePrefixExt, CounterpartyPrefix, IBC_COMMITMENT_PREFIX};
 
 use super::{connection_counter::ConnectionCounter, state_key};
 
@@ -58,6 +62,20 @@ pub trait StateWriteExt: StateWrite {
             connection.clone(),
         );
     }
+
+    /// Registers counterparty chain information for a connection, or removes
+    /// the information registered for it if `metadata` is `None`.
+    fn put_connection_metadata(
+        &mut self,
+        connection_id: &ConnectionId,
+        metadata: Option<ConnectionMetadata>,
+    ) {
+        let key = state_key::connection_metadata::by_connection_id(connection_id);
+        match metadata {
+            Some(metadata) => self.put(key, metadata),
+            None => self.delete(key),
+        }
+    }
 }
 
 impl<T: StateWrite + ?Sized> StateWriteExt for T {}
@@ -84,6 +102,182 @@ pub trait StateReadExt: StateRead {
         .await
         .map(|paths| paths.unwrap_or(ClientPaths { paths: vec![] }))
     }
+
+    /// Gets the maximum consensus state age registered for a connection, if any.
+    async fn get_connection_max_consensus_age(
+        &self,
+        connection_id: &ConnectionId,
+    ) -> Result<Option<Duration>> {
+        Ok(self
+            .get_connection_metadata(connection_id)
+            .await?
+            .and_then(|metadata| metadata.max_consensus_age))
+    }
+
+    /// Gets the prefix that proofs relayed over a connection are verified
+    /// against: the key path registered in the connection's metadata, if any,
+    /// or else the connection's counterparty commitment prefix as a single key.
+    async fn get_counterparty_prefix(
+        &self,
+        connection_id: &ConnectionId,
+        connection: &ConnectionEnd,
+    ) -> Result<CounterpartyPrefix> {
+        Ok(match self.get_connection_metadata(connection_id).await? {
+            Some(metadata) => metadata.counterparty_prefix(&connection.counterparty.prefix),
+            None => CounterpartyPrefix::Single(connection.counterparty.prefix.clone()),
+        })
+    }
+
+    /// Gets the counterparty chain information registered for a connection, if any.
+    async fn get_connection_metadata(
+        &self,
+        connection_id: &ConnectionId,
+    ) -> Result<Option<ConnectionMetadata>> {
+        self.get(&state_key::connection_metadata::by_connection_id(
+            connection_id,
+        ))
+        .await
+    }
+
+    /// Gets the counterparty chain information registered for every connection
+    /// that has any.
+    async fn connection_metadata(&self) -> Result<Vec<IdentifiedConnectionMetadata>> {
+        self.prefix(state_key::connection_metadata::prefix())
+            .map(|result| {
+                let (key, metadata) = result?;
+                let connection_id = key
+                    .rsplit('/')
+                    .next()
+                    .expect("key is well-formed")
+                    .parse::<ConnectionId>()
+                    .map_err(|e| anyhow::anyhow!("invalid connection id: {e}"))?;
+                Ok(IdentifiedConnectionMetadata {
+                    connection_id,
+                    metadata,
+                })
+            })
+            .try_collect()
+            .await
+    }
 }
 
 impl<T: StateRead + ?Sized> StateReadExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use cnidarium::StateDelta;
+    use ibc_types::core::{
+        channel::ChannelId,
+        commitment::MerklePrefix,
+        connection::{Counterparty, State},
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn connection_metadata_is_registered_and_removed() -> Result<()> {
+        let mut state = StateDelta::new(());
+        let metadata = ConnectionMetadata {
+            counterparty_chain_id: "osmosis-1".to_string(),
+            display_name: "Osmosis".to_string(),
+            preferred_channels: vec![ChannelId::new(17)],
+            max_consensus_age: Some(Duration::from_secs(3600)),
+            counterparty_prefix_path: vec![],
+        };
+
+        state.put_connection_metadata(&ConnectionId::new(0), Some(metadata.clone()));
+        state.put_connection_metadata(&ConnectionId::new(2), Some(metadata.clone()));
+        assert_eq!(
+            state.get_connection_metadata(&ConnectionId::new(0)).await?,
+            Some(metadata.clone())
+        );
+        assert_eq!(
+            state.get_connection_metadata(&ConnectionId::new(1)).await?,
+            None
+        );
+        assert_eq!(
+            state
+                .get_connection_max_consensus_age(&ConnectionId::new(0))
+                .await?,
+            Some(Duration::from_secs(3600))
+        );
+        assert_eq!(
+            state
+                .connection_metadata()
+                .await?
+                .into_iter()
+                .map(|identified| identified.connection_id)
+                .collect::<Vec<_>>(),
+            vec![ConnectionId::new(0), ConnectionId::new(2)]
+        );
+
+        state.put_connection_metadata(&ConnectionId::new(0), None);
+        assert_eq!(
+            state.get_connection_metadata(&ConnectionId::new(0)).await?,
+            None
+        );
+        assert_eq!(state.connection_metadata().await?.len(), 1);
+        assert_eq!(
+            state
+                .get_connection_max_consensus_age(&ConnectionId::new(0))
+                .await?,
+            None
+        );
+
+        Ok(())
+    }
+    #[tokio::test]
+    async fn registered_prefix_paths_replace_the_commitment_prefix() -> Result<()> {
+        let mut state = StateDelta::new(());
+        let connection_id = ConnectionId::new(0);
+        let commitment_prefix = MerklePrefix {
+            key_prefix: b"rollup/ibc".to_vec(),
+        };
+        let connection = ConnectionEnd {
+            state: State::Open,
+            client_id: ClientId::from_str("07-tendermint-0")?,
+            counterparty: Counterparty {
+                client_id: ClientId::from_str("07-tendermint-0")?,
+                connection_id: Some(ConnectionId::new(0)),
+                prefix: commitment_prefix.clone(),
+            },
+            versions: vec![],
+            delay_period: Duration::ZERO,
+        };
+        let mut metadata = ConnectionMetadata {
+            counterparty_chain_id: "rollup-1".to_string(),
+            display_name: "Rollup".to_string(),
+            preferred_channels: vec![],
+            max_consensus_age: None,
+            counterparty_prefix_path: vec![],
+        };
+
+        // Without a registered key path, the commitment prefix is a single key.
+        assert_eq!(
+            state
+                .get_counterparty_prefix(&connection_id, &connection)
+                .await?,
+            CounterpartyPrefix::Single(commitment_prefix.clone())
+        );
+        state.put_connection_metadata(&connection_id, Some(metadata.clone()));
+        assert_eq!(
+            state
+                .get_counterparty_prefix(&connection_id, &connection)
+                .await?,
+            CounterpartyPrefix::Single(commitment_prefix)
+        );
+
+        metadata.counterparty_prefix_path = vec![b"rollup".to_vec(), b"ibc".to_vec()];
+        state.put_connection_metadata(&connection_id, Some(metadata));
+        assert_eq!(
+            state
+                .get_counterparty_prefix(&connection_id, &connection)
+                .await?,
+            CounterpartyPrefix::KeyPath(vec![b"rollup".to_vec(), b"ibc".to_vec()])
+        );
+
+        Ok(())
+    }
+}
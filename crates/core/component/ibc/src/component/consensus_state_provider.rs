@@ -0,0 +1,126 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use ibc_types::core::client::{ClientId, Height};
+use ibc_types::lightclients::tendermint::consensus_state::ConsensusState as TendermintConsensusState;
+
+/// A source of counterparty consensus states that are no longer (or were never)
+/// in the local state, such as an archive node or an external store.
+///
+/// Providers are consulted by
+/// [`get_verified_consensus_state_with_provider`](super::ClientStateReadExt::get_verified_consensus_state_with_provider)
+/// only when the local state has no consensus state at the requested height.
+///
+/// The provider is trusted: a consensus state it returns is only checked for
+/// being well-formed, not for having been verified by a client update, so it
+/// must only be backed by a source the operator trusts as much as its own state.
+/// It must never be used for verification that affects consensus.
+#[async_trait]
+pub trait ConsensusStateProvider: Send + Sync {
+    /// Returns the consensus state of `client_id` at `height`, if available.
+    async fn get_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<TendermintConsensusState>>;
+}
+
+/// A [`ConsensusStateProvider`] that never has any consensus states, so only
+/// the local state is used.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoConsensusStateProvider;
+
+#[async_trait]
+impl ConsensusStateProvider for NoConsensusStateProvider {
+    async fn get_consensus_state(
+        &self,
+        _client_id: &ClientId,
+        _height: &Height,
+    ) -> Result<Option<TendermintConsensusState>> {
+        Ok(None)
+    }
+}
+
+/// Checks that a consensus state obtained from a [`ConsensusStateProvider`]
+/// is well-formed before it is used for verification.
+pub(crate) fn validate_provided_consensus_state(
+    consensus_state: &TendermintConsensusState,
+) -> Result<()> {
+    if consensus_state.root.hash.is_empty() || consensus_state.root.hash.iter().all(|b| *b == 0) {
+        anyhow::bail!("provided consensus state has an empty commitment root");
+    }
+
+    if consensus_state.timestamp <= tendermint::Time::unix_epoch() {
+        anyhow::bail!(
+            "provided consensus state has an invalid timestamp {}",
+            consensus_state.timestamp
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use cnidarium::StateDelta;
+    use ibc_types::core::commitment::MerkleRoot;
+
+    use super::*;
+    use crate::component::ClientStateReadExt as _;
+
+    struct FixedProvider(TendermintConsensusState);
+
+    #[async_trait]
+    impl ConsensusStateProvider for FixedProvider {
+        async fn get_consensus_state(
+            &self,
+            _client_id: &ClientId,
+            _height: &Height,
+        ) -> Result<Option<TendermintConsensusState>> {
+            Ok(Some(self.0.clone()))
+        }
+    }
+
+    fn consensus_state(root: Vec<u8>) -> TendermintConsensusState {
+        TendermintConsensusState::new(
+            MerkleRoot { hash: root },
+            tendermint::Time::from_unix_timestamp(1_700_000_000, 0).unwrap(),
+            tendermint::Hash::None,
+        )
+    }
+
+    #[tokio::test]
+    async fn provider_is_consulted_for_missing_consensus_states() -> anyhow::Result<()> {
+        let state = StateDelta::new(());
+        let client_id = ClientId::from_str("07-tendermint-0")?;
+        let height = Height::new(1, 10)?;
+
+        assert!(state
+            .get_verified_consensus_state(&height, &client_id)
+            .await
+            .is_err());
+
+        let provided = consensus_state(vec![1; 32]);
+        let found = state
+            .get_verified_consensus_state_with_provider(
+                &height,
+                &client_id,
+                &FixedProvider(provided.clone()),
+            )
+            .await?;
+        assert_eq!(found, provided);
+
+        // Malformed consensus states from the provider are not used.
+        assert!(state
+            .get_verified_consensus_state_with_provider(
+                &height,
+                &client_id,
+                &FixedProvider(consensus_state(vec![0; 32])),
+            )
+            .await
+            .is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+//! In-memory caches of decoded client and consensus states.
+//!
+//! Every proof verified against a client reads its client state and one of its
+//! consensus states, and decoding them costs more than reading them. On a busy
+//! channel, the same few states are decoded for every packet in a block.
+//!
+//! A [`DecodedCache`] keeps the most recently used decoded values, keyed by a
+//! digest of the encoding they were decoded from. Since a node reads several
+//! versions of the state at once (e.g. when checking transactions for the
+//! mempool while executing a block), the cache is only consulted after the
+//! encoding has been read from the state being verified against, so it can
+//! never serve a stale value: once a client is updated, its new states simply
+//! miss the cache, and the old ones are evicted as they fall out of use.
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use anyhow::Result;
+use sha2::{Digest, Sha256};
+
+/// A least-recently-used cache of values decoded from their encodings.
+pub(crate) struct DecodedCache<V> {
+    capacity: usize,
+    /// The number of lookups so far, used to order entries by their last use.
+    clock: u64,
+    entries: BTreeMap<[u8; 32], (V, u64)>,
+    /// The digests of the cached encodings, keyed by when they were last used.
+    by_last_use: BTreeMap<u64, [u8; 32]>,
+}
+
+impl<V: Clone> DecodedCache<V> {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            clock: 0,
+            entries: BTreeMap::new(),
+            by_last_use: BTreeMap::new(),
+        }
+    }
+
+    fn touch(&mut self, digest: [u8; 32]) -> u64 {
+        self.clock += 1;
+        self.by_last_use.insert(self.clock, digest);
+        self.clock
+    }
+
+    /// Gets the value decoded from `encoded`, if it's cached.
+    pub(crate) fn get(&mut self, encoded: &[u8]) -> Option<V> {
+        let digest: [u8; 32] = Sha256::digest(encoded).into();
+        let last_use = self.entries.get(&digest)?.1;
+        self.by_last_use.remove(&last_use);
+
+        let now = self.touch(digest);
+        let (value, last_use) = self.entries.get_mut(&digest)?;
+        *last_use = now;
+        Some(value.clone())
+    }
+
+    /// Caches the value decoded from `encoded`, evicting the least recently
+    /// used value if the cache is full.
+    pub(crate) fn insert(&mut self, encoded: &[u8], value: V) {
+        let digest: [u8; 32] = Sha256::digest(encoded).into();
+        if let Some((_, last_use)) = self.entries.remove(&digest) {
+            self.by_last_use.remove(&last_use);
+        }
+
+        while self.entries.len() >= self.capacity {
+            let Some((_, evicted)) = self.by_last_use.pop_first() else {
+                break;
+            };
+            self.entries.remove(&evicted);
+        }
+
+        let now = self.touch(digest);
+        self.entries.insert(digest, (value, now));
+    }
+}
+
+/// Decodes `encoded` with `decode`, reusing the value cached in `cache` if the
+/// same encoding was decoded recently.
+pub(crate) fn decode_with<V: Clone>(
+    cache: &Mutex<DecodedCache<V>>,
+    encoded: &[u8],
+    decode: impl FnOnce(&[u8]) -> Result<V>,
+) -> Result<V> {
+    if let Some(value) = cache
+        .lock()
+        .expect("decoded cache lock is not poisoned")
+        .get(encoded)
+    {
+        return Ok(value);
+    }
+
+    let value = decode(encoded)?;
+    cache
+        .lock()
+        .expect("decoded cache lock is not poisoned")
+        .insert(encoded, value.clone());
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode_utf8(encoded: &[u8]) -> Result<String> {
+        Ok(String::from_utf8(encoded.to_vec())?)
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let cache = Mutex::new(DecodedCache::new(2));
+        decode_with(&cache, b"a", decode_utf8).unwrap();
+        decode_with(&cache, b"b", decode_utf8).unwrap();
+
+        // using "a" again makes "b" the least recently used
+        assert_eq!(
+            decode_with(&cache, b"a", |_| anyhow::bail!("a should be cached")).unwrap(),
+            "a"
+        );
+        decode_with(&cache, b"c", decode_utf8).unwrap();
+
+        let mut cache = cache.into_inner().unwrap();
+        assert_eq!(cache.get(b"a"), Some("a".to_string()));
+        assert_eq!(cache.get(b"b"), None);
+        assert_eq!(cache.get(b"c"), Some("c".to_string()));
+    }
+
+    #[test]
+    fn decoding_failures_are_not_cached() {
+        let cache = Mutex::new(DecodedCache::new(2));
+        assert!(decode_with(&cache, &[0xff], decode_utf8).is_err());
+        assert_eq!(cache.lock().unwrap().get(&[0xff]), None);
+    }
+}
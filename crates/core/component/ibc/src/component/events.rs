@@ -0,0 +1,117 @@
+//! IBC events that have no counterpart in `ibc-types`.
+//!
+//! Relayers that source their view of the chain from events, such as Hermes in
+//! event-sourcing mode, key off ibc-go's event types and attributes, so these
+//! events use the same names.
+//!
+//! See: https://github.com/cosmos/ibc-go/blob/main/modules/core/04-channel/keeper/events.go
+
+use ibc_types::core::channel::{ChannelEnd, ChannelId, PortId};
+use ibc_types::core::client::{ClientId, ClientType};
+use penumbra_asset::Value;
+use tendermint::abci::{Event, EventAttribute};
+
+use crate::packet_fee::Fee;
+
+fn event(kind: &str, attributes: Vec<(&str, String)>) -> Event {
+    Event::new(
+        kind,
+        attributes
+            .into_iter()
+            .map(|(key, value)| EventAttribute {
+                key: key.to_string(),
+                value,
+                index: true,
+            })
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// The attributes identifying a channel and its counterparty.
+fn channel_attributes(
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    channel: &ChannelEnd,
+) -> Vec<(&'static str, String)> {
+    vec![
+        ("port_id", port_id.to_string()),
+        ("channel_id", channel_id.to_string()),
+        (
+            "counterparty_port_id",
+            channel.counterparty().port_id.to_string(),
+        ),
+        (
+            "counterparty_channel_id",
+            channel
+                .counterparty()
+                .channel_id
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+        ),
+    ]
+}
+
+fn fee_string(value: &Value) -> String {
+    format!("{}{}", value.amount, value.asset_id)
+}
+
+/// An ordered channel was closed because one of its packets timed out.
+pub(crate) fn channel_closed(
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    channel: &ChannelEnd,
+) -> Event {
+    let mut attributes = channel_attributes(port_id, channel_id, channel);
+    attributes.extend([
+        (
+            "connection_id",
+            channel
+                .connection_hops
+                .first()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+        ),
+        ("channel_ordering", channel.ordering.as_str().to_string()),
+    ]);
+    event("channel_close", attributes)
+}
+
+/// A frozen or expired client was recovered from a substitute.
+pub(crate) fn recover_client(subject_client_id: &ClientId, client_type: &ClientType) -> Event {
+    event(
+        "recover_client",
+        vec![
+            ("subject_client_id", subject_client_id.to_string()),
+            ("client_type", client_type.as_str().to_string()),
+        ],
+    )
+}
+
+/// Fees were escrowed for relaying a packet.
+pub(crate) fn incentivized_packet(
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    sequence: u64,
+    fee: &Fee,
+) -> Event {
+    event(
+        "incentivized_ibc_packet",
+        vec![
+            ("port_id", port_id.to_string()),
+            ("channel_id", channel_id.to_string()),
+            ("packet_sequence", sequence.to_string()),
+            ("recv_fee", fee_string(&fee.recv_fee)),
+            ("ack_fee", fee_string(&fee.ack_fee)),
+            ("timeout_fee", fee_string(&fee.timeout_fee)),
+        ],
+    )
+}
+
+/// An escrowed fee was paid to a relayer, or refunded.
+pub(crate) fn distribute_fee(receiver: &str, fee: &Value) -> Event {
+    event(
+        "distribute_fee",
+        vec![("receiver", receiver.to_string()), ("fee", fee_string(fee))],
+    )
+}
@@ -0,0 +1,731 @@
+//! ICS-29 relayer fee middleware.
+//!
+//! Channels negotiate fee support by wrapping the underlying application's
+//! version in a [`FeeMetadata`] JSON object. On fee-enabled channels, users
+//! may escrow a [`Fee`](crate::packet_fee::Fee) alongside each packet they send, which is paid out to
+//! the relayers once the packet is acknowledged or times out:
+//!
+//! - on acknowledgement, the receive fee goes to the forward relayer named in
+//!   the [`IncentivizedAcknowledgement`], the acknowledgement fee goes to the
+//!   relayer of the acknowledgement, and the timeout fee is refunded;
+//! - on timeout, the timeout fee goes to the relayer of the timeout, and the
+//!   receive and acknowledgement fees are refunded.
+//!
+//! Fees that can't be paid, e.g. because a relayer's address isn't a valid
+//! Penumbra address, are refunded instead.
+//!
+//! See: https://github.com/cosmos/ibc/tree/main/spec/app/ics-029-fee-payment
+
+use std::marker::PhantomData;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use cnidarium::{StateDelta, StateRead, StateWrite};
+use futures::{StreamExt, TryStreamExt};
+use ibc_types::core::channel::{
+    msgs::{
+        MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
+        MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket, MsgTimeout,
+    },
+    ChannelId, PortId, Version,
+};
+use penumbra_asset::Value;
+use penumbra_num::Amount;
+use penumbra_proto::{StateReadProto, StateWriteProto};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    app_handler::{AcknowledgementResult, AppHandler, AppHandlerCheck, AppHandlerExecute},
+    base64_bytes,
+    channel::StateReadExt as _,
+    events,
+    proof_verification::VerificationError,
+    state_key,
+};
+use crate::packet_fee::{IdentifiedPacketFee, PacketFee};
+
+/// The ICS-29 version supported by the middleware.
+pub const FEE_VERSION: &str = "ics29-1";
+
+/// The version of a fee-enabled channel, in the JSON encoding used by ibc-go.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeMetadata {
+    pub fee_version: String,
+    /// The version of the application the fees are paid for.
+    pub app_version: String,
+}
+
+impl FeeMetadata {
+    /// Parses the fee metadata wrapped around `version`, returning `None` if
+    /// the version isn't fee-wrapped.
+    pub fn from_version(version: &Version) -> Option<Self> {
+        serde_json::from_str(&version.to_string()).ok()
+    }
+
+    pub fn to_version(&self) -> Version {
+        Version::new(serde_json::to_string(self).expect("fee metadata serializes to JSON"))
+    }
+}
+
+/// Returns whether `version` is the version of a fee-enabled channel.
+pub fn is_fee_enabled(version: &Version) -> bool {
+    FeeMetadata::from_version(version).is_some()
+}
+
+/// Splits a proposed channel version into whether it enables fees, and the
+/// version of the underlying application.
+fn split_version(version: &Version) -> Result<(bool, Version)> {
+    match FeeMetadata::from_version(version) {
+        Some(metadata) => {
+            if metadata.fee_version != FEE_VERSION {
+                anyhow::bail!("unsupported ICS-29 version {}", metadata.fee_version);
+            }
+            Ok((true, Version::new(metadata.app_version)))
+        }
+        None => Ok((false, version.clone())),
+    }
+}
+
+/// The acknowledgement written for packets received over fee-enabled
+/// channels, in the JSON encoding used by ibc-go.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IncentivizedAcknowledgement {
+    /// The acknowledgement written by the underlying application.
+    #[serde(with = "base64_bytes")]
+    pub app_acknowledgement: Vec<u8>,
+    /// The address, on the packet's source chain, of the relayer that relayed
+    /// the packet.
+    pub forward_relayer_address: String,
+    pub underlying_app_success: bool,
+}
+
+impl IncentivizedAcknowledgement {
+    /// Wraps the acknowledgement written by the underlying application.
+    pub fn new(app_acknowledgement: Vec<u8>, forward_relayer_address: String) -> Self {
+        // Applications signal failure with an `error` field, following the
+        // acknowledgement envelope of ICS-04.
+        let underlying_app_success =
+            serde_json::from_slice::<serde_json::Value>(&app_acknowledgement)
+                .map(|ack| ack.get("error").is_none())
+                .unwrap_or(true);
+
+        Self {
+            app_acknowledgement,
+            forward_relayer_address,
+            underlying_app_success,
+        }
+    }
+}
+
+impl From<IncentivizedAcknowledgement> for Vec<u8> {
+    fn from(ack: IncentivizedAcknowledgement) -> Self {
+        serde_json::to_vec(&ack).expect("acknowledgements serialize to JSON")
+    }
+}
+
+#[async_trait]
+pub trait StateReadExt: StateRead {
+    /// Gets the fees escrowed for the packet with the given sequence number.
+    async fn get_packet_fee(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: u64,
+    ) -> Result<Option<PacketFee>> {
+        self.get(&state_key::fees::escrow(port_id, channel_id, sequence))
+            .await
+    }
+
+    /// Gets the fees escrowed for all unrelayed packets sent over a channel,
+    /// in sequence order.
+    async fn incentivized_packets(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Vec<IdentifiedPacketFee>> {
+        let prefix = state_key::fees::escrow_prefix(port_id, channel_id);
+        self.prefix(&prefix)
+            .map(|result| {
+                let (key, packet_fee) = result?;
+                let sequence = key
+                    .rsplit('/')
+                    .next()
+                    .expect("key is well-formed")
+                    .parse()?;
+                Ok(IdentifiedPacketFee {
+                    port_id: port_id.clone(),
+                    channel_id: channel_id.clone(),
+                    sequence,
+                    packet_fee,
+                })
+            })
+            .try_collect()
+            .await
+    }
+}
+
+impl<T: StateRead + ?Sized> StateReadExt for T {}
+
+#[async_trait]
+pub trait StateWriteExt: StateWrite {
+    /// Records the fees escrowed for the packet with the given sequence number.
+    fn put_packet_fee(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: u64,
+        packet_fee: PacketFee,
+    ) {
+        self.record(events::incentivized_packet(
+            port_id,
+            channel_id,
+            sequence,
+            &packet_fee.fee,
+        ));
+        self.put(
+            state_key::fees::escrow(port_id, channel_id, sequence),
+            packet_fee,
+        );
+    }
+
+    fn delete_packet_fee(&mut self, port_id: &PortId, channel_id: &ChannelId, sequence: u64) {
+        self.delete(state_key::fees::escrow(port_id, channel_id, sequence));
+    }
+}
+
+impl<T: StateWrite + ?Sized> StateWriteExt for T {}
+
+/// Pays escrowed fees out of the fee middleware.
+#[async_trait]
+pub trait FeePayout: Send + Sync {
+    /// Pays `value` to the Penumbra address `recipient`, failing without
+    /// modifying `state` if the recipient can't be paid.
+    async fn pay<S: StateWrite>(state: S, recipient: &str, value: Value) -> Result<()>;
+}
+
+/// Pays `value` to `recipient`, or refunds it to `refund_address` if that
+/// fails.
+async fn pay_or_refund<S: StateWrite, P: FeePayout>(
+    mut state: S,
+    recipient: &str,
+    value: Value,
+    refund_address: &str,
+) -> Result<()> {
+    if value.amount == Amount::zero() {
+        return Ok(());
+    }
+
+    let mut payment = StateDelta::new(&mut state);
+    match P::pay(&mut payment, recipient, value).await {
+        Ok(()) => {
+            let (state, payment_events) = payment.apply();
+            for event in payment_events {
+                state.record(event);
+            }
+            state.record(events::distribute_fee(recipient, &value));
+            Ok(())
+        }
+        Err(e) => {
+            drop(payment);
+            tracing::debug!(%recipient, "couldn't pay relayer fee, refunding: {:#}", e);
+            P::pay(&mut state, refund_address, value)
+                .await
+                .context("couldn't refund relayer fee")?;
+            state.record(events::distribute_fee(refund_address, &value));
+            Ok(())
+        }
+    }
+}
+
+/// An app handler that adds ICS-29 fee payments to the channels of `AH`,
+/// paying fees out with `P`.
+pub struct WithFees<AH, P> {
+    _marker: PhantomData<(AH, P)>,
+}
+
+impl<AH, P> WithFees<AH, P> {
+    async fn channel_is_fee_enabled<S: StateRead>(
+        state: &S,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+    ) -> Result<bool> {
+        let channel = state
+            .get_channel(channel_id, port_id)
+            .await?
+            .ok_or_else(|| {
+                anyhow::anyhow!("channel {} on port {} not found", channel_id, port_id)
+            })?;
+        Ok(is_fee_enabled(&channel.version))
+    }
+
+    /// Unwraps the acknowledgement of a packet sent over a fee-enabled
+    /// channel, returning the message the underlying application expects
+    /// along with the incentivized acknowledgement, if there is one.
+    async fn unwrap_acknowledgement<S: StateRead>(
+        state: &S,
+        msg: &MsgAcknowledgement,
+    ) -> Result<(MsgAcknowledgement, Option<IncentivizedAcknowledgement>)> {
+        if !Self::channel_is_fee_enabled(state, &msg.packet.chan_on_a, &msg.packet.port_on_a)
+            .await?
+        {
+            return Ok((msg.clone(), None));
+        }
+
+        let ack: IncentivizedAcknowledgement = serde_json::from_slice(&msg.acknowledgement)
+            .context("failed to decode incentivized acknowledgement")?;
+        let mut inner = msg.clone();
+        inner.acknowledgement = ack.app_acknowledgement.clone();
+        Ok((inner, Some(ack)))
+    }
+}
+
+#[async_trait]
+impl<AH: AppHandlerCheck, P: FeePayout> AppHandlerCheck for WithFees<AH, P> {
+    fn is_bound_port(port_id: &PortId) -> bool {
+        AH::is_bound_port(port_id)
+    }
+
+    async fn chan_open_try_version<S: StateRead>(
+        state: S,
+        msg: &MsgChannelOpenTry,
+    ) -> Result<Version> {
+        let (fee_enabled, app_version) = split_version(&msg.version_supported_on_a)?;
+        if !fee_enabled {
+            return AH::chan_open_try_version(state, msg).await;
+        }
+
+        let mut inner = msg.clone();
+        inner.version_supported_on_a = app_version;
+        let app_version = AH::chan_open_try_version(state, &inner).await?;
+        Ok(FeeMetadata {
+            fee_version: FEE_VERSION.to_string(),
+            app_version: app_version.to_string(),
+        }
+        .to_version())
+    }
+
+    fn pending_ack_sentinel(port_id: &PortId, version: &Version) -> Option<Vec<u8>> {
+        let (_, app_version) = split_version(version).ok()?;
+        AH::pending_ack_sentinel(port_id, &app_version)
+    }
+
+    async fn chan_open_init_check<S: StateRead>(state: S, msg: &MsgChannelOpenInit) -> Result<()> {
+        let mut inner = msg.clone();
+        inner.version_proposal = split_version(&msg.version_proposal)?.1;
+        AH::chan_open_init_check(state, &inner).await
+    }
+
+    async fn chan_open_try_check<S: StateRead>(state: S, msg: &MsgChannelOpenTry) -> Result<()> {
+        let mut inner = msg.clone();
+        inner.version_supported_on_a = split_version(&msg.version_supported_on_a)?.1;
+        AH::chan_open_try_check(state, &inner).await
+    }
+
+    async fn chan_open_ack_check<S: StateRead>(state: S, msg: &MsgChannelOpenAck) -> Result<()> {
+        let proposed_fees =
+            Self::channel_is_fee_enabled(&state, &msg.chan_id_on_a, &msg.port_id_on_a).await?;
+        let mut inner = msg.clone();
+        let (fee_enabled, app_version) = split_version(&msg.version_on_b)?;
+        if proposed_fees != fee_enabled {
+            anyhow::bail!(
+                "counterparty version {} does not match the proposed fee support",
+                msg.version_on_b
+            );
+        }
+        inner.version_on_b = app_version;
+        AH::chan_open_ack_check(state, &inner).await
+    }
+
+    async fn chan_open_confirm_check<S: StateRead>(
+        state: S,
+        msg: &MsgChannelOpenConfirm,
+    ) -> Result<()> {
+        AH::chan_open_confirm_check(state, msg).await
+    }
+
+    async fn chan_close_confirm_check<S: StateRead>(
+        state: S,
+        msg: &MsgChannelCloseConfirm,
+    ) -> Result<()> {
+        AH::chan_close_confirm_check(state, msg).await
+    }
+
+    async fn chan_close_init_check<S: StateRead>(
+        state: S,
+        msg: &MsgChannelCloseInit,
+    ) -> Result<()> {
+        AH::chan_close_init_check(state, msg).await
+    }
+
+    async fn recv_packet_check<S: StateRead>(state: S, msg: &MsgRecvPacket) -> Result<()> {
+        AH::recv_packet_check(state, msg).await
+    }
+
+    async fn timeout_packet_check<S: StateRead>(state: S, msg: &MsgTimeout) -> Result<()> {
+        AH::timeout_packet_check(state, msg).await
+    }
+
+    async fn acknowledge_packet_check<S: StateRead>(
+        state: S,
+        msg: &MsgAcknowledgement,
+    ) -> Result<()> {
+        let (inner, ack) = Self::unwrap_acknowledgement(&state, msg).await?;
+        // The counterparty wraps its application's pending-acknowledgement
+        // sentinel like any other acknowledgement, so only the unwrapped
+        // acknowledgement can be compared with it.
+        if ack.is_some()
+            && state
+                .is_pending_ack(
+                    &msg.packet.chan_on_a,
+                    &msg.packet.port_on_a,
+                    &inner.acknowledgement,
+                )
+                .await?
+        {
+            return Err(VerificationError::AckPending.into());
+        }
+        AH::acknowledge_packet_check(state, &inner).await
+    }
+}
+
+#[async_trait]
+impl<AH: AppHandlerExecute, P: FeePayout> AppHandlerExecute for WithFees<AH, P> {
+    async fn chan_open_init_execute<S: StateWrite>(
+        state: S,
+        msg: &MsgChannelOpenInit,
+    ) -> Result<()> {
+        let mut inner = msg.clone();
+        inner.version_proposal = split_version(&msg.version_proposal)?.1;
+        AH::chan_open_init_execute(state, &inner).await
+    }
+
+    async fn chan_open_try_execute<S: StateWrite>(state: S, msg: &MsgChannelOpenTry) -> Result<()> {
+        let mut inner = msg.clone();
+        inner.version_supported_on_a = split_version(&msg.version_supported_on_a)?.1;
+        AH::chan_open_try_execute(state, &inner).await
+    }
+
+    async fn chan_open_ack_execute<S: StateWrite>(state: S, msg: &MsgChannelOpenAck) -> Result<()> {
+        let mut inner = msg.clone();
+        inner.version_on_b = split_version(&msg.version_on_b)?.1;
+        AH::chan_open_ack_execute(state, &inner).await
+    }
+
+    async fn chan_open_confirm_execute<S: StateWrite>(
+        state: S,
+        msg: &MsgChannelOpenConfirm,
+    ) -> Result<()> {
+        AH::chan_open_confirm_execute(state, msg).await
+    }
+
+    async fn chan_close_confirm_execute<S: StateWrite>(
+        state: S,
+        msg: &MsgChannelCloseConfirm,
+    ) -> Result<()> {
+        AH::chan_close_confirm_execute(state, msg).await
+    }
+
+    async fn chan_close_init_execute<S: StateWrite>(
+        state: S,
+        msg: &MsgChannelCloseInit,
+    ) -> Result<()> {
+        AH::chan_close_init_execute(state, msg).await
+    }
+
+    async fn recv_packet_execute<S: StateWrite>(mut state: S, msg: &MsgRecvPacket) -> Result<()> {
+        // The acknowledgement written by the application is wrapped with the
+        // relayer's address when it's committed, see `write_acknowledgement`.
+        state.object_put(state_key::fees::forward_relayer(), msg.signer.clone());
+        let result = AH::recv_packet_execute(&mut state, msg).await;
+        state.object_delete(state_key::fees::forward_relayer());
+        result
+    }
+
+    async fn timeout_packet_execute<S: StateWrite>(mut state: S, msg: &MsgTimeout) -> Result<()> {
+        AH::timeout_packet_execute(&mut state, msg).await?;
+
+        let packet = &msg.packet;
+        let sequence = packet.sequence.0;
+        let Some(PacketFee {
+            fee,
+            refund_address,
+        }) = state
+            .get_packet_fee(&packet.port_on_a, &packet.chan_on_a, sequence)
+            .await?
+        else {
+            return Ok(());
+        };
+
+        pay_or_refund::<_, P>(&mut state, &msg.signer, fee.timeout_fee, &refund_address).await?;
+        refund::<_, P>(&mut state, &[fee.recv_fee, fee.ack_fee], &refund_address).await?;
+        state.delete_packet_fee(&packet.port_on_a, &packet.chan_on_a, sequence);
+
+        Ok(())
+    }
+
+    async fn acknowledge_packet_execute<S: StateWrite>(
+        mut state: S,
+        msg: &MsgAcknowledgement,
+    ) -> Result<()> {
+        let (inner, ack) = Self::unwrap_acknowledgement(&state, msg).await?;
+        AH::acknowledge_packet_execute(&mut state, &inner).await?;
+
+        let packet = &msg.packet;
+        let sequence = packet.sequence.0;
+        let (
+            Some(ack),
+            Some(PacketFee {
+                fee,
+                refund_address,
+            }),
+        ) = (
+            ack,
+            state
+                .get_packet_fee(&packet.port_on_a, &packet.chan_on_a, sequence)
+                .await?,
+        )
+        else {
+            return Ok(());
+        };
+
+        pay_or_refund::<_, P>(
+            &mut state,
+            &ack.forward_relayer_address,
+            fee.recv_fee,
+            &refund_address,
+        )
+        .await?;
+        pay_or_refund::<_, P>(&mut state, &msg.signer, fee.ack_fee, &refund_address).await?;
+        refund::<_, P>(&mut state, &[fee.timeout_fee], &refund_address).await?;
+        state.delete_packet_fee(&packet.port_on_a, &packet.chan_on_a, sequence);
+
+        Ok(())
+    }
+
+    async fn on_acknowledgement<S: StateWrite>(
+        state: S,
+        msg: &MsgAcknowledgement,
+        ack: &AcknowledgementResult,
+    ) -> Result<()> {
+        AH::on_acknowledgement(state, msg, ack).await
+    }
+}
+
+/// Refunds unpaid fees to `refund_address`.
+async fn refund<S: StateWrite, P: FeePayout>(
+    mut state: S,
+    values: &[Value],
+    refund_address: &str,
+) -> Result<()> {
+    for value in values {
+        if value.amount == Amount::zero() {
+            continue;
+        }
+        P::pay(&mut state, refund_address, *value)
+            .await
+            .context("couldn't refund relayer fee")?;
+        state.record(events::distribute_fee(refund_address, value));
+    }
+
+    Ok(())
+}
+
+impl<AH: AppHandler, P: FeePayout> AppHandler for WithFees<AH, P> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::channel::StateWriteExt as _;
+    use ibc_types::core::{
+        channel::{
+            channel::{Order, State as ChannelState},
+            ChannelEnd, Counterparty, Packet, TimeoutHeight,
+        },
+        client::Height,
+        commitment::MerkleProof,
+        connection::ConnectionId,
+    };
+    use ibc_types::timestamp::Timestamp;
+
+    /// An application that writes `pending` as its acknowledgement until it
+    /// has processed a packet.
+    struct AsyncAckApp;
+
+    #[async_trait]
+    impl AppHandlerCheck for AsyncAckApp {
+        fn pending_ack_sentinel(_port_id: &PortId, version: &Version) -> Option<Vec<u8>> {
+            (*version == Version::new("ics20-1".to_string())).then(|| b"pending".to_vec())
+        }
+
+        async fn chan_open_init_check<S: StateRead>(
+            _state: S,
+            _msg: &MsgChannelOpenInit,
+        ) -> Result<()> {
+            Ok(())
+        }
+        async fn chan_open_try_check<S: StateRead>(
+            _state: S,
+            _msg: &MsgChannelOpenTry,
+        ) -> Result<()> {
+            Ok(())
+        }
+        async fn chan_open_ack_check<S: StateRead>(
+            _state: S,
+            _msg: &MsgChannelOpenAck,
+        ) -> Result<()> {
+            Ok(())
+        }
+        async fn chan_open_confirm_check<S: StateRead>(
+            _state: S,
+            _msg: &MsgChannelOpenConfirm,
+        ) -> Result<()> {
+            Ok(())
+        }
+        async fn chan_close_confirm_check<S: StateRead>(
+            _state: S,
+            _msg: &MsgChannelCloseConfirm,
+        ) -> Result<()> {
+            Ok(())
+        }
+        async fn chan_close_init_check<S: StateRead>(
+            _state: S,
+            _msg: &MsgChannelCloseInit,
+        ) -> Result<()> {
+            Ok(())
+        }
+        async fn recv_packet_check<S: StateRead>(_state: S, _msg: &MsgRecvPacket) -> Result<()> {
+            Ok(())
+        }
+        async fn timeout_packet_check<S: StateRead>(_state: S, _msg: &MsgTimeout) -> Result<()> {
+            Ok(())
+        }
+        async fn acknowledge_packet_check<S: StateRead>(
+            _state: S,
+            _msg: &MsgAcknowledgement,
+        ) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    struct NoPayout;
+
+    #[async_trait]
+    impl FeePayout for NoPayout {
+        async fn pay<S: StateWrite>(_state: S, _recipient: &str, _value: Value) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    type AsyncAckAppWithFees = WithFees<AsyncAckApp, NoPayout>;
+
+    #[tokio::test]
+    async fn pending_ack_sentinels_are_unwrapped_on_fee_enabled_channels() -> Result<()> {
+        let version = FeeMetadata {
+            fee_version: FEE_VERSION.to_string(),
+            app_version: "ics20-1".to_string(),
+        }
+        .to_version();
+        let sentinel = AsyncAckAppWithFees::pending_ack_sentinel(&PortId::transfer(), &version)
+            .expect("the application's sentinel is passed through");
+        assert_eq!(sentinel, b"pending");
+
+        let mut state = StateDelta::new(());
+        let channel_id = ChannelId::new(0);
+        state.put_channel(
+            &channel_id,
+            &PortId::transfer(),
+            ChannelEnd {
+                state: ChannelState::Open,
+                ordering: Order::Unordered,
+                remote: Counterparty::new(PortId::transfer(), Some(ChannelId::new(1))),
+                connection_hops: vec![ConnectionId::new(0)],
+                version,
+            },
+        );
+        state.put_pending_ack_sentinel(&channel_id, &PortId::transfer(), sentinel.clone());
+
+        let ack = |app_acknowledgement: Vec<u8>| MsgAcknowledgement {
+            packet: Packet {
+                sequence: 1u64.into(),
+                port_on_a: PortId::transfer(),
+                chan_on_a: channel_id.clone(),
+                port_on_b: PortId::transfer(),
+                chan_on_b: ChannelId::new(1),
+                data: vec![],
+                timeout_height_on_b: TimeoutHeight::Never,
+                timeout_timestamp_on_b: Timestamp::none(),
+            },
+            acknowledgement: IncentivizedAcknowledgement::new(
+                app_acknowledgement,
+                "relayer".to_string(),
+            )
+            .into(),
+            proof_acked_on_b: MerkleProof { proofs: vec![] },
+            proof_height_on_b: Height::new(0, 1).unwrap(),
+            signer: "relayer".to_string(),
+        };
+
+        let err = AsyncAckAppWithFees::acknowledge_packet_check(&state, &ack(sentinel))
+            .await
+            .expect_err("a wrapped sentinel is not a final acknowledgement");
+        assert!(matches!(
+            err.downcast_ref::<VerificationError>(),
+            Some(VerificationError::AckPending)
+        ));
+
+        AsyncAckAppWithFees::acknowledge_packet_check(
+            &state,
+            &ack(br#"{"result":"AQ=="}"#.to_vec()),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn fee_metadata_matches_ibc_go_encoding() {
+        let version =
+            Version::new(r#"{"fee_version":"ics29-1","app_version":"ics20-1"}"#.to_string());
+
+        let (fee_enabled, app_version) = split_version(&version).unwrap();
+
+        assert!(fee_enabled);
+        assert_eq!(app_version, Version::new("ics20-1".to_string()));
+        assert_eq!(
+            FeeMetadata::from_version(&version).unwrap().to_version(),
+            version
+        );
+
+        let (fee_enabled, app_version) =
+            split_version(&Version::new("ics20-1".to_string())).unwrap();
+        assert!(!fee_enabled);
+        assert_eq!(app_version, Version::new("ics20-1".to_string()));
+
+        assert!(split_version(&Version::new(
+            r#"{"fee_version":"ics29-2","app_version":"ics20-1"}"#.to_string()
+        ))
+        .is_err());
+    }
+
+    #[test]
+    fn incentivized_acknowledgements_record_app_success() {
+        let success = IncentivizedAcknowledgement::new(
+            br#"{"result":"AQ=="}"#.to_vec(),
+            "relayer".to_string(),
+        );
+        let failure =
+            IncentivizedAcknowledgement::new(br#"{"error":"oops"}"#.to_vec(), String::new());
+
+        assert!(success.underlying_app_success);
+        assert!(!failure.underlying_app_success);
+
+        let bytes: Vec<u8> = success.clone().into();
+        let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["app_acknowledgement"], "eyJyZXN1bHQiOiJBUT09In0=");
+        assert_eq!(json["forward_relayer_address"], "relayer");
+        assert_eq!(
+            serde_json::from_slice::<IncentivizedAcknowledgement>(&bytes).unwrap(),
+            success
+        );
+    }
+}
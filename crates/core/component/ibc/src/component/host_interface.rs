@@ -1,10 +1,17 @@
 use async_trait::async_trait;
 use cnidarium::StateRead;
 
+use super::LightClientRegistry;
+
 #[async_trait]
 pub trait HostInterface {
     async fn get_chain_id<S: StateRead>(state: S) -> anyhow::Result<String>;
     async fn get_revision_number<S: StateRead>(state: S) -> anyhow::Result<u64>;
     async fn get_block_height<S: StateRead>(state: S) -> anyhow::Result<u64>;
     async fn get_block_timestamp<S: StateRead>(state: S) -> anyhow::Result<tendermint::Time>;
+
+    /// The light clients this host can verify proofs with, keyed by client type.
+    fn light_clients() -> LightClientRegistry {
+        LightClientRegistry::default()
+    }
 }
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use anyhow::Result;
-use cnidarium::StateWrite;
+use cnidarium::{StateRead, StateWrite};
 use ibc_types::{
     core::client::Height, lightclients::tendermint::ConsensusState as TendermintConsensusState,
 };
@@ -9,8 +9,11 @@ use tendermint::abci;
 use tracing::instrument;
 
 use crate::{
-    component::{client::StateWriteExt as _, client_counter::ClientCounter},
-    genesis, StateWriteExt as _,
+    component::{
+        ack_pruning, client::StateWriteExt as _, client_counter::ClientCounter, metrics,
+        verification_stats,
+    },
+    genesis, StateReadExt as _, StateWriteExt as _,
 };
 
 use super::HostInterface;
@@ -32,6 +35,18 @@ impl Ibc {
         }
     }
 
+    /// Exports the genesis state of a new chain starting from `state`, in the
+    /// form passed in to [`Ibc::init_chain`].
+    ///
+    /// Clients, connections and channels are left behind, since a new chain
+    /// has to establish them again with its counterparties.
+    #[instrument(name = "ibc", skip(state))]
+    pub async fn export_genesis<S: StateRead>(state: &S) -> Result<genesis::Content> {
+        Ok(genesis::Content {
+            ibc_params: state.get_ibc_params().await?,
+        })
+    }
+
     #[instrument(name = "ibc", skip(state, begin_block))]
     pub async fn begin_block<HI: HostInterface, S: StateWrite + 'static>(
         state: &mut Arc<S>,
@@ -62,13 +77,25 @@ impl Ibc {
         .expect("block height cannot be zero");
 
         state.put_penumbra_consensus_state(height, cs);
+
+        verification_stats::begin_block(state);
     }
 
-    #[instrument(name = "ibc", skip(_state, _end_block))]
-    pub async fn end_block<S: StateWrite + 'static>(
-        mut _state: &mut Arc<S>,
+    #[instrument(name = "ibc", skip(state, _end_block))]
+    pub async fn end_block<HI: HostInterface, S: StateWrite + 'static>(
+        state: &mut Arc<S>,
         _end_block: &abci::request::EndBlock,
     ) {
+        let state = Arc::get_mut(state).expect("state should be unique");
+        verification_stats::flush(state).await;
+        metrics::flush_block_counts(state);
+
+        let height = HI::get_block_height(&state)
+            .await
+            .expect("must be able to get block height in end block");
+        ack_pruning::prune(state, height)
+            .await
+            .expect("must be able to prune acknowledgements in end block");
     }
 
     #[instrument(name = "ibc", skip(_state))]
@@ -0,0 +1,599 @@
+//! An ICS-27 interchain accounts host.
+//!
+//! Controller chains open ordered channels from an `icacontroller-{owner}`
+//! port to the [`HOST_PORT_ID`] port. Each (connection, controller port) pair
+//! owns one interchain account, whose address is derived deterministically
+//! and returned to the controller in the channel version during `ChanOpenTry`.
+//!
+//! Transactions received over the account's active channel are executed
+//! atomically by an [`IcaMessageExecutor`], which dispatches each message into
+//! the component that handles it. Only messages whose type URLs governance has
+//! put in
+//! [`IBCParameters::ica_allowed_messages`](crate::params::IBCParameters::ica_allowed_messages)
+//! may be executed.
+//!
+//! See: https://github.com/cosmos/ibc/tree/main/spec/app/ics-027-interchain-accounts
+
+use std::marker::PhantomData;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use cnidarium::{StateDelta, StateRead, StateWrite};
+use ibc_types::core::channel::{
+    channel::{Order as ChannelOrder, State as ChannelState},
+    msgs::{
+        MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
+        MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket, MsgTimeout,
+    },
+    PortId, Version,
+};
+use prost::Message as _;
+
+use super::{
+    app_handler::{AcknowledgementResult, AppHandler, AppHandlerCheck, AppHandlerExecute},
+    channel::StateReadExt as _,
+    connection::StateReadExt as _,
+    packet::WriteAcknowledgement as _,
+    view::StateReadExt as _,
+};
+
+mod account;
+mod metadata;
+mod packet;
+
+pub use account::{
+    interchain_account_address, StateReadExt, StateWriteExt, ACCOUNT_ADDRESS_PREFIX,
+};
+pub use metadata::Metadata;
+pub use packet::{InterchainAccountPacketData, PacketType};
+
+/// The port that the interchain accounts host is bound to.
+pub const HOST_PORT_ID: &str = "icahost";
+/// The prefix of the ports that controllers open channels from.
+pub const CONTROLLER_PORT_PREFIX: &str = "icacontroller-";
+/// The ICS-27 version supported by the host.
+pub const VERSION: &str = "ics27-1";
+/// The only transaction encoding supported by the host.
+pub const ENCODING_PROTO3: &str = "proto3";
+/// The only transaction type supported by the host.
+pub const TX_TYPE_SDK_MULTI_MSG: &str = "sdk_multi_msg";
+
+pub fn host_port_id() -> PortId {
+    PortId(HOST_PORT_ID.to_string())
+}
+
+/// Executes the messages in interchain account transactions.
+#[async_trait]
+pub trait IcaMessageExecutor: Send + Sync {
+    /// Executes `msg` on behalf of the interchain account at `address`,
+    /// returning the message's response.
+    ///
+    /// Only called with messages whose type URLs governance allows, so this
+    /// should fail on any message type it doesn't support.
+    async fn execute<S: StateWrite>(
+        state: S,
+        address: &str,
+        msg: &pbjson_types::Any,
+    ) -> Result<pbjson_types::Any>;
+}
+
+/// An app handler that binds the interchain accounts host to
+/// [`HOST_PORT_ID`], executing messages with `X`, and routes every other port
+/// to `AH`.
+pub struct WithIcaHost<AH, X> {
+    _marker: PhantomData<(AH, X)>,
+}
+
+#[async_trait]
+impl<AH: AppHandlerCheck, X: IcaMessageExecutor> AppHandlerCheck for WithIcaHost<AH, X> {
+    fn is_bound_port(port_id: &PortId) -> bool {
+        *port_id == host_port_id() || AH::is_bound_port(port_id)
+    }
+
+    fn pending_ack_sentinel(port_id: &PortId, version: &Version) -> Option<Vec<u8>> {
+        if *port_id == host_port_id() {
+            return None;
+        }
+        AH::pending_ack_sentinel(port_id, version)
+    }
+
+    async fn chan_open_try_version<S: StateRead>(
+        state: S,
+        msg: &MsgChannelOpenTry,
+    ) -> Result<Version> {
+        if msg.port_id_on_b != host_port_id() {
+            return AH::chan_open_try_version(state, msg).await;
+        }
+
+        let connection_id = msg
+            .connection_hops_on_b
+            .first()
+            .context("no connection hops")?;
+        let mut metadata = Metadata::from_version(&msg.version_supported_on_a)?;
+        metadata.address = interchain_account_address(connection_id, &msg.port_id_on_a);
+        Ok(metadata.to_version())
+    }
+
+    async fn chan_open_init_check<S: StateRead>(state: S, msg: &MsgChannelOpenInit) -> Result<()> {
+        if msg.port_id_on_a != host_port_id() {
+            return AH::chan_open_init_check(state, msg).await;
+        }
+
+        anyhow::bail!("interchain account channels must be opened by the controller chain");
+    }
+
+    async fn chan_open_try_check<S: StateRead>(state: S, msg: &MsgChannelOpenTry) -> Result<()> {
+        if msg.port_id_on_b != host_port_id() {
+            return AH::chan_open_try_check(state, msg).await;
+        }
+
+        if msg.ordering != ChannelOrder::Ordered {
+            anyhow::bail!("interchain account channels must be ordered");
+        }
+        if !msg.port_id_on_a.0.starts_with(CONTROLLER_PORT_PREFIX) {
+            anyhow::bail!(
+                "counterparty port {} is not an interchain account controller port",
+                msg.port_id_on_a
+            );
+        }
+
+        let connection_id = msg
+            .connection_hops_on_b
+            .first()
+            .context("no connection hops")?;
+        let connection = state
+            .get_connection(connection_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("connection not found"))?;
+        let counterparty_connection_id = connection
+            .counterparty
+            .connection_id
+            .ok_or_else(|| anyhow::anyhow!("no counterparty connection id"))?;
+        Metadata::from_version(&msg.version_supported_on_a)?
+            .validate(connection_id, &counterparty_connection_id)?;
+
+        // An account can only be controlled over one channel at a time. Once
+        // its channel closes, e.g. because a packet timed out, the controller
+        // may open a new one.
+        if let Some(active_channel_id) = state
+            .get_active_ica_channel(connection_id, &msg.port_id_on_a)
+            .await?
+        {
+            let active_channel = state
+                .get_channel(&active_channel_id, &msg.port_id_on_b)
+                .await?;
+            if active_channel.is_some_and(|channel| channel.state != ChannelState::Closed) {
+                anyhow::bail!(
+                    "interchain account already has an active channel {}",
+                    active_channel_id
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn chan_open_ack_check<S: StateRead>(state: S, msg: &MsgChannelOpenAck) -> Result<()> {
+        if msg.port_id_on_a != host_port_id() {
+            return AH::chan_open_ack_check(state, msg).await;
+        }
+
+        anyhow::bail!("interchain account channels must be opened by the controller chain");
+    }
+
+    async fn chan_open_confirm_check<S: StateRead>(
+        state: S,
+        msg: &MsgChannelOpenConfirm,
+    ) -> Result<()> {
+        if msg.port_id_on_b != host_port_id() {
+            return AH::chan_open_confirm_check(state, msg).await;
+        }
+
+        Ok(())
+    }
+
+    async fn chan_close_confirm_check<S: StateRead>(
+        state: S,
+        msg: &MsgChannelCloseConfirm,
+    ) -> Result<()> {
+        if msg.port_id_on_b != host_port_id() {
+            return AH::chan_close_confirm_check(state, msg).await;
+        }
+
+        Ok(())
+    }
+
+    async fn chan_close_init_check<S: StateRead>(
+        state: S,
+        msg: &MsgChannelCloseInit,
+    ) -> Result<()> {
+        if msg.port_id_on_a != host_port_id() {
+            return AH::chan_close_init_check(state, msg).await;
+        }
+
+        anyhow::bail!("interchain account channels cannot be closed by the host");
+    }
+
+    async fn recv_packet_check<S: StateRead>(state: S, msg: &MsgRecvPacket) -> Result<()> {
+        if msg.packet.port_on_b != host_port_id() {
+            return AH::recv_packet_check(state, msg).await;
+        }
+
+        // Failures to execute the transaction are reported in the acknowledgement.
+        Ok(())
+    }
+
+    async fn timeout_packet_check<S: StateRead>(state: S, msg: &MsgTimeout) -> Result<()> {
+        if msg.packet.port_on_b != host_port_id() {
+            return AH::timeout_packet_check(state, msg).await;
+        }
+
+        anyhow::bail!("the interchain accounts host does not send packets");
+    }
+
+    async fn acknowledge_packet_check<S: StateRead>(
+        state: S,
+        msg: &MsgAcknowledgement,
+    ) -> Result<()> {
+        if msg.packet.port_on_b != host_port_id() {
+            return AH::acknowledge_packet_check(state, msg).await;
+        }
+
+        anyhow::bail!("the interchain accounts host does not send packets");
+    }
+}
+
+/// Executes the transaction in an `EXECUTE_TX` packet, returning the encoded
+/// responses to its messages.
+async fn execute_tx<S: StateWrite, X: IcaMessageExecutor>(
+    mut state: S,
+    msg: &MsgRecvPacket,
+) -> Result<Vec<u8>> {
+    let packet_data: InterchainAccountPacketData = serde_json::from_slice(&msg.packet.data)
+        .context("failed to decode interchain account packet data")?;
+    if packet_data.packet_type != PacketType::ExecuteTx {
+        anyhow::bail!("unsupported packet type {:?}", packet_data.packet_type);
+    }
+
+    let messages = packet_data.messages()?;
+    if messages.is_empty() {
+        anyhow::bail!("interchain account transaction has no messages");
+    }
+    let allowed_messages = state.get_ibc_params().await?.ica_allowed_messages;
+    if let Some(message) = messages
+        .iter()
+        .find(|message| !allowed_messages.contains(&message.type_url))
+    {
+        anyhow::bail!("message type {} is not allowed", message.type_url);
+    }
+
+    let channel = state
+        .get_channel(&msg.packet.chan_on_b, &msg.packet.port_on_b)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("channel not found"))?;
+    let connection_id = channel
+        .connection_hops
+        .first()
+        .context("channel has no connection hops")?;
+    let address = state
+        .get_interchain_account(connection_id, &msg.packet.port_on_a)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("no interchain account registered"))?;
+
+    let mut msg_responses = Vec::with_capacity(messages.len());
+    for (i, message) in messages.iter().enumerate() {
+        let response = X::execute(&mut state, &address, message)
+            .await
+            .with_context(|| format!("failed to execute message {i} ({})", message.type_url))?;
+        msg_responses.push(response);
+    }
+
+    Ok(packet::TxMsgData { msg_responses }.encode_to_vec())
+}
+
+#[async_trait]
+impl<AH: AppHandlerExecute, X: IcaMessageExecutor> AppHandlerExecute for WithIcaHost<AH, X> {
+    async fn chan_open_init_execute<S: StateWrite>(
+        state: S,
+        msg: &MsgChannelOpenInit,
+    ) -> Result<()> {
+        if msg.port_id_on_a != host_port_id() {
+            AH::chan_open_init_execute(state, msg).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn chan_open_try_execute<S: StateWrite>(
+        mut state: S,
+        msg: &MsgChannelOpenTry,
+    ) -> Result<()> {
+        if msg.port_id_on_b != host_port_id() {
+            return AH::chan_open_try_execute(state, msg).await;
+        }
+
+        let connection_id = msg
+            .connection_hops_on_b
+            .first()
+            .context("no connection hops")?;
+        let registered = state
+            .get_interchain_account(connection_id, &msg.port_id_on_a)
+            .await
+            .context("unable to read interchain account")?
+            .is_some();
+        if !registered {
+            state.put_interchain_account(
+                connection_id,
+                &msg.port_id_on_a,
+                interchain_account_address(connection_id, &msg.port_id_on_a),
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn chan_open_ack_execute<S: StateWrite>(state: S, msg: &MsgChannelOpenAck) -> Result<()> {
+        if msg.port_id_on_a != host_port_id() {
+            AH::chan_open_ack_execute(state, msg).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn chan_open_confirm_execute<S: StateWrite>(
+        mut state: S,
+        msg: &MsgChannelOpenConfirm,
+    ) -> Result<()> {
+        if msg.port_id_on_b != host_port_id() {
+            return AH::chan_open_confirm_execute(state, msg).await;
+        }
+
+        let channel = state
+            .get_channel(&msg.chan_id_on_b, &msg.port_id_on_b)
+            .await
+            .context("unable to read channel")?
+            .ok_or_else(|| anyhow::anyhow!("channel {} not found", msg.chan_id_on_b))?;
+        let connection_id = channel
+            .connection_hops
+            .first()
+            .context("channel has no connection hops")?;
+        state.put_active_ica_channel(connection_id, &channel.remote.port_id, &msg.chan_id_on_b);
+
+        Ok(())
+    }
+
+    async fn chan_close_confirm_execute<S: StateWrite>(
+        state: S,
+        msg: &MsgChannelCloseConfirm,
+    ) -> Result<()> {
+        if msg.port_id_on_b != host_port_id() {
+            AH::chan_close_confirm_execute(state, msg).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn chan_close_init_execute<S: StateWrite>(
+        state: S,
+        msg: &MsgChannelCloseInit,
+    ) -> Result<()> {
+        if msg.port_id_on_a != host_port_id() {
+            AH::chan_close_init_execute(state, msg).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn recv_packet_execute<S: StateWrite>(mut state: S, msg: &MsgRecvPacket) -> Result<()> {
+        if msg.packet.port_on_b != host_port_id() {
+            return AH::recv_packet_execute(state, msg).await;
+        }
+
+        // Messages are executed atomically: if any of them fails, none of
+        // their effects are kept, and an error acknowledgement is written.
+        let mut tx = StateDelta::new(&mut state);
+        let ack = match execute_tx::<_, X>(&mut tx, msg).await {
+            Ok(response) => {
+                let (state, events) = tx.apply();
+                for event in events {
+                    state.record(event);
+                }
+                AcknowledgementResult::Success(response)
+            }
+            Err(e) => {
+                drop(tx);
+                tracing::debug!("couldn't execute interchain account transaction: {:#}", e);
+                AcknowledgementResult::Error(e.to_string())
+            }
+        };
+
+        state
+            .write_acknowledgement(&msg.packet, &Vec::<u8>::from(ack))
+            .await
+            .context("able to write acknowledgement")?;
+
+        Ok(())
+    }
+
+    async fn timeout_packet_execute<S: StateWrite>(state: S, msg: &MsgTimeout) -> Result<()> {
+        if msg.packet.port_on_b != host_port_id() {
+            return AH::timeout_packet_execute(state, msg).await;
+        }
+
+        anyhow::bail!("the interchain accounts host does not send packets");
+    }
+
+    async fn on_acknowledgement<S: StateWrite>(
+        state: S,
+        msg: &MsgAcknowledgement,
+        ack: &AcknowledgementResult,
+    ) -> Result<()> {
+        if msg.packet.port_on_b != host_port_id() {
+            return AH::on_acknowledgement(state, msg, ack).await;
+        }
+
+        anyhow::bail!("the interchain accounts host does not send packets");
+    }
+}
+
+impl<AH: AppHandler, X: IcaMessageExecutor> AppHandler for WithIcaHost<AH, X> {}
+
+#[cfg(test)]
+mod tests {
+    use base64::prelude::*;
+    use ibc_types::core::channel::{
+        channel::Counterparty, ChannelEnd, ChannelId, Packet, TimeoutHeight,
+    };
+    use ibc_types::core::client::Height;
+    use ibc_types::core::commitment::MerkleProof;
+    use ibc_types::core::connection::ConnectionId;
+    use ibc_types::timestamp::Timestamp;
+
+    use super::*;
+    use crate::component::{channel::StateWriteExt as _, view::StateWriteExt as _};
+    use crate::params::IBCParameters;
+
+    /// Echoes the messages it executes back as their responses.
+    struct EchoMessages;
+
+    #[async_trait]
+    impl IcaMessageExecutor for EchoMessages {
+        async fn execute<S: StateWrite>(
+            _state: S,
+            _address: &str,
+            msg: &pbjson_types::Any,
+        ) -> Result<pbjson_types::Any> {
+            Ok(msg.clone())
+        }
+    }
+
+    fn execute_tx_packet(messages: Vec<pbjson_types::Any>) -> MsgRecvPacket {
+        let packet_data = InterchainAccountPacketData {
+            packet_type: PacketType::ExecuteTx,
+            data: packet::CosmosTx { messages }.encode_to_vec(),
+            memo: String::new(),
+        };
+        MsgRecvPacket {
+            packet: Packet {
+                sequence: 1.into(),
+                port_on_a: PortId("icacontroller-alice".to_string()),
+                chan_on_a: ChannelId::new(0),
+                port_on_b: host_port_id(),
+                chan_on_b: ChannelId::new(0),
+                data: serde_json::to_vec(&packet_data).unwrap(),
+                timeout_height_on_b: TimeoutHeight::Never,
+                timeout_timestamp_on_b: Timestamp::none(),
+            },
+            proof_commitment_on_a: MerkleProof { proofs: vec![] },
+            proof_height_on_a: Height::new(0, 1).unwrap(),
+            signer: "relayer".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn only_allowed_messages_are_executed() -> Result<()> {
+        let mut state = StateDelta::new(());
+        let controller_port_id = PortId("icacontroller-alice".to_string());
+        state.put_ibc_params(IBCParameters {
+            ica_allowed_messages: vec!["/ibc.core.client.v1.MsgUpdateClient".to_string()],
+            ..Default::default()
+        });
+        state.put_channel(
+            &ChannelId::new(0),
+            &host_port_id(),
+            ChannelEnd {
+                state: ChannelState::Open,
+                ordering: ChannelOrder::Ordered,
+                remote: Counterparty::new(controller_port_id.clone(), Some(ChannelId::new(0))),
+                connection_hops: vec![ConnectionId::new(0)],
+                version: Version::new(VERSION.to_string()),
+            },
+        );
+        state.put_interchain_account(
+            &ConnectionId::new(0),
+            &controller_port_id,
+            interchain_account_address(&ConnectionId::new(0), &controller_port_id),
+        );
+
+        let allowed = pbjson_types::Any {
+            type_url: "/ibc.core.client.v1.MsgUpdateClient".to_string(),
+            value: vec![1, 2, 3].into(),
+        };
+        let disallowed = pbjson_types::Any {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            value: vec![4, 5, 6].into(),
+        };
+
+        let response =
+            execute_tx::<_, EchoMessages>(&mut state, &execute_tx_packet(vec![allowed.clone()]))
+                .await?;
+        assert_eq!(
+            packet::TxMsgData::decode(response.as_slice())?.msg_responses,
+            vec![allowed.clone()]
+        );
+        assert!(execute_tx::<_, EchoMessages>(
+            &mut state,
+            &execute_tx_packet(vec![allowed, disallowed])
+        )
+        .await
+        .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn metadata_matches_ibc_go_encoding() {
+        let version = Version::new(
+            r#"{"version":"ics27-1","controller_connection_id":"connection-3","host_connection_id":"connection-0","address":"","encoding":"proto3","tx_type":"sdk_multi_msg"}"#
+                .to_string(),
+        );
+        let metadata = Metadata::from_version(&version).unwrap();
+
+        metadata
+            .validate(&ConnectionId::new(0), &ConnectionId::new(3))
+            .unwrap();
+        assert!(metadata
+            .validate(&ConnectionId::new(1), &ConnectionId::new(3))
+            .is_err());
+        assert_eq!(
+            Metadata::from_version(&metadata.to_version()).unwrap(),
+            metadata
+        );
+    }
+
+    #[test]
+    fn packet_data_decodes_messages() {
+        let tx = packet::CosmosTx {
+            messages: vec![pbjson_types::Any {
+                type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+                value: vec![1, 2, 3].into(),
+            }],
+        };
+        let json = format!(
+            r#"{{"type":"TYPE_EXECUTE_TX","data":"{}","memo":""}}"#,
+            BASE64_STANDARD.encode(tx.encode_to_vec())
+        );
+
+        let packet_data: InterchainAccountPacketData = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(packet_data.packet_type, PacketType::ExecuteTx);
+        assert_eq!(packet_data.messages().unwrap(), tx.messages);
+    }
+
+    #[test]
+    fn account_addresses_are_per_controller() {
+        let connection_id = ConnectionId::new(0);
+        let alice = PortId("icacontroller-alice".to_string());
+        let bob = PortId("icacontroller-bob".to_string());
+
+        let address = interchain_account_address(&connection_id, &alice);
+
+        assert!(address.starts_with(ACCOUNT_ADDRESS_PREFIX));
+        assert_eq!(address, interchain_account_address(&connection_id, &alice));
+        assert_ne!(address, interchain_account_address(&connection_id, &bob));
+        assert_ne!(
+            address,
+            interchain_account_address(&ConnectionId::new(1), &alice)
+        );
+    }
+}
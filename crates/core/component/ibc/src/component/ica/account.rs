@@ -0,0 +1,93 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use ibc_types::core::{
+    channel::{ChannelId, PortId},
+    connection::ConnectionId,
+};
+use penumbra_proto::serializers::bech32str;
+use sha2::{Digest, Sha256};
+
+use crate::component::state_key;
+
+/// The Bech32 prefix of interchain account addresses.
+pub const ACCOUNT_ADDRESS_PREFIX: &str = "penumbraica";
+
+/// Derives the address of the interchain account owned by `controller_port_id`
+/// on the other end of `connection_id`.
+pub fn interchain_account_address(
+    connection_id: &ConnectionId,
+    controller_port_id: &PortId,
+) -> String {
+    let digest = Sha256::new()
+        .chain_update(b"ics27-account")
+        .chain_update(connection_id.0.as_bytes())
+        .chain_update(b"/")
+        .chain_update(controller_port_id.0.as_bytes())
+        .finalize();
+
+    bech32str::encode(&digest, ACCOUNT_ADDRESS_PREFIX, bech32str::Bech32m)
+}
+
+#[async_trait]
+pub trait StateReadExt: StateRead {
+    /// Gets the address of the interchain account registered for
+    /// `controller_port_id` on `connection_id`, if there is one.
+    async fn get_interchain_account(
+        &self,
+        connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+    ) -> Result<Option<String>> {
+        self.get_raw(&state_key::ica::account(connection_id, controller_port_id))
+            .await?
+            .map(|bytes| String::from_utf8(bytes).map_err(Into::into))
+            .transpose()
+    }
+
+    /// Gets the channel over which the interchain account registered for
+    /// `controller_port_id` on `connection_id` is currently controlled.
+    async fn get_active_ica_channel(
+        &self,
+        connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+    ) -> Result<Option<ChannelId>> {
+        self.get_raw(&state_key::ica::active_channel(
+            connection_id,
+            controller_port_id,
+        ))
+        .await?
+        .map(|bytes| Ok(ChannelId(String::from_utf8(bytes)?)))
+        .transpose()
+    }
+}
+
+impl<T: StateRead + ?Sized> StateReadExt for T {}
+
+#[async_trait]
+pub trait StateWriteExt: StateWrite {
+    fn put_interchain_account(
+        &mut self,
+        connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+        address: String,
+    ) {
+        self.put_raw(
+            state_key::ica::account(connection_id, controller_port_id),
+            address.into_bytes(),
+        );
+    }
+
+    fn put_active_ica_channel(
+        &mut self,
+        connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+        channel_id: &ChannelId,
+    ) {
+        self.put_raw(
+            state_key::ica::active_channel(connection_id, controller_port_id),
+            channel_id.0.clone().into_bytes(),
+        );
+    }
+}
+
+impl<T: StateWrite + ?Sized> StateWriteExt for T {}
@@ -0,0 +1,67 @@
+use anyhow::Result;
+use ibc_types::core::{channel::Version, connection::ConnectionId};
+use serde::{Deserialize, Serialize};
+
+use super::{ENCODING_PROTO3, TX_TYPE_SDK_MULTI_MSG, VERSION};
+
+/// The channel version negotiated in an ICS-27 handshake.
+///
+/// This is JSON-encoded into the channel version string, using the same field
+/// names as ibc-go.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Metadata {
+    pub version: String,
+    pub controller_connection_id: String,
+    pub host_connection_id: String,
+    /// The address of the interchain account, which is empty until the host
+    /// fills it in during `ChanOpenTry`.
+    #[serde(default)]
+    pub address: String,
+    pub encoding: String,
+    pub tx_type: String,
+}
+
+impl Metadata {
+    pub fn from_version(version: &Version) -> Result<Self> {
+        serde_json::from_str(&version.to_string())
+            .map_err(|e| anyhow::anyhow!("invalid ICS-27 version metadata: {e}"))
+    }
+
+    pub fn to_version(&self) -> Version {
+        Version::new(serde_json::to_string(self).expect("metadata serializes to JSON"))
+    }
+
+    /// Checks that the metadata proposes a version, encoding, and transaction
+    /// type supported by the host, on the expected pair of connections.
+    pub fn validate(
+        &self,
+        host_connection_id: &ConnectionId,
+        controller_connection_id: &ConnectionId,
+    ) -> Result<()> {
+        if self.version != VERSION {
+            anyhow::bail!("unsupported ICS-27 version {}", self.version);
+        }
+        if self.encoding != ENCODING_PROTO3 {
+            anyhow::bail!("unsupported ICS-27 encoding {}", self.encoding);
+        }
+        if self.tx_type != TX_TYPE_SDK_MULTI_MSG {
+            anyhow::bail!("unsupported ICS-27 transaction type {}", self.tx_type);
+        }
+        if self.host_connection_id != host_connection_id.0 {
+            anyhow::bail!(
+                "metadata host connection {} does not match channel connection {}",
+                self.host_connection_id,
+                host_connection_id
+            );
+        }
+        if self.controller_connection_id != controller_connection_id.0 {
+            anyhow::bail!(
+                "metadata controller connection {} does not match counterparty connection {}",
+                self.controller_connection_id,
+                controller_connection_id
+            );
+        }
+
+        Ok(())
+    }
+}
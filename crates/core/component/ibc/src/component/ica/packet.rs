@@ -0,0 +1,50 @@
+use anyhow::{Context, Result};
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use crate::component::base64_bytes;
+
+/// The type of an ICS-27 packet.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PacketType {
+    #[serde(rename = "TYPE_UNSPECIFIED")]
+    Unspecified,
+    #[serde(rename = "TYPE_EXECUTE_TX")]
+    ExecuteTx,
+}
+
+/// The data of an ICS-27 packet, in the JSON encoding used by ibc-go.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InterchainAccountPacketData {
+    #[serde(rename = "type")]
+    pub packet_type: PacketType,
+    /// A protobuf-encoded `CosmosTx`.
+    #[serde(with = "base64_bytes")]
+    pub data: Vec<u8>,
+    #[serde(default)]
+    pub memo: String,
+}
+
+impl InterchainAccountPacketData {
+    /// Decodes the messages in the transaction carried by this packet.
+    pub fn messages(&self) -> Result<Vec<pbjson_types::Any>> {
+        let tx = CosmosTx::decode(self.data.as_slice())
+            .context("failed to decode interchain account transaction")?;
+        Ok(tx.messages)
+    }
+}
+
+/// The transaction carried by an `EXECUTE_TX` packet.
+#[derive(Clone, PartialEq, Message)]
+pub(super) struct CosmosTx {
+    #[prost(message, repeated, tag = "1")]
+    pub messages: Vec<pbjson_types::Any>,
+}
+
+/// The responses to the messages in a successfully executed transaction,
+/// which make up the result of the acknowledgement.
+#[derive(Clone, PartialEq, Message)]
+pub(super) struct TxMsgData {
+    #[prost(message, repeated, tag = "2")]
+    pub msg_responses: Vec<pbjson_types::Any>,
+}
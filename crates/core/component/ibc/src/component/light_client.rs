@@ -0,0 +1,304 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use cnidarium::StateRead;
+use ibc_proto::google::protobuf::Any;
+use ibc_types::core::client::{ClientId, ClientType, Height};
+use ibc_types::core::commitment::MerkleProof;
+use ibc_types::path::{ClientConsensusStatePath, ClientStatePath, Path};
+
+use super::client::{decode_client_state, decode_consensus_state, StateReadExt as _};
+use super::ics02_validation;
+use super::proof_verification::{verify_merkle_absence_proof, verify_merkle_proof};
+use super::HostInterface;
+use crate::prefix::MerklePrefixExt as _;
+use crate::{CounterpartyPrefix, IBC_COMMITMENT_PREFIX};
+
+/// The verification logic for one type of light client.
+///
+/// Client and consensus states are passed to a light client in the encoding
+/// they're stored in, so that verification can be dispatched on a client's type
+/// without the caller knowing how that type represents its states.
+pub trait LightClient: Send + Sync {
+    /// The type of the clients this light client verifies, e.g. `07-tendermint`.
+    fn client_type(&self) -> ClientType;
+
+    /// Checks that `client_state`, as submitted in a `MsgCreateClient`, is a
+    /// valid client state for this light client.
+    fn validate_client_state(&self, client_state: &Any) -> Result<()>;
+
+    /// Checks that `consensus_state`, as submitted in a `MsgCreateClient`, is a
+    /// valid consensus state for this light client.
+    fn validate_consensus_state(&self, consensus_state: &Any) -> Result<()>;
+
+    /// Verifies that `value` is committed at `path` on the counterparty, as of
+    /// `consensus_state`.
+    fn verify_membership(
+        &self,
+        client_state: &[u8],
+        consensus_state: &[u8],
+        prefix: &CounterpartyPrefix,
+        proof: &MerkleProof,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<()>;
+
+    /// Verifies that nothing is committed at `path` on the counterparty, as of
+    /// `consensus_state`.
+    fn verify_non_membership(
+        &self,
+        client_state: &[u8],
+        consensus_state: &[u8],
+        prefix: &CounterpartyPrefix,
+        proof: &MerkleProof,
+        path: Path,
+    ) -> Result<()>;
+}
+
+/// The ICS-07 Tendermint light client.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TendermintLightClient;
+
+impl LightClient for TendermintLightClient {
+    fn client_type(&self) -> ClientType {
+        ibc_types::lightclients::tendermint::client_type()
+    }
+
+    fn validate_client_state(&self, client_state: &Any) -> Result<()> {
+        ics02_validation::get_tendermint_client_state(client_state.clone()).map(|_| ())
+    }
+
+    fn validate_consensus_state(&self, consensus_state: &Any) -> Result<()> {
+        ics02_validation::get_tendermint_consensus_state(consensus_state.clone()).map(|_| ())
+    }
+
+    fn verify_membership(
+        &self,
+        client_state: &[u8],
+        consensus_state: &[u8],
+        prefix: &CounterpartyPrefix,
+        proof: &MerkleProof,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<()> {
+        let client_state = decode_client_state(client_state)?;
+        let consensus_state = decode_consensus_state(consensus_state)?;
+
+        verify_merkle_proof(
+            &client_state.proof_specs,
+            prefix,
+            proof,
+            &consensus_state.root,
+            path,
+            value,
+        )
+    }
+
+    fn verify_non_membership(
+        &self,
+        client_state: &[u8],
+        consensus_state: &[u8],
+        prefix: &CounterpartyPrefix,
+        proof: &MerkleProof,
+        path: Path,
+    ) -> Result<()> {
+        let client_state = decode_client_state(client_state)?;
+        let consensus_state = decode_consensus_state(consensus_state)?;
+
+        verify_merkle_absence_proof(
+            &client_state.proof_specs,
+            prefix,
+            proof,
+            &consensus_state.root,
+            path,
+        )
+    }
+}
+
+/// The light clients a host supports, keyed by client type.
+///
+/// The default registry only contains the [`TendermintLightClient`]. Hosts
+/// that support other types of clients register them, and return the registry
+/// from [`HostInterface::light_clients`].
+#[derive(Clone)]
+pub struct LightClientRegistry {
+    clients: BTreeMap<String, Arc<dyn LightClient>>,
+}
+
+impl LightClientRegistry {
+    /// A registry without any light clients.
+    pub fn empty() -> Self {
+        Self {
+            clients: BTreeMap::new(),
+        }
+    }
+
+    /// Registers `light_client` for its client type, replacing any light client
+    /// previously registered for that type.
+    pub fn register(&mut self, light_client: impl LightClient + 'static) -> &mut Self {
+        self.clients.insert(
+            light_client.client_type().as_str().to_string(),
+            Arc::new(light_client),
+        );
+        self
+    }
+
+    /// Gets the light client for `client_type`, if one is registered.
+    pub fn get(&self, client_type: &ClientType) -> Option<Arc<dyn LightClient>> {
+        self.clients.get(client_type.as_str()).cloned()
+    }
+
+    /// Gets the light client for `client_type`, failing if none is registered.
+    pub fn require(&self, client_type: &ClientType) -> Result<Arc<dyn LightClient>> {
+        self.get(client_type)
+            .ok_or_else(|| anyhow::anyhow!("client type {client_type} is not supported"))
+    }
+
+    /// The client types with a registered light client.
+    pub fn client_types(&self) -> impl Iterator<Item = &str> {
+        self.clients.keys().map(String::as_str)
+    }
+}
+
+impl Default for LightClientRegistry {
+    fn default() -> Self {
+        let mut registry = Self::empty();
+        registry.register(TendermintLightClient);
+        registry
+    }
+}
+
+/// Verifies proofs against a client's stored states using the light client
+/// registered for its type.
+#[async_trait]
+pub trait LightClientVerifier: StateRead + Sized {
+    /// Verifies that `value` is committed at `path` on the counterparty tracked
+    /// by `client_id`, as of its consensus state at `height`.
+    async fn verify_client_membership<HI: HostInterface>(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+        prefix: &CounterpartyPrefix,
+        proof: &MerkleProof,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<()> {
+        let (light_client, client_state, consensus_state) =
+            self.light_client_states::<HI>(client_id, height).await?;
+
+        light_client.verify_membership(&client_state, &consensus_state, prefix, proof, path, value)
+    }
+
+    /// Verifies that nothing is committed at `path` on the counterparty tracked
+    /// by `client_id`, as of its consensus state at `height`.
+    async fn verify_client_non_membership<HI: HostInterface>(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+        prefix: &CounterpartyPrefix,
+        proof: &MerkleProof,
+        path: Path,
+    ) -> Result<()> {
+        let (light_client, client_state, consensus_state) =
+            self.light_client_states::<HI>(client_id, height).await?;
+
+        light_client.verify_non_membership(&client_state, &consensus_state, prefix, proof, path)
+    }
+
+    /// Gets the light client for `client_id`'s type, along with its encoded
+    /// client state and its consensus state at `height`.
+    async fn light_client_states<HI: HostInterface>(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<(Arc<dyn LightClient>, Vec<u8>, Vec<u8>)> {
+        let client_type = self.get_client_type(client_id).await?;
+        let light_client = HI::light_clients().require(&client_type)?;
+
+        let client_state = self
+            .get_raw(
+                &IBC_COMMITMENT_PREFIX.apply_string(ClientStatePath(client_id.clone()).to_string()),
+            )
+            .await?
+            .context(format!("could not find client state for {client_id}"))?;
+        let consensus_state = self
+            .get_raw(
+                &IBC_COMMITMENT_PREFIX
+                    .apply_string(ClientConsensusStatePath::new(client_id, height).to_string()),
+            )
+            .await?
+            .context(format!(
+                "counterparty consensus state not found for client {client_id} at height {height}"
+            ))?;
+
+        Ok((light_client, client_state, consensus_state))
+    }
+}
+
+impl<T: StateRead> LightClientVerifier for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct SoloMachineLightClient;
+
+    impl LightClient for SoloMachineLightClient {
+        fn client_type(&self) -> ClientType {
+            ClientType::new("06-solomachine".to_string())
+        }
+
+        fn validate_client_state(&self, _client_state: &Any) -> Result<()> {
+            Ok(())
+        }
+
+        fn validate_consensus_state(&self, _consensus_state: &Any) -> Result<()> {
+            Ok(())
+        }
+
+        fn verify_membership(
+            &self,
+            _client_state: &[u8],
+            _consensus_state: &[u8],
+            _prefix: &CounterpartyPrefix,
+            _proof: &MerkleProof,
+            _path: Path,
+            _value: Vec<u8>,
+        ) -> Result<()> {
+            Ok(())
+        }
+
+        fn verify_non_membership(
+            &self,
+            _client_state: &[u8],
+            _consensus_state: &[u8],
+            _prefix: &CounterpartyPrefix,
+            _proof: &MerkleProof,
+            _path: Path,
+        ) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn light_clients_are_looked_up_by_client_type() {
+        let mut registry = LightClientRegistry::default();
+        let solo_machine = ClientType::new("06-solomachine".to_string());
+        assert!(registry
+            .get(&ibc_types::lightclients::tendermint::client_type())
+            .is_some());
+        assert!(registry.require(&solo_machine).is_err());
+
+        registry.register(SoloMachineLightClient);
+        assert_eq!(
+            registry.require(&solo_machine).unwrap().client_type(),
+            solo_machine
+        );
+        assert_eq!(
+            registry.client_types().collect::<Vec<_>>(),
+            vec!["06-solomachine", "07-tendermint"]
+        );
+    }
+}
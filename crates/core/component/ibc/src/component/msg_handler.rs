@@ -13,7 +13,8 @@ mod create_client;
 mod misbehavior;
 mod recv_packet;
 mod timeout;
-mod update_client;
+mod timeout_on_close;
+pub(crate) mod update_client;
 mod upgrade_client;
 
 use crate::component::app_handler::{AppHandlerCheck, AppHandlerExecute};
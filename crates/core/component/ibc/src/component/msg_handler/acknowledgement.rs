@@ -3,15 +3,15 @@ use async_trait::async_trait;
 use cnidarium::StateWrite;
 use ibc_types::core::{
     channel::channel::Order as ChannelOrder, channel::channel::State as ChannelState,
-    channel::events, channel::msgs::MsgAcknowledgement, channel::PortId,
-    connection::State as ConnectionState,
+    channel::events, channel::msgs::MsgAcknowledgement, connection::State as ConnectionState,
 };
 
 use crate::component::{
     app_handler::{AppHandlerCheck, AppHandlerExecute},
     channel::{StateReadExt as _, StateWriteExt as _},
     connection::StateReadExt as _,
-    proof_verification::{commit_packet, PacketProofVerifier},
+    metrics,
+    proof_verification::{packet_commitment_matches, PacketProofVerifier},
     HostInterface, MsgHandler,
 };
 
@@ -69,12 +69,12 @@ impl MsgHandler for MsgAcknowledgement {
             .get_packet_commitment(&self.packet)
             .await?
             .ok_or_else(|| anyhow::anyhow!("packet commitment not found"))?;
-        if commitment != commit_packet(&self.packet) {
+        if !packet_commitment_matches(&self.packet, &commitment) {
             anyhow::bail!("packet commitment does not match");
         }
 
         state
-            .verify_packet_ack_proof::<HI>(&connection, self)
+            .verify_packet_ack_proof::<HI>(&channel.connection_hops[0], &connection, self)
             .await
             .with_context(|| "packet ack proof verification failed")?;
 
@@ -87,8 +87,7 @@ impl MsgHandler for MsgAcknowledgement {
             }
         }
 
-        let transfer = PortId::transfer();
-        if self.packet.port_on_b == transfer {
+        if AH::is_bound_port(&self.packet.port_on_b) {
             AH::acknowledge_packet_check(&mut state, self).await?;
         } else {
             anyhow::bail!("invalid port id");
@@ -126,9 +125,16 @@ impl MsgHandler for MsgAcknowledgement {
             }
             .into(),
         );
+        metrics::count_in_block(
+            &mut state,
+            metrics::IBC_PACKETS_ACKNOWLEDGED_TOTAL,
+            vec![
+                ("port_id", self.packet.port_on_a.to_string()),
+                ("channel_id", self.packet.chan_on_a.to_string()),
+            ],
+        );
 
-        let transfer = PortId::transfer();
-        if self.packet.port_on_b == transfer {
+        if AH::is_bound_port(&self.packet.port_on_b) {
             AH::acknowledge_packet_execute(state, self).await?;
         } else {
             anyhow::bail!("invalid port id");
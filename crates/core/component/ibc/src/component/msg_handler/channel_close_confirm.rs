@@ -4,7 +4,7 @@ use cnidarium::StateWrite;
 use ibc_types::core::{
     channel::{
         channel::State as ChannelState, events, msgs::MsgChannelCloseConfirm, ChannelEnd,
-        Counterparty, PortId,
+        Counterparty,
     },
     connection::State as ConnectionState,
 };
@@ -73,7 +73,8 @@ impl MsgHandler for MsgChannelCloseConfirm {
         };
 
         state
-            .verify_channel_proof(
+            .verify_channel_proof::<HI>(
+                &channel.connection_hops[0],
                 &connection,
                 &self.proof_chan_end_on_a,
                 &self.proof_height_on_a,
@@ -87,8 +88,7 @@ impl MsgHandler for MsgChannelCloseConfirm {
             )
             .await?;
 
-        let transfer = PortId::transfer();
-        if self.port_id_on_b == transfer {
+        if AH::is_bound_port(&self.port_id_on_b) {
             AH::chan_close_confirm_check(&mut state, self).await?;
         } else {
             anyhow::bail!("invalid port id");
@@ -112,9 +112,8 @@ impl MsgHandler for MsgChannelCloseConfirm {
         );
 
         // TODO: should this be part of the handler?
-        let transfer = PortId::transfer();
-        if self.port_id_on_b == transfer {
-            AH::chan_close_confirm_execute(state, self).await;
+        if AH::is_bound_port(&self.port_id_on_b) {
+            AH::chan_close_confirm_execute(state, self).await?;
         } else {
             anyhow::bail!("invalid port id");
         }
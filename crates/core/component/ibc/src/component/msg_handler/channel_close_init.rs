@@ -3,7 +3,7 @@ use async_trait::async_trait;
 use cnidarium::StateWrite;
 use ibc_types::core::{
     channel::channel::State as ChannelState, channel::events, channel::msgs::MsgChannelCloseInit,
-    channel::PortId, connection::State as ConnectionState,
+    connection::State as ConnectionState,
 };
 
 use crate::component::{
@@ -50,8 +50,7 @@ impl MsgHandler for MsgChannelCloseInit {
         if !connection.state_matches(&ConnectionState::Open) {
             anyhow::bail!("connection for channel is not open");
         }
-        let transfer = PortId::transfer();
-        if self.port_id_on_a == transfer {
+        if AH::is_bound_port(&self.port_id_on_a) {
             AH::chan_close_init_check(&mut state, self).await?;
         } else {
             anyhow::bail!("invalid port id");
@@ -75,9 +74,8 @@ impl MsgHandler for MsgChannelCloseInit {
             .into(),
         );
 
-        let transfer = PortId::transfer();
-        if self.port_id_on_a == transfer {
-            AH::chan_close_init_execute(state, self).await;
+        if AH::is_bound_port(&self.port_id_on_a) {
+            AH::chan_close_init_execute(state, self).await?;
         } else {
             anyhow::bail!("invalid port id");
         }
@@ -3,7 +3,7 @@ use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
 use ibc_types::core::{
     channel::channel::State as ChannelState, channel::events, channel::msgs::MsgChannelOpenAck,
-    channel::ChannelEnd, channel::Counterparty, channel::PortId, connection::ConnectionEnd,
+    channel::ChannelEnd, channel::Counterparty, connection::ConnectionEnd,
     connection::State as ConnectionState,
 };
 
@@ -37,7 +37,7 @@ impl MsgHandler for MsgChannelOpenAck {
             .await?
             .ok_or_else(|| anyhow::anyhow!("channel not found"))?;
 
-        channel_state_is_correct(&channel)?;
+        channel_state_is_correct(&channel, self)?;
 
         // TODO: capability authentication?
 
@@ -61,7 +61,8 @@ impl MsgHandler for MsgChannelOpenAck {
         };
 
         state
-            .verify_channel_proof(
+            .verify_channel_proof::<HI>(
+                &channel.connection_hops[0],
                 &connection,
                 &self.proof_chan_end_on_b,
                 &self.proof_height_on_b,
@@ -71,8 +72,7 @@ impl MsgHandler for MsgChannelOpenAck {
             )
             .await?;
 
-        let transfer = PortId::transfer();
-        if self.port_id_on_a == transfer {
+        if AH::is_bound_port(&self.port_id_on_a) {
             AH::chan_open_ack_check(&mut state, self).await?;
         } else {
             anyhow::bail!("invalid port id");
@@ -82,6 +82,9 @@ impl MsgHandler for MsgChannelOpenAck {
         channel.set_version(self.version_on_b.clone());
         channel.set_counterparty_channel_id(self.chan_id_on_b.clone());
         state.put_channel(&self.chan_id_on_a, &self.port_id_on_a, channel.clone());
+        if let Some(sentinel) = AH::pending_ack_sentinel(&self.port_id_on_a, &channel.version) {
+            state.put_pending_ack_sentinel(&self.chan_id_on_a, &self.port_id_on_a, sentinel);
+        }
 
         state.record(
             events::channel::OpenAck {
@@ -98,9 +101,8 @@ impl MsgHandler for MsgChannelOpenAck {
             .into(),
         );
 
-        let transfer = PortId::transfer();
-        if self.port_id_on_a == transfer {
-            AH::chan_open_ack_execute(state, self).await;
+        if AH::is_bound_port(&self.port_id_on_a) {
+            AH::chan_open_ack_execute(state, self).await?;
         } else {
             anyhow::bail!("invalid port id");
         }
@@ -109,8 +111,14 @@ impl MsgHandler for MsgChannelOpenAck {
     }
 }
 
-fn channel_state_is_correct(channel: &ChannelEnd) -> anyhow::Result<()> {
-    if channel.state == ChannelState::Init {
+// a channel in TRYOPEN is one whose init crossed the counterparty's ("crossing hellos"), so its
+// version and counterparty are already set.
+fn channel_state_is_correct(channel: &ChannelEnd, msg: &MsgChannelOpenAck) -> anyhow::Result<()> {
+    let state_is_consistent = channel.state == ChannelState::Init
+        || (channel.state == ChannelState::TryOpen
+            && channel.version == msg.version_on_b
+            && channel.remote.channel_id.as_ref() == Some(&msg.chan_id_on_b));
+    if state_is_consistent {
         Ok(())
     } else {
         Err(anyhow::anyhow!("channel is not in the correct state"))
@@ -3,8 +3,7 @@ use async_trait::async_trait;
 use cnidarium::StateWrite;
 use ibc_types::core::{
     channel::channel::State as ChannelState, channel::events, channel::msgs::MsgChannelOpenConfirm,
-    channel::ChannelEnd, channel::Counterparty, channel::PortId,
-    connection::State as ConnectionState,
+    channel::ChannelEnd, channel::Counterparty, connection::State as ConnectionState,
 };
 
 use crate::component::{
@@ -68,7 +67,8 @@ impl MsgHandler for MsgChannelOpenConfirm {
         };
 
         state
-            .verify_channel_proof(
+            .verify_channel_proof::<HI>(
+                &channel.connection_hops[0],
                 &connection,
                 &self.proof_chan_end_on_a,
                 &self.proof_height_on_a,
@@ -82,8 +82,7 @@ impl MsgHandler for MsgChannelOpenConfirm {
             )
             .await?;
 
-        let transfer = PortId::transfer();
-        if self.port_id_on_b == transfer {
+        if AH::is_bound_port(&self.port_id_on_b) {
             AH::chan_open_confirm_check(&mut state, self).await?;
         } else {
             anyhow::bail!("invalid port id");
@@ -107,9 +106,8 @@ impl MsgHandler for MsgChannelOpenConfirm {
             .into(),
         );
 
-        let transfer = PortId::transfer();
-        if self.port_id_on_b == transfer {
-            AH::chan_open_confirm_execute(state, self).await;
+        if AH::is_bound_port(&self.port_id_on_b) {
+            AH::chan_open_confirm_execute(state, self).await?;
         } else {
             anyhow::bail!("invalid port id");
         }
@@ -3,7 +3,7 @@ use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
 use ibc_types::core::channel::msgs::MsgChannelOpenInit;
 use ibc_types::core::channel::{
-    channel::State, events, ChannelEnd, ChannelId, Counterparty, PortId,
+    channel::Order, channel::State, events, ChannelEnd, ChannelId, Counterparty, PortId,
 };
 
 use crate::component::HostInterface;
@@ -18,6 +18,7 @@ use crate::component::{
 impl MsgHandler for MsgChannelOpenInit {
     async fn check_stateless<H: AppHandlerCheck>(&self) -> Result<()> {
         connection_hops_eq_1(self)?;
+        ordering_is_set(self.ordering)?;
 
         Ok(())
     }
@@ -41,8 +42,7 @@ impl MsgHandler for MsgChannelOpenInit {
 
         // TODO: do we want to do capability authentication?
 
-        let transfer = PortId::transfer();
-        if self.port_id_on_a == transfer {
+        if AH::is_bound_port(&self.port_id_on_a) {
             AH::chan_open_init_check(&mut state, self).await?;
         } else {
             anyhow::bail!("invalid port id");
@@ -75,9 +75,8 @@ impl MsgHandler for MsgChannelOpenInit {
             .into(),
         );
 
-        let transfer = PortId::transfer();
-        if self.port_id_on_a == transfer {
-            AH::chan_open_init_execute(state, self).await;
+        if AH::is_bound_port(&self.port_id_on_a) {
+            AH::chan_open_init_execute(state, self).await?;
         } else {
             anyhow::bail!("invalid port id");
         }
@@ -92,6 +91,13 @@ fn connection_hops_eq_1(msg: &MsgChannelOpenInit) -> anyhow::Result<()> {
     }
     Ok(())
 }
+fn ordering_is_set(ordering: Order) -> anyhow::Result<()> {
+    if ordering == Order::None {
+        anyhow::bail!("channel must be either ordered or unordered");
+    }
+    Ok(())
+}
+
 async fn verify_connections_exist<S: StateRead>(
     state: S,
     msg: &MsgChannelOpenInit,
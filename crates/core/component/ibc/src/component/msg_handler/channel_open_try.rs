@@ -1,13 +1,17 @@
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
+use futures::StreamExt as _;
 use ibc_types::core::{
     channel::{
-        channel::State as ChannelState, events, msgs::MsgChannelOpenTry, ChannelEnd, Counterparty,
-        PortId,
+        channel::{Order, State as ChannelState},
+        events,
+        msgs::MsgChannelOpenTry,
+        ChannelEnd, ChannelId, Counterparty,
     },
     connection::{ConnectionEnd, State as ConnectionState},
 };
+use penumbra_proto::StateReadProto as _;
 
 use crate::component::{
     app_handler::{AppHandlerCheck, AppHandlerExecute},
@@ -16,11 +20,14 @@ use crate::component::{
     proof_verification::ChannelProofVerifier,
     HostInterface, MsgHandler,
 };
+use crate::prefix::MerklePrefixExt as _;
+use crate::IBC_COMMITMENT_PREFIX;
 
 #[async_trait]
 impl MsgHandler for MsgChannelOpenTry {
     async fn check_stateless<H: AppHandlerCheck>(&self) -> Result<()> {
         connection_hops_eq_1(self)?;
+        ordering_is_set(self.ordering)?;
 
         Ok(())
     }
@@ -54,7 +61,8 @@ impl MsgHandler for MsgChannelOpenTry {
         tracing::debug!(?self, ?expected_channel_on_a);
 
         state
-            .verify_channel_proof(
+            .verify_channel_proof::<HI>(
+                &self.connection_hops_on_b[0],
                 &connection_on_b,
                 &self.proof_chan_end_on_a,
                 &self.proof_height_on_a,
@@ -64,29 +72,45 @@ impl MsgHandler for MsgChannelOpenTry {
             )
             .await?;
 
-        let transfer = PortId::transfer();
-        if self.port_id_on_b == transfer {
+        if AH::is_bound_port(&self.port_id_on_b) {
             AH::chan_open_try_check(&mut state, self).await?;
         } else {
             anyhow::bail!("invalid port id");
         }
+        let version = AH::chan_open_try_version(&state, self).await?;
 
-        let channel_id = state
-            .next_channel_id()
-            .await
-            .context("unable to retrieve next channel id")?;
         let new_channel = ChannelEnd {
             state: ChannelState::TryOpen,
             ordering: self.ordering,
             remote: Counterparty::new(self.port_id_on_a.clone(), Some(self.chan_id_on_a.clone())),
             connection_hops: self.connection_hops_on_b.clone(),
-            version: self.version_supported_on_a.clone(),
+            version,
         };
 
-        state.put_channel(&channel_id, &self.port_id_on_b, new_channel.clone());
-        state.put_send_sequence(&channel_id, &self.port_id_on_b, 1);
-        state.put_recv_sequence(&channel_id, &self.port_id_on_b, 1);
-        state.put_ack_sequence(&channel_id, &self.port_id_on_b, 1);
+        // if we initiated a channel to the counterparty at the same time as it initiated this one
+        // ("crossing hellos"), the two are merged: our channel moves from INIT to TRYOPEN, rather
+        // than a second channel being opened.
+        let channel_id = match crossing_hello_channel(&state, self).await? {
+            Some(channel_id) => {
+                tracing::debug!(%channel_id, "channel open try crosses a local init");
+                state.put_channel(&channel_id, &self.port_id_on_b, new_channel.clone());
+                channel_id
+            }
+            None => {
+                let channel_id = state
+                    .next_channel_id()
+                    .await
+                    .context("unable to retrieve next channel id")?;
+                state.put_channel(&channel_id, &self.port_id_on_b, new_channel.clone());
+                state.put_send_sequence(&channel_id, &self.port_id_on_b, 1);
+                state.put_recv_sequence(&channel_id, &self.port_id_on_b, 1);
+                state.put_ack_sequence(&channel_id, &self.port_id_on_b, 1);
+                channel_id
+            }
+        };
+        if let Some(sentinel) = AH::pending_ack_sentinel(&self.port_id_on_b, &new_channel.version) {
+            state.put_pending_ack_sentinel(&channel_id, &self.port_id_on_b, sentinel);
+        }
 
         state.record(
             events::channel::OpenTry {
@@ -104,9 +128,8 @@ impl MsgHandler for MsgChannelOpenTry {
             .into(),
         );
 
-        let transfer = PortId::transfer();
-        if self.port_id_on_b == transfer {
-            AH::chan_open_try_execute(state, self).await;
+        if AH::is_bound_port(&self.port_id_on_b) {
+            AH::chan_open_try_execute(state, self).await?;
         } else {
             anyhow::bail!("invalid port id");
         }
@@ -122,6 +145,13 @@ pub fn connection_hops_eq_1(msg: &MsgChannelOpenTry) -> anyhow::Result<()> {
     Ok(())
 }
 
+fn ordering_is_set(ordering: Order) -> anyhow::Result<()> {
+    if ordering == Order::None {
+        anyhow::bail!("channel must be either ordered or unordered");
+    }
+    Ok(())
+}
+
 async fn verify_connections_open<S: StateRead>(
     state: S,
     msg: &MsgChannelOpenTry,
@@ -137,3 +167,34 @@ async fn verify_connections_open<S: StateRead>(
         Ok(connection)
     }
 }
+
+/// Finds a channel that we initiated, over the same connection and with the
+/// same ordering, to the counterparty port that initiated the channel being
+/// tried.
+///
+/// See: https://github.com/cosmos/ibc/tree/main/spec/core/ics-004-channel-and-packet-semantics#opening-handshake
+async fn crossing_hello_channel<S: StateRead>(
+    state: S,
+    msg: &MsgChannelOpenTry,
+) -> anyhow::Result<Option<ChannelId>> {
+    let prefix = IBC_COMMITMENT_PREFIX
+        .apply_string(format!("channelEnds/ports/{}/channels/", msg.port_id_on_b));
+    let mut channels = state.prefix::<ChannelEnd>(&prefix);
+    while let Some((key, channel)) = channels.next().await.transpose()? {
+        if channel.state == ChannelState::Init
+            && channel.ordering == msg.ordering
+            && channel.connection_hops == msg.connection_hops_on_b
+            && channel.remote.port_id == msg.port_id_on_a
+            && channel.remote.channel_id.is_none()
+        {
+            let channel_id = key
+                .rsplit('/')
+                .next()
+                .expect("key is well-formed")
+                .parse()?;
+            return Ok(Some(channel_id));
+        }
+    }
+
+    Ok(None)
+}
@@ -28,7 +28,8 @@ impl MsgHandler for MsgConnectionOpenAck {
         tracing::debug!(msg = ?self);
         // Validate a ConnectionOpenAck message, which is sent to us by a counterparty chain that
         // has committed a Connection to us expected to be in the TRYOPEN state. Before executing a
-        // ConnectionOpenAck, we must have a prior connection to this chain in the INIT state.
+        // ConnectionOpenAck, we must have a prior connection to this chain in the INIT state, or
+        // in the TRYOPEN state if both chains initiated the connection at once.
         //
         // In order to verify a ConnectionOpenAck, we need to check that the counterparty chain has
         // committed a _valid_ Penumbra consensus state, that the counterparty chain has committed
@@ -80,6 +81,10 @@ impl MsgHandler for MsgConnectionOpenAck {
             .get_verified_consensus_state(&self.proofs_height_on_b, &connection.client_id)
             .await?;
 
+        let counterparty_prefix = state
+            .get_counterparty_prefix(&self.conn_id_on_a, &connection)
+            .await?;
+
         // PROOF VERIFICATION
         // 1. verify that the counterparty chain committed the expected_conn to its state
         tracing::debug!(?trusted_client_state,);
@@ -87,7 +92,7 @@ impl MsgHandler for MsgConnectionOpenAck {
             msg.proofs_height_on_b = ?self.proofs_height_on_b,
         );
         tracing::debug!(
-            counterparty_prefix = ?connection.counterparty.prefix,
+            counterparty_prefix = ?counterparty_prefix,
         );
         tracing::debug!(
             msg.proof_conn_end_on_b = ?self.proof_conn_end_on_b,
@@ -105,7 +110,7 @@ impl MsgHandler for MsgConnectionOpenAck {
         proof_verification::verify_connection_state(
             &trusted_client_state,
             self.proofs_height_on_b,
-            &connection.counterparty.prefix,
+            &counterparty_prefix,
             &conn_end_on_b_proof,
             &trusted_consensus_state.root,
             &ConnectionPath::new(&self.conn_id_on_b),
@@ -122,7 +127,7 @@ impl MsgHandler for MsgConnectionOpenAck {
         proof_verification::verify_client_full_state(
             &trusted_client_state,
             self.proofs_height_on_b,
-            &connection.counterparty.prefix,
+            &counterparty_prefix,
             &proof_client_state_of_a_on_b,
             &trusted_consensus_state.root,
             &ClientStatePath::new(&connection.counterparty.client_id),
@@ -140,7 +145,7 @@ impl MsgHandler for MsgConnectionOpenAck {
         proof_verification::verify_client_consensus_state(
             &trusted_client_state,
             self.proofs_height_on_b,
-            &connection.counterparty.prefix,
+            &counterparty_prefix,
             &proof_consensus_state_of_a_on_b,
             &trusted_consensus_state.root,
             &ClientConsensusStatePath::new(
@@ -231,9 +236,13 @@ async fn verify_previous_connection<S: StateRead>(
     // see
     // https://github.com/cosmos/ibc/blob/master/spec/core/ics-003-connection-semantics/README.md
     //
-    // for this validation logic
-    let state_is_consistent =
-        connection.state_matches(&State::Init) && connection.versions.contains(&msg.version);
+    // for this validation logic. a connection in TRYOPEN is one whose init crossed the
+    // counterparty's ("crossing hellos"), so its version and counterparty are already set.
+    let state_is_consistent = (connection.state_matches(&State::Init)
+        && connection.versions.contains(&msg.version))
+        || (connection.state_matches(&State::TryOpen)
+            && connection.versions == vec![msg.version.clone()]
+            && connection.counterparty.connection_id.as_ref() == Some(&msg.conn_id_on_b));
 
     if !state_is_consistent {
         anyhow::bail!("connection is not in the correct state");
@@ -77,7 +77,9 @@ impl MsgHandler for MsgConnectionOpenConfirm {
         proof_verification::verify_connection_state(
             &trusted_client_state,
             self.proof_height_on_a,
-            &connection.counterparty.prefix,
+            &state
+                .get_counterparty_prefix(&self.conn_id_on_b, &connection)
+                .await?,
             &proof_conn_end_on_a,
             &trusted_consensus_state.root,
             &ConnectionPath::new(connection.counterparty.connection_id.as_ref().ok_or_else(
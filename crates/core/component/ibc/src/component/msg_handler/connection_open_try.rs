@@ -1,6 +1,6 @@
 use crate::component::{proof_verification, HostInterface};
 use crate::version::pick_connection_version;
-use crate::IBC_COMMITMENT_PREFIX;
+use crate::{CounterpartyPrefix, IBC_COMMITMENT_PREFIX};
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
@@ -10,7 +10,7 @@ use ibc_types::{
     core::client::Height as IBCHeight,
     core::connection::{
         events, msgs::MsgConnectionOpenTry, ConnectionEnd, ConnectionId, Counterparty,
-        State as ConnectionState,
+        State as ConnectionState, Version,
     },
 };
 
@@ -96,13 +96,17 @@ impl MsgHandler for MsgConnectionOpenTry {
             .get_verified_consensus_state(&self.proofs_height_on_a, &self.client_id_on_b)
             .await?;
 
+        // No metadata can be registered for a connection that doesn't exist yet,
+        // so the counterparty's commitment prefix is applied as a single key.
+        let counterparty_prefix = CounterpartyPrefix::from(self.counterparty.prefix.clone());
+
         // PROOF VERIFICATION
         // 1. verify that the counterparty chain committed the expected_conn to its state
         let proof_conn_end_on_a = self.proof_conn_end_on_a.clone();
         proof_verification::verify_connection_state(
             &trusted_client_state,
             self.proofs_height_on_a,
-            &self.counterparty.prefix,
+            &counterparty_prefix,
             &proof_conn_end_on_a,
             &trusted_consensus_state.root,
             &ConnectionPath::new(
@@ -125,7 +129,7 @@ impl MsgHandler for MsgConnectionOpenTry {
         proof_verification::verify_client_full_state(
             &trusted_client_state,
             self.proofs_height_on_a,
-            &self.counterparty.prefix,
+            &counterparty_prefix,
             &proof_client_state_of_b_on_a,
             &trusted_consensus_state.root,
             &ClientStatePath::new(&self.counterparty.client_id),
@@ -143,7 +147,7 @@ impl MsgHandler for MsgConnectionOpenTry {
         proof_verification::verify_client_consensus_state(
             &trusted_client_state,
             self.proofs_height_on_a,
-            &self.counterparty.prefix,
+            &counterparty_prefix,
             &proof_consensus_state_of_b_on_a,
             &trusted_consensus_state.root,
             &ClientConsensusStatePath::new(
@@ -155,33 +159,46 @@ impl MsgHandler for MsgConnectionOpenTry {
         .context("couldn't verify client consensus state")?;
 
         // VALIDATION SUCCESSFUL, now execute
-        //
-        // new_conn is the new connection that we will open on this chain
-        let mut new_conn = ConnectionEnd {
-            state: ConnectionState::TryOpen,
-            client_id: self.client_id_on_b.clone(),
-            counterparty: self.counterparty.clone(),
-            versions: self.versions_on_a.clone(),
-            delay_period: self.delay_period,
-        };
+        let version =
+            pick_connection_version(&SUPPORTED_VERSIONS.to_vec(), &self.versions_on_a.clone())?;
 
-        new_conn.versions = vec![pick_connection_version(
-            &SUPPORTED_VERSIONS.to_vec(),
-            &self.versions_on_a.clone(),
-        )?];
-
-        let new_connection_id = ConnectionId::new(
-            state
-                .get_connection_counter()
-                .await
-                .context("unable to get connection counter")?
-                .0,
-        );
+        // if we initiated a connection to the counterparty at the same time as it initiated this
+        // one ("crossing hellos"), the two are merged: our connection moves from INIT to TRYOPEN,
+        // rather than a second connection being opened.
+        let new_connection_id = match crossing_hello_connection(&state, self, &version).await? {
+            Some((connection_id, mut connection)) => {
+                tracing::debug!(%connection_id, "connection open try crosses a local init");
+                connection.state = ConnectionState::TryOpen;
+                connection.counterparty = self.counterparty.clone();
+                connection.versions = vec![version];
+                state.update_connection(&connection_id, connection);
+                connection_id
+            }
+            None => {
+                // new_conn is the new connection that we will open on this chain
+                let new_conn = ConnectionEnd {
+                    state: ConnectionState::TryOpen,
+                    client_id: self.client_id_on_b.clone(),
+                    counterparty: self.counterparty.clone(),
+                    versions: vec![version],
+                    delay_period: self.delay_period,
+                };
 
-        state
-            .put_new_connection(&new_connection_id, new_conn)
-            .await
-            .context("unable to put new connection")?;
+                let new_connection_id = ConnectionId::new(
+                    state
+                        .get_connection_counter()
+                        .await
+                        .context("unable to get connection counter")?
+                        .0,
+                );
+
+                state
+                    .put_new_connection(&new_connection_id, new_conn)
+                    .await
+                    .context("unable to put new connection")?;
+                new_connection_id
+            }
+        };
 
         state.record(
             events::ConnectionOpenTry {
@@ -220,3 +237,31 @@ async fn penumbra_client_state_is_well_formed<S: StateRead, HI: HostInterface>(
 
     Ok(())
 }
+
+/// Finds a connection that we initiated, on the same client and with the same
+/// parameters, to the counterparty that initiated the connection being tried.
+///
+/// See: https://github.com/cosmos/ibc/tree/main/spec/core/ics-003-connection-semantics#opening-handshake
+async fn crossing_hello_connection<S: StateRead>(
+    state: S,
+    msg: &MsgConnectionOpenTry,
+    version: &Version,
+) -> anyhow::Result<Option<(ConnectionId, ConnectionEnd)>> {
+    let client_connections = state.get_client_connections(&msg.client_id_on_b).await?;
+    for connection_id in client_connections.paths {
+        let Some(connection) = state.get_connection(&connection_id).await? else {
+            continue;
+        };
+        if connection.state_matches(&ConnectionState::Init)
+            && connection.counterparty.client_id == msg.counterparty.client_id
+            && connection.counterparty.connection_id.is_none()
+            && connection.counterparty.prefix == msg.counterparty.prefix
+            && connection.delay_period == msg.delay_period
+            && connection.versions.contains(version)
+        {
+            return Ok(Some((connection_id, connection)));
+        }
+    }
+
+    Ok(None)
+}
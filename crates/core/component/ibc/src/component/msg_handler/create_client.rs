@@ -9,7 +9,9 @@ use ibc_types::{
 use crate::component::{
     client::{ConsensusStateWriteExt as _, StateReadExt as _, StateWriteExt as _},
     client_counter::ClientCounter,
-    ics02_validation, HostInterface, MsgHandler,
+    ics02_validation,
+    view::StateReadExt as _,
+    HostInterface, MsgHandler,
 };
 
 #[async_trait]
@@ -30,8 +32,17 @@ impl MsgHandler for MsgCreateClient {
     // - processed time and height
     async fn try_execute<S: StateWrite, AH, HI: HostInterface>(&self, mut state: S) -> Result<()> {
         tracing::debug!(msg = ?self);
+        let light_client = HI::light_clients().require(&client_type())?;
+        light_client.validate_client_state(&self.client_state)?;
+        light_client.validate_consensus_state(&self.consensus_state)?;
+
         let client_state =
             ics02_validation::get_tendermint_client_state(self.client_state.clone())?;
+        state
+            .get_ibc_params()
+            .await?
+            .check_proof_specs(&client_state.proof_specs)
+            .context("MsgCreateClient: unacceptable proof specs")?;
 
         // get the current client counter
         let id_counter = state.client_counter().await?;
@@ -12,7 +12,7 @@ use tendermint_light_client_verifier::{
     ProdVerifier, Verdict, Verifier,
 };
 
-use super::update_client::verify_header_validator_set;
+use super::update_client::{header_revision_matches_client_state, verify_header_validator_set};
 use super::MsgHandler;
 use crate::component::client::StateWriteExt as _;
 use crate::component::HostInterface;
@@ -24,6 +24,15 @@ impl MsgHandler for MsgSubmitMisbehaviour {
         misbehavior_is_tendermint(self)?;
         let untrusted_misbehavior =
             ics02_validation::get_tendermint_misbehavior(self.misbehaviour.clone())?;
+        // the evidence is checked against the consensus states of the client it names, so it
+        // must name the client being frozen.
+        if untrusted_misbehavior.client_id != self.client_id {
+            anyhow::bail!(
+                "misbehavior is for client {}, but was submitted for client {}",
+                untrusted_misbehavior.client_id,
+                self.client_id
+            );
+        }
         // misbehavior must either contain equivocation or timestamp monotonicity violation
         if !misbehavior_equivocation_violation(&untrusted_misbehavior)
             && !misbehavior_timestamp_monotonicity_violation(&untrusted_misbehavior)
@@ -118,6 +127,10 @@ async fn verify_misbehavior_header<S: StateRead, HI: HostInterface>(
     mb_header: &TendermintHeader,
     trusted_client_state: &TendermintClientState,
 ) -> Result<()> {
+    // evidence from another revision of the counterparty can't be checked against this client's
+    // consensus states.
+    header_revision_matches_client_state(trusted_client_state, mb_header)?;
+
     let trusted_height = mb_header.trusted_height;
     let last_trusted_consensus_state = state
         .get_verified_consensus_state(&trusted_height, &client_id)
@@ -189,3 +202,66 @@ fn misbehavior_is_tendermint(msg: &MsgSubmitMisbehaviour) -> Result<()> {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use base64::prelude::*;
+    use ibc_types::core::client::msgs::MsgUpdateClient;
+    use ibc_types::DomainType;
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn test_header(raw: &str) -> TendermintHeader {
+        let msg_update_client_raw = BASE64_STANDARD.decode(raw.replace('\n', "")).unwrap();
+        let msg_update_client = MsgUpdateClient::decode(msg_update_client_raw.as_slice()).unwrap();
+        ics02_validation::get_tendermint_header(msg_update_client.client_message).unwrap()
+    }
+
+    fn misbehavior(header1: TendermintHeader, header2: TendermintHeader) -> TendermintMisbehavior {
+        TendermintMisbehavior {
+            client_id: ClientId::from_str("07-tendermint-0").unwrap(),
+            header1,
+            header2,
+        }
+    }
+
+    #[test]
+    fn conflicting_headers_at_the_same_height_are_equivocation() {
+        let header = test_header(include_str!("../test/update_client_1.msg"));
+
+        // the same header twice is not evidence of anything.
+        assert!(!misbehavior_equivocation_violation(&misbehavior(
+            header.clone(),
+            header.clone()
+        )));
+
+        let mut conflicting_header = header.clone();
+        conflicting_header.signed_header.commit.block_id.hash =
+            tendermint::Hash::Sha256([0xab; 32]);
+        assert!(misbehavior_equivocation_violation(&misbehavior(
+            header,
+            conflicting_header
+        )));
+    }
+
+    #[test]
+    fn earlier_headers_with_later_timestamps_violate_monotonicity() {
+        let header1 = test_header(include_str!("../test/update_client_1.msg"));
+        let header2 = test_header(include_str!("../test/update_client_2.msg"));
+        assert!(header1.height() < header2.height());
+
+        assert!(!misbehavior_timestamp_monotonicity_violation(&misbehavior(
+            header1.clone(),
+            header2.clone()
+        )));
+
+        let mut late_header1 = header1;
+        late_header1.signed_header.header.time =
+            (header2.signed_header.header.time + std::time::Duration::from_secs(1)).unwrap();
+        assert!(misbehavior_timestamp_monotonicity_violation(&misbehavior(
+            late_header1,
+            header2
+        )));
+    }
+}
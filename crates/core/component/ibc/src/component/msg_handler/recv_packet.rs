@@ -1,3 +1,5 @@
+use std::cmp::Ordering;
+
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use cnidarium::StateWrite;
@@ -6,7 +8,6 @@ use ibc_types::core::{
         channel::{Order as ChannelOrder, State as ChannelState},
         events,
         msgs::MsgRecvPacket,
-        PortId,
     },
     client::Height as IBCHeight,
     connection::State as ConnectionState,
@@ -16,6 +17,7 @@ use crate::component::{
     app_handler::{AppHandlerCheck, AppHandlerExecute},
     channel::{StateReadExt as _, StateWriteExt},
     connection::StateReadExt as _,
+    metrics,
     proof_verification::PacketProofVerifier,
     HostInterface, MsgHandler,
 };
@@ -92,7 +94,7 @@ impl MsgHandler for MsgRecvPacket {
         }
 
         state
-            .verify_packet_recv_proof::<HI>(&connection, self)
+            .verify_packet_recv_proof::<HI>(&channel.connection_hops[0], &connection, self)
             .await
             .with_context(|| format!("packet {:?} failed to verify", self.packet))?;
 
@@ -101,15 +103,12 @@ impl MsgHandler for MsgRecvPacket {
                 .get_recv_sequence(&self.packet.chan_on_b, &self.packet.port_on_b)
                 .await?;
 
-            if self.packet.sequence != next_sequence_recv.into() {
-                anyhow::bail!("packet sequence number does not match");
-            }
+            check_ordered_recv_sequence(self.packet.sequence.into(), next_sequence_recv)?;
         } else if state.seen_packet(&self.packet).await? {
             anyhow::bail!("packet has already been processed");
         }
 
-        let transfer = PortId::transfer();
-        if self.packet.port_on_b == transfer {
+        if AH::is_bound_port(&self.packet.port_on_b) {
             AH::recv_packet_check(&mut state, self).await?;
         } else {
             anyhow::bail!("invalid port id");
@@ -148,10 +147,17 @@ impl MsgHandler for MsgRecvPacket {
             }
             .into(),
         );
+        metrics::count_in_block(
+            &mut state,
+            metrics::IBC_PACKETS_RECEIVED_TOTAL,
+            vec![
+                ("port_id", self.packet.port_on_b.to_string()),
+                ("channel_id", self.packet.chan_on_b.to_string()),
+            ],
+        );
 
-        let transfer = PortId::transfer();
         // todo: should this be part of the app handler logic?
-        if self.packet.port_on_b == transfer {
+        if AH::is_bound_port(&self.packet.port_on_b) {
             AH::recv_packet_execute(state, self).await?;
         } else {
             anyhow::bail!("invalid port id");
@@ -160,3 +166,32 @@ impl MsgHandler for MsgRecvPacket {
         Ok(())
     }
 }
+
+/// Checks that a packet received on an ordered channel is the next one the
+/// channel expects, distinguishing packets that were already received (e.g.
+/// relayed twice) from packets that skip ahead of the channel.
+fn check_ordered_recv_sequence(sequence: u64, next_sequence_recv: u64) -> Result<()> {
+    match sequence.cmp(&next_sequence_recv) {
+        Ordering::Equal => Ok(()),
+        Ordering::Less => anyhow::bail!("packet {sequence} has already been received"),
+        Ordering::Greater => anyhow::bail!(
+            "packet sequence {sequence} is ahead of the channel's next receive sequence {next_sequence_recv}"
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ordered_channels_only_receive_the_next_sequence() {
+        assert!(check_ordered_recv_sequence(5, 5).is_ok());
+
+        let redelivered = check_ordered_recv_sequence(4, 5).unwrap_err();
+        assert!(redelivered.to_string().contains("already been received"));
+
+        let skipped = check_ordered_recv_sequence(6, 5).unwrap_err();
+        assert!(skipped.to_string().contains("ahead of"));
+    }
+}
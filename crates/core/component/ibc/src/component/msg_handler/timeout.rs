@@ -5,7 +5,6 @@ use ibc_types::core::channel::{
     channel::{Order as ChannelOrder, State as ChannelState},
     events,
     msgs::MsgTimeout,
-    PortId,
 };
 
 use crate::component::{
@@ -13,7 +12,9 @@ use crate::component::{
     channel::{StateReadExt as _, StateWriteExt},
     client::StateReadExt,
     connection::StateReadExt as _,
-    proof_verification::{commit_packet, PacketProofVerifier},
+    events::channel_closed,
+    metrics,
+    proof_verification::{packet_commitment_matches, PacketProofVerifier},
     HostInterface, MsgHandler,
 };
 
@@ -82,7 +83,7 @@ impl MsgHandler for MsgTimeout {
             .await
             .context("failed to get packet commitment")?
             .ok_or_else(|| anyhow::anyhow!("packet commitment not found"))?;
-        if commitment != commit_packet(&self.packet) {
+        if !packet_commitment_matches(&self.packet, &commitment) {
             anyhow::bail!("packet commitment does not match");
         }
 
@@ -95,20 +96,23 @@ impl MsgHandler for MsgTimeout {
             // in the case of a timed-out ordered packet, the counterparty should have
             // committed the next sequence number to their state
             state
-                .verify_packet_timeout_proof::<HI>(&connection, self)
+                .verify_packet_timeout_proof::<HI>(&channel.connection_hops[0], &connection, self)
                 .await
                 .context("failed to verify packet timeout proof")?;
         } else {
             // in the case of a timed-out unordered packet, the counterparty should not have
             // committed a receipt to the state.
             state
-                .verify_packet_timeout_absence_proof::<HI>(&connection, self)
+                .verify_packet_timeout_absence_proof::<HI>(
+                    &channel.connection_hops[0],
+                    &connection,
+                    self,
+                )
                 .await
                 .context("failed to verify packet timeout absence proof")?;
         }
 
-        let transfer = PortId::transfer();
-        if self.packet.port_on_b == transfer {
+        if H::is_bound_port(&self.packet.port_on_b) {
             H::timeout_packet_check(&mut state, self)
                 .await
                 .context("failed to execute handler for timeout_packet_check")?;
@@ -130,6 +134,11 @@ impl MsgHandler for MsgTimeout {
                 &self.packet.port_on_a,
                 channel.clone(),
             );
+            state.record(channel_closed(
+                &self.packet.port_on_a,
+                &self.packet.chan_on_a,
+                &channel,
+            ));
         }
 
         state.record(
@@ -145,9 +154,16 @@ impl MsgHandler for MsgTimeout {
             }
             .into(),
         );
+        metrics::count_in_block(
+            &mut state,
+            metrics::IBC_PACKETS_TIMED_OUT_TOTAL,
+            vec![
+                ("port_id", self.packet.port_on_a.to_string()),
+                ("channel_id", self.packet.chan_on_a.to_string()),
+            ],
+        );
 
-        let transfer = PortId::transfer();
-        if self.packet.port_on_b == transfer {
+        if H::is_bound_port(&self.packet.port_on_b) {
             H::timeout_packet_execute(state, self).await?;
         } else {
             anyhow::bail!("invalid port id");
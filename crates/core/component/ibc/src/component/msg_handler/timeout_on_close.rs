@@ -0,0 +1,194 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use cnidarium::StateWrite;
+use ibc_types::core::channel::{
+    channel::{Order as ChannelOrder, State as ChannelState},
+    events,
+    msgs::{MsgTimeout, MsgTimeoutOnClose},
+    ChannelEnd, Counterparty,
+};
+
+use crate::component::{
+    app_handler::{AppHandlerCheck, AppHandlerExecute},
+    channel::{StateReadExt as _, StateWriteExt},
+    connection::StateReadExt as _,
+    events::channel_closed,
+    metrics,
+    proof_verification::{packet_commitment_matches, ChannelProofVerifier, PacketProofVerifier},
+    HostInterface, MsgHandler,
+};
+
+#[async_trait]
+impl MsgHandler for MsgTimeoutOnClose {
+    async fn check_stateless<H: AppHandlerCheck>(&self) -> Result<()> {
+        // NOTE: no additional stateless validation is possible
+
+        Ok(())
+    }
+
+    async fn try_execute<
+        S: StateWrite,
+        H: AppHandlerCheck + AppHandlerExecute,
+        HI: HostInterface,
+    >(
+        &self,
+        mut state: S,
+    ) -> Result<()> {
+        tracing::debug!(msg = ?self);
+        let mut channel = state
+            .get_channel(&self.packet.chan_on_a, &self.packet.port_on_a)
+            .await
+            .context("failed to get channel")?
+            .ok_or_else(|| anyhow::anyhow!("channel not found"))?;
+
+        // TODO: capability authentication?
+        if self.packet.chan_on_b.ne(channel
+            .counterparty()
+            .channel_id()
+            .ok_or_else(|| anyhow::anyhow!("missing channel id"))?)
+        {
+            anyhow::bail!("packet destination channel does not match channel");
+        }
+        if self.packet.port_on_b != channel.counterparty().port_id {
+            anyhow::bail!("packet destination port does not match channel");
+        }
+
+        let connection = state
+            .get_connection(&channel.connection_hops[0])
+            .await
+            .context("failed to get connection")?
+            .ok_or_else(|| anyhow::anyhow!("connection not found for channel"))?;
+
+        // verify that we actually sent this packet
+        let commitment = state
+            .get_packet_commitment(&self.packet)
+            .await
+            .context("failed to get packet commitment")?
+            .ok_or_else(|| anyhow::anyhow!("packet commitment not found"))?;
+        if !packet_commitment_matches(&self.packet, &commitment) {
+            anyhow::bail!("packet commitment does not match");
+        }
+
+        // the counterparty must have closed its end of the channel, so the
+        // packet can never be received
+        let expected_channel = ChannelEnd {
+            state: ChannelState::Closed,
+            ordering: channel.ordering,
+            remote: Counterparty::new(
+                self.packet.port_on_a.clone(),
+                Some(self.packet.chan_on_a.clone()),
+            ),
+            connection_hops: vec![connection
+                .counterparty
+                .connection_id
+                .clone()
+                .ok_or_else(|| anyhow::anyhow!("no counterparty connection id provided"))?],
+            version: channel.version.clone(),
+        };
+        state
+            .verify_channel_proof::<HI>(
+                &channel.connection_hops[0],
+                &connection,
+                &self.proof_close_on_b,
+                &self.proof_height_on_b,
+                &self.packet.chan_on_b,
+                &self.packet.port_on_b,
+                &expected_channel,
+            )
+            .await
+            .context("failed to verify counterparty channel is closed")?;
+
+        // the rest of a timeout-on-close is handled exactly like a timeout, so
+        // the app handlers see it as one.
+        let timeout = MsgTimeout {
+            packet: self.packet.clone(),
+            next_seq_recv_on_b: self.next_seq_recv_on_b,
+            proof_unreceived_on_b: self.proof_unreceived_on_b.clone(),
+            proof_height_on_b: self.proof_height_on_b,
+            signer: self.signer.clone(),
+        };
+
+        if channel.ordering == ChannelOrder::Ordered {
+            // ordered channel: check that packet has not been received
+            if self.next_seq_recv_on_b > self.packet.sequence {
+                anyhow::bail!("packet sequence number does not match");
+            }
+
+            state
+                .verify_packet_timeout_proof::<HI>(
+                    &channel.connection_hops[0],
+                    &connection,
+                    &timeout,
+                )
+                .await
+                .context("failed to verify packet timeout proof")?;
+        } else {
+            state
+                .verify_packet_timeout_absence_proof::<HI>(
+                    &channel.connection_hops[0],
+                    &connection,
+                    &timeout,
+                )
+                .await
+                .context("failed to verify packet timeout absence proof")?;
+        }
+
+        if H::is_bound_port(&self.packet.port_on_b) {
+            H::timeout_packet_check(&mut state, &timeout)
+                .await
+                .context("failed to execute handler for timeout_packet_check")?;
+        } else {
+            anyhow::bail!("invalid port id");
+        }
+
+        state.delete_packet_commitment(
+            &self.packet.chan_on_a,
+            &self.packet.port_on_a,
+            self.packet.sequence.into(),
+        );
+
+        if channel.ordering == ChannelOrder::Ordered {
+            channel.set_state(ChannelState::Closed);
+            state.put_channel(
+                &self.packet.chan_on_a,
+                &self.packet.port_on_a,
+                channel.clone(),
+            );
+            state.record(channel_closed(
+                &self.packet.port_on_a,
+                &self.packet.chan_on_a,
+                &channel,
+            ));
+        }
+
+        state.record(
+            events::packet::TimeoutPacket {
+                timeout_height: self.packet.timeout_height_on_b,
+                timeout_timestamp: self.packet.timeout_timestamp_on_b,
+                sequence: self.packet.sequence,
+                src_port_id: self.packet.port_on_a.clone(),
+                src_channel_id: self.packet.chan_on_a.clone(),
+                dst_port_id: self.packet.port_on_b.clone(),
+                dst_channel_id: self.packet.chan_on_b.clone(),
+                channel_ordering: channel.ordering,
+            }
+            .into(),
+        );
+        metrics::count_in_block(
+            &mut state,
+            metrics::IBC_PACKETS_TIMED_OUT_TOTAL,
+            vec![
+                ("port_id", self.packet.port_on_a.to_string()),
+                ("channel_id", self.packet.chan_on_a.to_string()),
+            ],
+        );
+
+        if H::is_bound_port(&self.packet.port_on_b) {
+            H::timeout_packet_execute(state, &timeout).await?;
+        } else {
+            anyhow::bail!("invalid port id");
+        }
+
+        Ok(())
+    }
+}
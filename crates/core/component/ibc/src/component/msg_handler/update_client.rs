@@ -2,7 +2,11 @@ use anyhow::{Context, Result};
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
 use ibc_types::{
-    core::{client::events::UpdateClient, client::msgs::MsgUpdateClient, client::ClientId},
+    core::{
+        client::events::{ClientMisbehaviour, UpdateClient},
+        client::msgs::MsgUpdateClient,
+        client::ClientId,
+    },
     lightclients::tendermint::client_state::ClientState as TendermintClientState,
     lightclients::tendermint::header::Header as TendermintHeader,
     lightclients::tendermint::{
@@ -19,7 +23,7 @@ use crate::component::{
     client::{
         ConsensusStateWriteExt as _, Ics2ClientExt as _, StateReadExt as _, StateWriteExt as _,
     },
-    ics02_validation, HostInterface, MsgHandler,
+    ics02_validation, metrics, HostInterface, MsgHandler,
 };
 
 #[async_trait]
@@ -30,135 +34,109 @@ impl MsgHandler for MsgUpdateClient {
         Ok(())
     }
 
-    async fn try_execute<S: StateWrite, AH, HI: HostInterface>(&self, mut state: S) -> Result<()> {
-        // Optimization: no-op if the update is already committed.  We no-op
-        // to Ok(()) rather than erroring to avoid having two "racing" relay
-        // transactions fail just because they both contain the same client
-        // update.
-        if update_is_already_committed(&state, self).await? {
-            tracing::debug!("skipping duplicate update");
-            return Ok(());
-        }
-        tracing::debug!(msg = ?self);
-
-        let client_state = client_is_present(&state, self).await?;
-
-        client_is_not_frozen(&client_state)?;
-        client_is_not_expired::<&S, HI>(&state, &self.client_id, &client_state).await?;
-
-        let trusted_client_state = client_state;
-
-        let untrusted_header =
-            ics02_validation::get_tendermint_header(self.client_message.clone())?;
-
-        header_revision_matches_client_state(&trusted_client_state, &untrusted_header)?;
-        header_height_is_consistent(&untrusted_header)?;
-
-        // The (still untrusted) header uses the `trusted_height` field to
-        // specify the trusted anchor data it is extending.
-        let trusted_height = untrusted_header.trusted_height;
-
-        // We use the specified trusted height to query the trusted
-        // consensus state the update extends.
-        let last_trusted_consensus_state = state
-            .get_verified_consensus_state(&trusted_height, &self.client_id)
-            .await?;
-
-        // We also have to convert from an IBC height, which has two
-        // components, to a Tendermint height, which has only one.
-        let trusted_height = trusted_height
-            .revision_height()
-            .try_into()
-            .context("invalid header height")?;
-
-        let trusted_validator_set =
-            verify_header_validator_set(&untrusted_header, &last_trusted_consensus_state)?;
-
-        // Now we build the trusted and untrusted states to feed to the Tendermint light client.
-
-        let trusted_state = TrustedBlockState {
-            // TODO(erwan): do we need an additional check on `chain_id`
-            chain_id: &trusted_client_state.chain_id.clone().into(),
-            header_time: last_trusted_consensus_state.timestamp,
-            height: trusted_height,
-            next_validators: trusted_validator_set,
-            next_validators_hash: last_trusted_consensus_state.next_validators_hash,
-        };
-
-        let untrusted_state = UntrustedBlockState {
-            signed_header: &untrusted_header.signed_header,
-            validators: &untrusted_header.validator_set,
-            next_validators: None, // TODO: do we need this?
-        };
-
-        let options = trusted_client_state.as_light_client_options()?;
-        let verifier = ProdVerifier::default();
-
-        let verdict = verifier.verify_update_header(
-            untrusted_state,
-            trusted_state,
-            &options,
-            HI::get_block_timestamp(&state).await?,
-        );
+    async fn try_execute<S: StateWrite, AH, HI: HostInterface>(&self, state: S) -> Result<()> {
+        execute_update::<S, HI>(state, self).await
+    }
+}
+
+/// Verifies the header in `msg` and, if it is valid, stores the updated client
+/// and consensus states.
+pub(crate) async fn execute_update<S: StateWrite, HI: HostInterface>(
+    mut state: S,
+    msg: &MsgUpdateClient,
+) -> Result<()> {
+    // Optimization: no-op if the update is already committed.  We no-op
+    // to Ok(()) rather than erroring to avoid having two "racing" relay
+    // transactions fail just because they both contain the same client
+    // update.
+    if update_is_already_committed(&state, msg).await? {
+        tracing::debug!("skipping duplicate update");
+        return Ok(());
+    }
+    tracing::debug!(?msg);
 
-        match verdict {
-            Verdict::Success => Ok(()),
-            Verdict::NotEnoughTrust(voting_power_tally) => Err(anyhow::anyhow!(
-                "not enough trust, voting power tally: {:?}",
-                voting_power_tally
-            )),
-            Verdict::Invalid(detail) => Err(anyhow::anyhow!(
-                "could not verify tendermint header: invalid: {:?}",
-                detail
-            )),
-        }?;
-
-        let trusted_header = untrusted_header;
-
-        // get the latest client state
-        let client_state = state
-            .get_client_state(&self.client_id)
-            .await
-            .context("unable to get client state")?;
-
-        // NOTE: next_tendermint_state will freeze the client on equivocation.
-        let (next_tm_client_state, next_tm_consensus_state) = state
-            .next_tendermint_state(
-                self.client_id.clone(),
-                client_state.clone(),
-                trusted_header.clone(),
-            )
-            .await;
-
-        // store the updated client and consensus states
-        state.put_client(&self.client_id, next_tm_client_state);
-        state
-            .put_verified_consensus_state::<HI>(
-                trusted_header.height(),
-                self.client_id.clone(),
-                next_tm_consensus_state,
-            )
-            .await?;
+    let client_state = client_is_present(&state, msg).await?;
 
+    client_is_not_frozen(&client_state)?;
+    client_is_not_expired::<&S, HI>(&state, &msg.client_id, &client_state).await?;
+
+    let trusted_client_state = client_state;
+
+    let untrusted_header = ics02_validation::get_tendermint_header(msg.client_message.clone())?;
+
+    verify_tendermint_header::<&S, HI>(
+        &state,
+        &msg.client_id,
+        &trusted_client_state,
+        &untrusted_header,
+    )
+    .await?;
+
+    let trusted_header = untrusted_header;
+
+    // get the latest client state
+    let client_state = state
+        .get_client_state(&msg.client_id)
+        .await
+        .context("unable to get client state")?;
+
+    // NOTE: next_tendermint_state will freeze the client on equivocation.
+    let (next_tm_client_state, next_tm_consensus_state) = state
+        .next_tendermint_state(
+            msg.client_id.clone(),
+            client_state.clone(),
+            trusted_header.clone(),
+        )
+        .await;
+
+    // a header that conflicts with a stored consensus state, or with the timestamps of its
+    // neighbours, is evidence of misbehaviour. the client is frozen, and the conflicting
+    // consensus state is not stored.
+    if next_tm_client_state.is_frozen() {
+        tracing::info!(client_id = ?msg.client_id, "update header is misbehavior evidence! freezing client");
+        state.put_client(&msg.client_id, next_tm_client_state);
         state.record(
-            UpdateClient {
-                client_id: self.client_id.clone(),
+            ClientMisbehaviour {
+                client_id: msg.client_id.clone(),
                 client_type: ibc_types::core::client::ClientType(
                     TENDERMINT_CLIENT_TYPE.to_string(),
-                ), // TODO: hardcoded
-                consensus_height: trusted_header.height(),
-                header:
-                    <ibc_types::lightclients::tendermint::header::Header as ibc_proto::Protobuf<
-                        ibc_proto::ibc::lightclients::tendermint::v1::Header,
-                    >>::encode_vec(trusted_header),
+                ),
             }
             .into(),
         );
-        Ok(())
+        return Ok(());
     }
+
+    // store the updated client and consensus states
+    state.put_client(&msg.client_id, next_tm_client_state);
+    state
+        .put_verified_consensus_state::<HI>(
+            trusted_header.height(),
+            msg.client_id.clone(),
+            next_tm_consensus_state,
+        )
+        .await?;
+
+    state.record(
+        UpdateClient {
+            client_id: msg.client_id.clone(),
+            client_type: ibc_types::core::client::ClientType(TENDERMINT_CLIENT_TYPE.to_string()), // TODO: hardcoded
+            consensus_height: trusted_header.height(),
+            header: <ibc_types::lightclients::tendermint::header::Header as ibc_proto::Protobuf<
+                ibc_proto::ibc::lightclients::tendermint::v1::Header,
+            >>::encode_vec(trusted_header),
+        }
+        .into(),
+    );
+    metrics::count_in_block(
+        &mut state,
+        metrics::IBC_CLIENT_UPDATES_TOTAL,
+        vec![("client_id", msg.client_id.to_string())],
+    );
+    Ok(())
 }
 
-fn header_is_tendermint(msg: &MsgUpdateClient) -> anyhow::Result<()> {
+pub(crate) fn header_is_tendermint(msg: &MsgUpdateClient) -> anyhow::Result<()> {
     if ics02_validation::is_tendermint_header_state(&msg.client_message) {
         Ok(())
     } else {
@@ -192,6 +170,80 @@ async fn update_is_already_committed<S: StateRead>(
     }
 }
 
+/// Verifies an untrusted Tendermint header against the stored state of the
+/// client identified by `client_id`.
+///
+/// The header's `trusted_height` selects the stored consensus state that the
+/// header extends; verification fails if no such consensus state exists.
+pub(crate) async fn verify_tendermint_header<S: StateRead, HI: HostInterface>(
+    state: S,
+    client_id: &ClientId,
+    trusted_client_state: &TendermintClientState,
+    untrusted_header: &TendermintHeader,
+) -> anyhow::Result<()> {
+    header_revision_matches_client_state(trusted_client_state, untrusted_header)?;
+    header_height_is_consistent(untrusted_header)?;
+
+    // The (still untrusted) header uses the `trusted_height` field to
+    // specify the trusted anchor data it is extending.
+    let trusted_height = untrusted_header.trusted_height;
+
+    // We use the specified trusted height to query the trusted
+    // consensus state the update extends.
+    let last_trusted_consensus_state = state
+        .get_verified_consensus_state(&trusted_height, client_id)
+        .await?;
+
+    // We also have to convert from an IBC height, which has two
+    // components, to a Tendermint height, which has only one.
+    let trusted_height = trusted_height
+        .revision_height()
+        .try_into()
+        .context("invalid header height")?;
+
+    let trusted_validator_set =
+        verify_header_validator_set(untrusted_header, &last_trusted_consensus_state)?;
+
+    // Now we build the trusted and untrusted states to feed to the Tendermint light client.
+
+    let trusted_state = TrustedBlockState {
+        // TODO(erwan): do we need an additional check on `chain_id`
+        chain_id: &trusted_client_state.chain_id.clone().into(),
+        header_time: last_trusted_consensus_state.timestamp,
+        height: trusted_height,
+        next_validators: trusted_validator_set,
+        next_validators_hash: last_trusted_consensus_state.next_validators_hash,
+    };
+
+    let untrusted_state = UntrustedBlockState {
+        signed_header: &untrusted_header.signed_header,
+        validators: &untrusted_header.validator_set,
+        next_validators: None, // TODO: do we need this?
+    };
+
+    let options = trusted_client_state.as_light_client_options()?;
+    let verifier = ProdVerifier::default();
+
+    let verdict = verifier.verify_update_header(
+        untrusted_state,
+        trusted_state,
+        &options,
+        HI::get_block_timestamp(&state).await?,
+    );
+
+    match verdict {
+        Verdict::Success => Ok(()),
+        Verdict::NotEnoughTrust(voting_power_tally) => Err(anyhow::anyhow!(
+            "not enough trust, voting power tally: {:?}",
+            voting_power_tally
+        )),
+        Verdict::Invalid(detail) => Err(anyhow::anyhow!(
+            "could not verify tendermint header: invalid: {:?}",
+            detail
+        )),
+    }
+}
+
 async fn client_is_not_expired<S: StateRead, HI: HostInterface>(
     state: S,
     client_id: &ClientId,
@@ -232,7 +284,7 @@ fn client_is_not_frozen(client: &TendermintClientState) -> anyhow::Result<()> {
     }
 }
 
-fn header_revision_matches_client_state(
+pub(crate) fn header_revision_matches_client_state(
     trusted_client_state: &TendermintClientState,
     untrusted_header: &TendermintHeader,
 ) -> anyhow::Result<()> {
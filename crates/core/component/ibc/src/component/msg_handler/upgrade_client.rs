@@ -17,6 +17,7 @@ use ibc_types::{
 use crate::component::{
     client::{ConsensusStateWriteExt as _, StateReadExt as _, StateWriteExt as _},
     proof_verification::ClientUpgradeProofVerifier,
+    view::StateReadExt as _,
     HostInterface, MsgHandler,
 };
 
@@ -25,6 +26,11 @@ static SENTINEL_UPGRADE_ROOT: &str = "sentinel_root";
 #[async_trait]
 impl MsgHandler for MsgUpgradeClient {
     async fn check_stateless<AH>(&self) -> Result<()> {
+        TendermintClientState::try_from(self.client_state.clone())
+            .context("client state is not a Tendermint client state")?;
+        TendermintConsensusState::try_from(self.consensus_state.clone())
+            .context("consensus state is not a Tendermint consensus state")?;
+
         Ok(())
     }
 
@@ -60,6 +66,36 @@ impl MsgHandler for MsgUpgradeClient {
         // relevant ibc-go code:
         // https://github.com/cosmos/ibc-go/blob/main/modules/light-clients/07-tendermint/upgrade.go#L74
         // https://github.com/cosmos/ibc-go/blob/2555a7c504a904064d659e4c1a3a74000887f73d/modules/core/02-client/keeper/keeper.go#L552-L564
+        // an upgrade can only move the client forward: the upgraded chain starts after the last
+        // height of the old one.
+        let old_client_state = state.get_client_state(&self.client_id).await?;
+        if upgraded_client_state_tm.latest_height() <= old_client_state.latest_height() {
+            anyhow::bail!(
+                "upgraded client height {} must be greater than current client height {}",
+                upgraded_client_state_tm.latest_height(),
+                old_client_state.latest_height()
+            );
+        }
+        // the revision the upgraded chain starts at is the one its chain id names, which is what
+        // its later headers are checked against.
+        if upgraded_client_state_tm.latest_height().revision_number()
+            != upgraded_client_state_tm.chain_id.version()
+        {
+            anyhow::bail!(
+                "upgraded client height {} is not in the revision of chain id {}",
+                upgraded_client_state_tm.latest_height(),
+                upgraded_client_state_tm.chain_id
+            );
+        }
+
+        // the upgraded chain may commit to its state differently, but only in a way clients may
+        // be created for.
+        state
+            .get_ibc_params()
+            .await?
+            .check_proof_specs(&upgraded_client_state_tm.proof_specs)
+            .context("upgraded client state has unacceptable proof specs")?;
+
         let mut upgraded_client_state_tm_zeroed_fields = upgraded_client_state_tm.clone();
         upgraded_client_state_tm_zeroed_fields.trusting_period = ZERO_DURATION;
         upgraded_client_state_tm_zeroed_fields.trust_level = TrustThreshold::ZERO;
@@ -93,8 +129,6 @@ impl MsgHandler for MsgUpgradeClient {
             )
             .await?;
 
-        let old_client_state = state.get_client_state(&self.client_id).await?;
-
         // construct the new client state to be committed to our state. we don't allow the
         // trust_level, trusting_period, clock_drift, allow_update, or frozen_height to change
         // across upgrades.
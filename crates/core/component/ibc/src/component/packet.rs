@@ -11,6 +11,8 @@ use crate::component::{
     channel::{StateReadExt as _, StateWriteExt as _},
     client::StateReadExt as _,
     connection::StateReadExt as _,
+    fees::{self, IncentivizedAcknowledgement},
+    state_key,
 };
 
 pub trait CheckStatus: private::Sealed {}
@@ -283,11 +285,25 @@ pub trait WriteAcknowledgement: StateWrite {
                 )
             })?;
 
+        // On fee-enabled channels, the counterparty expects the acknowledgement
+        // to name the relayer that relayed the packet, so it can be paid.
+        let ack_bytes: Vec<u8> = if fees::is_fee_enabled(&channel.version) {
+            let forward_relayer: Option<String> =
+                self.object_get(state_key::fees::forward_relayer());
+            IncentivizedAcknowledgement::new(
+                ack_bytes.to_vec(),
+                forward_relayer.unwrap_or_default(),
+            )
+            .into()
+        } else {
+            ack_bytes.to_vec()
+        };
+
         self.put_packet_acknowledgement(
             &packet.port_on_b,
             &packet.chan_on_b,
             packet.sequence.into(),
-            ack_bytes,
+            &ack_bytes,
         );
 
         self.record(
@@ -300,7 +316,7 @@ pub trait WriteAcknowledgement: StateWrite {
                 src_channel_id: packet.chan_on_a.clone(),
                 dst_port_id: packet.port_on_b.clone(),
                 dst_channel_id: packet.chan_on_b.clone(),
-                acknowledgement: ack_bytes.to_vec(),
+                acknowledgement: ack_bytes,
                 dst_connection_id: channel.connection_hops[0].clone(),
             }
             .into(),
@@ -0,0 +1,240 @@
+//! Batch verification of membership proofs against a single consensus state.
+//!
+//! A proof that a key is committed on the counterparty is a chain of proofs:
+//! one of the key within the counterparty's IBC store, followed by proofs that
+//! link the root of that store to the root of the counterparty's whole state.
+//! When many packets are proven against the same consensus state, only the
+//! first proofs of their chains differ: the links from the IBC store to the
+//! root are the same for every one of them.
+//!
+//! So proofs are verified in two steps: the proof of each key is verified on
+//! its own, and each distinct link from a store root to the consensus root is
+//! verified only once. Whether a link holds only depends on the hashes in it,
+//! not on any state, so verified links are remembered process-wide, and
+//! packets relayed in separate messages (or transactions) at the same height
+//! share the work too.
+
+use std::sync::Mutex;
+
+use anyhow::Result;
+use ibc_types::core::commitment::{MerklePath, MerkleProof, MerkleRoot};
+use ics23::{commitment_proof::Proof, CommitmentProof, HostFunctionsManager, ProofSpec};
+use once_cell::sync::Lazy;
+use prost::Message as _;
+
+use super::decoded_cache::DecodedCache;
+
+/// The number of verified links remembered. Each consensus state of each
+/// client that proofs are verified against has its own link.
+const VERIFIED_LINKS_CAPACITY: usize = 1024;
+
+static VERIFIED_LINKS: Lazy<Mutex<DecodedCache<()>>> =
+    Lazy::new(|| Mutex::new(DecodedCache::new(VERIFIED_LINKS_CAPACITY)));
+
+/// A proof that `value` is committed at `path`.
+pub(crate) struct MembershipProof<'a> {
+    pub proof: &'a MerkleProof,
+    pub path: MerklePath,
+    pub value: Vec<u8>,
+}
+
+/// Verifies that each of `proofs` holds under `root`, returning the outcome of
+/// each in order.
+pub(crate) fn verify_membership_batch(
+    proof_specs: &[ProofSpec],
+    root: &MerkleRoot,
+    proofs: &[MembershipProof<'_>],
+) -> Vec<Result<()>> {
+    proofs
+        .iter()
+        .map(|membership| verify_membership(proof_specs, root, membership))
+        .collect()
+}
+
+fn verify_membership(
+    proof_specs: &[ProofSpec],
+    root: &MerkleRoot,
+    membership: &MembershipProof<'_>,
+) -> Result<()> {
+    let MembershipProof { proof, path, value } = membership;
+    if proof.proofs.is_empty() {
+        anyhow::bail!("merkle proof is empty");
+    }
+    if proof.proofs.len() != proof_specs.len() {
+        anyhow::bail!(
+            "merkle proof has {} proofs, but there are {} proof specs",
+            proof.proofs.len(),
+            proof_specs.len()
+        );
+    }
+    if proof.proofs.len() != path.key_path.len() {
+        anyhow::bail!(
+            "merkle proof has {} proofs, but the path has {} keys",
+            proof.proofs.len(),
+            path.key_path.len()
+        );
+    }
+    if value.is_empty() {
+        anyhow::bail!("can't prove an empty value");
+    }
+
+    // The proofs are ordered from the innermost key outwards.
+    let mut keys = path.key_path.iter().rev();
+    let store_root = verify_step(
+        &proof.proofs[0],
+        &proof_specs[0],
+        keys.next().expect("path has as many keys as proofs"),
+        value,
+    )?;
+
+    let link = encode_link(
+        &proof.proofs[1..],
+        &proof_specs[1..],
+        &path.key_path[..path.key_path.len() - 1],
+        &store_root,
+        &root.hash,
+    );
+    let already_verified = VERIFIED_LINKS
+        .lock()
+        .expect("verified links lock is not poisoned")
+        .get(&link)
+        .is_some();
+    if already_verified {
+        return Ok(());
+    }
+
+    let mut subroot = store_root;
+    for ((commitment_proof, spec), key) in proof.proofs[1..].iter().zip(&proof_specs[1..]).zip(keys)
+    {
+        subroot = verify_step(commitment_proof, spec, key, &subroot)?;
+    }
+    if subroot != root.hash {
+        anyhow::bail!("merkle proof does not lead to the consensus state's root");
+    }
+
+    VERIFIED_LINKS
+        .lock()
+        .expect("verified links lock is not poisoned")
+        .insert(&link, ());
+
+    Ok(())
+}
+
+/// Verifies that `value` is committed at `key` by `commitment_proof`, returning
+/// the root it is committed under.
+fn verify_step(
+    commitment_proof: &CommitmentProof,
+    spec: &ProofSpec,
+    key: &str,
+    value: &[u8],
+) -> Result<Vec<u8>> {
+    let Some(Proof::Exist(existence_proof)) = &commitment_proof.proof else {
+        anyhow::bail!("merkle proof is not an existence proof");
+    };
+    let root = ics23::calculate_existence_root::<HostFunctionsManager>(existence_proof)
+        .map_err(|e| anyhow::anyhow!("couldn't calculate the root of the proof of {key}: {e}"))?;
+
+    if !ics23::verify_membership::<HostFunctionsManager>(
+        commitment_proof,
+        spec,
+        &root,
+        key.as_bytes(),
+        value,
+    ) {
+        anyhow::bail!("proof of {key} does not verify");
+    }
+
+    Ok(root)
+}
+
+/// Encodes everything that determines whether the proofs linking `store_root`
+/// to `root` hold, to identify the link in [`VERIFIED_LINKS`].
+fn encode_link(
+    proofs: &[CommitmentProof],
+    proof_specs: &[ProofSpec],
+    keys: &[String],
+    store_root: &[u8],
+    root: &[u8],
+) -> Vec<u8> {
+    let mut link = Vec::new();
+    let mut push = |bytes: &[u8]| {
+        link.extend_from_slice(&(bytes.len() as u64).to_be_bytes());
+        link.extend_from_slice(bytes);
+    };
+
+    for proof in proofs {
+        push(&proof.encode_to_vec());
+    }
+    for spec in proof_specs {
+        push(&spec.encode_to_vec());
+    }
+    for key in keys {
+        push(key.as_bytes());
+    }
+    push(store_root);
+    push(root);
+
+    link
+}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::{StateDelta, StateWrite as _, TempStorage};
+
+    use super::*;
+
+    fn membership<'a>(proof: &'a MerkleProof, key: &str, value: &[u8]) -> MembershipProof<'a> {
+        MembershipProof {
+            proof,
+            path: MerklePath {
+                key_path: vec!["ibc".to_string(), key.to_string()],
+            },
+            value: value.to_vec(),
+        }
+    }
+
+    #[tokio::test]
+    async fn proofs_sharing_a_root_are_verified_individually() -> Result<()> {
+        let storage = TempStorage::new_with_prefixes(vec!["ibc".to_string()]).await?;
+        let mut delta = StateDelta::new(storage.latest_snapshot());
+        delta.put_raw("ibc/key_a".to_string(), b"value_a".to_vec());
+        delta.put_raw("ibc/key_b".to_string(), b"value_b".to_vec());
+        storage.commit(delta).await?;
+
+        let snapshot = storage.latest_snapshot();
+        let root = MerkleRoot {
+            hash: snapshot.root_hash().await?.0.to_vec(),
+        };
+        let (_, proof_a) = snapshot.get_with_proof(b"ibc/key_a".to_vec()).await?;
+        let (_, proof_b) = snapshot.get_with_proof(b"ibc/key_b".to_vec()).await?;
+        let specs = vec![cnidarium::ics23_spec(), cnidarium::ics23_spec()];
+
+        let results = verify_membership_batch(
+            &specs,
+            &root,
+            &[
+                membership(&proof_a, "key_a", b"value_a"),
+                membership(&proof_b, "key_b", b"value_b"),
+                // The link from the IBC store to the root is already verified,
+                // but the proof of the key itself still has to hold.
+                membership(&proof_b, "key_b", b"value_a"),
+                membership(&proof_a, "key_b", b"value_a"),
+            ],
+        );
+
+        assert!(results[0].is_ok());
+        assert!(results[1].is_ok());
+        assert!(results[2].is_err());
+        assert!(results[3].is_err());
+
+        let other_root = MerkleRoot { hash: vec![0; 32] };
+        assert!(verify_membership_batch(
+            &specs,
+            &other_root,
+            &[membership(&proof_a, "key_a", b"value_a")]
+        )[0]
+        .is_err());
+
+        Ok(())
+    }
+}
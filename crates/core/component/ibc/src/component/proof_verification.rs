@@ -1,4 +1,7 @@
+use crate::component::channel::StateReadExt as _;
 use crate::component::client::StateReadExt;
+use crate::component::connection::StateReadExt as _;
+use crate::prefix::CounterpartyPrefix;
 
 use core::time::Duration;
 use ibc_proto::Protobuf;
@@ -7,30 +10,117 @@ use ibc_types::DomainType;
 use ibc_types::{
     core::{
         channel::{
-            msgs::MsgAcknowledgement, msgs::MsgRecvPacket, msgs::MsgTimeout, ChannelEnd, ChannelId,
-            Packet, PortId,
+            channel::Order, msgs::MsgAcknowledgement, msgs::MsgRecvPacket, msgs::MsgTimeout,
+            ChannelEnd, ChannelId, Packet, PortId,
         },
         client::ClientId,
         client::Height,
-        commitment::{MerklePrefix, MerkleProof, MerkleRoot},
-        connection::ConnectionEnd,
+        commitment::{MerklePath, MerklePrefix, MerkleProof, MerkleRoot},
+        connection::{ConnectionEnd, ConnectionId, State as ConnectionState},
     },
     lightclients::tendermint::{
         client_state::ClientState as TendermintClientState,
         consensus_state::ConsensusState as TendermintConsensusState,
+        header::Header as TendermintHeader,
     },
     path::{
         AckPath, ChannelEndPath, ClientStatePath, CommitmentPath, ConnectionPath, Path,
         ReceiptPath, SeqRecvPath,
     },
 };
+use std::collections::BTreeMap;
+use std::time::Instant;
 
+use anyhow::Context;
 use async_trait::async_trait;
 use cnidarium::StateRead;
-use num_traits::float::FloatCore;
 use sha2::{Digest, Sha256};
 
+use super::msg_handler::update_client::verify_tendermint_header;
+use super::proof_batch::{self, MembershipProof};
+use super::verification_stats;
 use super::HostInterface;
+use crate::verification_stats::VerificationKind;
+
+/// A proof verification failure that callers (e.g. relayers) may want to handle
+/// specifically, rather than as an opaque error.
+///
+/// Verifiers return these wrapped in an [`anyhow::Error`]; use
+/// [`anyhow::Error::downcast_ref`] to recover them.
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum VerificationError {
+    /// The client state has no ICS-23 proof specs, so no proof can be checked
+    /// against it.
+    #[error("client {client_id} has no proof specs")]
+    ClientMissingProofSpecs { client_id: ClientId },
+    /// The counterparty committed the channel's pending-acknowledgement
+    /// sentinel, so the real acknowledgement has not been written yet.
+    #[error("acknowledgement is still pending on the counterparty")]
+    AckPending,
+    /// The proof is for a different packet commitment than the one computed
+    /// from the packet. Both commitments are truncated to 32 bytes.
+    #[error(
+        "counterparty committed {}, but the packet commits to {}",
+        hex::encode(committed),
+        hex::encode(expected_commitment)
+    )]
+    CommitmentMismatch {
+        expected_commitment: Vec<u8>,
+        committed: Vec<u8>,
+    },
+    /// The consensus state a proof is verified against is older than the
+    /// maximum age configured for the connection.
+    #[error(
+        "consensus state is {age:?} old, but connection {connection_id} accepts at most {max_age:?}"
+    )]
+    ConsensusStateTooOld {
+        connection_id: ConnectionId,
+        age: Duration,
+        max_age: Duration,
+    },
+    /// A batch of packets on an ordered channel is not contiguous and ascending
+    /// from the channel's next receive sequence.
+    #[error("packet {at_index} in the batch has sequence {got}, expected {expected}")]
+    SequenceGap {
+        at_index: usize,
+        expected: u64,
+        got: u64,
+    },
+    /// A batch of packets on an ordered channel is too long to continue from
+    /// the channel's next receive sequence without overflowing it.
+    #[error("packet {at_index} in the batch would have a sequence past the largest sequence")]
+    SequenceOverflow { at_index: usize },
+    /// The counterparty has not yet received every packet sent before a
+    /// channel upgrade, so the channel has not been flushed.
+    #[error("counterparty's next receive sequence is {committed_seq}, expected {expected}")]
+    FlushIncomplete { committed_seq: u64, expected: u64 },
+    /// The proof height is from a different revision of the counterparty chain
+    /// than the client's latest height.
+    #[error(
+        "proof height is in revision {proof_revision}, but the client is at revision {client_revision}"
+    )]
+    RevisionMismatch {
+        proof_revision: u64,
+        client_revision: u64,
+    },
+    /// The proof is for a different key than the path it is claimed to prove.
+    #[error("proof is for key {got}, but was submitted for {expected}")]
+    PathMismatch { expected: String, got: String },
+    /// A key of the counterparty's prefix is not valid UTF-8, so no key path
+    /// can be built under it.
+    #[error("commitment prefix {} is not valid utf-8", hex::encode(prefix))]
+    InvalidPrefix { prefix: Vec<u8> },
+    /// The client's latest consensus state is older than its trusting period,
+    /// so the client must be recovered before it can verify proofs again.
+    #[error(
+        "client {client_id} is expired: its latest consensus state is {elapsed:?} old, but its trusting period is {trusting_period:?}"
+    )]
+    ClientExpired {
+        client_id: ClientId,
+        elapsed: Duration,
+        trusting_period: Duration,
+    },
+}
 
 // NOTE: this is underspecified.
 // using the same implementation here as ibc-go:
@@ -57,6 +147,16 @@ pub fn commit_packet(packet: &Packet) -> Vec<u8> {
     Sha256::digest(&commit).to_vec()
 }
 
+/// Returns whether `stored_commitment` is the commitment to `packet`, as
+/// computed by [`commit_packet`].
+///
+/// This lets a relayer that has both a packet and the commitment stored on its
+/// source chain catch packet-construction mistakes (e.g. a wrong timeout or
+/// data) before building and verifying a merkle proof.
+pub fn packet_commitment_matches(packet: &Packet, stored_commitment: &[u8]) -> bool {
+    commit_packet(packet) == stored_commitment
+}
+
 // NOTE: this is underspecified.
 // using the same implementation here as ibc-go:
 // https://github.com/cosmos/ibc-go/blob/main/modules/core/04-channel/types/packet.go#L38
@@ -64,45 +164,314 @@ pub fn commit_acknowledgement(ack_data: &[u8]) -> Vec<u8> {
     Sha256::digest(ack_data).to_vec()
 }
 
+/// Calculates the number of blocks that must pass for a connection's delay
+/// period, as in ibc-go: the delay period divided by the expected time per
+/// block, rounded up.
+///
+/// ibc-go divides the nanosecond counts as floats, but the block delay is part
+/// of consensus, so this divides them as integers instead, which is exact on
+/// every platform. The two agree wherever ibc-go's floats are exact.
 pub fn calculate_block_delay(
     delay_period_time: &Duration,
     max_expected_time_per_block: &Duration,
 ) -> u64 {
-    if max_expected_time_per_block.is_zero() {
+    let delay = delay_period_time.as_nanos();
+    let time_per_block = max_expected_time_per_block.as_nanos();
+
+    let Some(whole_blocks) = delay.checked_div(time_per_block) else {
+        // No expected block time means there's no block delay.
         return 0;
+    };
+    let blocks = if delay % time_per_block == 0 {
+        whole_blocks
+    } else {
+        whole_blocks + 1
+    };
+
+    u64::try_from(blocks).unwrap_or(u64::MAX)
+}
+
+/// Checks that a stored client state can be used to verify proofs at all.
+///
+/// A client with empty proof specs would otherwise be handed to ics23 with an
+/// empty spec slice, failing in an unhelpful way.
+fn client_has_proof_specs(
+    client_id: &ClientId,
+    client_state: &TendermintClientState,
+) -> Result<(), VerificationError> {
+    if client_state.proof_specs.is_empty() {
+        return Err(VerificationError::ClientMissingProofSpecs {
+            client_id: client_id.clone(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Checks that a client whose latest consensus state is `latest_consensus_state`
+/// is still within its trusting period at `now`.
+fn check_client_not_expired(
+    client_id: &ClientId,
+    client_state: &TendermintClientState,
+    latest_consensus_state: &TendermintConsensusState,
+    now: tendermint::Time,
+) -> anyhow::Result<()> {
+    // A consensus state timestamped after our own block time can't have expired.
+    let elapsed = now
+        .duration_since(latest_consensus_state.timestamp)
+        .unwrap_or(Duration::ZERO);
+    if client_state.expired(elapsed) {
+        return Err(VerificationError::ClientExpired {
+            client_id: client_id.clone(),
+            elapsed,
+            trusting_period: client_state.trusting_period,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Checks that a consensus state is no older at `now` than the maximum age
+/// registered for its connection, if there is one.
+fn check_consensus_state_age(
+    connection_id: &ConnectionId,
+    max_age: Option<Duration>,
+    consensus_state: &TendermintConsensusState,
+    now: tendermint::Time,
+) -> Result<(), VerificationError> {
+    let Some(max_age) = max_age else {
+        return Ok(());
+    };
+
+    // A consensus state timestamped after our own block time is as fresh as it gets.
+    let age = now
+        .duration_since(consensus_state.timestamp)
+        .unwrap_or(Duration::ZERO);
+    if age > max_age {
+        return Err(VerificationError::ConsensusStateTooOld {
+            connection_id: connection_id.clone(),
+            age,
+            max_age,
+        });
     }
 
-    FloatCore::ceil(delay_period_time.as_secs_f64() / max_expected_time_per_block.as_secs_f64())
-        as u64
+    Ok(())
+}
+
+/// Returns the value committed by a membership proof, as claimed by the proof
+/// itself. The value is unverified until the proof has been checked.
+fn membership_proof_value(proof: &MerkleProof) -> anyhow::Result<&[u8]> {
+    // The first proof is for the key inside the counterparty's substore; the
+    // remaining proofs only link that substore to the root.
+    let leaf = proof
+        .proofs
+        .first()
+        .and_then(|commitment_proof| commitment_proof.proof.as_ref())
+        .ok_or_else(|| anyhow::anyhow!("merkle proof is empty"))?;
+
+    match leaf {
+        ics23::commitment_proof::Proof::Exist(existence_proof) => Ok(&existence_proof.value),
+        _ => anyhow::bail!("merkle proof is not an existence proof"),
+    }
 }
 
-fn verify_merkle_absence_proof(
+/// Checks whether a failed packet commitment proof proves a value other than
+/// `expected`, in which case the failure is reported as a
+/// [`VerificationError::CommitmentMismatch`] rather than `err`.
+fn commitment_mismatch_or(
+    err: anyhow::Error,
+    proof: &MerkleProof,
+    expected: &[u8],
+) -> anyhow::Error {
+    match membership_proof_value(proof) {
+        Ok(committed) if committed != expected => VerificationError::CommitmentMismatch {
+            expected_commitment: expected.iter().take(32).copied().collect(),
+            committed: committed.iter().take(32).copied().collect(),
+        }
+        .into(),
+        _ => err,
+    }
+}
+
+/// Checks that `proof_height` is in the same revision as the client's latest
+/// height, so that a proof from before a counterparty upgrade is reported as
+/// such rather than failing in some later check.
+fn check_proof_revision(
+    proof_height: &Height,
+    client_state: &TendermintClientState,
+) -> Result<(), VerificationError> {
+    let proof_revision = proof_height.revision_number();
+    let client_revision = client_state.latest_height().revision_number();
+
+    if proof_revision != client_revision {
+        return Err(VerificationError::RevisionMismatch {
+            proof_revision,
+            client_revision,
+        });
+    }
+
+    Ok(())
+}
+
+/// Verifies the commitment proofs of packets proven against the same `root`,
+/// returning the outcome for each packet in order.
+///
+/// The links from the counterparty's IBC store to `root` are shared by all the
+/// proofs, so they are only verified once (see [`proof_batch`]).
+fn verify_packet_commitment_proofs(
     proof_specs: &[ics23::ProofSpec],
-    prefix: &MerklePrefix,
+    prefix: &CounterpartyPrefix,
+    root: &MerkleRoot,
+    msgs: &[&MsgRecvPacket],
+) -> Vec<anyhow::Result<()>> {
+    let proofs: Result<Vec<MembershipProof<'_>>, VerificationError> = msgs
+        .iter()
+        .map(|msg| {
+            let commitment_path = CommitmentPath {
+                port_id: msg.packet.port_on_a.clone(),
+                channel_id: msg.packet.chan_on_a.clone(),
+                sequence: msg.packet.sequence,
+            };
+
+            Ok(MembershipProof {
+                proof: &msg.proof_commitment_on_a,
+                path: apply_prefix(prefix, commitment_path)?,
+                value: commit_packet(&msg.packet),
+            })
+        })
+        .collect();
+    let proofs = match proofs {
+        Ok(proofs) => proofs,
+        // The prefix is shared by every packet, so none of them can be verified.
+        Err(e) => return msgs.iter().map(|_| Err(e.clone().into())).collect(),
+    };
+
+    proof_batch::verify_membership_batch(proof_specs, root, &proofs)
+        .into_iter()
+        .zip(&proofs)
+        .map(|(result, membership)| -> anyhow::Result<()> {
+            check_proof_keys(membership.proof, &membership.path)?;
+            result.map_err(|e| commitment_mismatch_or(e, membership.proof, &membership.value))
+        })
+        .collect()
+}
+
+/// Applies the counterparty's `prefix` to `path`.
+fn apply_prefix(
+    prefix: &CounterpartyPrefix,
+    path: impl Into<Path>,
+) -> Result<MerklePath, VerificationError> {
+    prefix
+        .apply_path(path.into().to_string())
+        .map_err(|e| VerificationError::InvalidPrefix {
+            prefix: e.into_bytes(),
+        })
+}
+
+/// Encodes a sequence number the way it is committed under a [`SeqRecvPath`].
+fn encode_sequence(sequence: u64) -> Vec<u8> {
+    sequence.to_be_bytes().to_vec()
+}
+
+/// Checks that `packets` have the sequences `expected_start_seq`,
+/// `expected_start_seq + 1`, ... in order, as a batch received on an ordered
+/// channel must.
+fn check_ordered_batch_sequences<'a>(
+    packets: impl IntoIterator<Item = &'a Packet>,
+    expected_start_seq: u64,
+) -> Result<(), VerificationError> {
+    for (at_index, packet) in packets.into_iter().enumerate() {
+        let expected = u64::try_from(at_index)
+            .ok()
+            .and_then(|offset| expected_start_seq.checked_add(offset))
+            .ok_or(VerificationError::SequenceOverflow { at_index })?;
+        if packet.sequence.0 != expected {
+            return Err(VerificationError::SequenceGap {
+                at_index,
+                expected,
+                got: packet.sequence.0,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that each proof in `proof` is for the corresponding key of
+/// `merkle_path`, rather than relying on the proof specs in use to bind the
+/// proven key.
+///
+/// The proofs are ordered from the innermost key outwards, i.e. in the reverse
+/// order of the key path.
+fn check_proof_keys(
+    proof: &MerkleProof,
+    merkle_path: &MerklePath,
+) -> Result<(), VerificationError> {
+    for (commitment_proof, expected) in proof.proofs.iter().zip(merkle_path.key_path.iter().rev()) {
+        let key = match &commitment_proof.proof {
+            Some(ics23::commitment_proof::Proof::Exist(existence_proof)) => &existence_proof.key,
+            Some(ics23::commitment_proof::Proof::Nonexist(non_existence_proof)) => {
+                &non_existence_proof.key
+            }
+            // Batched and compressed proofs don't carry a single key.
+            _ => continue,
+        };
+
+        if key.as_slice() != expected.as_bytes() {
+            return Err(VerificationError::PathMismatch {
+                expected: expected.clone(),
+                got: String::from_utf8_lossy(key).into_owned(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) fn verify_merkle_absence_proof(
+    proof_specs: &[ics23::ProofSpec],
+    prefix: &CounterpartyPrefix,
     proof: &MerkleProof,
     root: &MerkleRoot,
     path: impl Into<Path>,
 ) -> anyhow::Result<()> {
-    let merkle_path = prefix.apply(vec![path.into().to_string()]);
+    let merkle_path = apply_prefix(prefix, path)?;
+    verify_merkle_absence_proof_at(proof_specs, proof, root, merkle_path)
+}
+
+/// Verifies that nothing is committed at `merkle_path` under `root`.
+///
+/// Unlike [`verify_merkle_absence_proof`], the path isn't an IBC path under the
+/// counterparty's commitment prefix, so this can prove the absence of any key
+/// in the counterparty's state.
+fn verify_merkle_absence_proof_at(
+    proof_specs: &[ics23::ProofSpec],
+    proof: &MerkleProof,
+    root: &MerkleRoot,
+    merkle_path: MerklePath,
+) -> anyhow::Result<()> {
+    check_proof_keys(proof, &merkle_path)?;
     proof.verify_non_membership(proof_specs, root.clone(), merkle_path)?;
 
     Ok(())
 }
 
-fn verify_merkle_proof(
+pub(crate) fn verify_merkle_proof(
     proof_specs: &[ics23::ProofSpec],
-    prefix: &MerklePrefix,
+    prefix: &CounterpartyPrefix,
     proof: &MerkleProof,
     root: &MerkleRoot,
     path: impl Into<Path>,
     value: Vec<u8>,
 ) -> anyhow::Result<()> {
-    let merkle_path = prefix.apply(vec![path.into().to_string()]);
+    let merkle_path = apply_prefix(prefix, path)?;
     tracing::debug!(
         ?root,
         ?merkle_path,
         value = ?hex::encode(&value),
     );
+    check_proof_keys(proof, &merkle_path)?;
     proof.verify_membership(proof_specs, root.clone(), merkle_path, value, 0)?;
 
     Ok(())
@@ -118,70 +487,89 @@ pub trait ClientUpgradeProofVerifier: StateReadExt + Sized {
         upgraded_tm_consensus_state: TendermintConsensusState,
         upgraded_tm_client_state: TendermintClientState,
     ) -> anyhow::Result<()> {
-        // get the stored client state for the counterparty
-        let trusted_client_state = self.get_client_state(client_id).await?;
+        let started = Instant::now();
+        let result: anyhow::Result<()> = async {
+            // get the stored client state for the counterparty
+            let trusted_client_state = self.get_client_state(client_id).await?;
+            client_has_proof_specs(client_id, &trusted_client_state)?;
 
-        // Check to see if the upgrade path is set
-        let mut upgrade_path = trusted_client_state.upgrade_path.clone();
-        if upgrade_path.pop().is_none() {
-            anyhow::bail!("upgrade path is not set");
-        };
+            // Check to see if the upgrade path is set
+            let mut upgrade_path = trusted_client_state.upgrade_path.clone();
+            if upgrade_path.pop().is_none() {
+                anyhow::bail!("upgrade path is not set");
+            };
 
-        let upgrade_path_prefix =
-            MerklePrefix::try_from(upgrade_path.clone().concat().into_bytes()).map_err(|_| {
+            let upgrade_path_prefix = MerklePrefix::try_from(
+                upgrade_path.clone().concat().into_bytes(),
+            )
+            .map_err(|_| {
                 anyhow::anyhow!("couldn't create commitment prefix from client upgrade path")
             })?;
+            let upgrade_path_prefix = CounterpartyPrefix::from(upgrade_path_prefix);
 
-        // check if the client is frozen
-        if trusted_client_state.is_frozen() {
-            anyhow::bail!("client is frozen");
-        }
-
-        // get the stored consensus state for the counterparty
-        let trusted_consensus_state = self
-            .get_verified_consensus_state(&trusted_client_state.latest_height(), client_id)
-            .await?;
-
-        // check that the client is not expired
-        let now = HI::get_block_timestamp(&self).await?;
-        let time_elapsed = now.duration_since(trusted_consensus_state.timestamp)?;
+            // check if the client is frozen
+            if trusted_client_state.is_frozen() {
+                anyhow::bail!("client is frozen");
+            }
 
-        if trusted_client_state.expired(time_elapsed) {
-            anyhow::bail!("client is expired");
-        }
+            // get the stored consensus state for the counterparty
+            let trusted_consensus_state = self
+                .get_verified_consensus_state(&trusted_client_state.latest_height(), client_id)
+                .await?;
+
+            // check that the client is not expired
+            let now = HI::get_block_timestamp(&self).await?;
+            check_client_not_expired(
+                client_id,
+                &trusted_client_state,
+                &trusted_consensus_state,
+                now,
+            )?;
 
-        verify_merkle_proof(
-            &trusted_client_state.proof_specs,
-            &upgrade_path_prefix,
-            client_state_proof,
-            &trusted_consensus_state.root,
-            ClientUpgradePath::UpgradedClientState(
-                trusted_client_state.latest_height().revision_height(),
-            ),
-            upgraded_tm_client_state.encode_to_vec(),
-        )?;
+            verify_merkle_proof(
+                &trusted_client_state.proof_specs,
+                &upgrade_path_prefix,
+                client_state_proof,
+                &trusted_consensus_state.root,
+                ClientUpgradePath::UpgradedClientState(
+                    trusted_client_state.latest_height().revision_height(),
+                ),
+                upgraded_tm_client_state.encode_to_vec(),
+            )?;
 
-        verify_merkle_proof(
-            &trusted_client_state.proof_specs,
-            &upgrade_path_prefix,
-            consensus_state_proof,
-            &trusted_consensus_state.root,
-            ClientUpgradePath::UpgradedClientConsensusState(
-                trusted_client_state.latest_height().revision_height(),
-            ),
-            upgraded_tm_consensus_state.encode_to_vec(),
-        )?;
+            verify_merkle_proof(
+                &trusted_client_state.proof_specs,
+                &upgrade_path_prefix,
+                consensus_state_proof,
+                &trusted_consensus_state.root,
+                ClientUpgradePath::UpgradedClientConsensusState(
+                    trusted_client_state.latest_height().revision_height(),
+                ),
+                upgraded_tm_consensus_state.encode_to_vec(),
+            )?;
 
-        Ok(())
+            Ok(())
+        }
+        .await;
+
+        verification_stats::record(
+            self,
+            client_id,
+            VerificationKind::ClientUpgrade,
+            started,
+            &result,
+        );
+        result
     }
 }
 
 impl<T: StateRead> ClientUpgradeProofVerifier for T {}
 
 #[async_trait]
-pub trait ChannelProofVerifier: StateReadExt {
-    async fn verify_channel_proof(
+pub trait ChannelProofVerifier: StateReadExt + inner::Inner {
+    async fn verify_channel_proof<HI: HostInterface>(
         &self,
+        connection_id: &ConnectionId,
         connection: &ConnectionEnd,
         proof: &MerkleProof,
         proof_height: &Height,
@@ -189,35 +577,127 @@ pub trait ChannelProofVerifier: StateReadExt {
         port_id: &PortId,
         expected_channel: &ChannelEnd,
     ) -> anyhow::Result<()> {
-        // get the stored client state for the counterparty
-        let trusted_client_state = self.get_client_state(&connection.client_id).await?;
-
-        // check if the client is frozen
-        // TODO: should we also check if the client is expired here?
-        if trusted_client_state.is_frozen() {
-            anyhow::bail!("client is frozen");
-        }
+        let started = Instant::now();
+        let result: anyhow::Result<()> = async {
+            // get the stored client state for the counterparty
+            let trusted_client_state = self.get_client_state(&connection.client_id).await?;
+            client_has_proof_specs(&connection.client_id, &trusted_client_state)?;
 
-        // get the stored consensus state for the counterparty
-        let trusted_consensus_state = self
-            .get_verified_consensus_state(proof_height, &connection.client_id)
-            .await?;
+            // check if the client is frozen or expired
+            if trusted_client_state.is_frozen() {
+                anyhow::bail!("client is frozen");
+            }
+            self.check_client_not_expired::<HI>(&connection.client_id, &trusted_client_state)
+                .await?;
+
+            // get the stored consensus state for the counterparty
+            let trusted_consensus_state = self
+                .get_verified_consensus_state(proof_height, &connection.client_id)
+                .await?;
+
+            trusted_client_state.verify_height(*proof_height)?;
+
+            // TODO: ok to clone this?
+            let value = expected_channel.clone().encode_vec();
+
+            let prefix = self
+                .get_counterparty_prefix(connection_id, connection)
+                .await?;
+            verify_merkle_proof(
+                &trusted_client_state.proof_specs,
+                &prefix,
+                proof,
+                &trusted_consensus_state.root,
+                ChannelEndPath::new(port_id, channel_id),
+                value,
+            )?;
 
-        trusted_client_state.verify_height(*proof_height)?;
+            Ok(())
+        }
+        .await;
+
+        verification_stats::record(
+            self,
+            &connection.client_id,
+            VerificationKind::Channel,
+            started,
+            &result,
+        );
+        result
+    }
 
-        // TODO: ok to clone this?
-        let value = expected_channel.clone().encode_vec();
+    /// Verifies that the counterparty's next receive sequence for the channel
+    /// has reached `expected_next_seq_recv`, i.e. that it has received every
+    /// packet sent on the channel before an upgrade, so the flush is complete.
+    async fn verify_upgrade_flush_complete<HI: HostInterface>(
+        &self,
+        connection_id: &ConnectionId,
+        connection: &ConnectionEnd,
+        proof: &MerkleProof,
+        proof_height: &Height,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        expected_next_seq_recv: u64,
+    ) -> anyhow::Result<()> {
+        let started = Instant::now();
+        let result: anyhow::Result<()> = async {
+            let trusted_client_state = self.get_client_state(&connection.client_id).await?;
+            client_has_proof_specs(&connection.client_id, &trusted_client_state)?;
 
-        verify_merkle_proof(
-            &trusted_client_state.proof_specs,
-            &connection.counterparty.prefix.clone(),
-            proof,
-            &trusted_consensus_state.root,
-            ChannelEndPath::new(port_id, channel_id),
-            value,
-        )?;
+            if trusted_client_state.is_frozen() {
+                anyhow::bail!("client is frozen");
+            }
+            self.check_client_not_expired::<HI>(&connection.client_id, &trusted_client_state)
+                .await?;
+
+            let trusted_consensus_state = self
+                .get_verified_consensus_state(proof_height, &connection.client_id)
+                .await?;
+
+            trusted_client_state.verify_height(*proof_height)?;
+
+            let prefix = self
+                .get_counterparty_prefix(connection_id, connection)
+                .await?;
+            verify_merkle_proof(
+                &trusted_client_state.proof_specs,
+                &prefix,
+                proof,
+                &trusted_consensus_state.root,
+                SeqRecvPath(port_id.clone(), channel_id.clone()),
+                encode_sequence(expected_next_seq_recv),
+            )
+            .map_err(|e| {
+                // If the proof is for an earlier sequence, the counterparty
+                // still has packets in flight.
+                match membership_proof_value(proof)
+                    .ok()
+                    .and_then(|committed| <[u8; 8]>::try_from(committed).ok())
+                    .map(u64::from_be_bytes)
+                {
+                    Some(committed_seq) if committed_seq < expected_next_seq_recv => {
+                        VerificationError::FlushIncomplete {
+                            committed_seq,
+                            expected: expected_next_seq_recv,
+                        }
+                        .into()
+                    }
+                    _ => e,
+                }
+            })?;
 
-        Ok(())
+            Ok(())
+        }
+        .await;
+
+        verification_stats::record(
+            self,
+            &connection.client_id,
+            VerificationKind::Channel,
+            started,
+            &result,
+        );
+        result
     }
 }
 
@@ -226,7 +706,7 @@ impl<T: StateRead> ChannelProofVerifier for T {}
 pub fn verify_connection_state(
     client_state: &TendermintClientState,
     height: Height,
-    prefix: &MerklePrefix,
+    prefix: &CounterpartyPrefix,
     proof: &MerkleProof,
     root: &MerkleRoot,
     conn_path: &ConnectionPath,
@@ -252,7 +732,7 @@ pub fn verify_connection_state(
 pub fn verify_client_full_state(
     client_state: &TendermintClientState,
     height: Height,
-    prefix: &MerklePrefix,
+    prefix: &CounterpartyPrefix,
     proof: &MerkleProof,
     root: &MerkleRoot,
     client_state_path: &ClientStatePath,
@@ -277,7 +757,7 @@ pub fn verify_client_full_state(
 pub fn verify_client_consensus_state(
     client_state: &TendermintClientState,
     height: Height,
-    prefix: &MerklePrefix,
+    prefix: &CounterpartyPrefix,
     proof: &MerkleProof,
     root: &MerkleRoot,
     client_cons_state_path: &ClientConsensusStatePath,
@@ -299,154 +779,538 @@ pub fn verify_client_consensus_state(
     Ok(())
 }
 
+/// Verifies that *some* connection end is committed at `conn_path` on the
+/// counterparty, returning it decoded.
+///
+/// Unlike [`verify_connection_state`], the caller doesn't need to know the
+/// expected connection end in advance: the committed value is read out of the
+/// membership proof and then verified like any other connection proof.
+pub fn verify_and_decode_connection_proof(
+    client_state: &TendermintClientState,
+    height: Height,
+    prefix: &CounterpartyPrefix,
+    proof: &MerkleProof,
+    root: &MerkleRoot,
+    conn_path: &ConnectionPath,
+) -> anyhow::Result<ConnectionEnd> {
+    let committed = membership_proof_value(proof)?;
+    let connection_end = ConnectionEnd::decode_vec(committed)
+        .map_err(|e| anyhow::anyhow!("committed value is not a valid connection end: {e}"))?;
+
+    verify_connection_state(
+        client_state,
+        height,
+        prefix,
+        proof,
+        root,
+        conn_path,
+        &connection_end,
+    )?;
+
+    Ok(connection_end)
+}
+
 #[async_trait]
 pub trait PacketProofVerifier: StateReadExt + inner::Inner {
     async fn verify_packet_recv_proof<HI: HostInterface>(
         &self,
+        connection_id: &ConnectionId,
         connection: &ConnectionEnd,
         msg: &MsgRecvPacket,
     ) -> anyhow::Result<()> {
-        let (trusted_client_state, trusted_consensus_state) = self
-            .get_trusted_client_and_consensus_state::<HI>(
-                &connection.client_id,
-                &msg.proof_height_on_a,
-                connection,
+        let started = Instant::now();
+        let result: anyhow::Result<()> = async {
+            let (trusted_client_state, trusted_consensus_state) = self
+                .get_trusted_client_and_consensus_state::<HI>(
+                    &connection.client_id,
+                    &msg.proof_height_on_a,
+                    connection_id,
+                    connection,
+                )
+                .await?;
+
+            let prefix = self
+                .get_counterparty_prefix(connection_id, connection)
+                .await?;
+            verify_packet_commitment_proofs(
+                &trusted_client_state.proof_specs,
+                &prefix,
+                &trusted_consensus_state.root,
+                &[msg],
             )
-            .await?;
+            .pop()
+            .expect("there is an outcome for each packet")
+        }
+        .await;
+
+        verification_stats::record(
+            self,
+            &connection.client_id,
+            VerificationKind::PacketRecv,
+            started,
+            &result,
+        );
+        result
+    }
 
-        let commitment_path = CommitmentPath {
-            port_id: msg.packet.port_on_a.clone(),
-            channel_id: msg.packet.chan_on_a.clone(),
-            sequence: msg.packet.sequence,
-        };
+    /// Verifies a packet commitment proof against a consensus state supplied
+    /// inline with the packet, rather than one stored by a prior client update.
+    ///
+    /// The inline consensus state is only trusted after `header` has been
+    /// verified against the client's stored state, exactly as it would be in a
+    /// `MsgUpdateClient`, and `inline_consensus_state` has been checked to be
+    /// the consensus state that header commits to. Like a stored consensus
+    /// state, it must also be within the connection's maximum consensus state
+    /// age, if one is registered. Nothing is written to state.
+    async fn verify_packet_recv_proof_with_inline_consensus_state<HI: HostInterface>(
+        &self,
+        connection_id: &ConnectionId,
+        connection: &ConnectionEnd,
+        msg: &MsgRecvPacket,
+        header: &TendermintHeader,
+        inline_consensus_state: &TendermintConsensusState,
+    ) -> anyhow::Result<()> {
+        let started = Instant::now();
+        let result: anyhow::Result<()> = async {
+            let trusted_client_state = self
+                .trust_inline_consensus_state::<HI>(
+                    connection_id,
+                    connection,
+                    &msg.proof_height_on_a,
+                    header,
+                    inline_consensus_state,
+                )
+                .await?;
+
+            let prefix = self
+                .get_counterparty_prefix(connection_id, connection)
+                .await?;
+            verify_packet_commitment_proofs(
+                &trusted_client_state.proof_specs,
+                &prefix,
+                &inline_consensus_state.root,
+                &[msg],
+            )
+            .pop()
+            .expect("there is an outcome for each packet")
+        }
+        .await;
+
+        verification_stats::record(
+            self,
+            &connection.client_id,
+            VerificationKind::PacketRecv,
+            started,
+            &result,
+        );
+        result
+    }
 
-        let commitment_bytes = commit_packet(&msg.packet);
+    /// Verifies the commitment proofs of a batch of packets received on
+    /// `channel`.
+    ///
+    /// On ordered channels, the batch must also have contiguous, ascending
+    /// sequences starting at `expected_start_seq`, the channel's next receive
+    /// sequence. Unordered channels accept packets in any order.
+    ///
+    /// Packets are grouped by the height they are proven at, so that the
+    /// consensus state for each height is only looked up and checked once, and
+    /// the parts of their proofs that packets proven at the same height share
+    /// are only verified once.
+    async fn verify_packet_recv_batch<HI: HostInterface>(
+        &self,
+        connection_id: &ConnectionId,
+        connection: &ConnectionEnd,
+        channel: &ChannelEnd,
+        msgs: &[MsgRecvPacket],
+        expected_start_seq: u64,
+    ) -> anyhow::Result<()> {
+        if channel.ordering == Order::Ordered {
+            check_ordered_batch_sequences(msgs.iter().map(|msg| &msg.packet), expected_start_seq)?;
+        }
 
-        verify_merkle_proof(
-            &trusted_client_state.proof_specs,
-            &connection.counterparty.prefix.clone(),
-            &msg.proof_commitment_on_a,
-            &trusted_consensus_state.root,
-            commitment_path,
-            commitment_bytes,
-        )?;
+        let mut by_height: BTreeMap<Height, Vec<&MsgRecvPacket>> = BTreeMap::new();
+        for msg in msgs {
+            by_height
+                .entry(msg.proof_height_on_a)
+                .or_default()
+                .push(msg);
+        }
+
+        let prefix = self
+            .get_counterparty_prefix(connection_id, connection)
+            .await?;
+        for (height, msgs) in by_height {
+            let started = Instant::now();
+            let (trusted_client_state, trusted_consensus_state) = match self
+                .get_trusted_client_and_consensus_state::<HI>(
+                    &connection.client_id,
+                    &height,
+                    connection_id,
+                    connection,
+                )
+                .await
+            {
+                Ok(trusted) => trusted,
+                Err(e) => {
+                    let result: anyhow::Result<()> = Err(e);
+                    for _ in &msgs {
+                        verification_stats::record(
+                            self,
+                            &connection.client_id,
+                            VerificationKind::PacketRecv,
+                            started,
+                            &result,
+                        );
+                    }
+                    return result;
+                }
+            };
+
+            let results = verify_packet_commitment_proofs(
+                &trusted_client_state.proof_specs,
+                &prefix,
+                &trusted_consensus_state.root,
+                &msgs,
+            );
+            for result in &results {
+                verification_stats::record(
+                    self,
+                    &connection.client_id,
+                    VerificationKind::PacketRecv,
+                    started,
+                    result,
+                );
+            }
+            for (msg, result) in msgs.iter().zip(results) {
+                result.with_context(|| format!("packet {:?} failed to verify", msg.packet))?;
+            }
+        }
 
         Ok(())
     }
 
     async fn verify_packet_ack_proof<HI: HostInterface>(
         &self,
+        connection_id: &ConnectionId,
         connection: &ConnectionEnd,
         msg: &MsgAcknowledgement,
     ) -> anyhow::Result<()> {
-        let (trusted_client_state, trusted_consensus_state) = self
-            .get_trusted_client_and_consensus_state::<HI>(
-                &connection.client_id,
-                &msg.proof_height_on_b,
-                connection,
-            )
-            .await?;
-
-        let ack_path = AckPath {
-            port_id: msg.packet.port_on_b.clone(),
-            channel_id: msg.packet.chan_on_b.clone(),
-            sequence: msg.packet.sequence,
-        };
-
-        let ack_bytes = commit_acknowledgement(&msg.acknowledgement);
+        let started = Instant::now();
+        let result: anyhow::Result<()> = async {
+            let (trusted_client_state, trusted_consensus_state) = self
+                .get_trusted_client_and_consensus_state::<HI>(
+                    &connection.client_id,
+                    &msg.proof_height_on_b,
+                    connection_id,
+                    connection,
+                )
+                .await?;
+
+            let ack_path = AckPath {
+                port_id: msg.packet.port_on_b.clone(),
+                channel_id: msg.packet.chan_on_b.clone(),
+                sequence: msg.packet.sequence,
+            };
+
+            let ack_bytes = commit_acknowledgement(&msg.acknowledgement);
+
+            let prefix = self
+                .get_counterparty_prefix(connection_id, connection)
+                .await?;
+            verify_merkle_proof(
+                &trusted_client_state.proof_specs,
+                &prefix,
+                &msg.proof_acked_on_b,
+                &trusted_consensus_state.root,
+                ack_path,
+                ack_bytes,
+            )?;
 
-        verify_merkle_proof(
-            &trusted_client_state.proof_specs,
-            &connection.counterparty.prefix.clone(),
-            &msg.proof_acked_on_b,
-            &trusted_consensus_state.root,
-            ack_path,
-            ack_bytes,
-        )?;
+            // Channels whose application acknowledges asynchronously have a
+            // sentinel; a proven sentinel is not a final acknowledgement.
+            if self
+                .is_pending_ack(
+                    &msg.packet.chan_on_a,
+                    &msg.packet.port_on_a,
+                    &msg.acknowledgement,
+                )
+                .await?
+            {
+                return Err(VerificationError::AckPending.into());
+            }
 
-        Ok(())
+            Ok(())
+        }
+        .await;
+
+        verification_stats::record(
+            self,
+            &connection.client_id,
+            VerificationKind::PacketAck,
+            started,
+            &result,
+        );
+        result
     }
 
     async fn verify_packet_timeout_proof<HI: HostInterface>(
         &self,
+        connection_id: &ConnectionId,
         connection: &ConnectionEnd,
         msg: &MsgTimeout,
     ) -> anyhow::Result<()> {
-        let (trusted_client_state, trusted_consensus_state) = self
-            .get_trusted_client_and_consensus_state::<HI>(
-                &connection.client_id,
-                &msg.proof_height_on_b,
-                connection,
-            )
-            .await?;
-
-        let seq_bytes = msg.next_seq_recv_on_b.0.to_be_bytes().to_vec();
-        let seq_path = SeqRecvPath(msg.packet.port_on_b.clone(), msg.packet.chan_on_b.clone());
-
-        verify_merkle_proof(
-            &trusted_client_state.proof_specs,
-            &connection.counterparty.prefix.clone(),
-            &msg.proof_unreceived_on_b,
-            &trusted_consensus_state.root,
-            seq_path,
-            seq_bytes,
-        )?;
+        let started = Instant::now();
+        let result: anyhow::Result<()> = async {
+            let (trusted_client_state, trusted_consensus_state) = self
+                .get_trusted_client_and_consensus_state::<HI>(
+                    &connection.client_id,
+                    &msg.proof_height_on_b,
+                    connection_id,
+                    connection,
+                )
+                .await?;
+
+            let seq_bytes = encode_sequence(msg.next_seq_recv_on_b.0);
+            let seq_path = SeqRecvPath(msg.packet.port_on_b.clone(), msg.packet.chan_on_b.clone());
+
+            let prefix = self
+                .get_counterparty_prefix(connection_id, connection)
+                .await?;
+            verify_merkle_proof(
+                &trusted_client_state.proof_specs,
+                &prefix,
+                &msg.proof_unreceived_on_b,
+                &trusted_consensus_state.root,
+                seq_path,
+                seq_bytes,
+            )?;
 
-        Ok(())
+            Ok(())
+        }
+        .await;
+
+        verification_stats::record(
+            self,
+            &connection.client_id,
+            VerificationKind::PacketTimeout,
+            started,
+            &result,
+        );
+        result
     }
 
     async fn verify_packet_timeout_absence_proof<HI: HostInterface>(
         &self,
+        connection_id: &ConnectionId,
         connection: &ConnectionEnd,
         msg: &MsgTimeout,
     ) -> anyhow::Result<()> {
-        let (trusted_client_state, trusted_consensus_state) = self
-            .get_trusted_client_and_consensus_state::<HI>(
-                &connection.client_id,
-                &msg.proof_height_on_b,
-                connection,
-            )
-            .await?;
+        let started = Instant::now();
+        let result: anyhow::Result<()> = async {
+            let (trusted_client_state, trusted_consensus_state) = self
+                .get_trusted_client_and_consensus_state::<HI>(
+                    &connection.client_id,
+                    &msg.proof_height_on_b,
+                    connection_id,
+                    connection,
+                )
+                .await?;
+
+            let receipt_path = ReceiptPath {
+                port_id: msg.packet.port_on_b.clone(),
+                channel_id: msg.packet.chan_on_b.clone(),
+                sequence: msg.packet.sequence,
+            };
+
+            let prefix = self
+                .get_counterparty_prefix(connection_id, connection)
+                .await?;
+            verify_merkle_absence_proof(
+                &trusted_client_state.proof_specs,
+                &prefix,
+                &msg.proof_unreceived_on_b,
+                &trusted_consensus_state.root,
+                receipt_path,
+            )?;
 
-        let receipt_path = ReceiptPath {
-            port_id: msg.packet.port_on_b.clone(),
-            channel_id: msg.packet.chan_on_b.clone(),
-            sequence: msg.packet.sequence,
-        };
+            Ok(())
+        }
+        .await;
+
+        verification_stats::record(
+            self,
+            &connection.client_id,
+            VerificationKind::PacketTimeout,
+            started,
+            &result,
+        );
+        result
+    }
+}
 
-        verify_merkle_absence_proof(
-            &trusted_client_state.proof_specs,
-            &connection.counterparty.prefix.clone(),
-            &msg.proof_unreceived_on_b,
-            &trusted_consensus_state.root,
-            receipt_path,
-        )?;
+impl<T: StateRead> PacketProofVerifier for T {}
 
-        Ok(())
+/// Verifies proofs of arbitrary counterparty state, such as the results of
+/// cross-chain (ICS-31) queries, against the client of one of our connections.
+///
+/// Keys are given as a full [`MerklePath`], from the counterparty's substore
+/// (e.g. `bank`) down to the key within it, rather than as an IBC path.
+#[async_trait]
+pub trait QueryProofVerifier: StateReadExt + inner::Inner {
+    /// Verifies that nothing is committed at `key_path` in the state of the
+    /// counterparty of `connection_id`, as of `proof_height`.
+    async fn verify_query_absence_proof<HI: HostInterface>(
+        &self,
+        connection_id: &ConnectionId,
+        proof_height: &Height,
+        proof: &MerkleProof,
+        key_path: MerklePath,
+    ) -> anyhow::Result<()> {
+        let connection = self
+            .get_connection(connection_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("connection {connection_id} not found"))?;
+        if !connection.state_matches(&ConnectionState::Open) {
+            anyhow::bail!("connection {connection_id} is not open");
+        }
+
+        let started = Instant::now();
+        let result: anyhow::Result<()> = async {
+            if key_path.key_path.is_empty() {
+                anyhow::bail!("query key path is empty");
+            }
+
+            let (trusted_client_state, trusted_consensus_state) = self
+                .get_trusted_client_and_consensus_state::<HI>(
+                    &connection.client_id,
+                    proof_height,
+                    connection_id,
+                    &connection,
+                )
+                .await?;
+
+            verify_merkle_absence_proof_at(
+                &trusted_client_state.proof_specs,
+                proof,
+                &trusted_consensus_state.root,
+                key_path,
+            )
+        }
+        .await;
+
+        verification_stats::record(
+            self,
+            &connection.client_id,
+            VerificationKind::Query,
+            started,
+            &result,
+        );
+        result
     }
 }
 
-impl<T: StateRead> PacketProofVerifier for T {}
+impl<T: StateRead> QueryProofVerifier for T {}
 
 mod inner {
+    use crate::component::connection::StateReadExt as _;
+    use crate::component::view::StateReadExt as _;
     use crate::component::HostInterface;
 
     use super::*;
 
     #[async_trait]
     pub trait Inner: StateReadExt + Sized {
+        /// Checks that the client's latest consensus state is still within its
+        /// trusting period.
+        async fn check_client_not_expired<HI: HostInterface>(
+            &self,
+            client_id: &ClientId,
+            client_state: &TendermintClientState,
+        ) -> anyhow::Result<()> {
+            let latest_consensus_state = self
+                .get_verified_consensus_state(&client_state.latest_height(), client_id)
+                .await?;
+            let now = HI::get_block_timestamp(&self).await?;
+
+            check_client_not_expired(client_id, client_state, &latest_consensus_state, now)
+        }
+
+        /// Checks that `inline_consensus_state` can be trusted for proofs at
+        /// `proof_height`, returning the client's trusted state.
+        async fn trust_inline_consensus_state<HI: HostInterface>(
+            &self,
+            connection_id: &ConnectionId,
+            connection: &ConnectionEnd,
+            proof_height: &Height,
+            header: &TendermintHeader,
+            inline_consensus_state: &TendermintConsensusState,
+        ) -> anyhow::Result<TendermintClientState> {
+            let trusted_client_state = self.get_client_state(&connection.client_id).await?;
+            client_has_proof_specs(&connection.client_id, &trusted_client_state)?;
+
+            if trusted_client_state.is_frozen() {
+                anyhow::bail!("client is frozen");
+            }
+            self.check_client_not_expired::<HI>(&connection.client_id, &trusted_client_state)
+                .await?;
+
+            check_proof_revision(proof_height, &trusted_client_state)?;
+
+            if header.height() != *proof_height {
+                anyhow::bail!(
+                    "inline header height {} does not match proof height {}",
+                    header.height(),
+                    proof_height
+                );
+            }
+
+            // An inline consensus state has never been processed by this chain, so
+            // it can't have satisfied a non-zero connection delay.
+            if !connection.delay_period.is_zero() {
+                anyhow::bail!("inline consensus states cannot be used on connections with a delay");
+            }
+
+            verify_tendermint_header::<&Self, HI>(
+                self,
+                &connection.client_id,
+                &trusted_client_state,
+                header,
+            )
+            .await
+            .context("inline consensus state does not descend from a stored trusted state")?;
+
+            if TendermintConsensusState::from(header.clone()) != *inline_consensus_state {
+                anyhow::bail!("inline consensus state does not match the verified header");
+            }
+
+            check_consensus_state_age(
+                connection_id,
+                self.get_connection_max_consensus_age(connection_id).await?,
+                inline_consensus_state,
+                HI::get_block_timestamp(&self).await?,
+            )?;
+
+            Ok(trusted_client_state)
+        }
+
         async fn get_trusted_client_and_consensus_state<HI: HostInterface>(
             &self,
             client_id: &ClientId,
             height: &Height,
+            connection_id: &ConnectionId,
             connection: &ConnectionEnd,
         ) -> anyhow::Result<(TendermintClientState, TendermintConsensusState)> {
             let trusted_client_state = self.get_client_state(client_id).await?;
+            client_has_proof_specs(client_id, &trusted_client_state)?;
 
-            // TODO: should we also check if the client is expired here?
             if trusted_client_state.is_frozen() {
                 anyhow::bail!("client is frozen");
             }
+            self.check_client_not_expired::<HI>(client_id, &trusted_client_state)
+                .await?;
+
+            check_proof_revision(height, &trusted_client_state)?;
 
             let trusted_consensus_state =
                 self.get_verified_consensus_state(height, client_id).await?;
@@ -462,8 +1326,14 @@ mod inner {
             let processed_height = self.get_client_update_height(client_id, height).await?;
             let processed_time = self.get_client_update_time(client_id, height).await?;
 
-            // NOTE: hardcoded for now, should probably be a chain parameter.
-            let max_time_per_block = std::time::Duration::from_secs(20);
+            check_consensus_state_age(
+                connection_id,
+                self.get_connection_max_consensus_age(connection_id).await?,
+                &trusted_consensus_state,
+                current_timestamp,
+            )?;
+
+            let max_time_per_block = self.get_ibc_params().await?.max_expected_time_per_block;
 
             let delay_period_time = connection.delay_period;
             let delay_period_blocks =
@@ -476,7 +1346,12 @@ mod inner {
                 processed_height,
                 delay_period_time,
                 delay_period_blocks,
-            )?;
+            )
+            .with_context(|| {
+                format!(
+                    "delay period of connection {connection_id} ({delay_period_time:?}, or {delay_period_blocks} blocks) has not passed"
+                )
+            })?;
 
             Ok((tm_client_state, trusted_consensus_state))
         }
@@ -484,3 +1359,896 @@ mod inner {
 
     impl<T: StateReadExt> Inner for T {}
 }
+
+#[cfg(test)]
+mod tests {
+    use base64::prelude::*;
+    use cnidarium::StateDelta;
+    use ibc_types::core::{
+        channel::{channel::State as ChannelState, Counterparty, TimeoutHeight, Version},
+        client::msgs::{MsgCreateClient, MsgUpdateClient},
+        commitment::MerkleProof,
+        connection::{ConnectionId, Counterparty as ConnectionCounterparty, State},
+    };
+    use ibc_types::timestamp::Timestamp;
+    use proptest::prelude::*;
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::component::client::StateWriteExt as _;
+    use crate::component::ics02_validation;
+    use crate::component::state_key;
+    use crate::component::StateReadExt as _;
+    use crate::{IBC_COMMITMENT_PREFIX, IBC_PROOF_SPECS};
+
+    struct MockHost {}
+
+    #[async_trait]
+    impl HostInterface for MockHost {
+        async fn get_chain_id<S: StateRead>(_state: S) -> anyhow::Result<String> {
+            Ok("mock_chain_id".to_string())
+        }
+
+        async fn get_revision_number<S: StateRead>(_state: S) -> anyhow::Result<u64> {
+            Ok(0)
+        }
+
+        async fn get_block_height<S: StateRead>(_state: S) -> anyhow::Result<u64> {
+            Ok(0)
+        }
+
+        async fn get_block_timestamp<S: StateRead>(_state: S) -> anyhow::Result<tendermint::Time> {
+            Ok(tendermint::Time::unix_epoch())
+        }
+    }
+
+    /// A host whose clock reads the time of the first update of the Stargaze
+    /// client on the Cosmos Hub, so that the header of that update verifies.
+    struct StargazeUpdateHost {}
+
+    #[async_trait]
+    impl HostInterface for StargazeUpdateHost {
+        async fn get_chain_id<S: StateRead>(_state: S) -> anyhow::Result<String> {
+            Ok("mock_chain_id".to_string())
+        }
+
+        async fn get_revision_number<S: StateRead>(_state: S) -> anyhow::Result<u64> {
+            Ok(0)
+        }
+
+        async fn get_block_height<S: StateRead>(_state: S) -> anyhow::Result<u64> {
+            Ok(1)
+        }
+
+        async fn get_block_timestamp<S: StateRead>(_state: S) -> anyhow::Result<tendermint::Time> {
+            Ok(tendermint::Time::parse_from_rfc3339(
+                "2022-02-11T17:30:50.425417198Z",
+            )?)
+        }
+    }
+
+    fn stargaze_create_client() -> MsgCreateClient {
+        let msg_create_client_stargaze_raw = BASE64_STANDARD
+            .decode(include_str!("./test/create_client.msg").replace('\n', ""))
+            .unwrap();
+        <MsgCreateClient as DomainType>::decode(msg_create_client_stargaze_raw.as_slice()).unwrap()
+    }
+
+    fn stargaze_client_state() -> TendermintClientState {
+        ics02_validation::get_tendermint_client_state(stargaze_create_client().client_state)
+            .unwrap()
+    }
+
+    fn test_connection(client_id: &ClientId) -> ConnectionEnd {
+        ConnectionEnd {
+            state: State::Open,
+            client_id: client_id.clone(),
+            counterparty: ConnectionCounterparty {
+                client_id: ClientId::from_str("07-tendermint-0").unwrap(),
+                connection_id: Some(ConnectionId::new(0)),
+                prefix: IBC_COMMITMENT_PREFIX.clone(),
+            },
+            versions: vec![],
+            delay_period: Duration::ZERO,
+        }
+    }
+
+    fn test_channel() -> ChannelEnd {
+        ChannelEnd {
+            state: ChannelState::Init,
+            ordering: Order::Unordered,
+            remote: Counterparty::new(PortId::transfer(), None),
+            connection_hops: vec![ConnectionId::new(0)],
+            version: Version::new("ics20-1".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn empty_proof_specs_are_rejected() -> anyhow::Result<()> {
+        let mut state = StateDelta::new(());
+
+        let client_id = ClientId::from_str("07-tendermint-0")?;
+        let mut client_state = stargaze_client_state();
+        client_state.proof_specs = vec![];
+        state.put_client(&client_id, client_state.clone());
+
+        let connection = test_connection(&client_id);
+        let expected_channel = test_channel();
+
+        let err = state
+            .verify_channel_proof::<MockHost>(
+                &ConnectionId::new(0),
+                &connection,
+                &MerkleProof { proofs: vec![] },
+                &client_state.latest_height(),
+                &ChannelId::new(0),
+                &PortId::transfer(),
+                &expected_channel,
+            )
+            .await
+            .expect_err("a client without proof specs cannot verify proofs");
+
+        assert!(matches!(
+            err.downcast_ref::<VerificationError>(),
+            Some(VerificationError::ClientMissingProofSpecs { client_id: id }) if *id == client_id
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn verification_outcomes_are_flushed_at_end_of_block() -> anyhow::Result<()> {
+        let mut state = StateDelta::new(());
+
+        let client_id = ClientId::from_str("07-tendermint-0")?;
+        let mut client_state = stargaze_client_state();
+        client_state.proof_specs = vec![];
+        state.put_client(&client_id, client_state.clone());
+
+        let connection = test_connection(&client_id);
+        let height = client_state.latest_height();
+        async fn fail_verification<S: StateRead>(
+            state: &S,
+            connection: &ConnectionEnd,
+            height: &Height,
+        ) {
+            state
+                .verify_channel_proof::<MockHost>(
+                    &ConnectionId::new(0),
+                    connection,
+                    &MerkleProof { proofs: vec![] },
+                    height,
+                    &ChannelId::new(0),
+                    &PortId::transfer(),
+                    &test_channel(),
+                )
+                .await
+                .expect_err("a client without proof specs cannot verify proofs");
+        }
+
+        // Verifications outside of a block, e.g. of transactions checked for
+        // the mempool, are not recorded.
+        fail_verification(&state, &connection, &height).await;
+        verification_stats::flush(&mut state).await;
+        let stats = state.get_client_verification_stats(&client_id).await?;
+        assert_eq!(stats.channel.failed, 0);
+
+        verification_stats::begin_block(&mut state);
+        let applied = StateDelta::new(&mut state);
+        fail_verification(&applied, &connection, &height).await;
+        applied.apply();
+        // The outcomes of failed transactions are kept, even though their
+        // state changes are discarded.
+        let discarded = StateDelta::new(&mut state);
+        fail_verification(&discarded, &connection, &height).await;
+        drop(discarded);
+
+        // Nothing is persisted until the end of the block.
+        let stats = state.get_client_verification_stats(&client_id).await?;
+        assert_eq!(stats.channel.failed, 0);
+
+        verification_stats::flush(&mut state).await;
+
+        // A flush with nothing pending keeps the stored counts.
+        verification_stats::flush(&mut state).await;
+
+        let stats = state.get_client_verification_stats(&client_id).await?;
+        assert_eq!(stats.channel.failed, 2);
+        assert_eq!(stats.channel.succeeded, 0);
+        assert_eq!(stats.packet_recv, Default::default());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn simulated_verifications_are_not_recorded() -> anyhow::Result<()> {
+        let mut state = StateDelta::new(());
+
+        let client_id = ClientId::from_str("07-tendermint-0")?;
+        let mut client_state = stargaze_client_state();
+        client_state.proof_specs = vec![];
+        state.put_client(&client_id, client_state.clone());
+
+        let connection = test_connection(&client_id);
+        verification_stats::begin_block(&mut state);
+        let mut simulation = StateDelta::new(&mut state);
+        verification_stats::mark_simulation(&mut simulation);
+        simulation
+            .verify_channel_proof::<MockHost>(
+                &ConnectionId::new(0),
+                &connection,
+                &MerkleProof { proofs: vec![] },
+                &client_state.latest_height(),
+                &ChannelId::new(0),
+                &PortId::transfer(),
+                &test_channel(),
+            )
+            .await
+            .expect_err("a client without proof specs cannot verify proofs");
+        // Even if the simulated fork were kept, it has nothing to flush.
+        simulation.apply();
+        state.object_delete(state_key::verification_stats::simulating());
+
+        verification_stats::flush(&mut state).await;
+        let stats = state.get_client_verification_stats(&client_id).await?;
+        assert_eq!(stats.channel, Default::default());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn queries_need_an_open_connection_and_a_key() -> anyhow::Result<()> {
+        use crate::component::connection::StateWriteExt as _;
+
+        let mut state = StateDelta::new(());
+
+        let client_id = ClientId::from_str("07-tendermint-0")?;
+        let client_state = stargaze_client_state();
+        let open_id = ConnectionId::new(0);
+        let init_id = ConnectionId::new(1);
+
+        state.put_client(&client_id, client_state.clone());
+        state.update_connection(&open_id, test_connection(&client_id));
+        state.update_connection(
+            &init_id,
+            ConnectionEnd {
+                state: State::Init,
+                ..test_connection(&client_id)
+            },
+        );
+
+        let no_proof = MerkleProof { proofs: vec![] };
+        let height = client_state.latest_height();
+
+        let err = state
+            .verify_query_absence_proof::<MockHost>(
+                &init_id,
+                &height,
+                &no_proof,
+                MerklePath {
+                    key_path: vec!["bank".to_string(), "balances".to_string()],
+                },
+            )
+            .await
+            .expect_err("queries can't be verified over a connection that isn't open");
+        assert!(err.to_string().contains("is not open"));
+
+        let err = state
+            .verify_query_absence_proof::<MockHost>(
+                &open_id,
+                &height,
+                &no_proof,
+                MerklePath { key_path: vec![] },
+            )
+            .await
+            .expect_err("an empty key path can't be proven absent");
+        assert!(err.to_string().contains("key path is empty"));
+
+        Ok(())
+    }
+
+    /// A state with a freshly created Stargaze client, and the header of the
+    /// client's first update, which is never applied.
+    async fn stargaze_client_and_first_header(
+    ) -> anyhow::Result<(StateDelta<()>, ClientId, TendermintHeader)> {
+        let mut state = StateDelta::new(());
+
+        let client_id = ClientId::from_str("07-tendermint-0")?;
+        let msg_create_client = stargaze_create_client();
+        let client_state =
+            ics02_validation::get_tendermint_client_state(msg_create_client.client_state)?;
+        state.put_client(&client_id, client_state.clone());
+        state
+            .put_verified_consensus_state::<StargazeUpdateHost>(
+                client_state.latest_height(),
+                client_id.clone(),
+                ics02_validation::get_tendermint_consensus_state(
+                    msg_create_client.consensus_state,
+                )?,
+            )
+            .await?;
+
+        let msg_update_client_raw =
+            BASE64_STANDARD.decode(include_str!("./test/update_client_1.msg").replace('\n', ""))?;
+        let msg_update_client =
+            <MsgUpdateClient as DomainType>::decode(msg_update_client_raw.as_slice())?;
+        let header = ics02_validation::get_tendermint_header(msg_update_client.client_message)?;
+
+        Ok((state, client_id, header))
+    }
+
+    #[tokio::test]
+    async fn inline_consensus_states_must_match_a_verified_header() -> anyhow::Result<()> {
+        use super::inner::Inner as _;
+
+        let (state, client_id, header) = stargaze_client_and_first_header().await?;
+
+        let connection = test_connection(&client_id);
+        let inline_consensus_state = TendermintConsensusState::from(header.clone());
+        state
+            .trust_inline_consensus_state::<StargazeUpdateHost>(
+                &ConnectionId::new(0),
+                &connection,
+                &header.height(),
+                &header,
+                &inline_consensus_state,
+            )
+            .await?;
+
+        // The header verifies, but the consensus state isn't the one it commits to.
+        let mismatched_consensus_state = TendermintConsensusState {
+            root: MerkleRoot { hash: vec![0; 32] },
+            ..inline_consensus_state
+        };
+        let msg = MsgRecvPacket {
+            packet: Packet {
+                sequence: 1u64.into(),
+                port_on_a: PortId::transfer(),
+                chan_on_a: ChannelId::new(0),
+                port_on_b: PortId::transfer(),
+                chan_on_b: ChannelId::new(0),
+                data: vec![],
+                timeout_height_on_b: TimeoutHeight::Never,
+                timeout_timestamp_on_b: Timestamp::none(),
+            },
+            proof_commitment_on_a: MerkleProof { proofs: vec![] },
+            proof_height_on_a: header.height(),
+            signer: "relayer".to_string(),
+        };
+        let err = state
+            .verify_packet_recv_proof_with_inline_consensus_state::<StargazeUpdateHost>(
+                &ConnectionId::new(0),
+                &connection,
+                &msg,
+                &header,
+                &mismatched_consensus_state,
+            )
+            .await
+            .expect_err("a consensus state the header doesn't commit to is not trusted");
+        assert!(err
+            .to_string()
+            .contains("does not match the verified header"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn inline_consensus_states_are_held_to_the_connection_max_age() -> anyhow::Result<()> {
+        use super::inner::Inner as _;
+        use crate::component::connection::StateWriteExt as _;
+        use crate::connection_metadata::ConnectionMetadata;
+
+        let (mut state, client_id, header) = stargaze_client_and_first_header().await?;
+
+        let connection_id = ConnectionId::new(0);
+        let connection = test_connection(&client_id);
+        let inline_consensus_state = TendermintConsensusState::from(header.clone());
+        let mut metadata = ConnectionMetadata {
+            counterparty_chain_id: "stargaze-1".to_string(),
+            display_name: "Stargaze".to_string(),
+            preferred_channels: vec![],
+            max_consensus_age: Some(Duration::from_secs(60)),
+            counterparty_prefix_path: vec![],
+        };
+
+        // The header is about eight seconds older than the host's block time.
+        state.put_connection_metadata(&connection_id, Some(metadata.clone()));
+        state
+            .trust_inline_consensus_state::<StargazeUpdateHost>(
+                &connection_id,
+                &connection,
+                &header.height(),
+                &header,
+                &inline_consensus_state,
+            )
+            .await?;
+
+        metadata.max_consensus_age = Some(Duration::from_secs(1));
+        state.put_connection_metadata(&connection_id, Some(metadata));
+        let err = state
+            .trust_inline_consensus_state::<StargazeUpdateHost>(
+                &connection_id,
+                &connection,
+                &header.height(),
+                &header,
+                &inline_consensus_state,
+            )
+            .await
+            .expect_err("an inline consensus state older than the maximum age is not trusted");
+        assert!(matches!(
+            err.downcast_ref::<VerificationError>(),
+            Some(VerificationError::ConsensusStateTooOld { connection_id: id, .. })
+                if *id == connection_id
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn connection_proofs_are_decoded_and_verified() -> anyhow::Result<()> {
+        use crate::component::connection::StateWriteExt as _;
+        use crate::{MerklePrefixExt as _, IBC_SUBSTORE_PREFIX};
+        use cnidarium::TempStorage;
+        use ibc_types::core::connection::Version as ConnectionVersion;
+
+        let storage = TempStorage::new_with_prefixes(vec![IBC_SUBSTORE_PREFIX.to_string()]).await?;
+
+        let client_id = ClientId::from_str("07-tendermint-0")?;
+        let connection = ConnectionEnd {
+            versions: vec![ConnectionVersion::default()],
+            ..test_connection(&client_id)
+        };
+        let conn_path = ConnectionPath::new(&ConnectionId::new(0));
+        let garbled_path = ConnectionPath::new(&ConnectionId::new(1));
+
+        let mut delta = StateDelta::new(storage.latest_snapshot());
+        delta.update_connection(&ConnectionId::new(0), connection.clone());
+        delta.put_raw(
+            IBC_COMMITMENT_PREFIX.apply_string(garbled_path.to_string()),
+            b"not a connection end".to_vec(),
+        );
+        storage.commit(delta).await?;
+
+        let snapshot = storage.latest_snapshot();
+        let root = MerkleRoot {
+            hash: snapshot.root_hash().await?.0.to_vec(),
+        };
+        let mut client_state = stargaze_client_state();
+        client_state.proof_specs = IBC_PROOF_SPECS.clone();
+        let height = client_state.latest_height();
+
+        let (_, proof) = snapshot
+            .get_with_proof(
+                IBC_COMMITMENT_PREFIX
+                    .apply_string(conn_path.to_string())
+                    .into_bytes(),
+            )
+            .await?;
+        let decoded = verify_and_decode_connection_proof(
+            &client_state,
+            height,
+            &IBC_COMMITMENT_PREFIX.clone().into(),
+            &proof,
+            &root,
+            &conn_path,
+        )?;
+        assert_eq!(decoded, connection);
+
+        let (_, proof) = snapshot
+            .get_with_proof(
+                IBC_COMMITMENT_PREFIX
+                    .apply_string(garbled_path.to_string())
+                    .into_bytes(),
+            )
+            .await?;
+        let err = verify_and_decode_connection_proof(
+            &client_state,
+            height,
+            &IBC_COMMITMENT_PREFIX.clone().into(),
+            &proof,
+            &root,
+            &garbled_path,
+        )
+        .expect_err("a committed value that isn't a connection end can't be decoded");
+        assert!(err.to_string().contains("not a valid connection end"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn block_delays_round_up_to_whole_blocks() {
+        let secs = Duration::from_secs;
+        let millis = Duration::from_millis;
+
+        // No delay, or no expected block time, means no block delay.
+        assert_eq!(calculate_block_delay(&Duration::ZERO, &secs(5)), 0);
+        assert_eq!(calculate_block_delay(&secs(30), &Duration::ZERO), 0);
+
+        assert_eq!(calculate_block_delay(&secs(30), &secs(5)), 6);
+        assert_eq!(calculate_block_delay(&secs(31), &secs(5)), 7);
+        assert_eq!(calculate_block_delay(&Duration::from_nanos(1), &secs(5)), 1);
+
+        // Sub-second blocks.
+        assert_eq!(calculate_block_delay(&secs(1), &millis(500)), 2);
+        assert_eq!(calculate_block_delay(&secs(1), &millis(300)), 4);
+        assert_eq!(calculate_block_delay(&millis(900), &millis(300)), 3);
+
+        // Delays too long to count in blocks saturate.
+        assert_eq!(
+            calculate_block_delay(&Duration::MAX, &Duration::from_nanos(1)),
+            u64::MAX
+        );
+    }
+
+    /// ibc-go's block delay, on the nanosecond counts it stores durations as.
+    fn ibc_go_block_delay(delay_period: u64, max_expected_time_per_block: u64) -> u64 {
+        if max_expected_time_per_block == 0 {
+            return 0;
+        }
+        (delay_period as f64 / max_expected_time_per_block as f64).ceil() as u64
+    }
+
+    proptest! {
+        // Below 2^50 nanoseconds (about 13 days), the quotient is never close
+        // enough to a whole number for ibc-go's float division to round onto it.
+        #[test]
+        fn block_delays_match_ibc_go(
+            delay_period in 0u64..(1 << 50),
+            max_expected_time_per_block in 0u64..(1 << 50),
+        ) {
+            prop_assert_eq!(
+                calculate_block_delay(
+                    &Duration::from_nanos(delay_period),
+                    &Duration::from_nanos(max_expected_time_per_block),
+                ),
+                ibc_go_block_delay(delay_period, max_expected_time_per_block)
+            );
+        }
+    }
+
+    #[test]
+    fn block_delays_are_exact_where_floats_are_not() {
+        let delay_period = (1 << 60) + 1;
+        assert_eq!(ibc_go_block_delay(delay_period, 1), 1 << 60);
+        assert_eq!(
+            calculate_block_delay(
+                &Duration::from_nanos(delay_period),
+                &Duration::from_nanos(1)
+            ),
+            delay_period
+        );
+    }
+
+    #[test]
+    fn mismatched_commitments_are_reported() {
+        let committed = vec![0xaa; 32];
+        let expected = vec![0xbb; 32];
+        let proof = MerkleProof {
+            proofs: vec![ics23::CommitmentProof {
+                proof: Some(ics23::commitment_proof::Proof::Exist(
+                    ics23::ExistenceProof {
+                        key: b"commitments/ports/transfer/channels/channel-0/sequences/1".to_vec(),
+                        value: committed.clone(),
+                        leaf: None,
+                        path: vec![],
+                    },
+                )),
+            }],
+        };
+
+        let err = commitment_mismatch_or(anyhow::anyhow!("proof failed"), &proof, &expected);
+        assert!(matches!(
+            err.downcast_ref::<VerificationError>(),
+            Some(VerificationError::CommitmentMismatch { expected_commitment, committed: c })
+                if *expected_commitment == expected && *c == committed
+        ));
+
+        // A proof of the expected value failed for some other reason.
+        let err = commitment_mismatch_or(anyhow::anyhow!("proof failed"), &proof, &committed);
+        assert!(err.downcast_ref::<VerificationError>().is_none());
+    }
+
+    #[test]
+    fn ordered_batches_must_be_contiguous() {
+        let packet = |sequence: u64| Packet {
+            sequence: sequence.into(),
+            port_on_a: PortId::transfer(),
+            chan_on_a: ChannelId::new(0),
+            port_on_b: PortId::transfer(),
+            chan_on_b: ChannelId::new(0),
+            data: vec![],
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: Timestamp::none(),
+        };
+
+        let batch = vec![packet(5), packet(6), packet(7)];
+        assert!(check_ordered_batch_sequences(&batch, 5).is_ok());
+
+        let batch = vec![packet(5), packet(7), packet(6)];
+        assert!(matches!(
+            check_ordered_batch_sequences(&batch, 5),
+            Err(VerificationError::SequenceGap {
+                at_index: 1,
+                expected: 6,
+                got: 7
+            })
+        ));
+
+        let batch = vec![packet(6)];
+        assert!(matches!(
+            check_ordered_batch_sequences(&batch, 5),
+            Err(VerificationError::SequenceGap {
+                at_index: 0,
+                expected: 5,
+                got: 6
+            })
+        ));
+
+        // No packet can follow the largest sequence.
+        let batch = vec![packet(u64::MAX - 1), packet(u64::MAX)];
+        assert!(check_ordered_batch_sequences(&batch, u64::MAX - 1).is_ok());
+
+        let batch = vec![packet(u64::MAX), packet(0)];
+        assert!(matches!(
+            check_ordered_batch_sequences(&batch, u64::MAX),
+            Err(VerificationError::SequenceOverflow { at_index: 1 })
+        ));
+    }
+
+    #[test]
+    fn proofs_from_another_revision_are_rejected() -> anyhow::Result<()> {
+        let client_state = stargaze_client_state();
+        let latest = client_state.latest_height();
+
+        let same_revision = Height::new(latest.revision_number(), 1)?;
+        assert!(check_proof_revision(&same_revision, &client_state).is_ok());
+
+        let next_revision = Height::new(latest.revision_number() + 1, 1)?;
+        assert!(matches!(
+            check_proof_revision(&next_revision, &client_state),
+            Err(VerificationError::RevisionMismatch { proof_revision, client_revision })
+                if proof_revision == latest.revision_number() + 1
+                    && client_revision == latest.revision_number()
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn packet_commitments_match_only_the_committed_packet() {
+        let packet = Packet {
+            sequence: 1u64.into(),
+            port_on_a: PortId::transfer(),
+            chan_on_a: ChannelId::new(0),
+            port_on_b: PortId::transfer(),
+            chan_on_b: ChannelId::new(0),
+            data: b"packet data".to_vec(),
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: Timestamp::from_nanoseconds(1_700_000_000_000_000_000).unwrap(),
+        };
+        let stored_commitment = commit_packet(&packet);
+
+        assert!(packet_commitment_matches(&packet, &stored_commitment));
+
+        let mut wrong_data = packet.clone();
+        wrong_data.data = b"other data".to_vec();
+        assert!(!packet_commitment_matches(&wrong_data, &stored_commitment));
+
+        let mut wrong_timeout = packet.clone();
+        wrong_timeout.timeout_timestamp_on_b = Timestamp::none();
+        assert!(!packet_commitment_matches(
+            &wrong_timeout,
+            &stored_commitment
+        ));
+
+        assert!(!packet_commitment_matches(&packet, &[]));
+    }
+
+    #[test]
+    fn proofs_for_another_sequence_are_rejected() {
+        let existence_proof = |key: &str| ics23::CommitmentProof {
+            proof: Some(ics23::commitment_proof::Proof::Exist(
+                ics23::ExistenceProof {
+                    key: key.as_bytes().to_vec(),
+                    value: vec![1; 32],
+                    leaf: None,
+                    path: vec![],
+                },
+            )),
+        };
+        let commitment_path = |sequence: u64| CommitmentPath {
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::new(0),
+            sequence: sequence.into(),
+        };
+
+        // A proof of the commitment to packet 1, inside the IBC substore.
+        let proof = MerkleProof {
+            proofs: vec![
+                existence_proof(&Path::from(commitment_path(1)).to_string()),
+                existence_proof("ibc-data"),
+            ],
+        };
+
+        let err = verify_merkle_proof(
+            &IBC_PROOF_SPECS,
+            &IBC_COMMITMENT_PREFIX.clone().into(),
+            &proof,
+            &MerkleRoot { hash: vec![0; 32] },
+            commitment_path(2),
+            vec![1; 32],
+        )
+        .expect_err("a proof for packet 1 cannot prove packet 2");
+
+        assert!(matches!(
+            err.downcast_ref::<VerificationError>(),
+            Some(VerificationError::PathMismatch { expected, got })
+                if expected.ends_with("sequences/2") && got.ends_with("sequences/1")
+        ));
+    }
+
+    #[test]
+    fn prefixes_are_single_keys_unless_a_key_path_is_registered() {
+        let existence_proof = |key: &str| ics23::CommitmentProof {
+            proof: Some(ics23::commitment_proof::Proof::Exist(
+                ics23::ExistenceProof {
+                    key: key.as_bytes().to_vec(),
+                    value: vec![1; 32],
+                    leaf: None,
+                    path: vec![],
+                },
+            )),
+        };
+        let commitment_path = CommitmentPath {
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::new(0),
+            sequence: 1u64.into(),
+        };
+
+        // A proof from a counterparty whose IBC store is nested two levels deep.
+        let proof = MerkleProof {
+            proofs: vec![
+                existence_proof(&Path::from(commitment_path.clone()).to_string()),
+                existence_proof("ibc"),
+                existence_proof("rollup"),
+            ],
+        };
+        let verify = |prefix: CounterpartyPrefix| {
+            verify_merkle_proof(
+                &IBC_PROOF_SPECS,
+                &prefix,
+                &proof,
+                &MerkleRoot { hash: vec![0; 32] },
+                commitment_path.clone(),
+                vec![1; 32],
+            )
+            .expect_err("the proofs have no inner nodes to reach the root")
+        };
+
+        // A commitment prefix containing a `/` is still a single key.
+        let err = verify(CounterpartyPrefix::Single(MerklePrefix {
+            key_prefix: b"rollup/ibc".to_vec(),
+        }));
+        assert!(matches!(
+            err.downcast_ref::<VerificationError>(),
+            Some(VerificationError::PathMismatch { expected, got })
+                if expected == "rollup/ibc" && got == "ibc"
+        ));
+
+        // A registered key path contributes one key per store.
+        let err = verify(CounterpartyPrefix::KeyPath(vec![
+            b"rollup".to_vec(),
+            b"ibc".to_vec(),
+        ]));
+        assert!(!matches!(
+            err.downcast_ref::<VerificationError>(),
+            Some(VerificationError::PathMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn non_utf8_counterparty_prefixes_are_rejected() {
+        let prefix = CounterpartyPrefix::Single(MerklePrefix {
+            key_prefix: vec![0xff, 0xfe],
+        });
+        let commitment_path = CommitmentPath {
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::new(0),
+            sequence: 1u64.into(),
+        };
+        let proof = MerkleProof { proofs: vec![] };
+        let root = MerkleRoot { hash: vec![0; 32] };
+
+        let err = verify_merkle_proof(
+            &IBC_PROOF_SPECS,
+            &prefix,
+            &proof,
+            &root,
+            commitment_path.clone(),
+            vec![1; 32],
+        )
+        .expect_err("no key path can be built under a non-utf-8 prefix");
+        assert!(matches!(
+            err.downcast_ref::<VerificationError>(),
+            Some(VerificationError::InvalidPrefix { prefix }) if *prefix == vec![0xff, 0xfe]
+        ));
+
+        let err =
+            verify_merkle_absence_proof(&IBC_PROOF_SPECS, &prefix, &proof, &root, commitment_path)
+                .expect_err("no key path can be built under a non-utf-8 prefix");
+        assert!(matches!(
+            err.downcast_ref::<VerificationError>(),
+            Some(VerificationError::InvalidPrefix { .. })
+        ));
+    }
+
+    #[test]
+    fn expired_clients_are_reported() -> anyhow::Result<()> {
+        let client_id = ClientId::from_str("07-tendermint-0")?;
+        let client_state = stargaze_client_state();
+        let consensus_state = TendermintConsensusState::new(
+            MerkleRoot { hash: vec![] },
+            tendermint::Time::from_unix_timestamp(1_700_000_000, 0)?,
+            tendermint::Hash::None,
+        );
+
+        let within_trusting_period = (consensus_state.timestamp + client_state.trusting_period)?;
+        check_client_not_expired(
+            &client_id,
+            &client_state,
+            &consensus_state,
+            within_trusting_period,
+        )?;
+
+        let after_trusting_period = (within_trusting_period + Duration::from_secs(1))?;
+        let err = check_client_not_expired(
+            &client_id,
+            &client_state,
+            &consensus_state,
+            after_trusting_period,
+        )
+        .expect_err("a client past its trusting period is expired");
+        assert!(matches!(
+            err.downcast_ref::<VerificationError>(),
+            Some(VerificationError::ClientExpired { client_id: id, trusting_period, .. })
+                if *id == client_id && *trusting_period == client_state.trusting_period
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn consensus_states_older_than_the_connection_allows_are_rejected() -> anyhow::Result<()> {
+        let connection_id = ConnectionId::new(0);
+        let consensus_state = TendermintConsensusState::new(
+            MerkleRoot { hash: vec![] },
+            tendermint::Time::from_unix_timestamp(1_700_000_000, 0)?,
+            tendermint::Hash::None,
+        );
+        let max_age = Duration::from_secs(60);
+
+        let at_max_age = (consensus_state.timestamp + max_age)?;
+        check_consensus_state_age(&connection_id, Some(max_age), &consensus_state, at_max_age)?;
+
+        // A consensus state from after our own block time is as fresh as it gets.
+        let before_consensus_state = (consensus_state.timestamp - max_age)?;
+        check_consensus_state_age(
+            &connection_id,
+            Some(max_age),
+            &consensus_state,
+            before_consensus_state,
+        )?;
+
+        let past_max_age = (at_max_age + Duration::from_secs(1))?;
+        assert!(matches!(
+            check_consensus_state_age(&connection_id, Some(max_age), &consensus_state, past_max_age),
+            Err(VerificationError::ConsensusStateTooOld { connection_id: id, age, max_age: max })
+                if id == connection_id && age == max_age + Duration::from_secs(1) && max == max_age
+        ));
+
+        // Without a maximum age, only the client's trusting period applies.
+        check_consensus_state_age(&connection_id, None, &consensus_state, past_max_age)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+use async_trait::async_trait;
+use cnidarium::{StateDelta, StateRead};
+use ibc_types::core::{channel::msgs::MsgRecvPacket, client::msgs::MsgUpdateClient};
+
+use super::{
+    channel::StateReadExt as _,
+    connection::StateReadExt as _,
+    msg_handler::update_client::{execute_update, header_is_tendermint},
+    proof_verification::PacketProofVerifier as _,
+    verification_stats, HostInterface,
+};
+
+/// The outcome of a simulated relay.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SimulationResult {
+    /// The client update was applied and the packet proof verified against it.
+    Verified,
+    /// The client update could not be applied.
+    UpdateFailed(String),
+    /// The client update was applied, but the packet proof did not verify.
+    RecvFailed(String),
+}
+
+impl SimulationResult {
+    /// Returns `true` if the client update was applied.
+    pub fn update_applied(&self) -> bool {
+        !matches!(self, SimulationResult::UpdateFailed(_))
+    }
+
+    /// Returns `true` if the packet proof verified against the updated client.
+    pub fn packet_verified(&self) -> bool {
+        matches!(self, SimulationResult::Verified)
+    }
+}
+
+#[async_trait]
+pub trait RelaySimulationExt: StateRead + Sized {
+    /// Simulates the usual relayer workflow of updating a client and then
+    /// receiving a packet proven against the new consensus state.
+    ///
+    /// The update is applied to a scratch fork of the state, which is
+    /// discarded once the packet proof has been checked, so nothing is ever
+    /// committed. Simulations are anonymous, so the verifications they perform
+    /// are not recorded in the client's verification statistics or metrics.
+    async fn simulate_update_and_recv<HI: HostInterface>(
+        &self,
+        update_msg: &MsgUpdateClient,
+        recv_msg: &MsgRecvPacket,
+    ) -> SimulationResult {
+        let mut scratch = StateDelta::new(self);
+        verification_stats::mark_simulation(&mut scratch);
+
+        let update = async {
+            header_is_tendermint(update_msg)?;
+            execute_update::<_, HI>(&mut scratch, update_msg).await
+        };
+        if let Err(e) = update.await {
+            return SimulationResult::UpdateFailed(format!("{e:#}"));
+        }
+
+        let recv = async {
+            let channel = scratch
+                .get_channel(&recv_msg.packet.chan_on_b, &recv_msg.packet.port_on_b)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("channel not found"))?;
+            let connection = scratch
+                .get_connection(&channel.connection_hops[0])
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("connection not found for channel"))?;
+
+            scratch
+                .verify_packet_recv_proof::<HI>(&channel.connection_hops[0], &connection, recv_msg)
+                .await
+        };
+        match recv.await {
+            Ok(()) => SimulationResult::Verified,
+            Err(e) => SimulationResult::RecvFailed(format!("{e:#}")),
+        }
+    }
+}
+
+impl<T: StateRead + Sized> RelaySimulationExt for T {}
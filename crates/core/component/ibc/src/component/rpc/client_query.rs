@@ -2,12 +2,12 @@ use async_trait::async_trait;
 
 use ibc_proto::ibc::core::client::v1::query_server::Query as ClientQuery;
 use ibc_proto::ibc::core::client::v1::{
-    ConsensusStateWithHeight, IdentifiedClientState, QueryClientParamsRequest,
-    QueryClientParamsResponse, QueryClientStateRequest, QueryClientStateResponse,
-    QueryClientStatesRequest, QueryClientStatesResponse, QueryClientStatusRequest,
-    QueryClientStatusResponse, QueryConsensusStateHeightsRequest,
-    QueryConsensusStateHeightsResponse, QueryConsensusStateRequest, QueryConsensusStateResponse,
-    QueryConsensusStatesRequest, QueryConsensusStatesResponse, QueryUpgradedClientStateRequest,
+    ConsensusStateWithHeight, Params, QueryClientParamsRequest, QueryClientParamsResponse,
+    QueryClientStateRequest, QueryClientStateResponse, QueryClientStatesRequest,
+    QueryClientStatesResponse, QueryClientStatusRequest, QueryClientStatusResponse,
+    QueryConsensusStateHeightsRequest, QueryConsensusStateHeightsResponse,
+    QueryConsensusStateRequest, QueryConsensusStateResponse, QueryConsensusStatesRequest,
+    QueryConsensusStatesResponse, QueryUpgradedClientStateRequest,
     QueryUpgradedClientStateResponse, QueryUpgradedConsensusStateRequest,
     QueryUpgradedConsensusStateResponse,
 };
@@ -26,7 +26,7 @@ use crate::component::{ClientStateReadExt, HostInterface};
 use crate::prefix::MerklePrefixExt;
 use crate::IBC_COMMITMENT_PREFIX;
 
-use super::utils::determine_snapshot_from_metadata;
+use super::utils::{all_client_states, determine_snapshot_from_metadata};
 use super::IbcQuery;
 
 #[async_trait]
@@ -81,28 +81,18 @@ impl<HI: HostInterface + Send + Sync + 'static> ClientQuery for IbcQuery<HI> {
     /// ClientStates queries all the IBC light clients of a chain.
     async fn client_states(
         &self,
-        _request: tonic::Request<QueryClientStatesRequest>,
+        request: tonic::Request<QueryClientStatesRequest>,
     ) -> std::result::Result<tonic::Response<QueryClientStatesResponse>, tonic::Status> {
-        let snapshot = self.storage.latest_snapshot();
+        let snapshot = match determine_snapshot_from_metadata(self.storage.clone(), request.metadata()) {
+            Err(err) => return Err(tonic::Status::aborted(
+                format!("could not determine the correct snapshot to open given the `\"height\"` header of the request: {err:#}")
+            )),
+            Ok(snapshot) => snapshot,
+        };
 
-        let client_counter = snapshot
-            .client_counter()
+        let client_states = all_client_states(&snapshot, &HI::light_clients())
             .await
-            .map_err(|e| tonic::Status::aborted(format!("couldn't get client counter: {e}")))?
-            .0;
-
-        let mut client_states = vec![];
-        for client_idx in 0..client_counter {
-            // NOTE: currently, we only look up tendermint clients, because we only support tendermint clients.
-            let client_id = ClientId::from_str(format!("07-tendermint-{}", client_idx).as_str())
-                .map_err(|e| tonic::Status::aborted(format!("invalid client id: {e}")))?;
-            let client_state = snapshot.get_client_state(&client_id).await;
-            let id_client = IdentifiedClientState {
-                client_id: client_id.to_string(),
-                client_state: client_state.ok().map(|state| state.into()), // send None if we couldn't find the client state
-            };
-            client_states.push(id_client);
-        }
+            .map_err(|e| tonic::Status::aborted(format!("couldn't get client states: {e}")))?;
 
         let res = QueryClientStatesResponse {
             client_states,
@@ -174,7 +164,12 @@ impl<HI: HostInterface + Send + Sync + 'static> ClientQuery for IbcQuery<HI> {
         &self,
         request: tonic::Request<QueryConsensusStatesRequest>,
     ) -> std::result::Result<tonic::Response<QueryConsensusStatesResponse>, tonic::Status> {
-        let snapshot = self.storage.latest_snapshot();
+        let snapshot = match determine_snapshot_from_metadata(self.storage.clone(), request.metadata()) {
+            Err(err) => return Err(tonic::Status::aborted(
+                format!("could not determine the correct snapshot to open given the `\"height\"` header of the request: {err:#}")
+            )),
+            Ok(snapshot) => snapshot,
+        };
         let client_id = ClientId::from_str(&request.get_ref().client_id)
             .map_err(|e| tonic::Status::invalid_argument(format!("invalid client id: {e}")))?;
 
@@ -216,7 +211,12 @@ impl<HI: HostInterface + Send + Sync + 'static> ClientQuery for IbcQuery<HI> {
         request: tonic::Request<QueryConsensusStateHeightsRequest>,
     ) -> std::result::Result<tonic::Response<QueryConsensusStateHeightsResponse>, tonic::Status>
     {
-        let snapshot = self.storage.latest_snapshot();
+        let snapshot = match determine_snapshot_from_metadata(self.storage.clone(), request.metadata()) {
+            Err(err) => return Err(tonic::Status::aborted(
+                format!("could not determine the correct snapshot to open given the `\"height\"` header of the request: {err:#}")
+            )),
+            Ok(snapshot) => snapshot,
+        };
         let client_id = ClientId::from_str(&request.get_ref().client_id)
             .map_err(|e| tonic::Status::invalid_argument(format!("invalid client id: {e}")))?;
 
@@ -248,13 +248,15 @@ impl<HI: HostInterface + Send + Sync + 'static> ClientQuery for IbcQuery<HI> {
         &self,
         request: tonic::Request<QueryClientStatusRequest>,
     ) -> std::result::Result<tonic::Response<QueryClientStatusResponse>, tonic::Status> {
-        let snapshot = self.storage.latest_snapshot();
+        let snapshot = match determine_snapshot_from_metadata(self.storage.clone(), request.metadata()) {
+            Err(err) => return Err(tonic::Status::aborted(
+                format!("could not determine the correct snapshot to open given the `\"height\"` header of the request: {err:#}")
+            )),
+            Ok(snapshot) => snapshot,
+        };
         let client_id = ClientId::from_str(&request.get_ref().client_id)
             .map_err(|e| tonic::Status::invalid_argument(format!("invalid client id: {e}")))?;
-        let timestamp = HI::get_block_timestamp(snapshot.clone())
-            .await
-            .map_err(|e| tonic::Status::aborted(format!("couldn't get block timestamp: {e}")))?;
-        let client_status = snapshot.get_client_status(&client_id, timestamp).await;
+        let client_status = snapshot.client_status::<HI>(&client_id).await;
         let resp = QueryClientStatusResponse {
             status: client_status.to_string(),
         };
@@ -266,14 +268,26 @@ impl<HI: HostInterface + Send + Sync + 'static> ClientQuery for IbcQuery<HI> {
         &self,
         _request: tonic::Request<QueryClientParamsRequest>,
     ) -> std::result::Result<tonic::Response<QueryClientParamsResponse>, tonic::Status> {
-        Err(tonic::Status::unimplemented("not implemented"))
+        let resp = QueryClientParamsResponse {
+            params: Some(Params {
+                allowed_clients: HI::light_clients()
+                    .client_types()
+                    .map(ToString::to_string)
+                    .collect(),
+            }),
+        };
+
+        Ok(tonic::Response::new(resp))
     }
     /// UpgradedClientState queries an Upgraded IBC light client.
     async fn upgraded_client_state(
         &self,
         _request: tonic::Request<QueryUpgradedClientStateRequest>,
     ) -> std::result::Result<tonic::Response<QueryUpgradedClientStateResponse>, tonic::Status> {
-        Err(tonic::Status::unimplemented("not implemented"))
+        // penumbra doesn't schedule upgraded client states for counterparties.
+        Err(tonic::Status::not_found(
+            "no upgraded client state is scheduled",
+        ))
     }
     /// UpgradedConsensusState queries an Upgraded IBC consensus state.
     async fn upgraded_consensus_state(
@@ -281,7 +295,9 @@ impl<HI: HostInterface + Send + Sync + 'static> ClientQuery for IbcQuery<HI> {
         _request: tonic::Request<QueryUpgradedConsensusStateRequest>,
     ) -> std::result::Result<tonic::Response<QueryUpgradedConsensusStateResponse>, tonic::Status>
     {
-        Err(tonic::Status::unimplemented("not implemented"))
+        Err(tonic::Status::not_found(
+            "no upgraded consensus state is scheduled",
+        ))
     }
 }
 
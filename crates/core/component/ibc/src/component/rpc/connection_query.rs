@@ -22,6 +22,7 @@ use std::str::FromStr;
 use crate::component::rpc::utils::determine_snapshot_from_metadata;
 use crate::component::{ConnectionStateReadExt, HostInterface};
 use crate::prefix::MerklePrefixExt;
+use crate::StateReadExt as _;
 use crate::IBC_COMMITMENT_PREFIX;
 
 use super::IbcQuery;
@@ -91,16 +92,37 @@ impl<HI: HostInterface + Send + Sync + 'static> ConnectionQuery for IbcQuery<HI>
         tonic::Response<ibc_proto::ibc::core::connection::v1::QueryConnectionParamsResponse>,
         tonic::Status,
     > {
-        Err(tonic::Status::unimplemented("not implemented"))
+        let snapshot = self.storage.latest_snapshot();
+        let ibc_params = snapshot
+            .get_ibc_params()
+            .await
+            .map_err(|e| tonic::Status::aborted(format!("couldn't get IBC parameters: {e}")))?;
+
+        let resp = ibc_proto::ibc::core::connection::v1::QueryConnectionParamsResponse {
+            params: Some(ibc_proto::ibc::core::connection::v1::Params {
+                max_expected_time_per_block: ibc_params
+                    .max_expected_time_per_block
+                    .as_nanos()
+                    .try_into()
+                    .unwrap_or(u64::MAX),
+            }),
+        };
+
+        Ok(tonic::Response::new(resp))
     }
 
     /// Connections queries all the IBC connections of a chain.
     #[tracing::instrument(skip(self), err, level = "debug")]
     async fn connections(
         &self,
-        _request: tonic::Request<QueryConnectionsRequest>,
+        request: tonic::Request<QueryConnectionsRequest>,
     ) -> std::result::Result<tonic::Response<QueryConnectionsResponse>, tonic::Status> {
-        let snapshot = self.storage.latest_snapshot();
+        let snapshot = match determine_snapshot_from_metadata(self.storage.clone(), request.metadata()) {
+            Err(err) => return Err(tonic::Status::aborted(
+                format!("could not determine the correct snapshot to open given the `\"height\"` header of the request: {err:#}")
+            )),
+            Ok(snapshot) => snapshot,
+        };
         let height = HI::get_block_height(&snapshot)
             .await
             .map_err(|e| tonic::Status::aborted(format!("couldn't decode height: {e}")))?
@@ -132,7 +154,9 @@ impl<HI: HostInterface + Send + Sync + 'static> ConnectionQuery for IbcQuery<HI>
         }
 
         let height = Height {
-            revision_number: 0,
+            revision_number: HI::get_revision_number(&snapshot)
+                .await
+                .map_err(|e| tonic::Status::aborted(format!("couldn't decode height: {e}")))?,
             revision_height: height,
         };
 
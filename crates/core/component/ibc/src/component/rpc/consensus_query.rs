@@ -22,7 +22,7 @@ use ibc_types::path::{
 };
 use ibc_types::DomainType;
 
-use ibc_types::core::channel::{ChannelId, IdentifiedChannelEnd, PortId};
+use ibc_types::core::channel::{ChannelId, PortId};
 
 use ibc_types::core::connection::ConnectionId;
 use prost::Message;
@@ -31,7 +31,7 @@ use std::str::FromStr;
 
 use crate::component::{ChannelStateReadExt, ConnectionStateReadExt, HostInterface};
 
-use super::utils::determine_snapshot_from_metadata;
+use super::utils::{all_channels, determine_snapshot_from_metadata};
 use super::IbcQuery;
 
 #[async_trait]
@@ -94,9 +94,14 @@ impl<HI: HostInterface + Send + Sync + 'static> ConsensusQuery for IbcQuery<HI>
     #[tracing::instrument(skip(self), err, level = "debug")]
     async fn channels(
         &self,
-        _request: tonic::Request<QueryChannelsRequest>,
+        request: tonic::Request<QueryChannelsRequest>,
     ) -> std::result::Result<tonic::Response<QueryChannelsResponse>, tonic::Status> {
-        let snapshot = self.storage.latest_snapshot();
+        let snapshot = match determine_snapshot_from_metadata(self.storage.clone(), request.metadata()) {
+            Err(err) => return Err(tonic::Status::aborted(
+                format!("could not determine the correct snapshot to open given the `\"height\"` header of the request: {err:#}")
+            )),
+            Ok(snapshot) => snapshot,
+        };
 
         let height = Height {
             revision_number: HI::get_revision_number(&snapshot)
@@ -107,32 +112,12 @@ impl<HI: HostInterface + Send + Sync + 'static> ConsensusQuery for IbcQuery<HI>
                 .map_err(|e| tonic::Status::aborted(format!("couldn't decode height: {e}")))?,
         };
 
-        let channel_counter = snapshot
-            .get_channel_counter()
+        let channels = all_channels(&snapshot)
             .await
-            .map_err(|e| tonic::Status::aborted(format!("couldn't get channel counter: {e}")))?;
-
-        let mut channels = vec![];
-        for chan_idx in 0..channel_counter {
-            let chan_id = ChannelId(format!("channel-{}", chan_idx));
-            let channel = snapshot
-                .get_channel(&chan_id, &PortId::transfer())
-                .await
-                .map_err(|e| {
-                    tonic::Status::aborted(format!("couldn't get channel {chan_id}: {e}"))
-                })?
-                .ok_or("unable to get channel")
-                .map_err(|e| {
-                    tonic::Status::aborted(format!("couldn't get channel {chan_id}: {e}"))
-                })?;
-
-            let id_chan = IdentifiedChannelEnd {
-                channel_id: chan_id,
-                port_id: PortId::transfer(),
-                channel_end: channel,
-            };
-            channels.push(id_chan.into());
-        }
+            .map_err(|e| tonic::Status::aborted(format!("couldn't get channels: {e}")))?
+            .into_iter()
+            .map(Into::into)
+            .collect();
 
         let res = QueryChannelsResponse {
             channels,
@@ -149,7 +134,12 @@ impl<HI: HostInterface + Send + Sync + 'static> ConsensusQuery for IbcQuery<HI>
         &self,
         request: tonic::Request<QueryConnectionChannelsRequest>,
     ) -> std::result::Result<tonic::Response<QueryConnectionChannelsResponse>, tonic::Status> {
-        let snapshot = self.storage.latest_snapshot();
+        let snapshot = match determine_snapshot_from_metadata(self.storage.clone(), request.metadata()) {
+            Err(err) => return Err(tonic::Status::aborted(
+                format!("could not determine the correct snapshot to open given the `\"height\"` header of the request: {err:#}")
+            )),
+            Ok(snapshot) => snapshot,
+        };
         let height = Height {
             revision_number: HI::get_revision_number(&snapshot)
                 .await
@@ -164,33 +154,13 @@ impl<HI: HostInterface + Send + Sync + 'static> ConsensusQuery for IbcQuery<HI>
             .map_err(|e| tonic::Status::aborted(format!("invalid connection id: {e}")))?;
 
         // look up all of the channels for this connection
-        let channel_counter = snapshot
-            .get_channel_counter()
+        let channels = all_channels(&snapshot)
             .await
-            .map_err(|e| tonic::Status::aborted(format!("couldn't get channel counter: {e}")))?;
-
-        let mut channels = vec![];
-        for chan_idx in 0..channel_counter {
-            let chan_id = ChannelId(format!("channel-{}", chan_idx));
-            let channel = snapshot
-                .get_channel(&chan_id, &PortId::transfer())
-                .await
-                .map_err(|e| {
-                    tonic::Status::aborted(format!("couldn't get channel {chan_id}: {e}"))
-                })?
-                .ok_or("unable to get channel")
-                .map_err(|e| {
-                    tonic::Status::aborted(format!("couldn't get channel {chan_id}: {e}"))
-                })?;
-            if channel.connection_hops.contains(&connection_id) {
-                let id_chan = IdentifiedChannelEnd {
-                    channel_id: chan_id,
-                    port_id: PortId::transfer(),
-                    channel_end: channel,
-                };
-                channels.push(id_chan.into());
-            }
-        }
+            .map_err(|e| tonic::Status::aborted(format!("couldn't get channels: {e}")))?
+            .into_iter()
+            .filter(|channel| channel.channel_end.connection_hops.contains(&connection_id))
+            .map(Into::into)
+            .collect();
 
         let res = QueryConnectionChannelsResponse {
             channels,
@@ -449,7 +419,12 @@ impl<HI: HostInterface + Send + Sync + 'static> ConsensusQuery for IbcQuery<HI>
         &self,
         request: tonic::Request<QueryPacketCommitmentsRequest>,
     ) -> std::result::Result<tonic::Response<QueryPacketCommitmentsResponse>, tonic::Status> {
-        let snapshot = self.storage.latest_snapshot();
+        let snapshot = match determine_snapshot_from_metadata(self.storage.clone(), request.metadata()) {
+            Err(err) => return Err(tonic::Status::aborted(
+                format!("could not determine the correct snapshot to open given the `\"height\"` header of the request: {err:#}")
+            )),
+            Ok(snapshot) => snapshot,
+        };
         let height = snapshot.version();
         let request = request.get_ref();
 
@@ -493,7 +468,9 @@ impl<HI: HostInterface + Send + Sync + 'static> ConsensusQuery for IbcQuery<HI>
         }
 
         let height = Height {
-            revision_number: 0,
+            revision_number: HI::get_revision_number(&snapshot)
+                .await
+                .map_err(|e| tonic::Status::aborted(format!("couldn't decode height: {e}")))?,
             revision_height: height,
         };
 
@@ -610,9 +587,16 @@ impl<HI: HostInterface + Send + Sync + 'static> ConsensusQuery for IbcQuery<HI>
         request: tonic::Request<QueryPacketAcknowledgementsRequest>,
     ) -> std::result::Result<tonic::Response<QueryPacketAcknowledgementsResponse>, tonic::Status>
     {
-        let snapshot = self.storage.latest_snapshot();
+        let snapshot = match determine_snapshot_from_metadata(self.storage.clone(), request.metadata()) {
+            Err(err) => return Err(tonic::Status::aborted(
+                format!("could not determine the correct snapshot to open given the `\"height\"` header of the request: {err:#}")
+            )),
+            Ok(snapshot) => snapshot,
+        };
         let height = Height {
-            revision_number: 0,
+            revision_number: HI::get_revision_number(&snapshot)
+                .await
+                .map_err(|e| tonic::Status::aborted(format!("couldn't decode height: {e}")))?,
             revision_height: snapshot.version(),
         };
         let request = request.get_ref();
@@ -670,7 +654,12 @@ impl<HI: HostInterface + Send + Sync + 'static> ConsensusQuery for IbcQuery<HI>
         &self,
         request: tonic::Request<QueryUnreceivedPacketsRequest>,
     ) -> std::result::Result<tonic::Response<QueryUnreceivedPacketsResponse>, tonic::Status> {
-        let snapshot = self.storage.latest_snapshot();
+        let snapshot = match determine_snapshot_from_metadata(self.storage.clone(), request.metadata()) {
+            Err(err) => return Err(tonic::Status::aborted(
+                format!("could not determine the correct snapshot to open given the `\"height\"` header of the request: {err:#}")
+            )),
+            Ok(snapshot) => snapshot,
+        };
         let height = snapshot.version();
         let request = request.get_ref();
 
@@ -702,7 +691,9 @@ impl<HI: HostInterface + Send + Sync + 'static> ConsensusQuery for IbcQuery<HI>
         }
 
         let height = Height {
-            revision_number: 0,
+            revision_number: HI::get_revision_number(&snapshot)
+                .await
+                .map_err(|e| tonic::Status::aborted(format!("couldn't decode height: {e}")))?,
             revision_height: height,
         };
 
@@ -720,9 +711,16 @@ impl<HI: HostInterface + Send + Sync + 'static> ConsensusQuery for IbcQuery<HI>
         &self,
         request: tonic::Request<QueryUnreceivedAcksRequest>,
     ) -> std::result::Result<tonic::Response<QueryUnreceivedAcksResponse>, tonic::Status> {
-        let snapshot = self.storage.latest_snapshot();
+        let snapshot = match determine_snapshot_from_metadata(self.storage.clone(), request.metadata()) {
+            Err(err) => return Err(tonic::Status::aborted(
+                format!("could not determine the correct snapshot to open given the `\"height\"` header of the request: {err:#}")
+            )),
+            Ok(snapshot) => snapshot,
+        };
         let height = Height {
-            revision_number: 0,
+            revision_number: HI::get_revision_number(&snapshot)
+                .await
+                .map_err(|e| tonic::Status::aborted(format!("couldn't decode height: {e}")))?,
             revision_height: snapshot.version(),
         };
         let request = request.get_ref();
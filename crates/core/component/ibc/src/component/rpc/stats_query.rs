@@ -0,0 +1,157 @@
+use async_trait::async_trait;
+use ibc_proto::ibc::core::{
+    channel::v1::MsgRecvPacket as RawMsgRecvPacket,
+    client::v1::MsgUpdateClient as RawMsgUpdateClient,
+};
+use ibc_types::core::{
+    channel::{msgs::MsgRecvPacket, ChannelId, PortId},
+    client::msgs::MsgUpdateClient,
+    client::ClientId,
+    connection::ConnectionId,
+};
+use ibc_types::DomainType as IbcTypesDomainType;
+use penumbra_proto::core::component::ibc::v1::{self as pb, query_service_server::QueryService};
+use penumbra_proto::Name;
+use std::str::FromStr;
+use tonic::{Response, Status};
+
+use crate::component::{
+    fees::StateReadExt as _, ConnectionStateReadExt as _, HostInterface, RelaySimulationExt,
+    SimulationResult, StateReadExt,
+};
+use crate::connection_metadata::IdentifiedConnectionMetadata;
+
+use super::IbcQuery;
+
+#[async_trait]
+impl<HI: HostInterface + Send + Sync + 'static> QueryService for IbcQuery<HI> {
+    /// Returns the proof verification statistics recorded for a client.
+    async fn client_verification_stats(
+        &self,
+        request: tonic::Request<pb::ClientVerificationStatsRequest>,
+    ) -> std::result::Result<Response<pb::ClientVerificationStatsResponse>, Status> {
+        let snapshot = self.storage.latest_snapshot();
+
+        let client_id = ClientId::from_str(&request.get_ref().client_id)
+            .map_err(|e| tonic::Status::invalid_argument(format!("invalid client id: {e}")))?;
+
+        let stats = snapshot
+            .get_client_verification_stats(&client_id)
+            .await
+            .map_err(|e| tonic::Status::aborted(format!("couldn't get verification stats: {e}")))?;
+
+        Ok(tonic::Response::new(pb::ClientVerificationStatsResponse {
+            stats: Some(stats.into()),
+        }))
+    }
+
+    /// Simulates a client update followed by a packet receipt against the
+    /// latest state, without committing either.
+    async fn simulate_update_and_recv(
+        &self,
+        request: tonic::Request<pb::SimulateUpdateAndRecvRequest>,
+    ) -> std::result::Result<Response<pb::SimulateUpdateAndRecvResponse>, Status> {
+        let snapshot = self.storage.latest_snapshot();
+        let request = request.into_inner();
+
+        let update_client = request
+            .update_client
+            .ok_or_else(|| tonic::Status::invalid_argument("missing update_client"))?;
+        if update_client.type_url != RawMsgUpdateClient::type_url() {
+            return Err(tonic::Status::invalid_argument(format!(
+                "expected a MsgUpdateClient, got {}",
+                update_client.type_url
+            )));
+        }
+        let update_msg = MsgUpdateClient::decode(update_client.value).map_err(|e| {
+            tonic::Status::invalid_argument(format!("invalid MsgUpdateClient: {e}"))
+        })?;
+
+        let recv_packet = request
+            .recv_packet
+            .ok_or_else(|| tonic::Status::invalid_argument("missing recv_packet"))?;
+        if recv_packet.type_url != RawMsgRecvPacket::type_url() {
+            return Err(tonic::Status::invalid_argument(format!(
+                "expected a MsgRecvPacket, got {}",
+                recv_packet.type_url
+            )));
+        }
+        let recv_msg = MsgRecvPacket::decode(recv_packet.value)
+            .map_err(|e| tonic::Status::invalid_argument(format!("invalid MsgRecvPacket: {e}")))?;
+
+        let result = snapshot
+            .simulate_update_and_recv::<HI>(&update_msg, &recv_msg)
+            .await;
+
+        let error = match &result {
+            SimulationResult::Verified => String::new(),
+            SimulationResult::UpdateFailed(e) | SimulationResult::RecvFailed(e) => e.clone(),
+        };
+
+        Ok(tonic::Response::new(pb::SimulateUpdateAndRecvResponse {
+            update_applied: result.update_applied(),
+            packet_verified: result.packet_verified(),
+            error,
+        }))
+    }
+
+    /// Returns the fees escrowed for the unrelayed packets sent over a channel.
+    async fn incentivized_packets(
+        &self,
+        request: tonic::Request<pb::IncentivizedPacketsRequest>,
+    ) -> std::result::Result<Response<pb::IncentivizedPacketsResponse>, Status> {
+        let snapshot = self.storage.latest_snapshot();
+        let request = request.into_inner();
+
+        let port_id = PortId::from_str(&request.port_id)
+            .map_err(|e| tonic::Status::invalid_argument(format!("invalid port id: {e}")))?;
+        let channel_id = ChannelId::from_str(&request.channel_id)
+            .map_err(|e| tonic::Status::invalid_argument(format!("invalid channel id: {e}")))?;
+
+        let incentivized_packets = snapshot
+            .incentivized_packets(&port_id, &channel_id)
+            .await
+            .map_err(|e| {
+                tonic::Status::aborted(format!("couldn't get incentivized packets: {e}"))
+            })?;
+
+        Ok(tonic::Response::new(pb::IncentivizedPacketsResponse {
+            incentivized_packets: incentivized_packets.into_iter().map(Into::into).collect(),
+        }))
+    }
+    /// Returns the counterparty chain information registered for a connection,
+    /// or for every connection that has any.
+    async fn connection_metadata(
+        &self,
+        request: tonic::Request<pb::ConnectionMetadataRequest>,
+    ) -> std::result::Result<Response<pb::ConnectionMetadataResponse>, Status> {
+        let snapshot = self.storage.latest_snapshot();
+        let request = request.into_inner();
+
+        let connection_metadata = if request.connection_id.is_empty() {
+            snapshot.connection_metadata().await.map_err(|e| {
+                tonic::Status::aborted(format!("couldn't get connection metadata: {e}"))
+            })?
+        } else {
+            let connection_id = ConnectionId::from_str(&request.connection_id).map_err(|e| {
+                tonic::Status::invalid_argument(format!("invalid connection id: {e}"))
+            })?;
+            snapshot
+                .get_connection_metadata(&connection_id)
+                .await
+                .map_err(|e| {
+                    tonic::Status::aborted(format!("couldn't get connection metadata: {e}"))
+                })?
+                .map(|metadata| IdentifiedConnectionMetadata {
+                    connection_id,
+                    metadata,
+                })
+                .into_iter()
+                .collect()
+        };
+
+        Ok(tonic::Response::new(pb::ConnectionMetadataResponse {
+            connection_metadata: connection_metadata.into_iter().map(Into::into).collect(),
+        }))
+    }
+}
@@ -4,10 +4,22 @@ use anyhow::bail;
 use anyhow::Context as _;
 use cnidarium::Snapshot;
 use cnidarium::Storage;
-use ibc_proto::ibc::core::client::v1::Height;
+use futures::{StreamExt as _, TryStreamExt as _};
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::client::v1::{Height, IdentifiedClientState};
+use ibc_types::core::channel::{ChannelEnd, ChannelId, IdentifiedChannelEnd, PortId};
+use ibc_types::core::client::{ClientId, ClientType};
+use ibc_types::path::ClientStatePath;
+use penumbra_proto::StateReadProto as _;
+use prost::Message as _;
 use tracing::debug;
 use tracing::instrument;
 
+use crate::component::client::StateReadExt as _;
+use crate::component::LightClientRegistry;
+use crate::prefix::MerklePrefixExt as _;
+use crate::IBC_COMMITMENT_PREFIX;
+
 type Type = tonic::metadata::MetadataMap;
 
 /// Determines which state snapshot to open given the height header in a [`MetadataMap`].
@@ -93,6 +105,73 @@ impl FromStr for TheHeight {
     }
 }
 
+/// Lists the channel ends on every port.
+pub(in crate::component::rpc) async fn all_channels(
+    snapshot: &Snapshot,
+) -> anyhow::Result<Vec<IdentifiedChannelEnd>> {
+    let prefix = IBC_COMMITMENT_PREFIX.apply_string("channelEnds/".to_string());
+    snapshot
+        .prefix::<ChannelEnd>(&prefix)
+        .map(|entry| {
+            let (key, channel_end) = entry?;
+            let (port_id, channel_id) = parse_channel_end_key(&key[prefix.len()..])?;
+            Ok(IdentifiedChannelEnd {
+                port_id,
+                channel_id,
+                channel_end,
+            })
+        })
+        .try_collect()
+        .await
+}
+
+// parses the `ports/{port_id}/channels/{channel_id}` suffix of a channel end's key.
+fn parse_channel_end_key(key: &str) -> anyhow::Result<(PortId, ChannelId)> {
+    let (port_id, channel_id) = key
+        .strip_prefix("ports/")
+        .and_then(|key| key.split_once("/channels/"))
+        .with_context(|| format!("invalid channel end key {key}"))?;
+    Ok((
+        PortId(port_id.to_string()),
+        ChannelId(channel_id.to_string()),
+    ))
+}
+
+/// Lists the client states of every client, of any type with a registered
+/// light client.
+pub(in crate::component::rpc) async fn all_client_states(
+    snapshot: &Snapshot,
+    light_clients: &LightClientRegistry,
+) -> anyhow::Result<Vec<IdentifiedClientState>> {
+    let client_counter = snapshot.client_counter().await?.0;
+
+    let mut client_states = vec![];
+    // client identifiers are numbered across all client types, so each number
+    // belongs to a client of exactly one type.
+    for client_idx in 0..client_counter {
+        for client_type in light_clients.client_types() {
+            let client_id = ClientId::new(ClientType::new(client_type.to_string()), client_idx)?;
+            let Some(client_state) = snapshot
+                .get_raw(
+                    &IBC_COMMITMENT_PREFIX
+                        .apply_string(ClientStatePath(client_id.clone()).to_string()),
+                )
+                .await?
+            else {
+                continue;
+            };
+
+            client_states.push(IdentifiedClientState {
+                client_id: client_id.to_string(),
+                client_state: Some(Any::decode(client_state.as_slice())?),
+            });
+            break;
+        }
+    }
+
+    Ok(client_states)
+}
+
 fn parse_as_ibc_height(input: &str) -> anyhow::Result<Height> {
     let height = input
         .trim()
@@ -110,7 +189,7 @@ mod tests {
 
     use crate::component::rpc::utils::determine_height_from_metadata;
 
-    use super::TheHeight;
+    use super::{parse_channel_end_key, TheHeight};
 
     fn zero() -> Height {
         Height {
@@ -160,4 +239,13 @@ mod tests {
         assert_ibc_height_is_determined_correctly(Some("1-0"), height(1, 0));
         assert_ibc_height_is_determined_correctly(Some("1-1"), height(1, 1));
     }
+
+    #[test]
+    fn parse_channel_end_keys() {
+        let (port_id, channel_id) =
+            parse_channel_end_key("ports/icahost/channels/channel-3").unwrap();
+        assert_eq!(port_id.as_str(), "icahost");
+        assert_eq!(channel_id.as_str(), "channel-3");
+        assert!(parse_channel_end_key("ports/transfer").is_err());
+    }
 }
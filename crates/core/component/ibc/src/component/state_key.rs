@@ -1,4 +1,7 @@
-use ibc_types::{core::client::ClientId, core::client::Height};
+use ibc_types::core::{
+    channel::{ChannelId, PortId},
+    client::{ClientId, Height},
+};
 
 use std::string::String;
 
@@ -17,6 +20,28 @@ pub fn client_processed_times(client_id: &ClientId, height: &Height) -> String {
 pub fn counter() -> &'static str {
     "ibc/connection_counter"
 }
+pub fn pending_ack_sentinel(channel_id: &ChannelId, port_id: &PortId) -> String {
+    format!("ibc/channels/{port_id}/{channel_id}/pendingAckSentinel")
+}
+pub fn client_verification_stats(client_id: &ClientId) -> String {
+    format!("ibc/clients/{client_id}/verificationStats")
+}
+pub fn client_proof_specs(client_id: &ClientId) -> String {
+    format!("ibc/clients/{client_id}/proofSpecs")
+}
+
+pub mod connection_metadata {
+    use ibc_types::core::connection::ConnectionId;
+
+    pub fn prefix() -> &'static str {
+        "ibc/connection_metadata/"
+    }
+
+    /// The counterparty chain information registered for a connection.
+    pub fn by_connection_id(connection_id: &ConnectionId) -> String {
+        format!("ibc/connection_metadata/{connection_id}")
+    }
+}
 
 pub mod ics20_value_balance {
     use ibc_types::core::channel::ChannelId;
@@ -30,3 +55,105 @@ pub mod ics20_value_balance {
         format!("ibc/ics20-value-balance/{channel_id}/{asset_id}")
     }
 }
+
+pub mod ics20_rate_limit {
+    use ibc_types::core::channel::ChannelId;
+    use penumbra_asset::asset;
+
+    /// The flow of `asset_id` over `channel_id` in the current rate limit window.
+    pub fn flow(channel_id: &ChannelId, asset_id: &asset::Id) -> String {
+        format!("ibc/ics20-rate-limit/flow/{channel_id}/{asset_id}")
+    }
+}
+
+pub mod ics20_forward {
+    use ibc_types::core::channel::ChannelId;
+
+    /// A transfer forwarded over `channel_id` that hasn't been acknowledged yet.
+    pub fn in_flight(channel_id: &ChannelId, sequence: u64) -> String {
+        format!("ibc/ics20-forwards/{channel_id}/{sequence}")
+    }
+}
+
+pub mod ack_pruning {
+    pub fn queue_prefix() -> &'static str {
+        "ibc/ack_pruning/queue/"
+    }
+
+    // Heights are zero-padded so that the queue is listed in the order the
+    // acknowledgements were written.
+    pub fn queued(height: u64, index: u64) -> String {
+        format!("ibc/ack_pruning/queue/{height:020}/{index:010}")
+    }
+
+    /// Object store key for the acknowledgements written in the current block.
+    pub fn pending() -> &'static str {
+        "ibc/ack_pruning/pending"
+    }
+}
+
+pub mod verification_stats {
+    /// Object store key for the recorder of the verification outcomes of the current block.
+    pub fn recorder() -> &'static str {
+        "ibc/verification_stats/recorder"
+    }
+
+    /// Object store key marking a state fork as a simulation, whose
+    /// verifications are not recorded.
+    pub fn simulating() -> &'static str {
+        "ibc/verification_stats/simulating"
+    }
+}
+
+pub mod metrics {
+    /// Object store key for the metric counts accumulated in the current block.
+    pub fn pending_counts() -> &'static str {
+        "ibc/metrics/pending_counts"
+    }
+}
+
+pub mod ics721 {
+    use ibc_types::core::channel::ChannelId;
+
+    pub fn class(class_id: &str) -> String {
+        format!("ibc/ics721/classes/{class_id}")
+    }
+
+    pub fn token(class_id: &str, token_id: &str) -> String {
+        format!("ibc/ics721/tokens/{class_id}/{token_id}")
+    }
+
+    pub fn escrow(channel_id: &ChannelId, class_id: &str, token_id: &str) -> String {
+        format!("ibc/ics721/escrow/{channel_id}/{class_id}/{token_id}")
+    }
+}
+
+pub mod ica {
+    use ibc_types::core::{channel::PortId, connection::ConnectionId};
+
+    pub fn account(connection_id: &ConnectionId, controller_port_id: &PortId) -> String {
+        format!("ibc/ica/accounts/{connection_id}/{controller_port_id}")
+    }
+
+    pub fn active_channel(connection_id: &ConnectionId, controller_port_id: &PortId) -> String {
+        format!("ibc/ica/activeChannels/{connection_id}/{controller_port_id}")
+    }
+}
+
+pub mod fees {
+    use ibc_types::core::channel::{ChannelId, PortId};
+
+    pub fn escrow_prefix(port_id: &PortId, channel_id: &ChannelId) -> String {
+        format!("ibc/fees/{port_id}/{channel_id}/escrow/")
+    }
+
+    // Sequences are zero-padded so that escrows are listed in packet order.
+    pub fn escrow(port_id: &PortId, channel_id: &ChannelId, sequence: u64) -> String {
+        format!("ibc/fees/{port_id}/{channel_id}/escrow/{sequence:020}")
+    }
+
+    /// Object store key for the relayer of the packet currently being received.
+    pub fn forward_relayer() -> &'static str {
+        "ibc/fees/forward_relayer"
+    }
+}
@@ -0,0 +1,107 @@
+//! Per-client proof verification statistics.
+//!
+//! Outcomes are accumulated in a per-block [`Recorder`], which
+//! [`Ibc::begin_block`](super::Ibc::begin_block) places in the object store
+//! of the block's state and [`Ibc::end_block`](super::Ibc::end_block) flushes
+//! to the nonverifiable store. Every fork of the block's state shares the
+//! recorder, so the outcomes of transactions that fail are kept even though
+//! their state changes are discarded: a failed verification fails its
+//! transaction, and those failures are what the statistics are for.
+//!
+//! Verifications performed outside of a block, such as those of transactions
+//! checked for the mempool, have no recorder to record into. Verifications
+//! in forks marked with [`mark_simulation`] are not recorded at all, not even
+//! in the duration metrics. The statistics are not part of consensus.
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use cnidarium::{StateRead, StateWrite};
+use ibc_types::core::client::ClientId;
+
+use crate::verification_stats::{ClientVerificationStats, VerificationKind};
+
+use super::metrics;
+use super::state_key;
+use super::view::{StateReadExt as _, StateWriteExt as _};
+
+/// The verification outcomes recorded in the current block, shared by every
+/// fork of the block's state.
+#[derive(Clone, Default)]
+struct Recorder(Arc<Mutex<BTreeMap<ClientId, ClientVerificationStats>>>);
+
+/// Starts recording the verifications performed in `state` and its forks,
+/// until the next [`flush`].
+pub(crate) fn begin_block<S: StateWrite + ?Sized>(state: &mut S) {
+    state.object_put(
+        state_key::verification_stats::recorder(),
+        Recorder::default(),
+    );
+}
+
+/// Marks `state` as a simulation, so that verifications in it are not recorded.
+pub(crate) fn mark_simulation<S: StateWrite + ?Sized>(state: &mut S) {
+    state.object_put(state_key::verification_stats::simulating(), ());
+}
+
+/// Records the outcome of a verification against `client_id`, and how long
+/// it took since `started`.
+pub(crate) fn record<S: StateRead + ?Sized, T>(
+    state: &S,
+    client_id: &ClientId,
+    kind: VerificationKind,
+    started: Instant,
+    result: &anyhow::Result<T>,
+) {
+    if state
+        .object_get::<()>(state_key::verification_stats::simulating())
+        .is_some()
+    {
+        return;
+    }
+
+    metrics::histogram!(
+        metrics::IBC_PROOF_VERIFICATION_DURATION,
+        "kind" => metrics::verification_kind_label(kind),
+        "succeeded" => if result.is_ok() { "true" } else { "false" }
+    )
+    .record(started.elapsed());
+
+    let Some(recorder) = state.object_get::<Recorder>(state_key::verification_stats::recorder())
+    else {
+        return;
+    };
+    let mut pending = recorder.0.lock().expect("recorder lock is not poisoned");
+    let counts = pending
+        .entry(client_id.clone())
+        .or_default()
+        .counts_mut(kind);
+    if result.is_ok() {
+        counts.succeeded += 1;
+    } else {
+        counts.failed += 1;
+    }
+}
+
+/// Adds the outcomes recorded in the current block to the stored statistics.
+pub(crate) async fn flush<S: StateWrite + ?Sized>(state: &mut S) {
+    let Some(recorder) = state.object_get::<Recorder>(state_key::verification_stats::recorder())
+    else {
+        return;
+    };
+    state.object_delete(state_key::verification_stats::recorder());
+    let pending = std::mem::take(&mut *recorder.0.lock().expect("recorder lock is not poisoned"));
+
+    for (client_id, delta) in pending {
+        let mut stats = match state.get_client_verification_stats(&client_id).await {
+            Ok(stats) => stats,
+            Err(e) => {
+                tracing::warn!(%client_id, ?e, "could not read verification stats, resetting them");
+                ClientVerificationStats::default()
+            }
+        };
+        stats.merge(&delta);
+        state.put_client_verification_stats(&client_id, stats);
+    }
+}
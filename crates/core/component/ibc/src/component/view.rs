@@ -1,9 +1,11 @@
 use anyhow::Result;
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
+use ibc_types::core::client::ClientId;
 use penumbra_proto::{StateReadProto, StateWriteProto};
 
 use crate::params::IBCParameters;
+use crate::verification_stats::ClientVerificationStats;
 
 use super::state_key;
 
@@ -13,6 +15,18 @@ pub trait StateWriteExt: StateWrite {
     fn put_ibc_params(&mut self, params: IBCParameters) {
         self.put(state_key::ibc_params().into(), params)
     }
+
+    /// Writes the verification statistics for a client to the nonverifiable store.
+    fn put_client_verification_stats(
+        &mut self,
+        client_id: &ClientId,
+        stats: ClientVerificationStats,
+    ) {
+        self.nonverifiable_put(
+            state_key::client_verification_stats(client_id).into_bytes(),
+            stats,
+        )
+    }
 }
 
 impl<T: StateWrite + ?Sized> StateWriteExt for T {}
@@ -25,6 +39,19 @@ pub trait StateReadExt: StateRead {
             .await?
             .ok_or_else(|| anyhow::anyhow!("Missing IBCParameters"))
     }
+
+    /// Gets the verification statistics recorded for a client, as of the end
+    /// of the last block. Clients with no recorded verifications have all
+    /// counts set to zero.
+    async fn get_client_verification_stats(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<ClientVerificationStats> {
+        Ok(self
+            .nonverifiable_get(state_key::client_verification_stats(client_id).as_bytes())
+            .await?
+            .unwrap_or_default())
+    }
 }
 
 impl<T: StateRead + ?Sized> StateReadExt for T {}
@@ -0,0 +1,185 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use ibc_types::core::{channel::ChannelId, commitment::MerklePrefix, connection::ConnectionId};
+use penumbra_proto::core::component::ibc::v1 as pb;
+use penumbra_proto::DomainType;
+use serde::{Deserialize, Serialize};
+
+use crate::CounterpartyPrefix;
+
+/// The maximum length, in bytes, of a chain id or display name.
+const MAX_NAME_LEN: usize = 128;
+
+/// The maximum number of preferred channels registered for a connection.
+const MAX_PREFERRED_CHANNELS: usize = 16;
+
+/// The maximum number of keys in a counterparty prefix path.
+const MAX_PREFIX_PATH_LEN: usize = 8;
+
+/// Information about the chain at the other end of a connection, registered by
+/// governance so that wallets can label channels with the chain they lead to,
+/// and so that proofs relayed over the connection can be held to a stricter
+/// freshness policy.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::ConnectionMetadata", into = "pb::ConnectionMetadata")]
+pub struct ConnectionMetadata {
+    /// The chain id of the counterparty chain, e.g. `osmosis-1`.
+    pub counterparty_chain_id: String,
+    /// The name to display for the counterparty chain.
+    pub display_name: String,
+    /// The channels over the connection, as named on this chain, that wallets
+    /// should prefer for transfers to the counterparty chain.
+    pub preferred_channels: Vec<ChannelId>,
+    /// The maximum age of a consensus state that proofs relayed over the
+    /// connection may be verified against, on top of the client's trusting
+    /// period. `None` means only the trusting period applies.
+    pub max_consensus_age: Option<Duration>,
+    /// The keys, from the outermost store inwards, under which the counterparty
+    /// chain commits its IBC state. Empty means that the counterparty
+    /// commitment prefix of the connection is applied as a single key.
+    pub counterparty_prefix_path: Vec<Vec<u8>>,
+}
+
+impl ConnectionMetadata {
+    /// The prefix that proofs relayed over the connection are verified
+    /// against, given the counterparty commitment prefix of the connection.
+    pub fn counterparty_prefix(&self, commitment_prefix: &MerklePrefix) -> CounterpartyPrefix {
+        if self.counterparty_prefix_path.is_empty() {
+            CounterpartyPrefix::Single(commitment_prefix.clone())
+        } else {
+            CounterpartyPrefix::KeyPath(self.counterparty_prefix_path.clone())
+        }
+    }
+}
+
+impl DomainType for ConnectionMetadata {
+    type Proto = pb::ConnectionMetadata;
+}
+
+impl TryFrom<pb::ConnectionMetadata> for ConnectionMetadata {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::ConnectionMetadata) -> anyhow::Result<Self> {
+        if msg.counterparty_chain_id.is_empty() {
+            anyhow::bail!("missing counterparty chain id");
+        }
+        if msg.counterparty_chain_id.len() > MAX_NAME_LEN {
+            anyhow::bail!("counterparty chain id must be at most {MAX_NAME_LEN} bytes");
+        }
+        if msg.display_name.len() > MAX_NAME_LEN {
+            anyhow::bail!("display name must be at most {MAX_NAME_LEN} bytes");
+        }
+        if msg.preferred_channels.len() > MAX_PREFERRED_CHANNELS {
+            anyhow::bail!("at most {MAX_PREFERRED_CHANNELS} preferred channels may be registered");
+        }
+        if msg.counterparty_prefix_path.len() > MAX_PREFIX_PATH_LEN {
+            anyhow::bail!("counterparty prefix path must have at most {MAX_PREFIX_PATH_LEN} keys");
+        }
+        for key in &msg.counterparty_prefix_path {
+            if key.is_empty() || key.len() > MAX_NAME_LEN {
+                anyhow::bail!("counterparty prefix keys must be 1 to {MAX_NAME_LEN} bytes");
+            }
+            if std::str::from_utf8(key).is_err() {
+                anyhow::bail!("counterparty prefix keys must be valid UTF-8");
+            }
+        }
+
+        Ok(ConnectionMetadata {
+            counterparty_chain_id: msg.counterparty_chain_id,
+            display_name: msg.display_name,
+            preferred_channels: msg
+                .preferred_channels
+                .iter()
+                .map(|channel_id| {
+                    ChannelId::from_str(channel_id)
+                        .map_err(|e| anyhow::anyhow!("invalid preferred channel: {e}"))
+                })
+                .collect::<anyhow::Result<_>>()?,
+            max_consensus_age: msg.max_consensus_age.map(Duration::from_nanos),
+            counterparty_prefix_path: msg.counterparty_prefix_path,
+        })
+    }
+}
+
+impl From<ConnectionMetadata> for pb::ConnectionMetadata {
+    fn from(metadata: ConnectionMetadata) -> Self {
+        pb::ConnectionMetadata {
+            counterparty_chain_id: metadata.counterparty_chain_id,
+            display_name: metadata.display_name,
+            preferred_channels: metadata
+                .preferred_channels
+                .into_iter()
+                .map(|channel_id| channel_id.0)
+                .collect(),
+            max_consensus_age: metadata
+                .max_consensus_age
+                .map(|max_age| u64::try_from(max_age.as_nanos()).unwrap_or(u64::MAX)),
+            counterparty_prefix_path: metadata.counterparty_prefix_path,
+        }
+    }
+}
+
+/// The metadata registered for the given connection.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::IdentifiedConnectionMetadata",
+    into = "pb::IdentifiedConnectionMetadata"
+)]
+pub struct IdentifiedConnectionMetadata {
+    pub connection_id: ConnectionId,
+    pub metadata: ConnectionMetadata,
+}
+
+impl DomainType for IdentifiedConnectionMetadata {
+    type Proto = pb::IdentifiedConnectionMetadata;
+}
+
+impl TryFrom<pb::IdentifiedConnectionMetadata> for IdentifiedConnectionMetadata {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::IdentifiedConnectionMetadata) -> anyhow::Result<Self> {
+        Ok(IdentifiedConnectionMetadata {
+            connection_id: ConnectionId::from_str(&msg.connection_id)
+                .map_err(|e| anyhow::anyhow!("invalid connection id: {e}"))?,
+            metadata: msg
+                .metadata
+                .ok_or_else(|| anyhow::anyhow!("missing metadata"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<IdentifiedConnectionMetadata> for pb::IdentifiedConnectionMetadata {
+    fn from(identified: IdentifiedConnectionMetadata) -> Self {
+        pb::IdentifiedConnectionMetadata {
+            connection_id: identified.connection_id.to_string(),
+            metadata: Some(identified.metadata.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn max_consensus_ages_round_trip() {
+        let metadata = |max_consensus_age| ConnectionMetadata {
+            counterparty_chain_id: "osmosis-1".to_string(),
+            display_name: "Osmosis".to_string(),
+            preferred_channels: vec![],
+            max_consensus_age,
+            counterparty_prefix_path: vec![],
+        };
+
+        for max_consensus_age in [None, Some(Duration::ZERO), Some(Duration::from_secs(3600))] {
+            let metadata = metadata(max_consensus_age);
+            assert_eq!(
+                ConnectionMetadata::decode(metadata.encode_to_vec().as_slice())
+                    .expect("metadata decodes"),
+                metadata
+            );
+        }
+    }
+}
@@ -6,6 +6,7 @@ use ibc_proto::ibc::core::{
         MsgChannelOpenConfirm as RawMsgChannelOpenConfirm,
         MsgChannelOpenInit as RawMsgChannelOpenInit, MsgChannelOpenTry as RawMsgChannelOpenTry,
         MsgRecvPacket as RawMsgRecvPacket, MsgTimeout as RawMsgTimeout,
+        MsgTimeoutOnClose as RawMsgTimeoutOnClose,
     },
     client::v1::{
         MsgCreateClient as RawMsgCreateClient, MsgSubmitMisbehaviour as RawMsgSubmitMisbehaviour,
@@ -22,6 +23,7 @@ use ibc_types::core::{
     channel::msgs::{
         MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
         MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket, MsgTimeout,
+        MsgTimeoutOnClose,
     },
     client::msgs::{MsgCreateClient, MsgSubmitMisbehaviour, MsgUpdateClient, MsgUpgradeClient},
     connection::msgs::{
@@ -56,6 +58,7 @@ pub enum IbcRelay {
     RecvPacket(MsgRecvPacket),
     Acknowledgement(MsgAcknowledgement),
     Timeout(MsgTimeout),
+    TimeoutOnClose(MsgTimeoutOnClose),
     Unknown(pbjson_types::Any),
 }
 
@@ -125,6 +128,9 @@ impl IbcRelay {
             IbcRelay::Timeout(msg) => {
                 tracing::info_span!(parent: parent, "Timeout", chan_id = %msg.packet.chan_on_a, seq = %msg.packet.sequence)
             }
+            IbcRelay::TimeoutOnClose(msg) => {
+                tracing::info_span!(parent: parent, "TimeoutOnClose", chan_id = %msg.packet.chan_on_a, seq = %msg.packet.sequence)
+            }
             IbcRelay::Unknown(_) => {
                 tracing::info_span!(parent: parent, "Unknown")
             }
@@ -204,6 +210,9 @@ impl TryFrom<pb::IbcRelay> for IbcRelay {
         } else if action_type == RawMsgTimeout::type_url() {
             let msg = MsgTimeout::decode(raw_action_bytes)?;
             IbcRelay::Timeout(msg)
+        } else if action_type == RawMsgTimeoutOnClose::type_url() {
+            let msg = MsgTimeoutOnClose::decode(raw_action_bytes)?;
+            IbcRelay::TimeoutOnClose(msg)
         } else {
             IbcRelay::Unknown(raw_action)
         };
@@ -283,6 +292,10 @@ impl From<IbcRelay> for pb::IbcRelay {
                 type_url: RawMsgTimeout::type_url(),
                 value: msg.encode_to_vec().into(),
             },
+            IbcRelay::TimeoutOnClose(msg) => pbjson_types::Any {
+                type_url: RawMsgTimeoutOnClose::type_url(),
+                value: msg.encode_to_vec().into(),
+            },
             IbcRelay::Unknown(raw_action) => raw_action,
         };
         pb::IbcRelay {
@@ -11,14 +11,20 @@ pub mod component;
 #[cfg(feature = "component")]
 pub use component::ibc_action_with_handler::IbcRelayWithHandlers;
 
+pub mod connection_metadata;
 pub mod genesis;
 mod ibc_action;
 mod ibc_token;
+pub mod packet_fee;
 pub mod params;
+pub mod verification_stats;
 mod version;
 
 mod prefix;
-pub use prefix::{MerklePrefixExt, IBC_COMMITMENT_PREFIX, IBC_PROOF_SPECS, IBC_SUBSTORE_PREFIX};
+pub use prefix::{
+    CounterpartyPrefix, MerklePrefixExt, IBC_COMMITMENT_PREFIX, IBC_PROOF_SPECS,
+    IBC_SUBSTORE_PREFIX,
+};
 
 pub use ibc_action::IbcRelay;
 pub use ibc_token::IbcToken;
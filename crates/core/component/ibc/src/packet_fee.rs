@@ -0,0 +1,137 @@
+use ibc_types::core::channel::{ChannelId, PortId};
+use penumbra_asset::{Balance, Value};
+use penumbra_proto::core::component::ibc::v1 as pb;
+use penumbra_proto::DomainType;
+use serde::{Deserialize, Serialize};
+
+/// The fees paid to the relayers of a packet, as defined by ICS-29.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::Fee", into = "pb::Fee")]
+pub struct Fee {
+    /// Paid to the relayer that relays the packet to the counterparty.
+    pub recv_fee: Value,
+    /// Paid to the relayer that relays the acknowledgement back.
+    pub ack_fee: Value,
+    /// Paid to the relayer that relays the timeout back.
+    pub timeout_fee: Value,
+}
+
+impl Fee {
+    /// The total value escrowed for these fees.
+    pub fn total(&self) -> Balance {
+        Balance::from(self.recv_fee) + Balance::from(self.ack_fee) + Balance::from(self.timeout_fee)
+    }
+}
+
+impl DomainType for Fee {
+    type Proto = pb::Fee;
+}
+
+impl TryFrom<pb::Fee> for Fee {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::Fee) -> anyhow::Result<Self> {
+        Ok(Fee {
+            recv_fee: msg
+                .recv_fee
+                .ok_or_else(|| anyhow::anyhow!("missing recv fee"))?
+                .try_into()?,
+            ack_fee: msg
+                .ack_fee
+                .ok_or_else(|| anyhow::anyhow!("missing ack fee"))?
+                .try_into()?,
+            timeout_fee: msg
+                .timeout_fee
+                .ok_or_else(|| anyhow::anyhow!("missing timeout fee"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<Fee> for pb::Fee {
+    fn from(fee: Fee) -> Self {
+        pb::Fee {
+            recv_fee: Some(fee.recv_fee.into()),
+            ack_fee: Some(fee.ack_fee.into()),
+            timeout_fee: Some(fee.timeout_fee.into()),
+        }
+    }
+}
+
+/// The fees escrowed for a packet, and the Penumbra address that unpaid fees
+/// are refunded to.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::PacketFee", into = "pb::PacketFee")]
+pub struct PacketFee {
+    pub fee: Fee,
+    pub refund_address: String,
+}
+
+impl DomainType for PacketFee {
+    type Proto = pb::PacketFee;
+}
+
+impl TryFrom<pb::PacketFee> for PacketFee {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::PacketFee) -> anyhow::Result<Self> {
+        Ok(PacketFee {
+            fee: msg
+                .fee
+                .ok_or_else(|| anyhow::anyhow!("missing fee"))?
+                .try_into()?,
+            refund_address: msg.refund_address,
+        })
+    }
+}
+
+impl From<PacketFee> for pb::PacketFee {
+    fn from(packet_fee: PacketFee) -> Self {
+        pb::PacketFee {
+            fee: Some(packet_fee.fee.into()),
+            refund_address: packet_fee.refund_address,
+        }
+    }
+}
+
+/// The fees escrowed for the packet with the given sequence number, sent over
+/// the given port and channel.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::IdentifiedPacketFee", into = "pb::IdentifiedPacketFee")]
+pub struct IdentifiedPacketFee {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub sequence: u64,
+    pub packet_fee: PacketFee,
+}
+
+impl DomainType for IdentifiedPacketFee {
+    type Proto = pb::IdentifiedPacketFee;
+}
+
+impl TryFrom<pb::IdentifiedPacketFee> for IdentifiedPacketFee {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::IdentifiedPacketFee) -> anyhow::Result<Self> {
+        Ok(IdentifiedPacketFee {
+            port_id: PortId(msg.port_id),
+            channel_id: ChannelId(msg.channel_id),
+            sequence: msg.sequence,
+            packet_fee: msg
+                .packet_fee
+                .ok_or_else(|| anyhow::anyhow!("missing packet fee"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<IdentifiedPacketFee> for pb::IdentifiedPacketFee {
+    fn from(identified: IdentifiedPacketFee) -> Self {
+        pb::IdentifiedPacketFee {
+            port_id: identified.port_id.0,
+            channel_id: identified.channel_id.0,
+            sequence: identified.sequence,
+            packet_fee: Some(identified.packet_fee.into()),
+        }
+    }
+}
@@ -1,3 +1,8 @@
+use std::time::Duration;
+
+use ibc_types::core::channel::ChannelId;
+use penumbra_asset::asset;
+use penumbra_num::Amount;
 use penumbra_proto::core::component::ibc::v1 as pb;
 use penumbra_proto::DomainType;
 use serde::{Deserialize, Serialize};
@@ -11,6 +16,71 @@ pub struct IBCParameters {
     pub inbound_ics20_transfers_enabled: bool,
     /// Whether outbound ICS-20 transfers are enabled
     pub outbound_ics20_transfers_enabled: bool,
+    /// The maximum expected time between blocks, used to convert a
+    /// connection's delay period into a number of blocks.
+    pub max_expected_time_per_block: Duration,
+    /// Quotas on the value of ICS-20 transfers over individual channels.
+    pub ics20_rate_limits: Vec<Ics20RateLimit>,
+    /// If non-empty, only inbound ICS-20 transfers matching one of these
+    /// filters are accepted.
+    pub inbound_ics20_allowlist: Vec<Ics20AssetFilter>,
+    /// Inbound ICS-20 transfers matching any of these filters are rejected.
+    pub inbound_ics20_denylist: Vec<Ics20AssetFilter>,
+    /// The number of blocks the acknowledgements of received packets are kept
+    /// for before being pruned, or 0 to keep them forever.
+    pub ack_retention_blocks: u64,
+    /// The layouts of counterparty state that clients may be created for.
+    pub accepted_proof_spec_templates: Vec<ProofSpecTemplate>,
+    /// The type URLs of the messages that interchain accounts may execute.
+    pub ica_allowed_messages: Vec<String>,
+}
+
+impl IBCParameters {
+    /// The maximum expected time between blocks for chains whose parameters
+    /// predate it being configurable.
+    pub const DEFAULT_MAX_EXPECTED_TIME_PER_BLOCK: Duration = Duration::from_secs(20);
+
+    /// Gets the quota on transfers of `asset_id` over `channel_id`, if there is one.
+    pub fn ics20_rate_limit(
+        &self,
+        channel_id: &ChannelId,
+        asset_id: &asset::Id,
+    ) -> Option<&Ics20RateLimit> {
+        self.ics20_rate_limits
+            .iter()
+            .find(|limit| &limit.channel_id == channel_id && &limit.asset_id == asset_id)
+    }
+
+    /// Returns whether inbound ICS-20 transfers over `channel_id`, credited in
+    /// `denom`, are accepted by the allowlist and denylist.
+    pub fn inbound_ics20_allowed(&self, channel_id: &ChannelId, denom: &str) -> bool {
+        let allowed = self.inbound_ics20_allowlist.is_empty()
+            || self
+                .inbound_ics20_allowlist
+                .iter()
+                .any(|filter| filter.matches(channel_id, denom));
+        let denied = self
+            .inbound_ics20_denylist
+            .iter()
+            .any(|filter| filter.matches(channel_id, denom));
+
+        allowed && !denied
+    }
+
+    /// Checks that a client with `proof_specs` may be created: the proof specs
+    /// must be safe to verify proofs with, and match an accepted template.
+    pub fn check_proof_specs(&self, proof_specs: &[ics23::ProofSpec]) -> anyhow::Result<()> {
+        check_proof_specs_are_safe(proof_specs)?;
+        if !self
+            .accepted_proof_spec_templates
+            .iter()
+            .any(|template| template.matches(proof_specs))
+        {
+            anyhow::bail!("proof specs don't match any accepted proof spec template");
+        }
+
+        Ok(())
+    }
 }
 
 impl DomainType for IBCParameters {
@@ -25,6 +95,37 @@ impl TryFrom<pb::IbcParameters> for IBCParameters {
             ibc_enabled: msg.ibc_enabled,
             inbound_ics20_transfers_enabled: msg.inbound_ics20_transfers_enabled,
             outbound_ics20_transfers_enabled: msg.outbound_ics20_transfers_enabled,
+            // Parameters written before this field existed decode it as zero.
+            max_expected_time_per_block: match msg.max_expected_time_per_block {
+                0 => Self::DEFAULT_MAX_EXPECTED_TIME_PER_BLOCK,
+                nanos => Duration::from_nanos(nanos),
+            },
+            ics20_rate_limits: msg
+                .ics20_rate_limits
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<anyhow::Result<_>>()?,
+            inbound_ics20_allowlist: msg
+                .inbound_ics20_allowlist
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<anyhow::Result<_>>()?,
+            inbound_ics20_denylist: msg
+                .inbound_ics20_denylist
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<anyhow::Result<_>>()?,
+            ack_retention_blocks: msg.ack_retention_blocks,
+            // Parameters written before this field existed decode it as empty.
+            accepted_proof_spec_templates: match msg.accepted_proof_spec_templates.len() {
+                0 => ProofSpecTemplate::defaults(),
+                _ => msg
+                    .accepted_proof_spec_templates
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<anyhow::Result<_>>()?,
+            },
+            ica_allowed_messages: msg.ica_allowed_messages,
         })
     }
 }
@@ -35,6 +136,33 @@ impl From<IBCParameters> for pb::IbcParameters {
             ibc_enabled: params.ibc_enabled,
             inbound_ics20_transfers_enabled: params.inbound_ics20_transfers_enabled,
             outbound_ics20_transfers_enabled: params.outbound_ics20_transfers_enabled,
+            max_expected_time_per_block: params
+                .max_expected_time_per_block
+                .as_nanos()
+                .try_into()
+                .unwrap_or(u64::MAX),
+            ics20_rate_limits: params
+                .ics20_rate_limits
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            inbound_ics20_allowlist: params
+                .inbound_ics20_allowlist
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            inbound_ics20_denylist: params
+                .inbound_ics20_denylist
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            ack_retention_blocks: params.ack_retention_blocks,
+            accepted_proof_spec_templates: params
+                .accepted_proof_spec_templates
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            ica_allowed_messages: params.ica_allowed_messages,
         }
     }
 }
@@ -45,6 +173,289 @@ impl Default for IBCParameters {
             ibc_enabled: true,
             inbound_ics20_transfers_enabled: true,
             outbound_ics20_transfers_enabled: true,
+            max_expected_time_per_block: Self::DEFAULT_MAX_EXPECTED_TIME_PER_BLOCK,
+            ics20_rate_limits: Vec::new(),
+            inbound_ics20_allowlist: Vec::new(),
+            inbound_ics20_denylist: Vec::new(),
+            ack_retention_blocks: 0,
+            accepted_proof_spec_templates: ProofSpecTemplate::defaults(),
+            ica_allowed_messages: Vec::new(),
+        }
+    }
+}
+
+/// A quota on the amount of one asset that ICS-20 transfers may move over one
+/// channel within a window of blocks.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "pb::Ics20RateLimit", into = "pb::Ics20RateLimit")]
+pub struct Ics20RateLimit {
+    /// The channel the quota applies to, as named on this chain.
+    pub channel_id: ChannelId,
+    pub asset_id: asset::Id,
+    /// The maximum amount that may be sent over the channel in a window.
+    pub max_outflow: Amount,
+    /// The maximum amount that may be received over the channel in a window.
+    pub max_inflow: Amount,
+    /// The length of a window, in blocks.
+    pub window_blocks: u64,
+}
+
+impl DomainType for Ics20RateLimit {
+    type Proto = pb::Ics20RateLimit;
+}
+
+impl TryFrom<pb::Ics20RateLimit> for Ics20RateLimit {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::Ics20RateLimit) -> anyhow::Result<Self> {
+        Ok(Ics20RateLimit {
+            channel_id: msg.channel_id.parse()?,
+            asset_id: msg
+                .asset_id
+                .ok_or_else(|| anyhow::anyhow!("missing asset id in rate limit"))?
+                .try_into()?,
+            max_outflow: msg
+                .max_outflow
+                .ok_or_else(|| anyhow::anyhow!("missing max outflow in rate limit"))?
+                .try_into()?,
+            max_inflow: msg
+                .max_inflow
+                .ok_or_else(|| anyhow::anyhow!("missing max inflow in rate limit"))?
+                .try_into()?,
+            window_blocks: msg.window_blocks,
+        })
+    }
+}
+
+impl From<Ics20RateLimit> for pb::Ics20RateLimit {
+    fn from(limit: Ics20RateLimit) -> Self {
+        pb::Ics20RateLimit {
+            channel_id: limit.channel_id.to_string(),
+            asset_id: Some(limit.asset_id.into()),
+            max_outflow: Some(limit.max_outflow.into()),
+            max_inflow: Some(limit.max_inflow.into()),
+            window_blocks: limit.window_blocks,
+        }
+    }
+}
+
+/// Matches inbound ICS-20 transfers by the channel they're received over, the
+/// denom they're credited in, or both.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "pb::Ics20AssetFilter", into = "pb::Ics20AssetFilter")]
+pub struct Ics20AssetFilter {
+    /// The channel, as named on this chain, or `None` to match any channel.
+    pub channel_id: Option<ChannelId>,
+    /// The denom trace of the asset as named on this chain, e.g.
+    /// `transfer/channel-0/uatom`, or `None` to match any denom.
+    pub denom: Option<String>,
+}
+
+impl Ics20AssetFilter {
+    pub fn matches(&self, channel_id: &ChannelId, denom: &str) -> bool {
+        self.channel_id.as_ref().map_or(true, |c| c == channel_id)
+            && self.denom.as_deref().map_or(true, |d| d == denom)
+    }
+}
+
+impl DomainType for Ics20AssetFilter {
+    type Proto = pb::Ics20AssetFilter;
+}
+
+impl TryFrom<pb::Ics20AssetFilter> for Ics20AssetFilter {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::Ics20AssetFilter) -> anyhow::Result<Self> {
+        Ok(Ics20AssetFilter {
+            channel_id: match msg.channel_id.as_str() {
+                "" => None,
+                channel_id => Some(channel_id.parse()?),
+            },
+            denom: Some(msg.denom).filter(|denom| !denom.is_empty()),
+        })
+    }
+}
+
+impl From<Ics20AssetFilter> for pb::Ics20AssetFilter {
+    fn from(filter: Ics20AssetFilter) -> Self {
+        pb::Ics20AssetFilter {
+            channel_id: filter
+                .channel_id
+                .map(|channel_id| channel_id.to_string())
+                .unwrap_or_default(),
+            denom: filter.denom.unwrap_or_default(),
+        }
+    }
+}
+
+/// A named set of ICS-23 proof specs, describing how the state of a kind of
+/// counterparty chain is committed to.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(try_from = "pb::ProofSpecTemplate", into = "pb::ProofSpecTemplate")]
+pub struct ProofSpecTemplate {
+    pub name: String,
+    /// The proof specs a client's proof specs must equal to match the template.
+    pub proof_specs: Vec<ics23::ProofSpec>,
+}
+
+// `ics23::ProofSpec` only derives `PartialEq`, but has no floating point fields.
+impl Eq for ProofSpecTemplate {}
+
+impl ProofSpecTemplate {
+    /// The layout of Cosmos SDK chains: an IAVL store nested in a simple
+    /// Merkle tree of stores.
+    pub fn cosmos_sdk() -> Self {
+        Self {
+            name: "cosmos-sdk".to_string(),
+            proof_specs: vec![ics23::iavl_spec(), ics23::tendermint_spec()],
         }
     }
+
+    /// The layout of Penumbra chains.
+    pub fn penumbra() -> Self {
+        Self {
+            name: "penumbra".to_string(),
+            proof_specs: crate::IBC_PROOF_SPECS.clone(),
+        }
+    }
+
+    /// The templates accepted unless governance has chosen others.
+    pub fn defaults() -> Vec<Self> {
+        vec![Self::cosmos_sdk(), Self::penumbra()]
+    }
+
+    pub fn matches(&self, proof_specs: &[ics23::ProofSpec]) -> bool {
+        self.proof_specs == proof_specs
+    }
+}
+
+/// Checks that `proof_specs` can't be satisfied by proofs of data that was
+/// never committed to.
+///
+/// This doesn't make a set of proof specs correct for any particular chain, but
+/// rules out specs that would let leaves and inner nodes be confused, or skip
+/// hashing altogether.
+pub fn check_proof_specs_are_safe(proof_specs: &[ics23::ProofSpec]) -> anyhow::Result<()> {
+    use ics23::HashOp;
+
+    if proof_specs.is_empty() {
+        anyhow::bail!("proof specs must not be empty");
+    }
+
+    let hashes = |hash: i32| !matches!(HashOp::try_from(hash), Ok(HashOp::NoHash) | Err(_));
+    for (index, spec) in proof_specs.iter().enumerate() {
+        let leaf = spec
+            .leaf_spec
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("proof spec {index} has no leaf spec"))?;
+        let inner = spec
+            .inner_spec
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("proof spec {index} has no inner spec"))?;
+
+        if !hashes(leaf.hash) || !hashes(inner.hash) {
+            anyhow::bail!("proof spec {index} doesn't hash its leaves and inner nodes");
+        }
+        if leaf.prefix.is_empty() {
+            anyhow::bail!("proof spec {index} doesn't separate leaves from inner nodes");
+        }
+        if inner.child_size <= 0
+            || inner.min_prefix_length < 0
+            || inner.max_prefix_length < inner.min_prefix_length
+        {
+            anyhow::bail!("proof spec {index} has invalid inner node lengths");
+        }
+
+        let mut child_order = inner.child_order.clone();
+        child_order.sort_unstable();
+        if child_order.len() < 2 || child_order.iter().zip(0..).any(|(&c, i)| c != i) {
+            anyhow::bail!("proof spec {index} has an invalid child order");
+        }
+
+        if spec.min_depth < 0
+            || spec.max_depth < 0
+            || (spec.max_depth > 0 && spec.min_depth > spec.max_depth)
+        {
+            anyhow::bail!("proof spec {index} has invalid depth bounds");
+        }
+    }
+
+    Ok(())
+}
+
+impl DomainType for ProofSpecTemplate {
+    type Proto = pb::ProofSpecTemplate;
+}
+
+impl TryFrom<pb::ProofSpecTemplate> for ProofSpecTemplate {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::ProofSpecTemplate) -> anyhow::Result<Self> {
+        Ok(ProofSpecTemplate {
+            name: msg.name,
+            proof_specs: msg.proof_specs,
+        })
+    }
+}
+
+impl From<ProofSpecTemplate> for pb::ProofSpecTemplate {
+    fn from(template: ProofSpecTemplate) -> Self {
+        pb::ProofSpecTemplate {
+            name: template.name,
+            proof_specs: template.proof_specs,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inbound_ics20_transfers_are_filtered() {
+        let osmosis = ChannelId::new(0);
+        let cosmoshub = ChannelId::new(1);
+        let mut params = IBCParameters::default();
+        assert!(params.inbound_ics20_allowed(&osmosis, "transfer/channel-0/uosmo"));
+
+        params.inbound_ics20_allowlist = vec![Ics20AssetFilter {
+            channel_id: Some(osmosis.clone()),
+            denom: None,
+        }];
+        params.inbound_ics20_denylist = vec![Ics20AssetFilter {
+            channel_id: None,
+            denom: Some("transfer/channel-0/ushady".to_string()),
+        }];
+        assert!(params.inbound_ics20_allowed(&osmosis, "transfer/channel-0/uosmo"));
+        assert!(!params.inbound_ics20_allowed(&osmosis, "transfer/channel-0/ushady"));
+        assert!(!params.inbound_ics20_allowed(&cosmoshub, "transfer/channel-1/uatom"));
+    }
+
+    #[test]
+    fn proof_specs_are_checked_against_templates() {
+        let mut params = IBCParameters::default();
+        for template in ProofSpecTemplate::defaults() {
+            params.check_proof_specs(&template.proof_specs).unwrap();
+        }
+
+        // safe proof specs are still rejected if no template matches them.
+        let smt = vec![ics23::smt_spec()];
+        params.check_proof_specs(&smt).unwrap_err();
+        params
+            .accepted_proof_spec_templates
+            .push(ProofSpecTemplate {
+                name: "smt".to_string(),
+                proof_specs: smt.clone(),
+            });
+        params.check_proof_specs(&smt).unwrap();
+
+        let mut unhashed = ics23::iavl_spec();
+        unhashed
+            .leaf_spec
+            .as_mut()
+            .expect("iavl spec has a leaf spec")
+            .hash = ics23::HashOp::NoHash.into();
+        assert!(check_proof_specs_are_safe(&[unhashed]).is_err());
+        assert!(check_proof_specs_are_safe(&[]).is_err());
+    }
 }
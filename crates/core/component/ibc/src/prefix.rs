@@ -1,4 +1,6 @@
-use ibc_types::core::commitment::MerklePrefix;
+use std::string::FromUtf8Error;
+
+use ibc_types::core::commitment::{MerklePath, MerklePrefix};
 use once_cell::sync::Lazy;
 
 /// The substore prefix used for IBC data.
@@ -64,3 +66,101 @@ impl MerklePrefixExt for MerklePrefix {
         format!("{}/{}", prefix_string, path)
     }
 }
+
+/// The prefix under which the counterparty of a connection commits its IBC
+/// state.
+///
+/// Like ibc-go, the counterparty commitment prefix negotiated in the connection
+/// handshake is applied as a single key, whatever bytes it contains. Chains
+/// whose IBC store is nested more than one level deep instead have their full
+/// key path registered in the metadata of the connection.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CounterpartyPrefix {
+    /// The counterparty commitment prefix of the connection, as a single key.
+    Single(MerklePrefix),
+    /// The keys registered for the connection, from the outermost store inwards.
+    KeyPath(Vec<Vec<u8>>),
+}
+
+impl CounterpartyPrefix {
+    /// Applies this prefix to `path`, producing the key path that a proof for
+    /// `path` is verified against.
+    ///
+    /// The prefix is chosen by the counterparty, so this fails rather than
+    /// panics if any of its keys is not valid UTF-8.
+    pub fn apply_path(&self, path: String) -> Result<MerklePath, FromUtf8Error> {
+        let keys = match self {
+            CounterpartyPrefix::Single(prefix) => std::slice::from_ref(&prefix.key_prefix),
+            CounterpartyPrefix::KeyPath(keys) => keys.as_slice(),
+        };
+
+        let mut key_path = keys
+            .iter()
+            .map(|key| String::from_utf8(key.clone()))
+            .collect::<Result<Vec<_>, _>>()?;
+        key_path.push(path);
+
+        Ok(MerklePath { key_path })
+    }
+}
+
+impl From<MerklePrefix> for CounterpartyPrefix {
+    fn from(prefix: MerklePrefix) -> Self {
+        CounterpartyPrefix::Single(prefix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PATH: &str = "clients/07-tendermint-0/clientState";
+
+    #[test]
+    fn single_prefix_matches_apply() {
+        assert_eq!(
+            CounterpartyPrefix::from(IBC_COMMITMENT_PREFIX.clone())
+                .apply_path(PATH.to_string())
+                .expect("prefix is valid utf-8")
+                .key_path,
+            IBC_COMMITMENT_PREFIX.apply(vec![PATH.to_string()]).key_path,
+        );
+    }
+
+    #[test]
+    fn single_prefix_is_one_key_even_if_it_contains_a_slash() {
+        let prefix = CounterpartyPrefix::Single(MerklePrefix {
+            key_prefix: b"rollup/ibc".to_vec(),
+        });
+
+        assert_eq!(
+            prefix
+                .apply_path(PATH.to_string())
+                .expect("prefix is valid utf-8")
+                .key_path,
+            vec!["rollup/ibc".to_string(), PATH.to_string()],
+        );
+    }
+
+    #[test]
+    fn key_path_prepends_every_key() {
+        let prefix = CounterpartyPrefix::KeyPath(vec![b"rollup".to_vec(), b"ibc".to_vec()]);
+
+        assert_eq!(
+            prefix
+                .apply_path(PATH.to_string())
+                .expect("prefix is valid utf-8")
+                .key_path,
+            vec!["rollup".to_string(), "ibc".to_string(), PATH.to_string()],
+        );
+    }
+
+    #[test]
+    fn non_utf8_prefix_is_an_error() {
+        let prefix = CounterpartyPrefix::Single(MerklePrefix {
+            key_prefix: vec![0xff, 0xfe, b'i', b'b', b'c'],
+        });
+
+        assert!(prefix.apply_path(PATH.to_string()).is_err());
+    }
+}
@@ -0,0 +1,144 @@
+use penumbra_proto::core::component::ibc::v1 as pb;
+use penumbra_proto::DomainType;
+use serde::{Deserialize, Serialize};
+
+/// The kind of proof being verified against a client.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VerificationKind {
+    ClientUpgrade,
+    Channel,
+    PacketRecv,
+    PacketAck,
+    PacketTimeout,
+    /// A proof of arbitrary counterparty state, e.g. for a cross-chain query.
+    Query,
+}
+
+/// Counts of successful and failed proof verifications of a single kind.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::VerificationCounts", into = "pb::VerificationCounts")]
+pub struct VerificationCounts {
+    pub succeeded: u64,
+    pub failed: u64,
+}
+
+impl VerificationCounts {
+    fn merge(&mut self, other: &VerificationCounts) {
+        self.succeeded = self.succeeded.saturating_add(other.succeeded);
+        self.failed = self.failed.saturating_add(other.failed);
+    }
+}
+
+impl DomainType for VerificationCounts {
+    type Proto = pb::VerificationCounts;
+}
+
+impl TryFrom<pb::VerificationCounts> for VerificationCounts {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::VerificationCounts) -> anyhow::Result<Self> {
+        Ok(VerificationCounts {
+            succeeded: msg.succeeded,
+            failed: msg.failed,
+        })
+    }
+}
+
+impl From<VerificationCounts> for pb::VerificationCounts {
+    fn from(counts: VerificationCounts) -> Self {
+        pb::VerificationCounts {
+            succeeded: counts.succeeded,
+            failed: counts.failed,
+        }
+    }
+}
+
+/// Proof verification statistics for a single client, bucketed by
+/// [`VerificationKind`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::ClientVerificationStats",
+    into = "pb::ClientVerificationStats"
+)]
+pub struct ClientVerificationStats {
+    pub client_upgrade: VerificationCounts,
+    pub channel: VerificationCounts,
+    pub packet_recv: VerificationCounts,
+    pub packet_ack: VerificationCounts,
+    pub packet_timeout: VerificationCounts,
+    pub query: VerificationCounts,
+}
+
+impl ClientVerificationStats {
+    /// Returns the counts for the given kind of verification.
+    pub fn counts(&self, kind: VerificationKind) -> &VerificationCounts {
+        match kind {
+            VerificationKind::ClientUpgrade => &self.client_upgrade,
+            VerificationKind::Channel => &self.channel,
+            VerificationKind::PacketRecv => &self.packet_recv,
+            VerificationKind::PacketAck => &self.packet_ack,
+            VerificationKind::PacketTimeout => &self.packet_timeout,
+            VerificationKind::Query => &self.query,
+        }
+    }
+
+    /// Returns a mutable reference to the counts for the given kind of verification.
+    pub fn counts_mut(&mut self, kind: VerificationKind) -> &mut VerificationCounts {
+        match kind {
+            VerificationKind::ClientUpgrade => &mut self.client_upgrade,
+            VerificationKind::Channel => &mut self.channel,
+            VerificationKind::PacketRecv => &mut self.packet_recv,
+            VerificationKind::PacketAck => &mut self.packet_ack,
+            VerificationKind::PacketTimeout => &mut self.packet_timeout,
+            VerificationKind::Query => &mut self.query,
+        }
+    }
+
+    /// Adds the counts in `other` to these statistics.
+    pub fn merge(&mut self, other: &ClientVerificationStats) {
+        self.client_upgrade.merge(&other.client_upgrade);
+        self.channel.merge(&other.channel);
+        self.packet_recv.merge(&other.packet_recv);
+        self.packet_ack.merge(&other.packet_ack);
+        self.packet_timeout.merge(&other.packet_timeout);
+        self.query.merge(&other.query);
+    }
+}
+
+impl DomainType for ClientVerificationStats {
+    type Proto = pb::ClientVerificationStats;
+}
+
+impl TryFrom<pb::ClientVerificationStats> for ClientVerificationStats {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::ClientVerificationStats) -> anyhow::Result<Self> {
+        let counts = |c: Option<pb::VerificationCounts>| -> anyhow::Result<VerificationCounts> {
+            c.map(TryInto::try_into)
+                .transpose()
+                .map(Option::unwrap_or_default)
+        };
+
+        Ok(ClientVerificationStats {
+            client_upgrade: counts(msg.client_upgrade)?,
+            channel: counts(msg.channel)?,
+            packet_recv: counts(msg.packet_recv)?,
+            packet_ack: counts(msg.packet_ack)?,
+            packet_timeout: counts(msg.packet_timeout)?,
+            query: counts(msg.query)?,
+        })
+    }
+}
+
+impl From<ClientVerificationStats> for pb::ClientVerificationStats {
+    fn from(stats: ClientVerificationStats) -> Self {
+        pb::ClientVerificationStats {
+            client_upgrade: Some(stats.client_upgrade.into()),
+            channel: Some(stats.channel.into()),
+            packet_recv: Some(stats.packet_recv.into()),
+            packet_ack: Some(stats.packet_ack.into()),
+            packet_timeout: Some(stats.packet_timeout.into()),
+            query: Some(stats.query.into()),
+        }
+    }
+}
@@ -1,15 +1,18 @@
-use cnidarium::Storage;
+use cnidarium::{StateRead, Storage};
 use pbjson_types::Timestamp;
 use penumbra_proto::core::component::sct::v1::query_service_server::QueryService;
 use penumbra_proto::core::component::sct::v1::{
-    AnchorByHeightRequest, AnchorByHeightResponse, EpochByHeightRequest, EpochByHeightResponse,
+    self as pb, AnchorByHeightRequest, AnchorByHeightResponse, EpochByHeightRequest,
+    EpochByHeightResponse, NullifierStatusRequest, NullifierStatusResponse,
     TimestampByHeightRequest, TimestampByHeightResponse,
 };
+use penumbra_proto::Message;
 use tonic::Status;
 use tracing::instrument;
 
 use super::clock::EpochRead;
 use super::tree::SctRead;
+use crate::{state_key, Nullifier};
 
 // TODO: Hide this and only expose a Router?
 pub struct Server {
@@ -79,4 +82,57 @@ impl QueryService for Server {
             }),
         }))
     }
+
+    #[instrument(skip(self, request))]
+    async fn nullifier_status(
+        &self,
+        request: tonic::Request<NullifierStatusRequest>,
+    ) -> Result<tonic::Response<NullifierStatusResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        let request = request.into_inner();
+
+        let nullifier: Nullifier = request
+            .nullifier
+            .ok_or_else(|| Status::invalid_argument("missing nullifier"))?
+            .try_into()
+            .map_err(|e| Status::invalid_argument(format!("invalid nullifier: {e}")))?;
+        let key = state_key::nullifier_set::spent_nullifier_lookup(&nullifier);
+
+        // Don't generate the proof if the request doesn't ask for it.
+        let (value, proof) = if request.proof {
+            let (value, proof) = state
+                .get_with_proof(key.into_bytes())
+                .await
+                .map_err(|e| Status::internal(e.to_string()))?;
+            (value, Some(proof))
+        } else {
+            let value = state
+                .get_raw(&key)
+                .await
+                .map_err(|e| Status::internal(e.to_string()))?;
+            (value, None)
+        };
+
+        let spend_info = value
+            .map(|value| pb::NullificationInfo::decode(value.as_slice()))
+            .transpose()
+            .map_err(|e| Status::internal(format!("could not decode nullification info: {e}")))?;
+
+        Ok(tonic::Response::new(NullifierStatusResponse {
+            spend_info,
+            height: state.version(),
+            // The proof is re-encoded into the `ibc-proto` type, whose ics23
+            // version may differ from the one used by the JMT.
+            proof: proof.map(|proof| ibc_proto::ibc::core::commitment::v1::MerkleProof {
+                proofs: proof
+                    .proofs
+                    .into_iter()
+                    .map(|p| {
+                        let encoded = p.encode_to_vec();
+                        Message::decode(&*encoded).expect("able to decode proof")
+                    })
+                    .collect(),
+            }),
+        }))
+    }
 }
@@ -46,6 +46,13 @@ impl Component for Sct {
         }
     }
 
+    #[instrument(name = "sct_component", skip(state))]
+    async fn export_genesis<S: StateRead>(state: &S) -> Result<Self::AppState> {
+        Ok(genesis::Content {
+            sct_params: state.get_sct_params().await?,
+        })
+    }
+
     #[instrument(name = "sct_component", skip(state, begin_block))]
     async fn begin_block<S: StateWrite + 'static>(
         state: &mut Arc<S>,
@@ -27,6 +27,9 @@ pub enum CommitmentSource {
         channel_id: String,
         /// The sender address on the counterparty chain.
         sender: String,
+        /// The memo of the transfer, encrypted to the receiver of the minted
+        /// note, or empty if the transfer had no memo for the receiver.
+        encrypted_memo: Vec<u8>,
     },
 }
 
@@ -77,10 +80,12 @@ impl From<CommitmentSource> for pb::CommitmentSource {
                     packet_seq,
                     channel_id,
                     sender,
+                    encrypted_memo,
                 } => Source::Ics20Transfer(pbcs::Ics20Transfer {
                     packet_seq,
                     channel_id,
                     sender,
+                    encrypted_memo,
                 }),
             }),
         }
@@ -115,6 +120,7 @@ impl TryFrom<pb::CommitmentSource> for CommitmentSource {
                 packet_seq: x.packet_seq,
                 channel_id: x.channel_id,
                 sender: x.sender,
+                encrypted_memo: x.encrypted_memo,
             },
         })
     }
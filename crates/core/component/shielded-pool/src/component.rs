@@ -5,16 +5,21 @@ mod assets;
 mod fmd;
 mod ics20_withdrawal_with_handler;
 mod metrics;
+mod nft_withdrawal_with_handler;
 mod note_manager;
 mod shielded_pool;
+mod supply;
 mod transfer;
 
 pub use self::metrics::register_metrics;
 pub use assets::{AssetRegistry, AssetRegistryRead};
 pub use fmd::ClueManager;
 pub use ics20_withdrawal_with_handler::Ics20WithdrawalWithHandler;
+pub use nft_withdrawal_with_handler::NftWithdrawalWithHandler;
 pub use note_manager::NoteManager;
 pub use shielded_pool::{ShieldedPool, StateReadExt, StateWriteExt};
-pub use transfer::Ics20Transfer;
+pub use supply::{SupplyManager, SupplyRead};
+pub use transfer::{Ics20FeePayout, Ics20Hooks, Ics20Transfer, NoIcs20Hooks, ReceivedTransfer};
 
+pub mod nft_transfer;
 pub mod rpc;
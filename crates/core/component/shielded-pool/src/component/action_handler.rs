@@ -1,3 +1,4 @@
 mod ics20_withdrawal;
+mod nft_withdrawal;
 mod output;
 mod spend;
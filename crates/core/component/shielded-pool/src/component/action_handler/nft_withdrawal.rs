@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use anyhow::{ensure, Result};
+use cnidarium::{StateRead, StateWrite};
+use penumbra_ibc::{component::HostInterface, StateReadExt as _};
+
+use crate::component::nft_transfer::{Ics721TransferReadExt as _, Ics721TransferWriteExt as _};
+use crate::component::NftWithdrawalWithHandler;
+
+impl<HI: HostInterface> NftWithdrawalWithHandler<HI> {
+    pub async fn check_stateless(&self, _context: ()) -> Result<()> {
+        self.action().validate()
+    }
+
+    pub async fn check_historical<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
+        ensure!(
+            state.get_ibc_params().await?.ibc_enabled,
+            "transaction contains an NFT withdrawal, but IBC is not enabled"
+        );
+        Ok(())
+    }
+
+    pub async fn check_and_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        let current_block_time = HI::get_block_timestamp(&state).await?;
+        state
+            .nft_withdrawal_check(self.action(), current_block_time)
+            .await?;
+        state.nft_withdrawal_execute(self.action()).await
+    }
+}
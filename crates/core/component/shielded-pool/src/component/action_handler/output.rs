@@ -5,21 +5,23 @@ use cnidarium_component::ActionHandler;
 use penumbra_proof_params::OUTPUT_PROOF_VERIFICATION_KEY;
 use penumbra_proto::{DomainType as _, StateWriteProto as _};
 use penumbra_sct::component::source::SourceContext;
+use penumbra_txhash::TransactionContext;
 
 use crate::{component::NoteManager, event, output::OutputProofPublic, Output};
 
 #[async_trait]
 impl ActionHandler for Output {
-    type CheckStatelessContext = ();
-    async fn check_stateless(&self, _context: ()) -> Result<()> {
+    type CheckStatelessContext = TransactionContext;
+    async fn check_stateless(&self, context: TransactionContext) -> Result<()> {
         let output = self;
 
-        output.proof.verify(
+        output.proof.verify_unless_batched(
             &OUTPUT_PROOF_VERIFICATION_KEY,
             OutputProofPublic {
                 balance_commitment: output.body.balance_commitment,
                 note_commitment: output.body.note_payload.note_commitment,
             },
+            &context.verification_cache.proofs,
         )?;
 
         Ok(())
@@ -2,6 +2,7 @@ use anyhow::{Context, Result};
 use async_trait::async_trait;
 use cnidarium::StateWrite;
 use cnidarium_component::ActionHandler;
+use penumbra_keys::signature_batch;
 use penumbra_proof_params::SPEND_PROOF_VERIFICATION_KEY;
 use penumbra_proto::{DomainType, StateWriteProto as _};
 use penumbra_sct::component::{
@@ -18,11 +19,13 @@ impl ActionHandler for Spend {
     async fn check_stateless(&self, context: TransactionContext) -> Result<()> {
         let spend = self;
         // 2. Check spend auth signature using provided spend auth key.
-        spend
-            .body
-            .rk
-            .verify(context.effect_hash.as_ref(), &spend.auth_sig)
-            .context("spend auth signature failed to verify")?;
+        signature_batch::verify(
+            &spend.body.rk,
+            context.effect_hash.as_ref(),
+            &spend.auth_sig,
+            &context.verification_cache.signatures,
+        )
+        .context("spend auth signature failed to verify")?;
 
         // 3. Check that the proof verifies.
         let public = SpendProofPublic {
@@ -33,7 +36,11 @@ impl ActionHandler for Spend {
         };
         spend
             .proof
-            .verify(&SPEND_PROOF_VERIFICATION_KEY, public)
+            .verify_unless_batched(
+                &SPEND_PROOF_VERIFICATION_KEY,
+                public,
+                &context.verification_cache.proofs,
+            )
             .context("a spend proof did not verify")?;
 
         Ok(())
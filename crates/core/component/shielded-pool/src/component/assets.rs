@@ -1,5 +1,7 @@
+use anyhow::Result;
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
+use futures::TryStreamExt as _;
 use penumbra_asset::asset::{self, Metadata};
 use penumbra_proto::{StateReadProto, StateWriteProto};
 
@@ -14,6 +16,14 @@ pub trait AssetRegistryRead: StateRead {
             .await
             .expect("no deserialization error")
     }
+
+    /// The metadata of every registered asset.
+    async fn all_denom_metadata(&self) -> Result<Vec<Metadata>> {
+        self.prefix::<Metadata>(state_key::denom_metadata_by_asset::prefix())
+            .map_ok(|(_key, denom)| denom)
+            .try_collect()
+            .await
+    }
 }
 
 impl<T: StateRead + ?Sized> AssetRegistryRead for T {}
@@ -34,6 +44,20 @@ pub trait AssetRegistry: StateWrite {
             );
         }
     }
+
+    /// Register the metadata of an asset, replacing any already registered for it.
+    ///
+    /// This is used to give assets human-readable display units, e.g. by governance.
+    #[instrument(skip(self))]
+    async fn update_denom_metadata(&mut self, denom: &Metadata) {
+        let asset_id = denom.id();
+        tracing::debug!(?asset_id, "updating asset metadata in shielded pool");
+
+        self.put(
+            state_key::denom_metadata_by_asset::by_asset_id(&asset_id),
+            denom.clone(),
+        );
+    }
 }
 
 impl<T: StateWrite + ?Sized> AssetRegistry for T {}
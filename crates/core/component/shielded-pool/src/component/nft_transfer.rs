@@ -0,0 +1,778 @@
+use std::{marker::PhantomData, str::FromStr};
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use cnidarium::{StateDelta, StateRead, StateWrite};
+use ibc_types::{
+    core::channel::{
+        channel::Order as ChannelOrder,
+        msgs::{
+            MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
+            MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket,
+            MsgTimeout,
+        },
+        ChannelId, Packet, PortId, Version,
+    },
+    transfer::acknowledgement::TokenTransferAcknowledgement,
+};
+use penumbra_ibc::component::{
+    app_handler::{AcknowledgementResult, AppHandler, AppHandlerCheck, AppHandlerExecute},
+    packet::{
+        IBCPacket, SendPacketRead as _, SendPacketWrite as _, Unchecked, WriteAcknowledgement as _,
+    },
+    state_key,
+};
+use penumbra_keys::Address;
+use penumbra_num::Amount;
+use penumbra_sct::CommitmentSource;
+use serde::{Deserialize, Serialize};
+use tendermint::Time;
+
+use crate::component::{AssetRegistry, NoteManager};
+pub use crate::nft_withdrawal::token_denom;
+use crate::NftWithdrawal;
+
+// ICS-721 non-fungible token transfers, compatible with ibc-go's nft-transfer module.
+//
+// see: https://github.com/cosmos/ibc/tree/main/spec/app/ics-721-nft-transfer
+//
+// each token crosses IBC as a separate asset, with denom `{class_id}/{token_id}`, and is held in
+// notes of amount one. as with ICS20, the class ids of tokens received from other chains are
+// prefixed with the port and channel they were received over, so the path a token took is
+// recorded in its denom.
+
+pub const NFT_TRANSFER_PORT: &str = "nft-transfer";
+pub const ICS721_VERSION: &str = "ics721-1";
+
+pub fn nft_transfer_port() -> PortId {
+    PortId(NFT_TRANSFER_PORT.to_string())
+}
+
+/// The data of an ICS-721 packet, in the JSON encoding used by ibc-go.
+///
+/// Fields are declared in alphabetical order, matching the sorted JSON that
+/// ibc-go commits to.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NonFungibleTokenPacketData {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub class_data: String,
+    pub class_id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub class_uri: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub memo: String,
+    pub receiver: String,
+    pub sender: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub token_data: Vec<String>,
+    pub token_ids: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub token_uris: Vec<String>,
+}
+
+impl NonFungibleTokenPacketData {
+    pub fn validate(&self) -> Result<()> {
+        if self.class_id.trim().is_empty() {
+            anyhow::bail!("class id cannot be empty");
+        }
+        if self.token_ids.is_empty() {
+            anyhow::bail!("token ids cannot be empty");
+        }
+        for token_id in &self.token_ids {
+            // token ids are the last component of a token's denom
+            if token_id.trim().is_empty() || token_id.contains('/') {
+                anyhow::bail!("invalid token id {}", token_id);
+            }
+        }
+        if !self.token_uris.is_empty() && self.token_uris.len() != self.token_ids.len() {
+            anyhow::bail!("token uris must be empty or match the number of token ids");
+        }
+        if !self.token_data.is_empty() && self.token_data.len() != self.token_ids.len() {
+            anyhow::bail!("token data must be empty or match the number of token ids");
+        }
+        if self.sender.trim().is_empty() {
+            anyhow::bail!("sender cannot be empty");
+        }
+        if self.receiver.trim().is_empty() {
+            anyhow::bail!("receiver cannot be empty");
+        }
+
+        Ok(())
+    }
+
+    /// Returns the ids, uris, and data of the tokens in the packet.
+    pub fn tokens(&self) -> impl Iterator<Item = (&str, &str, &str)> {
+        self.token_ids.iter().enumerate().map(|(i, id)| {
+            (
+                id.as_str(),
+                self.token_uris.get(i).map(String::as_str).unwrap_or(""),
+                self.token_data.get(i).map(String::as_str).unwrap_or(""),
+            )
+        })
+    }
+}
+
+/// The trace of a class of tokens: the ports and channels it was transferred
+/// over to reach Penumbra, and its id on the chain it originates from.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClassTrace {
+    /// A sequence of `{port}/{channel}` pairs, e.g. `nft-transfer/channel-0`.
+    pub path: String,
+    pub base_class_id: String,
+}
+
+impl ClassTrace {
+    pub fn parse(class_id: &str) -> Self {
+        let components: Vec<&str> = class_id.split('/').collect();
+        // traces are pairs of ports and channels, followed by the base class id, which may
+        // itself contain slashes.
+        let mut path_len = 0;
+        while path_len + 2 < components.len() && components[path_len + 1].starts_with("channel-") {
+            path_len += 2;
+        }
+
+        Self {
+            path: components[..path_len].join("/"),
+            base_class_id: components[path_len..].join("/"),
+        }
+    }
+
+    pub fn class_id(&self) -> String {
+        if self.path.is_empty() {
+            self.base_class_id.clone()
+        } else {
+            format!("{}/{}", self.path, self.base_class_id)
+        }
+    }
+}
+
+/// A class of tokens received over IBC.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Class {
+    pub trace: ClassTrace,
+    pub uri: String,
+    pub data: String,
+}
+
+/// A token received over IBC.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Token {
+    pub uri: String,
+    pub data: String,
+}
+
+// returns whether the class was issued on the chain at the other end of `source_port` and
+// `source_channel`, i.e. whether the tokens are returning home on that chain.
+fn is_returning(source_port: &PortId, source_channel: &ChannelId, class_id: &str) -> bool {
+    class_id.starts_with(&format!("{source_port}/{source_channel}/"))
+}
+
+#[async_trait]
+pub trait Ics721TransferReadExt: StateRead {
+    async fn nft_class(&self, class_id: &str) -> Result<Option<Class>> {
+        self.get_raw(&state_key::ics721::class(class_id))
+            .await?
+            .map(|bytes| serde_json::from_slice(&bytes).map_err(Into::into))
+            .transpose()
+    }
+
+    async fn nft_token(&self, class_id: &str, token_id: &str) -> Result<Option<Token>> {
+        self.get_raw(&state_key::ics721::token(class_id, token_id))
+            .await?
+            .map(|bytes| serde_json::from_slice(&bytes).map_err(Into::into))
+            .transpose()
+    }
+
+    async fn is_nft_escrowed(
+        &self,
+        channel_id: &ChannelId,
+        class_id: &str,
+        token_id: &str,
+    ) -> Result<bool> {
+        Ok(self
+            .get_raw(&state_key::ics721::escrow(channel_id, class_id, token_id))
+            .await?
+            .is_some())
+    }
+
+    async fn nft_withdrawal_check(
+        &self,
+        withdrawal: &NftWithdrawal,
+        current_block_time: Time,
+    ) -> Result<()> {
+        withdrawal.validate()?;
+        let packet = withdrawal_packet(self, withdrawal).await?;
+        self.send_packet_check(packet, current_block_time).await?;
+
+        Ok(())
+    }
+}
+
+impl<T: StateRead + ?Sized> Ics721TransferReadExt for T {}
+
+#[async_trait]
+pub trait Ics721TransferWriteExt: StateWrite {
+    fn put_nft_class(&mut self, class: &Class) {
+        self.put_raw(
+            state_key::ics721::class(&class.trace.class_id()),
+            serde_json::to_vec(class).expect("classes serialize to JSON"),
+        );
+    }
+
+    fn put_nft_token(&mut self, class_id: &str, token_id: &str, token: &Token) {
+        self.put_raw(
+            state_key::ics721::token(class_id, token_id),
+            serde_json::to_vec(token).expect("tokens serialize to JSON"),
+        );
+    }
+
+    fn put_nft_escrow(&mut self, channel_id: &ChannelId, class_id: &str, token_id: &str) {
+        self.put_raw(
+            state_key::ics721::escrow(channel_id, class_id, token_id),
+            Vec::new(),
+        );
+    }
+
+    fn delete_nft_escrow(&mut self, channel_id: &ChannelId, class_id: &str, token_id: &str) {
+        self.delete(state_key::ics721::escrow(channel_id, class_id, token_id));
+    }
+
+    async fn nft_withdrawal_execute(&mut self, withdrawal: &NftWithdrawal) -> Result<()> {
+        // assume `nft_withdrawal_check` has been called.
+        let packet = withdrawal_packet(&*self, withdrawal)
+            .await?
+            .assume_checked();
+
+        // tokens leaving their home chain are escrowed, to be released when they return. vouchers
+        // returning home are burned, by the value balance of the withdrawal.
+        if !is_returning(
+            &nft_transfer_port(),
+            &withdrawal.source_channel,
+            &withdrawal.class_id,
+        ) {
+            for token_id in &withdrawal.token_ids {
+                self.put_nft_escrow(&withdrawal.source_channel, &withdrawal.class_id, token_id);
+            }
+        }
+
+        self.send_packet_execute(packet).await;
+
+        Ok(())
+    }
+}
+
+impl<T: StateWrite + ?Sized> Ics721TransferWriteExt for T {}
+
+/// Builds the packet for this withdrawal, including the metadata recorded
+/// for the class and tokens when they were received.
+async fn withdrawal_packet<S: StateRead + ?Sized>(
+    state: &S,
+    withdrawal: &NftWithdrawal,
+) -> Result<IBCPacket<Unchecked>> {
+    let class = state.nft_class(&withdrawal.class_id).await?;
+    let mut packet_data = NonFungibleTokenPacketData {
+        class_id: withdrawal.class_id.clone(),
+        class_uri: class.as_ref().map(|c| c.uri.clone()).unwrap_or_default(),
+        class_data: class.map(|c| c.data).unwrap_or_default(),
+        sender: withdrawal.return_address.to_string(),
+        receiver: withdrawal.destination_chain_address.clone(),
+        memo: withdrawal.memo.clone(),
+        ..Default::default()
+    };
+
+    let mut tokens = Vec::with_capacity(withdrawal.token_ids.len());
+    for token_id in &withdrawal.token_ids {
+        tokens.push(
+            state
+                .nft_token(&withdrawal.class_id, token_id)
+                .await?
+                .unwrap_or_default(),
+        );
+    }
+    packet_data.token_ids = withdrawal.token_ids.clone();
+    if tokens.iter().any(|token| !token.uri.is_empty()) {
+        packet_data.token_uris = tokens.iter().map(|token| token.uri.clone()).collect();
+    }
+    if tokens.iter().any(|token| !token.data.is_empty()) {
+        packet_data.token_data = tokens.into_iter().map(|token| token.data).collect();
+    }
+    packet_data.validate()?;
+
+    Ok(IBCPacket::new(
+        nft_transfer_port(),
+        withdrawal.source_channel.clone(),
+        withdrawal.timeout_height,
+        withdrawal.timeout_time,
+        serde_json::to_vec(&packet_data).expect("can serialize packet data as JSON"),
+    ))
+}
+
+/// An app handler that binds the ICS-721 transfer application to
+/// [`NFT_TRANSFER_PORT`], and routes every other port to `AH`.
+pub struct WithNftTransfer<AH> {
+    _marker: PhantomData<AH>,
+}
+
+fn check_version(version: &Version) -> Result<()> {
+    if *version != Version::new(ICS721_VERSION.to_string()) {
+        anyhow::bail!(
+            "channel version must be {} for ICS721 transfer",
+            ICS721_VERSION
+        );
+    }
+
+    Ok(())
+}
+
+#[async_trait]
+impl<AH: AppHandlerCheck> AppHandlerCheck for WithNftTransfer<AH> {
+    fn is_bound_port(port_id: &PortId) -> bool {
+        *port_id == nft_transfer_port() || AH::is_bound_port(port_id)
+    }
+
+    fn pending_ack_sentinel(port_id: &PortId, version: &Version) -> Option<Vec<u8>> {
+        if *port_id == nft_transfer_port() {
+            return None;
+        }
+        AH::pending_ack_sentinel(port_id, version)
+    }
+
+    async fn chan_open_init_check<S: StateRead>(state: S, msg: &MsgChannelOpenInit) -> Result<()> {
+        if msg.port_id_on_a != nft_transfer_port() {
+            return AH::chan_open_init_check(state, msg).await;
+        }
+
+        if msg.ordering != ChannelOrder::Unordered {
+            anyhow::bail!("channel order must be unordered for ICS721 transfer");
+        }
+        check_version(&msg.version_proposal)
+    }
+
+    async fn chan_open_try_check<S: StateRead>(state: S, msg: &MsgChannelOpenTry) -> Result<()> {
+        if msg.port_id_on_b != nft_transfer_port() {
+            return AH::chan_open_try_check(state, msg).await;
+        }
+
+        if msg.ordering != ChannelOrder::Unordered {
+            anyhow::bail!("channel order must be unordered for ICS721 transfer");
+        }
+        check_version(&msg.version_supported_on_a)
+    }
+
+    async fn chan_open_ack_check<S: StateRead>(state: S, msg: &MsgChannelOpenAck) -> Result<()> {
+        if msg.port_id_on_a != nft_transfer_port() {
+            return AH::chan_open_ack_check(state, msg).await;
+        }
+
+        check_version(&msg.version_on_b)
+    }
+
+    async fn chan_open_confirm_check<S: StateRead>(
+        state: S,
+        msg: &MsgChannelOpenConfirm,
+    ) -> Result<()> {
+        if msg.port_id_on_b != nft_transfer_port() {
+            return AH::chan_open_confirm_check(state, msg).await;
+        }
+
+        Ok(())
+    }
+
+    async fn chan_close_confirm_check<S: StateRead>(
+        state: S,
+        msg: &MsgChannelCloseConfirm,
+    ) -> Result<()> {
+        if msg.port_id_on_b != nft_transfer_port() {
+            return AH::chan_close_confirm_check(state, msg).await;
+        }
+
+        Ok(())
+    }
+
+    async fn chan_close_init_check<S: StateRead>(
+        state: S,
+        msg: &MsgChannelCloseInit,
+    ) -> Result<()> {
+        if msg.port_id_on_a != nft_transfer_port() {
+            return AH::chan_close_init_check(state, msg).await;
+        }
+
+        anyhow::bail!("ics721 always aborts on close init");
+    }
+
+    async fn recv_packet_check<S: StateRead>(state: S, msg: &MsgRecvPacket) -> Result<()> {
+        if msg.packet.port_on_b != nft_transfer_port() {
+            return AH::recv_packet_check(state, msg).await;
+        }
+
+        // all checks on recv_packet done in execute
+        Ok(())
+    }
+
+    async fn timeout_packet_check<S: StateRead>(state: S, msg: &MsgTimeout) -> Result<()> {
+        if msg.packet.port_on_a != nft_transfer_port() {
+            return AH::timeout_packet_check(state, msg).await;
+        }
+
+        let packet_data: NonFungibleTokenPacketData = serde_json::from_slice(&msg.packet.data)?;
+        packet_data.validate()
+    }
+
+    async fn acknowledge_packet_check<S: StateRead>(
+        state: S,
+        msg: &MsgAcknowledgement,
+    ) -> Result<()> {
+        if msg.packet.port_on_a != nft_transfer_port() {
+            return AH::acknowledge_packet_check(state, msg).await;
+        }
+
+        Ok(())
+    }
+}
+
+// the main entry point for ICS721 transfer packet handling
+async fn recv_nft_packet_inner<S: StateWrite>(mut state: S, msg: &MsgRecvPacket) -> Result<()> {
+    let packet_data: NonFungibleTokenPacketData =
+        serde_json::from_slice(msg.packet.data.as_slice())
+            .context("failed to decode ICS721 packet data")?;
+    packet_data.validate()?;
+    let receiver = Address::from_str(&packet_data.receiver)?;
+
+    // the source records the packet the tokens arrived in, as for ICS20 transfers.
+    let source = CommitmentSource::Ics20Transfer {
+        packet_seq: msg.packet.sequence.0,
+        channel_id: msg.packet.chan_on_a.0.clone(),
+        sender: packet_data.sender.clone(),
+        encrypted_memo: Vec::new(),
+    };
+
+    if is_returning(
+        &msg.packet.port_on_a,
+        &msg.packet.chan_on_a,
+        &packet_data.class_id,
+    ) {
+        // the tokens originate here, so release them from escrow.
+        let prefix = format!("{}/{}/", msg.packet.port_on_a, msg.packet.chan_on_a);
+        let class_id = packet_data
+            .class_id
+            .strip_prefix(&prefix)
+            .expect("class id has the source prefix");
+
+        for (token_id, _, _) in packet_data.tokens() {
+            if !state
+                .is_nft_escrowed(&msg.packet.chan_on_b, class_id, token_id)
+                .await?
+            {
+                anyhow::bail!("token {}/{} is not escrowed", class_id, token_id);
+            }
+            state.delete_nft_escrow(&msg.packet.chan_on_b, class_id, token_id);
+
+            let denom = token_denom(class_id, token_id);
+            state
+                .mint_note(denom.value(Amount::from(1u64)), &receiver, source.clone())
+                .await
+                .context("unable to mint note when receiving ics721 transfer packet")?;
+        }
+    } else {
+        // the tokens originate elsewhere, so mint vouchers for them, recording the class and
+        // token metadata so it can be sent along if they leave again.
+        let class_id = format!(
+            "{}/{}/{}",
+            msg.packet.port_on_b, msg.packet.chan_on_b, packet_data.class_id
+        );
+        if state.nft_class(&class_id).await?.is_none() {
+            state.put_nft_class(&Class {
+                trace: ClassTrace::parse(&class_id),
+                uri: packet_data.class_uri.clone(),
+                data: packet_data.class_data.clone(),
+            });
+        }
+
+        for (token_id, uri, data) in packet_data.tokens() {
+            state.put_nft_token(
+                &class_id,
+                token_id,
+                &Token {
+                    uri: uri.to_string(),
+                    data: data.to_string(),
+                },
+            );
+
+            let denom = token_denom(&class_id, token_id);
+            state.register_denom(&denom).await;
+            state
+                .mint_note(denom.value(Amount::from(1u64)), &receiver, source.clone())
+                .await
+                .context("unable to mint note when receiving ics721 transfer packet")?;
+        }
+    }
+
+    Ok(())
+}
+
+async fn refund_nfts<S: StateWrite>(mut state: S, packet: &Packet) -> Result<()> {
+    let packet_data: NonFungibleTokenPacketData = serde_json::from_slice(packet.data.as_slice())?;
+    // the sender of the packet is the penumbra address that withdrew the tokens
+    let receiver = Address::from_str(&packet_data.sender)
+        .context("couldn't decode receiver address in ics721 refund")?;
+    let source = CommitmentSource::Ics20Transfer {
+        packet_seq: packet.sequence.0,
+        channel_id: packet.chan_on_a.0.clone(),
+        sender: packet_data.sender.clone(),
+        encrypted_memo: Vec::new(),
+    };
+
+    let escrowed = !is_returning(&packet.port_on_a, &packet.chan_on_a, &packet_data.class_id);
+    for (token_id, _, _) in packet_data.tokens() {
+        if escrowed {
+            state.delete_nft_escrow(&packet.chan_on_a, &packet_data.class_id, token_id);
+        }
+
+        // escrowed tokens are released, and burned vouchers are minted again.
+        let denom = token_denom(&packet_data.class_id, token_id);
+        state
+            .mint_note(denom.value(Amount::from(1u64)), &receiver, source.clone())
+            .await
+            .context("couldn't mint note in ics721 refund")?;
+    }
+
+    Ok(())
+}
+
+#[async_trait]
+impl<AH: AppHandlerExecute> AppHandlerExecute for WithNftTransfer<AH> {
+    async fn chan_open_init_execute<S: StateWrite>(
+        state: S,
+        msg: &MsgChannelOpenInit,
+    ) -> Result<()> {
+        if msg.port_id_on_a != nft_transfer_port() {
+            AH::chan_open_init_execute(state, msg).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn chan_open_try_execute<S: StateWrite>(state: S, msg: &MsgChannelOpenTry) -> Result<()> {
+        if msg.port_id_on_b != nft_transfer_port() {
+            AH::chan_open_try_execute(state, msg).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn chan_open_ack_execute<S: StateWrite>(state: S, msg: &MsgChannelOpenAck) -> Result<()> {
+        if msg.port_id_on_a != nft_transfer_port() {
+            AH::chan_open_ack_execute(state, msg).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn chan_open_confirm_execute<S: StateWrite>(
+        state: S,
+        msg: &MsgChannelOpenConfirm,
+    ) -> Result<()> {
+        if msg.port_id_on_b != nft_transfer_port() {
+            AH::chan_open_confirm_execute(state, msg).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn chan_close_confirm_execute<S: StateWrite>(
+        state: S,
+        msg: &MsgChannelCloseConfirm,
+    ) -> Result<()> {
+        if msg.port_id_on_b != nft_transfer_port() {
+            AH::chan_close_confirm_execute(state, msg).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn chan_close_init_execute<S: StateWrite>(
+        state: S,
+        msg: &MsgChannelCloseInit,
+    ) -> Result<()> {
+        if msg.port_id_on_a != nft_transfer_port() {
+            AH::chan_close_init_execute(state, msg).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn recv_packet_execute<S: StateWrite>(mut state: S, msg: &MsgRecvPacket) -> Result<()> {
+        if msg.packet.port_on_b != nft_transfer_port() {
+            return AH::recv_packet_execute(state, msg).await;
+        }
+
+        // tokens are received atomically: if any of them can't be, none are, and an error
+        // acknowledgement is written.
+        let mut recv = StateDelta::new(&mut state);
+        let ack: Vec<u8> = match recv_nft_packet_inner(&mut recv, msg).await {
+            Ok(()) => {
+                let (state, events) = recv.apply();
+                for event in events {
+                    state.record(event);
+                }
+                TokenTransferAcknowledgement::success().into()
+            }
+            Err(e) => {
+                drop(recv);
+                tracing::debug!("couldnt execute nft transfer: {:#}", e);
+                TokenTransferAcknowledgement::Error(e.to_string()).into()
+            }
+        };
+
+        state
+            .write_acknowledgement(&msg.packet, &ack)
+            .await
+            .context("able to write acknowledgement")?;
+
+        Ok(())
+    }
+
+    async fn timeout_packet_execute<S: StateWrite>(mut state: S, msg: &MsgTimeout) -> Result<()> {
+        if msg.packet.port_on_a != nft_transfer_port() {
+            return AH::timeout_packet_execute(state, msg).await;
+        }
+
+        refund_nfts(&mut state, &msg.packet)
+            .await
+            .context("able to timeout packet")
+    }
+
+    async fn on_acknowledgement<S: StateWrite>(
+        mut state: S,
+        msg: &MsgAcknowledgement,
+        ack: &AcknowledgementResult,
+    ) -> Result<()> {
+        if msg.packet.port_on_a != nft_transfer_port() {
+            return AH::on_acknowledgement(state, msg, ack).await;
+        }
+
+        if !ack.is_success() {
+            refund_nfts(&mut state, &msg.packet)
+                .await
+                .context("unable to refund packet acknowledgement")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<AH: AppHandler> AppHandler for WithNftTransfer<AH> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ibc_types::core::{
+        channel::{
+            channel::{Counterparty, State as ChannelState},
+            ChannelEnd,
+        },
+        client::Height,
+        connection::ConnectionId,
+    };
+    use penumbra_ibc::component::{ChannelStateReadExt as _, ChannelStateWriteExt as _};
+
+    #[test]
+    fn packet_data_matches_ibc_go_encoding() {
+        let json = r#"{"classId":"nft-transfer/channel-4/kitties","classUri":"https://kitties.example","receiver":"penumbra1xyz","sender":"cosmos1abc","tokenIds":["kitty1","kitty2"],"tokenUris":["https://kitties.example/1","https://kitties.example/2"]}"#;
+
+        let packet_data: NonFungibleTokenPacketData = serde_json::from_str(json).unwrap();
+        packet_data.validate().unwrap();
+
+        assert_eq!(packet_data.class_id, "nft-transfer/channel-4/kitties");
+        assert_eq!(
+            packet_data.tokens().nth(1),
+            Some(("kitty2", "https://kitties.example/2", ""))
+        );
+        assert_eq!(serde_json::to_string(&packet_data).unwrap(), json);
+    }
+
+    #[test]
+    fn class_traces_split_path_from_base_class() {
+        let trace = ClassTrace::parse("nft-transfer/channel-1/nft-transfer/channel-0/a/b");
+
+        assert_eq!(trace.path, "nft-transfer/channel-1/nft-transfer/channel-0");
+        assert_eq!(trace.base_class_id, "a/b");
+        assert_eq!(
+            trace.class_id(),
+            "nft-transfer/channel-1/nft-transfer/channel-0/a/b"
+        );
+
+        let native = ClassTrace::parse("kitties");
+        assert_eq!(native.path, "");
+        assert_eq!(native.class_id(), "kitties");
+    }
+
+    #[test]
+    fn token_ids_cannot_contain_slashes() {
+        let packet_data = NonFungibleTokenPacketData {
+            class_id: "kitties".to_string(),
+            token_ids: vec!["a/b".to_string()],
+            sender: "sender".to_string(),
+            receiver: "receiver".to_string(),
+            ..Default::default()
+        };
+
+        assert!(packet_data.validate().is_err());
+    }
+
+    #[tokio::test]
+    async fn withdrawals_escrow_tokens_leaving_their_home_chain() -> Result<()> {
+        let mut state = StateDelta::new(());
+        let (port_id, channel_id) = (nft_transfer_port(), ChannelId::new(0));
+        state.put_channel(
+            &channel_id,
+            &port_id,
+            ChannelEnd {
+                state: ChannelState::Open,
+                ordering: ChannelOrder::Unordered,
+                remote: Counterparty::new(port_id.clone(), Some(ChannelId::new(5))),
+                connection_hops: vec![ConnectionId::new(0)],
+                version: Version::new(ICS721_VERSION.to_string()),
+            },
+        );
+        state.put_send_sequence(&channel_id, &port_id, 1);
+
+        let timeout_height = Height::new(0, 100)?;
+        let withdrawal = |class_id: &str| NftWithdrawal {
+            class_id: class_id.to_string(),
+            token_ids: vec!["kitty1".to_string()],
+            destination_chain_address: "cosmos1abc".to_string(),
+            return_address: penumbra_keys::test_keys::ADDRESS_0.clone(),
+            timeout_height,
+            timeout_time: 60_000_000_000,
+            source_channel: channel_id.clone(),
+            memo: String::new(),
+        };
+
+        // penumbra-native tokens are escrowed until they return.
+        let native = withdrawal("kitties");
+        native.validate()?;
+        state.nft_withdrawal_execute(&native).await?;
+        assert!(
+            state
+                .is_nft_escrowed(&channel_id, "kitties", "kitty1")
+                .await?
+        );
+        assert_eq!(state.get_send_sequence(&channel_id, &port_id).await?, 2);
+
+        // vouchers returning home are burned by the withdrawal's balance instead.
+        let returning_class = "nft-transfer/channel-0/kitties";
+        state
+            .nft_withdrawal_execute(&withdrawal(returning_class))
+            .await?;
+        assert!(
+            !state
+                .is_nft_escrowed(&channel_id, returning_class, "kitty1")
+                .await?
+        );
+        assert_eq!(state.get_send_sequence(&channel_id, &port_id).await?, 3);
+
+        Ok(())
+    }
+}
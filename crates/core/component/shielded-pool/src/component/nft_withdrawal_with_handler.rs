@@ -0,0 +1,31 @@
+use crate::NftWithdrawal;
+use penumbra_ibc::component::HostInterface;
+use std::marker::PhantomData;
+
+pub struct NftWithdrawalWithHandler<HI>(NftWithdrawal, PhantomData<HI>);
+
+impl<HI> NftWithdrawalWithHandler<HI> {
+    pub fn new(action: NftWithdrawal) -> Self {
+        Self(action, PhantomData)
+    }
+
+    pub fn action(&self) -> &NftWithdrawal {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> NftWithdrawal {
+        self.0
+    }
+}
+
+impl<HI> From<NftWithdrawalWithHandler<HI>> for NftWithdrawal {
+    fn from(value: NftWithdrawalWithHandler<HI>) -> Self {
+        value.0
+    }
+}
+
+impl NftWithdrawal {
+    pub fn with_handler<HI: HostInterface>(self) -> NftWithdrawalWithHandler<HI> {
+        NftWithdrawalWithHandler::new(self)
+    }
+}
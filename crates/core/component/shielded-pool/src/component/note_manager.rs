@@ -29,6 +29,16 @@ pub trait NoteManager: StateWrite {
     ) -> Result<()> {
         tracing::debug!(?value, ?address, "minting tokens");
 
+        let note = self.next_minted_note(value, address).await?;
+        self.add_note_payload(note.payload(), source).await;
+
+        Ok(())
+    }
+
+    /// Builds the note that [`mint_note`](Self::mint_note) would mint next,
+    /// for callers that need the note itself before adding it with
+    /// [`add_note_payload`](Self::add_note_payload).
+    async fn next_minted_note(&self, value: Value, address: &Address) -> Result<Note> {
         // These notes are public, so we don't need a blinding factor for
         // privacy, but since the note commitments are determined by the note
         // contents, we need to have unique (deterministic) blinding factors for
@@ -52,10 +62,11 @@ pub trait NoteManager: StateWrite {
             .as_bytes()[0..32]
             .try_into()?;
 
-        let note = Note::from_parts(address.clone(), value, Rseed(rseed_bytes))?;
-        self.add_note_payload(note.payload(), source).await;
-
-        Ok(())
+        Ok(Note::from_parts(
+            address.clone(),
+            value,
+            Rseed(rseed_bytes),
+        )?)
     }
 
     #[instrument(skip(self, note_payload, source), fields(commitment = ?note_payload.note_commitment))]
@@ -1,6 +1,7 @@
 use std::pin::Pin;
 
 use cnidarium::Storage;
+use futures::StreamExt as _;
 use penumbra_asset::asset::{self};
 use penumbra_proto::core::component::shielded_pool::v1::{
     query_service_server::QueryService, AssetMetadataByIdRequest, AssetMetadataByIdResponse,
@@ -64,10 +65,35 @@ impl QueryService for Server {
         Ok(tonic::Response::new(rsp))
     }
 
+    #[instrument(skip(self, request))]
     async fn asset_metadata_by_ids(
         &self,
-        _request: tonic::Request<AssetMetadataByIdsRequest>,
+        request: tonic::Request<AssetMetadataByIdsRequest>,
     ) -> Result<tonic::Response<Self::AssetMetadataByIdsStream>, tonic::Status> {
-        unimplemented!("asset_metadata_by_ids not yet implemented")
+        let state = self.storage.latest_snapshot();
+
+        let ids = request
+            .into_inner()
+            .asset_id
+            .into_iter()
+            .map(asset::Id::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| Status::invalid_argument(format!("could not parse asset_id: {e}")))?;
+
+        // Unknown assets are skipped, rather than failing the whole request.
+        let mut responses = Vec::with_capacity(ids.len());
+        for id in ids {
+            if let Some(denom_metadata) = state.denom_metadata_by_asset(&id).await {
+                responses.push(Ok(AssetMetadataByIdsResponse {
+                    denom_metadata: Some(denom_metadata.into()),
+                }));
+            } else {
+                tracing::debug!(?id, "unknown asset id");
+            }
+        }
+
+        Ok(tonic::Response::new(
+            futures::stream::iter(responses).boxed(),
+        ))
     }
 }
@@ -3,6 +3,7 @@ use std::sync::Arc;
 use super::fmd::ClueManagerInternal as _;
 use crate::fmd::should_update_fmd_params;
 use crate::params::ShieldedPoolParameters;
+use crate::supply::SupplyChange;
 use crate::{fmd, genesis, state_key};
 use anyhow::anyhow;
 use anyhow::Result;
@@ -15,7 +16,7 @@ use penumbra_sct::CommitmentSource;
 use tendermint::v0_37::abci;
 use tracing::instrument;
 
-use super::{AssetRegistry, NoteManager};
+use super::{AssetRegistry, AssetRegistryRead as _, NoteManager, SupplyManager};
 
 pub struct ShieldedPool {}
 
@@ -34,6 +35,12 @@ impl Component for ShieldedPool {
                 state.put_current_fmd_parameters(fmd::Parameters::default());
                 state.put_previous_fmd_parameters(fmd::Parameters::default());
 
+                // Register the metadata given at genesis first, so that it isn't shadowed by
+                // the default metadata of the allocated denoms.
+                for denom in &genesis.denom_metadata {
+                    state.update_denom_metadata(denom).await;
+                }
+
                 // Register a denom for each asset in the genesis state
                 for allocation in &genesis.allocations {
                     tracing::debug!(?allocation, "processing allocation");
@@ -52,11 +59,23 @@ impl Component for ShieldedPool {
                         )
                         .await
                         .expect("able to mint note for genesis allocation");
+                    state.record_supply_change(allocation.value(), SupplyChange::Genesis);
                 }
             }
         }
     }
 
+    #[instrument(name = "shielded_pool", skip(state))]
+    async fn export_genesis<S: StateRead>(state: &S) -> Result<Self::AppState> {
+        // Notes are private to their holders, so the allocations that minted
+        // them can't be recovered from the state.
+        Ok(genesis::Content {
+            allocations: Vec::new(),
+            shielded_pool_params: state.get_shielded_pool_params().await?,
+            denom_metadata: state.all_denom_metadata().await?,
+        })
+    }
+
     #[instrument(name = "shielded_pool", skip(_state, _begin_block))]
     async fn begin_block<S: StateWrite + 'static>(
         _state: &mut Arc<S>,
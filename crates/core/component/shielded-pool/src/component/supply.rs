@@ -0,0 +1,82 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use futures::{StreamExt, TryStreamExt};
+use penumbra_asset::{asset, Value};
+use penumbra_proto::{StateReadProto, StateWriteProto};
+
+use crate::state_key;
+use crate::supply::{AssetSupply, SupplyChange};
+
+/// Reads the changes the chain has made to the supply of assets.
+#[async_trait]
+pub trait SupplyRead: StateRead {
+    /// The changes made to the supply of `asset_id`, up to the end of the last block.
+    async fn asset_supply(&self, asset_id: &asset::Id) -> Result<AssetSupply> {
+        Ok(self
+            .get(&state_key::supply::by_asset_id(asset_id))
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// The changes made to the supply of every asset whose supply the chain has changed.
+    async fn all_asset_supplies(&self) -> Result<BTreeMap<asset::Id, AssetSupply>> {
+        self.prefix(state_key::supply::prefix())
+            .map(|result| {
+                let (key, supply) = result?;
+                let asset_id = key
+                    .rsplit('/')
+                    .next()
+                    .expect("key is well-formed")
+                    .parse()?;
+                Ok((asset_id, supply))
+            })
+            .try_collect()
+            .await
+    }
+}
+
+impl<T: StateRead + ?Sized> SupplyRead for T {}
+
+/// Tracks the changes the chain makes to the supply of assets.
+///
+/// Changes are accumulated during a block, and added to the totals of each
+/// asset once it ends, so that auditors can check the supply of an asset
+/// without replaying the chain.
+#[async_trait]
+pub trait SupplyManager: StateWrite {
+    /// Records a change to the supply of the asset of `value`.
+    fn record_supply_change(&mut self, value: Value, change: SupplyChange) {
+        let mut pending: BTreeMap<asset::Id, AssetSupply> = self
+            .object_get(state_key::pending_supply_changes())
+            .unwrap_or_default();
+        pending
+            .entry(value.asset_id)
+            .or_default()
+            .record(change, value.amount);
+        self.object_put(state_key::pending_supply_changes(), pending);
+    }
+
+    /// Adds the changes recorded during the block to the totals of each asset.
+    ///
+    /// This must run after everything that changes the supply of assets in a
+    /// block, including the end of an epoch.
+    async fn flush_supply_changes(&mut self) -> Result<()> {
+        let pending: BTreeMap<asset::Id, AssetSupply> = self
+            .object_get(state_key::pending_supply_changes())
+            .unwrap_or_default();
+        self.object_delete(state_key::pending_supply_changes());
+
+        for (asset_id, changes) in pending {
+            let mut supply = self.asset_supply(&asset_id).await?;
+            supply.merge(&changes);
+            self.put(state_key::supply::by_asset_id(&asset_id), supply);
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: StateWrite + ?Sized> SupplyManager for T {}
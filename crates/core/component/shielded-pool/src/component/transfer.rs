@@ -1,13 +1,15 @@
+use std::marker::PhantomData;
 use std::str::FromStr;
 
 use crate::{
-    component::{AssetRegistry, NoteManager},
+    component::{AssetRegistry, NoteManager, SupplyManager},
     event::{self, FungibleTokenTransferPacketMetadata},
+    supply::SupplyChange,
     Ics20Withdrawal,
 };
 use anyhow::{Context, Result};
 use async_trait::async_trait;
-use cnidarium::{StateRead, StateWrite};
+use cnidarium::{StateDelta, StateRead, StateWrite};
 use ibc_types::core::channel::Packet;
 use ibc_types::{
     core::channel::{
@@ -23,16 +25,19 @@ use ibc_types::{
 };
 use penumbra_asset::{asset, asset::Metadata, Value};
 use penumbra_ibc::component::ChannelStateReadExt;
+use penumbra_ibc::packet_fee::PacketFee;
+use penumbra_ibc::StateReadExt as _;
 use penumbra_keys::Address;
 use penumbra_num::Amount;
 use penumbra_proto::{
     penumbra::core::component::ibc::v1::FungibleTokenPacketData, DomainType as _, StateReadProto,
     StateWriteProto,
 };
-use penumbra_sct::CommitmentSource;
+use penumbra_sct::{component::source::SourceContext as _, CommitmentSource};
 
 use penumbra_ibc::component::{
-    app_handler::{AppHandler, AppHandlerCheck, AppHandlerExecute},
+    app_handler::{AcknowledgementResult, AppHandler, AppHandlerCheck, AppHandlerExecute},
+    fees::{self, FeePayout, StateWriteExt as _},
     packet::{
         IBCPacket, SendPacketRead as _, SendPacketWrite as _, Unchecked, WriteAcknowledgement as _,
     },
@@ -40,6 +45,15 @@ use penumbra_ibc::component::{
 };
 use tendermint::Time;
 
+use memo::Memo;
+
+mod hooks;
+mod memo;
+mod packet_forward;
+mod rate_limit;
+
+pub use hooks::{Ics20Hooks, NoIcs20Hooks, ReceivedTransfer};
+
 // returns a bool indicating if the provided denom was issued locally or if it was bridged in.
 // this logic is a bit tricky, and adapted from https://github.com/cosmos/ibc/tree/main/spec/app/ics-020-fungible-token-transfer (sendFungibleTokens).
 //
@@ -67,8 +81,31 @@ fn is_source(
     }
 }
 
+/// The ICS20 transfer application, running `H` on the memos of inbound
+/// transfers.
+// checks an inbound transfer over `channel_id`, credited in `denom`, against the governance
+// allowlist and denylist of bridged assets.
+async fn check_inbound_allowed<S: StateRead>(
+    state: S,
+    channel_id: &ChannelId,
+    denom: &Metadata,
+) -> Result<()> {
+    let denom = denom.base_denom().denom;
+    if !state
+        .get_ibc_params()
+        .await?
+        .inbound_ics20_allowed(channel_id, &denom)
+    {
+        anyhow::bail!("inbound transfers of {denom} over {channel_id} are not allowed");
+    }
+
+    Ok(())
+}
+
 #[derive(Clone)]
-pub struct Ics20Transfer {}
+pub struct Ics20Transfer<H = NoIcs20Hooks> {
+    _marker: PhantomData<H>,
+}
 
 #[async_trait]
 pub trait Ics20TransferReadExt: StateRead {
@@ -80,6 +117,20 @@ pub trait Ics20TransferReadExt: StateRead {
         // create packet
         let packet: IBCPacket<Unchecked> = withdrawal.clone().into();
 
+        // relay fees can only be escrowed on channels that will pay them out
+        if withdrawal.relay_fee.is_some() {
+            let channel = self
+                .get_channel(&withdrawal.source_channel, &PortId::transfer())
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("source channel not found"))?;
+            if !fees::is_fee_enabled(&channel.version) {
+                anyhow::bail!(
+                    "channel {} does not support relay fees",
+                    withdrawal.source_channel
+                );
+            }
+        }
+
         // send packet
         self.send_packet_check(packet, current_block_time).await?;
 
@@ -95,6 +146,15 @@ pub trait Ics20TransferWriteExt: StateWrite {
         // create packet, assume it's already checked since the component caller contract calls `check` before `execute`
         let checked_packet = IBCPacket::<Unchecked>::from(withdrawal.clone()).assume_checked();
 
+        rate_limit::record_outflow(
+            self,
+            &withdrawal.source_channel,
+            &withdrawal.denom.id(),
+            withdrawal.amount,
+        )
+        .await?;
+        self.record_supply_change(withdrawal.value(), SupplyChange::IbcOutbound);
+
         let prefix = format!("transfer/{}/", &withdrawal.source_channel);
         if !withdrawal.denom.starts_with(&prefix) {
             // we are the source. add the value balance to the escrow channel.
@@ -194,6 +254,22 @@ pub trait Ics20TransferWriteExt: StateWrite {
             );
         }
 
+        if let Some(relay_fee) = &withdrawal.relay_fee {
+            // the packet will be sent with the channel's next send sequence
+            let sequence = self
+                .get_send_sequence(&withdrawal.source_channel, &checked_packet.source_port())
+                .await?;
+            self.put_packet_fee(
+                &checked_packet.source_port(),
+                &withdrawal.source_channel,
+                sequence,
+                PacketFee {
+                    fee: relay_fee.clone(),
+                    refund_address: withdrawal.return_address.to_string(),
+                },
+            );
+        }
+
         self.send_packet_execute(checked_packet).await;
 
         Ok(())
@@ -204,7 +280,7 @@ impl<T: StateWrite + ?Sized> Ics20TransferWriteExt for T {}
 
 // see: https://github.com/cosmos/ibc/tree/master/spec/app/ics-020-fungible-token-transfer
 #[async_trait]
-impl AppHandlerCheck for Ics20Transfer {
+impl<H: Ics20Hooks> AppHandlerCheck for Ics20Transfer<H> {
     async fn chan_open_init_check<S: StateRead>(_state: S, msg: &MsgChannelOpenInit) -> Result<()> {
         if msg.ordering != ChannelOrder::Unordered {
             anyhow::bail!("channel order must be unordered for Ics20 transfer");
@@ -304,7 +380,7 @@ impl AppHandlerCheck for Ics20Transfer {
 async fn recv_transfer_packet_inner<S: StateWrite>(
     mut state: S,
     msg: &MsgRecvPacket,
-) -> Result<()> {
+) -> Result<ReceivedTransfer> {
     // parse if we are source or dest, and mint or burn accordingly
     //
     // see this part of the spec for this logic:
@@ -328,7 +404,7 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
     // NOTE: here we assume we are chain A.
 
     // 2. check if we are the source chain for the denom.
-    if is_source(
+    let value = if is_source(
         &msg.packet.port_on_a,
         &msg.packet.chan_on_a,
         &packet_denom,
@@ -372,17 +448,16 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
             anyhow::bail!("transfer coins failed");
         }
 
-        state
-            .mint_note(
-                value,
-                &receiver_address,
-                CommitmentSource::Ics20Transfer {
-                    packet_seq: msg.packet.sequence.0,
-                    // We are chain A
-                    channel_id: msg.packet.chan_on_a.0.clone(),
-                    sender: packet_data.sender.clone(),
-                },
-            )
+        check_inbound_allowed(&state, &msg.packet.chan_on_b, &denom).await?;
+        rate_limit::record_inflow(
+            &mut state,
+            &msg.packet.chan_on_b,
+            &denom.id(),
+            receiver_amount,
+        )
+        .await?;
+
+        mint_received_note(&mut state, value, &receiver_address, msg, &packet_data)
             .await
             .context("unable to mint note when receiving ics20 transfer packet")?;
 
@@ -399,7 +474,7 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
             event::EventInboundFungibleTokenTransfer {
                 value,
                 sender: packet_data.sender.clone(),
-                receiver: receiver_address,
+                receiver: receiver_address.clone(),
                 meta: FungibleTokenTransferPacketMetadata {
                     channel: msg.packet.chan_on_a.0.clone(),
                     sequence: msg.packet.sequence.0,
@@ -407,6 +482,7 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
             }
             .to_proto(),
         );
+        value
     } else {
         // create new denom:
         //
@@ -423,6 +499,14 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
             .as_str()
             .try_into()
             .context("unable to parse denom in ics20 transfer as DenomMetadata")?;
+        check_inbound_allowed(&state, &msg.packet.chan_on_b, &denom).await?;
+        rate_limit::record_inflow(
+            &mut state,
+            &msg.packet.chan_on_b,
+            &denom.id(),
+            receiver_amount,
+        )
+        .await?;
         state.register_denom(&denom).await;
 
         let value = Value {
@@ -430,17 +514,7 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
             asset_id: denom.id(),
         };
 
-        state
-            .mint_note(
-                value,
-                &receiver_address,
-                CommitmentSource::Ics20Transfer {
-                    packet_seq: msg.packet.sequence.0,
-                    // We are chain A
-                    channel_id: msg.packet.chan_on_a.0.clone(),
-                    sender: packet_data.sender.clone(),
-                },
-            )
+        mint_received_note(&mut state, value, &receiver_address, msg, &packet_data)
             .await
             .context("failed to mint notes in ibc transfer")?;
 
@@ -462,7 +536,7 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
             event::EventInboundFungibleTokenTransfer {
                 value,
                 sender: packet_data.sender.clone(),
-                receiver: receiver_address,
+                receiver: receiver_address.clone(),
                 meta: FungibleTokenTransferPacketMetadata {
                     channel: msg.packet.chan_on_a.0.clone(),
                     sequence: msg.packet.sequence.0,
@@ -470,6 +544,68 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
             }
             .to_proto(),
         );
+        value
+    };
+    state.record_supply_change(value, SupplyChange::IbcInbound);
+
+    Ok(ReceivedTransfer {
+        channel_id: msg.packet.chan_on_b.clone(),
+        sequence: msg.packet.sequence.0,
+        sender: packet_data.sender,
+        receiver: receiver_address,
+        value,
+    })
+}
+
+// mints the note crediting an inbound transfer to its receiver. a memo meant for the receiver is
+// encrypted to them with the note's key, so wallets can show it alongside the note. the memo is
+// public in the packet anyway, but this way wallets don't need to find and parse the packet.
+async fn mint_received_note<S: StateWrite>(
+    mut state: S,
+    value: Value,
+    receiver: &Address,
+    msg: &MsgRecvPacket,
+    packet_data: &FungibleTokenPacketData,
+) -> Result<()> {
+    let note = state.next_minted_note(value, receiver).await?;
+    let encrypted_memo = match Memo::parse(&packet_data.memo)? {
+        Memo::Text(text) => note.encrypt_memo(&text),
+        Memo::Empty | Memo::Json(_) => Vec::new(),
+    };
+
+    state
+        .add_note_payload(
+            note.payload(),
+            CommitmentSource::Ics20Transfer {
+                packet_seq: msg.packet.sequence.0,
+                // We are chain A
+                channel_id: msg.packet.chan_on_a.0.clone(),
+                sender: packet_data.sender.clone(),
+                encrypted_memo,
+            },
+        )
+        .await;
+
+    Ok(())
+}
+
+// credits an inbound transfer, then runs the hooks for the instructions in its memo. this may
+// leave partial writes behind when it fails, so the caller must run it in a fork of the state.
+async fn recv_transfer_packet_with_hooks<H: Ics20Hooks, S: StateWrite>(
+    mut state: S,
+    msg: &MsgRecvPacket,
+) -> Result<()> {
+    let packet_data: FungibleTokenPacketData = serde_json::from_slice(msg.packet.data.as_slice())
+        .with_context(|| "failed to decode FTPD packet")?;
+    let memo = Memo::parse(&packet_data.memo)?;
+
+    let transfer = recv_transfer_packet_inner(&mut state, msg).await?;
+    for (key, args) in memo.instructions() {
+        if H::handles(key) {
+            H::on_recv(&mut state, key, args, &transfer)
+                .await
+                .with_context(|| format!("failed to run {key} hook"))?;
+        }
     }
 
     Ok(())
@@ -504,6 +640,8 @@ async fn refund_tokens<S: StateWrite>(
         asset_id: denom.id(),
     };
 
+    rate_limit::undo_outflow(&mut state, &packet.chan_on_a, &denom.id(), amount).await?;
+
     if is_source(&packet.port_on_a, &packet.chan_on_a, &denom, true) {
         // sender was source chain, unescrow tokens back to sender
         let value_balance: Amount = state
@@ -526,6 +664,7 @@ async fn refund_tokens<S: StateWrite>(
                     packet_seq: packet.sequence.0,
                     channel_id: packet.chan_on_a.0.clone(),
                     sender: packet_data.sender.clone(),
+                    encrypted_memo: Vec::new(),
                 },
             )
             .await
@@ -572,6 +711,7 @@ async fn refund_tokens<S: StateWrite>(
                     packet_seq: packet.sequence.0,
                     channel_id: packet.chan_on_a.0.clone(),
                     sender: packet_data.sender.clone(),
+                    encrypted_memo: Vec::new(),
                 },
             )
             .await
@@ -598,26 +738,82 @@ async fn refund_tokens<S: StateWrite>(
         );
     }
 
+    state.record_supply_change(value, SupplyChange::IbcInbound);
+
     Ok(())
 }
 
-// NOTE: should these be fallible, now that our enclosing state machine is fallible in execution?
 #[async_trait]
-impl AppHandlerExecute for Ics20Transfer {
-    async fn chan_open_init_execute<S: StateWrite>(_state: S, _msg: &MsgChannelOpenInit) {}
-    async fn chan_open_try_execute<S: StateWrite>(_state: S, _msg: &MsgChannelOpenTry) {}
-    async fn chan_open_ack_execute<S: StateWrite>(_state: S, _msg: &MsgChannelOpenAck) {}
-    async fn chan_open_confirm_execute<S: StateWrite>(_state: S, _msg: &MsgChannelOpenConfirm) {}
-    async fn chan_close_confirm_execute<S: StateWrite>(_state: S, _msg: &MsgChannelCloseConfirm) {}
-    async fn chan_close_init_execute<S: StateWrite>(_state: S, _msg: &MsgChannelCloseInit) {}
+impl<H: Ics20Hooks> AppHandlerExecute for Ics20Transfer<H> {
+    async fn chan_open_init_execute<S: StateWrite>(
+        _state: S,
+        _msg: &MsgChannelOpenInit,
+    ) -> Result<()> {
+        Ok(())
+    }
+    async fn chan_open_try_execute<S: StateWrite>(
+        _state: S,
+        _msg: &MsgChannelOpenTry,
+    ) -> Result<()> {
+        Ok(())
+    }
+    async fn chan_open_ack_execute<S: StateWrite>(
+        _state: S,
+        _msg: &MsgChannelOpenAck,
+    ) -> Result<()> {
+        Ok(())
+    }
+    async fn chan_open_confirm_execute<S: StateWrite>(
+        _state: S,
+        _msg: &MsgChannelOpenConfirm,
+    ) -> Result<()> {
+        Ok(())
+    }
+    async fn chan_close_confirm_execute<S: StateWrite>(
+        _state: S,
+        _msg: &MsgChannelCloseConfirm,
+    ) -> Result<()> {
+        Ok(())
+    }
+    async fn chan_close_init_execute<S: StateWrite>(
+        _state: S,
+        _msg: &MsgChannelCloseInit,
+    ) -> Result<()> {
+        Ok(())
+    }
     async fn recv_packet_execute<S: StateWrite>(mut state: S, msg: &MsgRecvPacket) -> Result<()> {
         // recv packet should never fail a transaction, but it should record a failure acknowledgement.
-        let ack: Vec<u8> = match recv_transfer_packet_inner(&mut state, msg).await {
-            Ok(_) => {
+        //
+        // the packet is received in a fork of the state, which is only applied if it succeeds: a
+        // failed transfer leaves nothing escrowed, minted or forwarded behind, only the error
+        // acknowledgement, so that the sender is refunded on the chain it was sent from.
+        let mut recv_tx = StateDelta::new(&mut state);
+        let received = match packet_forward::forward_metadata(&msg.packet) {
+            Ok(Some(forward)) => {
+                packet_forward::forward_transfer_packet(&mut recv_tx, &msg.packet, forward)
+                    .await
+                    .map(|()| true)
+            }
+            Ok(None) => recv_transfer_packet_with_hooks::<H, _>(&mut recv_tx, msg)
+                .await
+                .map(|()| false),
+            Err(e) => Err(e),
+        };
+        let ack: Vec<u8> = match received {
+            Ok(forwarded) => {
+                let (state, events) = recv_tx.apply();
+                for event in events {
+                    state.record(event);
+                }
+                if forwarded {
+                    // forwarded packets are acknowledged once the forward settles.
+                    return Ok(());
+                }
                 // record packet acknowledgement without error
                 TokenTransferAcknowledgement::success().into()
             }
             Err(e) => {
+                drop(recv_tx);
                 tracing::debug!("couldnt execute transfer: {:#}", e);
                 // record packet acknowledgement with error
                 TokenTransferAcknowledgement::Error(e.to_string()).into()
@@ -633,6 +829,19 @@ impl AppHandlerExecute for Ics20Transfer {
     }
 
     async fn timeout_packet_execute<S: StateWrite>(mut state: S, msg: &MsgTimeout) -> Result<()> {
+        if packet_forward::settle_forward(
+            &mut state,
+            &msg.packet.chan_on_a,
+            msg.packet.sequence.0,
+            packet_forward::Settlement::TimedOut,
+        )
+        .await
+        .context("unable to settle forwarded packet")?
+        {
+            // forwarded tokens are refunded on the chain they were forwarded from.
+            return Ok(());
+        }
+
         // timeouts may fail due to counterparty chains sending transfers of u128-1
         refund_tokens(
             &mut state,
@@ -645,13 +854,32 @@ impl AppHandlerExecute for Ics20Transfer {
         Ok(())
     }
 
-    async fn acknowledge_packet_execute<S: StateWrite>(
+    async fn on_acknowledgement<S: StateWrite>(
         mut state: S,
         msg: &MsgAcknowledgement,
+        ack: &AcknowledgementResult,
     ) -> Result<()> {
-        let ack: TokenTransferAcknowledgement =
-            serde_json::from_slice(msg.acknowledgement.as_slice())?;
-        if !ack.is_successful() {
+        let settlement = match ack {
+            AcknowledgementResult::Error(e) => packet_forward::Settlement::Failed(e.clone()),
+            // the acknowledgement is relayed back verbatim.
+            AcknowledgementResult::Success(_) => {
+                packet_forward::Settlement::Acknowledged(msg.acknowledgement.to_vec())
+            }
+        };
+        if packet_forward::settle_forward(
+            &mut state,
+            &msg.packet.chan_on_a,
+            msg.packet.sequence.0,
+            settlement,
+        )
+        .await
+        .context("unable to settle forwarded packet")?
+        {
+            // forwarded tokens are refunded on the chain they were forwarded from.
+            return Ok(());
+        }
+
+        if !ack.is_success() {
             // in the case where a counterparty chain acknowledges a packet with an error,
             // for example due to a middleware processing issue or other behavior,
             // the funds should be unescrowed back to the packet sender.
@@ -668,4 +896,24 @@ impl AppHandlerExecute for Ics20Transfer {
     }
 }
 
-impl AppHandler for Ics20Transfer {}
+impl<H: Ics20Hooks> AppHandler for Ics20Transfer<H> {}
+
+/// Pays ICS-29 relayer fees by minting notes to the relayers' Penumbra
+/// addresses.
+pub struct Ics20FeePayout {}
+
+#[async_trait]
+impl FeePayout for Ics20FeePayout {
+    async fn pay<S: StateWrite>(mut state: S, recipient: &str, value: Value) -> Result<()> {
+        let address = Address::from_str(recipient)
+            .with_context(|| format!("relayer fee recipient {recipient} is not an address"))?;
+        let source = state
+            .get_current_source()
+            .unwrap_or_else(CommitmentSource::transaction);
+
+        state
+            .mint_note(value, &address, source)
+            .await
+            .context("couldn't mint relayer fee note")
+    }
+}
@@ -0,0 +1,62 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use cnidarium::StateWrite;
+use ibc_types::core::channel::ChannelId;
+use penumbra_asset::Value;
+use penumbra_keys::Address;
+
+/// An inbound ICS20 transfer that has been credited to its receiver.
+#[derive(Clone, Debug)]
+pub struct ReceivedTransfer {
+    /// The channel the transfer was received over, as named on this chain.
+    pub channel_id: ChannelId,
+    pub sequence: u64,
+    /// The sender on the counterparty chain.
+    pub sender: String,
+    pub receiver: Address,
+    /// The value credited to the receiver, in the denom it has on this chain.
+    pub value: Value,
+}
+
+/// Follow-on actions triggered by the memos of inbound ICS20 transfers.
+///
+/// A memo that is a JSON object holds instructions keyed by name. After a
+/// transfer is credited to its receiver, each instruction whose key a hook
+/// handles is passed to [`Ics20Hooks::on_recv`]. Instructions that no hook
+/// handles are ignored, since they may be meant for the receiver.
+///
+/// Hooks run atomically with the transfer: if a hook fails, the transfer is
+/// reverted and acknowledged with an error, so the sender is refunded.
+#[async_trait]
+pub trait Ics20Hooks: Send + Sync {
+    /// Returns whether a hook handles instructions under `key`.
+    fn handles(key: &str) -> bool;
+
+    /// Runs the hook for the instructions `args` under `key`.
+    async fn on_recv<S: StateWrite>(
+        state: S,
+        key: &str,
+        args: &serde_json::Value,
+        transfer: &ReceivedTransfer,
+    ) -> Result<()>;
+}
+
+/// [`Ics20Hooks`] that don't handle any instructions.
+#[derive(Clone)]
+pub struct NoIcs20Hooks;
+
+#[async_trait]
+impl Ics20Hooks for NoIcs20Hooks {
+    fn handles(_key: &str) -> bool {
+        false
+    }
+
+    async fn on_recv<S: StateWrite>(
+        _state: S,
+        key: &str,
+        _args: &serde_json::Value,
+        _transfer: &ReceivedTransfer,
+    ) -> Result<()> {
+        anyhow::bail!("no hook handles {key} memos")
+    }
+}
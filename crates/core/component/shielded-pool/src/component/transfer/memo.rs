@@ -0,0 +1,87 @@
+// Parsing of the `memo` field of ICS20 packets.
+//
+// the memo is free-form text, but by convention a memo that is a JSON object carries
+// instructions for the middleware and hooks on the receiving chain, keyed by name, e.g.
+// `{"forward": {...}}` for packet-forward-middleware. any other memo is meant for the receiver.
+
+use anyhow::Result;
+use serde_json::{Map, Value};
+
+/// The maximum length of the memo of an inbound transfer, in bytes, matching ibc-go.
+pub const MAX_MEMO_LEN: usize = 32_768;
+
+/// The memo of an ICS20 transfer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Memo {
+    Empty,
+    /// A memo for the receiver, which isn't interpreted.
+    Text(String),
+    /// A memo that is a JSON object, holding instructions keyed by name.
+    Json(Map<String, Value>),
+}
+
+impl Memo {
+    /// Parses a memo, failing if it's longer than [`MAX_MEMO_LEN`].
+    pub fn parse(memo: &str) -> Result<Self> {
+        if memo.len() > MAX_MEMO_LEN {
+            anyhow::bail!(
+                "memo is {} bytes, longer than the maximum of {} bytes",
+                memo.len(),
+                MAX_MEMO_LEN
+            );
+        }
+        if memo.is_empty() {
+            return Ok(Memo::Empty);
+        }
+
+        match serde_json::from_str::<Value>(memo) {
+            Ok(Value::Object(object)) => Ok(Memo::Json(object)),
+            _ => Ok(Memo::Text(memo.to_string())),
+        }
+    }
+
+    /// Gets the instructions under `key`, if the memo is a JSON object with that key.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Memo::Json(object) => object.get(key),
+            Memo::Empty | Memo::Text(_) => None,
+        }
+    }
+
+    /// The keys and instructions of a JSON memo.
+    pub fn instructions(&self) -> impl Iterator<Item = (&str, &Value)> {
+        let object = match self {
+            Memo::Json(object) => Some(object),
+            Memo::Empty | Memo::Text(_) => None,
+        };
+        object
+            .into_iter()
+            .flat_map(|object| object.iter().map(|(key, value)| (key.as_str(), value)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_memos() {
+        assert_eq!(Memo::parse("").unwrap(), Memo::Empty);
+        assert_eq!(
+            Memo::parse("thanks for lunch").unwrap(),
+            Memo::Text("thanks for lunch".to_string())
+        );
+        // JSON that isn't an object is left to the receiver.
+        assert_eq!(Memo::parse("42").unwrap(), Memo::Text("42".to_string()));
+
+        let memo = Memo::parse(r#"{"swap":{"to":"gm"},"note":"hi"}"#).unwrap();
+        assert_eq!(memo.get("swap"), Some(&serde_json::json!({"to": "gm"})));
+        assert_eq!(memo.get("forward"), None);
+        let mut keys = memo.instructions().map(|(key, _)| key).collect::<Vec<_>>();
+        keys.sort();
+        assert_eq!(keys, vec!["note", "swap"]);
+
+        assert!(Memo::parse(&"a".repeat(MAX_MEMO_LEN)).is_ok());
+        assert!(Memo::parse(&"a".repeat(MAX_MEMO_LEN + 1)).is_err());
+    }
+}
@@ -0,0 +1,560 @@
+// Packet forwarding for multi-hop ICS20 transfers, following the memo convention of
+// packet-forward-middleware.
+//
+// see: https://github.com/cosmos/ibc-apps/tree/main/middleware/packet-forward-middleware
+//
+// an inbound transfer whose memo contains a `forward` object isn't credited to its receiver.
+// instead, the tokens are atomically sent on over the next-hop channel, and the inbound packet
+// is left unacknowledged until the forwarded packet settles:
+//
+// - if the forwarded packet is acknowledged successfully, so is the inbound packet;
+// - if it's acknowledged with an error, or times out after exhausting its retries, both legs of
+//   the forward are unwound and the inbound packet is acknowledged with an error, so the
+//   upstream chain refunds the original sender.
+//
+// tokens in transit are never held in notes: only the value balances of the two channels move.
+
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use cnidarium::{StateRead, StateWrite};
+use ibc_proto::ibc::core::channel::v1::Packet as RawPacket;
+use ibc_types::{
+    core::{
+        channel::{ChannelId, Packet, PortId},
+        client::Height as IbcHeight,
+    },
+    transfer::acknowledgement::TokenTransferAcknowledgement,
+};
+use penumbra_asset::{asset, asset::Metadata};
+use penumbra_ibc::component::{
+    packet::{
+        IBCPacket, SendPacketRead as _, SendPacketWrite as _, Unchecked, WriteAcknowledgement as _,
+    },
+    state_key, ChannelStateReadExt as _, ClientStateReadExt as _, ConnectionStateReadExt as _,
+};
+use penumbra_num::Amount;
+use penumbra_proto::{
+    penumbra::core::component::ibc::v1::FungibleTokenPacketData, StateReadProto, StateWriteProto,
+};
+use penumbra_sct::component::clock::EpochRead as _;
+use prost::Message as _;
+use serde::{Deserialize, Serialize};
+
+use super::{check_inbound_allowed, is_source, memo::Memo};
+use crate::component::AssetRegistry;
+
+/// The timeout of forwarded packets whose memo doesn't specify one.
+const DEFAULT_FORWARD_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// The `forward` object of a packet-forward-middleware memo.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(super) struct ForwardMetadata {
+    /// The receiver on the next-hop chain.
+    pub receiver: String,
+    pub port: String,
+    pub channel: String,
+    /// Either a duration string, e.g. `10m`, or a number of nanoseconds.
+    #[serde(default)]
+    pub timeout: Option<serde_json::Value>,
+    /// How many times to resend the forwarded packet if it times out.
+    #[serde(default)]
+    pub retries: Option<u8>,
+    /// The memo of the forwarded packet, either as a JSON object or a string.
+    #[serde(default)]
+    pub next: Option<serde_json::Value>,
+}
+
+impl ForwardMetadata {
+    fn timeout(&self) -> Result<Duration> {
+        match &self.timeout {
+            None => Ok(DEFAULT_FORWARD_TIMEOUT),
+            Some(serde_json::Value::Number(nanos)) => nanos
+                .as_u64()
+                .map(Duration::from_nanos)
+                .ok_or_else(|| anyhow::anyhow!("invalid forward timeout {}", nanos)),
+            Some(serde_json::Value::String(duration)) => parse_duration(duration),
+            Some(timeout) => anyhow::bail!("invalid forward timeout {}", timeout),
+        }
+    }
+
+    fn next_memo(&self) -> String {
+        match &self.next {
+            None => String::new(),
+            Some(serde_json::Value::String(memo)) => memo.clone(),
+            Some(next) => next.to_string(),
+        }
+    }
+}
+
+/// Parses the forwarding instructions in a transfer's memo, if it has any.
+pub(super) fn forward_metadata(packet: &Packet) -> Result<Option<ForwardMetadata>> {
+    let packet_data: FungibleTokenPacketData =
+        serde_json::from_slice(packet.data.as_slice()).context("failed to decode FTPD packet")?;
+    let Some(forward) = Memo::parse(&packet_data.memo)?.get("forward").cloned() else {
+        return Ok(None);
+    };
+
+    let forward: ForwardMetadata =
+        serde_json::from_value(forward).context("invalid packet forwarding memo")?;
+    Ok(Some(forward))
+}
+
+// parses a go `time.Duration` string, e.g. `1h30m`.
+fn parse_duration(s: &str) -> Result<Duration> {
+    let mut total = Duration::ZERO;
+    let mut rest = s;
+    if rest.is_empty() {
+        anyhow::bail!("empty duration");
+    }
+    while !rest.is_empty() {
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .ok_or_else(|| anyhow::anyhow!("duration {} is missing a unit", s))?;
+        let value: u64 = rest[..digits]
+            .parse()
+            .with_context(|| format!("invalid duration {s}"))?;
+        rest = &rest[digits..];
+        let unit_len = rest
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let unit = match &rest[..unit_len] {
+            "ns" => Duration::from_nanos(1),
+            "us" | "µs" => Duration::from_micros(1),
+            "ms" => Duration::from_millis(1),
+            "s" => Duration::from_secs(1),
+            "m" => Duration::from_secs(60),
+            "h" => Duration::from_secs(60 * 60),
+            unit => anyhow::bail!("unknown unit {} in duration {}", unit, s),
+        };
+        rest = &rest[unit_len..];
+        total = unit
+            .checked_mul(value.try_into()?)
+            .and_then(|d| total.checked_add(d))
+            .ok_or_else(|| anyhow::anyhow!("duration {} overflows", s))?;
+    }
+
+    Ok(total)
+}
+
+/// A forwarded transfer awaiting acknowledgement.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct InFlightPacket {
+    /// The hex-encoded inbound packet, which is acknowledged once the forward settles.
+    inbound_packet: String,
+    /// The data of the forwarded packet.
+    packet_data: FungibleTokenPacketData,
+    timeout_nanos: u64,
+    retries_remaining: u8,
+}
+
+impl InFlightPacket {
+    fn inbound_packet(&self) -> Result<Packet> {
+        let bytes = hex::decode(&self.inbound_packet)?;
+        Packet::try_from(RawPacket::decode(bytes.as_slice())?)
+            .map_err(|e| anyhow::anyhow!("invalid inbound packet: {e}"))
+    }
+}
+
+async fn get_in_flight<S: StateRead>(
+    state: &S,
+    channel_id: &ChannelId,
+    sequence: u64,
+) -> Result<Option<InFlightPacket>> {
+    state
+        .get_raw(&state_key::ics20_forward::in_flight(channel_id, sequence))
+        .await?
+        .map(|bytes| serde_json::from_slice(&bytes).map_err(Into::into))
+        .transpose()
+}
+
+fn put_in_flight<S: StateWrite>(
+    state: &mut S,
+    channel_id: &ChannelId,
+    sequence: u64,
+    in_flight: &InFlightPacket,
+) {
+    state.put_raw(
+        state_key::ics20_forward::in_flight(channel_id, sequence),
+        serde_json::to_vec(in_flight).expect("in-flight packets serialize to JSON"),
+    );
+}
+
+/// Credits or debits the value balance of `denom` on `channel_id`.
+async fn adjust_value_balance<S: StateWrite>(
+    state: &mut S,
+    channel_id: &ChannelId,
+    denom: &Metadata,
+    amount: Amount,
+    credit: bool,
+) -> Result<()> {
+    let key = state_key::ics20_value_balance::by_asset_id(channel_id, &denom.id());
+    let value_balance: Amount = state.get(&key).await?.unwrap_or_else(Amount::zero);
+    let new_value_balance = if credit {
+        value_balance
+            .checked_add(&amount)
+            .context("overflow adding value balance in ics20 forward")?
+    } else {
+        value_balance
+            .checked_sub(&amount)
+            .context("insufficient value balance in ics20 forward")?
+    };
+    state.put(key, new_value_balance);
+
+    Ok(())
+}
+
+/// Accounts for the inbound leg of a forward, returning the local denom of the
+/// tokens being forwarded. Undoes the accounting if `undo` is set.
+async fn receive_leg<S: StateWrite>(
+    state: &mut S,
+    packet: &Packet,
+    packet_data: &FungibleTokenPacketData,
+    undo: bool,
+) -> Result<Metadata> {
+    let packet_denom: asset::Metadata = packet_data
+        .denom
+        .as_str()
+        .try_into()
+        .context("couldnt decode denom in ICS20 transfer")?;
+    let amount: Amount = packet_data
+        .amount
+        .clone()
+        .try_into()
+        .context("couldnt decode amount in ICS20 transfer")?;
+
+    if is_source(&packet.port_on_a, &packet.chan_on_a, &packet_denom, false) {
+        // the tokens are returning home, and are released from escrow.
+        let prefix = format!("{}/{}/", packet.port_on_a, packet.chan_on_a);
+        let denom: asset::Metadata = packet_data
+            .denom
+            .strip_prefix(&prefix)
+            .context("denom in packet didn't begin with expected prefix")?
+            .try_into()?;
+        adjust_value_balance(state, &packet.chan_on_b, &denom, amount, undo).await?;
+        Ok(denom)
+    } else {
+        // the tokens are arriving as vouchers.
+        let denom: asset::Metadata = format!(
+            "{}/{}/{}",
+            packet.port_on_b, packet.chan_on_b, packet_data.denom
+        )
+        .as_str()
+        .try_into()?;
+        if !undo {
+            state.register_denom(&denom).await;
+        }
+        adjust_value_balance(state, &packet.chan_on_b, &denom, amount, !undo).await?;
+        Ok(denom)
+    }
+}
+
+/// Accounts for the outbound leg of a forward over `channel_id`. Undoes the
+/// accounting if `undo` is set.
+async fn send_leg<S: StateWrite>(
+    state: &mut S,
+    channel_id: &ChannelId,
+    packet_data: &FungibleTokenPacketData,
+    undo: bool,
+) -> Result<()> {
+    let denom: asset::Metadata = packet_data.denom.as_str().try_into()?;
+    let amount: Amount = packet_data.amount.clone().try_into()?;
+
+    // tokens leaving their home chain are escrowed, and vouchers returning home are burned.
+    let escrowed = !denom.starts_with(&format!("transfer/{channel_id}/"));
+    adjust_value_balance(state, channel_id, &denom, amount, escrowed != undo).await
+}
+
+/// Sends `packet_data` over `channel_id`, timing out after `timeout`, and
+/// returns the packet's sequence number.
+async fn send_forward_packet<S: StateWrite>(
+    state: &mut S,
+    channel_id: &ChannelId,
+    packet_data: &FungibleTokenPacketData,
+    timeout: Duration,
+) -> Result<u64> {
+    let current_block_time = state.get_current_block_timestamp().await?;
+
+    // time out on the counterparty's clock, with a timeout height that, as long as the
+    // counterparty's blocks are at least a second apart, is only reached after the timestamp.
+    let channel = state
+        .get_channel(channel_id, &PortId::transfer())
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("channel {} not found", channel_id))?;
+    let connection = state
+        .get_connection(&channel.connection_hops[0])
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("connection {} not found", channel.connection_hops[0]))?;
+    let latest_height = state
+        .get_client_state(&connection.client_id)
+        .await?
+        .latest_height();
+    let timeout_height = IbcHeight {
+        revision_number: latest_height.revision_number,
+        revision_height: latest_height.revision_height + timeout.as_secs().max(1),
+    };
+    let timeout_timestamp = current_block_time
+        .unix_timestamp_nanos()
+        .try_into()
+        .ok()
+        .and_then(|now: u64| now.checked_add(timeout.as_nanos().try_into().ok()?))
+        .context("forward timeout overflows")?;
+
+    let packet = IBCPacket::<Unchecked>::new(
+        PortId::transfer(),
+        channel_id.clone(),
+        timeout_height,
+        timeout_timestamp,
+        serde_json::to_vec(packet_data).expect("can serialize FungibleTokenPacketData as JSON"),
+    );
+    let packet = state.send_packet_check(packet, current_block_time).await?;
+    let sequence = state
+        .get_send_sequence(channel_id, &PortId::transfer())
+        .await?;
+    state.send_packet_execute(packet).await;
+
+    Ok(sequence)
+}
+
+/// Forwards an inbound transfer to its next hop, leaving it to be
+/// acknowledged once the forwarded packet settles.
+///
+/// Both legs of the forward must happen atomically, so if the forwarded packet
+/// can't be sent, the caller must discard the writes made here.
+pub(super) async fn forward_transfer_packet<S: StateWrite>(
+    mut state: S,
+    packet: &Packet,
+    forward: ForwardMetadata,
+) -> Result<()> {
+    let packet_data: FungibleTokenPacketData =
+        serde_json::from_slice(packet.data.as_slice()).context("failed to decode FTPD packet")?;
+    if PortId(forward.port.clone()) != PortId::transfer() {
+        anyhow::bail!("can't forward transfers to port {}", forward.port);
+    }
+    let next_channel = ChannelId(forward.channel.clone());
+    let timeout = forward.timeout()?;
+
+    let denom = receive_leg(&mut state, packet, &packet_data, false).await?;
+    check_inbound_allowed(&state, &packet.chan_on_b, &denom).await?;
+    let forward_data = FungibleTokenPacketData {
+        amount: packet_data.amount.clone(),
+        denom: denom.base_denom().denom,
+        sender: packet_data.receiver.clone(),
+        receiver: forward.receiver.clone(),
+        memo: forward.next_memo(),
+    };
+    send_leg(&mut state, &next_channel, &forward_data, false).await?;
+    let sequence = send_forward_packet(&mut state, &next_channel, &forward_data, timeout).await?;
+
+    put_in_flight(
+        &mut state,
+        &next_channel,
+        sequence,
+        &InFlightPacket {
+            inbound_packet: hex::encode(RawPacket::from(packet.clone()).encode_to_vec()),
+            packet_data: forward_data,
+            timeout_nanos: timeout.as_nanos().try_into()?,
+            retries_remaining: forward.retries.unwrap_or(0),
+        },
+    );
+
+    Ok(())
+}
+
+/// Unwinds both legs of a failed forward, so that the upstream chain can
+/// refund the sender, and returns the error acknowledgement for the inbound
+/// packet.
+async fn unwind_forward<S: StateWrite>(
+    state: &mut S,
+    channel_id: &ChannelId,
+    in_flight: &InFlightPacket,
+    inbound_packet: &Packet,
+    error: &str,
+) -> Result<Vec<u8>> {
+    send_leg(state, channel_id, &in_flight.packet_data, true).await?;
+    let inbound_data: FungibleTokenPacketData =
+        serde_json::from_slice(inbound_packet.data.as_slice())?;
+    receive_leg(state, inbound_packet, &inbound_data, true).await?;
+
+    Ok(TokenTransferAcknowledgement::Error(format!("packet forward failed: {error}")).into())
+}
+
+/// How a forwarded packet settled.
+pub(super) enum Settlement {
+    Acknowledged(Vec<u8>),
+    Failed(String),
+    TimedOut,
+}
+
+/// Settles the forward of the packet with `sequence` on `channel_id`, if it
+/// was forwarded, by acknowledging the inbound packet or resending the
+/// forwarded packet. Returns whether the packet was forwarded.
+pub(super) async fn settle_forward<S: StateWrite>(
+    mut state: S,
+    channel_id: &ChannelId,
+    sequence: u64,
+    settlement: Settlement,
+) -> Result<bool> {
+    let Some(mut in_flight) = get_in_flight(&state, channel_id, sequence).await? else {
+        return Ok(false);
+    };
+    state.delete(state_key::ics20_forward::in_flight(channel_id, sequence));
+    let inbound_packet = in_flight.inbound_packet()?;
+
+    let ack: Vec<u8> = match settlement {
+        Settlement::Acknowledged(ack) => ack,
+        Settlement::TimedOut if in_flight.retries_remaining > 0 => {
+            // the timed-out packet is replaced by a new one, so nothing needs to be refunded.
+            in_flight.retries_remaining -= 1;
+            let sequence = send_forward_packet(
+                &mut state,
+                channel_id,
+                &in_flight.packet_data,
+                Duration::from_nanos(in_flight.timeout_nanos),
+            )
+            .await
+            .context("couldn't resend forwarded packet")?;
+            put_in_flight(&mut state, channel_id, sequence, &in_flight);
+            return Ok(true);
+        }
+        Settlement::Failed(error) => {
+            unwind_forward(&mut state, channel_id, &in_flight, &inbound_packet, &error).await?
+        }
+        Settlement::TimedOut => {
+            unwind_forward(
+                &mut state,
+                channel_id,
+                &in_flight,
+                &inbound_packet,
+                "forwarded packet timed out",
+            )
+            .await?
+        }
+    };
+
+    state
+        .write_acknowledgement(&inbound_packet, &ack)
+        .await
+        .context("able to write acknowledgement for forwarded packet")?;
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cnidarium::StateDelta;
+    use ibc_types::core::{
+        channel::{
+            channel::{Counterparty, State as ChannelState},
+            msgs::MsgRecvPacket,
+            ChannelEnd, TimeoutHeight, Version,
+        },
+        client::Height,
+        commitment::MerkleProof,
+        connection::ConnectionId,
+    };
+    use ibc_types::timestamp::Timestamp;
+    use penumbra_ibc::component::{app_handler::AppHandlerExecute as _, ChannelStateWriteExt as _};
+    use penumbra_ibc::{params::IBCParameters, StateWriteExt as _};
+    use penumbra_sct::component::clock::EpochManager as _;
+
+    use crate::component::{AssetRegistryRead as _, Ics20Transfer, NoIcs20Hooks};
+
+    #[test]
+    fn parses_go_durations() {
+        assert_eq!(parse_duration("10m").unwrap(), Duration::from_secs(600));
+        assert_eq!(parse_duration("1h30m").unwrap(), Duration::from_secs(5400));
+        assert_eq!(parse_duration("250ms").unwrap(), Duration::from_millis(250));
+        assert!(parse_duration("10").is_err());
+        assert!(parse_duration("10d").is_err());
+    }
+
+    #[test]
+    fn parses_forward_memos() {
+        let memo = Memo::parse(
+            r#"{"forward":{"receiver":"osmo1abc","port":"transfer","channel":"channel-2","timeout":"1h","retries":2,"next":{"forward":{"receiver":"cosmos1xyz","port":"transfer","channel":"channel-7"}}}}"#,
+        )
+        .unwrap();
+        let forward: ForwardMetadata =
+            serde_json::from_value(memo.get("forward").unwrap().clone()).unwrap();
+
+        assert_eq!(forward.channel, "channel-2");
+        assert_eq!(forward.timeout().unwrap(), Duration::from_secs(3600));
+        assert_eq!(forward.retries, Some(2));
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&forward.next_memo()).unwrap(),
+            serde_json::json!({
+                "forward": {"receiver": "cosmos1xyz", "port": "transfer", "channel": "channel-7"}
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn failed_forwards_leave_only_an_error_acknowledgement() -> Result<()> {
+        let mut state = StateDelta::new(());
+        state.put_ibc_params(IBCParameters::default());
+        state.put_block_height(1);
+        state.put_block_timestamp(1, tendermint::Time::from_unix_timestamp(1, 0)?);
+        let (port_id, channel_id) = (PortId::transfer(), ChannelId::new(0));
+        state.put_channel(
+            &channel_id,
+            &port_id,
+            ChannelEnd {
+                state: ChannelState::Open,
+                ordering: ibc_types::core::channel::channel::Order::Unordered,
+                remote: Counterparty::new(port_id.clone(), Some(ChannelId::new(5))),
+                connection_hops: vec![ConnectionId::new(0)],
+                version: Version::new("ics20-1".to_string()),
+            },
+        );
+
+        // the forward's next hop, channel-9, doesn't exist, so the forwarded
+        // packet can't be sent after both legs have been accounted for.
+        let packet_data = FungibleTokenPacketData {
+            denom: "uatom".to_string(),
+            amount: "100".to_string(),
+            sender: "cosmos1sender".to_string(),
+            receiver: "penumbra1intermediate".to_string(),
+            memo: r#"{"forward":{"receiver":"osmo1abc","port":"transfer","channel":"channel-9"}}"#
+                .to_string(),
+        };
+        let msg = MsgRecvPacket {
+            packet: Packet {
+                sequence: 1.into(),
+                port_on_a: port_id.clone(),
+                chan_on_a: ChannelId::new(5),
+                port_on_b: port_id.clone(),
+                chan_on_b: channel_id.clone(),
+                data: serde_json::to_vec(&packet_data)?,
+                timeout_height_on_b: TimeoutHeight::Never,
+                timeout_timestamp_on_b: Timestamp::none(),
+            },
+            proof_commitment_on_a: MerkleProof { proofs: vec![] },
+            proof_height_on_a: Height::new(0, 1)?,
+            signer: "relayer".to_string(),
+        };
+        Ics20Transfer::<NoIcs20Hooks>::recv_packet_execute(&mut state, &msg).await?;
+
+        let voucher: Metadata = "transfer/channel-0/uatom".try_into()?;
+        for channel in [&channel_id, &ChannelId::new(9)] {
+            let value_balance: Option<Amount> = state
+                .get(&state_key::ics20_value_balance::by_asset_id(
+                    channel,
+                    &voucher.id(),
+                ))
+                .await?;
+            assert!(value_balance.is_none());
+        }
+        assert!(state.denom_metadata_by_asset(&voucher.id()).await.is_none());
+        assert!(get_in_flight(&state, &ChannelId::new(9), 1)
+            .await?
+            .is_none());
+        assert!(state
+            .get_packet_acknowledgement(&port_id, &channel_id, 1)
+            .await?
+            .is_some());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,236 @@
+// Per-channel, per-asset quotas on ICS20 transfers, in the style of ibc-rate-limit.
+//
+// see: https://github.com/osmosis-labs/osmosis/tree/main/x/ibc-rate-limit
+//
+// governance sets quotas in the IBC parameters. for each quota, the amounts sent and received
+// over its channel are tracked over a window of blocks, starting afresh once the window has
+// elapsed. a transfer that would take either amount over the quota is rejected:
+//
+// - an outbound transfer fails the transaction that makes it;
+// - an inbound transfer is acknowledged with an error, so the sender is refunded.
+//
+// when an outbound transfer is refunded, its amount no longer counts against the current window.
+
+use anyhow::Result;
+use cnidarium::{StateRead, StateWrite};
+use ibc_types::core::channel::ChannelId;
+use penumbra_asset::asset;
+use penumbra_ibc::{component::state_key, params::Ics20RateLimit, StateReadExt as _};
+use penumbra_num::Amount;
+use penumbra_proto::{
+    core::component::ibc::v1 as pb, DomainType, StateReadProto as _, StateWriteProto as _,
+};
+use penumbra_sct::component::clock::EpochRead as _;
+
+/// The amounts of an asset transferred over a rate-limited channel in the
+/// current window.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct Ics20Flow {
+    window_start_height: u64,
+    inflow: Amount,
+    outflow: Amount,
+}
+
+impl Ics20Flow {
+    /// An empty flow in a window starting at `height`.
+    fn new(height: u64) -> Self {
+        Self {
+            window_start_height: height,
+            ..Default::default()
+        }
+    }
+
+    /// The flow as of `height`, which is empty if `limit`'s window has elapsed.
+    fn at_height(self, limit: &Ics20RateLimit, height: u64) -> Self {
+        if height >= self.window_start_height.saturating_add(limit.window_blocks) {
+            Self::new(height)
+        } else {
+            self
+        }
+    }
+
+    fn add_outflow(&mut self, limit: &Ics20RateLimit, amount: Amount) -> Result<()> {
+        self.outflow = self
+            .outflow
+            .checked_add(&amount)
+            .filter(|outflow| *outflow <= limit.max_outflow)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "outbound transfers of {} over {} exceed the rate limit of {} per {} blocks",
+                    limit.asset_id,
+                    limit.channel_id,
+                    limit.max_outflow,
+                    limit.window_blocks
+                )
+            })?;
+        Ok(())
+    }
+
+    fn add_inflow(&mut self, limit: &Ics20RateLimit, amount: Amount) -> Result<()> {
+        self.inflow = self
+            .inflow
+            .checked_add(&amount)
+            .filter(|inflow| *inflow <= limit.max_inflow)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "inbound transfers of {} over {} exceed the rate limit of {} per {} blocks",
+                    limit.asset_id,
+                    limit.channel_id,
+                    limit.max_inflow,
+                    limit.window_blocks
+                )
+            })?;
+        Ok(())
+    }
+}
+
+impl DomainType for Ics20Flow {
+    type Proto = pb::Ics20Flow;
+}
+
+impl TryFrom<pb::Ics20Flow> for Ics20Flow {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::Ics20Flow) -> Result<Self> {
+        Ok(Ics20Flow {
+            window_start_height: msg.window_start_height,
+            inflow: msg
+                .inflow
+                .map(TryInto::try_into)
+                .transpose()?
+                .unwrap_or_default(),
+            outflow: msg
+                .outflow
+                .map(TryInto::try_into)
+                .transpose()?
+                .unwrap_or_default(),
+        })
+    }
+}
+
+impl From<Ics20Flow> for pb::Ics20Flow {
+    fn from(flow: Ics20Flow) -> Self {
+        pb::Ics20Flow {
+            window_start_height: flow.window_start_height,
+            inflow: Some(flow.inflow.into()),
+            outflow: Some(flow.outflow.into()),
+        }
+    }
+}
+
+/// Gets the quota on transfers of `asset_id` over `channel_id`, if there is one,
+/// along with the flow in its current window.
+async fn current_flow<S: StateRead + ?Sized>(
+    state: &S,
+    channel_id: &ChannelId,
+    asset_id: &asset::Id,
+) -> Result<Option<(Ics20RateLimit, Ics20Flow)>> {
+    let Some(limit) = state
+        .get_ibc_params()
+        .await?
+        .ics20_rate_limit(channel_id, asset_id)
+        .cloned()
+    else {
+        return Ok(None);
+    };
+
+    let height = state.get_block_height().await?;
+    let flow = match state
+        .get::<Ics20Flow>(&state_key::ics20_rate_limit::flow(channel_id, asset_id))
+        .await?
+    {
+        Some(flow) => flow.at_height(&limit, height),
+        None => Ics20Flow::new(height),
+    };
+
+    Ok(Some((limit, flow)))
+}
+
+/// Counts an outbound transfer against its channel's quota, failing if the
+/// quota would be exceeded.
+pub(super) async fn record_outflow<S: StateWrite + ?Sized>(
+    state: &mut S,
+    channel_id: &ChannelId,
+    asset_id: &asset::Id,
+    amount: Amount,
+) -> Result<()> {
+    if let Some((limit, mut flow)) = current_flow(state, channel_id, asset_id).await? {
+        flow.add_outflow(&limit, amount)?;
+        state.put(
+            state_key::ics20_rate_limit::flow(channel_id, asset_id),
+            flow,
+        );
+    }
+    Ok(())
+}
+
+/// Counts an inbound transfer against its channel's quota, failing if the
+/// quota would be exceeded.
+pub(super) async fn record_inflow<S: StateWrite + ?Sized>(
+    state: &mut S,
+    channel_id: &ChannelId,
+    asset_id: &asset::Id,
+    amount: Amount,
+) -> Result<()> {
+    if let Some((limit, mut flow)) = current_flow(state, channel_id, asset_id).await? {
+        flow.add_inflow(&limit, amount)?;
+        state.put(
+            state_key::ics20_rate_limit::flow(channel_id, asset_id),
+            flow,
+        );
+    }
+    Ok(())
+}
+
+/// Stops counting a refunded outbound transfer against its channel's quota.
+pub(super) async fn undo_outflow<S: StateWrite + ?Sized>(
+    state: &mut S,
+    channel_id: &ChannelId,
+    asset_id: &asset::Id,
+    amount: Amount,
+) -> Result<()> {
+    if let Some((_, mut flow)) = current_flow(state, channel_id, asset_id).await? {
+        flow.outflow = flow.outflow.saturating_sub(&amount);
+        state.put(
+            state_key::ics20_rate_limit::flow(channel_id, asset_id),
+            flow,
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flows_are_limited_within_a_window() {
+        let limit = Ics20RateLimit {
+            channel_id: ChannelId::new(0),
+            asset_id: asset::Cache::with_known_assets()
+                .get_unit("upenumbra")
+                .unwrap()
+                .id(),
+            max_outflow: 100u64.into(),
+            max_inflow: 50u64.into(),
+            window_blocks: 10,
+        };
+
+        let mut flow = Ics20Flow::new(5);
+        flow.add_outflow(&limit, 60u64.into()).unwrap();
+        flow.add_outflow(&limit, 40u64.into()).unwrap();
+        assert!(flow.add_outflow(&limit, 1u64.into()).is_err());
+        assert!(flow.add_inflow(&limit, 51u64.into()).is_err());
+        flow.add_inflow(&limit, 50u64.into()).unwrap();
+
+        // the window is still open at its last block
+        let mut flow = flow.at_height(&limit, 14);
+        assert!(flow.add_outflow(&limit, 1u64.into()).is_err());
+
+        // and starts afresh after it
+        let mut flow = flow.at_height(&limit, 15);
+        assert_eq!(flow.window_start_height, 15);
+        flow.add_outflow(&limit, 100u64.into()).unwrap();
+        flow.add_inflow(&limit, 50u64.into()).unwrap();
+    }
+}
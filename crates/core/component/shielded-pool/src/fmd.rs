@@ -129,16 +129,64 @@ impl SlidingWindow {
     }
 }
 
+/// A step of a [`Schedule`].
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScheduleStep {
+    /// The number of clues from which this step applies.
+    pub min_clues: u64,
+    /// The precision to use for this step.
+    pub precision: Precision,
+}
+
+/// A schedule of precisions, ramping up with the size of the anonymity set,
+/// that is, the number of clues seen in the last update period.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Schedule {
+    steps: Vec<ScheduleStep>,
+}
+
+impl Schedule {
+    /// Creates a schedule from its steps, which must be by strictly increasing
+    /// number of clues, starting from zero clues.
+    pub fn new(steps: Vec<ScheduleStep>) -> Result<Self> {
+        anyhow::ensure!(
+            steps.first().map(|step| step.min_clues) == Some(0),
+            "the first step of an FMD schedule must apply from zero clues"
+        );
+        anyhow::ensure!(
+            steps.windows(2).all(|w| w[0].min_clues < w[1].min_clues),
+            "the steps of an FMD schedule must be by strictly increasing number of clues"
+        );
+        Ok(Self { steps })
+    }
+
+    pub fn steps(&self) -> &[ScheduleStep] {
+        &self.steps
+    }
+
+    /// The precision for an anonymity set of `clues` clues.
+    pub fn precision(&self, clues: u64) -> Precision {
+        self.steps
+            .iter()
+            .rev()
+            .find(|step| step.min_clues <= clues)
+            .map(|step| step.precision)
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum MetaParametersAlgorithm {
     /// Use a fixed precision forever.
     Fixed(Precision),
     /// Use a sliding window
     SlidingWindow(SlidingWindow),
+    /// Use a precision scheduled by the number of clues.
+    Schedule(Schedule),
 }
 
 /// Meta parameters governing how FMD parameters change.
-#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(try_from = "pb::FmdMetaParameters", into = "pb::FmdMetaParameters")]
 pub struct MetaParameters {
     pub fmd_grace_period_blocks: u64,
@@ -163,6 +211,19 @@ impl TryFrom<pb::FmdMetaParameters> for MetaParameters {
                     targeted_detections_per_window: x.targeted_detections_per_window,
                 })
             }
+            pb::fmd_meta_parameters::Algorithm::Schedule(x) => {
+                MetaParametersAlgorithm::Schedule(Schedule::new(
+                    x.steps
+                        .into_iter()
+                        .map(|step| {
+                            Ok(ScheduleStep {
+                                min_clues: step.min_clues,
+                                precision: step.precision_bits.try_into()?,
+                            })
+                        })
+                        .collect::<Result<_>>()?,
+                )?)
+            }
         };
         Ok(MetaParameters {
             fmd_grace_period_blocks,
@@ -186,6 +247,20 @@ impl From<MetaParameters> for pb::FmdMetaParameters {
                     targeted_detections_per_window,
                 },
             ),
+            MetaParametersAlgorithm::Schedule(schedule) => {
+                pb::fmd_meta_parameters::Algorithm::Schedule(
+                    pb::fmd_meta_parameters::AlgorithmSchedule {
+                        steps: schedule
+                            .steps
+                            .into_iter()
+                            .map(|step| pb::fmd_meta_parameters::algorithm_schedule::Step {
+                                min_clues: step.min_clues,
+                                precision_bits: step.precision.bits().into(),
+                            })
+                            .collect(),
+                    },
+                )
+            }
         };
         pb::FmdMetaParameters {
             fmd_grace_period_blocks: value.fmd_grace_period_blocks,
@@ -294,10 +369,10 @@ impl MetaParameters {
                 clue_count_delta.1
             );
         }
-        match self.algorithm {
+        match &self.algorithm {
             MetaParametersAlgorithm::Fixed(precision) => (
                 Parameters {
-                    precision,
+                    precision: *precision,
                     as_of_block_height: height,
                 },
                 MetaParametersAlgorithmState::Fixed,
@@ -305,6 +380,63 @@ impl MetaParameters {
             MetaParametersAlgorithm::SlidingWindow(w) => {
                 w.updated_fmd_params(old, state, height, clue_count_delta)
             }
+            // The clue count of the update period that just ended.
+            MetaParametersAlgorithm::Schedule(schedule) => (
+                Parameters {
+                    precision: schedule.precision(clue_count_delta.1),
+                    as_of_block_height: height,
+                },
+                MetaParametersAlgorithmState::Nothing,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step(min_clues: u64, bits: u8) -> ScheduleStep {
+        ScheduleStep {
+            min_clues,
+            precision: Precision::new(bits).expect("valid precision"),
         }
     }
+
+    #[test]
+    fn schedule_ramps_up_with_clues() {
+        assert!(Schedule::new(vec![]).is_err());
+        assert!(Schedule::new(vec![step(1, 1)]).is_err());
+        assert!(Schedule::new(vec![step(0, 1), step(10, 2), step(10, 3)]).is_err());
+
+        let schedule =
+            Schedule::new(vec![step(0, 1), step(100, 4), step(1000, 8)]).expect("valid schedule");
+        let meta_params = MetaParameters {
+            fmd_grace_period_blocks: FMD_GRACE_PERIOD_BLOCKS_DEFAULT,
+            algorithm: MetaParametersAlgorithm::Schedule(schedule),
+        };
+        for (clues, bits) in [
+            (0, 1),
+            (99, 1),
+            (100, 4),
+            (999, 4),
+            (1000, 8),
+            (u64::MAX, 8),
+        ] {
+            let (params, _) = meta_params.updated_fmd_params(
+                &Parameters::default(),
+                MetaParametersAlgorithmState::Nothing,
+                16,
+                (0, clues),
+            );
+            assert_eq!(params.precision.bits(), bits, "{clues} clues");
+            assert_eq!(params.as_of_block_height, 16);
+        }
+
+        let proto = pb::FmdMetaParameters::from(meta_params.clone());
+        assert_eq!(
+            MetaParameters::try_from(proto).expect("valid proto"),
+            meta_params
+        );
+    }
 }
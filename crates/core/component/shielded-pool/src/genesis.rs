@@ -1,3 +1,4 @@
+use penumbra_asset::asset::Metadata;
 use penumbra_proto::{penumbra::core::component::shielded_pool::v1 as pb, DomainType};
 use serde::{Deserialize, Serialize};
 
@@ -14,6 +15,8 @@ pub struct Content {
     pub allocations: Vec<Allocation>,
     /// The initial FMD parameters.
     pub shielded_pool_params: ShieldedPoolParameters,
+    /// The metadata of assets registered at genesis, in addition to the allocated denoms.
+    pub denom_metadata: Vec<Metadata>,
 }
 
 impl DomainType for Content {
@@ -25,6 +28,7 @@ impl From<Content> for pb::GenesisContent {
         pb::GenesisContent {
             allocations: value.allocations.into_iter().map(Into::into).collect(),
             shielded_pool_params: Some(value.shielded_pool_params.into()),
+            denom_metadata: value.denom_metadata.into_iter().map(Into::into).collect(),
         }
     }
 }
@@ -43,6 +47,11 @@ impl TryFrom<pb::GenesisContent> for Content {
                 .shielded_pool_params
                 .ok_or_else(|| anyhow::anyhow!("proto response missing shielded pool params"))?
                 .try_into()?,
+            denom_metadata: msg
+                .denom_metadata
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -51,6 +60,7 @@ impl Default for Content {
     fn default() -> Self {
         Self {
             shielded_pool_params: ShieldedPoolParameters::default(),
+            denom_metadata: Vec::new(),
             allocations: vec![
                 Allocation {
                     raw_amount: 1000u128.into(),
@@ -3,6 +3,7 @@ use penumbra_asset::{
     asset::{self, Metadata},
     Balance, Value,
 };
+use penumbra_ibc::packet_fee::Fee;
 use penumbra_keys::Address;
 use penumbra_num::Amount;
 use penumbra_proto::{
@@ -41,6 +42,10 @@ pub struct Ics20Withdrawal {
     // Whether to use a "compat" (bech32, non-m) address for the return address in the withdrawal,
     // for compatability with chains that expect to be able to parse the return address as bech32.
     pub use_compat_address: bool,
+
+    // fees escrowed for the relayers of the transfer packet, which requires the
+    // source channel to support ICS-29 fee incentivization.
+    pub relay_fee: Option<Fee>,
 }
 
 #[cfg(feature = "component")]
@@ -65,7 +70,11 @@ impl Ics20Withdrawal {
     }
 
     pub fn balance(&self) -> Balance {
-        -Balance::from(self.value())
+        let mut balance = -Balance::from(self.value());
+        if let Some(relay_fee) = &self.relay_fee {
+            balance -= relay_fee.total();
+        }
+        balance
     }
 
     pub fn packet_data(&self) -> Vec<u8> {
@@ -118,6 +127,7 @@ impl From<Ics20Withdrawal> for pb::Ics20Withdrawal {
             timeout_time: w.timeout_time,
             source_channel: w.source_channel.to_string(),
             use_compat_address: w.use_compat_address,
+            relay_fee: w.relay_fee.map(Into::into),
         }
     }
 }
@@ -148,6 +158,7 @@ impl TryFrom<pb::Ics20Withdrawal> for Ics20Withdrawal {
             timeout_time: s.timeout_time,
             source_channel: ChannelId::from_str(&s.source_channel)?,
             use_compat_address: s.use_compat_address,
+            relay_fee: s.relay_fee.map(TryInto::try_into).transpose()?,
         })
     }
 }
@@ -6,11 +6,15 @@ pub mod component;
 pub mod ics20_withdrawal;
 pub use ics20_withdrawal::Ics20Withdrawal;
 
+pub mod nft_withdrawal;
+pub use nft_withdrawal::NftWithdrawal;
+
 pub mod event;
 pub mod fmd;
 pub mod genesis;
 pub mod params;
 pub mod state_key;
+pub mod supply;
 
 pub mod note;
 mod note_payload;
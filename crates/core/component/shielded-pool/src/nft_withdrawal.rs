@@ -0,0 +1,118 @@
+use std::{collections::BTreeSet, str::FromStr};
+
+use ibc_types::core::{channel::ChannelId, client::Height as IbcHeight};
+use penumbra_asset::{asset::Metadata, Balance, Value};
+use penumbra_keys::Address;
+use penumbra_num::Amount;
+use penumbra_proto::{penumbra::core::component::ibc::v1 as pb, DomainType};
+use penumbra_txhash::{EffectHash, EffectingData};
+use serde::{Deserialize, Serialize};
+
+/// The asset representing the token `token_id` of `class_id`.
+pub fn token_denom(class_id: &str, token_id: &str) -> Metadata {
+    Metadata::new(format!("{class_id}/{token_id}"), Vec::new())
+}
+
+/// A request to transfer non-fungible tokens held on Penumbra to another chain.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "pb::NftWithdrawal", into = "pb::NftWithdrawal")]
+pub struct NftWithdrawal {
+    pub class_id: String,
+    pub token_ids: Vec<String>,
+    // the address on the destination chain to send the tokens to
+    pub destination_chain_address: String,
+    // the penumbra address to return the tokens to if the transfer fails
+    pub return_address: Address,
+    pub timeout_height: IbcHeight,
+    // the timestamp at which this transfer expires, in nanoseconds after unix epoch.
+    pub timeout_time: u64,
+    pub source_channel: ChannelId,
+    pub memo: String,
+}
+
+impl NftWithdrawal {
+    /// The balance of the withdrawal, which consumes one of each token.
+    pub fn balance(&self) -> Balance {
+        self.token_ids
+            .iter()
+            .map(|token_id| {
+                -Balance::from(Value {
+                    amount: Amount::from(1u64),
+                    asset_id: token_denom(&self.class_id, token_id).id(),
+                })
+            })
+            .fold(Balance::default(), |acc, balance| acc + balance)
+    }
+
+    // stateless validation of an NFT withdrawal action.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if self.token_ids.is_empty() {
+            anyhow::bail!("withdrawal must transfer at least one token");
+        }
+        if self.token_ids.iter().collect::<BTreeSet<_>>().len() != self.token_ids.len() {
+            anyhow::bail!("withdrawal transfers the same token more than once");
+        }
+
+        if self.timeout_time == 0 {
+            anyhow::bail!("timeout time must be non-zero");
+        }
+
+        // as with ICS20 withdrawals, enforce that timeout time is rounded to the nearest minute,
+        // so that clients can't be identified by their clock skew.
+        if self.timeout_time % 60_000_000_000 != 0 {
+            anyhow::bail!(
+                "withdrawal timeout timestamp {} is not rounded to one minute",
+                self.timeout_time
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl EffectingData for NftWithdrawal {
+    fn effect_hash(&self) -> EffectHash {
+        EffectHash::from_proto_effecting_data(&self.to_proto())
+    }
+}
+
+impl DomainType for NftWithdrawal {
+    type Proto = pb::NftWithdrawal;
+}
+
+impl From<NftWithdrawal> for pb::NftWithdrawal {
+    fn from(w: NftWithdrawal) -> Self {
+        pb::NftWithdrawal {
+            class_id: w.class_id,
+            token_ids: w.token_ids,
+            destination_chain_address: w.destination_chain_address,
+            return_address: Some(w.return_address.into()),
+            timeout_height: Some(w.timeout_height.into()),
+            timeout_time: w.timeout_time,
+            source_channel: w.source_channel.to_string(),
+            memo: w.memo,
+        }
+    }
+}
+
+impl TryFrom<pb::NftWithdrawal> for NftWithdrawal {
+    type Error = anyhow::Error;
+    fn try_from(s: pb::NftWithdrawal) -> Result<Self, Self::Error> {
+        Ok(Self {
+            class_id: s.class_id,
+            token_ids: s.token_ids,
+            destination_chain_address: s.destination_chain_address,
+            return_address: s
+                .return_address
+                .ok_or_else(|| anyhow::anyhow!("missing return address"))?
+                .try_into()?,
+            timeout_height: s
+                .timeout_height
+                .ok_or_else(|| anyhow::anyhow!("missing timeout height"))?
+                .try_into()?,
+            timeout_time: s.timeout_time,
+            source_channel: ChannelId::from_str(&s.source_channel)?,
+            memo: s.memo,
+        })
+    }
+}
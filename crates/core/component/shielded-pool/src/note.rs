@@ -240,6 +240,41 @@ impl Note {
         NoteCiphertext(ciphertext)
     }
 
+    /// Encrypt a memo for the receiver of this note, with the same key as the
+    /// note itself.
+    ///
+    /// Notes minted by the chain have no transaction memo, so this allows them
+    /// to carry one, e.g. the memo of the ICS20 transfer that minted them.
+    pub fn encrypt_memo(&self, memo: &str) -> Vec<u8> {
+        let esk = self.ephemeral_secret_key();
+        let epk = esk.diversified_public(&self.diversified_generator());
+        let shared_secret = esk
+            .key_agreement_with(self.transmission_key())
+            .expect("key agreement succeeded");
+
+        let key = PayloadKey::derive(&shared_secret, &epk);
+        key.encrypt(memo.as_bytes().to_vec(), PayloadKind::Memo)
+    }
+
+    /// Decrypt a memo encrypted with [`Note::encrypt_memo`], using the IVK and
+    /// the ephemeral public key of the note it was encrypted with.
+    pub fn decrypt_memo(
+        ciphertext: &[u8],
+        ivk: &IncomingViewingKey,
+        epk: &ka::Public,
+    ) -> Result<String, Error> {
+        let shared_secret = ivk
+            .key_agreement_with(epk)
+            .map_err(|_| Error::DecryptionError)?;
+
+        let key = PayloadKey::derive(&shared_secret, epk);
+        let plaintext = key
+            .decrypt(ciphertext.to_vec(), PayloadKind::Memo)
+            .map_err(|_| Error::DecryptionError)?;
+
+        String::from_utf8(plaintext).map_err(|_| Error::DecryptionError)
+    }
+
     /// Generate encrypted outgoing cipher key for use with this note.
     pub fn encrypt_key(&self, ovk: &OutgoingViewingKey, cv: balance::Commitment) -> OvkWrappedKey {
         let esk = self.ephemeral_secret_key();
@@ -640,6 +675,38 @@ mod tests {
         assert_eq!(plaintext, note);
     }
 
+    #[test]
+    fn minted_note_memo_encryption_and_decryption() {
+        let mut rng = OsRng;
+
+        let seed_phrase = SeedPhrase::generate(rng);
+        let sk = SpendKey::from_seed_phrase_bip44(seed_phrase, &Bip44Path::new(0));
+        let fvk = sk.full_viewing_key();
+        let ivk = fvk.incoming();
+        let (dest, _dtk_d) = ivk.payment_address(0u32.into());
+
+        let value = Value {
+            amount: 10u64.into(),
+            asset_id: asset::Cache::with_known_assets()
+                .get_unit("upenumbra")
+                .unwrap()
+                .id(),
+        };
+        let note = Note::generate(&mut rng, &dest, value);
+
+        let ciphertext = note.encrypt_memo("thanks for lunch");
+        let epk = note.ephemeral_public_key();
+        assert_eq!(
+            Note::decrypt_memo(&ciphertext, ivk, &epk).expect("can decrypt memo"),
+            "thanks for lunch"
+        );
+
+        let seed_phrase = SeedPhrase::generate(rng);
+        let sk2 = SpendKey::from_seed_phrase_bip44(seed_phrase, &Bip44Path::new(0));
+        let ivk2 = sk2.full_viewing_key().incoming();
+        assert!(Note::decrypt_memo(&ciphertext, ivk2, &epk).is_err());
+    }
+
     #[test]
     fn note_decryption_fails_with_incorrect_epk() {
         let mut rng = OsRng;
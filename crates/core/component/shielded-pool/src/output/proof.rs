@@ -1,5 +1,5 @@
 use base64::prelude::*;
-use std::str::FromStr;
+use std::{collections::BTreeSet, str::FromStr};
 
 use anyhow::Result;
 use ark_groth16::r1cs_to_qap::LibsnarkReduction;
@@ -24,7 +24,10 @@ use penumbra_asset::{
     balance::{commitment::BalanceCommitmentVar, BalanceVar},
     Value,
 };
-use penumbra_proof_params::{DummyWitness, VerifyingKeyExt, GROTH16_PROOF_LENGTH_BYTES};
+use penumbra_proof_params::{
+    batch::{self, BatchVerifier},
+    DummyWitness, VerifyingKeyExt, GROTH16_PROOF_LENGTH_BYTES,
+};
 
 /// The public input for an [`OutputProof`].
 #[derive(Clone, Debug)]
@@ -204,9 +207,51 @@ impl OutputProof {
         vk: &PreparedVerifyingKey<Bls12_377>,
         public: OutputProofPublic,
     ) -> anyhow::Result<()> {
+        let public_inputs = Self::public_inputs(public)?;
         let proof =
             Proof::deserialize_compressed_unchecked(&self.0[..]).map_err(|e| anyhow::anyhow!(e))?;
 
+        let start = std::time::Instant::now();
+        let proof_result = Groth16::<Bls12_377, LibsnarkReduction>::verify_with_processed_vk(
+            vk,
+            public_inputs.as_slice(),
+            &proof,
+        )
+        .map_err(|err| anyhow::anyhow!(err))?;
+        tracing::debug!(?proof_result, elapsed = ?start.elapsed());
+        proof_result
+            .then_some(())
+            .ok_or_else(|| anyhow::anyhow!("output proof did not verify"))
+    }
+
+    /// Verifies the proof like [`Self::verify`], unless it was recorded in
+    /// `verified` by a batch of output proofs.
+    pub fn verify_unless_batched(
+        &self,
+        vk: &PreparedVerifyingKey<Bls12_377>,
+        public: OutputProofPublic,
+        verified: &BTreeSet<[u8; 32]>,
+    ) -> anyhow::Result<()> {
+        let public_inputs = Self::public_inputs(public.clone())?;
+        if batch::is_verified(verified, vk, &self.0, &public_inputs) {
+            tracing::debug!("proof was verified in a batch");
+            return Ok(());
+        }
+        self.verify(vk, public)
+    }
+
+    /// Adds this proof to a batch of output proofs to verify at once.
+    pub fn queue(
+        &self,
+        batch: &mut BatchVerifier,
+        public: OutputProofPublic,
+    ) -> anyhow::Result<()> {
+        batch
+            .queue(self.0, Self::public_inputs(public)?)
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+
+    fn public_inputs(public: OutputProofPublic) -> anyhow::Result<Vec<Fq>> {
         let mut public_inputs = Vec::new();
         public_inputs.extend(
             public
@@ -226,17 +271,7 @@ impl OutputProof {
         );
 
         tracing::trace!(?public_inputs);
-        let start = std::time::Instant::now();
-        let proof_result = Groth16::<Bls12_377, LibsnarkReduction>::verify_with_processed_vk(
-            vk,
-            public_inputs.as_slice(),
-            &proof,
-        )
-        .map_err(|err| anyhow::anyhow!(err))?;
-        tracing::debug!(?proof_result, elapsed = ?start.elapsed());
-        proof_result
-            .then_some(())
-            .ok_or_else(|| anyhow::anyhow!("output proof did not verify"))
+        Ok(public_inputs)
     }
 }
 
@@ -267,7 +302,9 @@ mod tests {
     use penumbra_asset::{asset, Balance, Value};
     use penumbra_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
     use penumbra_num::Amount;
+    use penumbra_proof_params::generate_prepared_test_parameters;
     use proptest::prelude::*;
+    use rand_core::OsRng;
 
     use crate::{note, Note};
 
@@ -401,4 +438,46 @@ mod tests {
             assert!(check_circuit_satisfaction(public, private).is_err());
         }
     }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(1))]
+        #[test]
+        /// Check that valid output proofs verify in a batch, and that a batch
+        /// with an invalid statement doesn't.
+        fn output_proofs_verify_in_batch(statements in prop::collection::vec(arb_valid_output_statement(), 3)) {
+            let (pk, vk) = generate_prepared_test_parameters::<OutputCircuit>(&mut OsRng);
+            let proofs = statements
+                .into_iter()
+                .map(|(public, private)| {
+                    let proof = OutputProof::prove(Fq::from(1u64), Fq::from(2u64), &pk, public.clone(), private)
+                        .expect("can create proof");
+                    (proof, public)
+                })
+                .collect::<Vec<_>>();
+
+            // Swap the balance commitments of two of the statements.
+            let mut bad_batch = BatchVerifier::new();
+            for (i, (proof, public)) in proofs.iter().enumerate() {
+                let mut public = public.clone();
+                if i < 2 {
+                    public.balance_commitment = proofs[1 - i].1.balance_commitment;
+                }
+                proof.queue(&mut bad_batch, public).expect("can queue proof");
+            }
+            let mut verified = BTreeSet::new();
+            prop_assert!(!bad_batch.verify(&vk, &mut verified).expect("can verify batch"));
+            prop_assert!(verified.is_empty());
+
+            let mut batch = BatchVerifier::new();
+            for (proof, public) in &proofs {
+                proof.queue(&mut batch, public.clone()).expect("can queue proof");
+            }
+            prop_assert!(batch.verify(&vk, &mut verified).expect("can verify batch"));
+            for (proof, public) in proofs {
+                let public_inputs = OutputProof::public_inputs(public.clone()).expect("valid inputs");
+                prop_assert!(batch::is_verified(&verified, &vk, &proof.0, &public_inputs));
+                prop_assert!(proof.verify_unless_batched(&vk, public, &verified).is_ok());
+            }
+        }
+    }
 }
@@ -1,5 +1,5 @@
 use base64::prelude::*;
-use std::str::FromStr;
+use std::{collections::BTreeSet, str::FromStr};
 use tct::Root;
 
 use anyhow::Result;
@@ -33,7 +33,10 @@ use penumbra_keys::keys::{
     AuthorizationKeyVar, Bip44Path, IncomingViewingKeyVar, NullifierKey, NullifierKeyVar,
     RandomizedVerificationKey, SeedPhrase, SpendAuthRandomizerVar, SpendKey,
 };
-use penumbra_proof_params::{DummyWitness, VerifyingKeyExt, GROTH16_PROOF_LENGTH_BYTES};
+use penumbra_proof_params::{
+    batch::{self, BatchVerifier},
+    DummyWitness, VerifyingKeyExt, GROTH16_PROOF_LENGTH_BYTES,
+};
 use penumbra_sct::{Nullifier, NullifierVar};
 use tap::Tap;
 
@@ -317,15 +320,59 @@ impl SpendProof {
     pub fn verify(
         &self,
         vk: &PreparedVerifyingKey<Bls12_377>,
+        public: SpendProofPublic,
+    ) -> Result<(), VerificationError> {
+        let public_inputs = Self::public_inputs(public)?;
+        let proof = Proof::deserialize_compressed_unchecked(&self.0[..])
+            .map_err(VerificationError::ProofDeserialize)?;
+
+        let start = std::time::Instant::now();
+        Groth16::<Bls12_377, LibsnarkReduction>::verify_with_processed_vk(
+            vk,
+            public_inputs.as_slice(),
+            &proof,
+        )
+        .map_err(VerificationError::SynthesisError)?
+        .tap(|proof_result| tracing::debug!(?proof_result, elapsed = ?start.elapsed()))
+        .then_some(())
+        .ok_or(VerificationError::InvalidProof)
+    }
+
+    /// Verifies the proof like [`Self::verify`], unless it was recorded in
+    /// `verified` by a batch of spend proofs.
+    pub fn verify_unless_batched(
+        &self,
+        vk: &PreparedVerifyingKey<Bls12_377>,
+        public: SpendProofPublic,
+        verified: &BTreeSet<[u8; 32]>,
+    ) -> Result<(), VerificationError> {
+        let public_inputs = Self::public_inputs(public.clone())?;
+        if batch::is_verified(verified, vk, &self.0, &public_inputs) {
+            tracing::debug!("proof was verified in a batch");
+            return Ok(());
+        }
+        self.verify(vk, public)
+    }
+
+    /// Adds this proof to a batch of spend proofs to verify at once.
+    pub fn queue(
+        &self,
+        batch: &mut BatchVerifier,
+        public: SpendProofPublic,
+    ) -> Result<(), VerificationError> {
+        batch
+            .queue(self.0, Self::public_inputs(public)?)
+            .map_err(VerificationError::ProofDeserialize)
+    }
+
+    fn public_inputs(
         SpendProofPublic {
             anchor: Root(anchor),
             balance_commitment: Commitment(balance_commitment),
             nullifier: Nullifier(nullifier),
             rk,
         }: SpendProofPublic,
-    ) -> Result<(), VerificationError> {
-        let proof = Proof::deserialize_compressed_unchecked(&self.0[..])
-            .map_err(VerificationError::ProofDeserialize)?;
+    ) -> Result<Vec<Fq>, VerificationError> {
         let element_rk = decaf377::Encoding(rk.to_bytes())
             .vartime_decompress()
             .map_err(VerificationError::DecompressRk)?;
@@ -338,7 +385,7 @@ impl SpendProof {
         }
 
         use VerificationError::*;
-        let public_inputs = [
+        Ok([
             to_field_elements!(Fq::from(anchor), Anchor),
             to_field_elements!(balance_commitment, BalanceCommitment),
             to_field_elements!(nullifier, Nullifier),
@@ -347,18 +394,7 @@ impl SpendProof {
         .into_iter()
         .flatten()
         .collect::<Vec<_>>()
-        .tap(|public_inputs| tracing::trace!(?public_inputs));
-
-        let start = std::time::Instant::now();
-        Groth16::<Bls12_377, LibsnarkReduction>::verify_with_processed_vk(
-            vk,
-            public_inputs.as_slice(),
-            &proof,
-        )
-        .map_err(VerificationError::SynthesisError)?
-        .tap(|proof_result| tracing::debug!(?proof_result, elapsed = ?start.elapsed()))
-        .then_some(())
-        .ok_or(VerificationError::InvalidProof)
+        .tap(|public_inputs| tracing::trace!(?public_inputs)))
     }
 }
 
@@ -11,6 +11,19 @@ pub mod denom_metadata_by_asset {
     }
 }
 
+pub mod supply {
+    use penumbra_asset::asset;
+    use std::string::String;
+
+    pub fn prefix() -> &'static str {
+        "shielded_pool/supply/"
+    }
+
+    pub fn by_asset_id(asset_id: &asset::Id) -> String {
+        format!("shielded_pool/supply/{asset_id}")
+    }
+}
+
 // State keys used to temporarily store payloads and nullifiers to be inserted into the compact
 // block
 pub fn pending_notes() -> &'static str {
@@ -21,6 +34,10 @@ pub fn pending_rolled_up_payloads() -> &'static str {
     "shielded_pool/pending_rolled_up_payloads"
 }
 
+pub fn pending_supply_changes() -> &'static str {
+    "shielded_pool/pending_supply_changes"
+}
+
 pub fn shielded_pool_params() -> &'static str {
     "shielded_pool/params"
 }
@@ -0,0 +1,158 @@
+use penumbra_num::Amount;
+use penumbra_proto::{penumbra::core::component::shielded_pool::v1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+/// A change the chain itself makes to the supply of an asset, outside of the
+/// value balance of transactions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SupplyChange {
+    /// Value issued at genesis, as notes or as the initial Community Pool balance.
+    Genesis,
+    /// Staking rewards paid by funding streams, as notes or to the Community Pool.
+    ///
+    /// Rewards to delegators aren't included: they're paid by the delegation
+    /// token exchange rate when an `UndelegateClaim` mints the staking token,
+    /// and that mint can't be told apart from the return of the delegated
+    /// principal, which was burned by the delegation.
+    StakingReward,
+    /// Value credited by an inbound ICS20 transfer, or by the refund of an outbound one.
+    IbcInbound,
+    /// Value removed by an ICS20 withdrawal.
+    IbcOutbound,
+    /// Value deposited into the Community Pool.
+    CommunityPoolDeposit,
+    /// Value withdrawn from the Community Pool.
+    CommunityPoolWithdrawal,
+}
+
+/// The changes the chain itself has made to the supply of an asset since genesis.
+///
+/// Transactions convert value between assets, e.g. by delegating or swapping,
+/// and burn fees, so these totals don't account for the supply of assets that
+/// transactions create or destroy. For every other asset, they show how much
+/// the chain has issued, and where the value it holds on behalf of others,
+/// in IBC escrows and in the Community Pool, came from.
+///
+/// In particular, the staking token isn't audited by these totals: delegating
+/// burns it, and undelegating mints it back along with the delegator's share of
+/// staking rewards, neither of which is recorded.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::AssetSupply", into = "pb::AssetSupply")]
+pub struct AssetSupply {
+    pub genesis: Amount,
+    pub staking_rewards: Amount,
+    pub ibc_inbound: Amount,
+    pub ibc_outbound: Amount,
+    pub community_pool_deposits: Amount,
+    pub community_pool_withdrawals: Amount,
+}
+
+impl AssetSupply {
+    /// Adds `amount` to the total for the given kind of change.
+    pub fn record(&mut self, change: SupplyChange, amount: Amount) {
+        let total = match change {
+            SupplyChange::Genesis => &mut self.genesis,
+            SupplyChange::StakingReward => &mut self.staking_rewards,
+            SupplyChange::IbcInbound => &mut self.ibc_inbound,
+            SupplyChange::IbcOutbound => &mut self.ibc_outbound,
+            SupplyChange::CommunityPoolDeposit => &mut self.community_pool_deposits,
+            SupplyChange::CommunityPoolWithdrawal => &mut self.community_pool_withdrawals,
+        };
+        *total += amount;
+    }
+
+    /// Adds the totals of `other` to these.
+    pub fn merge(&mut self, other: &AssetSupply) {
+        self.genesis += other.genesis;
+        self.staking_rewards += other.staking_rewards;
+        self.ibc_inbound += other.ibc_inbound;
+        self.ibc_outbound += other.ibc_outbound;
+        self.community_pool_deposits += other.community_pool_deposits;
+        self.community_pool_withdrawals += other.community_pool_withdrawals;
+    }
+
+    /// The amount issued by the chain: what was issued at genesis and as staking
+    /// rewards, plus inbound IBC transfers, less outbound ones.
+    ///
+    /// Returns `None` if more left through IBC than the chain issued, which is
+    /// only possible for assets that transactions create. For the staking
+    /// token, this doesn't include delegator rewards, so it isn't the supply.
+    pub fn issued(&self) -> Option<Amount> {
+        self.genesis
+            .checked_add(&self.staking_rewards)?
+            .checked_add(&self.ibc_inbound)?
+            .checked_sub(&self.ibc_outbound)
+    }
+
+    /// The balance the Community Pool should have: its deposits less its withdrawals.
+    pub fn community_pool_balance(&self) -> Option<Amount> {
+        self.community_pool_deposits
+            .checked_sub(&self.community_pool_withdrawals)
+    }
+}
+
+impl DomainType for AssetSupply {
+    type Proto = pb::AssetSupply;
+}
+
+impl From<AssetSupply> for pb::AssetSupply {
+    fn from(supply: AssetSupply) -> Self {
+        pb::AssetSupply {
+            genesis: Some(supply.genesis.into()),
+            staking_rewards: Some(supply.staking_rewards.into()),
+            ibc_inbound: Some(supply.ibc_inbound.into()),
+            ibc_outbound: Some(supply.ibc_outbound.into()),
+            community_pool_deposits: Some(supply.community_pool_deposits.into()),
+            community_pool_withdrawals: Some(supply.community_pool_withdrawals.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::AssetSupply> for AssetSupply {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::AssetSupply) -> anyhow::Result<Self> {
+        // Unset totals are zero, so that totals added by later upgrades
+        // don't need a migration.
+        let amount = |amount: Option<penumbra_proto::core::num::v1::Amount>| {
+            amount
+                .map(Amount::try_from)
+                .transpose()
+                .map(Option::unwrap_or_default)
+        };
+        Ok(AssetSupply {
+            genesis: amount(msg.genesis)?,
+            staking_rewards: amount(msg.staking_rewards)?,
+            ibc_inbound: amount(msg.ibc_inbound)?,
+            ibc_outbound: amount(msg.ibc_outbound)?,
+            community_pool_deposits: amount(msg.community_pool_deposits)?,
+            community_pool_withdrawals: amount(msg.community_pool_withdrawals)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn issued_nets_ibc_transfers() {
+        let mut supply = AssetSupply::default();
+        supply.record(SupplyChange::Genesis, 100u64.into());
+        supply.record(SupplyChange::StakingReward, 10u64.into());
+        supply.record(SupplyChange::IbcOutbound, 30u64.into());
+        supply.record(SupplyChange::IbcInbound, 5u64.into());
+        assert_eq!(supply.issued(), Some(85u64.into()));
+
+        supply.record(SupplyChange::IbcOutbound, 100u64.into());
+        assert_eq!(supply.issued(), None);
+
+        let mut total = supply.clone();
+        total.merge(&supply);
+        assert_eq!(total.ibc_outbound, 260u64.into());
+        assert_eq!(
+            AssetSupply::try_from(pb::AssetSupply::from(total.clone())).unwrap(),
+            total
+        );
+    }
+}
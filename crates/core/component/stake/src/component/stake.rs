@@ -118,6 +118,26 @@ impl Component for Staking {
             .expect("should be able to build initial tendermint validator updates");
     }
 
+    #[instrument(name = "staking", skip(state))]
+    async fn export_genesis<S: StateRead>(state: &S) -> Result<Self::AppState> {
+        // Validators are exported by their definitions. The delegations to them are
+        // held in the shielded pool, so they can't be.
+        let validators = state
+            .prefix_proto::<penumbra_proto::core::component::stake::v1::Validator>(
+                state_key::validators::definitions::prefix(),
+            )
+            .map_ok(|(_key, validator)| validator)
+            .try_collect()
+            .await?;
+        let staking_genesis = crate::genesis::Content {
+            stake_params: state.get_stake_params().await?,
+            validators,
+        };
+        let sp_genesis =
+            penumbra_shielded_pool::component::ShieldedPool::export_genesis(state).await?;
+        Ok((staking_genesis, sp_genesis))
+    }
+
     #[instrument(name = "staking", skip(state, begin_block))]
     async fn begin_block<S: StateWrite + 'static>(
         state: &mut Arc<S>,
@@ -15,6 +15,9 @@ use rand::{CryptoRng, Rng};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
+mod ownership;
+pub use ownership::{AddressOwnershipProof, ADDRESS_OWNERSHIP_PROOF_LEN_BYTES};
+
 mod r1cs;
 pub use r1cs::AddressVar;
 
@@ -0,0 +1,106 @@
+use ark_ff::PrimeField;
+use decaf377::Fr;
+
+use crate::{prf, Address};
+
+/// The length of an [`AddressOwnershipProof`] in bytes.
+pub const ADDRESS_OWNERSHIP_PROOF_LEN_BYTES: usize = 64;
+
+/// A proof that the creator of some message controls an [`Address`].
+///
+/// This is a Schnorr proof of knowledge of the incoming viewing key `ivk` for
+/// which the address's transmission key is `pk_d = [ivk] B_d`, over its
+/// diversified generator `B_d`, bound to the message. Anyone who can decrypt
+/// notes sent to the address can create one, and anyone who knows the address
+/// can check it, without learning anything else about the key.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct AddressOwnershipProof {
+    /// The encoding of the commitment `R = [k] B_d`.
+    r: [u8; 32],
+    /// The response `s = k + c * ivk`.
+    s: Fr,
+}
+
+impl AddressOwnershipProof {
+    /// Proves knowledge of `ivk` for `address`, for the given `message`.
+    ///
+    /// The nonce is derived from the key, the address, and the message, so
+    /// proving is deterministic.
+    pub(crate) fn new(ivk: &Fr, address: &Address, message: &[u8]) -> Self {
+        let b_d = address.diversified_generator();
+
+        let mut nonce_input = address.to_vec();
+        nonce_input.extend_from_slice(message);
+        let k: Fr = prf::expand_ff(b"Penumbra_AddrOPk", &ivk.to_bytes(), &nonce_input);
+        let r = (k * b_d).vartime_compress().0;
+
+        let c = challenge(&r, address, message);
+        Self { r, s: k + c * ivk }
+    }
+
+    /// Checks that this proof shows control of `address`, for the given `message`.
+    pub fn verify(&self, address: &Address, message: &[u8]) -> anyhow::Result<()> {
+        let b_d = address.diversified_generator();
+        let pk_d = decaf377::Encoding(address.transmission_key().0)
+            .vartime_decompress()
+            .map_err(|_| anyhow::anyhow!("invalid transmission key"))?;
+        let r = decaf377::Encoding(self.r)
+            .vartime_decompress()
+            .map_err(|_| anyhow::anyhow!("invalid address ownership proof commitment"))?;
+
+        let c = challenge(&self.r, address, message);
+        anyhow::ensure!(
+            self.s * b_d == r + c * pk_d,
+            "address ownership proof does not verify"
+        );
+
+        Ok(())
+    }
+
+    pub fn to_bytes(&self) -> [u8; ADDRESS_OWNERSHIP_PROOF_LEN_BYTES] {
+        let mut bytes = [0u8; ADDRESS_OWNERSHIP_PROOF_LEN_BYTES];
+        bytes[0..32].copy_from_slice(&self.r);
+        bytes[32..64].copy_from_slice(&self.s.to_bytes());
+        bytes
+    }
+}
+
+/// The Fiat-Shamir challenge, binding the commitment, the address, and the message.
+fn challenge(r: &[u8; 32], address: &Address, message: &[u8]) -> Fr {
+    let hash = blake2b_simd::Params::new()
+        .personal(b"Penumbra_AddrOwn")
+        .to_state()
+        .update(r)
+        .update(&address.to_vec())
+        .update(message)
+        .finalize();
+    Fr::from_le_bytes_mod_order(hash.as_bytes())
+}
+
+impl std::fmt::Debug for AddressOwnershipProof {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!(
+            "AddressOwnershipProof({})",
+            hex::encode(self.to_bytes())
+        ))
+    }
+}
+
+impl TryFrom<&[u8]> for AddressOwnershipProof {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; ADDRESS_OWNERSHIP_PROOF_LEN_BYTES] = bytes.try_into().map_err(|_| {
+            anyhow::anyhow!(
+                "address ownership proof must be {} bytes",
+                ADDRESS_OWNERSHIP_PROOF_LEN_BYTES
+            )
+        })?;
+        let s = Fr::from_bytes_checked(&bytes[32..64].try_into().expect("32 bytes"))
+            .map_err(|_| anyhow::anyhow!("invalid address ownership proof response"))?;
+        Ok(Self {
+            r: bytes[0..32].try_into().expect("32 bytes"),
+            s,
+        })
+    }
+}
@@ -5,7 +5,7 @@ mod nullifier;
 pub use nullifier::{NullifierKey, NullifierKeyVar, NK_LEN_BYTES};
 
 mod seed_phrase;
-pub use seed_phrase::SeedPhrase;
+pub use seed_phrase::{SeedPhrase, Wordlist};
 
 mod spend;
 pub use spend::{SpendKey, SpendKeyBytes, SPENDKEY_LEN_BYTES};
@@ -23,7 +23,7 @@ mod ovk;
 pub(crate) use fvk::IVK_DOMAIN_SEP;
 pub use fvk::{
     r1cs::{AuthorizationKeyVar, RandomizedVerificationKey, SpendAuthRandomizerVar},
-    FullViewingKey,
+    FullViewingKey, FullViewingKeyExport,
 };
 pub use ivk::{IncomingViewingKey, IncomingViewingKeyVar, IVK_LEN_BYTES};
 pub use ovk::{OutgoingViewingKey, OVK_LEN_BYTES};
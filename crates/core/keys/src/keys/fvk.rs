@@ -17,8 +17,11 @@ use crate::{
 
 use super::{AddressIndex, DiversifierKey, IncomingViewingKey, NullifierKey, OutgoingViewingKey};
 
+mod export;
 pub mod r1cs;
 
+pub use export::FullViewingKeyExport;
+
 pub(crate) static IVK_DOMAIN_SEP: Lazy<Fq> =
     Lazy::new(|| Fq::from_le_bytes_mod_order(b"penumbra.derive.ivk"));
 
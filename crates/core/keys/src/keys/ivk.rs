@@ -10,6 +10,7 @@ use decaf377::{
 
 use super::{AddressIndex, Diversifier, DiversifierKey};
 use crate::{
+    address::AddressOwnershipProof,
     fmd, ka,
     keys::{AuthorizationKeyVar, NullifierKeyVar, IVK_DOMAIN_SEP},
     prf, Address,
@@ -80,6 +81,21 @@ impl IncomingViewingKey {
         self.ivk.diversified_public(address.diversified_generator()) == *address.transmission_key()
     }
 
+    /// Proves that the creator of `message` controls `address`.
+    ///
+    /// Returns `None` if the address is not viewed by this incoming viewing key.
+    pub fn prove_address_ownership(
+        &self,
+        address: &Address,
+        message: &[u8],
+    ) -> Option<AddressOwnershipProof> {
+        if !self.views_address(address) {
+            return None;
+        }
+        let ivk = Fr::from_bytes_checked(&self.ivk.to_bytes()).expect("ivk is a valid scalar");
+        Some(AddressOwnershipProof::new(&ivk, address, message))
+    }
+
     /// Returns the index of the given address, if the address is viewed by this
     /// viewing key; otherwise, returns `None`.
     // TODO: re-evaluate relative to FVK methods
@@ -195,6 +211,31 @@ mod test {
         assert!(ivk.views_address(&own_address));
     }
 
+    #[test]
+    fn address_ownership_proofs_verify_only_for_their_address_and_message() {
+        let rng = rand::rngs::OsRng;
+        let spend_key =
+            SpendKey::from_seed_phrase_bip44(SeedPhrase::generate(rng), &Bip44Path::new(0));
+        let ivk = spend_key.full_viewing_key().incoming();
+        let own_address = ivk.payment_address(AddressIndex::from(0u32)).0;
+        let other_address = ivk.payment_address(AddressIndex::from(1u32)).0;
+
+        let proof = ivk
+            .prove_address_ownership(&own_address, b"hello")
+            .expect("ivk views its own address");
+        assert!(proof.verify(&own_address, b"hello").is_ok());
+        assert!(proof.verify(&own_address, b"goodbye").is_err());
+        assert!(proof.verify(&other_address, b"hello").is_err());
+
+        let decoded = AddressOwnershipProof::try_from(&proof.to_bytes()[..]).unwrap();
+        assert_eq!(decoded, proof);
+
+        let foreign_address = Address::dummy(&mut rand::rngs::OsRng);
+        assert!(ivk
+            .prove_address_ownership(&foreign_address, b"hello")
+            .is_none());
+    }
+
     proptest! {
         #[test]
         fn views_address_succeeds_on_own_ephemeral_address(address_index in any::<u32>()) {
@@ -1,10 +1,18 @@
 use std::fmt;
 
+use hmac::Hmac;
+use once_cell::sync::Lazy;
+use pbkdf2::pbkdf2;
 use rand_core::{CryptoRng, RngCore};
 use sha2::Digest;
+use unicode_normalization::UnicodeNormalization;
 
+mod wordlist;
 mod words;
-use words::BIP39_WORDS;
+pub use wordlist::{Wordlist, NUM_WORDS_IN_WORDLIST};
+
+/// The English wordlist, used unless another one is given.
+static ENGLISH: Lazy<Wordlist> = Lazy::new(Wordlist::english);
 
 pub const NUM_PBKDF2_ROUNDS: u32 = 2048;
 pub const NUM_WORDS_SHORT: usize = 12;
@@ -86,8 +94,20 @@ impl SeedPhrase {
         Self::from_randomness(&randomness)
     }
 
+    /// Randomly generates a 24 word BIP39 [`SeedPhrase`] from the given [`Wordlist`].
+    pub fn generate_with<R: RngCore + CryptoRng>(mut rng: R, wordlist: &Wordlist) -> Self {
+        let mut randomness = [0u8; NUM_ENTROPY_BITS_LONG / NUM_BITS_PER_BYTE];
+        rng.fill_bytes(&mut randomness);
+        Self::from_randomness_with(&randomness, wordlist)
+    }
+
     /// Given bytes of randomness, generate a [`SeedPhrase`].
     pub fn from_randomness(randomness: &[u8]) -> Self {
+        Self::from_randomness_with(randomness, &ENGLISH)
+    }
+
+    /// Given bytes of randomness, generate a [`SeedPhrase`] from the given [`Wordlist`].
+    pub fn from_randomness_with(randomness: &[u8], wordlist: &Wordlist) -> Self {
         // We infer if the seed phrase will be a valid length based on the number of
         // random bytes generated.
         let seed_phrase_type = SeedPhraseType::from_randomness_length(randomness.len())
@@ -118,7 +138,7 @@ impl SeedPhrase {
         for (i, word) in words.iter_mut().enumerate() {
             let bits_this_word = &bits[i * NUM_BITS_PER_WORD..(i + 1) * NUM_BITS_PER_WORD];
             let word_index = convert_bits_to_usize(bits_this_word);
-            *word = BIP39_WORDS[word_index].to_string();
+            *word = wordlist.word(word_index).to_string();
         }
         SeedPhrase(words)
     }
@@ -128,19 +148,60 @@ impl SeedPhrase {
         self.0.len()
     }
 
+    /// Parses a [`SeedPhrase`] of words from the given [`Wordlist`].
+    pub fn parse_with(s: &str, wordlist: &Wordlist) -> anyhow::Result<Self> {
+        let words = s
+            .nfkd()
+            .collect::<String>()
+            .split_whitespace()
+            .map(|w| w.to_lowercase())
+            .collect::<Vec<String>>();
+
+        if words.len() != NUM_WORDS_LONG && words.len() != NUM_WORDS_SHORT {
+            anyhow::bail!(
+                "seed phrases should have {} or {} words",
+                NUM_WORDS_LONG,
+                NUM_WORDS_SHORT
+            );
+        }
+
+        let seed_phrase = SeedPhrase(words);
+        seed_phrase.verify_checksum_with(wordlist)?;
+
+        Ok(seed_phrase)
+    }
+
+    /// Derives the 64-byte BIP39 seed of this [`SeedPhrase`], protected by the
+    /// optional `passphrase` (the "25th word"), which is empty if unused.
+    ///
+    /// Each passphrase derives a distinct seed, and so distinct keys.
+    pub fn to_seed(&self, passphrase: &str) -> [u8; 64] {
+        let password = self.to_string().nfkd().collect::<String>();
+        let salt = format!("mnemonic{}", passphrase.nfkd());
+        let mut seed_bytes = [0u8; 64];
+        pbkdf2::<Hmac<sha2::Sha512>>(
+            password.as_bytes(),
+            salt.as_bytes(),
+            NUM_PBKDF2_ROUNDS,
+            &mut seed_bytes,
+        )
+        .expect("seed phrase hash always succeeds");
+        seed_bytes
+    }
+
     /// Verify the checksum of this [`SeedPhrase`].
     fn verify_checksum(&self) -> anyhow::Result<()> {
+        self.verify_checksum_with(&ENGLISH)
+    }
+
+    /// Verify the checksum of this [`SeedPhrase`] of words from the given [`Wordlist`].
+    fn verify_checksum_with(&self, wordlist: &Wordlist) -> anyhow::Result<()> {
         let seed_phrase_type = SeedPhraseType::from_length(self.length())?;
         let mut bits = vec![false; seed_phrase_type.num_total_bits()];
         for (i, word) in self.0.iter().enumerate() {
-            if !BIP39_WORDS.contains(&word.as_str()) {
-                anyhow::bail!("invalid word in BIP39 seed phrase");
-            }
-
-            let word_index = BIP39_WORDS
-                .iter()
-                .position(|&x| x == word)
-                .expect("can get index of word");
+            let word_index = wordlist
+                .index_of(word)
+                .ok_or_else(|| anyhow::anyhow!("invalid word in BIP39 seed phrase"))?;
             let word_bits = &mut bits[i * NUM_BITS_PER_WORD..(i + 1) * NUM_BITS_PER_WORD];
             word_bits
                 .iter_mut()
@@ -189,23 +250,7 @@ impl std::str::FromStr for SeedPhrase {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let words = s
-            .split_whitespace()
-            .map(|w| w.to_lowercase())
-            .collect::<Vec<String>>();
-
-        if words.len() != NUM_WORDS_LONG && words.len() != NUM_WORDS_SHORT {
-            anyhow::bail!(
-                "seed phrases should have {} or {} words",
-                NUM_WORDS_LONG,
-                NUM_WORDS_SHORT
-            );
-        }
-
-        let seed_phrase = SeedPhrase(words);
-        seed_phrase.verify_checksum()?;
-
-        Ok(seed_phrase)
+        Self::parse_with(s, &ENGLISH)
     }
 }
 
@@ -305,6 +350,10 @@ mod tests {
             .expect("seed phrase hash always succeeds");
             let seed_result = hex::encode(seed_bytes);
             assert_eq!(seed_result, seed_result_arr[i]);
+            assert_eq!(
+                hex::encode(actual_phrase.to_seed("TREZOR")),
+                seed_result_arr[i]
+            );
         }
     }
 
@@ -328,4 +377,28 @@ mod tests {
             assert!(SeedPhrase::from_str(phrase).is_ok());
         }
     }
+
+    #[test]
+    fn seed_phrase_with_wordlist() {
+        let english = Wordlist::english();
+        let wordlist = Wordlist::from_words(
+            (0..NUM_WORDS_IN_WORDLIST).map(|i| format!("{}x", english.word(i))),
+        )
+        .unwrap();
+
+        let randomness = [7u8; NUM_ENTROPY_BITS_LONG / NUM_BITS_PER_BYTE];
+        let phrase = SeedPhrase::from_randomness_with(&randomness, &wordlist);
+        let parsed = SeedPhrase::parse_with(&phrase.to_string(), &wordlist).unwrap();
+        assert_eq!(parsed.0, phrase.0);
+        assert!(SeedPhrase::from_str(&phrase.to_string()).is_err());
+
+        // The same entropy encodes to a different phrase, and so a different seed.
+        let english_phrase = SeedPhrase::from_randomness(&randomness);
+        assert_ne!(english_phrase.to_seed(""), phrase.to_seed(""));
+
+        // Wordlists must have distinct words.
+        assert!(
+            Wordlist::from_words((0..NUM_WORDS_IN_WORDLIST).map(|_| "zoo".to_string())).is_err()
+        );
+    }
 }
@@ -0,0 +1,82 @@
+use std::collections::BTreeMap;
+
+use unicode_normalization::UnicodeNormalization;
+
+use super::words::BIP39_WORDS;
+
+/// The number of words in a BIP39 wordlist.
+pub const NUM_WORDS_IN_WORDLIST: usize = 2048;
+
+/// A BIP39 wordlist, used to encode a [`SeedPhrase`](super::SeedPhrase).
+///
+/// Only the English wordlist is bundled. The other BIP39 wordlists, e.g. to
+/// import a seed phrase from a hardware wallet configured for another
+/// language, can be loaded with [`Wordlist::from_words`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Wordlist {
+    words: Vec<String>,
+    indices: BTreeMap<String, usize>,
+}
+
+impl Wordlist {
+    /// The English BIP39 wordlist.
+    pub fn english() -> Self {
+        Self::from_words(BIP39_WORDS.iter().map(|word| word.to_string()))
+            .expect("the English wordlist is valid")
+    }
+
+    /// Creates a wordlist from its 2048 distinct words, in order.
+    ///
+    /// The words are normalized to NFKD, as BIP39 requires.
+    pub fn from_words(words: impl IntoIterator<Item = String>) -> anyhow::Result<Self> {
+        let words = words
+            .into_iter()
+            .map(|word| word.trim().nfkd().collect::<String>())
+            .collect::<Vec<_>>();
+        if words.len() != NUM_WORDS_IN_WORDLIST {
+            anyhow::bail!(
+                "BIP39 wordlists have {} words, got {}",
+                NUM_WORDS_IN_WORDLIST,
+                words.len()
+            );
+        }
+
+        let mut indices = BTreeMap::new();
+        for (index, word) in words.iter().enumerate() {
+            if word.is_empty() || word.contains(char::is_whitespace) {
+                anyhow::bail!("invalid word {word:?} in BIP39 wordlist");
+            }
+            if indices.insert(word.clone(), index).is_some() {
+                anyhow::bail!("duplicate word {word:?} in BIP39 wordlist");
+            }
+        }
+
+        Ok(Self { words, indices })
+    }
+
+    /// Parses a wordlist in the format of the BIP39 repository, one word per line.
+    pub fn parse(contents: &str) -> anyhow::Result<Self> {
+        Self::from_words(
+            contents
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(str::to_string),
+        )
+    }
+
+    /// The word at `index`.
+    pub fn word(&self, index: usize) -> &str {
+        &self.words[index]
+    }
+
+    /// The index of `word`, if it's in the wordlist.
+    pub fn index_of(&self, word: &str) -> Option<usize> {
+        self.indices.get(word).copied()
+    }
+}
+
+impl Default for Wordlist {
+    fn default() -> Self {
+        Self::english()
+    }
+}
@@ -105,16 +105,19 @@ impl SpendKey {
     }
 
     pub fn from_seed_phrase_bip44(seed_phrase: SeedPhrase, path: &Bip44Path) -> Self {
-        let password = format!("{seed_phrase}");
-        let salt = "mnemonic";
-        let mut seed_bytes = [0u8; 64];
-        pbkdf2::<Hmac<sha2::Sha512>>(
-            password.as_bytes(),
-            salt.as_bytes(),
-            NUM_PBKDF2_ROUNDS,
-            &mut seed_bytes,
-        )
-        .expect("seed phrase hash always succeeds");
+        Self::from_seed_phrase_bip44_with_passphrase(seed_phrase, "", path)
+    }
+
+    /// Deterministically generate a [`SpendKey`] from a [`SeedPhrase`] protected by
+    /// a BIP39 passphrase (sometimes called the "25th word").
+    ///
+    /// Different passphrases derive unrelated spend keys from the same seed phrase.
+    pub fn from_seed_phrase_bip44_with_passphrase(
+        seed_phrase: SeedPhrase,
+        passphrase: &str,
+        path: &Bip44Path,
+    ) -> Self {
+        let seed_bytes = seed_phrase.to_seed(passphrase);
 
         // Now we derive the child keys from the BIP44 path. There are up five levels
         // in the BIP44 path: purpose, coin type, account, change, and address index.
@@ -216,4 +219,19 @@ mod tests {
 
         assert_eq!(software_spendkey.to_bytes(), expected_spendkey);
     }
+
+    #[test]
+    fn bip44_passphrase_derives_distinct_keys() {
+        let seed = SeedPhrase::from_str("comfort ten front cycle churn burger oak absent rice ice urge result art couple benefit cabbage frequent obscure hurry trick segment cool job debate").unwrap();
+        let derivation_path = Bip44Path::new(0);
+
+        let without = SpendKey::from_seed_phrase_bip44(seed.clone(), &derivation_path);
+        let empty =
+            SpendKey::from_seed_phrase_bip44_with_passphrase(seed.clone(), "", &derivation_path);
+        let with =
+            SpendKey::from_seed_phrase_bip44_with_passphrase(seed, "TREZOR", &derivation_path);
+
+        assert_eq!(without.to_bytes(), empty.to_bytes());
+        assert_ne!(without.to_bytes(), with.to_bytes());
+    }
 }
@@ -9,6 +9,7 @@ use decaf377_rdsa as rdsa;
 pub mod address;
 pub mod keys;
 pub mod prf;
+pub mod signature_batch;
 pub mod symmetric;
 pub mod test_keys;
 
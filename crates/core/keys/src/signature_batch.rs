@@ -0,0 +1,181 @@
+//! Batch verification of spend authorization and binding signatures.
+//!
+//! A [`SignatureBatch`] verifies many `decaf377-rdsa` signatures at once, with
+//! a single multiscalar multiplication rather than one per signature. If the
+//! batch fails to verify, its signatures are verified one by one, to find
+//! which of them are invalid.
+//!
+//! Signatures that verify are recorded in a set owned by the caller, so that
+//! checking one of them again afterwards, with [`verify`], can skip the work.
+
+use std::{any::type_name, collections::BTreeSet};
+
+use rand_core::OsRng;
+use sha2::{Digest, Sha256};
+
+use crate::rdsa::{
+    batch, Binding, Domain, Error, Signature, SpendAuth, VerificationKey, VerificationKeyBytes,
+};
+
+/// A batch of signatures to verify at once, each tagged with a `T`, e.g. the
+/// index of the transaction it belongs to.
+pub struct SignatureBatch<T> {
+    items: Vec<(T, batch::Item, [u8; 32])>,
+}
+
+impl<T> Default for SignatureBatch<T> {
+    fn default() -> Self {
+        Self { items: Vec::new() }
+    }
+}
+
+impl<T: Ord> SignatureBatch<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a spend authorization signature of `msg` to the batch.
+    pub fn queue_spend_auth(
+        &mut self,
+        tag: T,
+        vk: VerificationKey<SpendAuth>,
+        sig: Signature<SpendAuth>,
+        msg: &[u8],
+    ) {
+        let key = verified_key(&vk, &sig, msg);
+        let item = (VerificationKeyBytes::from(vk), sig, &msg).into();
+        self.items.push((tag, item, key));
+    }
+
+    /// Adds a binding signature of `msg` to the batch.
+    pub fn queue_binding(
+        &mut self,
+        tag: T,
+        vk: VerificationKey<Binding>,
+        sig: Signature<Binding>,
+        msg: &[u8],
+    ) {
+        let key = verified_key(&vk, &sig, msg);
+        let item = (VerificationKeyBytes::from(vk), sig, &msg).into();
+        self.items.push((tag, item, key));
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Verifies the signatures of the batch, returning the tags of those that
+    /// are invalid.
+    ///
+    /// Only if the batch as a whole fails to verify are its signatures verified
+    /// individually. Either way, the valid signatures are recorded in
+    /// `verified`.
+    pub fn verify(self, verified: &mut BTreeSet<[u8; 32]>) -> BTreeSet<T> {
+        // Signatures that were already verified, e.g. twice in the same
+        // proposal, need not be checked again.
+        let items = self
+            .items
+            .into_iter()
+            .filter(|(_, _, key)| !verified.contains(key))
+            .collect::<Vec<_>>();
+        if items.is_empty() {
+            return BTreeSet::new();
+        }
+
+        let mut verifier = batch::Verifier::new();
+        for (_, item, _) in &items {
+            verifier.queue(item.clone());
+        }
+        let start = std::time::Instant::now();
+        let batch_verified = verifier.verify(OsRng).is_ok();
+        tracing::debug!(
+            signatures = items.len(),
+            verified = batch_verified,
+            elapsed = ?start.elapsed(),
+            "verified batch of signatures"
+        );
+        if batch_verified {
+            verified.extend(items.into_iter().map(|(_, _, key)| key));
+            return BTreeSet::new();
+        }
+
+        let mut invalid = BTreeSet::new();
+        for (tag, item, key) in items {
+            match item.verify_single() {
+                Ok(()) => {
+                    verified.insert(key);
+                }
+                Err(_) => {
+                    invalid.insert(tag);
+                }
+            }
+        }
+        invalid
+    }
+}
+
+/// Verifies `sig` on `msg` under `vk`, unless it was recorded in `verified` by
+/// a [`SignatureBatch`].
+pub fn verify<D: Domain>(
+    vk: &VerificationKey<D>,
+    msg: &[u8],
+    sig: &Signature<D>,
+    verified: &BTreeSet<[u8; 32]>,
+) -> Result<(), Error> {
+    if verified.contains(&verified_key(vk, sig, msg)) {
+        return Ok(());
+    }
+    vk.verify(msg, sig)
+}
+
+fn verified_key<D: Domain>(vk: &VerificationKey<D>, sig: &Signature<D>, msg: &[u8]) -> [u8; 32] {
+    // The same key bytes could be used in either domain, so the domain is part
+    // of what was verified.
+    Sha256::new()
+        .chain_update(type_name::<D>())
+        .chain_update(vk.to_bytes())
+        .chain_update(sig.to_bytes())
+        .chain_update(msg)
+        .finalize()
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rdsa::SigningKey;
+
+    #[test]
+    fn batch_reports_invalid_signatures() {
+        let spend_auth = SigningKey::<SpendAuth>::new(OsRng);
+        let binding = SigningKey::<Binding>::new(OsRng);
+        let vk = VerificationKey::from(&spend_auth);
+
+        let mut batch = SignatureBatch::new();
+        batch.queue_spend_auth(0, vk, spend_auth.sign(OsRng, b"zero"), b"zero");
+        batch.queue_binding(
+            1,
+            VerificationKey::from(&binding),
+            binding.sign(OsRng, b"one"),
+            b"one",
+        );
+        // A signature of another message.
+        batch.queue_spend_auth(2, vk, spend_auth.sign(OsRng, b"two"), b"three");
+        let mut verified = BTreeSet::new();
+        assert_eq!(batch.verify(&mut verified), BTreeSet::from([2]));
+        assert_eq!(verified.len(), 2);
+
+        // Signatures of a batch that verifies are recorded as verified.
+        let sig = spend_auth.sign(OsRng, b"four");
+        let mut batch = SignatureBatch::new();
+        batch.queue_spend_auth((), vk, sig, b"four");
+        assert!(batch.verify(&mut verified).is_empty());
+        assert!(verified.contains(&verified_key(&vk, &sig, b"four")));
+        assert!(verify(&vk, b"four", &sig, &verified).is_ok());
+        assert!(verify(&vk, b"five", &sig, &verified).is_err());
+    }
+}
@@ -31,12 +31,14 @@ pub enum Action {
     PositionOpen(penumbra_dex::lp::action::PositionOpen),
     PositionClose(penumbra_dex::lp::action::PositionClose),
     PositionWithdraw(penumbra_dex::lp::action::PositionWithdraw),
+    GaugeFund(penumbra_dex::GaugeFund),
 
     Delegate(penumbra_stake::Delegate),
     Undelegate(penumbra_stake::Undelegate),
     UndelegateClaim(penumbra_stake::UndelegateClaim),
 
     Ics20Withdrawal(penumbra_shielded_pool::Ics20Withdrawal),
+    NftWithdrawal(penumbra_shielded_pool::NftWithdrawal),
 
     CommunityPoolSpend(penumbra_community_pool::CommunityPoolSpend),
     CommunityPoolOutput(penumbra_community_pool::CommunityPoolOutput),
@@ -67,7 +69,9 @@ impl EffectingData for Action {
             Action::PositionOpen(p) => p.effect_hash(),
             Action::PositionClose(p) => p.effect_hash(),
             Action::PositionWithdraw(p) => p.effect_hash(),
+            Action::GaugeFund(g) => g.effect_hash(),
             Action::Ics20Withdrawal(w) => w.effect_hash(),
+            Action::NftWithdrawal(w) => w.effect_hash(),
             Action::CommunityPoolSpend(d) => d.effect_hash(),
             Action::CommunityPoolOutput(d) => d.effect_hash(),
             Action::CommunityPoolDeposit(d) => d.effect_hash(),
@@ -111,10 +115,12 @@ impl Action {
             Action::PositionWithdraw(_) => {
                 tracing::info_span!("PositionWithdraw", ?idx)
             }
+            Action::GaugeFund(_) => tracing::info_span!("GaugeFund", ?idx),
             Action::Delegate(_) => tracing::info_span!("Delegate", ?idx),
             Action::Undelegate(_) => tracing::info_span!("Undelegate", ?idx),
             Action::UndelegateClaim(_) => tracing::info_span!("UndelegateClaim", ?idx),
             Action::Ics20Withdrawal(_) => tracing::info_span!("Ics20Withdrawal", ?idx),
+            Action::NftWithdrawal(_) => tracing::info_span!("NftWithdrawal", ?idx),
             Action::CommunityPoolDeposit(_) => tracing::info_span!("CommunityPoolDeposit", ?idx),
             Action::CommunityPoolSpend(_) => tracing::info_span!("CommunityPoolSpend", ?idx),
             Action::CommunityPoolOutput(_) => tracing::info_span!("CommunityPoolOutput", ?idx),
@@ -145,6 +151,7 @@ impl Action {
             Action::PositionOpen(_) => 30,
             Action::PositionClose(_) => 31,
             Action::PositionWithdraw(_) => 32,
+            Action::GaugeFund(_) => 33,
             Action::Delegate(_) => 40,
             Action::Undelegate(_) => 41,
             Action::UndelegateClaim(_) => 42,
@@ -152,6 +159,7 @@ impl Action {
             Action::CommunityPoolOutput(_) => 51,
             Action::CommunityPoolDeposit(_) => 52,
             Action::Ics20Withdrawal(_) => 200,
+            Action::NftWithdrawal(_) => 201,
             Action::ActionDutchAuctionSchedule(_) => 53,
             Action::ActionDutchAuctionEnd(_) => 54,
             Action::ActionDutchAuctionWithdraw(_) => 55,
@@ -177,7 +185,9 @@ impl IsAction for Action {
             Action::PositionOpen(p) => p.balance_commitment(),
             Action::PositionClose(p) => p.balance_commitment(),
             Action::PositionWithdraw(p) => p.balance_commitment(),
+            Action::GaugeFund(g) => g.balance_commitment(),
             Action::Ics20Withdrawal(withdrawal) => withdrawal.balance_commitment(),
+            Action::NftWithdrawal(withdrawal) => withdrawal.balance_commitment(),
             Action::CommunityPoolDeposit(deposit) => deposit.balance_commitment(),
             Action::CommunityPoolSpend(spend) => spend.balance_commitment(),
             Action::CommunityPoolOutput(output) => output.balance_commitment(),
@@ -208,7 +218,9 @@ impl IsAction for Action {
             Action::PositionOpen(x) => x.view_from_perspective(txp),
             Action::PositionClose(x) => x.view_from_perspective(txp),
             Action::PositionWithdraw(x) => x.view_from_perspective(txp),
+            Action::GaugeFund(x) => x.view_from_perspective(txp),
             Action::Ics20Withdrawal(x) => x.view_from_perspective(txp),
+            Action::NftWithdrawal(x) => x.view_from_perspective(txp),
             Action::CommunityPoolSpend(x) => x.view_from_perspective(txp),
             Action::CommunityPoolOutput(x) => x.view_from_perspective(txp),
             Action::CommunityPoolDeposit(x) => x.view_from_perspective(txp),
@@ -279,9 +291,15 @@ impl From<Action> for pb::Action {
             Action::PositionWithdraw(inner) => pb::Action {
                 action: Some(pb::action::Action::PositionWithdraw(inner.into())),
             },
+            Action::GaugeFund(inner) => pb::Action {
+                action: Some(pb::action::Action::GaugeFund(inner.into())),
+            },
             Action::Ics20Withdrawal(withdrawal) => pb::Action {
                 action: Some(pb::action::Action::Ics20Withdrawal(withdrawal.into())),
             },
+            Action::NftWithdrawal(withdrawal) => pb::Action {
+                action: Some(pb::action::Action::NftWithdrawal(withdrawal.into())),
+            },
             Action::CommunityPoolSpend(inner) => pb::Action {
                 action: Some(pb::action::Action::CommunityPoolSpend(inner.into())),
             },
@@ -350,12 +368,16 @@ impl TryFrom<pb::Action> for Action {
             pb::action::Action::PositionWithdraw(inner) => {
                 Ok(Action::PositionWithdraw(inner.try_into()?))
             }
+            pb::action::Action::GaugeFund(inner) => Ok(Action::GaugeFund(inner.try_into()?)),
             pb::action::Action::PositionRewardClaim(_) => {
                 Err(anyhow!("PositionRewardClaim is deprecated and unsupported"))
             }
             pb::action::Action::Ics20Withdrawal(inner) => {
                 Ok(Action::Ics20Withdrawal(inner.try_into()?))
             }
+            pb::action::Action::NftWithdrawal(inner) => {
+                Ok(Action::NftWithdrawal(inner.try_into()?))
+            }
             pb::action::Action::CommunityPoolSpend(inner) => {
                 Ok(Action::CommunityPoolSpend(inner.try_into()?))
             }
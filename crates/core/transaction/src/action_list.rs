@@ -11,6 +11,55 @@ use penumbra_num::Amount;
 use penumbra_shielded_pool::{fmd, OutputPlan};
 use rand_core::{CryptoRng, RngCore};
 
+/// How much a planned transaction hides about its shape, at some cost in fees.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PrivacyLevel {
+    /// Actions are ordered by type, and otherwise in the order they were planned.
+    #[default]
+    Standard,
+    /// Actions of each type are also ordered by a hash keyed with a random seed, so that change
+    /// outputs can't be told apart from other outputs.
+    Shuffled,
+    /// As well as being shuffled, outputs are padded with dummy outputs to a power of two, so
+    /// that the number of outputs only reveals its bucket.
+    Padded,
+}
+
+impl PrivacyLevel {
+    /// The number of outputs a transaction with `outputs` outputs is padded to.
+    ///
+    /// Transactions without outputs aren't padded.
+    pub fn padded_outputs(&self, outputs: usize) -> usize {
+        match self {
+            PrivacyLevel::Padded if outputs > 0 => outputs.max(2).next_power_of_two(),
+            _ => outputs,
+        }
+    }
+}
+
+impl std::str::FromStr for PrivacyLevel {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "standard" => Ok(PrivacyLevel::Standard),
+            "shuffled" => Ok(PrivacyLevel::Shuffled),
+            "padded" => Ok(PrivacyLevel::Padded),
+            _ => anyhow::bail!("unknown privacy level {s}, expected standard, shuffled or padded"),
+        }
+    }
+}
+
+impl std::fmt::Display for PrivacyLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            PrivacyLevel::Standard => "standard",
+            PrivacyLevel::Shuffled => "shuffled",
+            PrivacyLevel::Padded => "padded",
+        })
+    }
+}
+
 /// A list of planned actions to be turned into a TransactionPlan.
 ///
 /// A transaction is a bundle of actions plus auxiliary data (like a memo). A
@@ -26,6 +75,9 @@ pub struct ActionList {
     // The fee is tracked as part of the ActionList so it can be adjusted
     // internally to handle special cases like swap claims.
     fee: Fee,
+    // Dummy outputs padding the transaction, according to the privacy level.
+    padding_outputs: Vec<OutputPlan>,
+    privacy_level: PrivacyLevel,
 }
 
 impl ActionList {
@@ -44,6 +96,16 @@ impl ActionList {
         &self.fee
     }
 
+    /// Returns the privacy level the transaction is planned with.
+    pub fn privacy_level(&self) -> PrivacyLevel {
+        self.privacy_level
+    }
+
+    /// Sets the privacy level to plan the transaction with.
+    pub fn set_privacy_level(&mut self, privacy_level: PrivacyLevel) {
+        self.privacy_level = privacy_level;
+    }
+
     /// Returns true if the resulting transaction would require a memo.
     pub fn requires_memo(&self) -> bool {
         let has_change_outputs = !self.change_outputs.is_empty();
@@ -58,7 +120,7 @@ impl ActionList {
     /// Convert this list of actions into a [`TransactionPlan`].
     pub fn into_plan<R: RngCore + CryptoRng>(
         self,
-        rng: R,
+        mut rng: R,
         fmd_params: &fmd::Parameters,
         mut transaction_parameters: TransactionParameters,
         memo_plan: Option<MemoPlan>,
@@ -70,16 +132,24 @@ impl ActionList {
                 .actions
                 .into_iter()
                 .chain(self.change_outputs.into_values().map(Into::into))
+                .chain(self.padding_outputs.into_iter().map(Into::into))
                 .collect(),
             transaction_parameters,
             memo: memo_plan,
             detection_data: None,
         };
-        plan.populate_detection_data(rng, fmd_params.precision);
+        plan.populate_detection_data(&mut rng, fmd_params.precision);
 
         // Implement a canonical ordering to the actions within the transaction
         // plan to reduce client distinguishability.
-        plan.sort_actions();
+        match self.privacy_level {
+            PrivacyLevel::Standard => plan.sort_actions(),
+            PrivacyLevel::Shuffled | PrivacyLevel::Padded => {
+                let mut seed = [0u8; 32];
+                rng.fill_bytes(&mut seed);
+                plan.shuffle_actions(&seed);
+            }
+        }
 
         Ok(plan)
     }
@@ -124,7 +194,7 @@ impl ActionList {
             // TODO missing AddAssign
             gas = gas + action.gas_cost();
         }
-        for action in self.change_outputs.values() {
+        for action in self.change_outputs.values().chain(&self.padding_outputs) {
             // TODO missing AddAssign
             // TODO missing GasCost impl on OutputPlan
             gas = gas + ActionPlan::from(action.clone()).gas_cost();
@@ -151,13 +221,17 @@ impl ActionList {
     /// case, change notes will be adjusted to cover the increase if possible.
     pub fn refresh_fee_and_change<R: RngCore + CryptoRng>(
         &mut self,
-        rng: R,
+        mut rng: R,
         gas_prices: &GasPrices,
         fee_tier: &FeeTier,
         change_address: &Address,
     ) {
         // First, refresh the change outputs, to capture any surplus imbalance.
-        self.refresh_change(rng, &change_address);
+        self.refresh_change(&mut rng, &change_address);
+
+        // Pad the outputs, including the change outputs, so that the fee
+        // covers the padding too.
+        self.refresh_padding(&mut rng);
 
         // Next, recompute the fee estimate for the actions and change outputs.
         let new_fee = self.compute_fee_estimate(gas_prices, fee_tier);
@@ -210,6 +284,26 @@ impl ActionList {
         }
     }
 
+    /// Refresh the dummy outputs padding the transaction to the number of
+    /// outputs required by its privacy level.
+    ///
+    /// This is best-effort: a change output that's later emptied by a fee
+    /// increase is removed without adjusting the padding.
+    fn refresh_padding<R: RngCore + CryptoRng>(&mut self, mut rng: R) {
+        let outputs = self
+            .actions
+            .iter()
+            .filter(|action| matches!(action, ActionPlan::Output(_)))
+            .count()
+            + self.change_outputs.len();
+        let padding = self.privacy_level.padded_outputs(outputs) - outputs;
+
+        self.padding_outputs.truncate(padding);
+        while self.padding_outputs.len() < padding {
+            self.padding_outputs.push(OutputPlan::dummy(&mut rng));
+        }
+    }
+
     /// Attempt adjust existing change notes to repair imbalance:
     ///
     /// - cover required balance by decreasing change if possible
@@ -2,10 +2,10 @@ use penumbra_auction::auction::dutch::actions::{
     ActionDutchAuctionEnd, ActionDutchAuctionSchedule, ActionDutchAuctionWithdraw,
 };
 use penumbra_community_pool::{CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend};
-use penumbra_dex::{PositionClose, PositionOpen, PositionWithdraw, Swap, SwapClaim};
+use penumbra_dex::{GaugeFund, PositionClose, PositionOpen, PositionWithdraw, Swap, SwapClaim};
 use penumbra_fee::Gas;
 use penumbra_ibc::IbcRelay;
-use penumbra_shielded_pool::{Ics20Withdrawal, Output, Spend};
+use penumbra_shielded_pool::{Ics20Withdrawal, NftWithdrawal, Output, Spend};
 use penumbra_stake::{
     validator::Definition as ValidatorDefinition, Delegate, Undelegate, UndelegateClaim,
 };
@@ -335,10 +335,12 @@ impl GasCost for ActionPlan {
             ActionPlan::ProposalDepositClaim(pdc) => pdc.gas_cost(),
             ActionPlan::PositionOpen(po) => po.gas_cost(),
             ActionPlan::PositionClose(pc) => pc.gas_cost(),
+            ActionPlan::GaugeFund(gf) => gf.gas_cost(),
             ActionPlan::CommunityPoolSpend(ds) => ds.gas_cost(),
             ActionPlan::CommunityPoolOutput(d) => d.gas_cost(),
             ActionPlan::CommunityPoolDeposit(dd) => dd.gas_cost(),
             ActionPlan::Ics20Withdrawal(w) => w.gas_cost(),
+            ActionPlan::NftWithdrawal(w) => w.gas_cost(),
         }
     }
 }
@@ -361,7 +363,9 @@ impl GasCost for Action {
             Action::PositionOpen(p) => p.gas_cost(),
             Action::PositionClose(p) => p.gas_cost(),
             Action::PositionWithdraw(p) => p.gas_cost(),
+            Action::GaugeFund(g) => g.gas_cost(),
             Action::Ics20Withdrawal(withdrawal) => withdrawal.gas_cost(),
+            Action::NftWithdrawal(withdrawal) => withdrawal.gas_cost(),
             Action::CommunityPoolDeposit(deposit) => deposit.gas_cost(),
             Action::CommunityPoolSpend(spend) => spend.gas_cost(),
             Action::CommunityPoolOutput(output) => output.gas_cost(),
@@ -541,6 +545,23 @@ impl GasCost for PositionWithdraw {
     }
 }
 
+impl GasCost for GaugeFund {
+    fn gas_cost(&self) -> Gas {
+        Gas {
+            // The block space measured as the byte length of the encoded action.
+            block_space: self.encode_to_vec().len() as u64,
+            // The compact block space cost is based on the byte size of the data the [`Action`] adds
+            // to the compact block.
+            // For a GaugeFund the compact block is not modified.
+            compact_block_space: 0,
+            // Does not include a zk-SNARK proof, so there's no verification cost.
+            verification: 0,
+            // Execution cost is currently hardcoded at 10 for all Action variants.
+            execution: 10,
+        }
+    }
+}
+
 impl GasCost for Ics20Withdrawal {
     fn gas_cost(&self) -> Gas {
         Gas {
@@ -558,6 +579,23 @@ impl GasCost for Ics20Withdrawal {
     }
 }
 
+impl GasCost for NftWithdrawal {
+    fn gas_cost(&self) -> Gas {
+        Gas {
+            // The block space measured as the byte length of the encoded action.
+            block_space: self.encode_to_vec().len() as u64,
+            // The compact block space cost is based on the byte size of the data the [`Action`] adds
+            // to the compact block.
+            // For a NftWithdrawal the compact block is not modified.
+            compact_block_space: 0,
+            // Does not include a zk-SNARK proof, so there's no verification cost.
+            verification: 0,
+            // Execution cost is currently hardcoded at 10 for all Action variants.
+            execution: 10,
+        }
+    }
+}
+
 impl GasCost for CommunityPoolDeposit {
     fn gas_cost(&self) -> Gas {
         Gas {
@@ -13,13 +13,16 @@ use penumbra_dex::{
     },
     swap::{Swap, SwapCiphertext, SwapView},
     swap_claim::{SwapClaim, SwapClaimView},
+    GaugeFund,
 };
 use penumbra_governance::{
     DelegatorVote, DelegatorVoteView, ProposalDepositClaim, ProposalSubmit, ProposalWithdraw,
     ValidatorVote, VotingReceiptToken,
 };
 use penumbra_ibc::IbcRelay;
-use penumbra_shielded_pool::{Ics20Withdrawal, Note, Output, OutputView, Spend, SpendView};
+use penumbra_shielded_pool::{
+    Ics20Withdrawal, NftWithdrawal, Note, Output, OutputView, Spend, SpendView,
+};
 use penumbra_stake::{Delegate, Undelegate, UndelegateClaim};
 
 use crate::{Action, ActionView, TransactionPerspective};
@@ -219,6 +222,16 @@ impl IsAction for Ics20Withdrawal {
     }
 }
 
+impl IsAction for NftWithdrawal {
+    fn balance_commitment(&self) -> balance::Commitment {
+        self.balance().commit(Fr::zero())
+    }
+
+    fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
+        ActionView::NftWithdrawal(self.to_owned())
+    }
+}
+
 impl IsAction for CommunityPoolDeposit {
     fn balance_commitment(&self) -> balance::Commitment {
         self.balance().commit(Fr::zero())
@@ -303,7 +316,8 @@ impl IsAction for PositionWithdraw {
         }
         .commit(Fr::zero());
 
-        // The action consumes a closed position and produces the position's reserves and a withdrawn position NFT.
+        // The action consumes a closed position and produces the position's reserves, along with
+        // any rewards it accrued, and a withdrawn position NFT.
         self.reserves_commitment - prev_state_nft + next_state_nft
     }
 
@@ -312,6 +326,16 @@ impl IsAction for PositionWithdraw {
     }
 }
 
+impl IsAction for GaugeFund {
+    fn balance_commitment(&self) -> balance::Commitment {
+        self.balance().commit(Fr::zero())
+    }
+
+    fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
+        ActionView::GaugeFund(self.to_owned())
+    }
+}
+
 impl IsAction for Swap {
     /// Compute a commitment to the value contributed to a transaction by this swap.
     /// Will subtract (v1,t1), (v2,t2), and (f,fee_token)
@@ -33,7 +33,7 @@ pub mod plan;
 pub mod view;
 
 pub use action::Action;
-pub use action_list::ActionList;
+pub use action_list::{ActionList, PrivacyLevel};
 pub use auth_data::AuthorizationData;
 pub use detection_data::DetectionData;
 pub use error::Error;
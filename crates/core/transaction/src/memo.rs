@@ -8,8 +8,8 @@ use anyhow::anyhow;
 use decaf377_ka as ka;
 use penumbra_asset::balance;
 use penumbra_keys::{
-    address::ADDRESS_LEN_BYTES,
-    keys::OutgoingViewingKey,
+    address::{AddressOwnershipProof, ADDRESS_LEN_BYTES, ADDRESS_OWNERSHIP_PROOF_LEN_BYTES},
+    keys::{IncomingViewingKey, OutgoingViewingKey},
     symmetric::{OvkWrappedKey, PayloadKey, PayloadKind, WrappedMemoKey},
     Address,
 };
@@ -25,6 +25,10 @@ pub const MEMO_LEN_BYTES: usize = 512;
 // This is the largest text length we can support
 const MAX_TEXT_LEN: usize = MEMO_LEN_BYTES - ADDRESS_LEN_BYTES;
 
+// This is the largest text length we can support alongside a proof of the return address,
+// which takes up the end of the memo.
+const MAX_AUTHENTICATED_TEXT_LEN: usize = MAX_TEXT_LEN - ADDRESS_OWNERSHIP_PROOF_LEN_BYTES;
+
 /// A method which reads out bytes in a lossy way, and trims out null bytes
 fn raw_bytes_to_text(data: &[u8]) -> String {
     String::from_utf8_lossy(data)
@@ -45,6 +49,8 @@ impl EffectingData for MemoCiphertext {
 pub struct MemoPlaintext {
     return_address: Address,
     text: String,
+    /// A proof that the sender controls the return address, if they chose to include one.
+    return_address_proof: Option<AddressOwnershipProof>,
 }
 
 impl MemoPlaintext {
@@ -62,9 +68,30 @@ impl MemoPlaintext {
         Ok(Self {
             return_address,
             text,
+            return_address_proof: None,
         })
     }
 
+    /// Authenticate the return address of this memo, proving that the sender
+    /// controls it, so that the recipient can safely reply to it.
+    ///
+    /// The proof takes up the end of the memo, so this fails if the text is
+    /// longer than 368 bytes, or if the return address isn't viewed by `ivk`.
+    pub fn authenticate_return_address(mut self, ivk: &IncomingViewingKey) -> anyhow::Result<Self> {
+        if self.text.len() > MAX_AUTHENTICATED_TEXT_LEN {
+            anyhow::bail!(
+                "memo text length must be <= {} to authenticate the return address, found {}",
+                MAX_AUTHENTICATED_TEXT_LEN,
+                self.text.len()
+            );
+        }
+        self.return_address_proof = Some(
+            ivk.prove_address_ownership(&self.return_address, self.text.as_bytes())
+                .ok_or_else(|| anyhow!("return address is not controlled by the sender"))?,
+        );
+        Ok(self)
+    }
+
     pub fn return_address(&self) -> Address {
         self.return_address.clone()
     }
@@ -72,6 +99,44 @@ impl MemoPlaintext {
     pub fn text(&self) -> &str {
         self.text.as_str()
     }
+
+    /// Whether the sender proved that they control the return address.
+    ///
+    /// Note that the proof only shows that whoever created the memo controls
+    /// the return address: a recipient could copy an authenticated memo into a
+    /// transaction of their own.
+    pub fn return_address_authenticated(&self) -> bool {
+        self.return_address_proof.is_some()
+    }
+
+    /// Parse a memo plaintext, checking for a proof of the return address
+    /// at the end of a full-length memo.
+    fn from_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
+        if bytes.len() < ADDRESS_LEN_BYTES {
+            anyhow::bail!("malformed memo plaintext: missing return address");
+        }
+        let return_address: Address = bytes[..ADDRESS_LEN_BYTES].try_into()?;
+
+        if bytes.len() == MEMO_LEN_BYTES {
+            let (text_bytes, proof_bytes) =
+                bytes[ADDRESS_LEN_BYTES..].split_at(MAX_AUTHENTICATED_TEXT_LEN);
+            let text = raw_bytes_to_text(text_bytes);
+            // Unauthenticated memos can have text in place of the proof, which
+            // will fail to verify, so we fall back to parsing it as text.
+            if let Ok(proof) = AddressOwnershipProof::try_from(proof_bytes) {
+                if proof.verify(&return_address, text.as_bytes()).is_ok() {
+                    return Ok(Self {
+                        return_address,
+                        text,
+                        return_address_proof: Some(proof),
+                    });
+                }
+            }
+        }
+
+        let text = raw_bytes_to_text(&bytes[ADDRESS_LEN_BYTES..]);
+        MemoPlaintext::new(return_address, text)
+    }
 }
 
 impl From<&MemoPlaintext> for Vec<u8> {
@@ -79,6 +144,10 @@ impl From<&MemoPlaintext> for Vec<u8> {
         let mut bytes = vec![];
         bytes.extend_from_slice(&plaintext.return_address.to_vec());
         bytes.extend_from_slice(plaintext.text.as_bytes());
+        if let Some(proof) = &plaintext.return_address_proof {
+            bytes.resize(MEMO_LEN_BYTES - ADDRESS_OWNERSHIP_PROOF_LEN_BYTES, 0);
+            bytes.extend_from_slice(&proof.to_bytes());
+        }
         bytes
     }
 }
@@ -87,14 +156,7 @@ impl TryFrom<Vec<u8>> for MemoPlaintext {
     type Error = anyhow::Error;
 
     fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
-        if bytes.len() < 80 {
-            anyhow::bail!("malformed memo plaintext: missing return address");
-        }
-        let return_address_bytes = &bytes[..80];
-        let return_address: Address = return_address_bytes.try_into()?;
-        let text = raw_bytes_to_text(&bytes[80..]);
-
-        MemoPlaintext::new(return_address, text)
+        MemoPlaintext::from_bytes(&bytes)
     }
 }
 
@@ -107,6 +169,7 @@ impl MemoPlaintext {
         MemoPlaintext {
             return_address,
             text: String::new(),
+            return_address_proof: None,
         }
     }
 }
@@ -139,11 +202,7 @@ impl MemoCiphertext {
     ) -> anyhow::Result<MemoPlaintext> {
         let plaintext_bytes = MemoCiphertext::decrypt_bytes(memo_key, ciphertext)?;
 
-        let return_address_bytes = &plaintext_bytes[..80];
-        let return_address: Address = return_address_bytes.try_into()?;
-        let text = raw_bytes_to_text(&plaintext_bytes[80..]);
-
-        MemoPlaintext::new(return_address, text)
+        MemoPlaintext::from_bytes(&plaintext_bytes)
     }
 
     /// Decrypt a [`MemoCiphertext`] to generate a fixed-length slice of bytes.
@@ -186,11 +245,7 @@ impl MemoCiphertext {
             anyhow!("post-decryption, could not fit plaintext into memo size {MEMO_LEN_BYTES}")
         })?;
 
-        let return_address_bytes = &plaintext_bytes[..80];
-        let return_address: Address = return_address_bytes.try_into()?;
-        let text = raw_bytes_to_text(&plaintext_bytes[80..]);
-
-        MemoPlaintext::new(return_address, text)
+        MemoPlaintext::from_bytes(&plaintext_bytes)
     }
 }
 
@@ -213,6 +268,10 @@ impl From<MemoPlaintext> for pbt::MemoPlaintext {
         pbt::MemoPlaintext {
             return_address: Some(plaintext.return_address.into()),
             text: plaintext.text,
+            return_address_proof: plaintext
+                .return_address_proof
+                .map(|proof| proof.to_bytes().to_vec())
+                .unwrap_or_default(),
         }
     }
 }
@@ -251,9 +310,23 @@ impl TryFrom<pbt::MemoPlaintext> for MemoPlaintext {
                 MEMO_LEN_BYTES - ADDRESS_LEN_BYTES
             );
         }
+        let return_address_proof = if msg.return_address_proof.is_empty() {
+            None
+        } else {
+            if msg.text.len() > MAX_AUTHENTICATED_TEXT_LEN {
+                anyhow::bail!(
+                    "provided memo text exceeds {} bytes, leaving no room for the return address proof",
+                    MAX_AUTHENTICATED_TEXT_LEN
+                );
+            }
+            let proof = AddressOwnershipProof::try_from(&msg.return_address_proof[..])?;
+            proof.verify(&sender, msg.text.as_bytes())?;
+            Some(proof)
+        };
         Ok(Self {
             return_address: sender,
             text: msg.text,
+            return_address_proof,
         })
     }
 }
@@ -285,6 +358,7 @@ mod tests {
         let memo = MemoPlaintext {
             return_address: dest.clone(),
             text: String::from("Hi"),
+            return_address_proof: None,
         };
         let memo_key = PayloadKey::random_key(&mut OsRng);
         let ciphertext =
@@ -364,6 +438,42 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_memo_authenticated_return_address() -> anyhow::Result<()> {
+        let seed_phrase = SeedPhrase::generate(OsRng);
+        let sk = SpendKey::from_seed_phrase_bip44(seed_phrase, &Bip44Path::new(0));
+        let ivk = sk.full_viewing_key().incoming();
+        let (return_address, _dtk_d) = ivk.payment_address(1u32.into());
+
+        let memo = MemoPlaintext::new(return_address.clone(), "Reply here".into())?
+            .authenticate_return_address(ivk)?;
+        assert!(memo.return_address_authenticated());
+
+        // The proof survives encryption and proto encoding.
+        let memo_key = PayloadKey::random_key(&mut OsRng);
+        let ciphertext = MemoCiphertext::encrypt(memo_key.clone(), &memo)?;
+        let plaintext = MemoCiphertext::decrypt(&memo_key, ciphertext)?;
+        assert_eq!(plaintext, memo);
+        assert_eq!(
+            MemoPlaintext::try_from(pbt::MemoPlaintext::from(memo.clone()))?,
+            memo
+        );
+
+        // Long unauthenticated memos fill the space of the proof with text.
+        let long_memo = MemoPlaintext::new(return_address, "a".repeat(MAX_TEXT_LEN))?;
+        let ciphertext = MemoCiphertext::encrypt(memo_key.clone(), &long_memo)?;
+        let plaintext = MemoCiphertext::decrypt(&memo_key, ciphertext)?;
+        assert!(!plaintext.return_address_authenticated());
+        assert_eq!(plaintext, long_memo);
+        assert!(long_memo.authenticate_return_address(ivk).is_err());
+
+        // Only addresses controlled by the sender can be authenticated.
+        let other = MemoPlaintext::new(Address::dummy(&mut OsRng), "Reply here".into())?;
+        assert!(other.authenticate_return_address(ivk).is_err());
+
+        Ok(())
+    }
+
     proptest! {
         // We generate random strings, up to 10k chars long.
         // Since UTF-8 represents each char using 1 to 4 bytes,
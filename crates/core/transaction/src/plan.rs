@@ -55,6 +55,19 @@ impl TransactionPlan {
             .sort_by_key(|action: &ActionPlan| action.variant_index());
     }
 
+    /// Sort the actions in [`TransactionPlan`] by type, like [`Self::sort_actions`], but order
+    /// actions of the same type by a hash keyed with `seed` rather than by the order they were
+    /// planned in, so that e.g. change outputs can't be told apart from other outputs.
+    pub fn shuffle_actions(&mut self, seed: &[u8; 32]) {
+        self.actions.sort_by_cached_key(|action: &ActionPlan| {
+            let key = blake2b_simd::Params::new()
+                .hash_length(32)
+                .key(seed)
+                .hash(&action.encode_to_vec());
+            (action.variant_index(), key.as_bytes().to_vec())
+        });
+    }
+
     /// Computes the [`EffectHash`] for the [`Transaction`] described by this
     /// [`TransactionPlan`].
     ///
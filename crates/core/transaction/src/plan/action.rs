@@ -17,6 +17,7 @@ use penumbra_dex::{
     },
     swap::SwapPlan,
     swap_claim::SwapClaimPlan,
+    GaugeFund,
 };
 use penumbra_governance::{
     delegator_vote::DelegatorVotePlan, ProposalDepositClaim, ProposalSubmit, ProposalWithdraw,
@@ -26,7 +27,7 @@ use penumbra_governance::{
 use penumbra_ibc::IbcRelay;
 use penumbra_keys::{symmetric::PayloadKey, FullViewingKey};
 use penumbra_proto::{core::transaction::v1 as pb_t, DomainType};
-use penumbra_shielded_pool::{Ics20Withdrawal, OutputPlan, SpendPlan};
+use penumbra_shielded_pool::{Ics20Withdrawal, NftWithdrawal, OutputPlan, SpendPlan};
 use penumbra_stake::{Delegate, Undelegate, UndelegateClaimPlan};
 use serde::{Deserialize, Serialize};
 
@@ -72,12 +73,15 @@ pub enum ActionPlan {
     // PositionWithdrawPlan requires the balance of the funds to be withdrawn, so
     // a plan must be used.
     PositionWithdraw(PositionWithdrawPlan),
+    /// Fund a reward gauge of a trading pair.
+    GaugeFund(GaugeFund),
 
     CommunityPoolSpend(CommunityPoolSpend),
     CommunityPoolOutput(CommunityPoolOutput),
     CommunityPoolDeposit(CommunityPoolDeposit),
 
     Ics20Withdrawal(Ics20Withdrawal),
+    NftWithdrawal(NftWithdrawal),
 
     ActionDutchAuctionSchedule(ActionDutchAuctionSchedule),
     ActionDutchAuctionEnd(ActionDutchAuctionEnd),
@@ -156,10 +160,12 @@ impl ActionPlan {
             PositionOpen(plan) => Action::PositionOpen(plan.clone()),
             PositionClose(plan) => Action::PositionClose(plan.clone()),
             PositionWithdraw(plan) => Action::PositionWithdraw(plan.position_withdraw()),
+            GaugeFund(plan) => Action::GaugeFund(plan.clone()),
             CommunityPoolSpend(plan) => Action::CommunityPoolSpend(plan.clone()),
             CommunityPoolOutput(plan) => Action::CommunityPoolOutput(plan.clone()),
             CommunityPoolDeposit(plan) => Action::CommunityPoolDeposit(plan.clone()),
             Ics20Withdrawal(plan) => Action::Ics20Withdrawal(plan.clone()),
+            NftWithdrawal(plan) => Action::NftWithdrawal(plan.clone()),
             ActionDutchAuctionSchedule(plan) => Action::ActionDutchAuctionSchedule(plan.clone()),
             ActionDutchAuctionEnd(plan) => Action::ActionDutchAuctionEnd(plan.clone()),
             ActionDutchAuctionWithdraw(plan) => {
@@ -185,6 +191,7 @@ impl ActionPlan {
             ActionPlan::PositionOpen(_) => 30,
             ActionPlan::PositionClose(_) => 31,
             ActionPlan::PositionWithdraw(_) => 32,
+            ActionPlan::GaugeFund(_) => 33,
             ActionPlan::Delegate(_) => 40,
             ActionPlan::Undelegate(_) => 41,
             ActionPlan::UndelegateClaim(_) => 42,
@@ -192,6 +199,7 @@ impl ActionPlan {
             ActionPlan::CommunityPoolOutput(_) => 51,
             ActionPlan::CommunityPoolDeposit(_) => 52,
             ActionPlan::Ics20Withdrawal(_) => 200,
+            ActionPlan::NftWithdrawal(_) => 201,
             ActionPlan::ActionDutchAuctionSchedule(_) => 53,
             ActionPlan::ActionDutchAuctionEnd(_) => 54,
             ActionPlan::ActionDutchAuctionWithdraw(_) => 55,
@@ -219,7 +227,9 @@ impl ActionPlan {
             PositionOpen(position_open) => position_open.balance(),
             PositionClose(position_close) => position_close.balance(),
             PositionWithdraw(position_withdraw) => position_withdraw.balance(),
+            GaugeFund(gauge_fund) => gauge_fund.balance(),
             Ics20Withdrawal(withdrawal) => withdrawal.balance(),
+            NftWithdrawal(withdrawal) => withdrawal.balance(),
             ActionDutchAuctionSchedule(action) => action.balance(),
             ActionDutchAuctionEnd(action) => action.balance(),
             ActionDutchAuctionWithdraw(action) => action.balance(),
@@ -250,10 +260,12 @@ impl ActionPlan {
             PositionOpen(_) => Fr::zero(),
             PositionClose(_) => Fr::zero(),
             PositionWithdraw(_) => Fr::zero(),
+            GaugeFund(_) => Fr::zero(),
             CommunityPoolSpend(_) => Fr::zero(),
             CommunityPoolOutput(_) => Fr::zero(),
             CommunityPoolDeposit(_) => Fr::zero(),
             Ics20Withdrawal(_) => Fr::zero(),
+            NftWithdrawal(_) => Fr::zero(),
             ActionDutchAuctionSchedule(_) => Fr::zero(),
             ActionDutchAuctionEnd(_) => Fr::zero(),
             ActionDutchAuctionWithdraw(_) => Fr::zero(),
@@ -282,10 +294,12 @@ impl ActionPlan {
             PositionOpen(plan) => plan.effect_hash(),
             PositionClose(plan) => plan.effect_hash(),
             PositionWithdraw(plan) => plan.position_withdraw().effect_hash(),
+            GaugeFund(plan) => plan.effect_hash(),
             CommunityPoolSpend(plan) => plan.effect_hash(),
             CommunityPoolOutput(plan) => plan.effect_hash(),
             CommunityPoolDeposit(plan) => plan.effect_hash(),
             Ics20Withdrawal(plan) => plan.effect_hash(),
+            NftWithdrawal(plan) => plan.effect_hash(),
             ActionDutchAuctionSchedule(plan) => plan.effect_hash(),
             ActionDutchAuctionEnd(plan) => plan.effect_hash(),
             ActionDutchAuctionWithdraw(plan) => plan.to_action().effect_hash(),
@@ -385,6 +399,12 @@ impl From<PositionWithdrawPlan> for ActionPlan {
     }
 }
 
+impl From<GaugeFund> for ActionPlan {
+    fn from(inner: GaugeFund) -> ActionPlan {
+        ActionPlan::GaugeFund(inner)
+    }
+}
+
 impl From<CommunityPoolSpend> for ActionPlan {
     fn from(inner: CommunityPoolSpend) -> ActionPlan {
         ActionPlan::CommunityPoolSpend(inner)
@@ -409,6 +429,12 @@ impl From<Ics20Withdrawal> for ActionPlan {
     }
 }
 
+impl From<NftWithdrawal> for ActionPlan {
+    fn from(inner: NftWithdrawal) -> ActionPlan {
+        ActionPlan::NftWithdrawal(inner)
+    }
+}
+
 impl From<ActionDutchAuctionSchedule> for ActionPlan {
     fn from(inner: ActionDutchAuctionSchedule) -> ActionPlan {
         ActionPlan::ActionDutchAuctionSchedule(inner)
@@ -503,6 +529,9 @@ impl From<ActionPlan> for pb_t::ActionPlan {
                     inner
                 ))),
             },
+            ActionPlan::GaugeFund(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::GaugeFund(inner.into())),
+            },
             ActionPlan::CommunityPoolDeposit(inner) => pb_t::ActionPlan {
                 action: Some(pb_t::action_plan::Action::CommunityPoolDeposit(
                     inner.into(),
@@ -517,6 +546,9 @@ impl From<ActionPlan> for pb_t::ActionPlan {
             ActionPlan::Ics20Withdrawal(inner) => pb_t::ActionPlan {
                 action: Some(pb_t::action_plan::Action::Ics20Withdrawal(inner.into())),
             },
+            ActionPlan::NftWithdrawal(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::NftWithdrawal(inner.into())),
+            },
             ActionPlan::ActionDutchAuctionSchedule(inner) => pb_t::ActionPlan {
                 action: Some(pb_t::action_plan::Action::ActionDutchAuctionSchedule(
                     inner.into(),
@@ -593,6 +625,9 @@ impl TryFrom<pb_t::ActionPlan> for ActionPlan {
             pb_t::action_plan::Action::PositionWithdraw(inner) => {
                 Ok(ActionPlan::PositionWithdraw(inner.try_into()?))
             }
+            pb_t::action_plan::Action::GaugeFund(inner) => {
+                Ok(ActionPlan::GaugeFund(inner.try_into()?))
+            }
             pb_t::action_plan::Action::PositionRewardClaim(_) => {
                 Err(anyhow!("PositionRewardClaim is deprecated and unsupported"))
             }
@@ -617,6 +652,9 @@ impl TryFrom<pb_t::ActionPlan> for ActionPlan {
             pb_t::action_plan::Action::Ics20Withdrawal(inner) => {
                 Ok(ActionPlan::Ics20Withdrawal(inner.try_into()?))
             }
+            pb_t::action_plan::Action::NftWithdrawal(inner) => {
+                Ok(ActionPlan::NftWithdrawal(inner.try_into()?))
+            }
         }
     }
 }
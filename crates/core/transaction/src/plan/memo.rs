@@ -1,5 +1,5 @@
 use anyhow::Context;
-use penumbra_keys::{symmetric::PayloadKey, Address};
+use penumbra_keys::symmetric::PayloadKey;
 use penumbra_proto::{core::transaction::v1 as pb, DomainType};
 
 use rand::{CryptoRng, RngCore};
@@ -31,13 +31,8 @@ impl DomainType for MemoPlan {
 
 impl From<MemoPlan> for pb::MemoPlan {
     fn from(msg: MemoPlan) -> Self {
-        let return_address = Some(msg.plaintext.return_address().into());
-        let text = msg.plaintext.text().to_owned();
         Self {
-            plaintext: Some(pb::MemoPlaintext {
-                return_address,
-                text,
-            }),
+            plaintext: Some(msg.plaintext.into()),
             key: msg.key.to_vec(),
         }
     }
@@ -47,25 +42,14 @@ impl TryFrom<pb::MemoPlan> for MemoPlan {
     type Error = anyhow::Error;
 
     fn try_from(msg: pb::MemoPlan) -> Result<Self, Self::Error> {
-        let sender: Address = msg
+        let plaintext = msg
             .plaintext
-            .clone()
             .ok_or_else(|| anyhow::anyhow!("memo plan missing memo plaintext"))?
-            .return_address
-            .ok_or_else(|| anyhow::anyhow!("memo plaintext missing return address"))?
             .try_into()
-            .context("return address malformed")?;
-
-        let text: String = msg
-            .plaintext
-            .ok_or_else(|| anyhow::anyhow!("memo plan missing memo plaintext"))?
-            .text;
+            .context("memo plaintext malformed")?;
 
         let key = PayloadKey::try_from(msg.key.to_vec())?;
 
-        Ok(Self {
-            plaintext: MemoPlaintext::new(sender, text)?,
-            key,
-        })
+        Ok(Self { plaintext, key })
     }
 }
@@ -11,6 +11,7 @@ use penumbra_community_pool::{CommunityPoolDeposit, CommunityPoolOutput, Communi
 use penumbra_dex::{
     lp::action::{PositionClose, PositionOpen},
     swap::Swap,
+    GaugeFund,
 };
 use penumbra_governance::{DelegatorVote, ProposalSubmit, ProposalWithdraw, ValidatorVote};
 use penumbra_ibc::IbcRelay;
@@ -129,6 +130,7 @@ impl Transaction {
         TransactionContext {
             anchor: self.anchor,
             effect_hash: self.effect_hash(),
+            verification_cache: Default::default(),
         }
     }
 
@@ -254,7 +256,9 @@ impl Transaction {
                 | Action::PositionOpen(_)
                 | Action::PositionClose(_)
                 | Action::PositionWithdraw(_)
+                | Action::GaugeFund(_)
                 | Action::Ics20Withdrawal(_)
+                | Action::NftWithdrawal(_)
                 | Action::CommunityPoolSpend(_)
                 | Action::CommunityPoolOutput(_)
                 | Action::CommunityPoolDeposit(_) => {}
@@ -305,6 +309,7 @@ impl Transaction {
                     let plaintext_view: MemoPlaintextView = MemoPlaintextView {
                         return_address: txp.view_address(plaintext.return_address()),
                         text: plaintext.text().to_owned(),
+                        return_address_authenticated: plaintext.return_address_authenticated(),
                     };
                     Some(MemoView::Visible {
                         plaintext: plaintext_view,
@@ -544,6 +549,16 @@ impl Transaction {
         })
     }
 
+    pub fn gauge_fundings(&self) -> impl Iterator<Item = &GaugeFund> {
+        self.actions().filter_map(|action| {
+            if let Action::GaugeFund(g) = action {
+                Some(g)
+            } else {
+                None
+            }
+        })
+    }
+
     pub fn transaction_body(&self) -> TransactionBody {
         self.transaction_body.clone()
     }
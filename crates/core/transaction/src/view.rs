@@ -55,6 +55,8 @@ pub enum MemoView {
 pub struct MemoPlaintextView {
     pub return_address: AddressView,
     pub text: String,
+    /// Whether the sender proved that they control the return address.
+    pub return_address_authenticated: bool,
 }
 
 impl TransactionView {
@@ -272,6 +274,7 @@ impl From<MemoPlaintextView> for pbt::MemoPlaintextView {
         Self {
             return_address: Some(v.return_address.into()),
             text: v.text,
+            return_address_authenticated: v.return_address_authenticated,
         }
     }
 }
@@ -291,6 +294,7 @@ impl TryFrom<pbt::MemoPlaintextView> for MemoPlaintextView {
         Ok(Self {
             return_address: sender,
             text,
+            return_address_authenticated: v.return_address_authenticated,
         })
     }
 }
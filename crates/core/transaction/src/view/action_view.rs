@@ -7,11 +7,12 @@ use penumbra_dex::{
     lp::action::{PositionClose, PositionOpen, PositionWithdraw},
     swap::SwapView,
     swap_claim::SwapClaimView,
+    GaugeFund,
 };
 use penumbra_governance::{ProposalDepositClaim, ProposalSubmit, ProposalWithdraw, ValidatorVote};
 use penumbra_ibc::IbcRelay;
 use penumbra_proto::{core::transaction::v1 as pbt, DomainType};
-use penumbra_shielded_pool::Ics20Withdrawal;
+use penumbra_shielded_pool::{Ics20Withdrawal, NftWithdrawal};
 use penumbra_stake::{Delegate, Undelegate, UndelegateClaim};
 use serde::{Deserialize, Serialize};
 
@@ -41,10 +42,12 @@ pub enum ActionView {
     PositionOpen(PositionOpen),
     PositionClose(PositionClose),
     PositionWithdraw(PositionWithdraw),
+    GaugeFund(GaugeFund),
     Delegate(Delegate),
     Undelegate(Undelegate),
     UndelegateClaim(UndelegateClaim),
     Ics20Withdrawal(Ics20Withdrawal),
+    NftWithdrawal(NftWithdrawal),
     CommunityPoolDeposit(CommunityPoolDeposit),
     CommunityPoolSpend(CommunityPoolSpend),
     CommunityPoolOutput(CommunityPoolOutput),
@@ -84,12 +87,14 @@ impl TryFrom<pbt::ActionView> for ActionView {
                 AV::PositionOpen(x) => ActionView::PositionOpen(x.try_into()?),
                 AV::PositionClose(x) => ActionView::PositionClose(x.try_into()?),
                 AV::PositionWithdraw(x) => ActionView::PositionWithdraw(x.try_into()?),
+                AV::GaugeFund(x) => ActionView::GaugeFund(x.try_into()?),
                 AV::PositionRewardClaim(_) => {
                     return Err(anyhow::anyhow!(
                         "PositionRewardClaim is deprecated and unsupported"
                     ))
                 }
                 AV::Ics20Withdrawal(x) => ActionView::Ics20Withdrawal(x.try_into()?),
+                AV::NftWithdrawal(x) => ActionView::NftWithdrawal(x.try_into()?),
                 AV::CommunityPoolDeposit(x) => ActionView::CommunityPoolDeposit(x.try_into()?),
                 AV::CommunityPoolSpend(x) => ActionView::CommunityPoolSpend(x.try_into()?),
                 AV::CommunityPoolOutput(x) => ActionView::CommunityPoolOutput(x.try_into()?),
@@ -127,7 +132,9 @@ impl From<ActionView> for pbt::ActionView {
                 ActionView::PositionOpen(x) => AV::PositionOpen(x.into()),
                 ActionView::PositionClose(x) => AV::PositionClose(x.into()),
                 ActionView::PositionWithdraw(x) => AV::PositionWithdraw(x.into()),
+                ActionView::GaugeFund(x) => AV::GaugeFund(x.into()),
                 ActionView::Ics20Withdrawal(x) => AV::Ics20Withdrawal(x.into()),
+                ActionView::NftWithdrawal(x) => AV::NftWithdrawal(x.into()),
                 ActionView::CommunityPoolDeposit(x) => AV::CommunityPoolDeposit(x.into()),
                 ActionView::CommunityPoolSpend(x) => AV::CommunityPoolSpend(x.into()),
                 ActionView::CommunityPoolOutput(x) => AV::CommunityPoolOutput(x.into()),
@@ -163,7 +170,9 @@ impl From<ActionView> for Action {
             ActionView::PositionOpen(x) => Action::PositionOpen(x),
             ActionView::PositionClose(x) => Action::PositionClose(x),
             ActionView::PositionWithdraw(x) => Action::PositionWithdraw(x),
+            ActionView::GaugeFund(x) => Action::GaugeFund(x),
             ActionView::Ics20Withdrawal(x) => Action::Ics20Withdrawal(x),
+            ActionView::NftWithdrawal(x) => Action::NftWithdrawal(x),
             ActionView::CommunityPoolDeposit(x) => Action::CommunityPoolDeposit(x),
             ActionView::CommunityPoolSpend(x) => Action::CommunityPoolSpend(x),
             ActionView::CommunityPoolOutput(x) => Action::CommunityPoolOutput(x),
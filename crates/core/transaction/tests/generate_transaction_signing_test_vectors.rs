@@ -491,6 +491,7 @@ fn position_strategy() -> impl Strategy<Value = Position> {
             phi,
             nonce: [0u8; 32],
             close_on_fill: true,
+            expiry_height: None,
         })
 }
 
@@ -557,6 +558,7 @@ fn ics20_withdrawal_strategy() -> impl Strategy<Value = Ics20Withdrawal> {
                 timeout_time: 0u64,
                 source_channel: ChannelId::default(),
                 use_compat_address: false,
+                relay_fee: None,
             },
         )
 }
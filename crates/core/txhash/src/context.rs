@@ -1,3 +1,5 @@
+use std::{collections::BTreeSet, fmt, sync::Arc};
+
 use crate::EffectHash;
 use penumbra_tct as tct;
 
@@ -10,4 +12,29 @@ pub struct TransactionContext {
     pub anchor: tct::Root,
     /// The transaction's effect hash.
     pub effect_hash: EffectHash,
+    /// What was already verified in batches for the proposal the transaction
+    /// is part of, if any.
+    pub verification_cache: Arc<VerificationCache>,
+}
+
+/// The signatures and proofs of a proposal's transactions that were verified
+/// in batches, ahead of checking the transactions one by one.
+///
+/// A cache is built for one proposal, and dropped along with it, so nothing
+/// verified for a proposal carries over to another.
+#[derive(Clone, Default)]
+pub struct VerificationCache {
+    /// Hashes of the signatures verified, with their key and message.
+    pub signatures: BTreeSet<[u8; 32]>,
+    /// Hashes of the proofs verified, with their circuit and public inputs.
+    pub proofs: BTreeSet<[u8; 32]>,
+}
+
+impl fmt::Debug for VerificationCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VerificationCache")
+            .field("signatures", &self.signatures.len())
+            .field("proofs", &self.proofs.len())
+            .finish()
+    }
 }
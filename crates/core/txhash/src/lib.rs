@@ -7,7 +7,7 @@ mod effecting_data;
 mod transaction_id;
 
 pub use auth_hash::{AuthHash, AuthorizingData};
-pub use context::TransactionContext;
+pub use context::{TransactionContext, VerificationCache};
 pub use effect_hash::EffectHash;
 pub use effecting_data::EffectingData;
 pub use transaction_id::TransactionId;
@@ -1,4 +1,4 @@
-use crate::{hash, hkd, Clue, ClueKey, Error, MAX_PRECISION};
+use crate::{hash, hkd, threshold, Clue, ClueKey, DetectionKeyShare, Error, MAX_PRECISION};
 use bitvec::{order, slice::BitSlice};
 use decaf377::Fr;
 use rand_core::{CryptoRng, RngCore};
@@ -76,59 +76,89 @@ impl DetectionKey {
     /// key material, but short-circuits to return early on a false detection.
     #[allow(non_snake_case)]
     pub fn examine(&self, clue: &Clue) -> bool {
-        let P_encoding = decaf377::Encoding::try_from(&clue.0[0..32]).expect("slice is right len");
+        examine_with(clue, |&P, i| (P * self.xs[i]).vartime_compress())
+    }
 
-        let P = if let Ok(P) = P_encoding.vartime_decompress() {
-            P
-        } else {
-            // Invalid P encoding => not a match
-            return false;
-        };
-
-        let y = if let Ok(y) =
-            Fr::from_bytes_checked(&clue.0[32..64].try_into().expect("expected 32 bytes"))
-        {
-            y
-        } else {
-            // Invalid y encoding => not a match
-            return false;
-        };
+    /// Split this detection key into `num_shares` shares, any `threshold` of
+    /// which can jointly examine clues with a
+    /// [`ThresholdDetector`](crate::ThresholdDetector).
+    ///
+    /// # Errors
+    ///
+    /// `threshold` must be at least 1 and at most `num_shares`.
+    pub fn split<R: RngCore + CryptoRng>(
+        &self,
+        threshold: u16,
+        num_shares: u16,
+        rng: R,
+    ) -> Result<Vec<DetectionKeyShare>, Error> {
+        threshold::split(&self.dtk, threshold, num_shares, rng)
+    }
+}
 
-        // Reject P = 0 or y = 0, as these never occur in well-formed clues; as
-        // noted in the OpenPrivacy implementation, these could allow clues to
-        // match any detection key.
-        // https://docs.rs/fuzzytags/0.6.0/src/fuzzytags/lib.rs.html#348-351
-        if P.is_identity() || y == Fr::ZERO {
-            return false;
-        }
+/// Examines the given `clue`, using `shared_secret` to compute the encoding of
+/// `P * x_i` for the clue's ephemeral point `P` and the `i`-th child detection
+/// key `x_i`.
+///
+/// This lets the child detection keys be held in different ways, e.g. shared
+/// between detection servers.
+#[allow(non_snake_case)]
+pub(crate) fn examine_with(
+    clue: &Clue,
+    mut shared_secret: impl FnMut(&decaf377::Element, usize) -> decaf377::Encoding,
+) -> bool {
+    let P_encoding = decaf377::Encoding::try_from(&clue.0[0..32]).expect("slice is right len");
+
+    let P = if let Ok(P) = P_encoding.vartime_decompress() {
+        P
+    } else {
+        // Invalid P encoding => not a match
+        return false;
+    };
+
+    let y = if let Ok(y) =
+        Fr::from_bytes_checked(&clue.0[32..64].try_into().expect("expected 32 bytes"))
+    {
+        y
+    } else {
+        // Invalid y encoding => not a match
+        return false;
+    };
+
+    // Reject P = 0 or y = 0, as these never occur in well-formed clues; as
+    // noted in the OpenPrivacy implementation, these could allow clues to
+    // match any detection key.
+    // https://docs.rs/fuzzytags/0.6.0/src/fuzzytags/lib.rs.html#348-351
+    if P.is_identity() || y == Fr::ZERO {
+        return false;
+    }
 
-        let precision_bits = match clue.precision() {
-            Err(_) => return false,
-            Ok(x) => x.bits() as u8,
-        };
-        let ciphertexts = BitSlice::<u8, order::Lsb0>::from_slice(&clue.0[65..68]);
-
-        let m = hash::to_scalar(&P_encoding.0, precision_bits, &clue.0[65..68]);
-        let Q_bytes = ((y * P) + (m * decaf377::Element::GENERATOR)).vartime_compress();
-
-        for i in 0..(precision_bits as usize) {
-            let Px_i = (P * self.xs[i]).vartime_compress();
-            let key_i = hash::to_bit(&P_encoding.0, &Px_i.0, &Q_bytes.0);
-            let msg_i = (ciphertexts[i] as u8) ^ key_i;
-            // Short-circuit if we get a zero; this branch is dependent on the
-            // ephemeral key bit `key_i`, not the long-term key `xs[i]`, so we
-            // don't risk leaking any long-term secrets through timing channels.
-            //
-            // On the other hand, this gives a massive speedup, since we have a
-            // 1/2 chance of rejecting after 1 iteration, 1/4 chance of
-            // rejecting after 2 iterations, ..., so (in expectation) we do <= 2
-            // iterations instead of n iterations.
-            if msg_i == 0 {
-                return false;
-            }
+    let precision_bits = match clue.precision() {
+        Err(_) => return false,
+        Ok(x) => x.bits() as u8,
+    };
+    let ciphertexts = BitSlice::<u8, order::Lsb0>::from_slice(&clue.0[65..68]);
+
+    let m = hash::to_scalar(&P_encoding.0, precision_bits, &clue.0[65..68]);
+    let Q_bytes = ((y * P) + (m * decaf377::Element::GENERATOR)).vartime_compress();
+
+    for i in 0..(precision_bits as usize) {
+        let Px_i = shared_secret(&P, i);
+        let key_i = hash::to_bit(&P_encoding.0, &Px_i.0, &Q_bytes.0);
+        let msg_i = (ciphertexts[i] as u8) ^ key_i;
+        // Short-circuit if we get a zero; this branch is dependent on the
+        // ephemeral key bit `key_i`, not the long-term key `xs[i]`, so we
+        // don't risk leaking any long-term secrets through timing channels.
+        //
+        // On the other hand, this gives a massive speedup, since we have a
+        // 1/2 chance of rejecting after 1 iteration, 1/4 chance of
+        // rejecting after 2 iterations, ..., so (in expectation) we do <= 2
+        // iterations instead of n iterations.
+        if msg_i == 0 {
+            return false;
         }
-
-        // Otherwise, all message bits were 1 and we return true.
-        true
     }
+
+    // Otherwise, all message bits were 1 and we return true.
+    true
 }
@@ -15,4 +15,19 @@ pub enum Error {
     /// A clue key encoding was invalid.
     #[error("Invalid clue key.")]
     InvalidClueKey,
+    /// A threshold was zero or larger than the number of shares.
+    #[error("Invalid threshold {0} for {1} shares.")]
+    InvalidThreshold(u16, u16),
+    /// A detection key share encoding was invalid.
+    #[error("Invalid detection key share.")]
+    InvalidDetectionKeyShare,
+    /// A partial detection encoding was invalid.
+    #[error("Invalid partial detection.")]
+    InvalidPartialDetection,
+    /// Two partial detections were computed by the same share.
+    #[error("Duplicate partial detection.")]
+    DuplicatePartialDetection,
+    /// Fewer partial detections than the threshold were provided.
+    #[error("Got {0} partial detections, but the threshold is {1}.")]
+    InsufficientPartialDetections(usize, u16),
 }
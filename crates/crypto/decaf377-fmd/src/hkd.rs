@@ -1,31 +1,30 @@
 use decaf377::Fr;
 
-#[allow(non_snake_case)]
-pub fn derive_public(
-    root_pub: &decaf377::Element,
-    root_pub_enc: &decaf377::Encoding,
-    index: u8,
-) -> decaf377::Element {
+/// The offset from the root key to the child key at `index`.
+pub fn derive_offset(root_pub_enc: &decaf377::Encoding, index: u8) -> Fr {
     let hash = blake2b_simd::Params::default()
         .personal(b"decaf377-fmd.hkd")
         .to_state()
         .update(&root_pub_enc.0)
         .update(&[index])
         .finalize();
-    let x = Fr::from_le_bytes_mod_order(hash.as_bytes());
+    Fr::from_le_bytes_mod_order(hash.as_bytes())
+}
+
+#[allow(non_snake_case)]
+pub fn derive_public(
+    root_pub: &decaf377::Element,
+    root_pub_enc: &decaf377::Encoding,
+    index: u8,
+) -> decaf377::Element {
+    let x = derive_offset(root_pub_enc, index);
     let X = x * decaf377::Element::GENERATOR;
 
     root_pub + X
 }
 
 pub fn derive_private(root_priv: &Fr, root_pub_enc: &decaf377::Encoding, index: u8) -> Fr {
-    let hash = blake2b_simd::Params::default()
-        .personal(b"decaf377-fmd.hkd")
-        .to_state()
-        .update(&root_pub_enc.0)
-        .update(&[index])
-        .finalize();
-    let x = Fr::from_le_bytes_mod_order(hash.as_bytes());
+    let x = derive_offset(root_pub_enc, index);
 
     *root_priv + x
 }
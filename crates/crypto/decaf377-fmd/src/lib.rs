@@ -12,11 +12,13 @@ mod error;
 mod hash;
 mod hkd;
 mod precision;
+mod threshold;
 
 pub use clue::Clue;
 pub use clue_key::{ClueKey, ExpandedClueKey};
 pub use detection::DetectionKey;
 pub use error::Error;
 pub use precision::Precision;
+pub use threshold::{DetectionKeyShare, PartialDetection, ThresholdDetector};
 
 pub(crate) use precision::MAX_PRECISION;
@@ -0,0 +1,300 @@
+//! Threshold detection, with a detection key shared between detection servers.
+//!
+//! A [`DetectionKey`](crate::DetectionKey) is split into shares of its root key
+//! using Shamir secret sharing. Every child detection key `x_i` is the root key
+//! `dtk` plus a public offset `h_i`, so for a clue with ephemeral point `P`,
+//! `P * x_i = P * dtk + P * h_i`. Each server holding a share `s_j` computes a
+//! single [`PartialDetection`] `P * s_j`, independent of the precision of the
+//! clue, and any `threshold` of them are combined by a [`ThresholdDetector`]
+//! into `P * dtk`, without any party learning the detection key.
+//!
+//! Partial detections aren't verifiable: a faulty server can cause missed
+//! detections, so the combiner should trust the servers it queries.
+
+use std::collections::BTreeSet;
+
+use decaf377::Fr;
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{detection::examine_with, hkd, Clue, ClueKey, Error};
+
+/// One share of a [`DetectionKey`](crate::DetectionKey), held by a single
+/// detection server.
+#[derive(Clone)]
+pub struct DetectionKeyShare {
+    /// The nonzero index of this share.
+    index: u16,
+    /// The evaluation of the sharing polynomial at `index`.
+    share: Fr,
+}
+
+/// A detection server's contribution to the examination of a [`Clue`].
+#[derive(Clone, Debug)]
+pub struct PartialDetection {
+    /// The index of the share that computed this partial detection.
+    index: u16,
+    /// The clue's ephemeral point, multiplied by the share.
+    point: decaf377::Element,
+}
+
+/// Combines [`PartialDetection`]s to examine clues sent to a shared detection key.
+#[derive(Clone, Debug)]
+pub struct ThresholdDetector {
+    root_pub_enc: decaf377::Encoding,
+    threshold: u16,
+}
+
+/// Splits `dtk` into `num_shares` Shamir shares with the given `threshold`.
+pub(crate) fn split<R: RngCore + CryptoRng>(
+    dtk: &Fr,
+    threshold: u16,
+    num_shares: u16,
+    mut rng: R,
+) -> Result<Vec<DetectionKeyShare>, Error> {
+    if threshold == 0 || threshold > num_shares {
+        return Err(Error::InvalidThreshold(threshold, num_shares));
+    }
+
+    // The sharing polynomial has the detection key as its constant term.
+    let coefficients = std::iter::once(*dtk)
+        .chain((1..threshold).map(|_| Fr::rand(&mut rng)))
+        .collect::<Vec<_>>();
+
+    Ok((1..=num_shares)
+        .map(|index| {
+            let x = Fr::from(index as u64);
+            let share = coefficients
+                .iter()
+                .rev()
+                .fold(Fr::ZERO, |acc, coefficient| acc * x + *coefficient);
+            DetectionKeyShare { index, share }
+        })
+        .collect())
+}
+
+impl DetectionKeyShare {
+    /// The index of this share.
+    pub fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Serialize this share to bytes.
+    pub fn to_bytes(&self) -> [u8; 34] {
+        let mut bytes = [0u8; 34];
+        bytes[0..2].copy_from_slice(&self.index.to_le_bytes());
+        bytes[2..34].copy_from_slice(&self.share.to_bytes());
+        bytes
+    }
+
+    /// Deserialize a share from bytes.
+    pub fn from_bytes(bytes: [u8; 34]) -> Result<Self, Error> {
+        let index = u16::from_le_bytes([bytes[0], bytes[1]]);
+        if index == 0 {
+            return Err(Error::InvalidDetectionKeyShare);
+        }
+        let share = Fr::from_bytes_checked(&bytes[2..34].try_into().expect("32 bytes"))
+            .map_err(|_| Error::InvalidDetectionKeyShare)?;
+        Ok(Self { index, share })
+    }
+
+    /// Use this share to compute a [`PartialDetection`] of the given `clue`.
+    ///
+    /// Returns `None` if the clue is malformed, in which case it doesn't match
+    /// any detection key.
+    #[allow(non_snake_case)]
+    pub fn partial_detection(&self, clue: &Clue) -> Option<PartialDetection> {
+        let P_encoding = decaf377::Encoding::try_from(&clue.0[0..32]).expect("slice is right len");
+        let P = P_encoding.vartime_decompress().ok()?;
+        if P.is_identity() {
+            return None;
+        }
+
+        Some(PartialDetection {
+            index: self.index,
+            point: P * self.share,
+        })
+    }
+}
+
+impl PartialDetection {
+    /// The index of the share that computed this partial detection.
+    pub fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Serialize this partial detection to bytes.
+    pub fn to_bytes(&self) -> [u8; 34] {
+        let mut bytes = [0u8; 34];
+        bytes[0..2].copy_from_slice(&self.index.to_le_bytes());
+        bytes[2..34].copy_from_slice(&self.point.vartime_compress().0);
+        bytes
+    }
+
+    /// Deserialize a partial detection from bytes.
+    pub fn from_bytes(bytes: [u8; 34]) -> Result<Self, Error> {
+        let index = u16::from_le_bytes([bytes[0], bytes[1]]);
+        if index == 0 {
+            return Err(Error::InvalidPartialDetection);
+        }
+        let point = decaf377::Encoding::try_from(&bytes[2..34])
+            .expect("slice is right len")
+            .vartime_decompress()
+            .map_err(|_| Error::InvalidPartialDetection)?;
+        Ok(Self { index, point })
+    }
+}
+
+impl ThresholdDetector {
+    /// Create a detector for clues sent to `clue_key`, whose detection key was
+    /// split with the given `threshold`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the clue key is invalid or the threshold is zero.
+    pub fn new(clue_key: &ClueKey, threshold: u16) -> Result<Self, Error> {
+        let root_pub_enc = decaf377::Encoding(clue_key.0);
+        root_pub_enc
+            .vartime_decompress()
+            .map_err(|_| Error::InvalidClueKey)?;
+        if threshold == 0 {
+            return Err(Error::InvalidThreshold(threshold, 0));
+        }
+
+        Ok(Self {
+            root_pub_enc,
+            threshold,
+        })
+    }
+
+    /// The number of partial detections needed to examine a clue.
+    pub fn threshold(&self) -> u16 {
+        self.threshold
+    }
+
+    /// Examine the given `clue` using the `partials` computed by detection
+    /// servers, returning `true` if the clue was possibly sent to the shared
+    /// detection key's clue key.
+    ///
+    /// As with [`DetectionKey::examine`](crate::DetectionKey::examine), this
+    /// test has false positives, but no false negatives, provided the partial
+    /// detections were honestly computed for this clue.
+    ///
+    /// # Errors
+    ///
+    /// Fails if fewer than `threshold` partial detections are given, or if
+    /// two of them have the same index.
+    #[allow(non_snake_case)]
+    pub fn examine(&self, clue: &Clue, partials: &[PartialDetection]) -> Result<bool, Error> {
+        let indices = partials
+            .iter()
+            .map(|partial| partial.index)
+            .collect::<BTreeSet<_>>();
+        if indices.len() != partials.len() {
+            return Err(Error::DuplicatePartialDetection);
+        }
+        if partials.len() < self.threshold as usize {
+            return Err(Error::InsufficientPartialDetections(
+                partials.len(),
+                self.threshold,
+            ));
+        }
+
+        // Interpolate `P * dtk` from the partial detections, using the Lagrange
+        // coefficients of their indices at zero.
+        let mut P_dtk = decaf377::Element::default();
+        for partial in partials {
+            P_dtk += partial.point * lagrange_coefficient(&indices, partial.index);
+        }
+
+        Ok(examine_with(clue, |&P, i| {
+            let h_i = hkd::derive_offset(
+                &self.root_pub_enc,
+                u8::try_from(i).expect("i < MAX_PRECISION < 256"),
+            );
+            (P_dtk + P * h_i).vartime_compress()
+        }))
+    }
+}
+
+/// The Lagrange coefficient of share `index` at zero, for the given share `indices`.
+fn lagrange_coefficient(indices: &BTreeSet<u16>, index: u16) -> Fr {
+    let x_j = Fr::from(index as u64);
+    indices
+        .iter()
+        .filter(|&&m| m != index)
+        .map(|&m| Fr::from(m as u64))
+        .fold(Fr::ONE, |lambda, x_m| {
+            lambda * x_m * (x_m - x_j).inverse().expect("indices are distinct")
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::{DetectionKey, Precision};
+
+    #[test]
+    fn threshold_detection_matches_detection_key() {
+        let dtk = DetectionKey::new(OsRng);
+        let clue_key = dtk.clue_key();
+        let shares = dtk.split(2, 3, OsRng).unwrap();
+        let detector = ThresholdDetector::new(&clue_key, 2).unwrap();
+
+        let precision = Precision::new(8).unwrap();
+        let sent = clue_key
+            .expand()
+            .unwrap()
+            .create_clue(precision, OsRng)
+            .unwrap();
+        let other = DetectionKey::new(OsRng)
+            .clue_key()
+            .expand()
+            .unwrap()
+            .create_clue(precision, OsRng)
+            .unwrap();
+
+        for clue in [&sent, &other] {
+            let partials = shares
+                .iter()
+                .map(|share| share.partial_detection(clue).unwrap())
+                .collect::<Vec<_>>();
+            for pair in [[0, 1], [0, 2], [1, 2]] {
+                let subset = pair.map(|j| partials[j].clone());
+                assert_eq!(detector.examine(clue, &subset).unwrap(), dtk.examine(clue));
+            }
+            assert_eq!(
+                detector.examine(clue, &partials).unwrap(),
+                dtk.examine(clue)
+            );
+            assert!(detector.examine(clue, &partials[..1]).is_err());
+            assert!(detector
+                .examine(clue, &[partials[0].clone(), partials[0].clone()])
+                .is_err());
+        }
+
+        // Partial detections survive serialization, e.g. to be sent by a detection server.
+        let partials = shares
+            .iter()
+            .map(|share| {
+                let bytes = share.partial_detection(&sent).unwrap().to_bytes();
+                PartialDetection::from_bytes(bytes).unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert!(detector.examine(&sent, &partials).unwrap());
+    }
+
+    #[test]
+    fn shares_roundtrip_and_reject_bad_thresholds() {
+        let dtk = DetectionKey::new(OsRng);
+        assert!(dtk.split(0, 3, OsRng).is_err());
+        assert!(dtk.split(4, 3, OsRng).is_err());
+
+        for share in dtk.split(3, 5, OsRng).unwrap() {
+            let decoded = DetectionKeyShare::from_bytes(share.to_bytes()).unwrap();
+            assert_eq!(decoded.index(), share.index());
+            assert_eq!(decoded.to_bytes(), share.to_bytes());
+        }
+    }
+}
@@ -0,0 +1,183 @@
+//! Batch verification of Groth16 proofs.
+//!
+//! Verifying a single Groth16 proof checks that
+//!
+//! ```text
+//! e(A, B) · e(PI, -γ) · e(C, -δ) = e(α, β)
+//! ```
+//!
+//! where `PI` is the linear combination of the public inputs. A
+//! [`BatchVerifier`] checks many proofs of the same circuit at once, by
+//! checking a random linear combination of their equations:
+//!
+//! ```text
+//! ∏ e(rᵢ·Aᵢ, Bᵢ) · e(Σ rᵢ·PIᵢ, -γ) · e(Σ rᵢ·Cᵢ, -δ) = e(α, β)^(Σ rᵢ)
+//! ```
+//!
+//! which takes a single multi-Miller loop over `n + 2` pairs and a single final
+//! exponentiation, rather than `n` of each. If any proof in the batch is
+//! invalid, the batch fails to verify, except with negligible probability.
+//!
+//! Proofs of a batch that verifies are recorded in a set owned by the caller,
+//! so that verifying one of them again afterwards, with [`is_verified`], can
+//! skip the pairings.
+
+use std::collections::BTreeSet;
+
+use ark_ec::{pairing::Pairing, CurveGroup};
+use ark_ff::{Field, PrimeField, Zero};
+use ark_groth16::{r1cs_to_qap::LibsnarkReduction, Groth16, PreparedVerifyingKey, Proof};
+use ark_relations::r1cs::SynthesisError;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use decaf377::{Bls12_377, Fq};
+use rand_core::{OsRng, RngCore};
+use sha2::{Digest, Sha256};
+
+use crate::GROTH16_PROOF_LENGTH_BYTES;
+
+/// A batch of Groth16 proofs of the same circuit, to be verified at once.
+#[derive(Debug, Default)]
+pub struct BatchVerifier {
+    items: Vec<BatchItem>,
+}
+
+#[derive(Debug)]
+struct BatchItem {
+    bytes: [u8; GROTH16_PROOF_LENGTH_BYTES],
+    proof: Proof<Bls12_377>,
+    public_inputs: Vec<Fq>,
+}
+
+impl BatchVerifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a proof with its public inputs to the batch.
+    ///
+    /// Unlike for individual verification, the points of the proof are checked
+    /// to be in the prime order subgroup, which the random linear combination
+    /// relies on.
+    pub fn queue(
+        &mut self,
+        bytes: [u8; GROTH16_PROOF_LENGTH_BYTES],
+        public_inputs: Vec<Fq>,
+    ) -> Result<(), SerializationError> {
+        let proof = Proof::deserialize_compressed(&bytes[..])?;
+        self.items.push(BatchItem {
+            bytes,
+            proof,
+            public_inputs,
+        });
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Verifies all the proofs of the batch against `vk`, returning whether
+    /// they are all valid.
+    ///
+    /// If they are, each of them is recorded in `verified`, see [`is_verified`].
+    /// If not, nothing is recorded, and the invalid proofs can only be found by
+    /// verifying them individually.
+    pub fn verify(
+        self,
+        vk: &PreparedVerifyingKey<Bls12_377>,
+        verified: &mut BTreeSet<[u8; 32]>,
+    ) -> Result<bool, SynthesisError> {
+        // Proofs that were already verified, e.g. twice in the same proposal,
+        // need not be checked again.
+        let (items, keys): (Vec<_>, Vec<_>) = self
+            .items
+            .into_iter()
+            .map(|item| {
+                let key = verified_key(vk, &item.bytes, &item.public_inputs);
+                (item, key)
+            })
+            .filter(|(_, key)| !verified.contains(key))
+            .unzip();
+        if items.is_empty() {
+            return Ok(true);
+        }
+
+        let start = std::time::Instant::now();
+        let mut a = Vec::with_capacity(items.len() + 2);
+        let mut b = Vec::with_capacity(items.len() + 2);
+        let mut prepared_inputs = <Bls12_377 as Pairing>::G1::zero();
+        let mut c = <Bls12_377 as Pairing>::G1::zero();
+        let mut r_sum = Fq::zero();
+        for item in &items {
+            // 128 bits of randomness are enough for the soundness of the batch.
+            let mut r_bytes = [0u8; 16];
+            OsRng.fill_bytes(&mut r_bytes);
+            let r = Fq::from_le_bytes_mod_order(&r_bytes);
+
+            let inputs =
+                Groth16::<Bls12_377, LibsnarkReduction>::prepare_inputs(vk, &item.public_inputs)?;
+            prepared_inputs += inputs * r;
+            c += item.proof.c * r;
+            r_sum += r;
+            a.push(<Bls12_377 as Pairing>::G1Prepared::from(
+                (item.proof.a * r).into_affine(),
+            ));
+            b.push(<Bls12_377 as Pairing>::G2Prepared::from(item.proof.b));
+        }
+        a.push(prepared_inputs.into_affine().into());
+        b.push(vk.gamma_g2_neg_pc.clone());
+        a.push(c.into_affine().into());
+        b.push(vk.delta_g2_neg_pc.clone());
+
+        let miller_loop = Bls12_377::multi_miller_loop(a, b);
+        let batch_verified = Bls12_377::final_exponentiation(miller_loop)
+            .ok_or(SynthesisError::UnexpectedIdentity)?
+            .0
+            == vk.alpha_g1_beta_g2.pow(r_sum.into_bigint());
+        tracing::debug!(
+            proofs = items.len(),
+            verified = batch_verified,
+            elapsed = ?start.elapsed(),
+            "verified batch of proofs"
+        );
+
+        if batch_verified {
+            verified.extend(keys);
+        }
+        Ok(batch_verified)
+    }
+}
+
+/// Returns whether a proof with these public inputs was recorded in
+/// `verified` by a [`BatchVerifier`] for `vk`.
+pub fn is_verified(
+    verified: &BTreeSet<[u8; 32]>,
+    vk: &PreparedVerifyingKey<Bls12_377>,
+    proof: &[u8; GROTH16_PROOF_LENGTH_BYTES],
+    public_inputs: &[Fq],
+) -> bool {
+    verified.contains(&verified_key(vk, proof, public_inputs))
+}
+
+fn verified_key(
+    vk: &PreparedVerifyingKey<Bls12_377>,
+    proof: &[u8; GROTH16_PROOF_LENGTH_BYTES],
+    public_inputs: &[Fq],
+) -> [u8; 32] {
+    let mut bytes = Vec::new();
+    // Circuits share α and β from the first phase of the setup, but δ comes
+    // from the second, specific to each circuit.
+    vk.vk
+        .delta_g2
+        .serialize_compressed(&mut bytes)
+        .expect("can serialize to a vec");
+    bytes.extend_from_slice(proof);
+    public_inputs
+        .serialize_compressed(&mut bytes)
+        .expect("can serialize to a vec");
+    Sha256::digest(&bytes).into()
+}
@@ -7,6 +7,7 @@ use std::{
 };
 
 use futures::Stream;
+use penumbra_proto::{penumbra::crypto::tct::v1 as pb, DomainType};
 
 use crate::prelude::*;
 
@@ -327,3 +328,204 @@ impl Iterator for Updates {
         None
     }
 }
+
+impl Updates {
+    /// Write these updates to a synchronous writer holding the tree as it was before them.
+    ///
+    /// Deletions are written before the hashes and commitments to store, since the updates are
+    /// not kept in the order they were produced, and a forgotten node's own hash is stored
+    /// alongside the deletion of its children.
+    pub fn write_to<W: Write>(self, writer: &mut W) -> Result<(), W::Error> {
+        for DeleteRange {
+            below_height,
+            positions,
+        } in self.delete_ranges
+        {
+            writer.delete_range(below_height, positions)?;
+        }
+        for StoreHash {
+            position,
+            height,
+            hash,
+            essential,
+        } in self.store_hashes
+        {
+            writer.add_hash(position, height, hash, essential)?;
+        }
+        for StoreCommitment {
+            position,
+            commitment,
+        } in self.store_commitments
+        {
+            writer.add_commitment(position, commitment)?;
+        }
+        if let Some(forgotten) = self.set_forgotten {
+            writer.set_forgotten(forgotten)?;
+        }
+        if let Some(position) = self.set_position {
+            writer.set_position(position)?;
+        }
+        Ok(())
+    }
+}
+
+/// An error occurred when decoding [`Updates`] from their protobuf representation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("could not decode tree updates")]
+pub struct UpdatesDecodeError;
+
+impl DomainType for Updates {
+    type Proto = pb::TreeUpdates;
+}
+
+impl From<Updates> for pb::TreeUpdates {
+    fn from(updates: Updates) -> Self {
+        use pb::tree_updates;
+
+        Self {
+            set_position: updates.set_position.map(|position| match position {
+                StoredPosition::Position(position) => tree_updates::StoredPosition {
+                    position: position.into(),
+                    full: false,
+                },
+                StoredPosition::Full => tree_updates::StoredPosition {
+                    position: 0,
+                    full: true,
+                },
+            }),
+            set_forgotten: updates
+                .set_forgotten
+                .map(|forgotten| tree_updates::Forgotten {
+                    version: forgotten.into(),
+                }),
+            store_commitments: updates
+                .store_commitments
+                .into_iter()
+                .map(|store| tree_updates::StoreCommitment {
+                    position: store.position.into(),
+                    commitment: Some(store.commitment.into()),
+                })
+                .collect(),
+            store_hashes: updates
+                .store_hashes
+                .into_iter()
+                .map(|store| tree_updates::StoreHash {
+                    position: store.position.into(),
+                    height: store.height.into(),
+                    hash: store.hash.to_bytes().to_vec(),
+                    essential: store.essential,
+                })
+                .collect(),
+            delete_ranges: updates
+                .delete_ranges
+                .into_iter()
+                .map(|delete| tree_updates::DeleteRange {
+                    below_height: delete.below_height.into(),
+                    start: delete.positions.start.into(),
+                    end: delete.positions.end.into(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<pb::TreeUpdates> for Updates {
+    type Error = UpdatesDecodeError;
+
+    fn try_from(updates: pb::TreeUpdates) -> Result<Self, Self::Error> {
+        // Heights range from 0 to 24, the height of the tree.
+        let height = |height: u32| match u8::try_from(height) {
+            Ok(height) if height <= 24 => Ok(height),
+            _ => Err(UpdatesDecodeError),
+        };
+
+        Ok(Self {
+            set_position: updates.set_position.map(|position| {
+                if position.full {
+                    StoredPosition::Full
+                } else {
+                    StoredPosition::Position(position.position.into())
+                }
+            }),
+            set_forgotten: updates
+                .set_forgotten
+                .map(|forgotten| forgotten.version.into()),
+            store_commitments: updates
+                .store_commitments
+                .into_iter()
+                .map(|store| {
+                    Ok(StoreCommitment {
+                        position: store.position.into(),
+                        commitment: store
+                            .commitment
+                            .ok_or(UpdatesDecodeError)?
+                            .try_into()
+                            .map_err(|_| UpdatesDecodeError)?,
+                    })
+                })
+                .collect::<Result<_, _>>()?,
+            store_hashes: updates
+                .store_hashes
+                .into_iter()
+                .map(|store| {
+                    let hash = store.hash.try_into().map_err(|_| UpdatesDecodeError)?;
+                    Ok(StoreHash {
+                        position: store.position.into(),
+                        height: height(store.height)?,
+                        hash: Hash::from_bytes(hash).map_err(|_| UpdatesDecodeError)?,
+                        essential: store.essential,
+                    })
+                })
+                .collect::<Result<_, _>>()?,
+            delete_ranges: updates
+                .delete_ranges
+                .into_iter()
+                .map(|delete| {
+                    Ok(DeleteRange {
+                        below_height: height(delete.below_height)?,
+                        positions: delete.start.into()..delete.end.into(),
+                    })
+                })
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use decaf377::Fq;
+
+    use super::*;
+
+    #[test]
+    fn updates_roundtrip_and_apply() {
+        let mut tree = Tree::new();
+        let mut store = InMemory::new();
+
+        for block in 0..4u64 {
+            let last_position = tree.position();
+            let last_forgotten = tree.forgotten();
+
+            for i in 0..4u64 {
+                let commitment = StateCommitment(Fq::from(block * 4 + i + 1));
+                let witness = if i == 0 {
+                    Witness::Keep
+                } else {
+                    Witness::Forget
+                };
+                tree.insert(witness, commitment).unwrap();
+            }
+            if block > 0 {
+                // Forget a commitment witnessed in the previous block.
+                assert!(tree.forget(StateCommitment(Fq::from(block * 4 - 3))));
+            }
+            tree.end_block().unwrap();
+
+            let updates: Updates = tree.updates(last_position, last_forgotten).collect();
+            let updates = Updates::decode(updates.encode_to_vec().as_slice()).unwrap();
+            updates.write_to(&mut store).unwrap();
+
+            assert_eq!(Tree::from_reader(&mut store).unwrap(), tree);
+        }
+    }
+}
@@ -33,6 +33,62 @@ impl ::prost::Name for TransactionsByHeightResponse {
         ::prost::alloc::format!("penumbra.core.app.v1.{}", Self::NAME)
     }
 }
+/// Requests an audit of the supply of assets.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValueBalanceAuditRequest {
+    /// The assets to audit. If empty, every asset whose supply the chain has
+    /// changed is audited.
+    #[prost(message, repeated, tag = "1")]
+    pub asset_ids: ::prost::alloc::vec::Vec<super::super::asset::v1::AssetId>,
+}
+impl ::prost::Name for ValueBalanceAuditRequest {
+    const NAME: &'static str = "ValueBalanceAuditRequest";
+    const PACKAGE: &'static str = "penumbra.core.app.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.app.v1.{}", Self::NAME)
+    }
+}
+/// The audit of the supply of a single asset.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValueBalanceAuditResponse {
+    /// The audited asset.
+    #[prost(message, optional, tag = "1")]
+    pub asset_id: ::core::option::Option<super::super::asset::v1::AssetId>,
+    /// The changes the chain has made to the supply of the asset since genesis.
+    #[prost(message, optional, tag = "2")]
+    pub supply: ::core::option::Option<
+        super::super::component::shielded_pool::v1::AssetSupply,
+    >,
+    /// The amount of the asset issued by the chain: what was issued at genesis
+    /// and as staking rewards, plus inbound IBC transfers, less outbound ones.
+    ///
+    /// Absent if more of the asset left through IBC than the chain issued, which
+    /// is only possible for assets that transactions convert other assets into,
+    /// like the staking token.
+    #[prost(message, optional, tag = "3")]
+    pub issued: ::core::option::Option<super::super::num::v1::Amount>,
+    /// The sum of the ICS20 value balances of the asset over all channels: the
+    /// amount escrowed, for assets native to Penumbra, or the amount of vouchers
+    /// outstanding, for assets transferred in.
+    #[prost(message, optional, tag = "4")]
+    pub ibc_balance: ::core::option::Option<super::super::num::v1::Amount>,
+    /// The balance of the asset in the Community Pool.
+    #[prost(message, optional, tag = "5")]
+    pub community_pool_balance: ::core::option::Option<super::super::num::v1::Amount>,
+    /// Whether the Community Pool balance equals its deposits less its
+    /// withdrawals.
+    #[prost(bool, tag = "6")]
+    pub community_pool_reconciled: bool,
+}
+impl ::prost::Name for ValueBalanceAuditResponse {
+    const NAME: &'static str = "ValueBalanceAuditResponse";
+    const PACKAGE: &'static str = "penumbra.core.app.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.app.v1.{}", Self::NAME)
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AppParameters {
@@ -365,6 +421,39 @@ pub mod query_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Audits the supply of assets, reporting the changes the chain has made to
+        /// the supply of each asset alongside the IBC and Community Pool balances they
+        /// reconcile with.
+        pub async fn value_balance_audit(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ValueBalanceAuditRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ValueBalanceAuditResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.app.v1.QueryService/ValueBalanceAudit",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.app.v1.QueryService",
+                        "ValueBalanceAudit",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -391,6 +480,25 @@ pub mod query_service_server {
             tonic::Response<super::TransactionsByHeightResponse>,
             tonic::Status,
         >;
+        /// Server streaming response type for the ValueBalanceAudit method.
+        type ValueBalanceAuditStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::ValueBalanceAuditResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Audits the supply of assets, reporting the changes the chain has made to
+        /// the supply of each asset alongside the IBC and Community Pool balances they
+        /// reconcile with.
+        async fn value_balance_audit(
+            &self,
+            request: tonic::Request<super::ValueBalanceAuditRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ValueBalanceAuditStream>,
+            tonic::Status,
+        >;
     }
     /// Query operations for the overall Penumbra application.
     #[derive(Debug)]
@@ -565,6 +673,55 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.app.v1.QueryService/ValueBalanceAudit" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValueBalanceAuditSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::ServerStreamingService<
+                        super::ValueBalanceAuditRequest,
+                    > for ValueBalanceAuditSvc<T> {
+                        type Response = super::ValueBalanceAuditResponse;
+                        type ResponseStream = T::ValueBalanceAuditStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ValueBalanceAuditRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::value_balance_audit(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ValueBalanceAuditSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -1084,3 +1084,283 @@ impl<'de> serde::Deserialize<'de> for TransactionsByHeightResponse {
         deserializer.deserialize_struct("penumbra.core.app.v1.TransactionsByHeightResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for ValueBalanceAuditRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.asset_ids.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.ValueBalanceAuditRequest", len)?;
+        if !self.asset_ids.is_empty() {
+            struct_ser.serialize_field("assetIds", &self.asset_ids)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValueBalanceAuditRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "asset_ids",
+            "assetIds",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AssetIds,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "assetIds" | "asset_ids" => Ok(GeneratedField::AssetIds),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValueBalanceAuditRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1.ValueBalanceAuditRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValueBalanceAuditRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut asset_ids__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AssetIds => {
+                            if asset_ids__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetIds"));
+                            }
+                            asset_ids__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValueBalanceAuditRequest {
+                    asset_ids: asset_ids__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1.ValueBalanceAuditRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValueBalanceAuditResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.asset_id.is_some() {
+            len += 1;
+        }
+        if self.supply.is_some() {
+            len += 1;
+        }
+        if self.issued.is_some() {
+            len += 1;
+        }
+        if self.ibc_balance.is_some() {
+            len += 1;
+        }
+        if self.community_pool_balance.is_some() {
+            len += 1;
+        }
+        if self.community_pool_reconciled {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.ValueBalanceAuditResponse", len)?;
+        if let Some(v) = self.asset_id.as_ref() {
+            struct_ser.serialize_field("assetId", v)?;
+        }
+        if let Some(v) = self.supply.as_ref() {
+            struct_ser.serialize_field("supply", v)?;
+        }
+        if let Some(v) = self.issued.as_ref() {
+            struct_ser.serialize_field("issued", v)?;
+        }
+        if let Some(v) = self.ibc_balance.as_ref() {
+            struct_ser.serialize_field("ibcBalance", v)?;
+        }
+        if let Some(v) = self.community_pool_balance.as_ref() {
+            struct_ser.serialize_field("communityPoolBalance", v)?;
+        }
+        if self.community_pool_reconciled {
+            struct_ser.serialize_field("communityPoolReconciled", &self.community_pool_reconciled)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValueBalanceAuditResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "asset_id",
+            "assetId",
+            "supply",
+            "issued",
+            "ibc_balance",
+            "ibcBalance",
+            "community_pool_balance",
+            "communityPoolBalance",
+            "community_pool_reconciled",
+            "communityPoolReconciled",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AssetId,
+            Supply,
+            Issued,
+            IbcBalance,
+            CommunityPoolBalance,
+            CommunityPoolReconciled,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            "supply" => Ok(GeneratedField::Supply),
+                            "issued" => Ok(GeneratedField::Issued),
+                            "ibcBalance" | "ibc_balance" => Ok(GeneratedField::IbcBalance),
+                            "communityPoolBalance" | "community_pool_balance" => Ok(GeneratedField::CommunityPoolBalance),
+                            "communityPoolReconciled" | "community_pool_reconciled" => Ok(GeneratedField::CommunityPoolReconciled),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValueBalanceAuditResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1.ValueBalanceAuditResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValueBalanceAuditResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut asset_id__ = None;
+                let mut supply__ = None;
+                let mut issued__ = None;
+                let mut ibc_balance__ = None;
+                let mut community_pool_balance__ = None;
+                let mut community_pool_reconciled__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
+                            }
+                            asset_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Supply => {
+                            if supply__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("supply"));
+                            }
+                            supply__ = map_.next_value()?;
+                        }
+                        GeneratedField::Issued => {
+                            if issued__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("issued"));
+                            }
+                            issued__ = map_.next_value()?;
+                        }
+                        GeneratedField::IbcBalance => {
+                            if ibc_balance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ibcBalance"));
+                            }
+                            ibc_balance__ = map_.next_value()?;
+                        }
+                        GeneratedField::CommunityPoolBalance => {
+                            if community_pool_balance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("communityPoolBalance"));
+                            }
+                            community_pool_balance__ = map_.next_value()?;
+                        }
+                        GeneratedField::CommunityPoolReconciled => {
+                            if community_pool_reconciled__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("communityPoolReconciled"));
+                            }
+                            community_pool_reconciled__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValueBalanceAuditResponse {
+                    asset_id: asset_id__,
+                    supply: supply__,
+                    issued: issued__,
+                    ibc_balance: ibc_balance__,
+                    community_pool_balance: community_pool_balance__,
+                    community_pool_reconciled: community_pool_reconciled__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1.ValueBalanceAuditResponse", FIELDS, GeneratedVisitor)
+    }
+}
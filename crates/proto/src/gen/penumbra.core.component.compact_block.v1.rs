@@ -45,6 +45,12 @@ pub struct CompactBlock {
     /// The epoch index
     #[prost(uint64, tag = "11")]
     pub epoch_index: u64,
+    /// The changes made to the state commitment tree by this block, as they would
+    /// be written to a store of the tree, if this block inserted into it.
+    #[prost(message, optional, tag = "12")]
+    pub sct_updates: ::core::option::Option<
+        super::super::super::super::crypto::tct::v1::TreeUpdates,
+    >,
 }
 impl ::prost::Name for CompactBlock {
     const NAME: &'static str = "CompactBlock";
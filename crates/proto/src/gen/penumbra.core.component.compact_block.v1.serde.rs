@@ -42,6 +42,9 @@ impl serde::Serialize for CompactBlock {
         if self.epoch_index != 0 {
             len += 1;
         }
+        if self.sct_updates.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.compact_block.v1.CompactBlock", len)?;
         if self.height != 0 {
             #[allow(clippy::needless_borrow)]
@@ -81,6 +84,9 @@ impl serde::Serialize for CompactBlock {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("epochIndex", ToString::to_string(&self.epoch_index).as_str())?;
         }
+        if let Some(v) = self.sct_updates.as_ref() {
+            struct_ser.serialize_field("sctUpdates", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -113,6 +119,8 @@ impl<'de> serde::Deserialize<'de> for CompactBlock {
             "altGasPrices",
             "epoch_index",
             "epochIndex",
+            "sct_updates",
+            "sctUpdates",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -129,6 +137,7 @@ impl<'de> serde::Deserialize<'de> for CompactBlock {
             GasPrices,
             AltGasPrices,
             EpochIndex,
+            SctUpdates,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -163,6 +172,7 @@ impl<'de> serde::Deserialize<'de> for CompactBlock {
                             "gasPrices" | "gas_prices" => Ok(GeneratedField::GasPrices),
                             "altGasPrices" | "alt_gas_prices" => Ok(GeneratedField::AltGasPrices),
                             "epochIndex" | "epoch_index" => Ok(GeneratedField::EpochIndex),
+                            "sctUpdates" | "sct_updates" => Ok(GeneratedField::SctUpdates),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -194,6 +204,7 @@ impl<'de> serde::Deserialize<'de> for CompactBlock {
                 let mut gas_prices__ = None;
                 let mut alt_gas_prices__ = None;
                 let mut epoch_index__ = None;
+                let mut sct_updates__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Height => {
@@ -272,6 +283,12 @@ impl<'de> serde::Deserialize<'de> for CompactBlock {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::SctUpdates => {
+                            if sct_updates__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sctUpdates"));
+                            }
+                            sct_updates__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -290,6 +307,7 @@ impl<'de> serde::Deserialize<'de> for CompactBlock {
                     gas_prices: gas_prices__,
                     alt_gas_prices: alt_gas_prices__.unwrap_or_default(),
                     epoch_index: epoch_index__.unwrap_or_default(),
+                    sct_updates: sct_updates__,
                 })
             }
         }
@@ -607,6 +607,10 @@ pub struct Position {
     /// / immediately after being filled.
     #[prost(bool, tag = "5")]
     pub close_on_fill: bool,
+    /// If nonzero, the position is closed at the end of the block at this height,
+    /// after batch execution, so that its reserves can be withdrawn.
+    #[prost(uint64, tag = "6")]
+    pub expiry_height: u64,
 }
 impl ::prost::Name for Position {
     const NAME: &'static str = "Position";
@@ -879,6 +883,58 @@ impl ::prost::Name for SwapExecution {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
 }
+/// A transaction action that funds a reward gauge of a trading pair.
+///
+/// The rewards are distributed over the following blocks to the open positions
+/// of the trading pair, in proportion to their size, and are withdrawn together
+/// with the reserves of the positions.
+///
+/// Gauges are funded by governance: this action is only valid in the
+/// transactions of Community Pool spend proposals.
+///
+/// This action's contribution to the transaction's value balance is to consume
+/// the rewards.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GaugeFund {
+    /// The trading pair whose liquidity providers earn the rewards.
+    #[prost(message, optional, tag = "1")]
+    pub trading_pair: ::core::option::Option<TradingPair>,
+    /// The rewards to distribute.
+    #[prost(message, optional, tag = "2")]
+    pub rewards: ::core::option::Option<super::super::super::asset::v1::Value>,
+    /// The number of blocks over which the rewards are distributed.
+    #[prost(uint64, tag = "3")]
+    pub duration: u64,
+}
+impl ::prost::Name for GaugeFund {
+    const NAME: &'static str = "GaugeFund";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// The rewards of a trading pair still to be distributed to its liquidity providers.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RewardGauge {
+    /// The trading pair whose liquidity providers earn the rewards.
+    #[prost(message, optional, tag = "1")]
+    pub trading_pair: ::core::option::Option<TradingPair>,
+    /// The rewards that are still to be distributed.
+    #[prost(message, optional, tag = "2")]
+    pub remaining: ::core::option::Option<super::super::super::asset::v1::Value>,
+    /// The last height at which rewards are distributed.
+    #[prost(uint64, tag = "3")]
+    pub end_height: u64,
+}
+impl ::prost::Name for RewardGauge {
+    const NAME: &'static str = "RewardGauge";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
 /// Contains private and public data for withdrawing funds from a closed position.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1298,6 +1354,10 @@ pub struct SimulateTradeResponse {
     /// Estimated input amount that will not be swapped due to liquidity
     #[prost(message, optional, tag = "2")]
     pub unfilled: ::core::option::Option<super::super::super::asset::v1::Value>,
+    /// The fraction of the output lost to price impact, relative to filling the
+    /// whole input at the best price along the route, between 0 and 1.
+    #[prost(double, tag = "3")]
+    pub price_impact: f64,
 }
 impl ::prost::Name for SimulateTradeResponse {
     const NAME: &'static str = "SimulateTradeResponse";
@@ -1446,6 +1506,26 @@ impl ::prost::Name for EventPositionWithdraw {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventGaugeFund {
+    /// The trading pair whose liquidity providers earn the rewards.
+    #[prost(message, optional, tag = "1")]
+    pub trading_pair: ::core::option::Option<TradingPair>,
+    /// The rewards added to the gauge.
+    #[prost(message, optional, tag = "2")]
+    pub rewards: ::core::option::Option<super::super::super::asset::v1::Value>,
+    /// The last height at which the gauge distributes rewards.
+    #[prost(uint64, tag = "3")]
+    pub end_height: u64,
+}
+impl ::prost::Name for EventGaugeFund {
+    const NAME: &'static str = "EventGaugeFund";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventPositionExecution {
     /// The ID of the position executed against.
     #[prost(message, optional, tag = "1")]
@@ -1599,6 +1679,38 @@ pub struct DexParameters {
     /// for a single pair
     #[prost(uint32, tag = "5")]
     pub max_execution_budget: u32,
+    /// The windows, in blocks, over which the price oracle tracks time-weighted
+    /// average prices of each trading pair.
+    ///
+    /// If this is empty, the price oracle is disabled.
+    #[prost(uint64, repeated, tag = "6")]
+    pub price_oracle_windows: ::prost::alloc::vec::Vec<u64>,
+    /// The maximum number of assets, in addition to the fixed candidates, that
+    /// routing considers as the next hop from each asset of a path, chosen by
+    /// the depth of their liquidity.
+    #[prost(uint32, tag = "7")]
+    pub max_dynamic_candidates: u32,
+    /// How far past the spill price, in basis points, a route is filled before
+    /// routing searches for the next best route.
+    ///
+    /// The spill price is the price of the next best route. A nonzero margin
+    /// trades a slightly worse execution price for fewer path searches.
+    #[prost(uint32, tag = "8")]
+    pub spill_price_margin_bps: u32,
+    /// The maximum deviation, in basis points, of a batch swap's clearing price
+    /// from the time-weighted average price of its trading pair. A batch swap
+    /// clearing beyond it is not executed, and its inputs are returned unfilled.
+    ///
+    /// If this is zero, the price deviation circuit breaker is disabled.
+    #[prost(uint32, tag = "9")]
+    pub max_price_deviation_bps: u32,
+    /// The window, in blocks, of the time-weighted average price that clearing
+    /// prices are compared to. It can't be longer than the price oracle windows.
+    #[prost(uint64, tag = "10")]
+    pub price_deviation_window: u64,    /// The maximum number of reward gauges, each distributing a different asset,
+    /// that a trading pair can have at once.
+    #[prost(uint32, tag = "11")]
+    pub max_reward_gauges_per_pair: u32,
 }
 impl ::prost::Name for DexParameters {
     const NAME: &'static str = "DexParameters";
@@ -1673,6 +1785,14 @@ pub struct CandlestickDataRequest {
     /// If this is unset (= 0), the server will return the most recent data points.
     #[prost(uint64, tag = "3")]
     pub start_height: u64,
+    /// The number of blocks each candlestick covers.
+    ///
+    /// Each candlestick covers the blocks from its height, a multiple of the
+    /// resolution, to the next multiple. If this is unset (= 0), each candlestick
+    /// covers a single block. The server only supports the resolutions it
+    /// aggregates candlesticks at.
+    #[prost(uint64, tag = "4")]
+    pub resolution: u64,
 }
 impl ::prost::Name for CandlestickDataRequest {
     const NAME: &'static str = "CandlestickDataRequest";
@@ -1723,6 +1843,150 @@ impl ::prost::Name for CandlestickDataStreamResponse {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
 }
+/// A time-weighted average of the prices at which a trading pair's batch swaps cleared.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TimeWeightedAveragePrice {
+    /// The number of blocks the price is averaged over, ending with the block it
+    /// was computed at.
+    #[prost(uint64, tag = "1")]
+    pub window: u64,
+    /// The average price of the start asset of the pair, in terms of its end asset.
+    #[prost(double, tag = "2")]
+    pub price: f64,
+}
+impl ::prost::Name for TimeWeightedAveragePrice {
+    const NAME: &'static str = "TimeWeightedAveragePrice";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TimeWeightedAveragePricesRequest {
+    /// The directed trading pair to request prices for.
+    #[prost(message, optional, tag = "1")]
+    pub pair: ::core::option::Option<DirectedTradingPair>,
+}
+impl ::prost::Name for TimeWeightedAveragePricesRequest {
+    const NAME: &'static str = "TimeWeightedAveragePricesRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TimeWeightedAveragePricesResponse {
+    /// The height the prices were computed at.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// The time-weighted average prices, one per window tracked by the price
+    /// oracle, omitting windows longer than the oracle's history of the pair.
+    #[prost(message, repeated, tag = "2")]
+    pub prices: ::prost::alloc::vec::Vec<TimeWeightedAveragePrice>,
+}
+impl ::prost::Name for TimeWeightedAveragePricesResponse {
+    const NAME: &'static str = "TimeWeightedAveragePricesResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchSwapOutputDataStreamRequest {
+    /// The trading pairs to subscribe to.
+    #[prost(message, repeated, tag = "1")]
+    pub trading_pairs: ::prost::alloc::vec::Vec<TradingPair>,
+}
+impl ::prost::Name for BatchSwapOutputDataStreamRequest {
+    const NAME: &'static str = "BatchSwapOutputDataStreamRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchSwapOutputDataStreamResponse {
+    /// The height of the block the trading pair was executed in.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// The trading pair executed in the block.
+    #[prost(message, optional, tag = "2")]
+    pub trading_pair: ::core::option::Option<TradingPair>,
+    /// The output of the trading pair's batch swap, if there was one.
+    #[prost(message, optional, tag = "3")]
+    pub output_data: ::core::option::Option<BatchSwapOutputData>,
+    /// The executions of positions on the trading pair, in the order they happened,
+    /// whether they were part of a batch swap or of arbitrage.
+    #[prost(message, repeated, tag = "4")]
+    pub position_executions: ::prost::alloc::vec::Vec<EventPositionExecution>,
+}
+impl ::prost::Name for BatchSwapOutputDataStreamResponse {
+    const NAME: &'static str = "BatchSwapOutputDataStreamResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RewardGaugesRequest {
+    /// The trading pair to stream the reward gauges of.
+    #[prost(message, optional, tag = "1")]
+    pub trading_pair: ::core::option::Option<TradingPair>,
+}
+impl ::prost::Name for RewardGaugesRequest {
+    const NAME: &'static str = "RewardGaugesRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RewardGaugesResponse {
+    #[prost(message, optional, tag = "1")]
+    pub gauge: ::core::option::Option<RewardGauge>,
+}
+impl ::prost::Name for RewardGaugesResponse {
+    const NAME: &'static str = "RewardGaugesResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionRewardsRequest {
+    /// The position to get the accrued rewards of.
+    #[prost(message, optional, tag = "1")]
+    pub position_id: ::core::option::Option<PositionId>,
+}
+impl ::prost::Name for PositionRewardsRequest {
+    const NAME: &'static str = "PositionRewardsRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionRewardsResponse {
+    /// The rewards accrued by the position, which its next withdrawal must claim.
+    #[prost(message, repeated, tag = "1")]
+    pub rewards: ::prost::alloc::vec::Vec<super::super::super::asset::v1::Value>,
+}
+impl ::prost::Name for PositionRewardsResponse {
+    const NAME: &'static str = "PositionRewardsResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod query_service_client {
@@ -2192,6 +2456,135 @@ pub mod query_service_client {
                 );
             self.inner.server_streaming(req, path, codec).await
         }
+        /// Get the time-weighted average prices of a trading pair, over each of the
+        /// windows tracked by the price oracle.
+        pub async fn time_weighted_average_prices(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TimeWeightedAveragePricesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimeWeightedAveragePricesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.dex.v1.QueryService/TimeWeightedAveragePrices",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.dex.v1.QueryService",
+                        "TimeWeightedAveragePrices",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Subscribe to the batch swap outputs and position executions of trading pairs, block by block.
+        pub async fn batch_swap_output_data_stream(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BatchSwapOutputDataStreamRequest>,
+        ) -> std::result::Result<
+            tonic::Response<
+                tonic::codec::Streaming<super::BatchSwapOutputDataStreamResponse>,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.dex.v1.QueryService/BatchSwapOutputDataStream",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.dex.v1.QueryService",
+                        "BatchSwapOutputDataStream",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Stream the reward gauges of a trading pair.
+        pub async fn reward_gauges(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RewardGaugesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<
+                tonic::codec::Streaming<super::RewardGaugesResponse>,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.dex.v1.QueryService/RewardGauges",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.dex.v1.QueryService",
+                        "RewardGauges",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Get the rewards accrued by a position, to be withdrawn with its reserves.
+        pub async fn position_rewards(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PositionRewardsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PositionRewardsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.dex.v1.QueryService/PositionRewards",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.dex.v1.QueryService",
+                        "PositionRewards",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated client implementations.
@@ -2474,6 +2867,57 @@ pub mod query_service_server {
             tonic::Response<Self::CandlestickDataStreamStream>,
             tonic::Status,
         >;
+        /// Get the time-weighted average prices of a trading pair, over each of the
+        /// windows tracked by the price oracle.
+        async fn time_weighted_average_prices(
+            &self,
+            request: tonic::Request<super::TimeWeightedAveragePricesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimeWeightedAveragePricesResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the BatchSwapOutputDataStream method.
+        type BatchSwapOutputDataStreamStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::BatchSwapOutputDataStreamResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Subscribe to the batch swap outputs and position executions of trading pairs, block by block.
+        async fn batch_swap_output_data_stream(
+            &self,
+            request: tonic::Request<super::BatchSwapOutputDataStreamRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::BatchSwapOutputDataStreamStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the RewardGauges method.
+        type RewardGaugesStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::RewardGaugesResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Stream the reward gauges of a trading pair.
+        async fn reward_gauges(
+            &self,
+            request: tonic::Request<super::RewardGaugesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::RewardGaugesStream>,
+            tonic::Status,
+        >;
+        /// Get the rewards accrued by a position, to be withdrawn with its reserves.
+        async fn position_rewards(
+            &self,
+            request: tonic::Request<super::PositionRewardsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PositionRewardsResponse>,
+            tonic::Status,
+        >;
     }
     /// Query operations for the DEX component.
     #[derive(Debug)]
@@ -3137,6 +3581,214 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.component.dex.v1.QueryService/TimeWeightedAveragePrices" => {
+                    #[allow(non_camel_case_types)]
+                    struct TimeWeightedAveragePricesSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::TimeWeightedAveragePricesRequest>
+                    for TimeWeightedAveragePricesSvc<T> {
+                        type Response = super::TimeWeightedAveragePricesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::TimeWeightedAveragePricesRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::time_weighted_average_prices(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = TimeWeightedAveragePricesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.dex.v1.QueryService/BatchSwapOutputDataStream" => {
+                    #[allow(non_camel_case_types)]
+                    struct BatchSwapOutputDataStreamSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::ServerStreamingService<
+                        super::BatchSwapOutputDataStreamRequest,
+                    > for BatchSwapOutputDataStreamSvc<T> {
+                        type Response = super::BatchSwapOutputDataStreamResponse;
+                        type ResponseStream = T::BatchSwapOutputDataStreamStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BatchSwapOutputDataStreamRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::batch_swap_output_data_stream(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = BatchSwapOutputDataStreamSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.dex.v1.QueryService/RewardGauges" => {
+                    #[allow(non_camel_case_types)]
+                    struct RewardGaugesSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::ServerStreamingService<
+                        super::RewardGaugesRequest,
+                    > for RewardGaugesSvc<T> {
+                        type Response = super::RewardGaugesResponse;
+                        type ResponseStream = T::RewardGaugesStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RewardGaugesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::reward_gauges(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RewardGaugesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.dex.v1.QueryService/PositionRewards" => {
+                    #[allow(non_camel_case_types)]
+                    struct PositionRewardsSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::PositionRewardsRequest>
+                    for PositionRewardsSvc<T> {
+                        type Response = super::PositionRewardsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::PositionRewardsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::position_rewards(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PositionRewardsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -1056,6 +1056,254 @@ impl<'de> serde::Deserialize<'de> for BatchSwapOutputDataResponse {
         deserializer.deserialize_struct("penumbra.core.component.dex.v1.BatchSwapOutputDataResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for BatchSwapOutputDataStreamRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.trading_pairs.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.BatchSwapOutputDataStreamRequest", len)?;
+        if !self.trading_pairs.is_empty() {
+            struct_ser.serialize_field("tradingPairs", &self.trading_pairs)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BatchSwapOutputDataStreamRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "trading_pairs",
+            "tradingPairs",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TradingPairs,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tradingPairs" | "trading_pairs" => Ok(GeneratedField::TradingPairs),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BatchSwapOutputDataStreamRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.BatchSwapOutputDataStreamRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BatchSwapOutputDataStreamRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut trading_pairs__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TradingPairs => {
+                            if trading_pairs__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPairs"));
+                            }
+                            trading_pairs__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(BatchSwapOutputDataStreamRequest {
+                    trading_pairs: trading_pairs__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.BatchSwapOutputDataStreamRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BatchSwapOutputDataStreamResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.output_data.is_some() {
+            len += 1;
+        }
+        if !self.position_executions.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.BatchSwapOutputDataStreamResponse", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if let Some(v) = self.output_data.as_ref() {
+            struct_ser.serialize_field("outputData", v)?;
+        }
+        if !self.position_executions.is_empty() {
+            struct_ser.serialize_field("positionExecutions", &self.position_executions)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BatchSwapOutputDataStreamResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+            "trading_pair",
+            "tradingPair",
+            "output_data",
+            "outputData",
+            "position_executions",
+            "positionExecutions",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            TradingPair,
+            OutputData,
+            PositionExecutions,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "outputData" | "output_data" => Ok(GeneratedField::OutputData),
+                            "positionExecutions" | "position_executions" => Ok(GeneratedField::PositionExecutions),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BatchSwapOutputDataStreamResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.BatchSwapOutputDataStreamResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BatchSwapOutputDataStreamResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                let mut trading_pair__ = None;
+                let mut output_data__ = None;
+                let mut position_executions__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::OutputData => {
+                            if output_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outputData"));
+                            }
+                            output_data__ = map_.next_value()?;
+                        }
+                        GeneratedField::PositionExecutions => {
+                            if position_executions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionExecutions"));
+                            }
+                            position_executions__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(BatchSwapOutputDataStreamResponse {
+                    height: height__.unwrap_or_default(),
+                    trading_pair: trading_pair__,
+                    output_data: output_data__,
+                    position_executions: position_executions__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.BatchSwapOutputDataStreamResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for CandlestickData {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1287,6 +1535,9 @@ impl serde::Serialize for CandlestickDataRequest {
         if self.start_height != 0 {
             len += 1;
         }
+        if self.resolution != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.CandlestickDataRequest", len)?;
         if let Some(v) = self.pair.as_ref() {
             struct_ser.serialize_field("pair", v)?;
@@ -1299,6 +1550,10 @@ impl serde::Serialize for CandlestickDataRequest {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
         }
+        if self.resolution != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("resolution", ToString::to_string(&self.resolution).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -1313,6 +1568,7 @@ impl<'de> serde::Deserialize<'de> for CandlestickDataRequest {
             "limit",
             "start_height",
             "startHeight",
+            "resolution",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1320,6 +1576,7 @@ impl<'de> serde::Deserialize<'de> for CandlestickDataRequest {
             Pair,
             Limit,
             StartHeight,
+            Resolution,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1345,6 +1602,7 @@ impl<'de> serde::Deserialize<'de> for CandlestickDataRequest {
                             "pair" => Ok(GeneratedField::Pair),
                             "limit" => Ok(GeneratedField::Limit),
                             "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "resolution" => Ok(GeneratedField::Resolution),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1367,6 +1625,7 @@ impl<'de> serde::Deserialize<'de> for CandlestickDataRequest {
                 let mut pair__ = None;
                 let mut limit__ = None;
                 let mut start_height__ = None;
+                let mut resolution__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Pair => {
@@ -1391,6 +1650,14 @@ impl<'de> serde::Deserialize<'de> for CandlestickDataRequest {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::Resolution => {
+                            if resolution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("resolution"));
+                            }
+                            resolution__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -1400,6 +1667,7 @@ impl<'de> serde::Deserialize<'de> for CandlestickDataRequest {
                     pair: pair__,
                     limit: limit__.unwrap_or_default(),
                     start_height: start_height__.unwrap_or_default(),
+                    resolution: resolution__.unwrap_or_default(),
                 })
             }
         }
@@ -1714,6 +1982,24 @@ impl serde::Serialize for DexParameters {
         if self.max_execution_budget != 0 {
             len += 1;
         }
+        if !self.price_oracle_windows.is_empty() {
+            len += 1;
+        }
+        if self.max_dynamic_candidates != 0 {
+            len += 1;
+        }
+        if self.spill_price_margin_bps != 0 {
+            len += 1;
+        }
+        if self.max_price_deviation_bps != 0 {
+            len += 1;
+        }
+        if self.price_deviation_window != 0 {
+            len += 1;
+        }
+        if self.max_reward_gauges_per_pair != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.DexParameters", len)?;
         if self.is_enabled {
             struct_ser.serialize_field("isEnabled", &self.is_enabled)?;
@@ -1730,6 +2016,25 @@ impl serde::Serialize for DexParameters {
         if self.max_execution_budget != 0 {
             struct_ser.serialize_field("maxExecutionBudget", &self.max_execution_budget)?;
         }
+        if !self.price_oracle_windows.is_empty() {
+            struct_ser.serialize_field("priceOracleWindows", &self.price_oracle_windows.iter().map(ToString::to_string).collect::<Vec<_>>())?;
+        }
+        if self.max_dynamic_candidates != 0 {
+            struct_ser.serialize_field("maxDynamicCandidates", &self.max_dynamic_candidates)?;
+        }
+        if self.spill_price_margin_bps != 0 {
+            struct_ser.serialize_field("spillPriceMarginBps", &self.spill_price_margin_bps)?;
+        }
+        if self.max_price_deviation_bps != 0 {
+            struct_ser.serialize_field("maxPriceDeviationBps", &self.max_price_deviation_bps)?;
+        }
+        if self.price_deviation_window != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("priceDeviationWindow", ToString::to_string(&self.price_deviation_window).as_str())?;
+        }
+        if self.max_reward_gauges_per_pair != 0 {
+            struct_ser.serialize_field("maxRewardGaugesPerPair", &self.max_reward_gauges_per_pair)?;
+        }
         struct_ser.end()
     }
 }
@@ -1750,6 +2055,18 @@ impl<'de> serde::Deserialize<'de> for DexParameters {
             "maxPositionsPerPair",
             "max_execution_budget",
             "maxExecutionBudget",
+            "price_oracle_windows",
+            "priceOracleWindows",
+            "max_dynamic_candidates",
+            "maxDynamicCandidates",
+            "spill_price_margin_bps",
+            "spillPriceMarginBps",
+            "max_price_deviation_bps",
+            "maxPriceDeviationBps",
+            "price_deviation_window",
+            "priceDeviationWindow",
+            "max_reward_gauges_per_pair",
+            "maxRewardGaugesPerPair",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1759,6 +2076,12 @@ impl<'de> serde::Deserialize<'de> for DexParameters {
             MaxHops,
             MaxPositionsPerPair,
             MaxExecutionBudget,
+            PriceOracleWindows,
+            MaxDynamicCandidates,
+            SpillPriceMarginBps,
+            MaxPriceDeviationBps,
+            PriceDeviationWindow,
+            MaxRewardGaugesPerPair,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1786,6 +2109,12 @@ impl<'de> serde::Deserialize<'de> for DexParameters {
                             "maxHops" | "max_hops" => Ok(GeneratedField::MaxHops),
                             "maxPositionsPerPair" | "max_positions_per_pair" => Ok(GeneratedField::MaxPositionsPerPair),
                             "maxExecutionBudget" | "max_execution_budget" => Ok(GeneratedField::MaxExecutionBudget),
+                            "priceOracleWindows" | "price_oracle_windows" => Ok(GeneratedField::PriceOracleWindows),
+                            "maxDynamicCandidates" | "max_dynamic_candidates" => Ok(GeneratedField::MaxDynamicCandidates),
+                            "spillPriceMarginBps" | "spill_price_margin_bps" => Ok(GeneratedField::SpillPriceMarginBps),
+                            "maxPriceDeviationBps" | "max_price_deviation_bps" => Ok(GeneratedField::MaxPriceDeviationBps),
+                            "priceDeviationWindow" | "price_deviation_window" => Ok(GeneratedField::PriceDeviationWindow),
+                            "maxRewardGaugesPerPair" | "max_reward_gauges_per_pair" => Ok(GeneratedField::MaxRewardGaugesPerPair),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1810,6 +2139,12 @@ impl<'de> serde::Deserialize<'de> for DexParameters {
                 let mut max_hops__ = None;
                 let mut max_positions_per_pair__ = None;
                 let mut max_execution_budget__ = None;
+                let mut price_oracle_windows__ = None;
+                let mut max_dynamic_candidates__ = None;
+                let mut spill_price_margin_bps__ = None;
+                let mut max_price_deviation_bps__ = None;
+                let mut price_deviation_window__ = None;
+                let mut max_reward_gauges_per_pair__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::IsEnabled => {
@@ -1848,6 +2183,55 @@ impl<'de> serde::Deserialize<'de> for DexParameters {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::PriceOracleWindows => {
+                            if price_oracle_windows__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("priceOracleWindows"));
+                            }
+                            price_oracle_windows__ = 
+                                Some(map_.next_value::<Vec<::pbjson::private::NumberDeserialize<_>>>()?
+                                    .into_iter().map(|x| x.0).collect())
+                            ;
+                        }
+                        GeneratedField::MaxDynamicCandidates => {
+                            if max_dynamic_candidates__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxDynamicCandidates"));
+                            }
+                            max_dynamic_candidates__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SpillPriceMarginBps => {
+                            if spill_price_margin_bps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spillPriceMarginBps"));
+                            }
+                            spill_price_margin_bps__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaxPriceDeviationBps => {
+                            if max_price_deviation_bps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxPriceDeviationBps"));
+                            }
+                            max_price_deviation_bps__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PriceDeviationWindow => {
+                            if price_deviation_window__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("priceDeviationWindow"));
+                            }
+                            price_deviation_window__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaxRewardGaugesPerPair => {
+                            if max_reward_gauges_per_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxRewardGaugesPerPair"));
+                            }
+                            max_reward_gauges_per_pair__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -1859,6 +2243,12 @@ impl<'de> serde::Deserialize<'de> for DexParameters {
                     max_hops: max_hops__.unwrap_or_default(),
                     max_positions_per_pair: max_positions_per_pair__.unwrap_or_default(),
                     max_execution_budget: max_execution_budget__.unwrap_or_default(),
+                    price_oracle_windows: price_oracle_windows__.unwrap_or_default(),
+                    max_dynamic_candidates: max_dynamic_candidates__.unwrap_or_default(),
+                    spill_price_margin_bps: spill_price_margin_bps__.unwrap_or_default(),
+                    max_price_deviation_bps: max_price_deviation_bps__.unwrap_or_default(),
+                    price_deviation_window: price_deviation_window__.unwrap_or_default(),
+                    max_reward_gauges_per_pair: max_reward_gauges_per_pair__.unwrap_or_default(),
                 })
             }
         }
@@ -2337,6 +2727,140 @@ impl<'de> serde::Deserialize<'de> for EventCandlestickData {
         deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventCandlestickData", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for EventGaugeFund {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.rewards.is_some() {
+            len += 1;
+        }
+        if self.end_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventGaugeFund", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if let Some(v) = self.rewards.as_ref() {
+            struct_ser.serialize_field("rewards", v)?;
+        }
+        if self.end_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventGaugeFund {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "trading_pair",
+            "tradingPair",
+            "rewards",
+            "end_height",
+            "endHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TradingPair,
+            Rewards,
+            EndHeight,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "rewards" => Ok(GeneratedField::Rewards),
+                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventGaugeFund;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventGaugeFund")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventGaugeFund, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut trading_pair__ = None;
+                let mut rewards__ = None;
+                let mut end_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Rewards => {
+                            if rewards__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewards"));
+                            }
+                            rewards__ = map_.next_value()?;
+                        }
+                        GeneratedField::EndHeight => {
+                            if end_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endHeight"));
+                            }
+                            end_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventGaugeFund {
+                    trading_pair: trading_pair__,
+                    rewards: rewards__,
+                    end_height: end_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventGaugeFund", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for EventPositionClose {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -3652,6 +4176,139 @@ impl<'de> serde::Deserialize<'de> for EventValueCircuitBreakerDebit {
         deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventValueCircuitBreakerDebit", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for GaugeFund {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.rewards.is_some() {
+            len += 1;
+        }
+        if self.duration != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.GaugeFund", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if let Some(v) = self.rewards.as_ref() {
+            struct_ser.serialize_field("rewards", v)?;
+        }
+        if self.duration != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("duration", ToString::to_string(&self.duration).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GaugeFund {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "trading_pair",
+            "tradingPair",
+            "rewards",
+            "duration",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TradingPair,
+            Rewards,
+            Duration,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "rewards" => Ok(GeneratedField::Rewards),
+                            "duration" => Ok(GeneratedField::Duration),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GaugeFund;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.GaugeFund")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GaugeFund, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut trading_pair__ = None;
+                let mut rewards__ = None;
+                let mut duration__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Rewards => {
+                            if rewards__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewards"));
+                            }
+                            rewards__ = map_.next_value()?;
+                        }
+                        GeneratedField::Duration => {
+                            if duration__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("duration"));
+                            }
+                            duration__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(GaugeFund {
+                    trading_pair: trading_pair__,
+                    rewards: rewards__,
+                    duration: duration__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.GaugeFund", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for GenesisContent {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -4685,6 +5342,9 @@ impl serde::Serialize for Position {
         if self.close_on_fill {
             len += 1;
         }
+        if self.expiry_height != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.Position", len)?;
         if let Some(v) = self.phi.as_ref() {
             struct_ser.serialize_field("phi", v)?;
@@ -4702,6 +5362,10 @@ impl serde::Serialize for Position {
         if self.close_on_fill {
             struct_ser.serialize_field("closeOnFill", &self.close_on_fill)?;
         }
+        if self.expiry_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("expiryHeight", ToString::to_string(&self.expiry_height).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -4718,6 +5382,8 @@ impl<'de> serde::Deserialize<'de> for Position {
             "reserves",
             "close_on_fill",
             "closeOnFill",
+            "expiry_height",
+            "expiryHeight",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -4727,6 +5393,7 @@ impl<'de> serde::Deserialize<'de> for Position {
             State,
             Reserves,
             CloseOnFill,
+            ExpiryHeight,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4754,6 +5421,7 @@ impl<'de> serde::Deserialize<'de> for Position {
                             "state" => Ok(GeneratedField::State),
                             "reserves" => Ok(GeneratedField::Reserves),
                             "closeOnFill" | "close_on_fill" => Ok(GeneratedField::CloseOnFill),
+                            "expiryHeight" | "expiry_height" => Ok(GeneratedField::ExpiryHeight),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4778,6 +5446,7 @@ impl<'de> serde::Deserialize<'de> for Position {
                 let mut state__ = None;
                 let mut reserves__ = None;
                 let mut close_on_fill__ = None;
+                let mut expiry_height__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Phi => {
@@ -4812,6 +5481,14 @@ impl<'de> serde::Deserialize<'de> for Position {
                             }
                             close_on_fill__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::ExpiryHeight => {
+                            if expiry_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expiryHeight"));
+                            }
+                            expiry_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -4823,6 +5500,7 @@ impl<'de> serde::Deserialize<'de> for Position {
                     state: state__,
                     reserves: reserves__,
                     close_on_fill: close_on_fill__.unwrap_or_default(),
+                    expiry_height: expiry_height__.unwrap_or_default(),
                 })
             }
         }
@@ -5345,7 +6023,7 @@ impl<'de> serde::Deserialize<'de> for PositionRewardClaimPlan {
         deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionRewardClaimPlan", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for PositionState {
+impl serde::Serialize for PositionRewardsRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5353,33 +6031,224 @@ impl serde::Serialize for PositionState {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.state != 0 {
-            len += 1;
-        }
-        if self.sequence != 0 {
+        if self.position_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionState", len)?;
-        if self.state != 0 {
-            let v = position_state::PositionStateEnum::try_from(self.state)
-                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.state)))?;
-            struct_ser.serialize_field("state", &v)?;
-        }
-        if self.sequence != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionRewardsRequest", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PositionState {
+impl<'de> serde::Deserialize<'de> for PositionRewardsRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "state",
+            "position_id",
+            "positionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionRewardsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionRewardsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionRewardsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PositionRewardsRequest {
+                    position_id: position_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionRewardsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionRewardsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.rewards.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionRewardsResponse", len)?;
+        if !self.rewards.is_empty() {
+            struct_ser.serialize_field("rewards", &self.rewards)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionRewardsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rewards",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Rewards,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "rewards" => Ok(GeneratedField::Rewards),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionRewardsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionRewardsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionRewardsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut rewards__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Rewards => {
+                            if rewards__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewards"));
+                            }
+                            rewards__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PositionRewardsResponse {
+                    rewards: rewards__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionRewardsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionState {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.state != 0 {
+            len += 1;
+        }
+        if self.sequence != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionState", len)?;
+        if self.state != 0 {
+            let v = position_state::PositionStateEnum::try_from(self.state)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.state)))?;
+            struct_ser.serialize_field("state", &v)?;
+        }
+        if self.sequence != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionState {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "state",
             "sequence",
         ];
 
@@ -5955,7 +6824,7 @@ impl<'de> serde::Deserialize<'de> for Reserves {
         deserializer.deserialize_struct("penumbra.core.component.dex.v1.Reserves", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SimulateTradeRequest {
+impl serde::Serialize for RewardGauge {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5963,45 +6832,48 @@ impl serde::Serialize for SimulateTradeRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.input.is_some() {
+        if self.trading_pair.is_some() {
             len += 1;
         }
-        if self.output.is_some() {
+        if self.remaining.is_some() {
             len += 1;
         }
-        if self.routing.is_some() {
+        if self.end_height != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest", len)?;
-        if let Some(v) = self.input.as_ref() {
-            struct_ser.serialize_field("input", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.RewardGauge", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
         }
-        if let Some(v) = self.output.as_ref() {
-            struct_ser.serialize_field("output", v)?;
+        if let Some(v) = self.remaining.as_ref() {
+            struct_ser.serialize_field("remaining", v)?;
         }
-        if let Some(v) = self.routing.as_ref() {
-            struct_ser.serialize_field("routing", v)?;
+        if self.end_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
+impl<'de> serde::Deserialize<'de> for RewardGauge {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "input",
-            "output",
-            "routing",
+            "trading_pair",
+            "tradingPair",
+            "remaining",
+            "end_height",
+            "endHeight",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Input,
-            Output,
-            Routing,
+            TradingPair,
+            Remaining,
+            EndHeight,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6024,9 +6896,9 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "input" => Ok(GeneratedField::Input),
-                            "output" => Ok(GeneratedField::Output),
-                            "routing" => Ok(GeneratedField::Routing),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "remaining" => Ok(GeneratedField::Remaining),
+                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6036,55 +6908,57 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SimulateTradeRequest;
+            type Value = RewardGauge;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SimulateTradeRequest")
+                formatter.write_str("struct penumbra.core.component.dex.v1.RewardGauge")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SimulateTradeRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RewardGauge, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut input__ = None;
-                let mut output__ = None;
-                let mut routing__ = None;
+                let mut trading_pair__ = None;
+                let mut remaining__ = None;
+                let mut end_height__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Input => {
-                            if input__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("input"));
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
                             }
-                            input__ = map_.next_value()?;
+                            trading_pair__ = map_.next_value()?;
                         }
-                        GeneratedField::Output => {
-                            if output__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("output"));
+                        GeneratedField::Remaining => {
+                            if remaining__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("remaining"));
                             }
-                            output__ = map_.next_value()?;
+                            remaining__ = map_.next_value()?;
                         }
-                        GeneratedField::Routing => {
-                            if routing__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("routing"));
+                        GeneratedField::EndHeight => {
+                            if end_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endHeight"));
                             }
-                            routing__ = map_.next_value()?;
+                            end_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SimulateTradeRequest {
-                    input: input__,
-                    output: output__,
-                    routing: routing__,
+                Ok(RewardGauge {
+                    trading_pair: trading_pair__,
+                    remaining: remaining__,
+                    end_height: end_height__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.RewardGauge", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for simulate_trade_request::Routing {
+impl serde::Serialize for RewardGaugesRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6092,39 +6966,30 @@ impl serde::Serialize for simulate_trade_request::Routing {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.setting.is_some() {
+        if self.trading_pair.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest.Routing", len)?;
-        if let Some(v) = self.setting.as_ref() {
-            match v {
-                simulate_trade_request::routing::Setting::Default(v) => {
-                    struct_ser.serialize_field("default", v)?;
-                }
-                simulate_trade_request::routing::Setting::SingleHop(v) => {
-                    struct_ser.serialize_field("singleHop", v)?;
-                }
-            }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.RewardGaugesRequest", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for simulate_trade_request::Routing {
+impl<'de> serde::Deserialize<'de> for RewardGaugesRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "default",
-            "single_hop",
-            "singleHop",
+            "trading_pair",
+            "tradingPair",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Default,
-            SingleHop,
+            TradingPair,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6147,8 +7012,7 @@ impl<'de> serde::Deserialize<'de> for simulate_trade_request::Routing {
                         E: serde::de::Error,
                     {
                         match value {
-                            "default" => Ok(GeneratedField::Default),
-                            "singleHop" | "single_hop" => Ok(GeneratedField::SingleHop),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6158,69 +7022,69 @@ impl<'de> serde::Deserialize<'de> for simulate_trade_request::Routing {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = simulate_trade_request::Routing;
+            type Value = RewardGaugesRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SimulateTradeRequest.Routing")
+                formatter.write_str("struct penumbra.core.component.dex.v1.RewardGaugesRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<simulate_trade_request::Routing, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RewardGaugesRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut setting__ = None;
+                let mut trading_pair__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Default => {
-                            if setting__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("default"));
-                            }
-                            setting__ = map_.next_value::<::std::option::Option<_>>()?.map(simulate_trade_request::routing::Setting::Default)
-;
-                        }
-                        GeneratedField::SingleHop => {
-                            if setting__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("singleHop"));
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
                             }
-                            setting__ = map_.next_value::<::std::option::Option<_>>()?.map(simulate_trade_request::routing::Setting::SingleHop)
-;
+                            trading_pair__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(simulate_trade_request::Routing {
-                    setting: setting__,
+                Ok(RewardGaugesRequest {
+                    trading_pair: trading_pair__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest.Routing", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.RewardGaugesRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for simulate_trade_request::routing::Default {
+impl serde::Serialize for RewardGaugesResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest.Routing.Default", len)?;
+        let mut len = 0;
+        if self.gauge.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.RewardGaugesResponse", len)?;
+        if let Some(v) = self.gauge.as_ref() {
+            struct_ser.serialize_field("gauge", v)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for simulate_trade_request::routing::Default {
+impl<'de> serde::Deserialize<'de> for RewardGaugesResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "gauge",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            Gauge,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6242,7 +7106,10 @@ impl<'de> serde::Deserialize<'de> for simulate_trade_request::routing::Default {
                     where
                         E: serde::de::Error,
                     {
-                            Ok(GeneratedField::__SkipField__)
+                        match value {
+                            "gauge" => Ok(GeneratedField::Gauge),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -6250,49 +7117,85 @@ impl<'de> serde::Deserialize<'de> for simulate_trade_request::routing::Default {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = simulate_trade_request::routing::Default;
+            type Value = RewardGaugesResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SimulateTradeRequest.Routing.Default")
+                formatter.write_str("struct penumbra.core.component.dex.v1.RewardGaugesResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<simulate_trade_request::routing::Default, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RewardGaugesResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map_.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                let mut gauge__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Gauge => {
+                            if gauge__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gauge"));
+                            }
+                            gauge__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
                 }
-                Ok(simulate_trade_request::routing::Default {
+                Ok(RewardGaugesResponse {
+                    gauge: gauge__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest.Routing.Default", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.RewardGaugesResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for simulate_trade_request::routing::SingleHop {
+impl serde::Serialize for SimulateTradeRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest.Routing.SingleHop", len)?;
+        let mut len = 0;
+        if self.input.is_some() {
+            len += 1;
+        }
+        if self.output.is_some() {
+            len += 1;
+        }
+        if self.routing.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest", len)?;
+        if let Some(v) = self.input.as_ref() {
+            struct_ser.serialize_field("input", v)?;
+        }
+        if let Some(v) = self.output.as_ref() {
+            struct_ser.serialize_field("output", v)?;
+        }
+        if let Some(v) = self.routing.as_ref() {
+            struct_ser.serialize_field("routing", v)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for simulate_trade_request::routing::SingleHop {
+impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "input",
+            "output",
+            "routing",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            Input,
+            Output,
+            Routing,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6314,7 +7217,12 @@ impl<'de> serde::Deserialize<'de> for simulate_trade_request::routing::SingleHop
                     where
                         E: serde::de::Error,
                     {
-                            Ok(GeneratedField::__SkipField__)
+                        match value {
+                            "input" => Ok(GeneratedField::Input),
+                            "output" => Ok(GeneratedField::Output),
+                            "routing" => Ok(GeneratedField::Routing),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -6322,27 +7230,55 @@ impl<'de> serde::Deserialize<'de> for simulate_trade_request::routing::SingleHop
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = simulate_trade_request::routing::SingleHop;
+            type Value = SimulateTradeRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SimulateTradeRequest.Routing.SingleHop")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SimulateTradeRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<simulate_trade_request::routing::SingleHop, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SimulateTradeRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map_.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                let mut input__ = None;
+                let mut output__ = None;
+                let mut routing__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Input => {
+                            if input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("input"));
+                            }
+                            input__ = map_.next_value()?;
+                        }
+                        GeneratedField::Output => {
+                            if output__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output"));
+                            }
+                            output__ = map_.next_value()?;
+                        }
+                        GeneratedField::Routing => {
+                            if routing__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("routing"));
+                            }
+                            routing__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
                 }
-                Ok(simulate_trade_request::routing::SingleHop {
+                Ok(SimulateTradeRequest {
+                    input: input__,
+                    output: output__,
+                    routing: routing__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest.Routing.SingleHop", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SimulateTradeResponse {
+impl serde::Serialize for simulate_trade_request::Routing {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6350,37 +7286,39 @@ impl serde::Serialize for SimulateTradeResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.output.is_some() {
-            len += 1;
-        }
-        if self.unfilled.is_some() {
+        if self.setting.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateTradeResponse", len)?;
-        if let Some(v) = self.output.as_ref() {
-            struct_ser.serialize_field("output", v)?;
-        }
-        if let Some(v) = self.unfilled.as_ref() {
-            struct_ser.serialize_field("unfilled", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest.Routing", len)?;
+        if let Some(v) = self.setting.as_ref() {
+            match v {
+                simulate_trade_request::routing::Setting::Default(v) => {
+                    struct_ser.serialize_field("default", v)?;
+                }
+                simulate_trade_request::routing::Setting::SingleHop(v) => {
+                    struct_ser.serialize_field("singleHop", v)?;
+                }
+            }
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SimulateTradeResponse {
+impl<'de> serde::Deserialize<'de> for simulate_trade_request::Routing {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "output",
-            "unfilled",
+            "default",
+            "single_hop",
+            "singleHop",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Output,
-            Unfilled,
+            Default,
+            SingleHop,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6403,8 +7341,8 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "output" => Ok(GeneratedField::Output),
-                            "unfilled" => Ok(GeneratedField::Unfilled),
+                            "default" => Ok(GeneratedField::Default),
+                            "singleHop" | "single_hop" => Ok(GeneratedField::SingleHop),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6414,78 +7352,69 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SimulateTradeResponse;
+            type Value = simulate_trade_request::Routing;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SimulateTradeResponse")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SimulateTradeRequest.Routing")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SimulateTradeResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<simulate_trade_request::Routing, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut output__ = None;
-                let mut unfilled__ = None;
+                let mut setting__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Output => {
-                            if output__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("output"));
+                        GeneratedField::Default => {
+                            if setting__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("default"));
                             }
-                            output__ = map_.next_value()?;
+                            setting__ = map_.next_value::<::std::option::Option<_>>()?.map(simulate_trade_request::routing::Setting::Default)
+;
                         }
-                        GeneratedField::Unfilled => {
-                            if unfilled__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("unfilled"));
+                        GeneratedField::SingleHop => {
+                            if setting__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("singleHop"));
                             }
-                            unfilled__ = map_.next_value()?;
+                            setting__ = map_.next_value::<::std::option::Option<_>>()?.map(simulate_trade_request::routing::Setting::SingleHop)
+;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SimulateTradeResponse {
-                    output: output__,
-                    unfilled: unfilled__,
+                Ok(simulate_trade_request::Routing {
+                    setting: setting__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SimulateTradeResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest.Routing", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SpreadRequest {
+impl serde::Serialize for simulate_trade_request::routing::Default {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if self.trading_pair.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SpreadRequest", len)?;
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
-        }
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest.Routing.Default", len)?;
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SpreadRequest {
+impl<'de> serde::Deserialize<'de> for simulate_trade_request::routing::Default {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "trading_pair",
-            "tradingPair",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            TradingPair,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6507,10 +7436,7 @@ impl<'de> serde::Deserialize<'de> for SpreadRequest {
                     where
                         E: serde::de::Error,
                     {
-                        match value {
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
-                            _ => Ok(GeneratedField::__SkipField__),
-                        }
+                            Ok(GeneratedField::__SkipField__)
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -6518,97 +7444,49 @@ impl<'de> serde::Deserialize<'de> for SpreadRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SpreadRequest;
+            type Value = simulate_trade_request::routing::Default;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SpreadRequest")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SimulateTradeRequest.Routing.Default")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SpreadRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<simulate_trade_request::routing::Default, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut trading_pair__ = None;
-                while let Some(k) = map_.next_key()? {
-                    match k {
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
-                            }
-                            trading_pair__ = map_.next_value()?;
-                        }
-                        GeneratedField::__SkipField__ => {
-                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
-                        }
-                    }
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                 }
-                Ok(SpreadRequest {
-                    trading_pair: trading_pair__,
+                Ok(simulate_trade_request::routing::Default {
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SpreadRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest.Routing.Default", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SpreadResponse {
+impl serde::Serialize for simulate_trade_request::routing::SingleHop {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if self.best_1_to_2_position.is_some() {
-            len += 1;
-        }
-        if self.best_2_to_1_position.is_some() {
-            len += 1;
-        }
-        if self.approx_effective_price_1_to_2 != 0. {
-            len += 1;
-        }
-        if self.approx_effective_price_2_to_1 != 0. {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SpreadResponse", len)?;
-        if let Some(v) = self.best_1_to_2_position.as_ref() {
-            struct_ser.serialize_field("best1To2Position", v)?;
-        }
-        if let Some(v) = self.best_2_to_1_position.as_ref() {
-            struct_ser.serialize_field("best2To1Position", v)?;
-        }
-        if self.approx_effective_price_1_to_2 != 0. {
-            struct_ser.serialize_field("approxEffectivePrice1To2", &self.approx_effective_price_1_to_2)?;
-        }
-        if self.approx_effective_price_2_to_1 != 0. {
-            struct_ser.serialize_field("approxEffectivePrice2To1", &self.approx_effective_price_2_to_1)?;
-        }
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest.Routing.SingleHop", len)?;
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SpreadResponse {
+impl<'de> serde::Deserialize<'de> for simulate_trade_request::routing::SingleHop {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "best_1_to_2_position",
-            "best1To2Position",
-            "best_2_to_1_position",
-            "best2To1Position",
-            "approx_effective_price_1_to_2",
-            "approxEffectivePrice1To2",
-            "approx_effective_price_2_to_1",
-            "approxEffectivePrice2To1",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Best1To2Position,
-            Best2To1Position,
-            ApproxEffectivePrice1To2,
-            ApproxEffectivePrice2To1,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6630,13 +7508,7 @@ impl<'de> serde::Deserialize<'de> for SpreadResponse {
                     where
                         E: serde::de::Error,
                     {
-                        match value {
-                            "best1To2Position" | "best_1_to_2_position" => Ok(GeneratedField::Best1To2Position),
-                            "best2To1Position" | "best_2_to_1_position" => Ok(GeneratedField::Best2To1Position),
-                            "approxEffectivePrice1To2" | "approx_effective_price_1_to_2" => Ok(GeneratedField::ApproxEffectivePrice1To2),
-                            "approxEffectivePrice2To1" | "approx_effective_price_2_to_1" => Ok(GeneratedField::ApproxEffectivePrice2To1),
-                            _ => Ok(GeneratedField::__SkipField__),
-                        }
+                            Ok(GeneratedField::__SkipField__)
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -6644,67 +7516,27 @@ impl<'de> serde::Deserialize<'de> for SpreadResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SpreadResponse;
+            type Value = simulate_trade_request::routing::SingleHop;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SpreadResponse")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SimulateTradeRequest.Routing.SingleHop")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SpreadResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<simulate_trade_request::routing::SingleHop, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut best_1_to_2_position__ = None;
-                let mut best_2_to_1_position__ = None;
-                let mut approx_effective_price_1_to_2__ = None;
-                let mut approx_effective_price_2_to_1__ = None;
-                while let Some(k) = map_.next_key()? {
-                    match k {
-                        GeneratedField::Best1To2Position => {
-                            if best_1_to_2_position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("best1To2Position"));
-                            }
-                            best_1_to_2_position__ = map_.next_value()?;
-                        }
-                        GeneratedField::Best2To1Position => {
-                            if best_2_to_1_position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("best2To1Position"));
-                            }
-                            best_2_to_1_position__ = map_.next_value()?;
-                        }
-                        GeneratedField::ApproxEffectivePrice1To2 => {
-                            if approx_effective_price_1_to_2__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("approxEffectivePrice1To2"));
-                            }
-                            approx_effective_price_1_to_2__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::ApproxEffectivePrice2To1 => {
-                            if approx_effective_price_2_to_1__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("approxEffectivePrice2To1"));
-                            }
-                            approx_effective_price_2_to_1__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::__SkipField__ => {
-                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
-                        }
-                    }
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                 }
-                Ok(SpreadResponse {
-                    best_1_to_2_position: best_1_to_2_position__,
-                    best_2_to_1_position: best_2_to_1_position__,
-                    approx_effective_price_1_to_2: approx_effective_price_1_to_2__.unwrap_or_default(),
-                    approx_effective_price_2_to_1: approx_effective_price_2_to_1__.unwrap_or_default(),
+                Ok(simulate_trade_request::routing::SingleHop {
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SpreadResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest.Routing.SingleHop", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Swap {
+impl serde::Serialize for SimulateTradeResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6712,37 +7544,46 @@ impl serde::Serialize for Swap {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.proof.is_some() {
+        if self.output.is_some() {
             len += 1;
         }
-        if self.body.is_some() {
+        if self.unfilled.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.Swap", len)?;
-        if let Some(v) = self.proof.as_ref() {
-            struct_ser.serialize_field("proof", v)?;
+        if self.price_impact != 0. {
+            len += 1;
         }
-        if let Some(v) = self.body.as_ref() {
-            struct_ser.serialize_field("body", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateTradeResponse", len)?;
+        if let Some(v) = self.output.as_ref() {
+            struct_ser.serialize_field("output", v)?;
+        }
+        if let Some(v) = self.unfilled.as_ref() {
+            struct_ser.serialize_field("unfilled", v)?;
+        }
+        if self.price_impact != 0. {
+            struct_ser.serialize_field("priceImpact", &self.price_impact)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Swap {
+impl<'de> serde::Deserialize<'de> for SimulateTradeResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "proof",
-            "body",
+            "output",
+            "unfilled",
+            "price_impact",
+            "priceImpact",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Proof,
-            Body,
+            Output,
+            Unfilled,
+            PriceImpact,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6765,8 +7606,9 @@ impl<'de> serde::Deserialize<'de> for Swap {
                         E: serde::de::Error,
                     {
                         match value {
-                            "proof" => Ok(GeneratedField::Proof),
-                            "body" => Ok(GeneratedField::Body),
+                            "output" => Ok(GeneratedField::Output),
+                            "unfilled" => Ok(GeneratedField::Unfilled),
+                            "priceImpact" | "price_impact" => Ok(GeneratedField::PriceImpact),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6776,47 +7618,57 @@ impl<'de> serde::Deserialize<'de> for Swap {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Swap;
+            type Value = SimulateTradeResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.Swap")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SimulateTradeResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Swap, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SimulateTradeResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut proof__ = None;
-                let mut body__ = None;
+                let mut output__ = None;
+                let mut unfilled__ = None;
+                let mut price_impact__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Proof => {
-                            if proof__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proof"));
+                        GeneratedField::Output => {
+                            if output__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output"));
                             }
-                            proof__ = map_.next_value()?;
+                            output__ = map_.next_value()?;
                         }
-                        GeneratedField::Body => {
-                            if body__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("body"));
+                        GeneratedField::Unfilled => {
+                            if unfilled__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unfilled"));
                             }
-                            body__ = map_.next_value()?;
+                            unfilled__ = map_.next_value()?;
+                        }
+                        GeneratedField::PriceImpact => {
+                            if price_impact__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("priceImpact"));
+                            }
+                            price_impact__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(Swap {
-                    proof: proof__,
-                    body: body__,
+                Ok(SimulateTradeResponse {
+                    output: output__,
+                    unfilled: unfilled__,
+                    price_impact: price_impact__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.Swap", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SimulateTradeResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapBody {
+impl serde::Serialize for SpreadRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6827,38 +7679,14 @@ impl serde::Serialize for SwapBody {
         if self.trading_pair.is_some() {
             len += 1;
         }
-        if self.delta_1_i.is_some() {
-            len += 1;
-        }
-        if self.delta_2_i.is_some() {
-            len += 1;
-        }
-        if self.fee_commitment.is_some() {
-            len += 1;
-        }
-        if self.payload.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapBody", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SpreadRequest", len)?;
         if let Some(v) = self.trading_pair.as_ref() {
             struct_ser.serialize_field("tradingPair", v)?;
         }
-        if let Some(v) = self.delta_1_i.as_ref() {
-            struct_ser.serialize_field("delta1I", v)?;
-        }
-        if let Some(v) = self.delta_2_i.as_ref() {
-            struct_ser.serialize_field("delta2I", v)?;
-        }
-        if let Some(v) = self.fee_commitment.as_ref() {
-            struct_ser.serialize_field("feeCommitment", v)?;
-        }
-        if let Some(v) = self.payload.as_ref() {
-            struct_ser.serialize_field("payload", v)?;
-        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapBody {
+impl<'de> serde::Deserialize<'de> for SpreadRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -6867,22 +7695,11 @@ impl<'de> serde::Deserialize<'de> for SwapBody {
         const FIELDS: &[&str] = &[
             "trading_pair",
             "tradingPair",
-            "delta_1_i",
-            "delta1I",
-            "delta_2_i",
-            "delta2I",
-            "fee_commitment",
-            "feeCommitment",
-            "payload",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             TradingPair,
-            Delta1I,
-            Delta2I,
-            FeeCommitment,
-            Payload,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6906,10 +7723,6 @@ impl<'de> serde::Deserialize<'de> for SwapBody {
                     {
                         match value {
                             "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
-                            "delta1I" | "delta_1_i" => Ok(GeneratedField::Delta1I),
-                            "delta2I" | "delta_2_i" => Ok(GeneratedField::Delta2I),
-                            "feeCommitment" | "fee_commitment" => Ok(GeneratedField::FeeCommitment),
-                            "payload" => Ok(GeneratedField::Payload),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6919,21 +7732,17 @@ impl<'de> serde::Deserialize<'de> for SwapBody {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapBody;
+            type Value = SpreadRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapBody")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SpreadRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapBody, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SpreadRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut trading_pair__ = None;
-                let mut delta_1_i__ = None;
-                let mut delta_2_i__ = None;
-                let mut fee_commitment__ = None;
-                let mut payload__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::TradingPair => {
@@ -6942,48 +7751,20 @@ impl<'de> serde::Deserialize<'de> for SwapBody {
                             }
                             trading_pair__ = map_.next_value()?;
                         }
-                        GeneratedField::Delta1I => {
-                            if delta_1_i__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("delta1I"));
-                            }
-                            delta_1_i__ = map_.next_value()?;
-                        }
-                        GeneratedField::Delta2I => {
-                            if delta_2_i__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("delta2I"));
-                            }
-                            delta_2_i__ = map_.next_value()?;
-                        }
-                        GeneratedField::FeeCommitment => {
-                            if fee_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("feeCommitment"));
-                            }
-                            fee_commitment__ = map_.next_value()?;
-                        }
-                        GeneratedField::Payload => {
-                            if payload__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("payload"));
-                            }
-                            payload__ = map_.next_value()?;
-                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SwapBody {
+                Ok(SpreadRequest {
                     trading_pair: trading_pair__,
-                    delta_1_i: delta_1_i__,
-                    delta_2_i: delta_2_i__,
-                    fee_commitment: fee_commitment__,
-                    payload: payload__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapBody", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SpreadRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapClaim {
+impl serde::Serialize for SpreadResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6991,47 +7772,57 @@ impl serde::Serialize for SwapClaim {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.proof.is_some() {
+        if self.best_1_to_2_position.is_some() {
             len += 1;
         }
-        if self.body.is_some() {
+        if self.best_2_to_1_position.is_some() {
             len += 1;
         }
-        if self.epoch_duration != 0 {
+        if self.approx_effective_price_1_to_2 != 0. {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapClaim", len)?;
-        if let Some(v) = self.proof.as_ref() {
-            struct_ser.serialize_field("proof", v)?;
+        if self.approx_effective_price_2_to_1 != 0. {
+            len += 1;
         }
-        if let Some(v) = self.body.as_ref() {
-            struct_ser.serialize_field("body", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SpreadResponse", len)?;
+        if let Some(v) = self.best_1_to_2_position.as_ref() {
+            struct_ser.serialize_field("best1To2Position", v)?;
         }
-        if self.epoch_duration != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("epochDuration", ToString::to_string(&self.epoch_duration).as_str())?;
+        if let Some(v) = self.best_2_to_1_position.as_ref() {
+            struct_ser.serialize_field("best2To1Position", v)?;
+        }
+        if self.approx_effective_price_1_to_2 != 0. {
+            struct_ser.serialize_field("approxEffectivePrice1To2", &self.approx_effective_price_1_to_2)?;
+        }
+        if self.approx_effective_price_2_to_1 != 0. {
+            struct_ser.serialize_field("approxEffectivePrice2To1", &self.approx_effective_price_2_to_1)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapClaim {
+impl<'de> serde::Deserialize<'de> for SpreadResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "proof",
-            "body",
-            "epoch_duration",
-            "epochDuration",
+            "best_1_to_2_position",
+            "best1To2Position",
+            "best_2_to_1_position",
+            "best2To1Position",
+            "approx_effective_price_1_to_2",
+            "approxEffectivePrice1To2",
+            "approx_effective_price_2_to_1",
+            "approxEffectivePrice2To1",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Proof,
-            Body,
-            EpochDuration,
+            Best1To2Position,
+            Best2To1Position,
+            ApproxEffectivePrice1To2,
+            ApproxEffectivePrice2To1,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -7054,9 +7845,10 @@ impl<'de> serde::Deserialize<'de> for SwapClaim {
                         E: serde::de::Error,
                     {
                         match value {
-                            "proof" => Ok(GeneratedField::Proof),
-                            "body" => Ok(GeneratedField::Body),
-                            "epochDuration" | "epoch_duration" => Ok(GeneratedField::EpochDuration),
+                            "best1To2Position" | "best_1_to_2_position" => Ok(GeneratedField::Best1To2Position),
+                            "best2To1Position" | "best_2_to_1_position" => Ok(GeneratedField::Best2To1Position),
+                            "approxEffectivePrice1To2" | "approx_effective_price_1_to_2" => Ok(GeneratedField::ApproxEffectivePrice1To2),
+                            "approxEffectivePrice2To1" | "approx_effective_price_2_to_1" => Ok(GeneratedField::ApproxEffectivePrice2To1),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -7066,38 +7858,47 @@ impl<'de> serde::Deserialize<'de> for SwapClaim {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapClaim;
+            type Value = SpreadResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapClaim")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SpreadResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapClaim, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SpreadResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut proof__ = None;
-                let mut body__ = None;
-                let mut epoch_duration__ = None;
+                let mut best_1_to_2_position__ = None;
+                let mut best_2_to_1_position__ = None;
+                let mut approx_effective_price_1_to_2__ = None;
+                let mut approx_effective_price_2_to_1__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Proof => {
-                            if proof__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proof"));
+                        GeneratedField::Best1To2Position => {
+                            if best_1_to_2_position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("best1To2Position"));
                             }
-                            proof__ = map_.next_value()?;
+                            best_1_to_2_position__ = map_.next_value()?;
                         }
-                        GeneratedField::Body => {
-                            if body__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("body"));
+                        GeneratedField::Best2To1Position => {
+                            if best_2_to_1_position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("best2To1Position"));
                             }
-                            body__ = map_.next_value()?;
+                            best_2_to_1_position__ = map_.next_value()?;
                         }
-                        GeneratedField::EpochDuration => {
-                            if epoch_duration__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("epochDuration"));
+                        GeneratedField::ApproxEffectivePrice1To2 => {
+                            if approx_effective_price_1_to_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("approxEffectivePrice1To2"));
                             }
-                            epoch_duration__ = 
+                            approx_effective_price_1_to_2__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ApproxEffectivePrice2To1 => {
+                            if approx_effective_price_2_to_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("approxEffectivePrice2To1"));
+                            }
+                            approx_effective_price_2_to_1__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
@@ -7106,17 +7907,18 @@ impl<'de> serde::Deserialize<'de> for SwapClaim {
                         }
                     }
                 }
-                Ok(SwapClaim {
-                    proof: proof__,
-                    body: body__,
-                    epoch_duration: epoch_duration__.unwrap_or_default(),
+                Ok(SpreadResponse {
+                    best_1_to_2_position: best_1_to_2_position__,
+                    best_2_to_1_position: best_2_to_1_position__,
+                    approx_effective_price_1_to_2: approx_effective_price_1_to_2__.unwrap_or_default(),
+                    approx_effective_price_2_to_1: approx_effective_price_2_to_1__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapClaim", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SpreadResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapClaimBody {
+impl serde::Serialize for Swap {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -7124,64 +7926,37 @@ impl serde::Serialize for SwapClaimBody {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.nullifier.is_some() {
-            len += 1;
-        }
-        if self.fee.is_some() {
-            len += 1;
-        }
-        if self.output_1_commitment.is_some() {
-            len += 1;
-        }
-        if self.output_2_commitment.is_some() {
+        if self.proof.is_some() {
             len += 1;
         }
-        if self.output_data.is_some() {
+        if self.body.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapClaimBody", len)?;
-        if let Some(v) = self.nullifier.as_ref() {
-            struct_ser.serialize_field("nullifier", v)?;
-        }
-        if let Some(v) = self.fee.as_ref() {
-            struct_ser.serialize_field("fee", v)?;
-        }
-        if let Some(v) = self.output_1_commitment.as_ref() {
-            struct_ser.serialize_field("output1Commitment", v)?;
-        }
-        if let Some(v) = self.output_2_commitment.as_ref() {
-            struct_ser.serialize_field("output2Commitment", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.Swap", len)?;
+        if let Some(v) = self.proof.as_ref() {
+            struct_ser.serialize_field("proof", v)?;
         }
-        if let Some(v) = self.output_data.as_ref() {
-            struct_ser.serialize_field("outputData", v)?;
+        if let Some(v) = self.body.as_ref() {
+            struct_ser.serialize_field("body", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapClaimBody {
+impl<'de> serde::Deserialize<'de> for Swap {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "nullifier",
-            "fee",
-            "output_1_commitment",
-            "output1Commitment",
-            "output_2_commitment",
-            "output2Commitment",
-            "output_data",
-            "outputData",
+            "proof",
+            "body",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Nullifier,
-            Fee,
-            Output1Commitment,
-            Output2Commitment,
-            OutputData,
+            Proof,
+            Body,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -7204,11 +7979,8 @@ impl<'de> serde::Deserialize<'de> for SwapClaimBody {
                         E: serde::de::Error,
                     {
                         match value {
-                            "nullifier" => Ok(GeneratedField::Nullifier),
-                            "fee" => Ok(GeneratedField::Fee),
-                            "output1Commitment" | "output_1_commitment" => Ok(GeneratedField::Output1Commitment),
-                            "output2Commitment" | "output_2_commitment" => Ok(GeneratedField::Output2Commitment),
-                            "outputData" | "output_data" => Ok(GeneratedField::OutputData),
+                            "proof" => Ok(GeneratedField::Proof),
+                            "body" => Ok(GeneratedField::Body),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -7218,71 +7990,47 @@ impl<'de> serde::Deserialize<'de> for SwapClaimBody {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapClaimBody;
+            type Value = Swap;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapClaimBody")
+                formatter.write_str("struct penumbra.core.component.dex.v1.Swap")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapClaimBody, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Swap, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut nullifier__ = None;
-                let mut fee__ = None;
-                let mut output_1_commitment__ = None;
-                let mut output_2_commitment__ = None;
-                let mut output_data__ = None;
+                let mut proof__ = None;
+                let mut body__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Nullifier => {
-                            if nullifier__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("nullifier"));
-                            }
-                            nullifier__ = map_.next_value()?;
-                        }
-                        GeneratedField::Fee => {
-                            if fee__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("fee"));
+                        GeneratedField::Proof => {
+                            if proof__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proof"));
                             }
-                            fee__ = map_.next_value()?;
+                            proof__ = map_.next_value()?;
                         }
-                        GeneratedField::Output1Commitment => {
-                            if output_1_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("output1Commitment"));
+                        GeneratedField::Body => {
+                            if body__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("body"));
                             }
-                            output_1_commitment__ = map_.next_value()?;
+                            body__ = map_.next_value()?;
                         }
-                        GeneratedField::Output2Commitment => {
-                            if output_2_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("output2Commitment"));
-                            }
-                            output_2_commitment__ = map_.next_value()?;
-                        }
-                        GeneratedField::OutputData => {
-                            if output_data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("outputData"));
-                            }
-                            output_data__ = map_.next_value()?;
-                        }
-                        GeneratedField::__SkipField__ => {
-                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SwapClaimBody {
-                    nullifier: nullifier__,
-                    fee: fee__,
-                    output_1_commitment: output_1_commitment__,
-                    output_2_commitment: output_2_commitment__,
-                    output_data: output_data__,
+                Ok(Swap {
+                    proof: proof__,
+                    body: body__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapClaimBody", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.Swap", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapClaimPlan {
+impl serde::Serialize for SwapBody {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -7290,78 +8038,65 @@ impl serde::Serialize for SwapClaimPlan {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap_plaintext.is_some() {
-            len += 1;
-        }
-        if self.position != 0 {
+        if self.trading_pair.is_some() {
             len += 1;
         }
-        if self.output_data.is_some() {
+        if self.delta_1_i.is_some() {
             len += 1;
         }
-        if self.epoch_duration != 0 {
+        if self.delta_2_i.is_some() {
             len += 1;
         }
-        if !self.proof_blinding_r.is_empty() {
+        if self.fee_commitment.is_some() {
             len += 1;
         }
-        if !self.proof_blinding_s.is_empty() {
+        if self.payload.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapClaimPlan", len)?;
-        if let Some(v) = self.swap_plaintext.as_ref() {
-            struct_ser.serialize_field("swapPlaintext", v)?;
-        }
-        if self.position != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("position", ToString::to_string(&self.position).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapBody", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
         }
-        if let Some(v) = self.output_data.as_ref() {
-            struct_ser.serialize_field("outputData", v)?;
+        if let Some(v) = self.delta_1_i.as_ref() {
+            struct_ser.serialize_field("delta1I", v)?;
         }
-        if self.epoch_duration != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("epochDuration", ToString::to_string(&self.epoch_duration).as_str())?;
+        if let Some(v) = self.delta_2_i.as_ref() {
+            struct_ser.serialize_field("delta2I", v)?;
         }
-        if !self.proof_blinding_r.is_empty() {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("proofBlindingR", pbjson::private::base64::encode(&self.proof_blinding_r).as_str())?;
+        if let Some(v) = self.fee_commitment.as_ref() {
+            struct_ser.serialize_field("feeCommitment", v)?;
         }
-        if !self.proof_blinding_s.is_empty() {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("proofBlindingS", pbjson::private::base64::encode(&self.proof_blinding_s).as_str())?;
+        if let Some(v) = self.payload.as_ref() {
+            struct_ser.serialize_field("payload", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapClaimPlan {
+impl<'de> serde::Deserialize<'de> for SwapBody {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "swap_plaintext",
-            "swapPlaintext",
-            "position",
-            "output_data",
-            "outputData",
-            "epoch_duration",
-            "epochDuration",
-            "proof_blinding_r",
-            "proofBlindingR",
-            "proof_blinding_s",
-            "proofBlindingS",
+            "trading_pair",
+            "tradingPair",
+            "delta_1_i",
+            "delta1I",
+            "delta_2_i",
+            "delta2I",
+            "fee_commitment",
+            "feeCommitment",
+            "payload",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            SwapPlaintext,
-            Position,
-            OutputData,
-            EpochDuration,
-            ProofBlindingR,
-            ProofBlindingS,
+            TradingPair,
+            Delta1I,
+            Delta2I,
+            FeeCommitment,
+            Payload,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -7384,12 +8119,11 @@ impl<'de> serde::Deserialize<'de> for SwapClaimPlan {
                         E: serde::de::Error,
                     {
                         match value {
-                            "swapPlaintext" | "swap_plaintext" => Ok(GeneratedField::SwapPlaintext),
-                            "position" => Ok(GeneratedField::Position),
-                            "outputData" | "output_data" => Ok(GeneratedField::OutputData),
-                            "epochDuration" | "epoch_duration" => Ok(GeneratedField::EpochDuration),
-                            "proofBlindingR" | "proof_blinding_r" => Ok(GeneratedField::ProofBlindingR),
-                            "proofBlindingS" | "proof_blinding_s" => Ok(GeneratedField::ProofBlindingS),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "delta1I" | "delta_1_i" => Ok(GeneratedField::Delta1I),
+                            "delta2I" | "delta_2_i" => Ok(GeneratedField::Delta2I),
+                            "feeCommitment" | "fee_commitment" => Ok(GeneratedField::FeeCommitment),
+                            "payload" => Ok(GeneratedField::Payload),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -7399,87 +8133,71 @@ impl<'de> serde::Deserialize<'de> for SwapClaimPlan {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapClaimPlan;
+            type Value = SwapBody;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapClaimPlan")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapBody")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapClaimPlan, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapBody, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap_plaintext__ = None;
-                let mut position__ = None;
-                let mut output_data__ = None;
-                let mut epoch_duration__ = None;
-                let mut proof_blinding_r__ = None;
-                let mut proof_blinding_s__ = None;
+                let mut trading_pair__ = None;
+                let mut delta_1_i__ = None;
+                let mut delta_2_i__ = None;
+                let mut fee_commitment__ = None;
+                let mut payload__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::SwapPlaintext => {
-                            if swap_plaintext__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapPlaintext"));
-                            }
-                            swap_plaintext__ = map_.next_value()?;
-                        }
-                        GeneratedField::Position => {
-                            if position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("position"));
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
                             }
-                            position__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            trading_pair__ = map_.next_value()?;
                         }
-                        GeneratedField::OutputData => {
-                            if output_data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("outputData"));
+                        GeneratedField::Delta1I => {
+                            if delta_1_i__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delta1I"));
                             }
-                            output_data__ = map_.next_value()?;
+                            delta_1_i__ = map_.next_value()?;
                         }
-                        GeneratedField::EpochDuration => {
-                            if epoch_duration__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("epochDuration"));
+                        GeneratedField::Delta2I => {
+                            if delta_2_i__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delta2I"));
                             }
-                            epoch_duration__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            delta_2_i__ = map_.next_value()?;
                         }
-                        GeneratedField::ProofBlindingR => {
-                            if proof_blinding_r__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proofBlindingR"));
+                        GeneratedField::FeeCommitment => {
+                            if fee_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeCommitment"));
                             }
-                            proof_blinding_r__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            fee_commitment__ = map_.next_value()?;
                         }
-                        GeneratedField::ProofBlindingS => {
-                            if proof_blinding_s__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proofBlindingS"));
+                        GeneratedField::Payload => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("payload"));
                             }
-                            proof_blinding_s__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            payload__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SwapClaimPlan {
-                    swap_plaintext: swap_plaintext__,
-                    position: position__.unwrap_or_default(),
-                    output_data: output_data__,
-                    epoch_duration: epoch_duration__.unwrap_or_default(),
-                    proof_blinding_r: proof_blinding_r__.unwrap_or_default(),
-                    proof_blinding_s: proof_blinding_s__.unwrap_or_default(),
+                Ok(SwapBody {
+                    trading_pair: trading_pair__,
+                    delta_1_i: delta_1_i__,
+                    delta_2_i: delta_2_i__,
+                    fee_commitment: fee_commitment__,
+                    payload: payload__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapClaimPlan", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapBody", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapClaimView {
+impl serde::Serialize for SwapClaim {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -7487,38 +8205,47 @@ impl serde::Serialize for SwapClaimView {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap_claim_view.is_some() {
+        if self.proof.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapClaimView", len)?;
-        if let Some(v) = self.swap_claim_view.as_ref() {
-            match v {
-                swap_claim_view::SwapClaimView::Visible(v) => {
-                    struct_ser.serialize_field("visible", v)?;
-                }
-                swap_claim_view::SwapClaimView::Opaque(v) => {
-                    struct_ser.serialize_field("opaque", v)?;
-                }
-            }
+        if self.body.is_some() {
+            len += 1;
+        }
+        if self.epoch_duration != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapClaim", len)?;
+        if let Some(v) = self.proof.as_ref() {
+            struct_ser.serialize_field("proof", v)?;
+        }
+        if let Some(v) = self.body.as_ref() {
+            struct_ser.serialize_field("body", v)?;
+        }
+        if self.epoch_duration != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epochDuration", ToString::to_string(&self.epoch_duration).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapClaimView {
+impl<'de> serde::Deserialize<'de> for SwapClaim {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "visible",
-            "opaque",
+            "proof",
+            "body",
+            "epoch_duration",
+            "epochDuration",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Visible,
-            Opaque,
+            Proof,
+            Body,
+            EpochDuration,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -7541,8 +8268,9 @@ impl<'de> serde::Deserialize<'de> for SwapClaimView {
                         E: serde::de::Error,
                     {
                         match value {
-                            "visible" => Ok(GeneratedField::Visible),
-                            "opaque" => Ok(GeneratedField::Opaque),
+                            "proof" => Ok(GeneratedField::Proof),
+                            "body" => Ok(GeneratedField::Body),
+                            "epochDuration" | "epoch_duration" => Ok(GeneratedField::EpochDuration),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -7552,47 +8280,57 @@ impl<'de> serde::Deserialize<'de> for SwapClaimView {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapClaimView;
+            type Value = SwapClaim;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapClaimView")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapClaim")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapClaimView, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapClaim, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap_claim_view__ = None;
+                let mut proof__ = None;
+                let mut body__ = None;
+                let mut epoch_duration__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Visible => {
-                            if swap_claim_view__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("visible"));
+                        GeneratedField::Proof => {
+                            if proof__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proof"));
                             }
-                            swap_claim_view__ = map_.next_value::<::std::option::Option<_>>()?.map(swap_claim_view::SwapClaimView::Visible)
-;
+                            proof__ = map_.next_value()?;
                         }
-                        GeneratedField::Opaque => {
-                            if swap_claim_view__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("opaque"));
+                        GeneratedField::Body => {
+                            if body__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("body"));
                             }
-                            swap_claim_view__ = map_.next_value::<::std::option::Option<_>>()?.map(swap_claim_view::SwapClaimView::Opaque)
-;
+                            body__ = map_.next_value()?;
+                        }
+                        GeneratedField::EpochDuration => {
+                            if epoch_duration__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochDuration"));
+                            }
+                            epoch_duration__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SwapClaimView {
-                    swap_claim_view: swap_claim_view__,
+                Ok(SwapClaim {
+                    proof: proof__,
+                    body: body__,
+                    epoch_duration: epoch_duration__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapClaimView", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapClaim", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for swap_claim_view::Opaque {
+impl serde::Serialize for SwapClaimBody {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -7600,30 +8338,64 @@ impl serde::Serialize for swap_claim_view::Opaque {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap_claim.is_some() {
+        if self.nullifier.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapClaimView.Opaque", len)?;
-        if let Some(v) = self.swap_claim.as_ref() {
-            struct_ser.serialize_field("swapClaim", v)?;
+        if self.fee.is_some() {
+            len += 1;
+        }
+        if self.output_1_commitment.is_some() {
+            len += 1;
+        }
+        if self.output_2_commitment.is_some() {
+            len += 1;
+        }
+        if self.output_data.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapClaimBody", len)?;
+        if let Some(v) = self.nullifier.as_ref() {
+            struct_ser.serialize_field("nullifier", v)?;
+        }
+        if let Some(v) = self.fee.as_ref() {
+            struct_ser.serialize_field("fee", v)?;
+        }
+        if let Some(v) = self.output_1_commitment.as_ref() {
+            struct_ser.serialize_field("output1Commitment", v)?;
+        }
+        if let Some(v) = self.output_2_commitment.as_ref() {
+            struct_ser.serialize_field("output2Commitment", v)?;
+        }
+        if let Some(v) = self.output_data.as_ref() {
+            struct_ser.serialize_field("outputData", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for swap_claim_view::Opaque {
+impl<'de> serde::Deserialize<'de> for SwapClaimBody {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "swap_claim",
-            "swapClaim",
+            "nullifier",
+            "fee",
+            "output_1_commitment",
+            "output1Commitment",
+            "output_2_commitment",
+            "output2Commitment",
+            "output_data",
+            "outputData",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            SwapClaim,
+            Nullifier,
+            Fee,
+            Output1Commitment,
+            Output2Commitment,
+            OutputData,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -7646,7 +8418,11 @@ impl<'de> serde::Deserialize<'de> for swap_claim_view::Opaque {
                         E: serde::de::Error,
                     {
                         match value {
-                            "swapClaim" | "swap_claim" => Ok(GeneratedField::SwapClaim),
+                            "nullifier" => Ok(GeneratedField::Nullifier),
+                            "fee" => Ok(GeneratedField::Fee),
+                            "output1Commitment" | "output_1_commitment" => Ok(GeneratedField::Output1Commitment),
+                            "output2Commitment" | "output_2_commitment" => Ok(GeneratedField::Output2Commitment),
+                            "outputData" | "output_data" => Ok(GeneratedField::OutputData),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -7656,39 +8432,71 @@ impl<'de> serde::Deserialize<'de> for swap_claim_view::Opaque {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = swap_claim_view::Opaque;
+            type Value = SwapClaimBody;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapClaimView.Opaque")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapClaimBody")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<swap_claim_view::Opaque, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapClaimBody, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap_claim__ = None;
+                let mut nullifier__ = None;
+                let mut fee__ = None;
+                let mut output_1_commitment__ = None;
+                let mut output_2_commitment__ = None;
+                let mut output_data__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::SwapClaim => {
-                            if swap_claim__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapClaim"));
+                        GeneratedField::Nullifier => {
+                            if nullifier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nullifier"));
                             }
-                            swap_claim__ = map_.next_value()?;
+                            nullifier__ = map_.next_value()?;
+                        }
+                        GeneratedField::Fee => {
+                            if fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fee"));
+                            }
+                            fee__ = map_.next_value()?;
+                        }
+                        GeneratedField::Output1Commitment => {
+                            if output_1_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output1Commitment"));
+                            }
+                            output_1_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::Output2Commitment => {
+                            if output_2_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output2Commitment"));
+                            }
+                            output_2_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::OutputData => {
+                            if output_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outputData"));
+                            }
+                            output_data__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(swap_claim_view::Opaque {
-                    swap_claim: swap_claim__,
+                Ok(SwapClaimBody {
+                    nullifier: nullifier__,
+                    fee: fee__,
+                    output_1_commitment: output_1_commitment__,
+                    output_2_commitment: output_2_commitment__,
+                    output_data: output_data__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapClaimView.Opaque", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapClaimBody", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for swap_claim_view::Visible {
+impl serde::Serialize for SwapClaimPlan {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -7696,57 +8504,78 @@ impl serde::Serialize for swap_claim_view::Visible {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap_claim.is_some() {
+        if self.swap_plaintext.is_some() {
             len += 1;
         }
-        if self.output_1.is_some() {
+        if self.position != 0 {
             len += 1;
         }
-        if self.output_2.is_some() {
+        if self.output_data.is_some() {
             len += 1;
         }
-        if self.swap_tx.is_some() {
+        if self.epoch_duration != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapClaimView.Visible", len)?;
-        if let Some(v) = self.swap_claim.as_ref() {
-            struct_ser.serialize_field("swapClaim", v)?;
+        if !self.proof_blinding_r.is_empty() {
+            len += 1;
         }
-        if let Some(v) = self.output_1.as_ref() {
-            struct_ser.serialize_field("output1", v)?;
+        if !self.proof_blinding_s.is_empty() {
+            len += 1;
         }
-        if let Some(v) = self.output_2.as_ref() {
-            struct_ser.serialize_field("output2", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapClaimPlan", len)?;
+        if let Some(v) = self.swap_plaintext.as_ref() {
+            struct_ser.serialize_field("swapPlaintext", v)?;
         }
-        if let Some(v) = self.swap_tx.as_ref() {
-            struct_ser.serialize_field("swapTx", v)?;
+        if self.position != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("position", ToString::to_string(&self.position).as_str())?;
+        }
+        if let Some(v) = self.output_data.as_ref() {
+            struct_ser.serialize_field("outputData", v)?;
+        }
+        if self.epoch_duration != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epochDuration", ToString::to_string(&self.epoch_duration).as_str())?;
+        }
+        if !self.proof_blinding_r.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proofBlindingR", pbjson::private::base64::encode(&self.proof_blinding_r).as_str())?;
+        }
+        if !self.proof_blinding_s.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proofBlindingS", pbjson::private::base64::encode(&self.proof_blinding_s).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for swap_claim_view::Visible {
+impl<'de> serde::Deserialize<'de> for SwapClaimPlan {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "swap_claim",
-            "swapClaim",
-            "output_1",
-            "output1",
-            "output_2",
-            "output2",
-            "swap_tx",
-            "swapTx",
+            "swap_plaintext",
+            "swapPlaintext",
+            "position",
+            "output_data",
+            "outputData",
+            "epoch_duration",
+            "epochDuration",
+            "proof_blinding_r",
+            "proofBlindingR",
+            "proof_blinding_s",
+            "proofBlindingS",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            SwapClaim,
-            Output1,
-            Output2,
-            SwapTx,
+            SwapPlaintext,
+            Position,
+            OutputData,
+            EpochDuration,
+            ProofBlindingR,
+            ProofBlindingS,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -7769,10 +8598,12 @@ impl<'de> serde::Deserialize<'de> for swap_claim_view::Visible {
                         E: serde::de::Error,
                     {
                         match value {
-                            "swapClaim" | "swap_claim" => Ok(GeneratedField::SwapClaim),
-                            "output1" | "output_1" => Ok(GeneratedField::Output1),
-                            "output2" | "output_2" => Ok(GeneratedField::Output2),
-                            "swapTx" | "swap_tx" => Ok(GeneratedField::SwapTx),
+                            "swapPlaintext" | "swap_plaintext" => Ok(GeneratedField::SwapPlaintext),
+                            "position" => Ok(GeneratedField::Position),
+                            "outputData" | "output_data" => Ok(GeneratedField::OutputData),
+                            "epochDuration" | "epoch_duration" => Ok(GeneratedField::EpochDuration),
+                            "proofBlindingR" | "proof_blinding_r" => Ok(GeneratedField::ProofBlindingR),
+                            "proofBlindingS" | "proof_blinding_s" => Ok(GeneratedField::ProofBlindingS),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -7782,63 +8613,87 @@ impl<'de> serde::Deserialize<'de> for swap_claim_view::Visible {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = swap_claim_view::Visible;
+            type Value = SwapClaimPlan;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapClaimView.Visible")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapClaimPlan")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<swap_claim_view::Visible, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapClaimPlan, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap_claim__ = None;
-                let mut output_1__ = None;
-                let mut output_2__ = None;
-                let mut swap_tx__ = None;
+                let mut swap_plaintext__ = None;
+                let mut position__ = None;
+                let mut output_data__ = None;
+                let mut epoch_duration__ = None;
+                let mut proof_blinding_r__ = None;
+                let mut proof_blinding_s__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::SwapClaim => {
-                            if swap_claim__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapClaim"));
+                        GeneratedField::SwapPlaintext => {
+                            if swap_plaintext__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapPlaintext"));
                             }
-                            swap_claim__ = map_.next_value()?;
+                            swap_plaintext__ = map_.next_value()?;
                         }
-                        GeneratedField::Output1 => {
-                            if output_1__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("output1"));
+                        GeneratedField::Position => {
+                            if position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("position"));
                             }
-                            output_1__ = map_.next_value()?;
-                        }
-                        GeneratedField::Output2 => {
-                            if output_2__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("output2"));
+                            position__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::OutputData => {
+                            if output_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outputData"));
                             }
-                            output_2__ = map_.next_value()?;
+                            output_data__ = map_.next_value()?;
                         }
-                        GeneratedField::SwapTx => {
-                            if swap_tx__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapTx"));
+                        GeneratedField::EpochDuration => {
+                            if epoch_duration__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochDuration"));
                             }
-                            swap_tx__ = map_.next_value()?;
+                            epoch_duration__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ProofBlindingR => {
+                            if proof_blinding_r__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proofBlindingR"));
+                            }
+                            proof_blinding_r__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ProofBlindingS => {
+                            if proof_blinding_s__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proofBlindingS"));
+                            }
+                            proof_blinding_s__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(swap_claim_view::Visible {
-                    swap_claim: swap_claim__,
-                    output_1: output_1__,
-                    output_2: output_2__,
-                    swap_tx: swap_tx__,
+                Ok(SwapClaimPlan {
+                    swap_plaintext: swap_plaintext__,
+                    position: position__.unwrap_or_default(),
+                    output_data: output_data__,
+                    epoch_duration: epoch_duration__.unwrap_or_default(),
+                    proof_blinding_r: proof_blinding_r__.unwrap_or_default(),
+                    proof_blinding_s: proof_blinding_s__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapClaimView.Visible", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapClaimPlan", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapExecution {
+impl serde::Serialize for SwapClaimView {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -7846,45 +8701,38 @@ impl serde::Serialize for SwapExecution {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.traces.is_empty() {
-            len += 1;
-        }
-        if self.input.is_some() {
-            len += 1;
-        }
-        if self.output.is_some() {
+        if self.swap_claim_view.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapExecution", len)?;
-        if !self.traces.is_empty() {
-            struct_ser.serialize_field("traces", &self.traces)?;
-        }
-        if let Some(v) = self.input.as_ref() {
-            struct_ser.serialize_field("input", v)?;
-        }
-        if let Some(v) = self.output.as_ref() {
-            struct_ser.serialize_field("output", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapClaimView", len)?;
+        if let Some(v) = self.swap_claim_view.as_ref() {
+            match v {
+                swap_claim_view::SwapClaimView::Visible(v) => {
+                    struct_ser.serialize_field("visible", v)?;
+                }
+                swap_claim_view::SwapClaimView::Opaque(v) => {
+                    struct_ser.serialize_field("opaque", v)?;
+                }
+            }
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapExecution {
+impl<'de> serde::Deserialize<'de> for SwapClaimView {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "traces",
-            "input",
-            "output",
+            "visible",
+            "opaque",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Traces,
-            Input,
-            Output,
+            Visible,
+            Opaque,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -7907,9 +8755,8 @@ impl<'de> serde::Deserialize<'de> for SwapExecution {
                         E: serde::de::Error,
                     {
                         match value {
-                            "traces" => Ok(GeneratedField::Traces),
-                            "input" => Ok(GeneratedField::Input),
-                            "output" => Ok(GeneratedField::Output),
+                            "visible" => Ok(GeneratedField::Visible),
+                            "opaque" => Ok(GeneratedField::Opaque),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -7919,55 +8766,47 @@ impl<'de> serde::Deserialize<'de> for SwapExecution {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapExecution;
+            type Value = SwapClaimView;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapExecution")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapClaimView")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapExecution, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapClaimView, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut traces__ = None;
-                let mut input__ = None;
-                let mut output__ = None;
+                let mut swap_claim_view__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Traces => {
-                            if traces__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("traces"));
-                            }
-                            traces__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::Input => {
-                            if input__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("input"));
+                        GeneratedField::Visible => {
+                            if swap_claim_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("visible"));
                             }
-                            input__ = map_.next_value()?;
+                            swap_claim_view__ = map_.next_value::<::std::option::Option<_>>()?.map(swap_claim_view::SwapClaimView::Visible)
+;
                         }
-                        GeneratedField::Output => {
-                            if output__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("output"));
+                        GeneratedField::Opaque => {
+                            if swap_claim_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("opaque"));
                             }
-                            output__ = map_.next_value()?;
+                            swap_claim_view__ = map_.next_value::<::std::option::Option<_>>()?.map(swap_claim_view::SwapClaimView::Opaque)
+;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SwapExecution {
-                    traces: traces__.unwrap_or_default(),
-                    input: input__,
-                    output: output__,
+                Ok(SwapClaimView {
+                    swap_claim_view: swap_claim_view__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapExecution", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapClaimView", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for swap_execution::Trace {
+impl serde::Serialize for swap_claim_view::Opaque {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -7975,29 +8814,30 @@ impl serde::Serialize for swap_execution::Trace {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.value.is_empty() {
+        if self.swap_claim.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapExecution.Trace", len)?;
-        if !self.value.is_empty() {
-            struct_ser.serialize_field("value", &self.value)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapClaimView.Opaque", len)?;
+        if let Some(v) = self.swap_claim.as_ref() {
+            struct_ser.serialize_field("swapClaim", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for swap_execution::Trace {
+impl<'de> serde::Deserialize<'de> for swap_claim_view::Opaque {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "value",
+            "swap_claim",
+            "swapClaim",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Value,
+            SwapClaim,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -8020,7 +8860,7 @@ impl<'de> serde::Deserialize<'de> for swap_execution::Trace {
                         E: serde::de::Error,
                     {
                         match value {
-                            "value" => Ok(GeneratedField::Value),
+                            "swapClaim" | "swap_claim" => Ok(GeneratedField::SwapClaim),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -8030,39 +8870,39 @@ impl<'de> serde::Deserialize<'de> for swap_execution::Trace {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = swap_execution::Trace;
+            type Value = swap_claim_view::Opaque;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapExecution.Trace")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapClaimView.Opaque")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<swap_execution::Trace, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<swap_claim_view::Opaque, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut value__ = None;
+                let mut swap_claim__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Value => {
-                            if value__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("value"));
+                        GeneratedField::SwapClaim => {
+                            if swap_claim__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapClaim"));
                             }
-                            value__ = Some(map_.next_value()?);
+                            swap_claim__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(swap_execution::Trace {
-                    value: value__.unwrap_or_default(),
+                Ok(swap_claim_view::Opaque {
+                    swap_claim: swap_claim__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapExecution.Trace", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapClaimView.Opaque", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapExecutionRequest {
+impl serde::Serialize for swap_claim_view::Visible {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -8070,39 +8910,57 @@ impl serde::Serialize for SwapExecutionRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.height != 0 {
+        if self.swap_claim.is_some() {
             len += 1;
         }
-        if self.trading_pair.is_some() {
+        if self.output_1.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapExecutionRequest", len)?;
-        if self.height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        if self.output_2.is_some() {
+            len += 1;
         }
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
+        if self.swap_tx.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapClaimView.Visible", len)?;
+        if let Some(v) = self.swap_claim.as_ref() {
+            struct_ser.serialize_field("swapClaim", v)?;
+        }
+        if let Some(v) = self.output_1.as_ref() {
+            struct_ser.serialize_field("output1", v)?;
+        }
+        if let Some(v) = self.output_2.as_ref() {
+            struct_ser.serialize_field("output2", v)?;
+        }
+        if let Some(v) = self.swap_tx.as_ref() {
+            struct_ser.serialize_field("swapTx", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapExecutionRequest {
+impl<'de> serde::Deserialize<'de> for swap_claim_view::Visible {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "height",
-            "trading_pair",
-            "tradingPair",
+            "swap_claim",
+            "swapClaim",
+            "output_1",
+            "output1",
+            "output_2",
+            "output2",
+            "swap_tx",
+            "swapTx",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Height,
-            TradingPair,
+            SwapClaim,
+            Output1,
+            Output2,
+            SwapTx,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -8125,8 +8983,10 @@ impl<'de> serde::Deserialize<'de> for SwapExecutionRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "height" => Ok(GeneratedField::Height),
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "swapClaim" | "swap_claim" => Ok(GeneratedField::SwapClaim),
+                            "output1" | "output_1" => Ok(GeneratedField::Output1),
+                            "output2" | "output_2" => Ok(GeneratedField::Output2),
+                            "swapTx" | "swap_tx" => Ok(GeneratedField::SwapTx),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -8136,49 +8996,63 @@ impl<'de> serde::Deserialize<'de> for SwapExecutionRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapExecutionRequest;
+            type Value = swap_claim_view::Visible;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapExecutionRequest")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapClaimView.Visible")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapExecutionRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<swap_claim_view::Visible, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut height__ = None;
-                let mut trading_pair__ = None;
+                let mut swap_claim__ = None;
+                let mut output_1__ = None;
+                let mut output_2__ = None;
+                let mut swap_tx__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Height => {
-                            if height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("height"));
+                        GeneratedField::SwapClaim => {
+                            if swap_claim__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapClaim"));
                             }
-                            height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            swap_claim__ = map_.next_value()?;
                         }
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                        GeneratedField::Output1 => {
+                            if output_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output1"));
                             }
-                            trading_pair__ = map_.next_value()?;
+                            output_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::Output2 => {
+                            if output_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output2"));
+                            }
+                            output_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::SwapTx => {
+                            if swap_tx__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapTx"));
+                            }
+                            swap_tx__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SwapExecutionRequest {
-                    height: height__.unwrap_or_default(),
-                    trading_pair: trading_pair__,
+                Ok(swap_claim_view::Visible {
+                    swap_claim: swap_claim__,
+                    output_1: output_1__,
+                    output_2: output_2__,
+                    swap_tx: swap_tx__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapExecutionRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapClaimView.Visible", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapExecutionResponse {
+impl serde::Serialize for SwapExecution {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -8186,30 +9060,45 @@ impl serde::Serialize for SwapExecutionResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap_execution.is_some() {
+        if !self.traces.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapExecutionResponse", len)?;
-        if let Some(v) = self.swap_execution.as_ref() {
-            struct_ser.serialize_field("swapExecution", v)?;
+        if self.input.is_some() {
+            len += 1;
+        }
+        if self.output.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapExecution", len)?;
+        if !self.traces.is_empty() {
+            struct_ser.serialize_field("traces", &self.traces)?;
+        }
+        if let Some(v) = self.input.as_ref() {
+            struct_ser.serialize_field("input", v)?;
+        }
+        if let Some(v) = self.output.as_ref() {
+            struct_ser.serialize_field("output", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapExecutionResponse {
+impl<'de> serde::Deserialize<'de> for SwapExecution {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "swap_execution",
-            "swapExecution",
+            "traces",
+            "input",
+            "output",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            SwapExecution,
+            Traces,
+            Input,
+            Output,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -8232,7 +9121,9 @@ impl<'de> serde::Deserialize<'de> for SwapExecutionResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "swapExecution" | "swap_execution" => Ok(GeneratedField::SwapExecution),
+                            "traces" => Ok(GeneratedField::Traces),
+                            "input" => Ok(GeneratedField::Input),
+                            "output" => Ok(GeneratedField::Output),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -8242,39 +9133,55 @@ impl<'de> serde::Deserialize<'de> for SwapExecutionResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapExecutionResponse;
+            type Value = SwapExecution;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapExecutionResponse")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapExecution")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapExecutionResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapExecution, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap_execution__ = None;
+                let mut traces__ = None;
+                let mut input__ = None;
+                let mut output__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::SwapExecution => {
-                            if swap_execution__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapExecution"));
+                        GeneratedField::Traces => {
+                            if traces__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("traces"));
                             }
-                            swap_execution__ = map_.next_value()?;
+                            traces__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Input => {
+                            if input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("input"));
+                            }
+                            input__ = map_.next_value()?;
+                        }
+                        GeneratedField::Output => {
+                            if output__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output"));
+                            }
+                            output__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SwapExecutionResponse {
-                    swap_execution: swap_execution__,
+                Ok(SwapExecution {
+                    traces: traces__.unwrap_or_default(),
+                    input: input__,
+                    output: output__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapExecutionResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapExecution", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapExecutionsRequest {
+impl serde::Serialize for swap_execution::Trace {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -8282,50 +9189,29 @@ impl serde::Serialize for SwapExecutionsRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.start_height != 0 {
-            len += 1;
-        }
-        if self.end_height != 0 {
-            len += 1;
-        }
-        if self.trading_pair.is_some() {
+        if !self.value.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapExecutionsRequest", len)?;
-        if self.start_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
-        }
-        if self.end_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
-        }
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapExecution.Trace", len)?;
+        if !self.value.is_empty() {
+            struct_ser.serialize_field("value", &self.value)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapExecutionsRequest {
+impl<'de> serde::Deserialize<'de> for swap_execution::Trace {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "start_height",
-            "startHeight",
-            "end_height",
-            "endHeight",
-            "trading_pair",
-            "tradingPair",
+            "value",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            StartHeight,
-            EndHeight,
-            TradingPair,
+            Value,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -8348,9 +9234,7 @@ impl<'de> serde::Deserialize<'de> for SwapExecutionsRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
-                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "value" => Ok(GeneratedField::Value),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -8360,59 +9244,39 @@ impl<'de> serde::Deserialize<'de> for SwapExecutionsRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapExecutionsRequest;
+            type Value = swap_execution::Trace;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapExecutionsRequest")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapExecution.Trace")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapExecutionsRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<swap_execution::Trace, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut start_height__ = None;
-                let mut end_height__ = None;
-                let mut trading_pair__ = None;
+                let mut value__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::StartHeight => {
-                            if start_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("startHeight"));
-                            }
-                            start_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::EndHeight => {
-                            if end_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("endHeight"));
-                            }
-                            end_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
                             }
-                            trading_pair__ = map_.next_value()?;
+                            value__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SwapExecutionsRequest {
-                    start_height: start_height__.unwrap_or_default(),
-                    end_height: end_height__.unwrap_or_default(),
-                    trading_pair: trading_pair__,
+                Ok(swap_execution::Trace {
+                    value: value__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapExecutionsRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapExecution.Trace", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapExecutionsResponse {
+impl serde::Serialize for SwapExecutionRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -8420,19 +9284,13 @@ impl serde::Serialize for SwapExecutionsResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap_execution.is_some() {
-            len += 1;
-        }
         if self.height != 0 {
             len += 1;
         }
         if self.trading_pair.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapExecutionsResponse", len)?;
-        if let Some(v) = self.swap_execution.as_ref() {
-            struct_ser.serialize_field("swapExecution", v)?;
-        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapExecutionRequest", len)?;
         if self.height != 0 {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
@@ -8443,15 +9301,13 @@ impl serde::Serialize for SwapExecutionsResponse {
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapExecutionsResponse {
+impl<'de> serde::Deserialize<'de> for SwapExecutionRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "swap_execution",
-            "swapExecution",
             "height",
             "trading_pair",
             "tradingPair",
@@ -8459,7 +9315,6 @@ impl<'de> serde::Deserialize<'de> for SwapExecutionsResponse {
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            SwapExecution,
             Height,
             TradingPair,
             __SkipField__,
@@ -8484,7 +9339,6 @@ impl<'de> serde::Deserialize<'de> for SwapExecutionsResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "swapExecution" | "swap_execution" => Ok(GeneratedField::SwapExecution),
                             "height" => Ok(GeneratedField::Height),
                             "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
                             _ => Ok(GeneratedField::__SkipField__),
@@ -8496,27 +9350,20 @@ impl<'de> serde::Deserialize<'de> for SwapExecutionsResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapExecutionsResponse;
+            type Value = SwapExecutionRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapExecutionsResponse")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapExecutionRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapExecutionsResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapExecutionRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap_execution__ = None;
                 let mut height__ = None;
                 let mut trading_pair__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::SwapExecution => {
-                            if swap_execution__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapExecution"));
-                            }
-                            swap_execution__ = map_.next_value()?;
-                        }
                         GeneratedField::Height => {
                             if height__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("height"));
@@ -8536,17 +9383,16 @@ impl<'de> serde::Deserialize<'de> for SwapExecutionsResponse {
                         }
                     }
                 }
-                Ok(SwapExecutionsResponse {
-                    swap_execution: swap_execution__,
+                Ok(SwapExecutionRequest {
                     height: height__.unwrap_or_default(),
                     trading_pair: trading_pair__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapExecutionsResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapExecutionRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapPayload {
+impl serde::Serialize for SwapExecutionResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -8554,39 +9400,30 @@ impl serde::Serialize for SwapPayload {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.commitment.is_some() {
-            len += 1;
-        }
-        if !self.encrypted_swap.is_empty() {
+        if self.swap_execution.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapPayload", len)?;
-        if let Some(v) = self.commitment.as_ref() {
-            struct_ser.serialize_field("commitment", v)?;
-        }
-        if !self.encrypted_swap.is_empty() {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("encryptedSwap", pbjson::private::base64::encode(&self.encrypted_swap).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapExecutionResponse", len)?;
+        if let Some(v) = self.swap_execution.as_ref() {
+            struct_ser.serialize_field("swapExecution", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapPayload {
+impl<'de> serde::Deserialize<'de> for SwapExecutionResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "commitment",
-            "encrypted_swap",
-            "encryptedSwap",
+            "swap_execution",
+            "swapExecution",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Commitment,
-            EncryptedSwap,
+            SwapExecution,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -8609,8 +9446,7 @@ impl<'de> serde::Deserialize<'de> for SwapPayload {
                         E: serde::de::Error,
                     {
                         match value {
-                            "commitment" => Ok(GeneratedField::Commitment),
-                            "encryptedSwap" | "encrypted_swap" => Ok(GeneratedField::EncryptedSwap),
+                            "swapExecution" | "swap_execution" => Ok(GeneratedField::SwapExecution),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -8620,49 +9456,39 @@ impl<'de> serde::Deserialize<'de> for SwapPayload {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapPayload;
+            type Value = SwapExecutionResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapPayload")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapExecutionResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapPayload, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapExecutionResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut commitment__ = None;
-                let mut encrypted_swap__ = None;
+                let mut swap_execution__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Commitment => {
-                            if commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("commitment"));
-                            }
-                            commitment__ = map_.next_value()?;
-                        }
-                        GeneratedField::EncryptedSwap => {
-                            if encrypted_swap__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("encryptedSwap"));
+                        GeneratedField::SwapExecution => {
+                            if swap_execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapExecution"));
                             }
-                            encrypted_swap__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            swap_execution__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SwapPayload {
-                    commitment: commitment__,
-                    encrypted_swap: encrypted_swap__.unwrap_or_default(),
+                Ok(SwapExecutionResponse {
+                    swap_execution: swap_execution__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapPayload", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapExecutionResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapPlaintext {
+impl serde::Serialize for SwapExecutionsRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -8670,75 +9496,50 @@ impl serde::Serialize for SwapPlaintext {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.trading_pair.is_some() {
-            len += 1;
-        }
-        if self.delta_1_i.is_some() {
+        if self.start_height != 0 {
             len += 1;
         }
-        if self.delta_2_i.is_some() {
+        if self.end_height != 0 {
             len += 1;
         }
-        if self.claim_fee.is_some() {
+        if self.trading_pair.is_some() {
             len += 1;
         }
-        if self.claim_address.is_some() {
-            len += 1;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapExecutionsRequest", len)?;
+        if self.start_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
         }
-        if !self.rseed.is_empty() {
-            len += 1;
+        if self.end_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapPlaintext", len)?;
         if let Some(v) = self.trading_pair.as_ref() {
             struct_ser.serialize_field("tradingPair", v)?;
         }
-        if let Some(v) = self.delta_1_i.as_ref() {
-            struct_ser.serialize_field("delta1I", v)?;
-        }
-        if let Some(v) = self.delta_2_i.as_ref() {
-            struct_ser.serialize_field("delta2I", v)?;
-        }
-        if let Some(v) = self.claim_fee.as_ref() {
-            struct_ser.serialize_field("claimFee", v)?;
-        }
-        if let Some(v) = self.claim_address.as_ref() {
-            struct_ser.serialize_field("claimAddress", v)?;
-        }
-        if !self.rseed.is_empty() {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("rseed", pbjson::private::base64::encode(&self.rseed).as_str())?;
-        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapPlaintext {
+impl<'de> serde::Deserialize<'de> for SwapExecutionsRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "start_height",
+            "startHeight",
+            "end_height",
+            "endHeight",
             "trading_pair",
             "tradingPair",
-            "delta_1_i",
-            "delta1I",
-            "delta_2_i",
-            "delta2I",
-            "claim_fee",
-            "claimFee",
-            "claim_address",
-            "claimAddress",
-            "rseed",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            StartHeight,
+            EndHeight,
             TradingPair,
-            Delta1I,
-            Delta2I,
-            ClaimFee,
-            ClaimAddress,
-            Rseed,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -8761,12 +9562,9 @@ impl<'de> serde::Deserialize<'de> for SwapPlaintext {
                         E: serde::de::Error,
                     {
                         match value {
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
                             "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
-                            "delta1I" | "delta_1_i" => Ok(GeneratedField::Delta1I),
-                            "delta2I" | "delta_2_i" => Ok(GeneratedField::Delta2I),
-                            "claimFee" | "claim_fee" => Ok(GeneratedField::ClaimFee),
-                            "claimAddress" | "claim_address" => Ok(GeneratedField::ClaimAddress),
-                            "rseed" => Ok(GeneratedField::Rseed),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -8776,81 +9574,59 @@ impl<'de> serde::Deserialize<'de> for SwapPlaintext {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapPlaintext;
+            type Value = SwapExecutionsRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapPlaintext")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapExecutionsRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapPlaintext, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapExecutionsRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
+                let mut start_height__ = None;
+                let mut end_height__ = None;
                 let mut trading_pair__ = None;
-                let mut delta_1_i__ = None;
-                let mut delta_2_i__ = None;
-                let mut claim_fee__ = None;
-                let mut claim_address__ = None;
-                let mut rseed__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
-                            }
-                            trading_pair__ = map_.next_value()?;
-                        }
-                        GeneratedField::Delta1I => {
-                            if delta_1_i__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("delta1I"));
-                            }
-                            delta_1_i__ = map_.next_value()?;
-                        }
-                        GeneratedField::Delta2I => {
-                            if delta_2_i__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("delta2I"));
-                            }
-                            delta_2_i__ = map_.next_value()?;
-                        }
-                        GeneratedField::ClaimFee => {
-                            if claim_fee__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("claimFee"));
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
                             }
-                            claim_fee__ = map_.next_value()?;
+                            start_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::ClaimAddress => {
-                            if claim_address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("claimAddress"));
+                        GeneratedField::EndHeight => {
+                            if end_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endHeight"));
                             }
-                            claim_address__ = map_.next_value()?;
+                            end_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::Rseed => {
-                            if rseed__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rseed"));
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
                             }
-                            rseed__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            trading_pair__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SwapPlaintext {
+                Ok(SwapExecutionsRequest {
+                    start_height: start_height__.unwrap_or_default(),
+                    end_height: end_height__.unwrap_or_default(),
                     trading_pair: trading_pair__,
-                    delta_1_i: delta_1_i__,
-                    delta_2_i: delta_2_i__,
-                    claim_fee: claim_fee__,
-                    claim_address: claim_address__,
-                    rseed: rseed__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapPlaintext", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapExecutionsRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapPlan {
+impl serde::Serialize for SwapExecutionsResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -8858,60 +9634,48 @@ impl serde::Serialize for SwapPlan {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap_plaintext.is_some() {
-            len += 1;
-        }
-        if !self.fee_blinding.is_empty() {
+        if self.swap_execution.is_some() {
             len += 1;
         }
-        if !self.proof_blinding_r.is_empty() {
+        if self.height != 0 {
             len += 1;
         }
-        if !self.proof_blinding_s.is_empty() {
+        if self.trading_pair.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapPlan", len)?;
-        if let Some(v) = self.swap_plaintext.as_ref() {
-            struct_ser.serialize_field("swapPlaintext", v)?;
-        }
-        if !self.fee_blinding.is_empty() {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("feeBlinding", pbjson::private::base64::encode(&self.fee_blinding).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapExecutionsResponse", len)?;
+        if let Some(v) = self.swap_execution.as_ref() {
+            struct_ser.serialize_field("swapExecution", v)?;
         }
-        if !self.proof_blinding_r.is_empty() {
+        if self.height != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("proofBlindingR", pbjson::private::base64::encode(&self.proof_blinding_r).as_str())?;
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
         }
-        if !self.proof_blinding_s.is_empty() {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("proofBlindingS", pbjson::private::base64::encode(&self.proof_blinding_s).as_str())?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapPlan {
+impl<'de> serde::Deserialize<'de> for SwapExecutionsResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "swap_plaintext",
-            "swapPlaintext",
-            "fee_blinding",
-            "feeBlinding",
-            "proof_blinding_r",
-            "proofBlindingR",
-            "proof_blinding_s",
-            "proofBlindingS",
+            "swap_execution",
+            "swapExecution",
+            "height",
+            "trading_pair",
+            "tradingPair",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            SwapPlaintext,
-            FeeBlinding,
-            ProofBlindingR,
-            ProofBlindingS,
+            SwapExecution,
+            Height,
+            TradingPair,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -8934,10 +9698,9 @@ impl<'de> serde::Deserialize<'de> for SwapPlan {
                         E: serde::de::Error,
                     {
                         match value {
-                            "swapPlaintext" | "swap_plaintext" => Ok(GeneratedField::SwapPlaintext),
-                            "feeBlinding" | "fee_blinding" => Ok(GeneratedField::FeeBlinding),
-                            "proofBlindingR" | "proof_blinding_r" => Ok(GeneratedField::ProofBlindingR),
-                            "proofBlindingS" | "proof_blinding_s" => Ok(GeneratedField::ProofBlindingS),
+                            "swapExecution" | "swap_execution" => Ok(GeneratedField::SwapExecution),
+                            "height" => Ok(GeneratedField::Height),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -8947,69 +9710,57 @@ impl<'de> serde::Deserialize<'de> for SwapPlan {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapPlan;
+            type Value = SwapExecutionsResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapPlan")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapExecutionsResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapPlan, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapExecutionsResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap_plaintext__ = None;
-                let mut fee_blinding__ = None;
-                let mut proof_blinding_r__ = None;
-                let mut proof_blinding_s__ = None;
+                let mut swap_execution__ = None;
+                let mut height__ = None;
+                let mut trading_pair__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::SwapPlaintext => {
-                            if swap_plaintext__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapPlaintext"));
+                        GeneratedField::SwapExecution => {
+                            if swap_execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapExecution"));
                             }
-                            swap_plaintext__ = map_.next_value()?;
+                            swap_execution__ = map_.next_value()?;
                         }
-                        GeneratedField::FeeBlinding => {
-                            if fee_blinding__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("feeBlinding"));
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
                             }
-                            fee_blinding__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::ProofBlindingR => {
-                            if proof_blinding_r__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proofBlindingR"));
-                            }
-                            proof_blinding_r__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::ProofBlindingS => {
-                            if proof_blinding_s__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proofBlindingS"));
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
                             }
-                            proof_blinding_s__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            trading_pair__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SwapPlan {
-                    swap_plaintext: swap_plaintext__,
-                    fee_blinding: fee_blinding__.unwrap_or_default(),
-                    proof_blinding_r: proof_blinding_r__.unwrap_or_default(),
-                    proof_blinding_s: proof_blinding_s__.unwrap_or_default(),
+                Ok(SwapExecutionsResponse {
+                    swap_execution: swap_execution__,
+                    height: height__.unwrap_or_default(),
+                    trading_pair: trading_pair__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapPlan", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapExecutionsResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapView {
+impl serde::Serialize for SwapPayload {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -9017,38 +9768,39 @@ impl serde::Serialize for SwapView {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap_view.is_some() {
+        if self.commitment.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapView", len)?;
-        if let Some(v) = self.swap_view.as_ref() {
-            match v {
-                swap_view::SwapView::Visible(v) => {
-                    struct_ser.serialize_field("visible", v)?;
-                }
-                swap_view::SwapView::Opaque(v) => {
-                    struct_ser.serialize_field("opaque", v)?;
-                }
-            }
+        if !self.encrypted_swap.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapPayload", len)?;
+        if let Some(v) = self.commitment.as_ref() {
+            struct_ser.serialize_field("commitment", v)?;
+        }
+        if !self.encrypted_swap.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("encryptedSwap", pbjson::private::base64::encode(&self.encrypted_swap).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapView {
+impl<'de> serde::Deserialize<'de> for SwapPayload {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "visible",
-            "opaque",
+            "commitment",
+            "encrypted_swap",
+            "encryptedSwap",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Visible,
-            Opaque,
+            Commitment,
+            EncryptedSwap,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -9071,8 +9823,8 @@ impl<'de> serde::Deserialize<'de> for SwapView {
                         E: serde::de::Error,
                     {
                         match value {
-                            "visible" => Ok(GeneratedField::Visible),
-                            "opaque" => Ok(GeneratedField::Opaque),
+                            "commitment" => Ok(GeneratedField::Commitment),
+                            "encryptedSwap" | "encrypted_swap" => Ok(GeneratedField::EncryptedSwap),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -9082,47 +9834,49 @@ impl<'de> serde::Deserialize<'de> for SwapView {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapView;
+            type Value = SwapPayload;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapView")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapPayload")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapView, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapPayload, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap_view__ = None;
+                let mut commitment__ = None;
+                let mut encrypted_swap__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Visible => {
-                            if swap_view__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("visible"));
+                        GeneratedField::Commitment => {
+                            if commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("commitment"));
                             }
-                            swap_view__ = map_.next_value::<::std::option::Option<_>>()?.map(swap_view::SwapView::Visible)
-;
+                            commitment__ = map_.next_value()?;
                         }
-                        GeneratedField::Opaque => {
-                            if swap_view__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("opaque"));
+                        GeneratedField::EncryptedSwap => {
+                            if encrypted_swap__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("encryptedSwap"));
                             }
-                            swap_view__ = map_.next_value::<::std::option::Option<_>>()?.map(swap_view::SwapView::Opaque)
-;
+                            encrypted_swap__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SwapView {
-                    swap_view: swap_view__,
+                Ok(SwapPayload {
+                    commitment: commitment__,
+                    encrypted_swap: encrypted_swap__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapView", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapPayload", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for swap_view::Opaque {
+impl serde::Serialize for SwapPlaintext {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -9130,74 +9884,75 @@ impl serde::Serialize for swap_view::Opaque {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap.is_some() {
+        if self.trading_pair.is_some() {
             len += 1;
         }
-        if self.batch_swap_output_data.is_some() {
+        if self.delta_1_i.is_some() {
             len += 1;
         }
-        if self.output_1_value.is_some() {
+        if self.delta_2_i.is_some() {
             len += 1;
         }
-        if self.output_2_value.is_some() {
+        if self.claim_fee.is_some() {
             len += 1;
         }
-        if self.asset_1_metadata.is_some() {
+        if self.claim_address.is_some() {
             len += 1;
         }
-        if self.asset_2_metadata.is_some() {
+        if !self.rseed.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapView.Opaque", len)?;
-        if let Some(v) = self.swap.as_ref() {
-            struct_ser.serialize_field("swap", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapPlaintext", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
         }
-        if let Some(v) = self.batch_swap_output_data.as_ref() {
-            struct_ser.serialize_field("batchSwapOutputData", v)?;
+        if let Some(v) = self.delta_1_i.as_ref() {
+            struct_ser.serialize_field("delta1I", v)?;
         }
-        if let Some(v) = self.output_1_value.as_ref() {
-            struct_ser.serialize_field("output1Value", v)?;
+        if let Some(v) = self.delta_2_i.as_ref() {
+            struct_ser.serialize_field("delta2I", v)?;
         }
-        if let Some(v) = self.output_2_value.as_ref() {
-            struct_ser.serialize_field("output2Value", v)?;
+        if let Some(v) = self.claim_fee.as_ref() {
+            struct_ser.serialize_field("claimFee", v)?;
         }
-        if let Some(v) = self.asset_1_metadata.as_ref() {
-            struct_ser.serialize_field("asset1Metadata", v)?;
+        if let Some(v) = self.claim_address.as_ref() {
+            struct_ser.serialize_field("claimAddress", v)?;
         }
-        if let Some(v) = self.asset_2_metadata.as_ref() {
-            struct_ser.serialize_field("asset2Metadata", v)?;
+        if !self.rseed.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("rseed", pbjson::private::base64::encode(&self.rseed).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for swap_view::Opaque {
+impl<'de> serde::Deserialize<'de> for SwapPlaintext {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "swap",
-            "batch_swap_output_data",
-            "batchSwapOutputData",
-            "output_1_value",
-            "output1Value",
-            "output_2_value",
-            "output2Value",
-            "asset_1_metadata",
-            "asset1Metadata",
-            "asset_2_metadata",
-            "asset2Metadata",
+            "trading_pair",
+            "tradingPair",
+            "delta_1_i",
+            "delta1I",
+            "delta_2_i",
+            "delta2I",
+            "claim_fee",
+            "claimFee",
+            "claim_address",
+            "claimAddress",
+            "rseed",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Swap,
-            BatchSwapOutputData,
-            Output1Value,
-            Output2Value,
-            Asset1Metadata,
-            Asset2Metadata,
+            TradingPair,
+            Delta1I,
+            Delta2I,
+            ClaimFee,
+            ClaimAddress,
+            Rseed,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -9220,12 +9975,12 @@ impl<'de> serde::Deserialize<'de> for swap_view::Opaque {
                         E: serde::de::Error,
                     {
                         match value {
-                            "swap" => Ok(GeneratedField::Swap),
-                            "batchSwapOutputData" | "batch_swap_output_data" => Ok(GeneratedField::BatchSwapOutputData),
-                            "output1Value" | "output_1_value" => Ok(GeneratedField::Output1Value),
-                            "output2Value" | "output_2_value" => Ok(GeneratedField::Output2Value),
-                            "asset1Metadata" | "asset_1_metadata" => Ok(GeneratedField::Asset1Metadata),
-                            "asset2Metadata" | "asset_2_metadata" => Ok(GeneratedField::Asset2Metadata),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "delta1I" | "delta_1_i" => Ok(GeneratedField::Delta1I),
+                            "delta2I" | "delta_2_i" => Ok(GeneratedField::Delta2I),
+                            "claimFee" | "claim_fee" => Ok(GeneratedField::ClaimFee),
+                            "claimAddress" | "claim_address" => Ok(GeneratedField::ClaimAddress),
+                            "rseed" => Ok(GeneratedField::Rseed),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -9235,79 +9990,81 @@ impl<'de> serde::Deserialize<'de> for swap_view::Opaque {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = swap_view::Opaque;
+            type Value = SwapPlaintext;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapView.Opaque")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapPlaintext")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<swap_view::Opaque, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapPlaintext, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap__ = None;
-                let mut batch_swap_output_data__ = None;
-                let mut output_1_value__ = None;
-                let mut output_2_value__ = None;
-                let mut asset_1_metadata__ = None;
-                let mut asset_2_metadata__ = None;
+                let mut trading_pair__ = None;
+                let mut delta_1_i__ = None;
+                let mut delta_2_i__ = None;
+                let mut claim_fee__ = None;
+                let mut claim_address__ = None;
+                let mut rseed__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Swap => {
-                            if swap__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swap"));
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
                             }
-                            swap__ = map_.next_value()?;
+                            trading_pair__ = map_.next_value()?;
                         }
-                        GeneratedField::BatchSwapOutputData => {
-                            if batch_swap_output_data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("batchSwapOutputData"));
+                        GeneratedField::Delta1I => {
+                            if delta_1_i__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delta1I"));
                             }
-                            batch_swap_output_data__ = map_.next_value()?;
+                            delta_1_i__ = map_.next_value()?;
                         }
-                        GeneratedField::Output1Value => {
-                            if output_1_value__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("output1Value"));
+                        GeneratedField::Delta2I => {
+                            if delta_2_i__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delta2I"));
                             }
-                            output_1_value__ = map_.next_value()?;
+                            delta_2_i__ = map_.next_value()?;
                         }
-                        GeneratedField::Output2Value => {
-                            if output_2_value__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("output2Value"));
+                        GeneratedField::ClaimFee => {
+                            if claim_fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("claimFee"));
                             }
-                            output_2_value__ = map_.next_value()?;
+                            claim_fee__ = map_.next_value()?;
                         }
-                        GeneratedField::Asset1Metadata => {
-                            if asset_1_metadata__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("asset1Metadata"));
+                        GeneratedField::ClaimAddress => {
+                            if claim_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("claimAddress"));
                             }
-                            asset_1_metadata__ = map_.next_value()?;
+                            claim_address__ = map_.next_value()?;
                         }
-                        GeneratedField::Asset2Metadata => {
-                            if asset_2_metadata__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("asset2Metadata"));
+                        GeneratedField::Rseed => {
+                            if rseed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rseed"));
                             }
-                            asset_2_metadata__ = map_.next_value()?;
+                            rseed__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(swap_view::Opaque {
-                    swap: swap__,
-                    batch_swap_output_data: batch_swap_output_data__,
-                    output_1_value: output_1_value__,
-                    output_2_value: output_2_value__,
-                    asset_1_metadata: asset_1_metadata__,
-                    asset_2_metadata: asset_2_metadata__,
+                Ok(SwapPlaintext {
+                    trading_pair: trading_pair__,
+                    delta_1_i: delta_1_i__,
+                    delta_2_i: delta_2_i__,
+                    claim_fee: claim_fee__,
+                    claim_address: claim_address__,
+                    rseed: rseed__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapView.Opaque", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapPlaintext", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for swap_view::Visible {
+impl serde::Serialize for SwapPlan {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -9315,92 +10072,60 @@ impl serde::Serialize for swap_view::Visible {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap.is_some() {
-            len += 1;
-        }
         if self.swap_plaintext.is_some() {
             len += 1;
         }
-        if self.claim_tx.is_some() {
-            len += 1;
-        }
-        if self.batch_swap_output_data.is_some() {
-            len += 1;
-        }
-        if self.output_1.is_some() {
-            len += 1;
-        }
-        if self.output_2.is_some() {
+        if !self.fee_blinding.is_empty() {
             len += 1;
         }
-        if self.asset_1_metadata.is_some() {
+        if !self.proof_blinding_r.is_empty() {
             len += 1;
         }
-        if self.asset_2_metadata.is_some() {
+        if !self.proof_blinding_s.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapView.Visible", len)?;
-        if let Some(v) = self.swap.as_ref() {
-            struct_ser.serialize_field("swap", v)?;
-        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapPlan", len)?;
         if let Some(v) = self.swap_plaintext.as_ref() {
             struct_ser.serialize_field("swapPlaintext", v)?;
         }
-        if let Some(v) = self.claim_tx.as_ref() {
-            struct_ser.serialize_field("claimTx", v)?;
-        }
-        if let Some(v) = self.batch_swap_output_data.as_ref() {
-            struct_ser.serialize_field("batchSwapOutputData", v)?;
-        }
-        if let Some(v) = self.output_1.as_ref() {
-            struct_ser.serialize_field("output1", v)?;
-        }
-        if let Some(v) = self.output_2.as_ref() {
-            struct_ser.serialize_field("output2", v)?;
+        if !self.fee_blinding.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("feeBlinding", pbjson::private::base64::encode(&self.fee_blinding).as_str())?;
         }
-        if let Some(v) = self.asset_1_metadata.as_ref() {
-            struct_ser.serialize_field("asset1Metadata", v)?;
+        if !self.proof_blinding_r.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proofBlindingR", pbjson::private::base64::encode(&self.proof_blinding_r).as_str())?;
         }
-        if let Some(v) = self.asset_2_metadata.as_ref() {
-            struct_ser.serialize_field("asset2Metadata", v)?;
+        if !self.proof_blinding_s.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proofBlindingS", pbjson::private::base64::encode(&self.proof_blinding_s).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for swap_view::Visible {
+impl<'de> serde::Deserialize<'de> for SwapPlan {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "swap",
             "swap_plaintext",
             "swapPlaintext",
-            "claim_tx",
-            "claimTx",
-            "batch_swap_output_data",
-            "batchSwapOutputData",
-            "output_1",
-            "output1",
-            "output_2",
-            "output2",
-            "asset_1_metadata",
-            "asset1Metadata",
-            "asset_2_metadata",
-            "asset2Metadata",
+            "fee_blinding",
+            "feeBlinding",
+            "proof_blinding_r",
+            "proofBlindingR",
+            "proof_blinding_s",
+            "proofBlindingS",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Swap,
             SwapPlaintext,
-            ClaimTx,
-            BatchSwapOutputData,
-            Output1,
-            Output2,
-            Asset1Metadata,
-            Asset2Metadata,
+            FeeBlinding,
+            ProofBlindingR,
+            ProofBlindingS,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -9423,14 +10148,10 @@ impl<'de> serde::Deserialize<'de> for swap_view::Visible {
                         E: serde::de::Error,
                     {
                         match value {
-                            "swap" => Ok(GeneratedField::Swap),
                             "swapPlaintext" | "swap_plaintext" => Ok(GeneratedField::SwapPlaintext),
-                            "claimTx" | "claim_tx" => Ok(GeneratedField::ClaimTx),
-                            "batchSwapOutputData" | "batch_swap_output_data" => Ok(GeneratedField::BatchSwapOutputData),
-                            "output1" | "output_1" => Ok(GeneratedField::Output1),
-                            "output2" | "output_2" => Ok(GeneratedField::Output2),
-                            "asset1Metadata" | "asset_1_metadata" => Ok(GeneratedField::Asset1Metadata),
-                            "asset2Metadata" | "asset_2_metadata" => Ok(GeneratedField::Asset2Metadata),
+                            "feeBlinding" | "fee_blinding" => Ok(GeneratedField::FeeBlinding),
+                            "proofBlindingR" | "proof_blinding_r" => Ok(GeneratedField::ProofBlindingR),
+                            "proofBlindingS" | "proof_blinding_s" => Ok(GeneratedField::ProofBlindingS),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -9440,92 +10161,912 @@ impl<'de> serde::Deserialize<'de> for swap_view::Visible {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = swap_view::Visible;
+            type Value = SwapPlan;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.SwapView.Visible")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapPlan")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<swap_view::Visible, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapPlan, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap__ = None;
                 let mut swap_plaintext__ = None;
-                let mut claim_tx__ = None;
-                let mut batch_swap_output_data__ = None;
-                let mut output_1__ = None;
-                let mut output_2__ = None;
-                let mut asset_1_metadata__ = None;
-                let mut asset_2_metadata__ = None;
+                let mut fee_blinding__ = None;
+                let mut proof_blinding_r__ = None;
+                let mut proof_blinding_s__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Swap => {
-                            if swap__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swap"));
-                            }
-                            swap__ = map_.next_value()?;
-                        }
                         GeneratedField::SwapPlaintext => {
                             if swap_plaintext__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("swapPlaintext"));
                             }
                             swap_plaintext__ = map_.next_value()?;
                         }
-                        GeneratedField::ClaimTx => {
-                            if claim_tx__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("claimTx"));
-                            }
-                            claim_tx__ = map_.next_value()?;
-                        }
-                        GeneratedField::BatchSwapOutputData => {
-                            if batch_swap_output_data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("batchSwapOutputData"));
-                            }
-                            batch_swap_output_data__ = map_.next_value()?;
-                        }
-                        GeneratedField::Output1 => {
-                            if output_1__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("output1"));
-                            }
-                            output_1__ = map_.next_value()?;
-                        }
-                        GeneratedField::Output2 => {
-                            if output_2__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("output2"));
+                        GeneratedField::FeeBlinding => {
+                            if fee_blinding__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeBlinding"));
                             }
-                            output_2__ = map_.next_value()?;
+                            fee_blinding__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::Asset1Metadata => {
-                            if asset_1_metadata__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("asset1Metadata"));
+                        GeneratedField::ProofBlindingR => {
+                            if proof_blinding_r__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proofBlindingR"));
                             }
-                            asset_1_metadata__ = map_.next_value()?;
+                            proof_blinding_r__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::Asset2Metadata => {
-                            if asset_2_metadata__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("asset2Metadata"));
+                        GeneratedField::ProofBlindingS => {
+                            if proof_blinding_s__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proofBlindingS"));
                             }
-                            asset_2_metadata__ = map_.next_value()?;
+                            proof_blinding_s__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(swap_view::Visible {
-                    swap: swap__,
+                Ok(SwapPlan {
                     swap_plaintext: swap_plaintext__,
-                    claim_tx: claim_tx__,
-                    batch_swap_output_data: batch_swap_output_data__,
-                    output_1: output_1__,
-                    output_2: output_2__,
-                    asset_1_metadata: asset_1_metadata__,
-                    asset_2_metadata: asset_2_metadata__,
+                    fee_blinding: fee_blinding__.unwrap_or_default(),
+                    proof_blinding_r: proof_blinding_r__.unwrap_or_default(),
+                    proof_blinding_s: proof_blinding_s__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapView.Visible", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapPlan", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SwapView {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.swap_view.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapView", len)?;
+        if let Some(v) = self.swap_view.as_ref() {
+            match v {
+                swap_view::SwapView::Visible(v) => {
+                    struct_ser.serialize_field("visible", v)?;
+                }
+                swap_view::SwapView::Opaque(v) => {
+                    struct_ser.serialize_field("opaque", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SwapView {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "visible",
+            "opaque",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Visible,
+            Opaque,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "visible" => Ok(GeneratedField::Visible),
+                            "opaque" => Ok(GeneratedField::Opaque),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SwapView;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapView")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapView, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut swap_view__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Visible => {
+                            if swap_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("visible"));
+                            }
+                            swap_view__ = map_.next_value::<::std::option::Option<_>>()?.map(swap_view::SwapView::Visible)
+;
+                        }
+                        GeneratedField::Opaque => {
+                            if swap_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("opaque"));
+                            }
+                            swap_view__ = map_.next_value::<::std::option::Option<_>>()?.map(swap_view::SwapView::Opaque)
+;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SwapView {
+                    swap_view: swap_view__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapView", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for swap_view::Opaque {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.swap.is_some() {
+            len += 1;
+        }
+        if self.batch_swap_output_data.is_some() {
+            len += 1;
+        }
+        if self.output_1_value.is_some() {
+            len += 1;
+        }
+        if self.output_2_value.is_some() {
+            len += 1;
+        }
+        if self.asset_1_metadata.is_some() {
+            len += 1;
+        }
+        if self.asset_2_metadata.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapView.Opaque", len)?;
+        if let Some(v) = self.swap.as_ref() {
+            struct_ser.serialize_field("swap", v)?;
+        }
+        if let Some(v) = self.batch_swap_output_data.as_ref() {
+            struct_ser.serialize_field("batchSwapOutputData", v)?;
+        }
+        if let Some(v) = self.output_1_value.as_ref() {
+            struct_ser.serialize_field("output1Value", v)?;
+        }
+        if let Some(v) = self.output_2_value.as_ref() {
+            struct_ser.serialize_field("output2Value", v)?;
+        }
+        if let Some(v) = self.asset_1_metadata.as_ref() {
+            struct_ser.serialize_field("asset1Metadata", v)?;
+        }
+        if let Some(v) = self.asset_2_metadata.as_ref() {
+            struct_ser.serialize_field("asset2Metadata", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for swap_view::Opaque {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "swap",
+            "batch_swap_output_data",
+            "batchSwapOutputData",
+            "output_1_value",
+            "output1Value",
+            "output_2_value",
+            "output2Value",
+            "asset_1_metadata",
+            "asset1Metadata",
+            "asset_2_metadata",
+            "asset2Metadata",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Swap,
+            BatchSwapOutputData,
+            Output1Value,
+            Output2Value,
+            Asset1Metadata,
+            Asset2Metadata,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "swap" => Ok(GeneratedField::Swap),
+                            "batchSwapOutputData" | "batch_swap_output_data" => Ok(GeneratedField::BatchSwapOutputData),
+                            "output1Value" | "output_1_value" => Ok(GeneratedField::Output1Value),
+                            "output2Value" | "output_2_value" => Ok(GeneratedField::Output2Value),
+                            "asset1Metadata" | "asset_1_metadata" => Ok(GeneratedField::Asset1Metadata),
+                            "asset2Metadata" | "asset_2_metadata" => Ok(GeneratedField::Asset2Metadata),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = swap_view::Opaque;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapView.Opaque")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<swap_view::Opaque, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut swap__ = None;
+                let mut batch_swap_output_data__ = None;
+                let mut output_1_value__ = None;
+                let mut output_2_value__ = None;
+                let mut asset_1_metadata__ = None;
+                let mut asset_2_metadata__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Swap => {
+                            if swap__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swap"));
+                            }
+                            swap__ = map_.next_value()?;
+                        }
+                        GeneratedField::BatchSwapOutputData => {
+                            if batch_swap_output_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("batchSwapOutputData"));
+                            }
+                            batch_swap_output_data__ = map_.next_value()?;
+                        }
+                        GeneratedField::Output1Value => {
+                            if output_1_value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output1Value"));
+                            }
+                            output_1_value__ = map_.next_value()?;
+                        }
+                        GeneratedField::Output2Value => {
+                            if output_2_value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output2Value"));
+                            }
+                            output_2_value__ = map_.next_value()?;
+                        }
+                        GeneratedField::Asset1Metadata => {
+                            if asset_1_metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset1Metadata"));
+                            }
+                            asset_1_metadata__ = map_.next_value()?;
+                        }
+                        GeneratedField::Asset2Metadata => {
+                            if asset_2_metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset2Metadata"));
+                            }
+                            asset_2_metadata__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(swap_view::Opaque {
+                    swap: swap__,
+                    batch_swap_output_data: batch_swap_output_data__,
+                    output_1_value: output_1_value__,
+                    output_2_value: output_2_value__,
+                    asset_1_metadata: asset_1_metadata__,
+                    asset_2_metadata: asset_2_metadata__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapView.Opaque", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for swap_view::Visible {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.swap.is_some() {
+            len += 1;
+        }
+        if self.swap_plaintext.is_some() {
+            len += 1;
+        }
+        if self.claim_tx.is_some() {
+            len += 1;
+        }
+        if self.batch_swap_output_data.is_some() {
+            len += 1;
+        }
+        if self.output_1.is_some() {
+            len += 1;
+        }
+        if self.output_2.is_some() {
+            len += 1;
+        }
+        if self.asset_1_metadata.is_some() {
+            len += 1;
+        }
+        if self.asset_2_metadata.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapView.Visible", len)?;
+        if let Some(v) = self.swap.as_ref() {
+            struct_ser.serialize_field("swap", v)?;
+        }
+        if let Some(v) = self.swap_plaintext.as_ref() {
+            struct_ser.serialize_field("swapPlaintext", v)?;
+        }
+        if let Some(v) = self.claim_tx.as_ref() {
+            struct_ser.serialize_field("claimTx", v)?;
+        }
+        if let Some(v) = self.batch_swap_output_data.as_ref() {
+            struct_ser.serialize_field("batchSwapOutputData", v)?;
+        }
+        if let Some(v) = self.output_1.as_ref() {
+            struct_ser.serialize_field("output1", v)?;
+        }
+        if let Some(v) = self.output_2.as_ref() {
+            struct_ser.serialize_field("output2", v)?;
+        }
+        if let Some(v) = self.asset_1_metadata.as_ref() {
+            struct_ser.serialize_field("asset1Metadata", v)?;
+        }
+        if let Some(v) = self.asset_2_metadata.as_ref() {
+            struct_ser.serialize_field("asset2Metadata", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for swap_view::Visible {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "swap",
+            "swap_plaintext",
+            "swapPlaintext",
+            "claim_tx",
+            "claimTx",
+            "batch_swap_output_data",
+            "batchSwapOutputData",
+            "output_1",
+            "output1",
+            "output_2",
+            "output2",
+            "asset_1_metadata",
+            "asset1Metadata",
+            "asset_2_metadata",
+            "asset2Metadata",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Swap,
+            SwapPlaintext,
+            ClaimTx,
+            BatchSwapOutputData,
+            Output1,
+            Output2,
+            Asset1Metadata,
+            Asset2Metadata,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "swap" => Ok(GeneratedField::Swap),
+                            "swapPlaintext" | "swap_plaintext" => Ok(GeneratedField::SwapPlaintext),
+                            "claimTx" | "claim_tx" => Ok(GeneratedField::ClaimTx),
+                            "batchSwapOutputData" | "batch_swap_output_data" => Ok(GeneratedField::BatchSwapOutputData),
+                            "output1" | "output_1" => Ok(GeneratedField::Output1),
+                            "output2" | "output_2" => Ok(GeneratedField::Output2),
+                            "asset1Metadata" | "asset_1_metadata" => Ok(GeneratedField::Asset1Metadata),
+                            "asset2Metadata" | "asset_2_metadata" => Ok(GeneratedField::Asset2Metadata),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = swap_view::Visible;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.SwapView.Visible")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<swap_view::Visible, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut swap__ = None;
+                let mut swap_plaintext__ = None;
+                let mut claim_tx__ = None;
+                let mut batch_swap_output_data__ = None;
+                let mut output_1__ = None;
+                let mut output_2__ = None;
+                let mut asset_1_metadata__ = None;
+                let mut asset_2_metadata__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Swap => {
+                            if swap__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swap"));
+                            }
+                            swap__ = map_.next_value()?;
+                        }
+                        GeneratedField::SwapPlaintext => {
+                            if swap_plaintext__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapPlaintext"));
+                            }
+                            swap_plaintext__ = map_.next_value()?;
+                        }
+                        GeneratedField::ClaimTx => {
+                            if claim_tx__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("claimTx"));
+                            }
+                            claim_tx__ = map_.next_value()?;
+                        }
+                        GeneratedField::BatchSwapOutputData => {
+                            if batch_swap_output_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("batchSwapOutputData"));
+                            }
+                            batch_swap_output_data__ = map_.next_value()?;
+                        }
+                        GeneratedField::Output1 => {
+                            if output_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output1"));
+                            }
+                            output_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::Output2 => {
+                            if output_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output2"));
+                            }
+                            output_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::Asset1Metadata => {
+                            if asset_1_metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset1Metadata"));
+                            }
+                            asset_1_metadata__ = map_.next_value()?;
+                        }
+                        GeneratedField::Asset2Metadata => {
+                            if asset_2_metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset2Metadata"));
+                            }
+                            asset_2_metadata__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(swap_view::Visible {
+                    swap: swap__,
+                    swap_plaintext: swap_plaintext__,
+                    claim_tx: claim_tx__,
+                    batch_swap_output_data: batch_swap_output_data__,
+                    output_1: output_1__,
+                    output_2: output_2__,
+                    asset_1_metadata: asset_1_metadata__,
+                    asset_2_metadata: asset_2_metadata__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapView.Visible", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TimeWeightedAveragePrice {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.window != 0 {
+            len += 1;
+        }
+        if self.price != 0. {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.TimeWeightedAveragePrice", len)?;
+        if self.window != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("window", ToString::to_string(&self.window).as_str())?;
+        }
+        if self.price != 0. {
+            struct_ser.serialize_field("price", &self.price)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TimeWeightedAveragePrice {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "window",
+            "price",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Window,
+            Price,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "window" => Ok(GeneratedField::Window),
+                            "price" => Ok(GeneratedField::Price),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TimeWeightedAveragePrice;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.TimeWeightedAveragePrice")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TimeWeightedAveragePrice, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut window__ = None;
+                let mut price__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Window => {
+                            if window__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("window"));
+                            }
+                            window__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Price => {
+                            if price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("price"));
+                            }
+                            price__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TimeWeightedAveragePrice {
+                    window: window__.unwrap_or_default(),
+                    price: price__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.TimeWeightedAveragePrice", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TimeWeightedAveragePricesRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.pair.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.TimeWeightedAveragePricesRequest", len)?;
+        if let Some(v) = self.pair.as_ref() {
+            struct_ser.serialize_field("pair", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TimeWeightedAveragePricesRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "pair",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Pair,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "pair" => Ok(GeneratedField::Pair),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TimeWeightedAveragePricesRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.TimeWeightedAveragePricesRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TimeWeightedAveragePricesRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut pair__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Pair => {
+                            if pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pair"));
+                            }
+                            pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TimeWeightedAveragePricesRequest {
+                    pair: pair__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.TimeWeightedAveragePricesRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TimeWeightedAveragePricesResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        if !self.prices.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.TimeWeightedAveragePricesResponse", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if !self.prices.is_empty() {
+            struct_ser.serialize_field("prices", &self.prices)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TimeWeightedAveragePricesResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+            "prices",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            Prices,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            "prices" => Ok(GeneratedField::Prices),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TimeWeightedAveragePricesResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.TimeWeightedAveragePricesResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TimeWeightedAveragePricesResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                let mut prices__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Prices => {
+                            if prices__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("prices"));
+                            }
+                            prices__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TimeWeightedAveragePricesResponse {
+                    height: height__.unwrap_or_default(),
+                    prices: prices__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.TimeWeightedAveragePricesResponse", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for TradingFunction {
@@ -603,7 +603,7 @@ pub struct Proposal {
     #[prost(string, tag = "2")]
     pub description: ::prost::alloc::string::String,
     /// The proposal's payload.
-    #[prost(oneof = "proposal::Payload", tags = "5, 6, 7, 8, 9, 10, 11")]
+    #[prost(oneof = "proposal::Payload", tags = "5, 6, 7, 8, 9, 10, 11, 12")]
     pub payload: ::core::option::Option<proposal::Payload>,
 }
 /// Nested message and enum types in `Proposal`.
@@ -757,6 +757,68 @@ pub mod proposal {
             )
         }
     }
+    /// Recover a frozen or expired IBC client, by substituting its state with that
+    /// of an active client tracking the same chain.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct RecoverIbcClient {
+        /// The frozen or expired client to recover.
+        #[prost(string, tag = "1")]
+        pub subject_client_id: ::prost::alloc::string::String,
+        /// The active client whose state is copied to the subject.
+        #[prost(string, tag = "2")]
+        pub substitute_client_id: ::prost::alloc::string::String,
+    }
+    impl ::prost::Name for RecoverIbcClient {
+        const NAME: &'static str = "RecoverIbcClient";
+        const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.core.component.governance.v1.Proposal.{}", Self::NAME
+            )
+        }
+    }
+    /// Register human-readable information about the chain at the other end of an
+    /// IBC connection, or remove the information registered for it.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct SetIbcConnectionMetadata {
+        /// The connection to register the information for.
+        #[prost(string, tag = "1")]
+        pub connection_id: ::prost::alloc::string::String,
+        /// The information to register, or unset to remove the registered information.
+        #[prost(message, optional, tag = "2")]
+        pub metadata: ::core::option::Option<super::super::super::ibc::v1::ConnectionMetadata>,
+    }
+    impl ::prost::Name for SetIbcConnectionMetadata {
+        const NAME: &'static str = "SetIbcConnectionMetadata";
+        const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.core.component.governance.v1.Proposal.{}", Self::NAME
+            )
+        }
+    }
+    /// Register the metadata of assets, such as their display units and symbols,
+    /// so that wallets can render amounts of them in a human-readable way.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct RegisterAssetMetadata {
+        /// The metadata to register, replacing any registered for the same assets.
+        #[prost(message, repeated, tag = "1")]
+        pub metadata: ::prost::alloc::vec::Vec<
+            super::super::super::super::asset::v1::Metadata,
+        >,
+    }
+    impl ::prost::Name for RegisterAssetMetadata {
+        const NAME: &'static str = "RegisterAssetMetadata";
+        const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.core.component.governance.v1.Proposal.{}", Self::NAME
+            )
+        }
+    }
     /// The proposal's payload.
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
@@ -775,6 +837,12 @@ pub mod proposal {
         FreezeIbcClient(FreezeIbcClient),
         #[prost(message, tag = "11")]
         UnfreezeIbcClient(UnfreezeIbcClient),
+        #[prost(message, tag = "12")]
+        RecoverIbcClient(RecoverIbcClient),
+        #[prost(message, tag = "13")]
+        SetIbcConnectionMetadata(SetIbcConnectionMetadata),
+        #[prost(message, tag = "14")]
+        RegisterAssetMetadata(RegisterAssetMetadata),
     }
 }
 impl ::prost::Name for Proposal {
@@ -1357,6 +1425,9 @@ pub enum ProposalKind {
     UpgradePlan = 5,
     FreezeIbcClient = 6,
     UnfreezeIbcClient = 7,
+    RecoverIbcClient = 8,
+    SetIbcConnectionMetadata = 9,
+    RegisterAssetMetadata = 10,
 }
 impl ProposalKind {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -1373,6 +1444,11 @@ impl ProposalKind {
             ProposalKind::UpgradePlan => "PROPOSAL_KIND_UPGRADE_PLAN",
             ProposalKind::FreezeIbcClient => "PROPOSAL_KIND_FREEZE_IBC_CLIENT",
             ProposalKind::UnfreezeIbcClient => "PROPOSAL_KIND_UNFREEZE_IBC_CLIENT",
+            ProposalKind::RecoverIbcClient => "PROPOSAL_KIND_RECOVER_IBC_CLIENT",
+            ProposalKind::SetIbcConnectionMetadata => {
+                "PROPOSAL_KIND_SET_IBC_CONNECTION_METADATA"
+            }
+            ProposalKind::RegisterAssetMetadata => "PROPOSAL_KIND_REGISTER_ASSET_METADATA",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -1386,6 +1462,11 @@ impl ProposalKind {
             "PROPOSAL_KIND_UPGRADE_PLAN" => Some(Self::UpgradePlan),
             "PROPOSAL_KIND_FREEZE_IBC_CLIENT" => Some(Self::FreezeIbcClient),
             "PROPOSAL_KIND_UNFREEZE_IBC_CLIENT" => Some(Self::UnfreezeIbcClient),
+            "PROPOSAL_KIND_RECOVER_IBC_CLIENT" => Some(Self::RecoverIbcClient),
+            "PROPOSAL_KIND_SET_IBC_CONNECTION_METADATA" => {
+                Some(Self::SetIbcConnectionMetadata)
+            }
+            "PROPOSAL_KIND_REGISTER_ASSET_METADATA" => Some(Self::RegisterAssetMetadata),
             _ => None,
         }
     }
@@ -3275,6 +3275,15 @@ impl serde::Serialize for Proposal {
                 proposal::Payload::UnfreezeIbcClient(v) => {
                     struct_ser.serialize_field("unfreezeIbcClient", v)?;
                 }
+                proposal::Payload::RecoverIbcClient(v) => {
+                    struct_ser.serialize_field("recoverIbcClient", v)?;
+                }
+                proposal::Payload::SetIbcConnectionMetadata(v) => {
+                    struct_ser.serialize_field("setIbcConnectionMetadata", v)?;
+                }
+                proposal::Payload::RegisterAssetMetadata(v) => {
+                    struct_ser.serialize_field("registerAssetMetadata", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -3302,6 +3311,12 @@ impl<'de> serde::Deserialize<'de> for Proposal {
             "freezeIbcClient",
             "unfreeze_ibc_client",
             "unfreezeIbcClient",
+            "recover_ibc_client",
+            "recoverIbcClient",
+            "set_ibc_connection_metadata",
+            "setIbcConnectionMetadata",
+            "register_asset_metadata",
+            "registerAssetMetadata",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -3316,6 +3331,9 @@ impl<'de> serde::Deserialize<'de> for Proposal {
             UpgradePlan,
             FreezeIbcClient,
             UnfreezeIbcClient,
+            RecoverIbcClient,
+            SetIbcConnectionMetadata,
+            RegisterAssetMetadata,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3348,6 +3366,9 @@ impl<'de> serde::Deserialize<'de> for Proposal {
                             "upgradePlan" | "upgrade_plan" => Ok(GeneratedField::UpgradePlan),
                             "freezeIbcClient" | "freeze_ibc_client" => Ok(GeneratedField::FreezeIbcClient),
                             "unfreezeIbcClient" | "unfreeze_ibc_client" => Ok(GeneratedField::UnfreezeIbcClient),
+                            "recoverIbcClient" | "recover_ibc_client" => Ok(GeneratedField::RecoverIbcClient),
+                            "setIbcConnectionMetadata" | "set_ibc_connection_metadata" => Ok(GeneratedField::SetIbcConnectionMetadata),
+                            "registerAssetMetadata" | "register_asset_metadata" => Ok(GeneratedField::RegisterAssetMetadata),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3440,6 +3461,27 @@ impl<'de> serde::Deserialize<'de> for Proposal {
                                 return Err(serde::de::Error::duplicate_field("unfreezeIbcClient"));
                             }
                             payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::Payload::UnfreezeIbcClient)
+;
+                        }
+                        GeneratedField::RecoverIbcClient => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("recoverIbcClient"));
+                            }
+                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::Payload::RecoverIbcClient)
+;
+                        }
+                        GeneratedField::SetIbcConnectionMetadata => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("setIbcConnectionMetadata"));
+                            }
+                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::Payload::SetIbcConnectionMetadata)
+;
+                        }
+                        GeneratedField::RegisterAssetMetadata => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("registerAssetMetadata"));
+                            }
+                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::Payload::RegisterAssetMetadata)
 ;
                         }
                         GeneratedField::__SkipField__ => {
@@ -3894,6 +3936,328 @@ impl<'de> serde::Deserialize<'de> for proposal::ParameterChange {
         deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.ParameterChange", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for proposal::RecoverIbcClient {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.subject_client_id.is_empty() {
+            len += 1;
+        }
+        if !self.substitute_client_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.RecoverIbcClient", len)?;
+        if !self.subject_client_id.is_empty() {
+            struct_ser.serialize_field("subjectClientId", &self.subject_client_id)?;
+        }
+        if !self.substitute_client_id.is_empty() {
+            struct_ser.serialize_field("substituteClientId", &self.substitute_client_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::RecoverIbcClient {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "subject_client_id",
+            "subjectClientId",
+            "substitute_client_id",
+            "substituteClientId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SubjectClientId,
+            SubstituteClientId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "subjectClientId" | "subject_client_id" => Ok(GeneratedField::SubjectClientId),
+                            "substituteClientId" | "substitute_client_id" => Ok(GeneratedField::SubstituteClientId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::RecoverIbcClient;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.RecoverIbcClient")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::RecoverIbcClient, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut subject_client_id__ = None;
+                let mut substitute_client_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SubjectClientId => {
+                            if subject_client_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("subjectClientId"));
+                            }
+                            subject_client_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SubstituteClientId => {
+                            if substitute_client_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("substituteClientId"));
+                            }
+                            substitute_client_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(proposal::RecoverIbcClient {
+                    subject_client_id: subject_client_id__.unwrap_or_default(),
+                    substitute_client_id: substitute_client_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.RecoverIbcClient", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal::RegisterAssetMetadata {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.metadata.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.RegisterAssetMetadata", len)?;
+        if !self.metadata.is_empty() {
+            struct_ser.serialize_field("metadata", &self.metadata)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::RegisterAssetMetadata {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "metadata",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Metadata,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "metadata" => Ok(GeneratedField::Metadata),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::RegisterAssetMetadata;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.RegisterAssetMetadata")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::RegisterAssetMetadata, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut metadata__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Metadata => {
+                            if metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("metadata"));
+                            }
+                            metadata__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(proposal::RegisterAssetMetadata {
+                    metadata: metadata__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.RegisterAssetMetadata", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal::SetIbcConnectionMetadata {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.connection_id.is_empty() {
+            len += 1;
+        }
+        if self.metadata.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.SetIbcConnectionMetadata", len)?;
+        if !self.connection_id.is_empty() {
+            struct_ser.serialize_field("connectionId", &self.connection_id)?;
+        }
+        if let Some(v) = self.metadata.as_ref() {
+            struct_ser.serialize_field("metadata", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::SetIbcConnectionMetadata {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "connection_id",
+            "connectionId",
+            "metadata",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ConnectionId,
+            Metadata,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "connectionId" | "connection_id" => Ok(GeneratedField::ConnectionId),
+                            "metadata" => Ok(GeneratedField::Metadata),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::SetIbcConnectionMetadata;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.SetIbcConnectionMetadata")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::SetIbcConnectionMetadata, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut connection_id__ = None;
+                let mut metadata__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ConnectionId => {
+                            if connection_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("connectionId"));
+                            }
+                            connection_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Metadata => {
+                            if metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("metadata"));
+                            }
+                            metadata__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(proposal::SetIbcConnectionMetadata {
+                    connection_id: connection_id__.unwrap_or_default(),
+                    metadata: metadata__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.SetIbcConnectionMetadata", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for proposal::Signaling {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -4842,6 +5206,9 @@ impl serde::Serialize for ProposalKind {
             Self::UpgradePlan => "PROPOSAL_KIND_UPGRADE_PLAN",
             Self::FreezeIbcClient => "PROPOSAL_KIND_FREEZE_IBC_CLIENT",
             Self::UnfreezeIbcClient => "PROPOSAL_KIND_UNFREEZE_IBC_CLIENT",
+            Self::RecoverIbcClient => "PROPOSAL_KIND_RECOVER_IBC_CLIENT",
+            Self::SetIbcConnectionMetadata => "PROPOSAL_KIND_SET_IBC_CONNECTION_METADATA",
+            Self::RegisterAssetMetadata => "PROPOSAL_KIND_REGISTER_ASSET_METADATA",
         };
         serializer.serialize_str(variant)
     }
@@ -4861,6 +5228,9 @@ impl<'de> serde::Deserialize<'de> for ProposalKind {
             "PROPOSAL_KIND_UPGRADE_PLAN",
             "PROPOSAL_KIND_FREEZE_IBC_CLIENT",
             "PROPOSAL_KIND_UNFREEZE_IBC_CLIENT",
+            "PROPOSAL_KIND_RECOVER_IBC_CLIENT",
+            "PROPOSAL_KIND_SET_IBC_CONNECTION_METADATA",
+            "PROPOSAL_KIND_REGISTER_ASSET_METADATA",
         ];
 
         struct GeneratedVisitor;
@@ -4909,6 +5279,9 @@ impl<'de> serde::Deserialize<'de> for ProposalKind {
                     "PROPOSAL_KIND_UPGRADE_PLAN" => Ok(ProposalKind::UpgradePlan),
                     "PROPOSAL_KIND_FREEZE_IBC_CLIENT" => Ok(ProposalKind::FreezeIbcClient),
                     "PROPOSAL_KIND_UNFREEZE_IBC_CLIENT" => Ok(ProposalKind::UnfreezeIbcClient),
+                    "PROPOSAL_KIND_RECOVER_IBC_CLIENT" => Ok(ProposalKind::RecoverIbcClient),
+                    "PROPOSAL_KIND_SET_IBC_CONNECTION_METADATA" => Ok(ProposalKind::SetIbcConnectionMetadata),
+                    "PROPOSAL_KIND_REGISTER_ASSET_METADATA" => Ok(ProposalKind::RegisterAssetMetadata),
                     _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
                 }
             }
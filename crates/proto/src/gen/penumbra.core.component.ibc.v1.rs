@@ -73,6 +73,10 @@ pub struct Ics20Withdrawal {
     /// for compatability with chains that expect to be able to parse the return address as bech32.
     #[prost(bool, tag = "8")]
     pub use_compat_address: bool,
+    /// Fees escrowed for the relayers of the transfer packet, if the source
+    /// channel supports ICS-29 fee incentivization.
+    #[prost(message, optional, tag = "9")]
+    pub relay_fee: ::core::option::Option<Fee>,
 }
 impl ::prost::Name for Ics20Withdrawal {
     const NAME: &'static str = "Ics20Withdrawal";
@@ -81,6 +85,47 @@ impl ::prost::Name for Ics20Withdrawal {
         ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
     }
 }
+/// A Penumbra transaction action requesting an ICS-721 transfer of non-fungible tokens.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NftWithdrawal {
+    /// The class of the tokens to transfer.
+    #[prost(string, tag = "1")]
+    pub class_id: ::prost::alloc::string::String,
+    /// The tokens of the class to transfer.
+    #[prost(string, repeated, tag = "2")]
+    pub token_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// the address on the destination chain to send the tokens to
+    #[prost(string, tag = "3")]
+    pub destination_chain_address: ::prost::alloc::string::String,
+    /// the penumbra address to return the tokens to if the transfer fails.
+    /// this should be an ephemeral address
+    #[prost(message, optional, tag = "4")]
+    pub return_address: ::core::option::Option<super::super::super::keys::v1::Address>,
+    /// The height on the counterparty chain at which this transfer expires, and
+    /// tokens are sent back to the return address.
+    #[prost(message, optional, tag = "5")]
+    pub timeout_height: ::core::option::Option<
+        ::ibc_proto::ibc::core::client::v1::Height,
+    >,
+    /// The timestamp, in epoch time, after which this transfer will be considered invalid.
+    /// Clients must quantize this value to the nearest minute, to preserve privacy.
+    #[prost(uint64, tag = "6")]
+    pub timeout_time: u64,
+    /// The source channel used for the withdrawal
+    #[prost(string, tag = "7")]
+    pub source_channel: ::prost::alloc::string::String,
+    /// optional memo
+    #[prost(string, tag = "8")]
+    pub memo: ::prost::alloc::string::String,
+}
+impl ::prost::Name for NftWithdrawal {
+    const NAME: &'static str = "NftWithdrawal";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ClientData {
@@ -179,6 +224,32 @@ pub struct IbcParameters {
     /// Whether outbound ICS-20 transfers are enabled
     #[prost(bool, tag = "3")]
     pub outbound_ics20_transfers_enabled: bool,
+    /// The maximum expected time between blocks, in nanoseconds, used to convert
+    /// a connection's delay period into a number of blocks.
+    #[prost(uint64, tag = "4")]
+    pub max_expected_time_per_block: u64,
+    /// Quotas on the value of ICS-20 transfers over individual channels.
+    #[prost(message, repeated, tag = "5")]
+    pub ics20_rate_limits: ::prost::alloc::vec::Vec<Ics20RateLimit>,
+    /// If non-empty, only inbound ICS-20 transfers matching one of these filters
+    /// are accepted.
+    #[prost(message, repeated, tag = "6")]
+    pub inbound_ics20_allowlist: ::prost::alloc::vec::Vec<Ics20AssetFilter>,
+    /// Inbound ICS-20 transfers matching any of these filters are rejected.
+    #[prost(message, repeated, tag = "7")]
+    pub inbound_ics20_denylist: ::prost::alloc::vec::Vec<Ics20AssetFilter>,
+    /// The number of blocks the acknowledgements of received packets are kept
+    /// for before being pruned, or 0 to keep them forever.
+    #[prost(uint64, tag = "8")]
+    pub ack_retention_blocks: u64,
+    /// The layouts of counterparty state that clients may be created for. A
+    /// client is only created if its proof specs match one of these templates.
+    /// If empty, the layouts of Cosmos SDK and Penumbra chains are accepted.
+    #[prost(message, repeated, tag = "9")]
+    pub accepted_proof_spec_templates: ::prost::alloc::vec::Vec<ProofSpecTemplate>,
+    /// The type URLs of the messages that interchain accounts may execute.
+    #[prost(string, repeated, tag = "10")]
+    pub ica_allowed_messages: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
 impl ::prost::Name for IbcParameters {
     const NAME: &'static str = "IbcParameters";
@@ -202,3 +273,905 @@ impl ::prost::Name for GenesisContent {
         ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
     }
 }
+/// Counts of successful and failed proof verifications of a single kind.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VerificationCounts {
+    #[prost(uint64, tag = "1")]
+    pub succeeded: u64,
+    #[prost(uint64, tag = "2")]
+    pub failed: u64,
+}
+impl ::prost::Name for VerificationCounts {
+    const NAME: &'static str = "VerificationCounts";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
+    }
+}
+/// Proof verification statistics for a single client, bucketed by the kind of
+/// proof being verified.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClientVerificationStats {
+    #[prost(message, optional, tag = "1")]
+    pub client_upgrade: ::core::option::Option<VerificationCounts>,
+    #[prost(message, optional, tag = "2")]
+    pub channel: ::core::option::Option<VerificationCounts>,
+    #[prost(message, optional, tag = "3")]
+    pub packet_recv: ::core::option::Option<VerificationCounts>,
+    #[prost(message, optional, tag = "4")]
+    pub packet_ack: ::core::option::Option<VerificationCounts>,
+    #[prost(message, optional, tag = "5")]
+    pub packet_timeout: ::core::option::Option<VerificationCounts>,
+    #[prost(message, optional, tag = "6")]
+    pub query: ::core::option::Option<VerificationCounts>,
+}
+impl ::prost::Name for ClientVerificationStats {
+    const NAME: &'static str = "ClientVerificationStats";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
+    }
+}
+/// Requests the proof verification statistics recorded for a client.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClientVerificationStatsRequest {
+    /// The identifier of the client, e.g. `07-tendermint-0`.
+    #[prost(string, tag = "1")]
+    pub client_id: ::prost::alloc::string::String,
+}
+impl ::prost::Name for ClientVerificationStatsRequest {
+    const NAME: &'static str = "ClientVerificationStatsRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClientVerificationStatsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub stats: ::core::option::Option<ClientVerificationStats>,
+}
+impl ::prost::Name for ClientVerificationStatsResponse {
+    const NAME: &'static str = "ClientVerificationStatsResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
+    }
+}
+/// Requests a simulation of a client update followed by a packet receipt.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SimulateUpdateAndRecvRequest {
+    /// The `MsgUpdateClient` to apply, in the same encoding as an `IbcRelay` action.
+    #[prost(message, optional, tag = "1")]
+    pub update_client: ::core::option::Option<::pbjson_types::Any>,
+    /// The `MsgRecvPacket` whose proof should be verified against the updated client.
+    #[prost(message, optional, tag = "2")]
+    pub recv_packet: ::core::option::Option<::pbjson_types::Any>,
+}
+impl ::prost::Name for SimulateUpdateAndRecvRequest {
+    const NAME: &'static str = "SimulateUpdateAndRecvRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SimulateUpdateAndRecvResponse {
+    /// Whether the client update was applied.
+    #[prost(bool, tag = "1")]
+    pub update_applied: bool,
+    /// Whether the packet proof verified against the updated client.
+    #[prost(bool, tag = "2")]
+    pub packet_verified: bool,
+    /// If either step failed, a description of the failure.
+    #[prost(string, tag = "3")]
+    pub error: ::prost::alloc::string::String,
+}
+impl ::prost::Name for SimulateUpdateAndRecvResponse {
+    const NAME: &'static str = "SimulateUpdateAndRecvResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
+    }
+}
+/// The fees paid to relayers for relaying a packet, as defined by ICS-29.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Fee {
+    /// The fee paid to the relayer that relays the packet to the counterparty.
+    #[prost(message, optional, tag = "1")]
+    pub recv_fee: ::core::option::Option<super::super::super::asset::v1::Value>,
+    /// The fee paid to the relayer that relays the acknowledgement back.
+    #[prost(message, optional, tag = "2")]
+    pub ack_fee: ::core::option::Option<super::super::super::asset::v1::Value>,
+    /// The fee paid to the relayer that relays the timeout back.
+    #[prost(message, optional, tag = "3")]
+    pub timeout_fee: ::core::option::Option<super::super::super::asset::v1::Value>,
+}
+impl ::prost::Name for Fee {
+    const NAME: &'static str = "Fee";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
+    }
+}
+/// The fees escrowed for a packet, along with where to refund unspent fees.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PacketFee {
+    #[prost(message, optional, tag = "1")]
+    pub fee: ::core::option::Option<Fee>,
+    /// The Penumbra address that unpaid fees are refunded to.
+    #[prost(string, tag = "2")]
+    pub refund_address: ::prost::alloc::string::String,
+}
+impl ::prost::Name for PacketFee {
+    const NAME: &'static str = "PacketFee";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
+    }
+}
+/// The fees escrowed for a packet, identified by the packet's source.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IdentifiedPacketFee {
+    #[prost(string, tag = "1")]
+    pub port_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub channel_id: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "3")]
+    pub sequence: u64,
+    #[prost(message, optional, tag = "4")]
+    pub packet_fee: ::core::option::Option<PacketFee>,
+}
+impl ::prost::Name for IdentifiedPacketFee {
+    const NAME: &'static str = "IdentifiedPacketFee";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
+    }
+}
+/// Requests the packets with escrowed fees on a channel.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IncentivizedPacketsRequest {
+    #[prost(string, tag = "1")]
+    pub port_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub channel_id: ::prost::alloc::string::String,
+}
+impl ::prost::Name for IncentivizedPacketsRequest {
+    const NAME: &'static str = "IncentivizedPacketsRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IncentivizedPacketsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub incentivized_packets: ::prost::alloc::vec::Vec<IdentifiedPacketFee>,
+}
+impl ::prost::Name for IncentivizedPacketsResponse {
+    const NAME: &'static str = "IncentivizedPacketsResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
+    }
+}
+/// A quota on the amount of one asset that ICS-20 transfers may move over one
+/// channel within a window of blocks.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20RateLimit {
+    /// The channel the quota applies to, as named on this chain.
+    #[prost(string, tag = "1")]
+    pub channel_id: ::prost::alloc::string::String,
+    /// The asset the quota applies to.
+    #[prost(message, optional, tag = "2")]
+    pub asset_id: ::core::option::Option<super::super::super::asset::v1::AssetId>,
+    /// The maximum amount that may be sent over the channel in a window.
+    #[prost(message, optional, tag = "3")]
+    pub max_outflow: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The maximum amount that may be received over the channel in a window.
+    #[prost(message, optional, tag = "4")]
+    pub max_inflow: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The length of a window, in blocks.
+    #[prost(uint64, tag = "5")]
+    pub window_blocks: u64,
+}
+impl ::prost::Name for Ics20RateLimit {
+    const NAME: &'static str = "Ics20RateLimit";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
+    }
+}
+/// The amounts of an asset transferred over a rate-limited channel in the
+/// current window.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20Flow {
+    /// The height of the first block of the window.
+    #[prost(uint64, tag = "1")]
+    pub window_start_height: u64,
+    #[prost(message, optional, tag = "2")]
+    pub inflow: ::core::option::Option<super::super::super::num::v1::Amount>,
+    #[prost(message, optional, tag = "3")]
+    pub outflow: ::core::option::Option<super::super::super::num::v1::Amount>,
+}
+impl ::prost::Name for Ics20Flow {
+    const NAME: &'static str = "Ics20Flow";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
+    }
+}
+/// Matches inbound ICS-20 transfers by the channel they're received over, the
+/// denom they're credited in, or both.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20AssetFilter {
+    /// The channel, as named on this chain, or empty to match any channel.
+    #[prost(string, tag = "1")]
+    pub channel_id: ::prost::alloc::string::String,
+    /// The denom trace of the asset as named on this chain, e.g.
+    /// `transfer/channel-0/uatom`, or empty to match any denom.
+    #[prost(string, tag = "2")]
+    pub denom: ::prost::alloc::string::String,
+}
+impl ::prost::Name for Ics20AssetFilter {
+    const NAME: &'static str = "Ics20AssetFilter";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
+    }
+}
+/// A named set of ICS-23 proof specs, describing how the state of a kind of
+/// counterparty chain is committed to.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProofSpecTemplate {
+    /// A name for the template, e.g. `cosmos-sdk`.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// The proof specs a client's proof specs must equal to match the template.
+    #[prost(message, repeated, tag = "2")]
+    pub proof_specs: ::prost::alloc::vec::Vec<::ics23::ProofSpec>,
+}
+impl ::prost::Name for ProofSpecTemplate {
+    const NAME: &'static str = "ProofSpecTemplate";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
+    }
+}
+/// Information about the chain at the other end of a connection, registered by
+/// governance so that wallets can label channels, and so that proofs relayed
+/// over the connection can be held to a stricter freshness policy.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ConnectionMetadata {
+    /// The chain id of the counterparty chain, e.g. `osmosis-1`.
+    #[prost(string, tag = "1")]
+    pub counterparty_chain_id: ::prost::alloc::string::String,
+    /// The name to display for the counterparty chain, e.g. `Osmosis`.
+    #[prost(string, tag = "2")]
+    pub display_name: ::prost::alloc::string::String,
+    /// The channels over the connection, as named on this chain, that wallets
+    /// should prefer for transfers to the counterparty chain.
+    #[prost(string, repeated, tag = "3")]
+    pub preferred_channels: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// The maximum age, in nanoseconds, of a consensus state that proofs relayed
+    /// over the connection may be verified against. Unset means that only the
+    /// client's trusting period applies.
+    #[prost(uint64, optional, tag = "4")]
+    pub max_consensus_age: ::core::option::Option<u64>,
+    /// The keys, from the outermost store inwards, under which the counterparty
+    /// chain commits its IBC state, for counterparties whose IBC store is nested
+    /// more than one level deep. Empty means that the commitment prefix of the
+    /// connection's counterparty is applied as a single key.
+    #[prost(bytes = "vec", repeated, tag = "5")]
+    pub counterparty_prefix_path: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+impl ::prost::Name for ConnectionMetadata {
+    const NAME: &'static str = "ConnectionMetadata";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
+    }
+}
+/// The metadata registered for a connection.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IdentifiedConnectionMetadata {
+    #[prost(string, tag = "1")]
+    pub connection_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub metadata: ::core::option::Option<ConnectionMetadata>,
+}
+impl ::prost::Name for IdentifiedConnectionMetadata {
+    const NAME: &'static str = "IdentifiedConnectionMetadata";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
+    }
+}
+/// Requests the metadata registered for connections.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ConnectionMetadataRequest {
+    /// The identifier of the connection, e.g. `connection-0`, or empty to request
+    /// the metadata of every connection that has any.
+    #[prost(string, tag = "1")]
+    pub connection_id: ::prost::alloc::string::String,
+}
+impl ::prost::Name for ConnectionMetadataRequest {
+    const NAME: &'static str = "ConnectionMetadataRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ConnectionMetadataResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub connection_metadata: ::prost::alloc::vec::Vec<IdentifiedConnectionMetadata>,
+}
+impl ::prost::Name for ConnectionMetadataResponse {
+    const NAME: &'static str = "ConnectionMetadataResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
+    }
+}
+/// Generated client implementations.
+#[cfg(feature = "rpc")]
+pub mod query_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// Query operations for the IBC component.
+    #[derive(Debug, Clone)]
+    pub struct QueryServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl QueryServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> QueryServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> QueryServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            QueryServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// Returns the proof verification statistics recorded for a client.
+        pub async fn client_verification_stats(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ClientVerificationStatsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ClientVerificationStatsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.ibc.v1.QueryService/ClientVerificationStats",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("penumbra.core.component.ibc.v1.QueryService", "ClientVerificationStats"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Simulates applying a client update followed by receiving a packet whose
+        /// proof is checked against the updated client, without committing anything.
+        pub async fn simulate_update_and_recv(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SimulateUpdateAndRecvRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SimulateUpdateAndRecvResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.ibc.v1.QueryService/SimulateUpdateAndRecv",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("penumbra.core.component.ibc.v1.QueryService", "SimulateUpdateAndRecv"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Returns the fees escrowed for the unrelayed packets sent over a channel.
+        pub async fn incentivized_packets(
+            &mut self,
+            request: impl tonic::IntoRequest<super::IncentivizedPacketsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::IncentivizedPacketsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.ibc.v1.QueryService/IncentivizedPackets",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("penumbra.core.component.ibc.v1.QueryService", "IncentivizedPackets"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Returns the counterparty chain information registered for connections.
+        pub async fn connection_metadata(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ConnectionMetadataRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ConnectionMetadataResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.ibc.v1.QueryService/ConnectionMetadata",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("penumbra.core.component.ibc.v1.QueryService", "ConnectionMetadata"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "rpc")]
+pub mod query_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with QueryServiceServer.
+    #[async_trait]
+    pub trait QueryService: Send + Sync + 'static {
+        /// Returns the proof verification statistics recorded for a client.
+        async fn client_verification_stats(
+            &self,
+            request: tonic::Request<super::ClientVerificationStatsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ClientVerificationStatsResponse>,
+            tonic::Status,
+        >;
+        /// Simulates applying a client update followed by receiving a packet whose
+        /// proof is checked against the updated client, without committing anything.
+        async fn simulate_update_and_recv(
+            &self,
+            request: tonic::Request<super::SimulateUpdateAndRecvRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SimulateUpdateAndRecvResponse>,
+            tonic::Status,
+        >;
+        /// Returns the fees escrowed for the unrelayed packets sent over a channel.
+        async fn incentivized_packets(
+            &self,
+            request: tonic::Request<super::IncentivizedPacketsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::IncentivizedPacketsResponse>,
+            tonic::Status,
+        >;
+        /// Returns the counterparty chain information registered for connections.
+        async fn connection_metadata(
+            &self,
+            request: tonic::Request<super::ConnectionMetadataRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ConnectionMetadataResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Query operations for the IBC component.
+    #[derive(Debug)]
+    pub struct QueryServiceServer<T: QueryService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: QueryService> QueryServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for QueryServiceServer<T>
+    where
+        T: QueryService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/penumbra.core.component.ibc.v1.QueryService/ClientVerificationStats" => {
+                    #[allow(non_camel_case_types)]
+                    struct ClientVerificationStatsSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::ClientVerificationStatsRequest>
+                    for ClientVerificationStatsSvc<T> {
+                        type Response = super::ClientVerificationStatsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ClientVerificationStatsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::client_verification_stats(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ClientVerificationStatsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.ibc.v1.QueryService/SimulateUpdateAndRecv" => {
+                    #[allow(non_camel_case_types)]
+                    struct SimulateUpdateAndRecvSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::SimulateUpdateAndRecvRequest>
+                    for SimulateUpdateAndRecvSvc<T> {
+                        type Response = super::SimulateUpdateAndRecvResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SimulateUpdateAndRecvRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::simulate_update_and_recv(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SimulateUpdateAndRecvSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.ibc.v1.QueryService/IncentivizedPackets" => {
+                    #[allow(non_camel_case_types)]
+                    struct IncentivizedPacketsSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::IncentivizedPacketsRequest>
+                    for IncentivizedPacketsSvc<T> {
+                        type Response = super::IncentivizedPacketsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::IncentivizedPacketsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::incentivized_packets(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = IncentivizedPacketsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.ibc.v1.QueryService/ConnectionMetadata" => {
+                    #[allow(non_camel_case_types)]
+                    struct ConnectionMetadataSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::ConnectionMetadataRequest>
+                    for ConnectionMetadataSvc<T> {
+                        type Response = super::ConnectionMetadataResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ConnectionMetadataRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::connection_metadata(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ConnectionMetadataSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: QueryService> Clone for QueryServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: QueryService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: QueryService> tonic::server::NamedService for QueryServiceServer<T> {
+        const NAME: &'static str = "penumbra.core.component.ibc.v1.QueryService";
+    }
+}
@@ -344,7 +344,7 @@ impl<'de> serde::Deserialize<'de> for ClientData {
         deserializer.deserialize_struct("penumbra.core.component.ibc.v1.ClientData", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ConnectionCounter {
+impl serde::Serialize for ClientVerificationStats {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -352,30 +352,73 @@ impl serde::Serialize for ConnectionCounter {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.counter != 0 {
+        if self.client_upgrade.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.ConnectionCounter", len)?;
-        if self.counter != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("counter", ToString::to_string(&self.counter).as_str())?;
+        if self.channel.is_some() {
+            len += 1;
+        }
+        if self.packet_recv.is_some() {
+            len += 1;
+        }
+        if self.packet_ack.is_some() {
+            len += 1;
+        }
+        if self.packet_timeout.is_some() {
+            len += 1;
+        }
+        if self.query.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.ClientVerificationStats", len)?;
+        if let Some(v) = self.client_upgrade.as_ref() {
+            struct_ser.serialize_field("clientUpgrade", v)?;
+        }
+        if let Some(v) = self.channel.as_ref() {
+            struct_ser.serialize_field("channel", v)?;
+        }
+        if let Some(v) = self.packet_recv.as_ref() {
+            struct_ser.serialize_field("packetRecv", v)?;
+        }
+        if let Some(v) = self.packet_ack.as_ref() {
+            struct_ser.serialize_field("packetAck", v)?;
+        }
+        if let Some(v) = self.packet_timeout.as_ref() {
+            struct_ser.serialize_field("packetTimeout", v)?;
+        }
+        if let Some(v) = self.query.as_ref() {
+            struct_ser.serialize_field("query", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ConnectionCounter {
+impl<'de> serde::Deserialize<'de> for ClientVerificationStats {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "counter",
+            "client_upgrade",
+            "clientUpgrade",
+            "channel",
+            "packet_recv",
+            "packetRecv",
+            "packet_ack",
+            "packetAck",
+            "packet_timeout",
+            "packetTimeout",
+            "query",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Counter,
+            ClientUpgrade,
+            Channel,
+            PacketRecv,
+            PacketAck,
+            PacketTimeout,
+            Query,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -398,7 +441,12 @@ impl<'de> serde::Deserialize<'de> for ConnectionCounter {
                         E: serde::de::Error,
                     {
                         match value {
-                            "counter" => Ok(GeneratedField::Counter),
+                            "clientUpgrade" | "client_upgrade" => Ok(GeneratedField::ClientUpgrade),
+                            "channel" => Ok(GeneratedField::Channel),
+                            "packetRecv" | "packet_recv" => Ok(GeneratedField::PacketRecv),
+                            "packetAck" | "packet_ack" => Ok(GeneratedField::PacketAck),
+                            "packetTimeout" | "packet_timeout" => Ok(GeneratedField::PacketTimeout),
+                            "query" => Ok(GeneratedField::Query),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -408,41 +456,79 @@ impl<'de> serde::Deserialize<'de> for ConnectionCounter {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ConnectionCounter;
+            type Value = ClientVerificationStats;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.ibc.v1.ConnectionCounter")
+                formatter.write_str("struct penumbra.core.component.ibc.v1.ClientVerificationStats")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ConnectionCounter, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ClientVerificationStats, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut counter__ = None;
+                let mut client_upgrade__ = None;
+                let mut channel__ = None;
+                let mut packet_recv__ = None;
+                let mut packet_ack__ = None;
+                let mut packet_timeout__ = None;
+                let mut query__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Counter => {
-                            if counter__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("counter"));
+                        GeneratedField::ClientUpgrade => {
+                            if client_upgrade__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("clientUpgrade"));
                             }
-                            counter__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            client_upgrade__ = map_.next_value()?;
+                        }
+                        GeneratedField::Channel => {
+                            if channel__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("channel"));
+                            }
+                            channel__ = map_.next_value()?;
+                        }
+                        GeneratedField::PacketRecv => {
+                            if packet_recv__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("packetRecv"));
+                            }
+                            packet_recv__ = map_.next_value()?;
+                        }
+                        GeneratedField::PacketAck => {
+                            if packet_ack__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("packetAck"));
+                            }
+                            packet_ack__ = map_.next_value()?;
+                        }
+                        GeneratedField::PacketTimeout => {
+                            if packet_timeout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("packetTimeout"));
+                            }
+                            packet_timeout__ = map_.next_value()?;
+                        }
+                        GeneratedField::Query => {
+                            if query__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("query"));
+                            }
+                            query__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ConnectionCounter {
-                    counter: counter__.unwrap_or_default(),
+                Ok(ClientVerificationStats {
+                    client_upgrade: client_upgrade__,
+                    channel: channel__,
+                    packet_recv: packet_recv__,
+                    packet_ack: packet_ack__,
+                    packet_timeout: packet_timeout__,
+                    query: query__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.ConnectionCounter", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.ClientVerificationStats", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ConsensusState {
+impl serde::Serialize for ClientVerificationStatsRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -450,30 +536,30 @@ impl serde::Serialize for ConsensusState {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.consensus_state.is_some() {
+        if !self.client_id.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.ConsensusState", len)?;
-        if let Some(v) = self.consensus_state.as_ref() {
-            struct_ser.serialize_field("consensusState", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.ClientVerificationStatsRequest", len)?;
+        if !self.client_id.is_empty() {
+            struct_ser.serialize_field("clientId", &self.client_id)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ConsensusState {
+impl<'de> serde::Deserialize<'de> for ClientVerificationStatsRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "consensus_state",
-            "consensusState",
+            "client_id",
+            "clientId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            ConsensusState,
+            ClientId,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -496,7 +582,7 @@ impl<'de> serde::Deserialize<'de> for ConsensusState {
                         E: serde::de::Error,
                     {
                         match value {
-                            "consensusState" | "consensus_state" => Ok(GeneratedField::ConsensusState),
+                            "clientId" | "client_id" => Ok(GeneratedField::ClientId),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -506,39 +592,39 @@ impl<'de> serde::Deserialize<'de> for ConsensusState {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ConsensusState;
+            type Value = ClientVerificationStatsRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.ibc.v1.ConsensusState")
+                formatter.write_str("struct penumbra.core.component.ibc.v1.ClientVerificationStatsRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ConsensusState, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ClientVerificationStatsRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut consensus_state__ = None;
+                let mut client_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::ConsensusState => {
-                            if consensus_state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("consensusState"));
+                        GeneratedField::ClientId => {
+                            if client_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("clientId"));
                             }
-                            consensus_state__ = map_.next_value()?;
+                            client_id__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ConsensusState {
-                    consensus_state: consensus_state__,
+                Ok(ClientVerificationStatsRequest {
+                    client_id: client_id__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.ConsensusState", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.ClientVerificationStatsRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for FungibleTokenPacketData {
+impl serde::Serialize for ClientVerificationStatsResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -546,61 +632,29 @@ impl serde::Serialize for FungibleTokenPacketData {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.denom.is_empty() {
-            len += 1;
-        }
-        if !self.amount.is_empty() {
-            len += 1;
-        }
-        if !self.sender.is_empty() {
-            len += 1;
-        }
-        if !self.receiver.is_empty() {
-            len += 1;
-        }
-        if !self.memo.is_empty() {
+        if self.stats.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.FungibleTokenPacketData", len)?;
-        if !self.denom.is_empty() {
-            struct_ser.serialize_field("denom", &self.denom)?;
-        }
-        if !self.amount.is_empty() {
-            struct_ser.serialize_field("amount", &self.amount)?;
-        }
-        if !self.sender.is_empty() {
-            struct_ser.serialize_field("sender", &self.sender)?;
-        }
-        if !self.receiver.is_empty() {
-            struct_ser.serialize_field("receiver", &self.receiver)?;
-        }
-        if !self.memo.is_empty() {
-            struct_ser.serialize_field("memo", &self.memo)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.ClientVerificationStatsResponse", len)?;
+        if let Some(v) = self.stats.as_ref() {
+            struct_ser.serialize_field("stats", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for FungibleTokenPacketData {
+impl<'de> serde::Deserialize<'de> for ClientVerificationStatsResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "denom",
-            "amount",
-            "sender",
-            "receiver",
-            "memo",
+            "stats",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Denom,
-            Amount,
-            Sender,
-            Receiver,
-            Memo,
+            Stats,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -623,11 +677,7 @@ impl<'de> serde::Deserialize<'de> for FungibleTokenPacketData {
                         E: serde::de::Error,
                     {
                         match value {
-                            "denom" => Ok(GeneratedField::Denom),
-                            "amount" => Ok(GeneratedField::Amount),
-                            "sender" => Ok(GeneratedField::Sender),
-                            "receiver" => Ok(GeneratedField::Receiver),
-                            "memo" => Ok(GeneratedField::Memo),
+                            "stats" => Ok(GeneratedField::Stats),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -637,71 +687,39 @@ impl<'de> serde::Deserialize<'de> for FungibleTokenPacketData {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = FungibleTokenPacketData;
+            type Value = ClientVerificationStatsResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.ibc.v1.FungibleTokenPacketData")
+                formatter.write_str("struct penumbra.core.component.ibc.v1.ClientVerificationStatsResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FungibleTokenPacketData, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ClientVerificationStatsResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut denom__ = None;
-                let mut amount__ = None;
-                let mut sender__ = None;
-                let mut receiver__ = None;
-                let mut memo__ = None;
+                let mut stats__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Denom => {
-                            if denom__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("denom"));
-                            }
-                            denom__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::Amount => {
-                            if amount__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("amount"));
-                            }
-                            amount__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::Sender => {
-                            if sender__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("sender"));
-                            }
-                            sender__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::Receiver => {
-                            if receiver__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("receiver"));
-                            }
-                            receiver__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::Memo => {
-                            if memo__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("memo"));
+                        GeneratedField::Stats => {
+                            if stats__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stats"));
                             }
-                            memo__ = Some(map_.next_value()?);
+                            stats__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(FungibleTokenPacketData {
-                    denom: denom__.unwrap_or_default(),
-                    amount: amount__.unwrap_or_default(),
-                    sender: sender__.unwrap_or_default(),
-                    receiver: receiver__.unwrap_or_default(),
-                    memo: memo__.unwrap_or_default(),
+                Ok(ClientVerificationStatsResponse {
+                    stats: stats__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.FungibleTokenPacketData", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.ClientVerificationStatsResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for GenesisContent {
+impl serde::Serialize for ConnectionCounter {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -709,30 +727,30 @@ impl serde::Serialize for GenesisContent {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.ibc_params.is_some() {
+        if self.counter != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.GenesisContent", len)?;
-        if let Some(v) = self.ibc_params.as_ref() {
-            struct_ser.serialize_field("ibcParams", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.ConnectionCounter", len)?;
+        if self.counter != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("counter", ToString::to_string(&self.counter).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for GenesisContent {
+impl<'de> serde::Deserialize<'de> for ConnectionCounter {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "ibc_params",
-            "ibcParams",
+            "counter",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            IbcParams,
+            Counter,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -755,7 +773,7 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
                         E: serde::de::Error,
                     {
                         match value {
-                            "ibcParams" | "ibc_params" => Ok(GeneratedField::IbcParams),
+                            "counter" => Ok(GeneratedField::Counter),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -765,39 +783,41 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = GenesisContent;
+            type Value = ConnectionCounter;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.ibc.v1.GenesisContent")
+                formatter.write_str("struct penumbra.core.component.ibc.v1.ConnectionCounter")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GenesisContent, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ConnectionCounter, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut ibc_params__ = None;
+                let mut counter__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::IbcParams => {
-                            if ibc_params__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("ibcParams"));
+                        GeneratedField::Counter => {
+                            if counter__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("counter"));
                             }
-                            ibc_params__ = map_.next_value()?;
+                            counter__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(GenesisContent {
-                    ibc_params: ibc_params__,
+                Ok(ConnectionCounter {
+                    counter: counter__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.GenesisContent", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.ConnectionCounter", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for IbcParameters {
+impl serde::Serialize for ConnectionMetadata {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -805,48 +825,67 @@ impl serde::Serialize for IbcParameters {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.ibc_enabled {
+        if !self.counterparty_chain_id.is_empty() {
             len += 1;
         }
-        if self.inbound_ics20_transfers_enabled {
+        if !self.display_name.is_empty() {
             len += 1;
         }
-        if self.outbound_ics20_transfers_enabled {
+        if !self.preferred_channels.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.IbcParameters", len)?;
-        if self.ibc_enabled {
-            struct_ser.serialize_field("ibcEnabled", &self.ibc_enabled)?;
+        if self.max_consensus_age.is_some() {
+            len += 1;
         }
-        if self.inbound_ics20_transfers_enabled {
-            struct_ser.serialize_field("inboundIcs20TransfersEnabled", &self.inbound_ics20_transfers_enabled)?;
+        if !self.counterparty_prefix_path.is_empty() {
+            len += 1;
         }
-        if self.outbound_ics20_transfers_enabled {
-            struct_ser.serialize_field("outboundIcs20TransfersEnabled", &self.outbound_ics20_transfers_enabled)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.ConnectionMetadata", len)?;
+        if !self.counterparty_chain_id.is_empty() {
+            struct_ser.serialize_field("counterpartyChainId", &self.counterparty_chain_id)?;
+        }
+        if !self.display_name.is_empty() {
+            struct_ser.serialize_field("displayName", &self.display_name)?;
+        }
+        if !self.preferred_channels.is_empty() {
+            struct_ser.serialize_field("preferredChannels", &self.preferred_channels)?;
+        }
+        if let Some(v) = self.max_consensus_age.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("maxConsensusAge", ToString::to_string(&v).as_str())?;
+        }
+        if !self.counterparty_prefix_path.is_empty() {
+            struct_ser.serialize_field("counterpartyPrefixPath", &self.counterparty_prefix_path.iter().map(pbjson::private::base64::encode).collect::<Vec<_>>())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for IbcParameters {
+impl<'de> serde::Deserialize<'de> for ConnectionMetadata {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "ibc_enabled",
-            "ibcEnabled",
-            "inbound_ics20_transfers_enabled",
-            "inboundIcs20TransfersEnabled",
-            "outbound_ics20_transfers_enabled",
-            "outboundIcs20TransfersEnabled",
+            "counterparty_chain_id",
+            "counterpartyChainId",
+            "display_name",
+            "displayName",
+            "preferred_channels",
+            "preferredChannels",
+            "max_consensus_age",
+            "maxConsensusAge",
+            "counterparty_prefix_path",
+            "counterpartyPrefixPath",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            IbcEnabled,
-            InboundIcs20TransfersEnabled,
-            OutboundIcs20TransfersEnabled,
+            CounterpartyChainId,
+            DisplayName,
+            PreferredChannels,
+            MaxConsensusAge,
+            CounterpartyPrefixPath,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -869,9 +908,11 @@ impl<'de> serde::Deserialize<'de> for IbcParameters {
                         E: serde::de::Error,
                     {
                         match value {
-                            "ibcEnabled" | "ibc_enabled" => Ok(GeneratedField::IbcEnabled),
-                            "inboundIcs20TransfersEnabled" | "inbound_ics20_transfers_enabled" => Ok(GeneratedField::InboundIcs20TransfersEnabled),
-                            "outboundIcs20TransfersEnabled" | "outbound_ics20_transfers_enabled" => Ok(GeneratedField::OutboundIcs20TransfersEnabled),
+                            "counterpartyChainId" | "counterparty_chain_id" => Ok(GeneratedField::CounterpartyChainId),
+                            "displayName" | "display_name" => Ok(GeneratedField::DisplayName),
+                            "preferredChannels" | "preferred_channels" => Ok(GeneratedField::PreferredChannels),
+                            "maxConsensusAge" | "max_consensus_age" => Ok(GeneratedField::MaxConsensusAge),
+                            "counterpartyPrefixPath" | "counterparty_prefix_path" => Ok(GeneratedField::CounterpartyPrefixPath),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -881,55 +922,2696 @@ impl<'de> serde::Deserialize<'de> for IbcParameters {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = IbcParameters;
+            type Value = ConnectionMetadata;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.ibc.v1.IbcParameters")
+                formatter.write_str("struct penumbra.core.component.ibc.v1.ConnectionMetadata")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IbcParameters, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ConnectionMetadata, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut ibc_enabled__ = None;
-                let mut inbound_ics20_transfers_enabled__ = None;
-                let mut outbound_ics20_transfers_enabled__ = None;
+                let mut counterparty_chain_id__ = None;
+                let mut display_name__ = None;
+                let mut preferred_channels__ = None;
+                let mut max_consensus_age__ = None;
+                let mut counterparty_prefix_path__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::IbcEnabled => {
-                            if ibc_enabled__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("ibcEnabled"));
+                        GeneratedField::CounterpartyChainId => {
+                            if counterparty_chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("counterpartyChainId"));
                             }
-                            ibc_enabled__ = Some(map_.next_value()?);
+                            counterparty_chain_id__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::InboundIcs20TransfersEnabled => {
+                        GeneratedField::DisplayName => {
+                            if display_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("displayName"));
+                            }
+                            display_name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PreferredChannels => {
+                            if preferred_channels__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("preferredChannels"));
+                            }
+                            preferred_channels__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MaxConsensusAge => {
+                            if max_consensus_age__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxConsensusAge"));
+                            }
+                            max_consensus_age__ = map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0);
+                        }
+                        GeneratedField::CounterpartyPrefixPath => {
+                            if counterparty_prefix_path__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("counterpartyPrefixPath"));
+                            }
+                            counterparty_prefix_path__ = 
+                                Some(map_.next_value::<Vec<::pbjson::private::BytesDeserialize<_>>>()?
+                                    .into_iter().map(|x| x.0).collect())
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ConnectionMetadata {
+                    counterparty_chain_id: counterparty_chain_id__.unwrap_or_default(),
+                    display_name: display_name__.unwrap_or_default(),
+                    preferred_channels: preferred_channels__.unwrap_or_default(),
+                    max_consensus_age: max_consensus_age__,
+                    counterparty_prefix_path: counterparty_prefix_path__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.ConnectionMetadata", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ConnectionMetadataRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.connection_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.ConnectionMetadataRequest", len)?;
+        if !self.connection_id.is_empty() {
+            struct_ser.serialize_field("connectionId", &self.connection_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ConnectionMetadataRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "connection_id",
+            "connectionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ConnectionId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "connectionId" | "connection_id" => Ok(GeneratedField::ConnectionId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ConnectionMetadataRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.ibc.v1.ConnectionMetadataRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ConnectionMetadataRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut connection_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ConnectionId => {
+                            if connection_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("connectionId"));
+                            }
+                            connection_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ConnectionMetadataRequest {
+                    connection_id: connection_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.ConnectionMetadataRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ConnectionMetadataResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.connection_metadata.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.ConnectionMetadataResponse", len)?;
+        if !self.connection_metadata.is_empty() {
+            struct_ser.serialize_field("connectionMetadata", &self.connection_metadata)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ConnectionMetadataResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "connection_metadata",
+            "connectionMetadata",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ConnectionMetadata,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "connectionMetadata" | "connection_metadata" => Ok(GeneratedField::ConnectionMetadata),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ConnectionMetadataResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.ibc.v1.ConnectionMetadataResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ConnectionMetadataResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut connection_metadata__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ConnectionMetadata => {
+                            if connection_metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("connectionMetadata"));
+                            }
+                            connection_metadata__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ConnectionMetadataResponse {
+                    connection_metadata: connection_metadata__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.ConnectionMetadataResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ConsensusState {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.consensus_state.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.ConsensusState", len)?;
+        if let Some(v) = self.consensus_state.as_ref() {
+            struct_ser.serialize_field("consensusState", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ConsensusState {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "consensus_state",
+            "consensusState",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ConsensusState,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "consensusState" | "consensus_state" => Ok(GeneratedField::ConsensusState),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ConsensusState;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.ibc.v1.ConsensusState")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ConsensusState, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut consensus_state__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ConsensusState => {
+                            if consensus_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("consensusState"));
+                            }
+                            consensus_state__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ConsensusState {
+                    consensus_state: consensus_state__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.ConsensusState", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Fee {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.recv_fee.is_some() {
+            len += 1;
+        }
+        if self.ack_fee.is_some() {
+            len += 1;
+        }
+        if self.timeout_fee.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.Fee", len)?;
+        if let Some(v) = self.recv_fee.as_ref() {
+            struct_ser.serialize_field("recvFee", v)?;
+        }
+        if let Some(v) = self.ack_fee.as_ref() {
+            struct_ser.serialize_field("ackFee", v)?;
+        }
+        if let Some(v) = self.timeout_fee.as_ref() {
+            struct_ser.serialize_field("timeoutFee", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Fee {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "recv_fee",
+            "recvFee",
+            "ack_fee",
+            "ackFee",
+            "timeout_fee",
+            "timeoutFee",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RecvFee,
+            AckFee,
+            TimeoutFee,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "recvFee" | "recv_fee" => Ok(GeneratedField::RecvFee),
+                            "ackFee" | "ack_fee" => Ok(GeneratedField::AckFee),
+                            "timeoutFee" | "timeout_fee" => Ok(GeneratedField::TimeoutFee),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Fee;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.ibc.v1.Fee")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Fee, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut recv_fee__ = None;
+                let mut ack_fee__ = None;
+                let mut timeout_fee__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RecvFee => {
+                            if recv_fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("recvFee"));
+                            }
+                            recv_fee__ = map_.next_value()?;
+                        }
+                        GeneratedField::AckFee => {
+                            if ack_fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ackFee"));
+                            }
+                            ack_fee__ = map_.next_value()?;
+                        }
+                        GeneratedField::TimeoutFee => {
+                            if timeout_fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timeoutFee"));
+                            }
+                            timeout_fee__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Fee {
+                    recv_fee: recv_fee__,
+                    ack_fee: ack_fee__,
+                    timeout_fee: timeout_fee__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.Fee", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FungibleTokenPacketData {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.denom.is_empty() {
+            len += 1;
+        }
+        if !self.amount.is_empty() {
+            len += 1;
+        }
+        if !self.sender.is_empty() {
+            len += 1;
+        }
+        if !self.receiver.is_empty() {
+            len += 1;
+        }
+        if !self.memo.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.FungibleTokenPacketData", len)?;
+        if !self.denom.is_empty() {
+            struct_ser.serialize_field("denom", &self.denom)?;
+        }
+        if !self.amount.is_empty() {
+            struct_ser.serialize_field("amount", &self.amount)?;
+        }
+        if !self.sender.is_empty() {
+            struct_ser.serialize_field("sender", &self.sender)?;
+        }
+        if !self.receiver.is_empty() {
+            struct_ser.serialize_field("receiver", &self.receiver)?;
+        }
+        if !self.memo.is_empty() {
+            struct_ser.serialize_field("memo", &self.memo)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FungibleTokenPacketData {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "denom",
+            "amount",
+            "sender",
+            "receiver",
+            "memo",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Denom,
+            Amount,
+            Sender,
+            Receiver,
+            Memo,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "denom" => Ok(GeneratedField::Denom),
+                            "amount" => Ok(GeneratedField::Amount),
+                            "sender" => Ok(GeneratedField::Sender),
+                            "receiver" => Ok(GeneratedField::Receiver),
+                            "memo" => Ok(GeneratedField::Memo),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FungibleTokenPacketData;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.ibc.v1.FungibleTokenPacketData")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FungibleTokenPacketData, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut denom__ = None;
+                let mut amount__ = None;
+                let mut sender__ = None;
+                let mut receiver__ = None;
+                let mut memo__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Denom => {
+                            if denom__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("denom"));
+                            }
+                            denom__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Sender => {
+                            if sender__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sender"));
+                            }
+                            sender__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Receiver => {
+                            if receiver__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("receiver"));
+                            }
+                            receiver__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
+                            }
+                            memo__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(FungibleTokenPacketData {
+                    denom: denom__.unwrap_or_default(),
+                    amount: amount__.unwrap_or_default(),
+                    sender: sender__.unwrap_or_default(),
+                    receiver: receiver__.unwrap_or_default(),
+                    memo: memo__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.FungibleTokenPacketData", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GenesisContent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.ibc_params.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.GenesisContent", len)?;
+        if let Some(v) = self.ibc_params.as_ref() {
+            struct_ser.serialize_field("ibcParams", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GenesisContent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "ibc_params",
+            "ibcParams",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IbcParams,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "ibcParams" | "ibc_params" => Ok(GeneratedField::IbcParams),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GenesisContent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.ibc.v1.GenesisContent")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GenesisContent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut ibc_params__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::IbcParams => {
+                            if ibc_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ibcParams"));
+                            }
+                            ibc_params__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(GenesisContent {
+                    ibc_params: ibc_params__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.GenesisContent", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for IbcParameters {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.ibc_enabled {
+            len += 1;
+        }
+        if self.inbound_ics20_transfers_enabled {
+            len += 1;
+        }
+        if self.outbound_ics20_transfers_enabled {
+            len += 1;
+        }
+        if self.max_expected_time_per_block != 0 {
+            len += 1;
+        }
+        if !self.ics20_rate_limits.is_empty() {
+            len += 1;
+        }
+        if !self.inbound_ics20_allowlist.is_empty() {
+            len += 1;
+        }
+        if !self.inbound_ics20_denylist.is_empty() {
+            len += 1;
+        }
+        if self.ack_retention_blocks != 0 {
+            len += 1;
+        }
+        if !self.accepted_proof_spec_templates.is_empty() {
+            len += 1;
+        }
+        if !self.ica_allowed_messages.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.IbcParameters", len)?;
+        if self.ibc_enabled {
+            struct_ser.serialize_field("ibcEnabled", &self.ibc_enabled)?;
+        }
+        if self.inbound_ics20_transfers_enabled {
+            struct_ser.serialize_field("inboundIcs20TransfersEnabled", &self.inbound_ics20_transfers_enabled)?;
+        }
+        if self.outbound_ics20_transfers_enabled {
+            struct_ser.serialize_field("outboundIcs20TransfersEnabled", &self.outbound_ics20_transfers_enabled)?;
+        }
+        if self.max_expected_time_per_block != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("maxExpectedTimePerBlock", ToString::to_string(&self.max_expected_time_per_block).as_str())?;
+        }
+        if !self.ics20_rate_limits.is_empty() {
+            struct_ser.serialize_field("ics20RateLimits", &self.ics20_rate_limits)?;
+        }
+        if !self.inbound_ics20_allowlist.is_empty() {
+            struct_ser.serialize_field("inboundIcs20Allowlist", &self.inbound_ics20_allowlist)?;
+        }
+        if !self.inbound_ics20_denylist.is_empty() {
+            struct_ser.serialize_field("inboundIcs20Denylist", &self.inbound_ics20_denylist)?;
+        }
+        if self.ack_retention_blocks != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("ackRetentionBlocks", ToString::to_string(&self.ack_retention_blocks).as_str())?;
+        }
+        if !self.accepted_proof_spec_templates.is_empty() {
+            struct_ser.serialize_field("acceptedProofSpecTemplates", &self.accepted_proof_spec_templates)?;
+        }
+        if !self.ica_allowed_messages.is_empty() {
+            struct_ser.serialize_field("icaAllowedMessages", &self.ica_allowed_messages)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for IbcParameters {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "ibc_enabled",
+            "ibcEnabled",
+            "inbound_ics20_transfers_enabled",
+            "inboundIcs20TransfersEnabled",
+            "outbound_ics20_transfers_enabled",
+            "outboundIcs20TransfersEnabled",
+            "max_expected_time_per_block",
+            "maxExpectedTimePerBlock",
+            "ics20_rate_limits",
+            "ics20RateLimits",
+            "inbound_ics20_allowlist",
+            "inboundIcs20Allowlist",
+            "inbound_ics20_denylist",
+            "inboundIcs20Denylist",
+            "ack_retention_blocks",
+            "ackRetentionBlocks",
+            "accepted_proof_spec_templates",
+            "acceptedProofSpecTemplates",
+            "ica_allowed_messages",
+            "icaAllowedMessages",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IbcEnabled,
+            InboundIcs20TransfersEnabled,
+            OutboundIcs20TransfersEnabled,
+            MaxExpectedTimePerBlock,
+            Ics20RateLimits,
+            InboundIcs20Allowlist,
+            InboundIcs20Denylist,
+            AckRetentionBlocks,
+            AcceptedProofSpecTemplates,
+            IcaAllowedMessages,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "ibcEnabled" | "ibc_enabled" => Ok(GeneratedField::IbcEnabled),
+                            "inboundIcs20TransfersEnabled" | "inbound_ics20_transfers_enabled" => Ok(GeneratedField::InboundIcs20TransfersEnabled),
+                            "outboundIcs20TransfersEnabled" | "outbound_ics20_transfers_enabled" => Ok(GeneratedField::OutboundIcs20TransfersEnabled),
+                            "maxExpectedTimePerBlock" | "max_expected_time_per_block" => Ok(GeneratedField::MaxExpectedTimePerBlock),
+                            "ics20RateLimits" | "ics20_rate_limits" => Ok(GeneratedField::Ics20RateLimits),
+                            "inboundIcs20Allowlist" | "inbound_ics20_allowlist" => Ok(GeneratedField::InboundIcs20Allowlist),
+                            "inboundIcs20Denylist" | "inbound_ics20_denylist" => Ok(GeneratedField::InboundIcs20Denylist),
+                            "ackRetentionBlocks" | "ack_retention_blocks" => Ok(GeneratedField::AckRetentionBlocks),
+                            "acceptedProofSpecTemplates" | "accepted_proof_spec_templates" => Ok(GeneratedField::AcceptedProofSpecTemplates),
+                            "icaAllowedMessages" | "ica_allowed_messages" => Ok(GeneratedField::IcaAllowedMessages),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = IbcParameters;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.ibc.v1.IbcParameters")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IbcParameters, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut ibc_enabled__ = None;
+                let mut inbound_ics20_transfers_enabled__ = None;
+                let mut outbound_ics20_transfers_enabled__ = None;
+                let mut max_expected_time_per_block__ = None;
+                let mut ics20_rate_limits__ = None;
+                let mut inbound_ics20_allowlist__ = None;
+                let mut inbound_ics20_denylist__ = None;
+                let mut ack_retention_blocks__ = None;
+                let mut accepted_proof_spec_templates__ = None;
+                let mut ica_allowed_messages__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::IbcEnabled => {
+                            if ibc_enabled__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ibcEnabled"));
+                            }
+                            ibc_enabled__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::InboundIcs20TransfersEnabled => {
                             if inbound_ics20_transfers_enabled__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("inboundIcs20TransfersEnabled"));
                             }
-                            inbound_ics20_transfers_enabled__ = Some(map_.next_value()?);
+                            inbound_ics20_transfers_enabled__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::OutboundIcs20TransfersEnabled => {
+                            if outbound_ics20_transfers_enabled__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outboundIcs20TransfersEnabled"));
+                            }
+                            outbound_ics20_transfers_enabled__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MaxExpectedTimePerBlock => {
+                            if max_expected_time_per_block__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxExpectedTimePerBlock"));
+                            }
+                            max_expected_time_per_block__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Ics20RateLimits => {
+                            if ics20_rate_limits__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ics20RateLimits"));
+                            }
+                            ics20_rate_limits__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::InboundIcs20Allowlist => {
+                            if inbound_ics20_allowlist__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inboundIcs20Allowlist"));
+                            }
+                            inbound_ics20_allowlist__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::InboundIcs20Denylist => {
+                            if inbound_ics20_denylist__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inboundIcs20Denylist"));
+                            }
+                            inbound_ics20_denylist__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AckRetentionBlocks => {
+                            if ack_retention_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ackRetentionBlocks"));
+                            }
+                            ack_retention_blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AcceptedProofSpecTemplates => {
+                            if accepted_proof_spec_templates__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("acceptedProofSpecTemplates"));
+                            }
+                            accepted_proof_spec_templates__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::IcaAllowedMessages => {
+                            if ica_allowed_messages__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("icaAllowedMessages"));
+                            }
+                            ica_allowed_messages__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(IbcParameters {
+                    ibc_enabled: ibc_enabled__.unwrap_or_default(),
+                    inbound_ics20_transfers_enabled: inbound_ics20_transfers_enabled__.unwrap_or_default(),
+                    outbound_ics20_transfers_enabled: outbound_ics20_transfers_enabled__.unwrap_or_default(),
+                    max_expected_time_per_block: max_expected_time_per_block__.unwrap_or_default(),
+                    ics20_rate_limits: ics20_rate_limits__.unwrap_or_default(),
+                    inbound_ics20_allowlist: inbound_ics20_allowlist__.unwrap_or_default(),
+                    inbound_ics20_denylist: inbound_ics20_denylist__.unwrap_or_default(),
+                    ack_retention_blocks: ack_retention_blocks__.unwrap_or_default(),
+                    accepted_proof_spec_templates: accepted_proof_spec_templates__.unwrap_or_default(),
+                    ica_allowed_messages: ica_allowed_messages__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.IbcParameters", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for IbcRelay {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.raw_action.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.IbcRelay", len)?;
+        if let Some(v) = self.raw_action.as_ref() {
+            struct_ser.serialize_field("rawAction", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for IbcRelay {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "raw_action",
+            "rawAction",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RawAction,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "rawAction" | "raw_action" => Ok(GeneratedField::RawAction),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = IbcRelay;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.ibc.v1.IbcRelay")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IbcRelay, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut raw_action__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RawAction => {
+                            if raw_action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rawAction"));
+                            }
+                            raw_action__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(IbcRelay {
+                    raw_action: raw_action__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.IbcRelay", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Ics20AssetFilter {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.channel_id.is_empty() {
+            len += 1;
+        }
+        if !self.denom.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.Ics20AssetFilter", len)?;
+        if !self.channel_id.is_empty() {
+            struct_ser.serialize_field("channelId", &self.channel_id)?;
+        }
+        if !self.denom.is_empty() {
+            struct_ser.serialize_field("denom", &self.denom)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Ics20AssetFilter {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "channel_id",
+            "channelId",
+            "denom",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChannelId,
+            Denom,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
+                            "denom" => Ok(GeneratedField::Denom),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Ics20AssetFilter;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.ibc.v1.Ics20AssetFilter")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Ics20AssetFilter, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut channel_id__ = None;
+                let mut denom__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ChannelId => {
+                            if channel_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("channelId"));
+                            }
+                            channel_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Denom => {
+                            if denom__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("denom"));
+                            }
+                            denom__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Ics20AssetFilter {
+                    channel_id: channel_id__.unwrap_or_default(),
+                    denom: denom__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.Ics20AssetFilter", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Ics20Flow {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.window_start_height != 0 {
+            len += 1;
+        }
+        if self.inflow.is_some() {
+            len += 1;
+        }
+        if self.outflow.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.Ics20Flow", len)?;
+        if self.window_start_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("windowStartHeight", ToString::to_string(&self.window_start_height).as_str())?;
+        }
+        if let Some(v) = self.inflow.as_ref() {
+            struct_ser.serialize_field("inflow", v)?;
+        }
+        if let Some(v) = self.outflow.as_ref() {
+            struct_ser.serialize_field("outflow", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Ics20Flow {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "window_start_height",
+            "windowStartHeight",
+            "inflow",
+            "outflow",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WindowStartHeight,
+            Inflow,
+            Outflow,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "windowStartHeight" | "window_start_height" => Ok(GeneratedField::WindowStartHeight),
+                            "inflow" => Ok(GeneratedField::Inflow),
+                            "outflow" => Ok(GeneratedField::Outflow),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Ics20Flow;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.ibc.v1.Ics20Flow")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Ics20Flow, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut window_start_height__ = None;
+                let mut inflow__ = None;
+                let mut outflow__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::WindowStartHeight => {
+                            if window_start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("windowStartHeight"));
+                            }
+                            window_start_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Inflow => {
+                            if inflow__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inflow"));
+                            }
+                            inflow__ = map_.next_value()?;
+                        }
+                        GeneratedField::Outflow => {
+                            if outflow__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outflow"));
+                            }
+                            outflow__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Ics20Flow {
+                    window_start_height: window_start_height__.unwrap_or_default(),
+                    inflow: inflow__,
+                    outflow: outflow__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.Ics20Flow", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Ics20RateLimit {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.channel_id.is_empty() {
+            len += 1;
+        }
+        if self.asset_id.is_some() {
+            len += 1;
+        }
+        if self.max_outflow.is_some() {
+            len += 1;
+        }
+        if self.max_inflow.is_some() {
+            len += 1;
+        }
+        if self.window_blocks != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.Ics20RateLimit", len)?;
+        if !self.channel_id.is_empty() {
+            struct_ser.serialize_field("channelId", &self.channel_id)?;
+        }
+        if let Some(v) = self.asset_id.as_ref() {
+            struct_ser.serialize_field("assetId", v)?;
+        }
+        if let Some(v) = self.max_outflow.as_ref() {
+            struct_ser.serialize_field("maxOutflow", v)?;
+        }
+        if let Some(v) = self.max_inflow.as_ref() {
+            struct_ser.serialize_field("maxInflow", v)?;
+        }
+        if self.window_blocks != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("windowBlocks", ToString::to_string(&self.window_blocks).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Ics20RateLimit {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "channel_id",
+            "channelId",
+            "asset_id",
+            "assetId",
+            "max_outflow",
+            "maxOutflow",
+            "max_inflow",
+            "maxInflow",
+            "window_blocks",
+            "windowBlocks",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChannelId,
+            AssetId,
+            MaxOutflow,
+            MaxInflow,
+            WindowBlocks,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            "maxOutflow" | "max_outflow" => Ok(GeneratedField::MaxOutflow),
+                            "maxInflow" | "max_inflow" => Ok(GeneratedField::MaxInflow),
+                            "windowBlocks" | "window_blocks" => Ok(GeneratedField::WindowBlocks),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Ics20RateLimit;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.ibc.v1.Ics20RateLimit")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Ics20RateLimit, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut channel_id__ = None;
+                let mut asset_id__ = None;
+                let mut max_outflow__ = None;
+                let mut max_inflow__ = None;
+                let mut window_blocks__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ChannelId => {
+                            if channel_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("channelId"));
+                            }
+                            channel_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
+                            }
+                            asset_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::MaxOutflow => {
+                            if max_outflow__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxOutflow"));
+                            }
+                            max_outflow__ = map_.next_value()?;
+                        }
+                        GeneratedField::MaxInflow => {
+                            if max_inflow__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxInflow"));
+                            }
+                            max_inflow__ = map_.next_value()?;
+                        }
+                        GeneratedField::WindowBlocks => {
+                            if window_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("windowBlocks"));
+                            }
+                            window_blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Ics20RateLimit {
+                    channel_id: channel_id__.unwrap_or_default(),
+                    asset_id: asset_id__,
+                    max_outflow: max_outflow__,
+                    max_inflow: max_inflow__,
+                    window_blocks: window_blocks__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.Ics20RateLimit", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Ics20Withdrawal {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.amount.is_some() {
+            len += 1;
+        }
+        if self.denom.is_some() {
+            len += 1;
+        }
+        if !self.destination_chain_address.is_empty() {
+            len += 1;
+        }
+        if self.return_address.is_some() {
+            len += 1;
+        }
+        if self.timeout_height.is_some() {
+            len += 1;
+        }
+        if self.timeout_time != 0 {
+            len += 1;
+        }
+        if !self.source_channel.is_empty() {
+            len += 1;
+        }
+        if self.use_compat_address {
+            len += 1;
+        }
+        if self.relay_fee.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.Ics20Withdrawal", len)?;
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
+        }
+        if let Some(v) = self.denom.as_ref() {
+            struct_ser.serialize_field("denom", v)?;
+        }
+        if !self.destination_chain_address.is_empty() {
+            struct_ser.serialize_field("destinationChainAddress", &self.destination_chain_address)?;
+        }
+        if let Some(v) = self.return_address.as_ref() {
+            struct_ser.serialize_field("returnAddress", v)?;
+        }
+        if let Some(v) = self.timeout_height.as_ref() {
+            struct_ser.serialize_field("timeoutHeight", v)?;
+        }
+        if self.timeout_time != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("timeoutTime", ToString::to_string(&self.timeout_time).as_str())?;
+        }
+        if !self.source_channel.is_empty() {
+            struct_ser.serialize_field("sourceChannel", &self.source_channel)?;
+        }
+        if self.use_compat_address {
+            struct_ser.serialize_field("useCompatAddress", &self.use_compat_address)?;
+        }
+        if let Some(v) = self.relay_fee.as_ref() {
+            struct_ser.serialize_field("relayFee", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Ics20Withdrawal {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "amount",
+            "denom",
+            "destination_chain_address",
+            "destinationChainAddress",
+            "return_address",
+            "returnAddress",
+            "timeout_height",
+            "timeoutHeight",
+            "timeout_time",
+            "timeoutTime",
+            "source_channel",
+            "sourceChannel",
+            "use_compat_address",
+            "useCompatAddress",
+            "relay_fee",
+            "relayFee",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Amount,
+            Denom,
+            DestinationChainAddress,
+            ReturnAddress,
+            TimeoutHeight,
+            TimeoutTime,
+            SourceChannel,
+            UseCompatAddress,
+            RelayFee,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "amount" => Ok(GeneratedField::Amount),
+                            "denom" => Ok(GeneratedField::Denom),
+                            "destinationChainAddress" | "destination_chain_address" => Ok(GeneratedField::DestinationChainAddress),
+                            "returnAddress" | "return_address" => Ok(GeneratedField::ReturnAddress),
+                            "timeoutHeight" | "timeout_height" => Ok(GeneratedField::TimeoutHeight),
+                            "timeoutTime" | "timeout_time" => Ok(GeneratedField::TimeoutTime),
+                            "sourceChannel" | "source_channel" => Ok(GeneratedField::SourceChannel),
+                            "useCompatAddress" | "use_compat_address" => Ok(GeneratedField::UseCompatAddress),
+                            "relayFee" | "relay_fee" => Ok(GeneratedField::RelayFee),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Ics20Withdrawal;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.ibc.v1.Ics20Withdrawal")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Ics20Withdrawal, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut amount__ = None;
+                let mut denom__ = None;
+                let mut destination_chain_address__ = None;
+                let mut return_address__ = None;
+                let mut timeout_height__ = None;
+                let mut timeout_time__ = None;
+                let mut source_channel__ = None;
+                let mut use_compat_address__ = None;
+                let mut relay_fee__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::Denom => {
+                            if denom__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("denom"));
+                            }
+                            denom__ = map_.next_value()?;
+                        }
+                        GeneratedField::DestinationChainAddress => {
+                            if destination_chain_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("destinationChainAddress"));
+                            }
+                            destination_chain_address__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ReturnAddress => {
+                            if return_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("returnAddress"));
+                            }
+                            return_address__ = map_.next_value()?;
+                        }
+                        GeneratedField::TimeoutHeight => {
+                            if timeout_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timeoutHeight"));
+                            }
+                            timeout_height__ = map_.next_value()?;
+                        }
+                        GeneratedField::TimeoutTime => {
+                            if timeout_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timeoutTime"));
+                            }
+                            timeout_time__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SourceChannel => {
+                            if source_channel__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sourceChannel"));
+                            }
+                            source_channel__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::UseCompatAddress => {
+                            if use_compat_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("useCompatAddress"));
+                            }
+                            use_compat_address__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RelayFee => {
+                            if relay_fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("relayFee"));
+                            }
+                            relay_fee__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Ics20Withdrawal {
+                    amount: amount__,
+                    denom: denom__,
+                    destination_chain_address: destination_chain_address__.unwrap_or_default(),
+                    return_address: return_address__,
+                    timeout_height: timeout_height__,
+                    timeout_time: timeout_time__.unwrap_or_default(),
+                    source_channel: source_channel__.unwrap_or_default(),
+                    use_compat_address: use_compat_address__.unwrap_or_default(),
+                    relay_fee: relay_fee__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.Ics20Withdrawal", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for IdentifiedConnectionMetadata {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.connection_id.is_empty() {
+            len += 1;
+        }
+        if self.metadata.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.IdentifiedConnectionMetadata", len)?;
+        if !self.connection_id.is_empty() {
+            struct_ser.serialize_field("connectionId", &self.connection_id)?;
+        }
+        if let Some(v) = self.metadata.as_ref() {
+            struct_ser.serialize_field("metadata", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for IdentifiedConnectionMetadata {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "connection_id",
+            "connectionId",
+            "metadata",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ConnectionId,
+            Metadata,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "connectionId" | "connection_id" => Ok(GeneratedField::ConnectionId),
+                            "metadata" => Ok(GeneratedField::Metadata),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = IdentifiedConnectionMetadata;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.ibc.v1.IdentifiedConnectionMetadata")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IdentifiedConnectionMetadata, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut connection_id__ = None;
+                let mut metadata__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ConnectionId => {
+                            if connection_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("connectionId"));
+                            }
+                            connection_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Metadata => {
+                            if metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("metadata"));
+                            }
+                            metadata__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(IdentifiedConnectionMetadata {
+                    connection_id: connection_id__.unwrap_or_default(),
+                    metadata: metadata__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.IdentifiedConnectionMetadata", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for IdentifiedPacketFee {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.port_id.is_empty() {
+            len += 1;
+        }
+        if !self.channel_id.is_empty() {
+            len += 1;
+        }
+        if self.sequence != 0 {
+            len += 1;
+        }
+        if self.packet_fee.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.IdentifiedPacketFee", len)?;
+        if !self.port_id.is_empty() {
+            struct_ser.serialize_field("portId", &self.port_id)?;
+        }
+        if !self.channel_id.is_empty() {
+            struct_ser.serialize_field("channelId", &self.channel_id)?;
+        }
+        if self.sequence != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        }
+        if let Some(v) = self.packet_fee.as_ref() {
+            struct_ser.serialize_field("packetFee", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for IdentifiedPacketFee {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "port_id",
+            "portId",
+            "channel_id",
+            "channelId",
+            "sequence",
+            "packet_fee",
+            "packetFee",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PortId,
+            ChannelId,
+            Sequence,
+            PacketFee,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "portId" | "port_id" => Ok(GeneratedField::PortId),
+                            "channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
+                            "sequence" => Ok(GeneratedField::Sequence),
+                            "packetFee" | "packet_fee" => Ok(GeneratedField::PacketFee),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = IdentifiedPacketFee;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.ibc.v1.IdentifiedPacketFee")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IdentifiedPacketFee, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut port_id__ = None;
+                let mut channel_id__ = None;
+                let mut sequence__ = None;
+                let mut packet_fee__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PortId => {
+                            if port_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("portId"));
+                            }
+                            port_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ChannelId => {
+                            if channel_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("channelId"));
+                            }
+                            channel_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Sequence => {
+                            if sequence__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequence"));
+                            }
+                            sequence__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PacketFee => {
+                            if packet_fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("packetFee"));
+                            }
+                            packet_fee__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(IdentifiedPacketFee {
+                    port_id: port_id__.unwrap_or_default(),
+                    channel_id: channel_id__.unwrap_or_default(),
+                    sequence: sequence__.unwrap_or_default(),
+                    packet_fee: packet_fee__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.IdentifiedPacketFee", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for IncentivizedPacketsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.port_id.is_empty() {
+            len += 1;
+        }
+        if !self.channel_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.IncentivizedPacketsRequest", len)?;
+        if !self.port_id.is_empty() {
+            struct_ser.serialize_field("portId", &self.port_id)?;
+        }
+        if !self.channel_id.is_empty() {
+            struct_ser.serialize_field("channelId", &self.channel_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for IncentivizedPacketsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "port_id",
+            "portId",
+            "channel_id",
+            "channelId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PortId,
+            ChannelId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "portId" | "port_id" => Ok(GeneratedField::PortId),
+                            "channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = IncentivizedPacketsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.ibc.v1.IncentivizedPacketsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IncentivizedPacketsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut port_id__ = None;
+                let mut channel_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PortId => {
+                            if port_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("portId"));
+                            }
+                            port_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ChannelId => {
+                            if channel_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("channelId"));
+                            }
+                            channel_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(IncentivizedPacketsRequest {
+                    port_id: port_id__.unwrap_or_default(),
+                    channel_id: channel_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.IncentivizedPacketsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for IncentivizedPacketsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.incentivized_packets.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.IncentivizedPacketsResponse", len)?;
+        if !self.incentivized_packets.is_empty() {
+            struct_ser.serialize_field("incentivizedPackets", &self.incentivized_packets)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for IncentivizedPacketsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "incentivized_packets",
+            "incentivizedPackets",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IncentivizedPackets,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "incentivizedPackets" | "incentivized_packets" => Ok(GeneratedField::IncentivizedPackets),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = IncentivizedPacketsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.ibc.v1.IncentivizedPacketsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IncentivizedPacketsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut incentivized_packets__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::IncentivizedPackets => {
+                            if incentivized_packets__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("incentivizedPackets"));
+                            }
+                            incentivized_packets__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(IncentivizedPacketsResponse {
+                    incentivized_packets: incentivized_packets__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.IncentivizedPacketsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NftWithdrawal {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.class_id.is_empty() {
+            len += 1;
+        }
+        if !self.token_ids.is_empty() {
+            len += 1;
+        }
+        if !self.destination_chain_address.is_empty() {
+            len += 1;
+        }
+        if self.return_address.is_some() {
+            len += 1;
+        }
+        if self.timeout_height.is_some() {
+            len += 1;
+        }
+        if self.timeout_time != 0 {
+            len += 1;
+        }
+        if !self.source_channel.is_empty() {
+            len += 1;
+        }
+        if !self.memo.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.NftWithdrawal", len)?;
+        if !self.class_id.is_empty() {
+            struct_ser.serialize_field("classId", &self.class_id)?;
+        }
+        if !self.token_ids.is_empty() {
+            struct_ser.serialize_field("tokenIds", &self.token_ids)?;
+        }
+        if !self.destination_chain_address.is_empty() {
+            struct_ser.serialize_field("destinationChainAddress", &self.destination_chain_address)?;
+        }
+        if let Some(v) = self.return_address.as_ref() {
+            struct_ser.serialize_field("returnAddress", v)?;
+        }
+        if let Some(v) = self.timeout_height.as_ref() {
+            struct_ser.serialize_field("timeoutHeight", v)?;
+        }
+        if self.timeout_time != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("timeoutTime", ToString::to_string(&self.timeout_time).as_str())?;
+        }
+        if !self.source_channel.is_empty() {
+            struct_ser.serialize_field("sourceChannel", &self.source_channel)?;
+        }
+        if !self.memo.is_empty() {
+            struct_ser.serialize_field("memo", &self.memo)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NftWithdrawal {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "class_id",
+            "classId",
+            "token_ids",
+            "tokenIds",
+            "destination_chain_address",
+            "destinationChainAddress",
+            "return_address",
+            "returnAddress",
+            "timeout_height",
+            "timeoutHeight",
+            "timeout_time",
+            "timeoutTime",
+            "source_channel",
+            "sourceChannel",
+            "memo",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ClassId,
+            TokenIds,
+            DestinationChainAddress,
+            ReturnAddress,
+            TimeoutHeight,
+            TimeoutTime,
+            SourceChannel,
+            Memo,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "classId" | "class_id" => Ok(GeneratedField::ClassId),
+                            "tokenIds" | "token_ids" => Ok(GeneratedField::TokenIds),
+                            "destinationChainAddress" | "destination_chain_address" => Ok(GeneratedField::DestinationChainAddress),
+                            "returnAddress" | "return_address" => Ok(GeneratedField::ReturnAddress),
+                            "timeoutHeight" | "timeout_height" => Ok(GeneratedField::TimeoutHeight),
+                            "timeoutTime" | "timeout_time" => Ok(GeneratedField::TimeoutTime),
+                            "sourceChannel" | "source_channel" => Ok(GeneratedField::SourceChannel),
+                            "memo" => Ok(GeneratedField::Memo),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NftWithdrawal;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.ibc.v1.NftWithdrawal")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NftWithdrawal, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut class_id__ = None;
+                let mut token_ids__ = None;
+                let mut destination_chain_address__ = None;
+                let mut return_address__ = None;
+                let mut timeout_height__ = None;
+                let mut timeout_time__ = None;
+                let mut source_channel__ = None;
+                let mut memo__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ClassId => {
+                            if class_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("classId"));
+                            }
+                            class_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TokenIds => {
+                            if token_ids__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tokenIds"));
+                            }
+                            token_ids__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DestinationChainAddress => {
+                            if destination_chain_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("destinationChainAddress"));
+                            }
+                            destination_chain_address__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ReturnAddress => {
+                            if return_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("returnAddress"));
+                            }
+                            return_address__ = map_.next_value()?;
+                        }
+                        GeneratedField::TimeoutHeight => {
+                            if timeout_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timeoutHeight"));
+                            }
+                            timeout_height__ = map_.next_value()?;
+                        }
+                        GeneratedField::TimeoutTime => {
+                            if timeout_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timeoutTime"));
+                            }
+                            timeout_time__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SourceChannel => {
+                            if source_channel__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sourceChannel"));
+                            }
+                            source_channel__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
+                            }
+                            memo__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(NftWithdrawal {
+                    class_id: class_id__.unwrap_or_default(),
+                    token_ids: token_ids__.unwrap_or_default(),
+                    destination_chain_address: destination_chain_address__.unwrap_or_default(),
+                    return_address: return_address__,
+                    timeout_height: timeout_height__,
+                    timeout_time: timeout_time__.unwrap_or_default(),
+                    source_channel: source_channel__.unwrap_or_default(),
+                    memo: memo__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.NftWithdrawal", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PacketFee {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.fee.is_some() {
+            len += 1;
+        }
+        if !self.refund_address.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.PacketFee", len)?;
+        if let Some(v) = self.fee.as_ref() {
+            struct_ser.serialize_field("fee", v)?;
+        }
+        if !self.refund_address.is_empty() {
+            struct_ser.serialize_field("refundAddress", &self.refund_address)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PacketFee {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "fee",
+            "refund_address",
+            "refundAddress",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Fee,
+            RefundAddress,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "fee" => Ok(GeneratedField::Fee),
+                            "refundAddress" | "refund_address" => Ok(GeneratedField::RefundAddress),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PacketFee;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.ibc.v1.PacketFee")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PacketFee, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut fee__ = None;
+                let mut refund_address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Fee => {
+                            if fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fee"));
+                            }
+                            fee__ = map_.next_value()?;
+                        }
+                        GeneratedField::RefundAddress => {
+                            if refund_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("refundAddress"));
+                            }
+                            refund_address__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PacketFee {
+                    fee: fee__,
+                    refund_address: refund_address__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.PacketFee", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProofSpecTemplate {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if !self.proof_specs.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.ProofSpecTemplate", len)?;
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if !self.proof_specs.is_empty() {
+            struct_ser.serialize_field("proofSpecs", &self.proof_specs)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProofSpecTemplate {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "name",
+            "proof_specs",
+            "proofSpecs",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Name,
+            ProofSpecs,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "name" => Ok(GeneratedField::Name),
+                            "proofSpecs" | "proof_specs" => Ok(GeneratedField::ProofSpecs),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProofSpecTemplate;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.ibc.v1.ProofSpecTemplate")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProofSpecTemplate, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut name__ = None;
+                let mut proof_specs__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::OutboundIcs20TransfersEnabled => {
-                            if outbound_ics20_transfers_enabled__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("outboundIcs20TransfersEnabled"));
+                        GeneratedField::ProofSpecs => {
+                            if proof_specs__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proofSpecs"));
                             }
-                            outbound_ics20_transfers_enabled__ = Some(map_.next_value()?);
+                            proof_specs__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(IbcParameters {
-                    ibc_enabled: ibc_enabled__.unwrap_or_default(),
-                    inbound_ics20_transfers_enabled: inbound_ics20_transfers_enabled__.unwrap_or_default(),
-                    outbound_ics20_transfers_enabled: outbound_ics20_transfers_enabled__.unwrap_or_default(),
+                Ok(ProofSpecTemplate {
+                    name: name__.unwrap_or_default(),
+                    proof_specs: proof_specs__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.IbcParameters", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.ProofSpecTemplate", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for IbcRelay {
+impl serde::Serialize for SimulateUpdateAndRecvRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -937,30 +3619,39 @@ impl serde::Serialize for IbcRelay {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.raw_action.is_some() {
+        if self.update_client.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.IbcRelay", len)?;
-        if let Some(v) = self.raw_action.as_ref() {
-            struct_ser.serialize_field("rawAction", v)?;
+        if self.recv_packet.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.SimulateUpdateAndRecvRequest", len)?;
+        if let Some(v) = self.update_client.as_ref() {
+            struct_ser.serialize_field("updateClient", v)?;
+        }
+        if let Some(v) = self.recv_packet.as_ref() {
+            struct_ser.serialize_field("recvPacket", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for IbcRelay {
+impl<'de> serde::Deserialize<'de> for SimulateUpdateAndRecvRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "raw_action",
-            "rawAction",
+            "update_client",
+            "updateClient",
+            "recv_packet",
+            "recvPacket",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            RawAction,
+            UpdateClient,
+            RecvPacket,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -983,7 +3674,8 @@ impl<'de> serde::Deserialize<'de> for IbcRelay {
                         E: serde::de::Error,
                     {
                         match value {
-                            "rawAction" | "raw_action" => Ok(GeneratedField::RawAction),
+                            "updateClient" | "update_client" => Ok(GeneratedField::UpdateClient),
+                            "recvPacket" | "recv_packet" => Ok(GeneratedField::RecvPacket),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -993,39 +3685,47 @@ impl<'de> serde::Deserialize<'de> for IbcRelay {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = IbcRelay;
+            type Value = SimulateUpdateAndRecvRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.ibc.v1.IbcRelay")
+                formatter.write_str("struct penumbra.core.component.ibc.v1.SimulateUpdateAndRecvRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IbcRelay, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SimulateUpdateAndRecvRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut raw_action__ = None;
+                let mut update_client__ = None;
+                let mut recv_packet__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::RawAction => {
-                            if raw_action__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rawAction"));
+                        GeneratedField::UpdateClient => {
+                            if update_client__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updateClient"));
                             }
-                            raw_action__ = map_.next_value()?;
+                            update_client__ = map_.next_value()?;
+                        }
+                        GeneratedField::RecvPacket => {
+                            if recv_packet__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("recvPacket"));
+                            }
+                            recv_packet__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(IbcRelay {
-                    raw_action: raw_action__,
+                Ok(SimulateUpdateAndRecvRequest {
+                    update_client: update_client__,
+                    recv_packet: recv_packet__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.IbcRelay", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.SimulateUpdateAndRecvRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Ics20Withdrawal {
+impl serde::Serialize for SimulateUpdateAndRecvResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1033,92 +3733,47 @@ impl serde::Serialize for Ics20Withdrawal {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.amount.is_some() {
-            len += 1;
-        }
-        if self.denom.is_some() {
-            len += 1;
-        }
-        if !self.destination_chain_address.is_empty() {
-            len += 1;
-        }
-        if self.return_address.is_some() {
-            len += 1;
-        }
-        if self.timeout_height.is_some() {
-            len += 1;
-        }
-        if self.timeout_time != 0 {
+        if self.update_applied {
             len += 1;
         }
-        if !self.source_channel.is_empty() {
+        if self.packet_verified {
             len += 1;
         }
-        if self.use_compat_address {
+        if !self.error.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.Ics20Withdrawal", len)?;
-        if let Some(v) = self.amount.as_ref() {
-            struct_ser.serialize_field("amount", v)?;
-        }
-        if let Some(v) = self.denom.as_ref() {
-            struct_ser.serialize_field("denom", v)?;
-        }
-        if !self.destination_chain_address.is_empty() {
-            struct_ser.serialize_field("destinationChainAddress", &self.destination_chain_address)?;
-        }
-        if let Some(v) = self.return_address.as_ref() {
-            struct_ser.serialize_field("returnAddress", v)?;
-        }
-        if let Some(v) = self.timeout_height.as_ref() {
-            struct_ser.serialize_field("timeoutHeight", v)?;
-        }
-        if self.timeout_time != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("timeoutTime", ToString::to_string(&self.timeout_time).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.SimulateUpdateAndRecvResponse", len)?;
+        if self.update_applied {
+            struct_ser.serialize_field("updateApplied", &self.update_applied)?;
         }
-        if !self.source_channel.is_empty() {
-            struct_ser.serialize_field("sourceChannel", &self.source_channel)?;
+        if self.packet_verified {
+            struct_ser.serialize_field("packetVerified", &self.packet_verified)?;
         }
-        if self.use_compat_address {
-            struct_ser.serialize_field("useCompatAddress", &self.use_compat_address)?;
+        if !self.error.is_empty() {
+            struct_ser.serialize_field("error", &self.error)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Ics20Withdrawal {
+impl<'de> serde::Deserialize<'de> for SimulateUpdateAndRecvResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "amount",
-            "denom",
-            "destination_chain_address",
-            "destinationChainAddress",
-            "return_address",
-            "returnAddress",
-            "timeout_height",
-            "timeoutHeight",
-            "timeout_time",
-            "timeoutTime",
-            "source_channel",
-            "sourceChannel",
-            "use_compat_address",
-            "useCompatAddress",
+            "update_applied",
+            "updateApplied",
+            "packet_verified",
+            "packetVerified",
+            "error",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Amount,
-            Denom,
-            DestinationChainAddress,
-            ReturnAddress,
-            TimeoutHeight,
-            TimeoutTime,
-            SourceChannel,
-            UseCompatAddress,
+            UpdateApplied,
+            PacketVerified,
+            Error,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1141,14 +3796,9 @@ impl<'de> serde::Deserialize<'de> for Ics20Withdrawal {
                         E: serde::de::Error,
                     {
                         match value {
-                            "amount" => Ok(GeneratedField::Amount),
-                            "denom" => Ok(GeneratedField::Denom),
-                            "destinationChainAddress" | "destination_chain_address" => Ok(GeneratedField::DestinationChainAddress),
-                            "returnAddress" | "return_address" => Ok(GeneratedField::ReturnAddress),
-                            "timeoutHeight" | "timeout_height" => Ok(GeneratedField::TimeoutHeight),
-                            "timeoutTime" | "timeout_time" => Ok(GeneratedField::TimeoutTime),
-                            "sourceChannel" | "source_channel" => Ok(GeneratedField::SourceChannel),
-                            "useCompatAddress" | "use_compat_address" => Ok(GeneratedField::UseCompatAddress),
+                            "updateApplied" | "update_applied" => Ok(GeneratedField::UpdateApplied),
+                            "packetVerified" | "packet_verified" => Ok(GeneratedField::PacketVerified),
+                            "error" => Ok(GeneratedField::Error),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1158,94 +3808,170 @@ impl<'de> serde::Deserialize<'de> for Ics20Withdrawal {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Ics20Withdrawal;
+            type Value = SimulateUpdateAndRecvResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.ibc.v1.Ics20Withdrawal")
+                formatter.write_str("struct penumbra.core.component.ibc.v1.SimulateUpdateAndRecvResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Ics20Withdrawal, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SimulateUpdateAndRecvResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut amount__ = None;
-                let mut denom__ = None;
-                let mut destination_chain_address__ = None;
-                let mut return_address__ = None;
-                let mut timeout_height__ = None;
-                let mut timeout_time__ = None;
-                let mut source_channel__ = None;
-                let mut use_compat_address__ = None;
+                let mut update_applied__ = None;
+                let mut packet_verified__ = None;
+                let mut error__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Amount => {
-                            if amount__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("amount"));
+                        GeneratedField::UpdateApplied => {
+                            if update_applied__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updateApplied"));
                             }
-                            amount__ = map_.next_value()?;
+                            update_applied__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::Denom => {
-                            if denom__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("denom"));
+                        GeneratedField::PacketVerified => {
+                            if packet_verified__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("packetVerified"));
                             }
-                            denom__ = map_.next_value()?;
+                            packet_verified__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::DestinationChainAddress => {
-                            if destination_chain_address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("destinationChainAddress"));
+                        GeneratedField::Error => {
+                            if error__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("error"));
                             }
-                            destination_chain_address__ = Some(map_.next_value()?);
+                            error__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::ReturnAddress => {
-                            if return_address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("returnAddress"));
-                            }
-                            return_address__ = map_.next_value()?;
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
-                        GeneratedField::TimeoutHeight => {
-                            if timeout_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("timeoutHeight"));
-                            }
-                            timeout_height__ = map_.next_value()?;
+                    }
+                }
+                Ok(SimulateUpdateAndRecvResponse {
+                    update_applied: update_applied__.unwrap_or_default(),
+                    packet_verified: packet_verified__.unwrap_or_default(),
+                    error: error__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.SimulateUpdateAndRecvResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for VerificationCounts {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.succeeded != 0 {
+            len += 1;
+        }
+        if self.failed != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1.VerificationCounts", len)?;
+        if self.succeeded != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("succeeded", ToString::to_string(&self.succeeded).as_str())?;
+        }
+        if self.failed != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("failed", ToString::to_string(&self.failed).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for VerificationCounts {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "succeeded",
+            "failed",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Succeeded,
+            Failed,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "succeeded" => Ok(GeneratedField::Succeeded),
+                            "failed" => Ok(GeneratedField::Failed),
+                            _ => Ok(GeneratedField::__SkipField__),
                         }
-                        GeneratedField::TimeoutTime => {
-                            if timeout_time__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("timeoutTime"));
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = VerificationCounts;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.ibc.v1.VerificationCounts")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<VerificationCounts, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut succeeded__ = None;
+                let mut failed__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Succeeded => {
+                            if succeeded__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("succeeded"));
                             }
-                            timeout_time__ = 
+                            succeeded__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::SourceChannel => {
-                            if source_channel__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("sourceChannel"));
-                            }
-                            source_channel__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::UseCompatAddress => {
-                            if use_compat_address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("useCompatAddress"));
+                        GeneratedField::Failed => {
+                            if failed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("failed"));
                             }
-                            use_compat_address__ = Some(map_.next_value()?);
+                            failed__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(Ics20Withdrawal {
-                    amount: amount__,
-                    denom: denom__,
-                    destination_chain_address: destination_chain_address__.unwrap_or_default(),
-                    return_address: return_address__,
-                    timeout_height: timeout_height__,
-                    timeout_time: timeout_time__.unwrap_or_default(),
-                    source_channel: source_channel__.unwrap_or_default(),
-                    use_compat_address: use_compat_address__.unwrap_or_default(),
+                Ok(VerificationCounts {
+                    succeeded: succeeded__.unwrap_or_default(),
+                    failed: failed__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.Ics20Withdrawal", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1.VerificationCounts", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for VerifiedHeights {
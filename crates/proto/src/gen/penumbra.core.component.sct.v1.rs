@@ -138,6 +138,10 @@ pub mod commitment_source {
         /// The sender address on the counterparty chain
         #[prost(string, tag = "3")]
         pub sender: ::prost::alloc::string::String,
+        /// The memo of the transfer, encrypted to the receiver with the key of the
+        /// note it minted, or empty if the transfer had no memo for the receiver.
+        #[prost(bytes = "vec", tag = "4")]
+        pub encrypted_memo: ::prost::alloc::vec::Vec<u8>,
     }
     impl ::prost::Name for Ics20Transfer {
         const NAME: &'static str = "Ics20Transfer";
@@ -359,6 +363,51 @@ impl ::prost::Name for TimestampByHeightResponse {
         ::prost::alloc::format!("penumbra.core.component.sct.v1.{}", Self::NAME)
     }
 }
+/// Requests the spend status of a nullifier.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NullifierStatusRequest {
+    /// The nullifier to look up.
+    #[prost(message, optional, tag = "1")]
+    pub nullifier: ::core::option::Option<Nullifier>,
+    /// Whether to return a proof of the nullifier's status.
+    #[prost(bool, tag = "2")]
+    pub proof: bool,
+}
+impl ::prost::Name for NullifierStatusRequest {
+    const NAME: &'static str = "NullifierStatusRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.sct.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.sct.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NullifierStatusResponse {
+    /// If the nullifier has been spent, the transaction that spent it and the
+    /// height at which it did.
+    #[prost(message, optional, tag = "1")]
+    pub spend_info: ::core::option::Option<NullificationInfo>,
+    /// The height of the state the status was read from.
+    ///
+    /// The proof, if any, is against the app hash of this height, which is
+    /// committed to by the header of the next block.
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+    /// If requested, a proof of existence or non-existence of the nullifier
+    /// in the set of spent nullifiers.
+    #[prost(message, optional, tag = "3")]
+    pub proof: ::core::option::Option<
+        ::ibc_proto::ibc::core::commitment::v1::MerkleProof,
+    >,
+}
+impl ::prost::Name for NullifierStatusResponse {
+    const NAME: &'static str = "NullifierStatusResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.sct.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.sct.v1.{}", Self::NAME)
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod query_service_client {
@@ -536,6 +585,36 @@ pub mod query_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        pub async fn nullifier_status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::NullifierStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::NullifierStatusResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.sct.v1.QueryService/NullifierStatus",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.sct.v1.QueryService",
+                        "NullifierStatus",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -567,6 +646,13 @@ pub mod query_service_server {
             tonic::Response<super::TimestampByHeightResponse>,
             tonic::Status,
         >;
+        async fn nullifier_status(
+            &self,
+            request: tonic::Request<super::NullifierStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::NullifierStatusResponse>,
+            tonic::Status,
+        >;
     }
     /// Query operations for the SCT component.
     #[derive(Debug)]
@@ -787,6 +873,53 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.component.sct.v1.QueryService/NullifierStatus" => {
+                    #[allow(non_camel_case_types)]
+                    struct NullifierStatusSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::NullifierStatusRequest>
+                    for NullifierStatusSvc<T> {
+                        type Response = super::NullifierStatusResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::NullifierStatusRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::nullifier_status(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = NullifierStatusSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
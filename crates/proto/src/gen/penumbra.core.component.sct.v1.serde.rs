@@ -606,6 +606,9 @@ impl serde::Serialize for commitment_source::Ics20Transfer {
         if !self.sender.is_empty() {
             len += 1;
         }
+        if !self.encrypted_memo.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.sct.v1.CommitmentSource.Ics20Transfer", len)?;
         if self.packet_seq != 0 {
             #[allow(clippy::needless_borrow)]
@@ -617,6 +620,10 @@ impl serde::Serialize for commitment_source::Ics20Transfer {
         if !self.sender.is_empty() {
             struct_ser.serialize_field("sender", &self.sender)?;
         }
+        if !self.encrypted_memo.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("encryptedMemo", pbjson::private::base64::encode(&self.encrypted_memo).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -632,6 +639,8 @@ impl<'de> serde::Deserialize<'de> for commitment_source::Ics20Transfer {
             "channel_id",
             "channelId",
             "sender",
+            "encrypted_memo",
+            "encryptedMemo",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -639,6 +648,7 @@ impl<'de> serde::Deserialize<'de> for commitment_source::Ics20Transfer {
             PacketSeq,
             ChannelId,
             Sender,
+            EncryptedMemo,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -664,6 +674,7 @@ impl<'de> serde::Deserialize<'de> for commitment_source::Ics20Transfer {
                             "packetSeq" | "packet_seq" => Ok(GeneratedField::PacketSeq),
                             "channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
                             "sender" => Ok(GeneratedField::Sender),
+                            "encryptedMemo" | "encrypted_memo" => Ok(GeneratedField::EncryptedMemo),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -686,6 +697,7 @@ impl<'de> serde::Deserialize<'de> for commitment_source::Ics20Transfer {
                 let mut packet_seq__ = None;
                 let mut channel_id__ = None;
                 let mut sender__ = None;
+                let mut encrypted_memo__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::PacketSeq => {
@@ -708,6 +720,14 @@ impl<'de> serde::Deserialize<'de> for commitment_source::Ics20Transfer {
                             }
                             sender__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::EncryptedMemo => {
+                            if encrypted_memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("encryptedMemo"));
+                            }
+                            encrypted_memo__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -717,6 +737,7 @@ impl<'de> serde::Deserialize<'de> for commitment_source::Ics20Transfer {
                     packet_seq: packet_seq__.unwrap_or_default(),
                     channel_id: channel_id__.unwrap_or_default(),
                     sender: sender__.unwrap_or_default(),
+                    encrypted_memo: encrypted_memo__.unwrap_or_default(),
                 })
             }
         }
@@ -1974,6 +1995,251 @@ impl<'de> serde::Deserialize<'de> for Nullifier {
         deserializer.deserialize_struct("penumbra.core.component.sct.v1.Nullifier", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for NullifierStatusRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.nullifier.is_some() {
+            len += 1;
+        }
+        if self.proof {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.sct.v1.NullifierStatusRequest", len)?;
+        if let Some(v) = self.nullifier.as_ref() {
+            struct_ser.serialize_field("nullifier", v)?;
+        }
+        if self.proof {
+            struct_ser.serialize_field("proof", &self.proof)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NullifierStatusRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "nullifier",
+            "proof",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Nullifier,
+            Proof,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "nullifier" => Ok(GeneratedField::Nullifier),
+                            "proof" => Ok(GeneratedField::Proof),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NullifierStatusRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.sct.v1.NullifierStatusRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NullifierStatusRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut nullifier__ = None;
+                let mut proof__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Nullifier => {
+                            if nullifier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nullifier"));
+                            }
+                            nullifier__ = map_.next_value()?;
+                        }
+                        GeneratedField::Proof => {
+                            if proof__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proof"));
+                            }
+                            proof__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(NullifierStatusRequest {
+                    nullifier: nullifier__,
+                    proof: proof__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.sct.v1.NullifierStatusRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NullifierStatusResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.spend_info.is_some() {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.proof.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.sct.v1.NullifierStatusResponse", len)?;
+        if let Some(v) = self.spend_info.as_ref() {
+            struct_ser.serialize_field("spendInfo", v)?;
+        }
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if let Some(v) = self.proof.as_ref() {
+            struct_ser.serialize_field("proof", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NullifierStatusResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "spend_info",
+            "spendInfo",
+            "height",
+            "proof",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SpendInfo,
+            Height,
+            Proof,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "spendInfo" | "spend_info" => Ok(GeneratedField::SpendInfo),
+                            "height" => Ok(GeneratedField::Height),
+                            "proof" => Ok(GeneratedField::Proof),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NullifierStatusResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.sct.v1.NullifierStatusResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NullifierStatusResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut spend_info__ = None;
+                let mut height__ = None;
+                let mut proof__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SpendInfo => {
+                            if spend_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spendInfo"));
+                            }
+                            spend_info__ = map_.next_value()?;
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Proof => {
+                            if proof__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proof"));
+                            }
+                            proof__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(NullifierStatusResponse {
+                    spend_info: spend_info__,
+                    height: height__.unwrap_or_default(),
+                    proof: proof__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.sct.v1.NullifierStatusResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for SctParameters {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -26,6 +26,12 @@ pub struct GenesisContent {
     /// The allocations present at genesis
     #[prost(message, repeated, tag = "3")]
     pub allocations: ::prost::alloc::vec::Vec<genesis_content::Allocation>,
+    /// The metadata of assets to register at genesis, in addition to that of the
+    /// allocated denoms, e.g. to give them display units.
+    #[prost(message, repeated, tag = "4")]
+    pub denom_metadata: ::prost::alloc::vec::Vec<
+        super::super::super::asset::v1::Metadata,
+    >,
 }
 /// Nested message and enum types in `GenesisContent`.
 pub mod genesis_content {
@@ -68,7 +74,7 @@ pub struct FmdMetaParameters {
     #[prost(uint64, tag = "1")]
     pub fmd_grace_period_blocks: u64,
     /// The algorithm governing how the parameters change.
-    #[prost(oneof = "fmd_meta_parameters::Algorithm", tags = "2, 3")]
+    #[prost(oneof = "fmd_meta_parameters::Algorithm", tags = "2, 3, 4")]
     pub algorithm: ::core::option::Option<fmd_meta_parameters::Algorithm>,
 }
 /// Nested message and enum types in `FmdMetaParameters`.
@@ -96,6 +102,52 @@ pub mod fmd_meta_parameters {
             )
         }
     }
+    /// A schedule of precisions, ramping up with the size of the anonymity set.
+    ///
+    /// The anonymity set is the number of clues seen in the last update period.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct AlgorithmSchedule {
+        /// The steps of the schedule, by strictly increasing number of clues.
+        ///
+        /// The first step must apply from zero clues.
+        #[prost(message, repeated, tag = "1")]
+        pub steps: ::prost::alloc::vec::Vec<algorithm_schedule::Step>,
+    }
+    /// Nested message and enum types in `AlgorithmSchedule`.
+    pub mod algorithm_schedule {
+        /// A step of the schedule.
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct Step {
+            /// The number of clues from which this step applies.
+            #[prost(uint64, tag = "1")]
+            pub min_clues: u64,
+            /// The precision to use for this step.
+            #[prost(uint32, tag = "2")]
+            pub precision_bits: u32,
+        }
+        impl ::prost::Name for Step {
+            const NAME: &'static str = "Step";
+            const PACKAGE: &'static str = "penumbra.core.component.shielded_pool.v1";
+            fn full_name() -> ::prost::alloc::string::String {
+                ::prost::alloc::format!(
+                    "penumbra.core.component.shielded_pool.v1.FmdMetaParameters.AlgorithmSchedule.{}",
+                    Self::NAME
+                )
+            }
+        }
+    }
+    impl ::prost::Name for AlgorithmSchedule {
+        const NAME: &'static str = "AlgorithmSchedule";
+        const PACKAGE: &'static str = "penumbra.core.component.shielded_pool.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.core.component.shielded_pool.v1.FmdMetaParameters.{}",
+                Self::NAME
+            )
+        }
+    }
     /// The algorithm governing how the parameters change.
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
@@ -104,6 +156,8 @@ pub mod fmd_meta_parameters {
         FixedPrecisionBits(u32),
         #[prost(message, tag = "3")]
         SlidingWindow(AlgorithmSlidingWindow),
+        #[prost(message, tag = "4")]
+        Schedule(AlgorithmSchedule),
     }
 }
 impl ::prost::Name for FmdMetaParameters {
@@ -663,6 +717,47 @@ impl ::prost::Name for OutputPlan {
         )
     }
 }
+/// The changes the chain itself has made to the supply of an asset since
+/// genesis, outside of the value balance of transactions.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetSupply {
+    /// Value issued at genesis, as notes or as the initial Community Pool balance.
+    #[prost(message, optional, tag = "1")]
+    pub genesis: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// Staking rewards paid by funding streams, as notes or to the Community Pool.
+    ///
+    /// Rewards to delegators aren't included: they're paid by the delegation
+    /// token exchange rate when delegation tokens are converted back into the
+    /// staking token, which transactions do.
+    #[prost(message, optional, tag = "2")]
+    pub staking_rewards: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// Value credited by inbound ICS20 transfers, and by refunds of outbound ones.
+    #[prost(message, optional, tag = "3")]
+    pub ibc_inbound: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// Value removed by ICS20 withdrawals.
+    #[prost(message, optional, tag = "4")]
+    pub ibc_outbound: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// Value deposited into the Community Pool.
+    #[prost(message, optional, tag = "5")]
+    pub community_pool_deposits: ::core::option::Option<
+        super::super::super::num::v1::Amount,
+    >,
+    /// Value withdrawn from the Community Pool.
+    #[prost(message, optional, tag = "6")]
+    pub community_pool_withdrawals: ::core::option::Option<
+        super::super::super::num::v1::Amount,
+    >,
+}
+impl ::prost::Name for AssetSupply {
+    const NAME: &'static str = "AssetSupply";
+    const PACKAGE: &'static str = "penumbra.core.component.shielded_pool.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!(
+            "penumbra.core.component.shielded_pool.v1.{}", Self::NAME
+        )
+    }
+}
 /// Requests information on an asset by asset id
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -382,6 +382,191 @@ impl<'de> serde::Deserialize<'de> for AssetMetadataByIdsResponse {
         deserializer.deserialize_struct("penumbra.core.component.shielded_pool.v1.AssetMetadataByIdsResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for AssetSupply {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.genesis.is_some() {
+            len += 1;
+        }
+        if self.staking_rewards.is_some() {
+            len += 1;
+        }
+        if self.ibc_inbound.is_some() {
+            len += 1;
+        }
+        if self.ibc_outbound.is_some() {
+            len += 1;
+        }
+        if self.community_pool_deposits.is_some() {
+            len += 1;
+        }
+        if self.community_pool_withdrawals.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.shielded_pool.v1.AssetSupply", len)?;
+        if let Some(v) = self.genesis.as_ref() {
+            struct_ser.serialize_field("genesis", v)?;
+        }
+        if let Some(v) = self.staking_rewards.as_ref() {
+            struct_ser.serialize_field("stakingRewards", v)?;
+        }
+        if let Some(v) = self.ibc_inbound.as_ref() {
+            struct_ser.serialize_field("ibcInbound", v)?;
+        }
+        if let Some(v) = self.ibc_outbound.as_ref() {
+            struct_ser.serialize_field("ibcOutbound", v)?;
+        }
+        if let Some(v) = self.community_pool_deposits.as_ref() {
+            struct_ser.serialize_field("communityPoolDeposits", v)?;
+        }
+        if let Some(v) = self.community_pool_withdrawals.as_ref() {
+            struct_ser.serialize_field("communityPoolWithdrawals", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AssetSupply {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "genesis",
+            "staking_rewards",
+            "stakingRewards",
+            "ibc_inbound",
+            "ibcInbound",
+            "ibc_outbound",
+            "ibcOutbound",
+            "community_pool_deposits",
+            "communityPoolDeposits",
+            "community_pool_withdrawals",
+            "communityPoolWithdrawals",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Genesis,
+            StakingRewards,
+            IbcInbound,
+            IbcOutbound,
+            CommunityPoolDeposits,
+            CommunityPoolWithdrawals,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "genesis" => Ok(GeneratedField::Genesis),
+                            "stakingRewards" | "staking_rewards" => Ok(GeneratedField::StakingRewards),
+                            "ibcInbound" | "ibc_inbound" => Ok(GeneratedField::IbcInbound),
+                            "ibcOutbound" | "ibc_outbound" => Ok(GeneratedField::IbcOutbound),
+                            "communityPoolDeposits" | "community_pool_deposits" => Ok(GeneratedField::CommunityPoolDeposits),
+                            "communityPoolWithdrawals" | "community_pool_withdrawals" => Ok(GeneratedField::CommunityPoolWithdrawals),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AssetSupply;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.shielded_pool.v1.AssetSupply")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AssetSupply, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut genesis__ = None;
+                let mut staking_rewards__ = None;
+                let mut ibc_inbound__ = None;
+                let mut ibc_outbound__ = None;
+                let mut community_pool_deposits__ = None;
+                let mut community_pool_withdrawals__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Genesis => {
+                            if genesis__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("genesis"));
+                            }
+                            genesis__ = map_.next_value()?;
+                        }
+                        GeneratedField::StakingRewards => {
+                            if staking_rewards__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stakingRewards"));
+                            }
+                            staking_rewards__ = map_.next_value()?;
+                        }
+                        GeneratedField::IbcInbound => {
+                            if ibc_inbound__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ibcInbound"));
+                            }
+                            ibc_inbound__ = map_.next_value()?;
+                        }
+                        GeneratedField::IbcOutbound => {
+                            if ibc_outbound__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ibcOutbound"));
+                            }
+                            ibc_outbound__ = map_.next_value()?;
+                        }
+                        GeneratedField::CommunityPoolDeposits => {
+                            if community_pool_deposits__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("communityPoolDeposits"));
+                            }
+                            community_pool_deposits__ = map_.next_value()?;
+                        }
+                        GeneratedField::CommunityPoolWithdrawals => {
+                            if community_pool_withdrawals__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("communityPoolWithdrawals"));
+                            }
+                            community_pool_withdrawals__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(AssetSupply {
+                    genesis: genesis__,
+                    staking_rewards: staking_rewards__,
+                    ibc_inbound: ibc_inbound__,
+                    ibc_outbound: ibc_outbound__,
+                    community_pool_deposits: community_pool_deposits__,
+                    community_pool_withdrawals: community_pool_withdrawals__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.shielded_pool.v1.AssetSupply", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for EventBroadcastClue {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1243,6 +1428,9 @@ impl serde::Serialize for FmdMetaParameters {
                 fmd_meta_parameters::Algorithm::SlidingWindow(v) => {
                     struct_ser.serialize_field("slidingWindow", v)?;
                 }
+                fmd_meta_parameters::Algorithm::Schedule(v) => {
+                    struct_ser.serialize_field("schedule", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -1261,6 +1449,7 @@ impl<'de> serde::Deserialize<'de> for FmdMetaParameters {
             "fixedPrecisionBits",
             "sliding_window",
             "slidingWindow",
+            "schedule",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1268,6 +1457,7 @@ impl<'de> serde::Deserialize<'de> for FmdMetaParameters {
             FmdGracePeriodBlocks,
             FixedPrecisionBits,
             SlidingWindow,
+            Schedule,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1293,6 +1483,7 @@ impl<'de> serde::Deserialize<'de> for FmdMetaParameters {
                             "fmdGracePeriodBlocks" | "fmd_grace_period_blocks" => Ok(GeneratedField::FmdGracePeriodBlocks),
                             "fixedPrecisionBits" | "fixed_precision_bits" => Ok(GeneratedField::FixedPrecisionBits),
                             "slidingWindow" | "sliding_window" => Ok(GeneratedField::SlidingWindow),
+                            "schedule" => Ok(GeneratedField::Schedule),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1335,6 +1526,13 @@ impl<'de> serde::Deserialize<'de> for FmdMetaParameters {
                                 return Err(serde::de::Error::duplicate_field("slidingWindow"));
                             }
                             algorithm__ = map_.next_value::<::std::option::Option<_>>()?.map(fmd_meta_parameters::Algorithm::SlidingWindow)
+;
+                        }
+                        GeneratedField::Schedule => {
+                            if algorithm__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("schedule"));
+                            }
+                            algorithm__ = map_.next_value::<::std::option::Option<_>>()?.map(fmd_meta_parameters::Algorithm::Schedule)
 ;
                         }
                         GeneratedField::__SkipField__ => {
@@ -1351,6 +1549,220 @@ impl<'de> serde::Deserialize<'de> for FmdMetaParameters {
         deserializer.deserialize_struct("penumbra.core.component.shielded_pool.v1.FmdMetaParameters", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for fmd_meta_parameters::AlgorithmSchedule {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.steps.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.shielded_pool.v1.FmdMetaParameters.AlgorithmSchedule", len)?;
+        if !self.steps.is_empty() {
+            struct_ser.serialize_field("steps", &self.steps)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for fmd_meta_parameters::AlgorithmSchedule {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "steps",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Steps,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "steps" => Ok(GeneratedField::Steps),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = fmd_meta_parameters::AlgorithmSchedule;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.shielded_pool.v1.FmdMetaParameters.AlgorithmSchedule")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<fmd_meta_parameters::AlgorithmSchedule, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut steps__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Steps => {
+                            if steps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("steps"));
+                            }
+                            steps__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(fmd_meta_parameters::AlgorithmSchedule {
+                    steps: steps__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.shielded_pool.v1.FmdMetaParameters.AlgorithmSchedule", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for fmd_meta_parameters::algorithm_schedule::Step {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.min_clues != 0 {
+            len += 1;
+        }
+        if self.precision_bits != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.shielded_pool.v1.FmdMetaParameters.AlgorithmSchedule.Step", len)?;
+        if self.min_clues != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("minClues", ToString::to_string(&self.min_clues).as_str())?;
+        }
+        if self.precision_bits != 0 {
+            struct_ser.serialize_field("precisionBits", &self.precision_bits)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for fmd_meta_parameters::algorithm_schedule::Step {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "min_clues",
+            "minClues",
+            "precision_bits",
+            "precisionBits",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MinClues,
+            PrecisionBits,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "minClues" | "min_clues" => Ok(GeneratedField::MinClues),
+                            "precisionBits" | "precision_bits" => Ok(GeneratedField::PrecisionBits),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = fmd_meta_parameters::algorithm_schedule::Step;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.shielded_pool.v1.FmdMetaParameters.AlgorithmSchedule.Step")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<fmd_meta_parameters::algorithm_schedule::Step, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut min_clues__ = None;
+                let mut precision_bits__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MinClues => {
+                            if min_clues__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minClues"));
+                            }
+                            min_clues__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PrecisionBits => {
+                            if precision_bits__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("precisionBits"));
+                            }
+                            precision_bits__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(fmd_meta_parameters::algorithm_schedule::Step {
+                    min_clues: min_clues__.unwrap_or_default(),
+                    precision_bits: precision_bits__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.shielded_pool.v1.FmdMetaParameters.AlgorithmSchedule.Step", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for fmd_meta_parameters::AlgorithmSlidingWindow {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -2001,6 +2413,9 @@ impl serde::Serialize for GenesisContent {
         if !self.allocations.is_empty() {
             len += 1;
         }
+        if !self.denom_metadata.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.shielded_pool.v1.GenesisContent", len)?;
         if let Some(v) = self.shielded_pool_params.as_ref() {
             struct_ser.serialize_field("shieldedPoolParams", v)?;
@@ -2008,6 +2423,9 @@ impl serde::Serialize for GenesisContent {
         if !self.allocations.is_empty() {
             struct_ser.serialize_field("allocations", &self.allocations)?;
         }
+        if !self.denom_metadata.is_empty() {
+            struct_ser.serialize_field("denomMetadata", &self.denom_metadata)?;
+        }
         struct_ser.end()
     }
 }
@@ -2021,12 +2439,15 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
             "shielded_pool_params",
             "shieldedPoolParams",
             "allocations",
+            "denom_metadata",
+            "denomMetadata",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             ShieldedPoolParams,
             Allocations,
+            DenomMetadata,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2051,6 +2472,7 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
                         match value {
                             "shieldedPoolParams" | "shielded_pool_params" => Ok(GeneratedField::ShieldedPoolParams),
                             "allocations" => Ok(GeneratedField::Allocations),
+                            "denomMetadata" | "denom_metadata" => Ok(GeneratedField::DenomMetadata),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2072,6 +2494,7 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
             {
                 let mut shielded_pool_params__ = None;
                 let mut allocations__ = None;
+                let mut denom_metadata__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::ShieldedPoolParams => {
@@ -2086,6 +2509,12 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
                             }
                             allocations__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::DenomMetadata => {
+                            if denom_metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("denomMetadata"));
+                            }
+                            denom_metadata__ = Some(map_.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -2094,6 +2523,7 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
                 Ok(GenesisContent {
                     shielded_pool_params: shielded_pool_params__,
                     allocations: allocations__.unwrap_or_default(),
+                    denom_metadata: denom_metadata__.unwrap_or_default(),
                 })
             }
         }
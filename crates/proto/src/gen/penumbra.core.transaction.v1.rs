@@ -94,7 +94,7 @@ impl ::prost::Name for DetectionData {
 pub struct Action {
     #[prost(
         oneof = "action::Action",
-        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 30, 31, 32, 34, 40, 41, 42, 50, 51, 52, 53, 54, 55, 200"
+        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 30, 31, 32, 33, 34, 40, 41, 42, 50, 51, 52, 53, 54, 55, 200, 201"
     )]
     pub action: ::core::option::Option<action::Action>,
 }
@@ -140,6 +140,8 @@ pub mod action {
         PositionClose(super::super::super::component::dex::v1::PositionClose),
         #[prost(message, tag = "32")]
         PositionWithdraw(super::super::super::component::dex::v1::PositionWithdraw),
+        #[prost(message, tag = "33")]
+        GaugeFund(super::super::super::component::dex::v1::GaugeFund),
         #[prost(message, tag = "34")]
         PositionRewardClaim(
             super::super::super::component::dex::v1::PositionRewardClaim,
@@ -179,6 +181,8 @@ pub mod action {
         ),
         #[prost(message, tag = "200")]
         Ics20Withdrawal(super::super::super::component::ibc::v1::Ics20Withdrawal),
+        #[prost(message, tag = "201")]
+        NftWithdrawal(super::super::super::component::ibc::v1::NftWithdrawal),
     }
 }
 impl ::prost::Name for Action {
@@ -400,7 +404,7 @@ impl ::prost::Name for TransactionBodyView {
 pub struct ActionView {
     #[prost(
         oneof = "action_view::ActionView",
-        tags = "1, 2, 3, 4, 21, 16, 17, 18, 19, 20, 22, 30, 31, 32, 34, 41, 42, 50, 51, 52, 53, 54, 55, 43, 200"
+        tags = "1, 2, 3, 4, 21, 16, 17, 18, 19, 20, 22, 30, 31, 32, 33, 34, 41, 42, 50, 51, 52, 53, 54, 55, 43, 200, 201"
     )]
     pub action_view: ::core::option::Option<action_view::ActionView>,
 }
@@ -446,6 +450,8 @@ pub mod action_view {
         PositionClose(super::super::super::component::dex::v1::PositionClose),
         #[prost(message, tag = "32")]
         PositionWithdraw(super::super::super::component::dex::v1::PositionWithdraw),
+        #[prost(message, tag = "33")]
+        GaugeFund(super::super::super::component::dex::v1::GaugeFund),
         #[prost(message, tag = "34")]
         PositionRewardClaim(
             super::super::super::component::dex::v1::PositionRewardClaim,
@@ -487,6 +493,8 @@ pub mod action_view {
         UndelegateClaim(super::super::super::component::stake::v1::UndelegateClaim),
         #[prost(message, tag = "200")]
         Ics20Withdrawal(super::super::super::component::ibc::v1::Ics20Withdrawal),
+        #[prost(message, tag = "201")]
+        NftWithdrawal(super::super::super::component::ibc::v1::NftWithdrawal),
     }
 }
 impl ::prost::Name for ActionView {
@@ -594,7 +602,7 @@ impl ::prost::Name for DetectionDataPlan {
 pub struct ActionPlan {
     #[prost(
         oneof = "action_plan::Action",
-        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 200, 30, 31, 32, 34, 40, 41, 42, 50, 51, 52, 53, 54, 55"
+        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 200, 201, 30, 31, 33, 32, 34, 40, 41, 42, 50, 51, 52, 53, 54, 55"
     )]
     pub action: ::core::option::Option<action_plan::Action>,
 }
@@ -636,10 +644,15 @@ pub mod action_plan {
         ),
         #[prost(message, tag = "200")]
         Ics20Withdrawal(super::super::super::component::ibc::v1::Ics20Withdrawal),
+        #[prost(message, tag = "201")]
+        NftWithdrawal(super::super::super::component::ibc::v1::NftWithdrawal),
         #[prost(message, tag = "30")]
         PositionOpen(super::super::super::component::dex::v1::PositionOpen),
         #[prost(message, tag = "31")]
         PositionClose(super::super::super::component::dex::v1::PositionClose),
+        /// This is just a message relayed to the chain.
+        #[prost(message, tag = "33")]
+        GaugeFund(super::super::super::component::dex::v1::GaugeFund),
         /// The position withdraw/reward claim actions require balance information so they have Plan types.
         #[prost(message, tag = "32")]
         PositionWithdraw(super::super::super::component::dex::v1::PositionWithdrawPlan),
@@ -757,8 +770,14 @@ pub struct MemoPlaintext {
     #[prost(message, optional, tag = "1")]
     pub return_address: ::core::option::Option<super::super::keys::v1::Address>,
     /// Free-form text, up to 432 bytes long.
+    ///
+    /// If the return address is authenticated, the text can be up to 368 bytes long.
     #[prost(string, tag = "2")]
     pub text: ::prost::alloc::string::String,
+    /// A proof that the sender controls the return address, or empty if the
+    /// return address is unauthenticated.
+    #[prost(bytes = "vec", tag = "3")]
+    pub return_address_proof: ::prost::alloc::vec::Vec<u8>,
 }
 impl ::prost::Name for MemoPlaintext {
     const NAME: &'static str = "MemoPlaintext";
@@ -774,6 +793,9 @@ pub struct MemoPlaintextView {
     pub return_address: ::core::option::Option<super::super::keys::v1::AddressView>,
     #[prost(string, tag = "2")]
     pub text: ::prost::alloc::string::String,
+    /// Whether the sender proved that they control the return address.
+    #[prost(bool, tag = "3")]
+    pub return_address_authenticated: bool,
 }
 impl ::prost::Name for MemoPlaintextView {
     const NAME: &'static str = "MemoPlaintextView";
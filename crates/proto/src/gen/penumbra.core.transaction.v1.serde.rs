@@ -54,6 +54,9 @@ impl serde::Serialize for Action {
                 action::Action::PositionWithdraw(v) => {
                     struct_ser.serialize_field("positionWithdraw", v)?;
                 }
+                action::Action::GaugeFund(v) => {
+                    struct_ser.serialize_field("gaugeFund", v)?;
+                }
                 action::Action::PositionRewardClaim(v) => {
                     struct_ser.serialize_field("positionRewardClaim", v)?;
                 }
@@ -87,6 +90,9 @@ impl serde::Serialize for Action {
                 action::Action::Ics20Withdrawal(v) => {
                     struct_ser.serialize_field("ics20Withdrawal", v)?;
                 }
+                action::Action::NftWithdrawal(v) => {
+                    struct_ser.serialize_field("nftWithdrawal", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -124,6 +130,8 @@ impl<'de> serde::Deserialize<'de> for Action {
             "positionClose",
             "position_withdraw",
             "positionWithdraw",
+            "gauge_fund",
+            "gaugeFund",
             "position_reward_claim",
             "positionRewardClaim",
             "delegate",
@@ -144,6 +152,8 @@ impl<'de> serde::Deserialize<'de> for Action {
             "actionDutchAuctionWithdraw",
             "ics20_withdrawal",
             "ics20Withdrawal",
+            "nft_withdrawal",
+            "nftWithdrawal",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -162,6 +172,7 @@ impl<'de> serde::Deserialize<'de> for Action {
             PositionOpen,
             PositionClose,
             PositionWithdraw,
+            GaugeFund,
             PositionRewardClaim,
             Delegate,
             Undelegate,
@@ -173,6 +184,7 @@ impl<'de> serde::Deserialize<'de> for Action {
             ActionDutchAuctionEnd,
             ActionDutchAuctionWithdraw,
             Ics20Withdrawal,
+            NftWithdrawal,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -209,6 +221,7 @@ impl<'de> serde::Deserialize<'de> for Action {
                             "positionOpen" | "position_open" => Ok(GeneratedField::PositionOpen),
                             "positionClose" | "position_close" => Ok(GeneratedField::PositionClose),
                             "positionWithdraw" | "position_withdraw" => Ok(GeneratedField::PositionWithdraw),
+                            "gaugeFund" | "gauge_fund" => Ok(GeneratedField::GaugeFund),
                             "positionRewardClaim" | "position_reward_claim" => Ok(GeneratedField::PositionRewardClaim),
                             "delegate" => Ok(GeneratedField::Delegate),
                             "undelegate" => Ok(GeneratedField::Undelegate),
@@ -220,6 +233,7 @@ impl<'de> serde::Deserialize<'de> for Action {
                             "actionDutchAuctionEnd" | "action_dutch_auction_end" => Ok(GeneratedField::ActionDutchAuctionEnd),
                             "actionDutchAuctionWithdraw" | "action_dutch_auction_withdraw" => Ok(GeneratedField::ActionDutchAuctionWithdraw),
                             "ics20Withdrawal" | "ics20_withdrawal" => Ok(GeneratedField::Ics20Withdrawal),
+                            "nftWithdrawal" | "nft_withdrawal" => Ok(GeneratedField::NftWithdrawal),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -338,6 +352,13 @@ impl<'de> serde::Deserialize<'de> for Action {
                                 return Err(serde::de::Error::duplicate_field("positionWithdraw"));
                             }
                             action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::PositionWithdraw)
+;
+                        }
+                        GeneratedField::GaugeFund => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gaugeFund"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::GaugeFund)
 ;
                         }
                         GeneratedField::PositionRewardClaim => {
@@ -415,6 +436,13 @@ impl<'de> serde::Deserialize<'de> for Action {
                                 return Err(serde::de::Error::duplicate_field("ics20Withdrawal"));
                             }
                             action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::Ics20Withdrawal)
+;
+                        }
+                        GeneratedField::NftWithdrawal => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nftWithdrawal"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::NftWithdrawal)
 ;
                         }
                         GeneratedField::__SkipField__ => {
@@ -480,12 +508,18 @@ impl serde::Serialize for ActionPlan {
                 action_plan::Action::Ics20Withdrawal(v) => {
                     struct_ser.serialize_field("ics20Withdrawal", v)?;
                 }
+                action_plan::Action::NftWithdrawal(v) => {
+                    struct_ser.serialize_field("nftWithdrawal", v)?;
+                }
                 action_plan::Action::PositionOpen(v) => {
                     struct_ser.serialize_field("positionOpen", v)?;
                 }
                 action_plan::Action::PositionClose(v) => {
                     struct_ser.serialize_field("positionClose", v)?;
                 }
+                action_plan::Action::GaugeFund(v) => {
+                    struct_ser.serialize_field("gaugeFund", v)?;
+                }
                 action_plan::Action::PositionWithdraw(v) => {
                     struct_ser.serialize_field("positionWithdraw", v)?;
                 }
@@ -552,10 +586,14 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
             "proposalDepositClaim",
             "ics20_withdrawal",
             "ics20Withdrawal",
+            "nft_withdrawal",
+            "nftWithdrawal",
             "position_open",
             "positionOpen",
             "position_close",
             "positionClose",
+            "gauge_fund",
+            "gaugeFund",
             "position_withdraw",
             "positionWithdraw",
             "position_reward_claim",
@@ -592,8 +630,10 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
             DelegatorVote,
             ProposalDepositClaim,
             Ics20Withdrawal,
+            NftWithdrawal,
             PositionOpen,
             PositionClose,
+            GaugeFund,
             PositionWithdraw,
             PositionRewardClaim,
             Delegate,
@@ -639,8 +679,10 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
                             "delegatorVote" | "delegator_vote" => Ok(GeneratedField::DelegatorVote),
                             "proposalDepositClaim" | "proposal_deposit_claim" => Ok(GeneratedField::ProposalDepositClaim),
                             "ics20Withdrawal" | "ics20_withdrawal" => Ok(GeneratedField::Ics20Withdrawal),
+                            "nftWithdrawal" | "nft_withdrawal" => Ok(GeneratedField::NftWithdrawal),
                             "positionOpen" | "position_open" => Ok(GeneratedField::PositionOpen),
                             "positionClose" | "position_close" => Ok(GeneratedField::PositionClose),
+                            "gaugeFund" | "gauge_fund" => Ok(GeneratedField::GaugeFund),
                             "positionWithdraw" | "position_withdraw" => Ok(GeneratedField::PositionWithdraw),
                             "positionRewardClaim" | "position_reward_claim" => Ok(GeneratedField::PositionRewardClaim),
                             "delegate" => Ok(GeneratedField::Delegate),
@@ -756,6 +798,13 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
                                 return Err(serde::de::Error::duplicate_field("ics20Withdrawal"));
                             }
                             action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::Ics20Withdrawal)
+;
+                        }
+                        GeneratedField::NftWithdrawal => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nftWithdrawal"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::NftWithdrawal)
 ;
                         }
                         GeneratedField::PositionOpen => {
@@ -770,6 +819,13 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
                                 return Err(serde::de::Error::duplicate_field("positionClose"));
                             }
                             action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::PositionClose)
+;
+                        }
+                        GeneratedField::GaugeFund => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gaugeFund"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::GaugeFund)
 ;
                         }
                         GeneratedField::PositionWithdraw => {
@@ -918,6 +974,9 @@ impl serde::Serialize for ActionView {
                 action_view::ActionView::PositionWithdraw(v) => {
                     struct_ser.serialize_field("positionWithdraw", v)?;
                 }
+                action_view::ActionView::GaugeFund(v) => {
+                    struct_ser.serialize_field("gaugeFund", v)?;
+                }
                 action_view::ActionView::PositionRewardClaim(v) => {
                     struct_ser.serialize_field("positionRewardClaim", v)?;
                 }
@@ -951,6 +1010,9 @@ impl serde::Serialize for ActionView {
                 action_view::ActionView::Ics20Withdrawal(v) => {
                     struct_ser.serialize_field("ics20Withdrawal", v)?;
                 }
+                action_view::ActionView::NftWithdrawal(v) => {
+                    struct_ser.serialize_field("nftWithdrawal", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -988,6 +1050,8 @@ impl<'de> serde::Deserialize<'de> for ActionView {
             "positionClose",
             "position_withdraw",
             "positionWithdraw",
+            "gauge_fund",
+            "gaugeFund",
             "position_reward_claim",
             "positionRewardClaim",
             "delegate",
@@ -1008,6 +1072,8 @@ impl<'de> serde::Deserialize<'de> for ActionView {
             "undelegateClaim",
             "ics20_withdrawal",
             "ics20Withdrawal",
+            "nft_withdrawal",
+            "nftWithdrawal",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1026,6 +1092,7 @@ impl<'de> serde::Deserialize<'de> for ActionView {
             PositionOpen,
             PositionClose,
             PositionWithdraw,
+            GaugeFund,
             PositionRewardClaim,
             Delegate,
             Undelegate,
@@ -1037,6 +1104,7 @@ impl<'de> serde::Deserialize<'de> for ActionView {
             ActionDutchAuctionWithdraw,
             UndelegateClaim,
             Ics20Withdrawal,
+            NftWithdrawal,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1073,6 +1141,7 @@ impl<'de> serde::Deserialize<'de> for ActionView {
                             "positionOpen" | "position_open" => Ok(GeneratedField::PositionOpen),
                             "positionClose" | "position_close" => Ok(GeneratedField::PositionClose),
                             "positionWithdraw" | "position_withdraw" => Ok(GeneratedField::PositionWithdraw),
+                            "gaugeFund" | "gauge_fund" => Ok(GeneratedField::GaugeFund),
                             "positionRewardClaim" | "position_reward_claim" => Ok(GeneratedField::PositionRewardClaim),
                             "delegate" => Ok(GeneratedField::Delegate),
                             "undelegate" => Ok(GeneratedField::Undelegate),
@@ -1084,6 +1153,7 @@ impl<'de> serde::Deserialize<'de> for ActionView {
                             "actionDutchAuctionWithdraw" | "action_dutch_auction_withdraw" => Ok(GeneratedField::ActionDutchAuctionWithdraw),
                             "undelegateClaim" | "undelegate_claim" => Ok(GeneratedField::UndelegateClaim),
                             "ics20Withdrawal" | "ics20_withdrawal" => Ok(GeneratedField::Ics20Withdrawal),
+                            "nftWithdrawal" | "nft_withdrawal" => Ok(GeneratedField::NftWithdrawal),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1202,6 +1272,13 @@ impl<'de> serde::Deserialize<'de> for ActionView {
                                 return Err(serde::de::Error::duplicate_field("positionWithdraw"));
                             }
                             action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::PositionWithdraw)
+;
+                        }
+                        GeneratedField::GaugeFund => {
+                            if action_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gaugeFund"));
+                            }
+                            action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::GaugeFund)
 ;
                         }
                         GeneratedField::PositionRewardClaim => {
@@ -1279,6 +1356,13 @@ impl<'de> serde::Deserialize<'de> for ActionView {
                                 return Err(serde::de::Error::duplicate_field("ics20Withdrawal"));
                             }
                             action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::Ics20Withdrawal)
+;
+                        }
+                        GeneratedField::NftWithdrawal => {
+                            if action_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nftWithdrawal"));
+                            }
+                            action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::NftWithdrawal)
 ;
                         }
                         GeneratedField::__SkipField__ => {
@@ -1866,6 +1950,9 @@ impl serde::Serialize for MemoPlaintext {
         if !self.text.is_empty() {
             len += 1;
         }
+        if !self.return_address_proof.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.transaction.v1.MemoPlaintext", len)?;
         if let Some(v) = self.return_address.as_ref() {
             struct_ser.serialize_field("returnAddress", v)?;
@@ -1873,6 +1960,10 @@ impl serde::Serialize for MemoPlaintext {
         if !self.text.is_empty() {
             struct_ser.serialize_field("text", &self.text)?;
         }
+        if !self.return_address_proof.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("returnAddressProof", pbjson::private::base64::encode(&self.return_address_proof).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -1886,12 +1977,15 @@ impl<'de> serde::Deserialize<'de> for MemoPlaintext {
             "return_address",
             "returnAddress",
             "text",
+            "return_address_proof",
+            "returnAddressProof",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             ReturnAddress,
             Text,
+            ReturnAddressProof,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1916,6 +2010,7 @@ impl<'de> serde::Deserialize<'de> for MemoPlaintext {
                         match value {
                             "returnAddress" | "return_address" => Ok(GeneratedField::ReturnAddress),
                             "text" => Ok(GeneratedField::Text),
+                            "returnAddressProof" | "return_address_proof" => Ok(GeneratedField::ReturnAddressProof),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1937,6 +2032,7 @@ impl<'de> serde::Deserialize<'de> for MemoPlaintext {
             {
                 let mut return_address__ = None;
                 let mut text__ = None;
+                let mut return_address_proof__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::ReturnAddress => {
@@ -1951,6 +2047,14 @@ impl<'de> serde::Deserialize<'de> for MemoPlaintext {
                             }
                             text__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::ReturnAddressProof => {
+                            if return_address_proof__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("returnAddressProof"));
+                            }
+                            return_address_proof__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -1959,6 +2063,7 @@ impl<'de> serde::Deserialize<'de> for MemoPlaintext {
                 Ok(MemoPlaintext {
                     return_address: return_address__,
                     text: text__.unwrap_or_default(),
+                    return_address_proof: return_address_proof__.unwrap_or_default(),
                 })
             }
         }
@@ -1979,6 +2084,9 @@ impl serde::Serialize for MemoPlaintextView {
         if !self.text.is_empty() {
             len += 1;
         }
+        if self.return_address_authenticated {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.transaction.v1.MemoPlaintextView", len)?;
         if let Some(v) = self.return_address.as_ref() {
             struct_ser.serialize_field("returnAddress", v)?;
@@ -1986,6 +2094,9 @@ impl serde::Serialize for MemoPlaintextView {
         if !self.text.is_empty() {
             struct_ser.serialize_field("text", &self.text)?;
         }
+        if self.return_address_authenticated {
+            struct_ser.serialize_field("returnAddressAuthenticated", &self.return_address_authenticated)?;
+        }
         struct_ser.end()
     }
 }
@@ -1999,12 +2110,15 @@ impl<'de> serde::Deserialize<'de> for MemoPlaintextView {
             "return_address",
             "returnAddress",
             "text",
+            "return_address_authenticated",
+            "returnAddressAuthenticated",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             ReturnAddress,
             Text,
+            ReturnAddressAuthenticated,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2029,6 +2143,7 @@ impl<'de> serde::Deserialize<'de> for MemoPlaintextView {
                         match value {
                             "returnAddress" | "return_address" => Ok(GeneratedField::ReturnAddress),
                             "text" => Ok(GeneratedField::Text),
+                            "returnAddressAuthenticated" | "return_address_authenticated" => Ok(GeneratedField::ReturnAddressAuthenticated),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2050,6 +2165,7 @@ impl<'de> serde::Deserialize<'de> for MemoPlaintextView {
             {
                 let mut return_address__ = None;
                 let mut text__ = None;
+                let mut return_address_authenticated__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::ReturnAddress => {
@@ -2064,6 +2180,12 @@ impl<'de> serde::Deserialize<'de> for MemoPlaintextView {
                             }
                             text__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::ReturnAddressAuthenticated => {
+                            if return_address_authenticated__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("returnAddressAuthenticated"));
+                            }
+                            return_address_authenticated__ = Some(map_.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -2072,6 +2194,7 @@ impl<'de> serde::Deserialize<'de> for MemoPlaintextView {
                 Ok(MemoPlaintextView {
                     return_address: return_address__,
                     text: text__.unwrap_or_default(),
+                    return_address_authenticated: return_address_authenticated__.unwrap_or_default(),
                 })
             }
         }
@@ -61,3 +61,125 @@ impl ::prost::Name for MerklePathChunk {
         ::prost::alloc::format!("penumbra.crypto.tct.v1.{}", Self::NAME)
     }
 }
+/// The changes made to a state commitment tree, e.g. by a block, as they would
+/// be written to the tree's storage.
+///
+/// Applying them to a store of the tree as it was before these changes brings
+/// it up to date, without re-inserting every commitment.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TreeUpdates {
+    /// The new position of the tree, if it changed.
+    #[prost(message, optional, tag = "1")]
+    pub set_position: ::core::option::Option<tree_updates::StoredPosition>,
+    /// The new forgotten version of the tree, if it changed.
+    #[prost(message, optional, tag = "2")]
+    pub set_forgotten: ::core::option::Option<tree_updates::Forgotten>,
+    /// The new commitments to store.
+    #[prost(message, repeated, tag = "3")]
+    pub store_commitments: ::prost::alloc::vec::Vec<tree_updates::StoreCommitment>,
+    /// The new hashes to store.
+    #[prost(message, repeated, tag = "4")]
+    pub store_hashes: ::prost::alloc::vec::Vec<tree_updates::StoreHash>,
+    /// The ranges of hashes and commitments to delete.
+    #[prost(message, repeated, tag = "5")]
+    pub delete_ranges: ::prost::alloc::vec::Vec<tree_updates::DeleteRange>,
+}
+/// Nested message and enum types in `TreeUpdates`.
+pub mod tree_updates {
+    /// A position of the tree.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct StoredPosition {
+        /// The position of the tree, unless it is full.
+        #[prost(uint64, tag = "1")]
+        pub position: u64,
+        /// Whether the tree is full, in which case it has no position.
+        #[prost(bool, tag = "2")]
+        pub full: bool,
+    }
+    impl ::prost::Name for StoredPosition {
+        const NAME: &'static str = "StoredPosition";
+        const PACKAGE: &'static str = "penumbra.crypto.tct.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!("penumbra.crypto.tct.v1.TreeUpdates.{}", Self::NAME)
+        }
+    }
+    /// A version of the forgotten count of the tree.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Forgotten {
+        #[prost(uint64, tag = "1")]
+        pub version: u64,
+    }
+    impl ::prost::Name for Forgotten {
+        const NAME: &'static str = "Forgotten";
+        const PACKAGE: &'static str = "penumbra.crypto.tct.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!("penumbra.crypto.tct.v1.TreeUpdates.{}", Self::NAME)
+        }
+    }
+    /// A commitment to store, which was inserted into the tree to be witnessed.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct StoreCommitment {
+        #[prost(uint64, tag = "1")]
+        pub position: u64,
+        #[prost(message, optional, tag = "2")]
+        pub commitment: ::core::option::Option<super::StateCommitment>,
+    }
+    impl ::prost::Name for StoreCommitment {
+        const NAME: &'static str = "StoreCommitment";
+        const PACKAGE: &'static str = "penumbra.crypto.tct.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!("penumbra.crypto.tct.v1.TreeUpdates.{}", Self::NAME)
+        }
+    }
+    /// A hash to store, at some height above some position.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct StoreHash {
+        #[prost(uint64, tag = "1")]
+        pub position: u64,
+        #[prost(uint32, tag = "2")]
+        pub height: u32,
+        #[prost(bytes = "vec", tag = "3")]
+        pub hash: ::prost::alloc::vec::Vec<u8>,
+        /// Whether the hash must be stored, or can be recomputed if dropped.
+        #[prost(bool, tag = "4")]
+        pub essential: bool,
+    }
+    impl ::prost::Name for StoreHash {
+        const NAME: &'static str = "StoreHash";
+        const PACKAGE: &'static str = "penumbra.crypto.tct.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!("penumbra.crypto.tct.v1.TreeUpdates.{}", Self::NAME)
+        }
+    }
+    /// A half-open range of positions whose hashes strictly below some height,
+    /// and commitments, should be deleted.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct DeleteRange {
+        #[prost(uint32, tag = "1")]
+        pub below_height: u32,
+        #[prost(uint64, tag = "2")]
+        pub start: u64,
+        #[prost(uint64, tag = "3")]
+        pub end: u64,
+    }
+    impl ::prost::Name for DeleteRange {
+        const NAME: &'static str = "DeleteRange";
+        const PACKAGE: &'static str = "penumbra.crypto.tct.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!("penumbra.crypto.tct.v1.TreeUpdates.{}", Self::NAME)
+        }
+    }
+}
+impl ::prost::Name for TreeUpdates {
+    const NAME: &'static str = "TreeUpdates";
+    const PACKAGE: &'static str = "penumbra.crypto.tct.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.crypto.tct.v1.{}", Self::NAME)
+    }
+}
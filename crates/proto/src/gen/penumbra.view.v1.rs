@@ -536,6 +536,11 @@ pub mod transaction_planner_request {
         pub trading_pair: ::core::option::Option<
             super::super::super::core::component::dex::v1::TradingPair,
         >,
+        /// The rewards accrued by the position.
+        #[prost(message, repeated, tag = "4")]
+        pub rewards: ::prost::alloc::vec::Vec<
+            super::super::super::core::asset::v1::Value,
+        >,
     }
     impl ::prost::Name for PositionWithdraw {
         const NAME: &'static str = "PositionWithdraw";
@@ -1458,6 +1463,9 @@ pub struct SpendableNoteRecord {
     pub return_address: ::core::option::Option<
         super::super::core::keys::v1::AddressView,
     >,
+    /// Whether the sender proved that they control the return address.
+    #[prost(bool, tag = "10")]
+    pub return_address_authenticated: bool,
 }
 impl ::prost::Name for SpendableNoteRecord {
     const NAME: &'static str = "SpendableNoteRecord";
@@ -4427,6 +4427,9 @@ impl serde::Serialize for SpendableNoteRecord {
         if self.return_address.is_some() {
             len += 1;
         }
+        if self.return_address_authenticated {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.SpendableNoteRecord", len)?;
         if let Some(v) = self.note_commitment.as_ref() {
             struct_ser.serialize_field("noteCommitment", v)?;
@@ -4458,6 +4461,9 @@ impl serde::Serialize for SpendableNoteRecord {
         if let Some(v) = self.return_address.as_ref() {
             struct_ser.serialize_field("returnAddress", v)?;
         }
+        if self.return_address_authenticated {
+            struct_ser.serialize_field("returnAddressAuthenticated", &self.return_address_authenticated)?;
+        }
         struct_ser.end()
     }
 }
@@ -4482,6 +4488,8 @@ impl<'de> serde::Deserialize<'de> for SpendableNoteRecord {
             "source",
             "return_address",
             "returnAddress",
+            "return_address_authenticated",
+            "returnAddressAuthenticated",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -4495,6 +4503,7 @@ impl<'de> serde::Deserialize<'de> for SpendableNoteRecord {
             Position,
             Source,
             ReturnAddress,
+            ReturnAddressAuthenticated,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4526,6 +4535,7 @@ impl<'de> serde::Deserialize<'de> for SpendableNoteRecord {
                             "position" => Ok(GeneratedField::Position),
                             "source" => Ok(GeneratedField::Source),
                             "returnAddress" | "return_address" => Ok(GeneratedField::ReturnAddress),
+                            "returnAddressAuthenticated" | "return_address_authenticated" => Ok(GeneratedField::ReturnAddressAuthenticated),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4554,6 +4564,7 @@ impl<'de> serde::Deserialize<'de> for SpendableNoteRecord {
                 let mut position__ = None;
                 let mut source__ = None;
                 let mut return_address__ = None;
+                let mut return_address_authenticated__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::NoteCommitment => {
@@ -4616,6 +4627,12 @@ impl<'de> serde::Deserialize<'de> for SpendableNoteRecord {
                             }
                             return_address__ = map_.next_value()?;
                         }
+                        GeneratedField::ReturnAddressAuthenticated => {
+                            if return_address_authenticated__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("returnAddressAuthenticated"));
+                            }
+                            return_address_authenticated__ = Some(map_.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -4631,6 +4648,7 @@ impl<'de> serde::Deserialize<'de> for SpendableNoteRecord {
                     position: position__.unwrap_or_default(),
                     source: source__,
                     return_address: return_address__,
+                    return_address_authenticated: return_address_authenticated__.unwrap_or_default(),
                 })
             }
         }
@@ -7454,6 +7472,9 @@ impl serde::Serialize for transaction_planner_request::PositionWithdraw {
         if self.trading_pair.is_some() {
             len += 1;
         }
+        if !self.rewards.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.PositionWithdraw", len)?;
         if let Some(v) = self.position_id.as_ref() {
             struct_ser.serialize_field("positionId", v)?;
@@ -7464,6 +7485,9 @@ impl serde::Serialize for transaction_planner_request::PositionWithdraw {
         if let Some(v) = self.trading_pair.as_ref() {
             struct_ser.serialize_field("tradingPair", v)?;
         }
+        if !self.rewards.is_empty() {
+            struct_ser.serialize_field("rewards", &self.rewards)?;
+        }
         struct_ser.end()
     }
 }
@@ -7479,6 +7503,7 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionWithd
             "reserves",
             "trading_pair",
             "tradingPair",
+            "rewards",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -7486,6 +7511,7 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionWithd
             PositionId,
             Reserves,
             TradingPair,
+            Rewards,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -7511,6 +7537,7 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionWithd
                             "positionId" | "position_id" => Ok(GeneratedField::PositionId),
                             "reserves" => Ok(GeneratedField::Reserves),
                             "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "rewards" => Ok(GeneratedField::Rewards),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -7533,6 +7560,7 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionWithd
                 let mut position_id__ = None;
                 let mut reserves__ = None;
                 let mut trading_pair__ = None;
+                let mut rewards__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::PositionId => {
@@ -7553,6 +7581,12 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionWithd
                             }
                             trading_pair__ = map_.next_value()?;
                         }
+                        GeneratedField::Rewards => {
+                            if rewards__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewards"));
+                            }
+                            rewards__ = Some(map_.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -7562,6 +7596,7 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionWithd
                     position_id: position_id__,
                     reserves: reserves__,
                     trading_pair: trading_pair__,
+                    rewards: rewards__.unwrap_or_default(),
                 })
             }
         }
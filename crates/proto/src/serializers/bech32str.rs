@@ -205,6 +205,28 @@ pub mod full_viewing_key {
     }
 }
 
+pub mod full_viewing_key_export {
+    use super::*;
+
+    /// The Bech32 prefix used for exported full viewing keys.
+    pub const BECH32_PREFIX: &str = "penumbraviewingkey";
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_bech32(deserializer, BECH32_PREFIX, Variant::Bech32m)
+    }
+
+    pub fn serialize<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: AsRef<[u8]>,
+    {
+        serialize_bech32(value, serializer, BECH32_PREFIX, Variant::Bech32m)
+    }
+}
+
 pub mod wallet_id {
     use super::*;
 
@@ -3,7 +3,7 @@ use crate::{DomainType, Message};
 use anyhow::Result;
 use cnidarium::StateRead;
 use futures::{Stream, StreamExt};
-use std::{fmt::Debug, pin::Pin};
+use std::{fmt::Debug, ops::RangeBounds, pin::Pin};
 
 use super::future::{DomainFuture, ProtoFuture};
 
@@ -97,6 +97,33 @@ pub trait StateReadProto: StateRead + Send + Sync {
         }))
     }
 
+    /// Retrieve the values for keys matching a prefix from consensus-critical state, as domain
+    /// types, restricted to the keys whose remainder after the prefix lies in `range`.
+    ///
+    /// Keys are returned in ascending order, or in descending order if `reverse` is set.
+    #[allow(clippy::type_complexity)]
+    fn prefix_range<D>(
+        &self,
+        prefix: &str,
+        range: impl RangeBounds<str>,
+        reverse: bool,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<(String, D)>> + Send + 'static>>>
+    where
+        D: DomainType,
+        anyhow::Error: From<<D as TryFrom<D::Proto>>::Error>,
+    {
+        Ok(Box::pin(
+            self.prefix_range_proto(prefix, range, reverse)?
+                .map(|p| match p {
+                    Ok(p) => match D::try_from(p.1) {
+                        Ok(d) => Ok((p.0, d)),
+                        Err(e) => Err(e.into()),
+                    },
+                    Err(e) => Err(e),
+                }),
+        ))
+    }
+
     /// Retrieve all values for keys matching a prefix from nonverifiable storage, as domain types.
     #[allow(clippy::type_complexity)]
     fn nonverifiable_prefix<'a, D>(
@@ -136,6 +163,31 @@ pub trait StateReadProto: StateRead + Send + Sync {
         Box::pin(o)
     }
 
+    /// Retrieve the values for keys matching a prefix from consensus-critical state, as proto
+    /// types, restricted to the keys whose remainder after the prefix lies in `range`.
+    ///
+    /// Keys are returned in ascending order, or in descending order if `reverse` is set.
+    #[allow(clippy::type_complexity)]
+    fn prefix_range_proto<P>(
+        &self,
+        prefix: &str,
+        range: impl RangeBounds<str>,
+        reverse: bool,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<(String, P)>> + Send + 'static>>>
+    where
+        P: Message + Default,
+    {
+        let o = self.prefix_range_raw(prefix, range, reverse)?.map(|r| {
+            r.and_then(|(key, bytes)| {
+                Ok((
+                    key,
+                    Message::decode(&*bytes).map_err(|e| anyhow::anyhow!(e))?,
+                ))
+            })
+        });
+        Ok(Box::pin(o))
+    }
+
     /// Retrieve all values for keys matching a prefix from the nonverifiable key-value store, as proto types.
     #[allow(clippy::type_complexity)]
     fn nonverifiable_prefix_proto<'a, P>(
@@ -155,5 +207,59 @@ pub trait StateReadProto: StateRead + Send + Sync {
         });
         Box::pin(o)
     }
+
+    /// Retrieve the values for keys in a range from the nonverifiable key-value store, as domain
+    /// types, restricted to the keys starting with `prefix`, if one is given, in which case the
+    /// bounds of the range apply to the remainder of the keys after the prefix.
+    ///
+    /// Keys are returned in ascending byte order. As with `nonverifiable_range_raw`, inclusive
+    /// ranges are not supported.
+    #[allow(clippy::type_complexity)]
+    fn nonverifiable_range<D>(
+        &self,
+        prefix: Option<&[u8]>,
+        range: impl RangeBounds<Vec<u8>>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<(Vec<u8>, D)>> + Send + 'static>>>
+    where
+        D: DomainType,
+        anyhow::Error: From<<D as TryFrom<D::Proto>>::Error>,
+    {
+        Ok(Box::pin(
+            self.nonverifiable_range_proto(prefix, range)?
+                .map(|p| match p {
+                    Ok(p) => match D::try_from(p.1) {
+                        Ok(d) => Ok((p.0, d)),
+                        Err(e) => Err(e.into()),
+                    },
+                    Err(e) => Err(e),
+                }),
+        ))
+    }
+
+    /// Retrieve the values for keys in a range from the nonverifiable key-value store, as proto
+    /// types, restricted to the keys starting with `prefix`, if one is given, in which case the
+    /// bounds of the range apply to the remainder of the keys after the prefix.
+    ///
+    /// Keys are returned in ascending byte order. As with `nonverifiable_range_raw`, inclusive
+    /// ranges are not supported.
+    #[allow(clippy::type_complexity)]
+    fn nonverifiable_range_proto<P>(
+        &self,
+        prefix: Option<&[u8]>,
+        range: impl RangeBounds<Vec<u8>>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<(Vec<u8>, P)>> + Send + 'static>>>
+    where
+        P: Message + Default,
+    {
+        let o = self.nonverifiable_range_raw(prefix, range)?.map(|r| {
+            r.and_then(|(key, bytes)| {
+                Ok((
+                    key,
+                    Message::decode(&*bytes).map_err(|e| anyhow::anyhow!(e))?,
+                ))
+            })
+        });
+        Ok(Box::pin(o))
+    }
 }
 impl<T: StateRead + ?Sized> StateReadProto for T {}
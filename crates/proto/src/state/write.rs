@@ -22,7 +22,7 @@ pub trait StateWriteProto: StateWrite + Send + Sync {
         self.put_raw(key, value.encode_to_vec());
     }
 
-    /// Puts a domain type into the nonverifiable key-value store with the given key
+    /// Puts a domain type into the nonverifiable key-value store with the given key.
     fn nonverifiable_put<D>(&mut self, key: Vec<u8>, value: D)
     where
         D: DomainType,
@@ -31,7 +31,7 @@ pub trait StateWriteProto: StateWrite + Send + Sync {
         self.nonverifiable_put_raw(key, value.encode_to_vec());
     }
 
-    /// Puts a proto type into the verifiable key-value store with the given key.
+    /// Puts a proto type into the nonverifiable key-value store with the given key.
     fn nonverifiable_put_proto<P>(&mut self, key: Vec<u8>, value: P)
     where
         P: Message + Default + Debug,
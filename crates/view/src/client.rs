@@ -322,6 +322,62 @@ pub trait ViewClient {
         .boxed()
     }
 
+    /// Scans accounts for received notes, gap-limit style: starting at account
+    /// `start`, stop after `gap_limit` consecutive accounts that never received
+    /// anything. Returns all the notes, spent or not, of each account in use,
+    /// grouped by account and then by address index.
+    ///
+    /// Trial decryption with the incoming viewing key detects notes sent to any
+    /// address of the wallet, whichever its index, so a view service synced
+    /// from the seed phrase alone already holds all of them; this finds which
+    /// of the addresses, possibly generated out-of-band, were actually used.
+    #[instrument(skip(self))]
+    fn scan_accounts(
+        &mut self,
+        start: u32,
+        gap_limit: u32,
+    ) -> Pin<
+        Box<
+            dyn Future<
+                    Output = Result<
+                        BTreeMap<u32, BTreeMap<AddressIndex, Vec<SpendableNoteRecord>>>,
+                    >,
+                > + Send
+                + 'static,
+        >,
+    > {
+        let notes = self.notes(pb::NotesRequest {
+            include_spent: true,
+            ..Default::default()
+        });
+
+        async move {
+            let mut notes_by_account = BTreeMap::new();
+            for note_record in notes.await? {
+                notes_by_account
+                    .entry(note_record.address_index.account)
+                    .or_insert_with(BTreeMap::new)
+                    .entry(note_record.address_index)
+                    .or_insert_with(Vec::new)
+                    .push(note_record);
+            }
+
+            let mut accounts = BTreeMap::new();
+            let mut next = u64::from(start);
+            for (account, notes) in notes_by_account.range(start..) {
+                if u64::from(*account) - next >= u64::from(gap_limit) {
+                    break;
+                }
+                next = u64::from(*account) + 1;
+                accounts.insert(*account, notes.clone());
+            }
+            tracing::debug!(accounts = ?accounts.keys().collect::<Vec<_>>(), "scanned accounts");
+
+            Ok(accounts)
+        }
+        .boxed()
+    }
+
     fn address_by_index(
         &mut self,
         address_index: AddressIndex,
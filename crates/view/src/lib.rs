@@ -23,6 +23,7 @@ mod storage;
 mod swap_record;
 mod sync;
 mod transaction_info;
+mod witness_policy;
 mod worker;
 
 pub use crate::client::ViewClient;
@@ -34,3 +35,4 @@ pub use crate::status::StatusStreamResponse;
 pub use crate::storage::Storage;
 pub use crate::swap_record::SwapRecord;
 pub use crate::transaction_info::TransactionInfo;
+pub use crate::witness_policy::WitnessPolicy;
@@ -21,6 +21,8 @@ pub struct SpendableNoteRecord {
     pub position: Position,
     pub source: CommitmentSource,
     pub return_address: Option<AddressView>,
+    /// Whether the sender proved that they control the return address.
+    pub return_address_authenticated: bool,
 }
 
 impl DomainType for SpendableNoteRecord {
@@ -39,6 +41,7 @@ impl From<SpendableNoteRecord> for pb::SpendableNoteRecord {
             position: v.position.into(),
             source: Some(v.source.into()),
             return_address: v.return_address.map(Into::into),
+            return_address_authenticated: v.return_address_authenticated,
         }
     }
 }
@@ -75,6 +78,7 @@ impl TryFrom<pb::SpendableNoteRecord> for SpendableNoteRecord {
                 .ok_or_else(|| anyhow::anyhow!("missing note source"))?
                 .try_into()?,
             return_address: v.return_address.map(TryInto::try_into).transpose()?,
+            return_address_authenticated: v.return_address_authenticated,
         })
     }
 }
@@ -95,6 +99,12 @@ impl TryFrom<&Row<'_>> for SpendableNoteRecord {
             })
             .transpose()?
             .map(|a| AddressView::Opaque { address: a });
+        let return_address_authenticated = row
+            .get::<_, Option<bool>>("return_address_authenticated")
+            // If there's no return_address_authenticated column, the return address is unauthenticated
+            .ok()
+            .flatten()
+            .unwrap_or(false);
         Ok(SpendableNoteRecord {
             address_index: row.get::<_, Vec<u8>>("address_index")?[..].try_into()?,
             nullifier: row.get::<_, Vec<u8>>("nullifier")?[..].try_into()?,
@@ -112,6 +122,7 @@ impl TryFrom<&Row<'_>> for SpendableNoteRecord {
             )?,
             source: CommitmentSource::decode(&row.get::<_, Vec<u8>>("source")?[..])?,
             return_address,
+            return_address_authenticated,
         })
     }
 }
@@ -36,7 +36,10 @@ use penumbra_governance::{
     ProposalWithdraw, ValidatorVote, Vote,
 };
 use penumbra_ibc::IbcRelay;
-use penumbra_keys::{keys::AddressIndex, Address};
+use penumbra_keys::{
+    keys::{AddressIndex, IncomingViewingKey},
+    Address,
+};
 use penumbra_num::Amount;
 use penumbra_proto::view::v1::{NotesForVotingRequest, NotesRequest};
 use penumbra_shielded_pool::{Ics20Withdrawal, Note, OutputPlan, SpendPlan};
@@ -45,7 +48,7 @@ use penumbra_tct as tct;
 use penumbra_transaction::{
     memo::MemoPlaintext,
     plan::{ActionPlan, MemoPlan, TransactionPlan},
-    ActionList, TransactionParameters,
+    ActionList, PrivacyLevel, TransactionParameters,
 };
 
 /// A planner for a [`TransactionPlan`] that can fill in the required spends and change outputs upon
@@ -65,6 +68,8 @@ pub struct Planner<R: RngCore + CryptoRng> {
     memo_text: Option<String>,
     /// A user-specified memo return address, if any.
     memo_return_address: Option<Address>,
+    /// The key used to authenticate the memo return address, if requested.
+    memo_return_address_ivk: Option<IncomingViewingKey>,
 }
 
 impl<R: RngCore + CryptoRng> Debug for Planner<R> {
@@ -77,6 +82,10 @@ impl<R: RngCore + CryptoRng> Debug for Planner<R> {
             .field("change_address", &self.change_address)
             .field("memo_text", &self.memo_text)
             .field("memo_return_address", &self.memo_return_address)
+            .field(
+                "authenticate_memo_return_address",
+                &self.memo_return_address_ivk.is_some(),
+            )
             .finish()
     }
 }
@@ -93,6 +102,7 @@ impl<R: RngCore + CryptoRng> Planner<R> {
             change_address: None,
             memo_text: None,
             memo_return_address: None,
+            memo_return_address_ivk: None,
         }
     }
 
@@ -116,6 +126,13 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Set the privacy level, i.e. how the transaction's actions are ordered and padded.
+    #[instrument(skip(self))]
+    pub fn set_privacy_level(&mut self, privacy_level: PrivacyLevel) -> &mut Self {
+        self.action_list.set_privacy_level(privacy_level);
+        self
+    }
+
     /// Set the expiry height for the transaction.
     #[instrument(skip(self))]
     pub fn expiry_height(&mut self, expiry_height: u64) -> &mut Self {
@@ -143,6 +160,17 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Authenticate the return address for the memo, so that the recipient
+    /// knows that replies to it will reach the sender.
+    ///
+    /// The `ivk` is used to prove control of the return address, and limits
+    /// the memo text to 368 bytes.
+    #[instrument(skip(self, ivk))]
+    pub fn authenticate_memo_return_address(&mut self, ivk: IncomingViewingKey) -> &mut Self {
+        self.memo_return_address_ivk = Some(ivk);
+        self
+    }
+
     /// Set the change address for the transaction.
     ///
     /// If unset, this will default to the address for the source account.
@@ -186,22 +214,23 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
-    /// Withdraw a liquidity position in the order book.
+    /// Withdraw a liquidity position in the order book, along with the rewards it accrued.
     ///
-    /// Note: Currently this only supports an initial withdrawal from Closed, with no rewards.
+    /// Note: Currently this only supports an initial withdrawal from Closed.
     #[instrument(skip(self))]
     pub fn position_withdraw(
         &mut self,
         position_id: position::Id,
         reserves: Reserves,
         pair: TradingPair,
+        rewards: Vec<Value>,
     ) -> &mut Self {
         self.action_list.push(PositionWithdrawPlan {
             reserves,
             position_id,
             pair,
             sequence: 0,
-            rewards: Vec::new(),
+            rewards,
         });
         self
     }
@@ -627,11 +656,16 @@ impl<R: RngCore + CryptoRng> Planner<R> {
                 view.address_by_index(source).await?.clone()
             };
 
-            Some(MemoPlan::new(
-                &mut self.rng,
+            let mut plaintext =
                 MemoPlaintext::new(return_address, self.memo_text.take().unwrap_or_default())
-                    .context("could not create memo plaintext")?,
-            ))
+                    .context("could not create memo plaintext")?;
+            if let Some(ivk) = self.memo_return_address_ivk.take() {
+                plaintext = plaintext
+                    .authenticate_return_address(&ivk)
+                    .context("could not authenticate memo return address")?;
+            }
+
+            Some(MemoPlan::new(&mut self.rng, plaintext))
         } else {
             None
         };
@@ -664,6 +698,7 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self.change_address = None;
         self.memo_text = None;
         self.memo_return_address = None;
+        self.memo_return_address_ivk = None;
 
         Ok(plan)
     }
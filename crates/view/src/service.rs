@@ -60,7 +60,7 @@ use penumbra_transaction::{
     AuthorizationData, Transaction, TransactionPerspective, TransactionPlan, WitnessData,
 };
 
-use crate::{worker::Worker, Planner, Storage};
+use crate::{worker::Worker, Planner, Storage, WitnessPolicy};
 
 /// A [`futures::Stream`] of broadcast transaction responses.
 ///
@@ -150,6 +150,17 @@ impl ViewServer {
         })
     }
 
+    /// Sets the [`WitnessPolicy`] deciding which witnesses the view server keeps, and forgets
+    /// those of the spent notes and claimed swaps it doesn't keep.
+    pub async fn set_witness_policy(&self, policy: WitnessPolicy) -> anyhow::Result<()> {
+        // Hold the SCT throughout, so that the worker doesn't record a block in the meantime.
+        let mut sct = self.state_commitment_tree.write().await;
+        self.storage.set_witness_policy(policy).await?;
+        let forgotten = self.storage.prune_witnesses(&mut sct).await?;
+        tracing::debug!(%policy, forgotten, "set witness policy");
+        Ok(())
+    }
+
     /// Checks if the view server worker has encountered an error.
     ///
     /// This function returns a gRPC [`tonic::Status`] containing the view server worker error if
@@ -727,7 +738,16 @@ impl ViewService for ViewServer {
                     tonic::Status::invalid_argument(format!("Could not parse pair: {e:#}"))
                 })?;
 
-            planner.position_withdraw(position_id, reserves, trading_pair);
+            let rewards = position_withdraw
+                .rewards
+                .into_iter()
+                .map(Value::try_from)
+                .collect::<anyhow::Result<Vec<_>>>()
+                .map_err(|e| {
+                    tonic::Status::invalid_argument(format!("Could not parse rewards: {e:#}"))
+                })?;
+
+            planner.position_withdraw(position_id, reserves, trading_pair, rewards);
         }
 
         // Insert any ICS20 withdrawals.
@@ -1604,11 +1624,16 @@ impl ViewService for ViewServer {
                 tonic::Status::failed_precondition("Error retrieving full viewing key")
             })?;
 
+        // TODO: building should provide some mechanism to get progress updates
+        #[cfg(not(feature = "parallel"))]
+        let transaction = transaction_plan.build(&fvk, &witness_data, &authorization_data);
+        #[cfg(feature = "parallel")]
+        let transaction = transaction_plan
+            .build_concurrent(&fvk, &witness_data, &authorization_data)
+            .await;
+
         let transaction = Some(
-            transaction_plan
-                // TODO: calling `.build` should provide some mechanism to get progress
-                // updates
-                .build(&fvk, &witness_data, &authorization_data)
+            transaction
                 .map_err(|_| tonic::Status::failed_precondition("Error building transaction"))?
                 .into(),
         );
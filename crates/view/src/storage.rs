@@ -42,7 +42,7 @@ use penumbra_transaction::Transaction;
 use sct::TreeStore;
 use tct::StateCommitment;
 
-use crate::{sync::FilteredBlock, SpendableNoteRecord, SwapRecord};
+use crate::{sync::FilteredBlock, SpendableNoteRecord, SwapRecord, WitnessPolicy};
 
 mod sct;
 
@@ -255,6 +255,58 @@ impl Storage {
         .await?
     }
 
+    /// Writes a checkpoint of the sync state, i.e. a consistent copy of the
+    /// whole database, with the SCT, note records and sync height, to `path`.
+    ///
+    /// The checkpoint can be imported on another machine with
+    /// [`Self::import_checkpoint`], to resume syncing from where it was taken.
+    pub async fn export_checkpoint(&self, path: impl AsRef<Utf8Path>) -> anyhow::Result<()> {
+        let path = path.as_ref().to_owned();
+        if path.exists() {
+            anyhow::bail!("refusing to overwrite existing file at {}", path);
+        }
+
+        // Empty blocks scanned since the last commit aren't part of the
+        // checkpoint; they're just synced again after importing it.
+        let pool = self.pool.clone();
+        spawn_blocking(move || {
+            pool.get()?
+                .execute("VACUUM INTO ?1", [path.as_str()])
+                .with_context(|| format!("failed to write checkpoint to {}", path))?;
+            Ok(())
+        })
+        .await?
+    }
+
+    /// Imports the checkpoint at `checkpoint_path`, written by
+    /// [`Self::export_checkpoint`], as the database at `storage_path`.
+    ///
+    /// The checkpoint must have been taken by the same client version, of the
+    /// wallet of `fvk`.
+    pub async fn import_checkpoint(
+        checkpoint_path: impl AsRef<Utf8Path>,
+        storage_path: impl AsRef<Utf8Path>,
+        fvk: &FullViewingKey,
+    ) -> anyhow::Result<Self> {
+        let storage_path = storage_path.as_ref();
+        if storage_path.exists() {
+            anyhow::bail!(
+                "view database already exists at {}; refusing to overwrite it",
+                storage_path
+            );
+        }
+
+        let checkpoint = Self::load(checkpoint_path)
+            .await
+            .context("failed to load checkpoint")?;
+        if checkpoint.full_viewing_key().await? != *fvk {
+            anyhow::bail!("checkpoint belongs to another wallet");
+        }
+        checkpoint.export_checkpoint(storage_path).await?;
+
+        Self::load(storage_path).await
+    }
+
     /// Loads asset metadata from a JSON file and use to update the database.
     pub async fn load_asset_metadata(
         &self,
@@ -387,7 +439,8 @@ impl Storage {
                         spendable_notes.height_spent,
                         spendable_notes.nullifier,
                         spendable_notes.position,
-                        tx.return_address
+                        tx.return_address,
+                        tx.return_address_authenticated
                     FROM notes
                     JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
                     LEFT JOIN tx ON spendable_notes.tx_hash = tx.tx_hash
@@ -644,6 +697,102 @@ impl Storage {
         .await?
     }
 
+    /// The policy deciding which witnesses to keep in the state commitment tree.
+    pub async fn witness_policy(&self) -> anyhow::Result<WitnessPolicy> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let bytes = pool
+                .get()?
+                .prepare_cached("SELECT v FROM kv WHERE k IS 'witness_policy' LIMIT 1")?
+                .query_row([], |row| row.get::<_, Option<Vec<u8>>>("v"))
+                .optional()?
+                .flatten();
+
+            match bytes {
+                Some(bytes) => std::str::from_utf8(&bytes)?.parse(),
+                None => Ok(WitnessPolicy::default()),
+            }
+        })
+        .await?
+    }
+
+    /// Sets the policy deciding which witnesses to keep in the state commitment tree.
+    ///
+    /// This only applies to notes and swaps spent or claimed from now on: call
+    /// [`Storage::prune_witnesses`] to forget the witnesses the policy doesn't keep.
+    pub async fn set_witness_policy(&self, policy: WitnessPolicy) -> anyhow::Result<()> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?.execute(
+                "INSERT INTO kv (k, v) VALUES ('witness_policy', ?1)
+                ON CONFLICT(k) DO UPDATE SET v = excluded.v",
+                [policy.to_string().as_bytes()],
+            )?;
+
+            anyhow::Ok(())
+        })
+        .await?
+    }
+
+    /// Forgets the witnesses of the spent notes and claimed swaps that the current
+    /// [`WitnessPolicy`] doesn't keep, returning how many were forgotten.
+    pub async fn prune_witnesses(&self, sct: &mut tct::Tree) -> anyhow::Result<usize> {
+        let witness_policy = self.witness_policy().await?;
+        let pool = self.pool.clone();
+
+        // As in `record_block`, the SCT is only replaced once the database transaction commits.
+        let mut new_sct = sct.clone();
+
+        let (new_sct, forgotten) = spawn_blocking(move || {
+            let mut lock = pool.get()?;
+            let mut dbtx = lock.transaction()?;
+
+            let spent_notes = dbtx
+                .prepare(
+                    "SELECT spendable_notes.note_commitment, assets.denom
+                    FROM spendable_notes
+                    JOIN notes ON notes.note_commitment = spendable_notes.note_commitment
+                    LEFT JOIN assets ON assets.asset_id = notes.asset_id
+                    WHERE spendable_notes.height_spent IS NOT NULL",
+                )?
+                .query_and_then([], |row| {
+                    let commitment = row.get::<_, Vec<u8>>("note_commitment")?;
+                    let denom = row.get::<_, Option<String>>("denom")?;
+                    anyhow::Ok((commitment, denom.unwrap_or_else(|| "unknown".to_string())))
+                })?
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            let claimed_swaps = dbtx
+                .prepare("SELECT swap_commitment FROM swaps WHERE height_claimed IS NOT NULL")?
+                .query_and_then([], |row| row.get::<_, Vec<u8>>("swap_commitment"))?
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let mut forgotten = 0;
+            for (commitment, denom) in spent_notes {
+                if !witness_policy.keeps_spent(&denom)
+                    && new_sct.forget(StateCommitment::try_from(commitment.as_slice())?)
+                {
+                    forgotten += 1;
+                }
+            }
+            for commitment in claimed_swaps {
+                if new_sct.forget(StateCommitment::try_from(commitment.as_slice())?) {
+                    forgotten += 1;
+                }
+            }
+
+            new_sct.to_writer(&mut TreeStore(&mut dbtx))?;
+            dbtx.commit()?;
+
+            anyhow::Ok((new_sct, forgotten))
+        })
+        .await??;
+
+        *sct = new_sct;
+        Ok(forgotten)
+    }
+
     pub async fn state_commitment_tree(&self) -> anyhow::Result<tct::Tree> {
         let pool = self.pool.clone();
         spawn_blocking(move || {
@@ -768,7 +917,8 @@ impl Storage {
                         spendable_notes.height_spent,
                         spendable_notes.nullifier,
                         spendable_notes.position,
-                        tx.return_address
+                        tx.return_address,
+                        tx.return_address_authenticated
                     FROM notes
                     JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
                     LEFT JOIN tx ON spendable_notes.tx_hash = tx.tx_hash
@@ -933,7 +1083,8 @@ impl Storage {
                         spendable_notes.height_spent,
                         spendable_notes.nullifier,
                         spendable_notes.position,
-                        tx.return_address
+                        tx.return_address,
+                        tx.return_address_authenticated
                 FROM notes
                 JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
                 LEFT JOIN tx ON spendable_notes.tx_hash = tx.tx_hash
@@ -1399,6 +1550,7 @@ impl Storage {
         let scanned_swaps_tx = self.scanned_swaps_tx.clone();
 
         let fvk = self.full_viewing_key().await?;
+        let witness_policy = self.witness_policy().await?;
 
         // If the app parameters have changed, update them.
         let new_app_parameters: Option<AppParameters> = if filtered_block.app_parameters_updated {
@@ -1562,11 +1714,11 @@ impl Storage {
                 // Mark spent notes as spent
                 if let Some(spent_commitment) = spent_commitment {
                     tracing::debug!(?nullifier, ?spent_commitment, ?spent_denom, "detected spent note commitment");
-                    // Forget spent note commitments from the SCT unless they are delegation tokens,
-                    // which must be saved to allow voting on proposals that might or might not be
-                    // open presently
+                    // Forget spent note commitments from the SCT unless the witness policy keeps
+                    // them, e.g. for delegation tokens, which may still be used to vote on
+                    // proposals that might or might not be open presently
 
-                    if DelegationToken::from_str(&spent_denom).is_err() {
+                    if !witness_policy.keeps_spent(&spent_denom) {
                         tracing::debug!(?nullifier, ?spent_commitment, ?spent_denom, "forgetting spent note commitment");
                         new_sct.forget(spent_commitment);
                     }
@@ -1591,13 +1743,14 @@ impl Storage {
                 let tx_block_height = filtered_block.height as i64;
                 let decrypted_memo = transaction.decrypt_memo(&fvk).ok();
                 let memo_text = decrypted_memo.clone().map_or(None,|x| Some(x.text().to_string()));
+                let return_address_authenticated = decrypted_memo.as_ref().map_or(false, |x| x.return_address_authenticated());
                 let return_address = decrypted_memo.map_or(None, |x| Some(x.return_address().to_vec()));
 
                 tracing::debug!(tx_hash = ?hex::encode(tx_hash), "recording extended transaction");
 
                 dbtx.execute(
-                    "INSERT OR IGNORE INTO tx (tx_hash, tx_bytes, block_height, return_address, memo_text) VALUES (?1, ?2, ?3, ?4, ?5)",
-                    (&tx_hash, &tx_bytes, tx_block_height, return_address, memo_text),
+                    "INSERT OR IGNORE INTO tx (tx_hash, tx_bytes, block_height, return_address, return_address_authenticated, memo_text) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                    (&tx_hash, &tx_bytes, tx_block_height, return_address, return_address_authenticated, memo_text),
                 )?;
 
                 // Associate all of the spent nullifiers with the transaction by hash.
@@ -55,6 +55,8 @@ CREATE TABLE tx (
     tx_bytes                BLOB NOT NULL,
     block_height            BIGINT NOT NULL,
     return_address          BLOB,
+    -- whether the sender proved that they control the return address
+    return_address_authenticated BOOLEAN NOT NULL DEFAULT FALSE,
     memo_text               TEXT
 );
 
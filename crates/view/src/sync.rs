@@ -1,16 +1,103 @@
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, future::Future, sync::Arc};
 
+use anyhow::Context;
 use penumbra_compact_block::{CompactBlock, StatePayload};
-use penumbra_dex::swap::{SwapPayload, SwapPlaintext};
+use penumbra_dex::swap::SwapPlaintext;
 use penumbra_fee::GasPrices;
 use penumbra_keys::FullViewingKey;
 use penumbra_sct::Nullifier;
-use penumbra_shielded_pool::{fmd, Note, NotePayload};
+use penumbra_shielded_pool::{fmd, Note};
 use penumbra_tct::{self as tct, StateCommitment};
-use tracing::Instrument;
+use rayon::prelude::*;
 
 use crate::{SpendableNoteRecord, Storage, SwapRecord};
 
+/// The fewest payloads a thread of a [`TrialDecryptor`] decrypts at once, so
+/// that the payloads of small blocks aren't split across many threads.
+const TRIAL_DECRYPTION_BATCH_SIZE: usize = 16;
+
+/// A pool of threads trial-decrypting the payloads of compact blocks with a
+/// full viewing key.
+///
+/// The payloads of a block are split into batches decrypted in parallel, and
+/// since decryption doesn't depend on the state of the view service, blocks
+/// can be decrypted ahead of being scanned, so that several are decrypted at
+/// once.
+#[derive(Clone)]
+pub struct TrialDecryptor {
+    fvk: Arc<FullViewingKey>,
+    pool: Arc<rayon::ThreadPool>,
+}
+
+/// The notes and swaps of a block that were meant for us, found by a
+/// [`TrialDecryptor`].
+#[derive(Debug, Default)]
+pub struct DecryptedPayloads {
+    notes: Vec<Note>,
+    swaps: Vec<SwapPlaintext>,
+}
+
+impl DecryptedPayloads {
+    fn extend(mut self, other: Self) -> Self {
+        self.notes.extend(other.notes);
+        self.swaps.extend(other.swaps);
+        self
+    }
+}
+
+impl TrialDecryptor {
+    /// Creates a pool trial-decrypting with `fvk` on a thread per core.
+    pub fn new(fvk: FullViewingKey) -> anyhow::Result<Self> {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .thread_name(|i| format!("trial-decryption-{i}"))
+            .build()
+            .context("failed to start trial decryption threads")?;
+        Ok(Self {
+            fvk: Arc::new(fvk),
+            pool: Arc::new(pool),
+        })
+    }
+
+    /// Trial-decrypts the note and swap payloads of `block` on the pool.
+    ///
+    /// Decryption starts right away, rather than when the returned future is
+    /// first polled.
+    pub fn decrypt(
+        &self,
+        block: &CompactBlock,
+    ) -> impl Future<Output = anyhow::Result<DecryptedPayloads>> {
+        let payloads = block.state_payloads.clone();
+        let fvk = self.fvk.clone();
+        let span = tracing::Span::current();
+        let (tx, rx) = tokio::sync::oneshot::channel();
+
+        self.pool.spawn(move || {
+            let decrypted = span.in_scope(|| {
+                payloads
+                    .par_iter()
+                    .with_min_len(TRIAL_DECRYPTION_BATCH_SIZE)
+                    .fold(DecryptedPayloads::default, |mut decrypted, payload| {
+                        match payload {
+                            StatePayload::Note { note, .. } => {
+                                decrypted.notes.extend(note.trial_decrypt(&fvk));
+                            }
+                            StatePayload::Swap { swap, .. } => {
+                                decrypted.swaps.extend(swap.trial_decrypt(&fvk));
+                            }
+                            StatePayload::RolledUp { .. } => {}
+                        }
+                        decrypted
+                    })
+                    .reduce(DecryptedPayloads::default, DecryptedPayloads::extend)
+            });
+            // The receiver is gone if the sync was interrupted.
+            let _ = tx.send(decrypted);
+        });
+
+        async move { rx.await.context("trial decryption was interrupted") }
+    }
+}
+
 /// Contains the results of scanning a single block.
 #[derive(Debug, Clone)]
 pub struct FilteredBlock {
@@ -41,67 +128,30 @@ pub async fn scan_block(
         // proposal_started,
         ..
     }: CompactBlock,
+    decrypted: DecryptedPayloads,
     storage: &Storage,
 ) -> anyhow::Result<FilteredBlock> {
-    // Trial-decrypt a note with our own specific viewing key
-    let trial_decrypt_note = |note_payload: NotePayload| -> tokio::task::JoinHandle<Option<Note>> {
-        // TODO: change fvk to Arc<FVK> in Worker and pass to scan_block as Arc
-        // need this so the task is 'static and not dependent on key lifetime
-        let fvk2 = fvk.clone();
-        tokio::spawn(
-            async move { note_payload.trial_decrypt(&fvk2) }.instrument(tracing::Span::current()),
-        )
-    };
-    // Trial-decrypt a swap with our own specific viewing key
-    let trial_decrypt_swap =
-        |swap_payload: SwapPayload| -> tokio::task::JoinHandle<Option<SwapPlaintext>> {
-            // TODO: change fvk to Arc<FVK> in Worker and pass to scan_block as Arc
-            // need this so the task is 'static and not dependent on key lifetime
-            let fvk2 = fvk.clone();
-            tokio::spawn(
-                async move { swap_payload.trial_decrypt(&fvk2) }
-                    .instrument(tracing::Span::current()),
-            )
-        };
-
     // Nullifiers we've found in this block
     let spent_nullifiers: Vec<Nullifier> = nullifiers;
 
-    // Trial-decrypt the notes in this block, keeping track of the ones that were meant for us
-    let mut note_decryptions = Vec::new();
-    let mut swap_decryptions = Vec::new();
-    let mut unknown_commitments = Vec::new();
-
-    for payload in state_payloads.iter() {
-        match payload {
-            StatePayload::Note { note, .. } => {
-                note_decryptions.push(trial_decrypt_note((**note).clone()));
-            }
-            StatePayload::Swap { swap, .. } => {
-                swap_decryptions.push(trial_decrypt_swap((**swap).clone()));
-            }
-            StatePayload::RolledUp { commitment, .. } => unknown_commitments.push(*commitment),
-        }
-    }
-    // Having started trial decryption in the background, ask the Storage for scanning advice:
+    // The notes and swaps were trial-decrypted ahead of time, so we only have
+    // to ask the Storage for scanning advice about the rolled up commitments:
+    let unknown_commitments = state_payloads
+        .iter()
+        .filter_map(|payload| match payload {
+            StatePayload::RolledUp { commitment, .. } => Some(*commitment),
+            _ => None,
+        })
+        .collect();
     let mut note_advice = storage.scan_advice(unknown_commitments).await?;
-    for decryption in note_decryptions {
-        if let Some(note) = decryption
-            .await
-            .expect("able to join tokio note decryption handle")
-        {
-            note_advice.insert(note.commit(), note);
-        }
-    }
-    let mut swap_advice = BTreeMap::new();
-    for decryption in swap_decryptions {
-        if let Some(swap) = decryption
-            .await
-            .expect("able to join tokio swap decryption handle")
-        {
-            swap_advice.insert(swap.swap_commitment(), swap);
-        }
+    for note in decrypted.notes {
+        note_advice.insert(note.commit(), note);
     }
+    let swap_advice: BTreeMap<_, _> = decrypted
+        .swaps
+        .into_iter()
+        .map(|swap| (swap.swap_commitment(), swap))
+        .collect();
 
     // Newly detected spendable notes.
     let mut new_notes = BTreeMap::new();
@@ -149,6 +199,7 @@ pub async fn scan_block(
                             position,
                             source,
                             return_address: None,
+                            return_address_authenticated: false,
                         },
                     );
                 }
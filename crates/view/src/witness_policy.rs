@@ -0,0 +1,50 @@
+use std::{fmt, str::FromStr};
+
+use penumbra_stake::DelegationToken;
+
+/// Which witnesses the view service keeps in its state commitment tree.
+///
+/// A witness is kept for each note and swap detected for the wallet, so that it can be spent or
+/// claimed. Once spent or claimed, it is forgotten unless the policy says otherwise, so that the
+/// tree of a long-lived wallet doesn't grow without bound.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WitnessPolicy {
+    /// Keep the witnesses of unspent notes and unclaimed swaps, and of spent delegation tokens,
+    /// which can still be used to vote on proposals that started before they were spent.
+    #[default]
+    KeepDelegations,
+    /// Keep the witnesses of unspent notes and unclaimed swaps only, forgetting all the others as
+    /// soon as they are spent. Delegation tokens can then only be used to vote while unspent.
+    UnspentOnly,
+}
+
+impl WitnessPolicy {
+    /// Whether to keep the witness of a spent note of the given denom.
+    pub fn keeps_spent(&self, denom: &str) -> bool {
+        match self {
+            WitnessPolicy::KeepDelegations => DelegationToken::from_str(denom).is_ok(),
+            WitnessPolicy::UnspentOnly => false,
+        }
+    }
+}
+
+impl fmt::Display for WitnessPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            WitnessPolicy::KeepDelegations => "keep-delegations",
+            WitnessPolicy::UnspentOnly => "unspent-only",
+        })
+    }
+}
+
+impl FromStr for WitnessPolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "keep-delegations" => Ok(WitnessPolicy::KeepDelegations),
+            "unspent-only" => Ok(WitnessPolicy::UnspentOnly),
+            _ => Err(anyhow::anyhow!("unknown witness policy {s:?}")),
+        }
+    }
+}
@@ -5,10 +5,13 @@ use std::{
 };
 
 use anyhow::Context;
+use futures::StreamExt;
+use penumbra_asset::asset::Metadata;
 use penumbra_auction::auction::AuctionNft;
 use penumbra_compact_block::CompactBlock;
 use penumbra_dex::lp::{position, LpNft};
 use penumbra_keys::FullViewingKey;
+use penumbra_proto::core::asset::v1 as pb_asset;
 use penumbra_proto::core::{
     app::v1::{
         query_service_client::QueryServiceClient as AppQueryServiceClient,
@@ -21,7 +24,7 @@ use penumbra_proto::core::{
         },
         shielded_pool::v1::{
             query_service_client::QueryServiceClient as ShieldedPoolQueryServiceClient,
-            AssetMetadataByIdRequest,
+            AssetMetadataByIdRequest, AssetMetadataByIdsRequest,
         },
     },
 };
@@ -29,21 +32,26 @@ use penumbra_sct::{CommitmentSource, Nullifier};
 use penumbra_transaction::Transaction;
 use tap::Tap;
 use tokio::sync::{watch, RwLock};
+use tokio_stream::wrappers::ReceiverStream;
 use tonic::transport::Channel;
 use tracing::instrument;
 
 use crate::{
-    sync::{scan_block, FilteredBlock},
+    sync::{scan_block, FilteredBlock, TrialDecryptor},
     Storage,
 };
 
 // The maximum size of a compact block, in bytes (12MB).
 const MAX_CB_SIZE_BYTES: usize = 12 * 1024 * 1024;
 
+// The number of blocks trial-decrypted ahead of the block being scanned.
+const TRIAL_DECRYPTION_LOOKAHEAD: usize = 64;
+
 pub struct Worker {
     storage: Storage,
     sct: Arc<RwLock<penumbra_tct::Tree>>,
     fvk: FullViewingKey, // TODO: notifications (see TODOs on ViewService)
+    decryptor: TrialDecryptor,
     error_slot: Arc<Mutex<Option<anyhow::Error>>>,
     sync_height_tx: watch::Sender<u64>,
     /// Tonic channel used to create GRPC clients.
@@ -87,11 +95,14 @@ impl Worker {
         // Mark the current height as seen, since it's not new.
         sync_height_rx.borrow_and_update();
 
+        let decryptor = TrialDecryptor::new(fvk.clone())?;
+
         Ok((
             Self {
                 storage,
                 sct: sct.clone(),
                 fvk,
+                decryptor,
                 error_slot: error_slot.clone(),
                 sync_height_tx,
                 channel,
@@ -184,7 +195,48 @@ impl Worker {
         Ok(transactions)
     }
 
+    /// Refreshes the metadata of the known assets from the chain's asset registry, to pick
+    /// up display units and symbols registered since they were first seen.
+    #[instrument(skip(self))]
+    pub async fn refresh_asset_metadata(&self) -> anyhow::Result<()> {
+        let known = self.storage.all_assets().await?;
+        if known.is_empty() {
+            return Ok(());
+        }
+
+        let mut client = ShieldedPoolQueryServiceClient::new(self.channel.clone());
+        let mut stream = client
+            .asset_metadata_by_ids(AssetMetadataByIdsRequest {
+                asset_id: known.iter().map(|metadata| metadata.id().into()).collect(),
+            })
+            .await?
+            .into_inner();
+
+        while let Some(response) = stream.message().await? {
+            let Some(denom_metadata) = response.denom_metadata else {
+                continue;
+            };
+            let denom_metadata: Metadata = denom_metadata.try_into()?;
+            // Only rewrite the records that changed.
+            let updated = pb_asset::Metadata::from(denom_metadata.clone());
+            if !known
+                .iter()
+                .any(|metadata| pb_asset::Metadata::from(metadata.clone()) == updated)
+            {
+                self.storage.record_asset(denom_metadata).await?;
+            }
+        }
+
+        Ok(())
+    }
+
     pub async fn sync(&mut self) -> anyhow::Result<()> {
+        // Pick up asset metadata registered since the last sync. This isn't needed to sync, and
+        // older nodes don't support the query, so failures are only logged.
+        if let Err(e) = self.refresh_asset_metadata().await {
+            tracing::warn!(?e, "failed to refresh asset metadata");
+        }
+
         // Do a single sync run, up to whatever the latest block height is
         tracing::info!("starting client sync");
 
@@ -212,7 +264,7 @@ impl Worker {
         // purposes: first, it allows buffering to smooth performance; second,
         // it makes it slightly more difficult for a remote server to observe
         // the exact timings of the scanning of each CompactBlock.
-        let (tx, mut buffered_stream) = tokio::sync::mpsc::channel(1000);
+        let (tx, buffered_stream) = tokio::sync::mpsc::channel(1000);
         tokio::spawn(async move {
             while let Some(block) = stream.message().await.transpose() {
                 if tx.send(block).await.is_err() {
@@ -221,10 +273,28 @@ impl Worker {
             }
         });
 
+        // Trial-decrypt blocks ahead of scanning them, so that the blocks in
+        // the buffer are decrypted in parallel, while still being scanned in order.
+        let decryptor = self.decryptor.clone();
+        let mut decrypted_stream = ReceiverStream::new(buffered_stream)
+            .map(move |block| {
+                let decryptor = decryptor.clone();
+                async move {
+                    let block: CompactBlock = block?.try_into()?;
+                    let decrypted = if block.requires_scanning() {
+                        decryptor.decrypt(&block).await?
+                    } else {
+                        Default::default()
+                    };
+                    anyhow::Ok((block, decrypted))
+                }
+            })
+            .buffered(TRIAL_DECRYPTION_LOOKAHEAD);
+
         let mut expected_height = start_height;
 
-        while let Some(block) = buffered_stream.recv().await {
-            let block: CompactBlock = block?.try_into()?;
+        while let Some(result) = decrypted_stream.next().await {
+            let (block, decrypted) = result?;
 
             let height = block.height;
             if height != expected_height {
@@ -253,7 +323,7 @@ impl Worker {
             } else {
                 // Otherwise, scan the block and commit its changes:
                 let mut filtered_block =
-                    scan_block(&self.fvk, &mut sct_guard, block, &self.storage).await?;
+                    scan_block(&self.fvk, &mut sct_guard, block, decrypted, &self.storage).await?;
 
                 // Download any transactions we detected.
                 let transactions = self.fetch_transactions(&mut filtered_block).await?;
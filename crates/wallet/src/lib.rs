@@ -4,3 +4,4 @@ mod build;
 pub use build::build_transaction;
 
 pub mod plan;
+pub mod recover;
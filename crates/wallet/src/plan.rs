@@ -5,8 +5,10 @@ use decaf377::Fq;
 use rand_core::{CryptoRng, RngCore};
 use tracing::instrument;
 
+use penumbra_asset::asset;
 use penumbra_dex::swap_claim::SwapClaimPlan;
 use penumbra_keys::keys::AddressIndex;
+use penumbra_num::Amount;
 use penumbra_proto::view::v1::NotesRequest;
 use penumbra_transaction::{TransactionParameters, TransactionPlan};
 pub use penumbra_view::Planner;
@@ -14,8 +16,41 @@ use penumbra_view::{SpendableNoteRecord, ViewClient};
 
 pub const SWEEP_COUNT: usize = 8;
 
+/// Options for sweeping the notes of a wallet.
+#[derive(Clone, Copy, Debug)]
+pub struct SweepOptions {
+    /// The number of notes consolidated by each sweep transaction.
+    pub count: usize,
+    /// The most that the sweep transactions may pay in fees, altogether.
+    pub max_fee: Option<Amount>,
+}
+
+impl Default for SweepOptions {
+    fn default() -> Self {
+        Self {
+            count: SWEEP_COUNT,
+            max_fee: None,
+        }
+    }
+}
+
 #[instrument(skip(view, rng))]
-pub async fn sweep<V, R>(view: &mut V, mut rng: R) -> anyhow::Result<Vec<TransactionPlan>>
+pub async fn sweep<V, R>(view: &mut V, rng: R) -> anyhow::Result<Vec<TransactionPlan>>
+where
+    V: ViewClient,
+    R: RngCore + CryptoRng,
+{
+    sweep_with(view, rng, SweepOptions::default()).await
+}
+
+/// Plans the transactions claiming unclaimed swaps and consolidating small
+/// notes, according to the given [`SweepOptions`].
+#[instrument(skip(view, rng))]
+pub async fn sweep_with<V, R>(
+    view: &mut V,
+    mut rng: R,
+    options: SweepOptions,
+) -> anyhow::Result<Vec<TransactionPlan>>
 where
     V: ViewClient,
     R: RngCore + CryptoRng,
@@ -27,11 +62,59 @@ where
 
     // Finally, sweep dust notes by spending them to their owner's address.
     // This will consolidate small-value notes into larger ones.
-    plans.extend(sweep_notes(view, &mut rng).await?);
+    plans.extend(sweep_notes(view, &mut rng, options).await?);
 
     Ok(plans)
 }
 
+/// Counts the notes that a sweep consolidating `count` notes at a time would
+/// spend, i.e. how fragmented the wallet is.
+#[instrument(skip(view))]
+pub async fn fragmented_notes<V: ViewClient>(view: &mut V, count: usize) -> anyhow::Result<usize> {
+    let fragmented = notes_by_addr_and_denom(view)
+        .await?
+        .into_values()
+        .flat_map(BTreeMap::into_values)
+        .map(|records| records.len() - records.len() % count.max(1))
+        .sum();
+
+    Ok(fragmented)
+}
+
+/// Returns whether at least `threshold` notes of the wallet are fragmented,
+/// so that it should be swept before it's used.
+pub async fn needs_sweep<V: ViewClient>(
+    view: &mut V,
+    count: usize,
+    threshold: usize,
+) -> anyhow::Result<bool> {
+    Ok(fragmented_notes(view, count).await? >= threshold)
+}
+
+async fn notes_by_addr_and_denom<V: ViewClient>(
+    view: &mut V,
+) -> anyhow::Result<BTreeMap<AddressIndex, BTreeMap<asset::Id, Vec<SpendableNoteRecord>>>> {
+    let all_notes = view
+        .notes(NotesRequest {
+            ..Default::default()
+        })
+        .await?;
+
+    let mut notes_by_addr_and_denom: BTreeMap<AddressIndex, BTreeMap<_, Vec<SpendableNoteRecord>>> =
+        BTreeMap::new();
+
+    for record in all_notes {
+        notes_by_addr_and_denom
+            .entry(record.address_index)
+            .or_default()
+            .entry(record.note.asset_id())
+            .or_default()
+            .push(record);
+    }
+
+    Ok(notes_by_addr_and_denom)
+}
+
 #[instrument(skip(view, rng))]
 async fn claim_unclaimed_swaps<V, R>(
     view: &mut V,
@@ -85,32 +168,21 @@ where
 }
 
 #[instrument(skip(view, rng))]
-async fn sweep_notes<V, R>(view: &mut V, mut rng: R) -> anyhow::Result<Vec<TransactionPlan>>
+async fn sweep_notes<V, R>(
+    view: &mut V,
+    mut rng: R,
+    options: SweepOptions,
+) -> anyhow::Result<Vec<TransactionPlan>>
 where
     V: ViewClient,
     R: RngCore + CryptoRng,
 {
     let gas_prices = view.gas_prices().await?;
 
-    let all_notes = view
-        .notes(NotesRequest {
-            ..Default::default()
-        })
-        .await?;
-
-    let mut notes_by_addr_and_denom: BTreeMap<AddressIndex, BTreeMap<_, Vec<SpendableNoteRecord>>> =
-        BTreeMap::new();
-
-    for record in all_notes {
-        notes_by_addr_and_denom
-            .entry(record.address_index)
-            .or_default()
-            .entry(record.note.asset_id())
-            .or_default()
-            .push(record);
-    }
+    let notes_by_addr_and_denom = notes_by_addr_and_denom(view).await?;
 
     let mut plans = Vec::new();
+    let mut total_fee = Amount::zero();
 
     for (index, notes_by_denom) in notes_by_addr_and_denom {
         tracing::info!(?index, "processing address");
@@ -120,9 +192,9 @@ where
 
             // Sort notes by amount, ascending, so the biggest notes are at the end...
             records.sort_by(|a, b| a.note.value().amount.cmp(&b.note.value().amount));
-            // ... so that when we use chunks_exact, we get SWEEP_COUNT sized
+            // ... so that when we use chunks_exact, we get `count` sized
             // chunks, ignoring the biggest notes in the remainder.
-            for group in records.chunks_exact(SWEEP_COUNT) {
+            for group in records.chunks_exact(options.count.max(1)) {
                 let mut planner = Planner::new(&mut rng);
                 planner.set_gas_prices(gas_prices);
 
@@ -135,6 +207,24 @@ where
                     .await
                     .context("can't build sweep transaction")?;
 
+                // Consolidating notes of the fee asset is pointless if the
+                // fee eats up as much as they're worth.
+                let fee = plan.transaction_parameters.fee.clone();
+                let value = group
+                    .iter()
+                    .map(|record| record.note.amount())
+                    .fold(Amount::zero(), |total, amount| total + amount);
+                if fee.asset_id() == asset_id && value <= fee.amount() {
+                    tracing::debug!(?value, ?fee, "skipping sweep costing more than its notes");
+                    continue;
+                }
+
+                total_fee += fee.amount();
+                if options.max_fee.is_some_and(|max_fee| total_fee > max_fee) {
+                    tracing::info!(?total_fee, "reached the maximum fee for sweeping");
+                    return Ok(plans);
+                }
+
                 tracing::debug!(?plan);
                 plans.push(plan);
             }
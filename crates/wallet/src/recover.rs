@@ -0,0 +1,40 @@
+use std::collections::BTreeMap;
+
+use tracing::instrument;
+
+use penumbra_asset::asset;
+use penumbra_keys::keys::AddressIndex;
+use penumbra_num::Amount;
+use penumbra_view::ViewClient;
+
+/// The number of consecutive unused accounts after which [`balances`] stops
+/// scanning, as is customary for wallets recovered from a seed phrase.
+pub const DEFAULT_GAP_LIMIT: u32 = 20;
+
+/// Recovers the balances of a wallet restored from its seed phrase, for every
+/// address index that received funds, scanning accounts from the first one
+/// until `gap_limit` consecutive accounts turn out to be unused.
+#[instrument(skip(view))]
+pub async fn balances<V>(
+    view: &mut V,
+    gap_limit: u32,
+) -> anyhow::Result<BTreeMap<AddressIndex, BTreeMap<asset::Id, Amount>>>
+where
+    V: ViewClient,
+{
+    let mut balances = BTreeMap::new();
+
+    for (_, addresses) in view.scan_accounts(0, gap_limit).await? {
+        for (address_index, notes) in addresses {
+            for record in notes.into_iter().filter(|r| r.height_spent.is_none()) {
+                *balances
+                    .entry(address_index)
+                    .or_insert_with(BTreeMap::new)
+                    .entry(record.note.asset_id())
+                    .or_insert_with(Amount::zero) += record.note.amount();
+            }
+        }
+    }
+
+    Ok(balances)
+}